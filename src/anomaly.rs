@@ -0,0 +1,128 @@
+//! Spike detection against a rolling baseline.
+//!
+//! Each metric keeps its recent samples; a new sample is anomalous when it
+//! lies more than a few standard deviations above their mean. A machine that
+//! idles at 60% CPU learns that 60% is normal, while one that sits at 2%
+//! still notices a jump to 15%.
+
+use serde::{Deserialize, Serialize};
+
+use crate::ringbuf::RingBuffer;
+
+/// Samples kept per metric.
+const WINDOW: usize = 120;
+/// No verdicts until this many samples have been seen.
+const MIN_SAMPLES: usize = 30;
+/// Lower bound on the standard deviation, in the metric's unit, so a
+/// perfectly flat baseline doesn't flag sub-point noise.
+const MIN_STD_DEV: f32 = 1.0;
+
+/// How many standard deviations above the baseline count as a spike.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Sensitivity {
+    Low,
+    #[default]
+    Medium,
+    High,
+}
+
+impl Sensitivity {
+    pub const ALL: &'static [Sensitivity] = &[Sensitivity::Low, Sensitivity::Medium, Sensitivity::High];
+
+    fn z_threshold(self) -> f32 {
+        match self {
+            Sensitivity::Low => 4.0,
+            Sensitivity::Medium => 3.0,
+            Sensitivity::High => 2.0,
+        }
+    }
+}
+
+/// A sample that stood out from the baseline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Anomaly {
+    pub value: f32,
+    pub mean: f32,
+    pub std_dev: f32,
+}
+
+/// Rolling baseline of one metric.
+#[derive(Debug, Clone)]
+pub struct Baseline {
+    samples: RingBuffer<f32>,
+    count: usize,
+    /// Set while samples stay anomalous, so a sustained spike is reported once.
+    active: bool,
+}
+
+impl Default for Baseline {
+    fn default() -> Self {
+        Self { samples: RingBuffer::new(WINDOW), count: 0, active: false }
+    }
+}
+
+impl Baseline {
+    /// Mean and standard deviation of the samples seen so far.
+    fn stats(&self) -> (f32, f32) {
+        let n = self.count.min(WINDOW) as f32;
+        let mean = self.samples.iter().sum::<f32>() / n;
+        let var = self.samples.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / n;
+        (mean, var.sqrt())
+    }
+
+    /// Add a sample and report it if it starts a spike.
+    pub fn observe(&mut self, value: f32, sensitivity: Sensitivity) -> Option<Anomaly> {
+        let mut anomaly = None;
+        if self.count >= MIN_SAMPLES {
+            let (mean, std_dev) = self.stats();
+            let is_spike = value - mean > sensitivity.z_threshold() * std_dev.max(MIN_STD_DEV);
+            if is_spike && !self.active {
+                anomaly = Some(Anomaly { value, mean, std_dev });
+            }
+            self.active = is_spike;
+        }
+        self.samples.push(value);
+        self.count += 1;
+        anomaly
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn warmed(level: f32, jitter: f32) -> Baseline {
+        let mut b = Baseline::default();
+        for i in 0..MIN_SAMPLES {
+            let v = if i % 2 == 0 { level + jitter } else { level - jitter };
+            assert_eq!(b.observe(v, Sensitivity::Medium), None);
+        }
+        b
+    }
+
+    #[test]
+    fn test_busy_machine_baseline() {
+        // Idling around 60% ± 5 is normal; 70% is not a spike, 95% is
+        let mut b = warmed(60.0, 5.0);
+        assert_eq!(b.observe(70.0, Sensitivity::Medium), None);
+        let a = b.observe(95.0, Sensitivity::Medium).unwrap();
+        assert!((a.mean - (60.0 + 10.0 / (MIN_SAMPLES as f32 + 1.0))).abs() < 0.01);
+        // Still high on the next tick: reported once
+        assert_eq!(b.observe(95.0, Sensitivity::Medium), None);
+    }
+
+    #[test]
+    fn test_quiet_machine_baseline() {
+        let mut b = warmed(2.0, 0.2);
+        assert_eq!(b.observe(3.0, Sensitivity::High), None);
+        assert!(b.observe(8.0, Sensitivity::Medium).is_some());
+    }
+
+    #[test]
+    fn test_sensitivity() {
+        let mut low = warmed(50.0, 2.0);
+        let mut high = warmed(50.0, 2.0);
+        assert_eq!(low.observe(57.0, Sensitivity::Low), None);
+        assert!(high.observe(57.0, Sensitivity::High).is_some());
+    }
+}
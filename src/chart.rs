@@ -1,16 +1,34 @@
+use std::cell::Cell;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use iced_tiny_skia::graphics::geometry;
 use iced::mouse;
 use iced::widget::canvas::{self, Event, Frame, Geometry, Path, Stroke, Text};
 use iced::{Color, Point, Rectangle, Renderer, Size, Theme};
 
+use crate::locale;
 use crate::{NERD_FONT, NERD_FONT_MONO};
 
-/// Hover state: stores the snapped data-point index (not raw pixel).
-#[derive(Debug, Clone, Default)]
+/// Per-widget state: hover position and the cached chart geometry.
+#[derive(Default)]
 pub struct ChartState {
     /// Index of the hovered data point, or None if not hovering.
     pub hover_idx: Option<usize>,
+    /// Everything except the hover overlay. The chart is rebuilt on every
+    /// view, so the cache is keyed on `LineChart::cache_key` rather than
+    /// cleared by the owner.
+    cache: canvas::Cache,
+    cache_key: Cell<u64>,
 }
 
+/// Padding around the plot area: left (y labels), right, top (title), bottom.
+const PAD_LEFT: f32 = 44.0;
+const PAD_RIGHT: f32 = 8.0;
+const PAD_TOP: f32 = 22.0;
+const PAD_BOTTOM: f32 = 6.0;
+/// Extra bottom padding when the chart has time labels.
+const X_LABEL_H: f32 = 14.0;
+
 /// Colors the chart needs from the active palette.
 #[derive(Debug, Clone, Copy)]
 pub struct ChartColors {
@@ -29,17 +47,207 @@ pub struct LineChart {
     pub y_max: f32,
     pub title: String,
     pub filled: bool,
+    /// Draw the left-axis series on top of each other, each filled down to
+    /// the one below. Tooltips and the legend keep each series' own value.
+    pub stacked: bool,
     /// Unit suffix for the tooltip (e.g. "%", " B/s", "°C").
     pub unit: String,
     pub colors: ChartColors,
     /// Whether to draw a horizontal average line for each series.
     pub show_avg: bool,
+    /// Labels under the plot as (position 0–1 across the plot, text), such
+    /// as sample times; empty for none.
+    pub x_labels: Vec<(f32, String)>,
+    /// Shaded ranges as (color, low, high), drawn behind the series, e.g.
+    /// the extremes hidden by downsampling; empty for none.
+    pub bands: Vec<(Color, Vec<f32>, Vec<f32>)>,
+    /// Vertical markers such as logged events; empty for none.
+    pub markers: Vec<ChartMarker>,
+    /// Series in a second unit, scaled to their own axis on the right.
+    pub right_axis: Option<RightAxis>,
+}
+
+/// A second Y axis, labelled on the right, for series in another unit.
+/// Its series are drawn as lines only.
+#[derive(Debug, Clone)]
+pub struct RightAxis {
+    pub series: Vec<(String, Color, Vec<f32>)>,
+    pub y_min: f32,
+    pub y_max: f32,
+    pub unit: String,
+}
+
+/// Range and unit of the axis a series is drawn against.
+#[derive(Clone, Copy)]
+struct Axis<'a> {
+    min: f32,
+    max: f32,
+    unit: &'a str,
+    right: bool,
+}
+
+impl Axis<'_> {
+    /// Height of `val` on this axis, 0 at the bottom and 1 at the top.
+    fn normalize(self, val: f32) -> f32 {
+        let range = self.max - self.min;
+        if range > 0.0 { (val - self.min) / range } else { 0.5 }
+    }
+}
+
+/// A vertical line across the plot whose label shows while the pointer is
+/// on its sample.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartMarker {
+    /// Position across the plot, 0–1.
+    pub pos: f32,
+    pub color: Color,
+    pub label: String,
 }
 
 impl LineChart {
     /// Number of data points in the longest series.
     fn data_len(&self) -> usize {
-        self.series.iter().map(|(_, _, d)| d.len()).max().unwrap_or(0)
+        self.plotted().map(|((_, _, d), _)| d.len()).max().unwrap_or(0)
+    }
+
+    /// Every series with the axis it is drawn against, left ones first.
+    fn plotted(&self) -> impl Iterator<Item = (&(String, Color, Vec<f32>), Axis<'_>)> {
+        let left = Axis { min: self.y_min, max: self.y_max, unit: &self.unit, right: false };
+        let right = self.right_axis.iter().flat_map(|a| {
+            let axis = Axis { min: a.y_min, max: a.y_max, unit: &a.unit, right: true };
+            a.series.iter().map(move |s| (s, axis))
+        });
+        self.series.iter().map(move |s| (s, left)).chain(right)
+    }
+
+    /// Values each plotted series is drawn at: its own, or when `stacked`,
+    /// the running total of the left-axis series up to and including it.
+    fn drawn(&self) -> Vec<Vec<f32>> {
+        let mut total: Vec<f32> = Vec::new();
+        self.plotted()
+            .map(|((_, _, data), axis)| {
+                if !self.stacked || axis.right {
+                    return data.clone();
+                }
+                if total.len() < data.len() {
+                    total.resize(data.len(), 0.0);
+                }
+                for (t, v) in total.iter_mut().zip(data) {
+                    *t += v;
+                }
+                total[..data.len()].to_vec()
+            })
+            .collect()
+    }
+
+    fn pad_right(&self) -> f32 {
+        if self.right_axis.is_some() { PAD_LEFT } else { PAD_RIGHT }
+    }
+
+    /// Fingerprint of everything `paint` draws apart from the frame size.
+    /// Equal keys mean the cached geometry can be reused.
+    fn cache_key(&self) -> u64 {
+        let mut h = DefaultHasher::new();
+        let color = |h: &mut DefaultHasher, c: &Color| {
+            for v in [c.r, c.g, c.b, c.a] {
+                v.to_bits().hash(h);
+            }
+        };
+        self.title.hash(&mut h);
+        self.unit.hash(&mut h);
+        (self.filled, self.show_avg, self.stacked).hash(&mut h);
+        (self.y_min.to_bits(), self.y_max.to_bits()).hash(&mut h);
+        for (pos, label) in &self.x_labels {
+            (pos.to_bits(), label).hash(&mut h);
+        }
+        for m in &self.markers {
+            (m.pos.to_bits(), &m.label).hash(&mut h);
+            color(&mut h, &m.color);
+        }
+        for (col, low, high) in &self.bands {
+            color(&mut h, col);
+            for v in low.iter().chain(high) {
+                v.to_bits().hash(&mut h);
+            }
+        }
+        let c = &self.colors;
+        for col in [&c.bg, &c.border, &c.grid, &c.label, &c.text] {
+            color(&mut h, col);
+        }
+        if let Some(axis) = &self.right_axis {
+            (axis.y_min.to_bits(), axis.y_max.to_bits(), &axis.unit).hash(&mut h);
+        }
+        for ((label, col, data), _) in self.plotted() {
+            label.hash(&mut h);
+            color(&mut h, col);
+            data.len().hash(&mut h);
+            for v in data {
+                v.to_bits().hash(&mut h);
+            }
+        }
+        h.finish()
+    }
+
+    /// Visible series as CSV: one row per sample, one column per series.
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from("sample");
+        for ((label, _, _), axis) in self.plotted() {
+            let unit = axis.unit.trim();
+            // Drop Nerd Font glyphs (private use area) from labels like " rx".
+            let name: String = label.chars().filter(|c| !('\u{e000}'..='\u{f8ff}').contains(c)).collect();
+            out.push(',');
+            out.push_str(name.trim());
+            if !unit.is_empty() {
+                out.push_str(&format!(" ({unit})"));
+            }
+        }
+        out.push('\n');
+        for i in 0..self.data_len() {
+            out.push_str(&i.to_string());
+            for ((_, _, data), _) in self.plotted() {
+                out.push(',');
+                if let Some(v) = data.get(i) {
+                    out.push_str(&format!("{v:.2}"));
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Render the chart (without hover overlay) to a PNG file.
+    pub fn save_png(&self, width: u32, height: u32, path: &std::path::Path) -> Result<(), String> {
+        let png = self.render_png(width, height)?;
+        std::fs::write(path, png).map_err(|e| e.to_string())
+    }
+
+    /// Render the chart to PNG bytes using the software renderer,
+    /// independent of the window's GPU backend.
+    pub fn render_png(&self, width: u32, height: u32) -> Result<Vec<u8>, String> {
+        use geometry::Renderer as _;
+        use iced_tiny_skia::graphics::Viewport;
+
+        let mut renderer = iced_tiny_skia::Renderer::new(NERD_FONT, iced::Pixels(14.0));
+        let mut frame = Frame::new(&renderer, Size::new(width as f32, height as f32));
+        self.paint(&mut frame);
+        renderer.draw_geometry(frame.into_geometry());
+
+        let mut pixmap = tiny_skia::Pixmap::new(width, height).ok_or("invalid image size")?;
+        let mut clip_mask = tiny_skia::Mask::new(width, height).ok_or("invalid image size")?;
+        let viewport = Viewport::with_physical_size(Size::new(width, height), 1.0);
+        renderer.draw::<&str>(
+            &mut pixmap.as_mut(),
+            &mut clip_mask,
+            &viewport,
+            &[Rectangle::with_size(Size::new(width as f32, height as f32))],
+            self.colors.bg,
+            &[],
+        );
+        // The renderer targets BGRA window surfaces; swap back to RGBA.
+        for px in pixmap.data_mut().chunks_exact_mut(4) {
+            px.swap(0, 2);
+        }
+        pixmap.encode_png().map_err(|e| e.to_string())
     }
 }
 
@@ -53,9 +261,8 @@ impl<Message: 'static> canvas::Program<Message> for LineChart {
         bounds: Rectangle,
         cursor: mouse::Cursor,
     ) -> (canvas::event::Status, Option<Message>) {
-        let pad_left = 44.0f32;
-        let pad_right = 8.0f32;
-        let chart_w = bounds.width - pad_left - pad_right;
+        let pad_left = PAD_LEFT;
+        let chart_w = bounds.width - PAD_LEFT - self.pad_right();
         let n = self.data_len();
 
         let new_idx = match &event {
@@ -76,7 +283,7 @@ impl<Message: 'static> canvas::Program<Message> for LineChart {
             _ => return (canvas::event::Status::Ignored, None),
         };
 
-        // Only update state (and thus invalidate cache) when the index actually changes.
+        // Only update state (and thus redraw the hover overlay) when the index actually changes.
         if new_idx != state.hover_idx {
             state.hover_idx = new_idx;
         }
@@ -104,23 +311,37 @@ impl<Message: 'static> canvas::Program<Message> for LineChart {
         bounds: Rectangle,
         _cursor: mouse::Cursor,
     ) -> Vec<Geometry> {
-        let mut frame = Frame::new(renderer, bounds.size());
-        let c = &self.colors;
-
-        let pad_left = 44.0f32;
-        let pad_right = 8.0f32;
-        let pad_top = 22.0f32;
-        let pad_bottom = 6.0f32;
+        let key = self.cache_key();
+        if state.cache_key.replace(key) != key {
+            state.cache.clear();
+        }
+        let base = state.cache.draw(renderer, bounds.size(), |frame| self.paint(frame));
+        let Some(idx) = state.hover_idx else { return vec![base] };
+        let mut overlay = Frame::new(renderer, bounds.size());
+        self.paint_hover(&mut overlay, idx);
+        vec![base, overlay.into_geometry()]
+    }
+}
 
-        let chart_w = bounds.width - pad_left - pad_right;
-        let chart_h = bounds.height - pad_top - pad_bottom;
+impl LineChart {
+    /// Plot area width and height for a frame, or None if it is too small.
+    fn plot_size(&self, bounds: Size) -> Option<(f32, f32)> {
+        let pad_bottom = if self.x_labels.is_empty() { PAD_BOTTOM } else { PAD_BOTTOM + X_LABEL_H };
+        let chart_w = bounds.width - PAD_LEFT - self.pad_right();
+        let chart_h = bounds.height - PAD_TOP - pad_bottom;
+        (chart_w > 0.0 && chart_h > 0.0).then_some((chart_w, chart_h))
+    }
 
-        if chart_w <= 0.0 || chart_h <= 0.0 {
-            return vec![frame.into_geometry()];
-        }
+    /// Draw the chart (without hover overlay) into `frame`; shared by the
+    /// canvas cache and PNG export.
+    fn paint<R: geometry::Renderer>(&self, frame: &mut Frame<R>) {
+        let bounds = frame.size();
+        let c = &self.colors;
+        let (pad_left, pad_top) = (PAD_LEFT, PAD_TOP);
+        let Some((chart_w, chart_h)) = self.plot_size(bounds) else { return };
 
         // Background with subtle rounded appearance
-        let bg = Path::rectangle(Point::ORIGIN, bounds.size());
+        let bg = Path::rectangle(Point::ORIGIN, bounds);
         frame.fill(&bg, c.bg);
 
         // Softer border
@@ -163,38 +384,122 @@ impl<Message: 'static> canvas::Program<Message> for LineChart {
             }
         }
 
-        let n = self.data_len();
+        // X-axis labels, kept inside the plot at both ends
+        for (pos, label_str) in &self.x_labels {
+            let mut label = Text::from(label_str.clone());
+            label.position = Point::new(pad_left + chart_w * pos.clamp(0.0, 1.0), pad_top + chart_h + 3.0);
+            label.horizontal_alignment = if *pos <= 0.05 {
+                iced::alignment::Horizontal::Left
+            } else if *pos >= 0.95 {
+                iced::alignment::Horizontal::Right
+            } else {
+                iced::alignment::Horizontal::Center
+            };
+            label.color = c.label;
+            label.size = 10.0.into();
+            label.font = NERD_FONT_MONO;
+            frame.fill_text(label);
+        }
+
+        // Right axis labels, without grid lines of their own
+        if let Some(axis) = &self.right_axis {
+            let range = axis.y_max - axis.y_min;
+            if range > 0.0 {
+                let step = nice_tick_step(range, 10);
+                let mut val = (axis.y_min / step).ceil() * step;
+                while val <= axis.y_max + step * 0.001 {
+                    let y = pad_top + chart_h * (1.0 - (val - axis.y_min) / range);
+                    let label_str = if step >= 1.0 { format!("{val:.0}") } else { format!("{val:.1}") };
+                    let mut label = Text::from(label_str);
+                    label.position = Point::new(bounds.width - 4.0, y - 5.0);
+                    label.horizontal_alignment = iced::alignment::Horizontal::Right;
+                    label.color = c.label;
+                    label.size = 10.0.into();
+                    label.font = NERD_FONT_MONO;
+                    frame.fill_text(label);
+                    val += step;
+                }
+            }
+        }
+
+        // Bands: along the highs, then back along the lows
+        for (color, low, high) in &self.bands {
+            let dn = low.len().min(high.len());
+            if dn < 2 {
+                continue;
+            }
+            let point = |i: usize, val: f32| {
+                let x = pad_left + (i as f32 / (dn - 1) as f32) * chart_w;
+                let normalized = if y_range > 0.0 { ((val - self.y_min) / y_range).clamp(0.0, 1.0) } else { 0.5 };
+                Point::new(x, pad_top + chart_h * (1.0 - normalized))
+            };
+            let mut builder = canvas::path::Builder::new();
+            builder.move_to(point(0, high[0]));
+            for (i, &val) in high.iter().enumerate().take(dn).skip(1) {
+                builder.line_to(point(i, val));
+            }
+            for (i, &val) in low.iter().enumerate().take(dn).rev() {
+                builder.line_to(point(i, val));
+            }
+            builder.close();
+            frame.fill(&builder.build(), Color::from_rgba(color.r, color.g, color.b, 0.25));
+        }
+
+        // Markers: a faint line with a small flag at the top
+        for m in &self.markers {
+            let x = pad_left + chart_w * m.pos.clamp(0.0, 1.0);
+            let line = Path::line(Point::new(x, pad_top), Point::new(x, pad_top + chart_h));
+            frame.stroke(&line, Stroke::default().with_color(Color::from_rgba(m.color.r, m.color.g, m.color.b, 0.5)).with_width(1.0));
+            let mut flag = canvas::path::Builder::new();
+            flag.move_to(Point::new(x - 4.0, pad_top));
+            flag.line_to(Point::new(x + 4.0, pad_top));
+            flag.line_to(Point::new(x, pad_top + 6.0));
+            flag.close();
+            frame.fill(&flag.build(), m.color);
+        }
 
         // Draw series
-        for (_label, color, data) in &self.series {
+        let drawn = self.drawn();
+        // Top of the stack so far, which the next stacked fill goes down to
+        let mut below: Option<&Vec<f32>> = None;
+        for (((_label, color, _), axis), data) in self.plotted().zip(&drawn) {
             if data.len() < 2 {
                 continue;
             }
             let dn = data.len();
+            let point = |i: usize, val: f32| {
+                Point::new(pad_left + (i as f32 / (dn - 1) as f32) * chart_w, pad_top + chart_h * (1.0 - axis.normalize(val)))
+            };
 
-            // Filled area
-            if self.filled {
+            // Filled area; right axis series stay lines so both read clearly
+            if self.filled && !axis.right {
                 let mut builder = canvas::path::Builder::new();
                 builder.move_to(Point::new(pad_left, pad_top + chart_h));
                 for (i, &val) in data.iter().enumerate() {
-                    let x = pad_left + (i as f32 / (dn - 1) as f32) * chart_w;
-                    let normalized = if y_range > 0.0 { (val - self.y_min) / y_range } else { 0.5 };
-                    let y = pad_top + chart_h * (1.0 - normalized);
-                    builder.line_to(Point::new(x, y));
+                    builder.line_to(point(i, val));
+                }
+                match below {
+                    Some(lower) => {
+                        for (i, &val) in lower.iter().enumerate().take(dn).rev() {
+                            builder.line_to(point(i, val));
+                        }
+                    }
+                    None => builder.line_to(Point::new(pad_left + chart_w, pad_top + chart_h)),
                 }
-                builder.line_to(Point::new(pad_left + chart_w, pad_top + chart_h));
                 builder.close();
                 let fill_path = builder.build();
-                let fill_color = Color::from_rgba(color.r, color.g, color.b, 0.15);
-                frame.fill(&fill_path, fill_color);
+                let alpha = if self.stacked { 0.35 } else { 0.15 };
+                frame.fill(&fill_path, Color::from_rgba(color.r, color.g, color.b, alpha));
+            }
+            if self.stacked && !axis.right {
+                below = Some(data);
             }
 
             // Line with glow effect
             let mut builder = canvas::path::Builder::new();
             for (i, &val) in data.iter().enumerate() {
                 let x = pad_left + (i as f32 / (dn - 1) as f32) * chart_w;
-                let normalized = if y_range > 0.0 { (val - self.y_min) / y_range } else { 0.5 };
-                let y = pad_top + chart_h * (1.0 - normalized);
+                let y = pad_top + chart_h * (1.0 - axis.normalize(val));
                 if i == 0 {
                     builder.move_to(Point::new(x, y));
                 } else {
@@ -211,13 +516,12 @@ impl<Message: 'static> canvas::Program<Message> for LineChart {
 
         // Average line (dashed appearance via dotted segments)
         if self.show_avg {
-            for (_label, color, data) in &self.series {
+            for ((_label, color, data), axis) in self.plotted() {
                 if data.is_empty() {
                     continue;
                 }
                 let avg_val = data.iter().sum::<f32>() / data.len() as f32;
-                let normalized = if y_range > 0.0 { (avg_val - self.y_min) / y_range } else { 0.5 };
-                let y = pad_top + chart_h * (1.0 - normalized);
+                let y = pad_top + chart_h * (1.0 - axis.normalize(avg_val));
                 // Draw dashed line (alternating segments)
                 let dash_len = 6.0;
                 let gap_len = 4.0;
@@ -244,85 +548,11 @@ impl<Message: 'static> canvas::Program<Message> for LineChart {
             }
         }
 
-        // Hover: snap to data-point index
-        if let Some(idx) = state.hover_idx {
-            if n >= 2 && idx < n {
-                let snap_x = pad_left + (idx as f32 / (n - 1) as f32) * chart_w;
-
-                // Vertical crosshair at snapped position
-                let crosshair = Path::line(
-                    Point::new(snap_x, pad_top),
-                    Point::new(snap_x, pad_top + chart_h),
-                );
-                frame.stroke(
-                    &crosshair,
-                    Stroke::default()
-                        .with_color(Color::from_rgba(c.text.r, c.text.g, c.text.b, 0.35))
-                        .with_width(1.0),
-                );
-
-                // Dot + tooltip for each series
-                let mut tooltip_y = pad_top + 4.0;
-                for (label, color, data) in &self.series {
-                    if idx >= data.len() {
-                        continue;
-                    }
-                    let val = data[idx];
-
-                    let normalized = if y_range > 0.0 { (val - self.y_min) / y_range } else { 0.5 };
-                    let dot_y = pad_top + chart_h * (1.0 - normalized);
-
-                    // Outer glow ring on dot
-                    let glow = Path::circle(Point::new(snap_x, dot_y), 7.0);
-                    frame.fill(&glow, Color::from_rgba(color.r, color.g, color.b, 0.25));
-                    // Dot
-                    let dot = Path::circle(Point::new(snap_x, dot_y), 4.0);
-                    frame.fill(&dot, *color);
-                    let ring = Path::circle(Point::new(snap_x, dot_y), 4.0);
-                    frame.stroke(&ring, Stroke::default().with_color(c.text).with_width(1.2));
-
-                    // Tooltip
-                    let tooltip_str = if self.series.len() > 1 {
-                        format!("{}: {:.1}{}", label, val, self.unit)
-                    } else {
-                        format!("{:.1}{}", val, self.unit)
-                    };
-                    let text_w = tooltip_str.len() as f32 * 6.6 + 20.0;
-                    let tx = (snap_x + 14.0).min(pad_left + chart_w - text_w);
-
-                    // Shadow box (offset slightly)
-                    let shadow_path = Path::rectangle(
-                        Point::new(tx - 3.0, tooltip_y - 0.0),
-                        Size::new(text_w, 18.0),
-                    );
-                    frame.fill(&shadow_path, Color::from_rgba(0.0, 0.0, 0.0, 0.15));
-
-                    // Background box with better styling
-                    let box_path = Path::rectangle(
-                        Point::new(tx - 4.0, tooltip_y - 2.0),
-                        Size::new(text_w, 18.0),
-                    );
-                    frame.fill(&box_path, Color::from_rgba(c.bg.r, c.bg.g, c.bg.b, 0.95));
-                    // Subtle border on tooltip
-                    frame.stroke(&box_path, Stroke::default()
-                        .with_color(Color::from_rgba(color.r, color.g, color.b, 0.4))
-                        .with_width(0.8));
-
-                    let mut tt = Text::from(tooltip_str);
-                    tt.position = Point::new(tx, tooltip_y);
-                    tt.color = *color;
-                    tt.size = 11.0.into();
-                    tt.font = NERD_FONT_MONO;
-                    frame.fill_text(tt);
-                    tooltip_y += 20.0;
-                }
-            }
-        }
-
         // Legend (top-right)
         let mut lx = bounds.width - 10.0;
         let ly = 7.0;
-        for (label, color, data) in self.series.iter().rev() {
+        let legend: Vec<_> = self.plotted().collect();
+        for ((label, color, data), _) in legend.into_iter().rev() {
             if let Some(&last) = data.last() {
                 let legend_str = format!("{label}: {last:.1}");
                 let text_w = legend_str.len() as f32 * 6.0 + 14.0;
@@ -337,8 +567,150 @@ impl<Message: 'static> canvas::Program<Message> for LineChart {
                 frame.fill_text(lt);
             }
         }
+    }
+
+    /// Crosshair, dots and value tooltips for the hovered data point.
+    fn paint_hover<R: geometry::Renderer>(&self, frame: &mut Frame<R>, idx: usize) {
+        let c = &self.colors;
+        let (pad_left, pad_top) = (PAD_LEFT, PAD_TOP);
+        let Some((chart_w, chart_h)) = self.plot_size(frame.size()) else { return };
+        let n = self.data_len();
+        let several = self.plotted().nth(1).is_some();
+
+        if n >= 2 && idx < n {
+            let snap_x = pad_left + (idx as f32 / (n - 1) as f32) * chart_w;
+
+            // Vertical crosshair at snapped position
+            let crosshair = Path::line(
+                Point::new(snap_x, pad_top),
+                Point::new(snap_x, pad_top + chart_h),
+            );
+            frame.stroke(
+                &crosshair,
+                Stroke::default()
+                    .with_color(Color::from_rgba(c.text.r, c.text.g, c.text.b, 0.35))
+                    .with_width(1.0),
+            );
+
+            // Dot + tooltip for each series
+            let mut tooltip_y = pad_top + 4.0;
+            for (((label, color, data), axis), drawn) in self.plotted().zip(self.drawn()) {
+                if idx >= data.len() {
+                    continue;
+                }
+                let val = data[idx];
+
+                let dot_y = pad_top + chart_h * (1.0 - axis.normalize(drawn[idx]));
+
+                // Outer glow ring on dot
+                let glow = Path::circle(Point::new(snap_x, dot_y), 7.0);
+                frame.fill(&glow, Color::from_rgba(color.r, color.g, color.b, 0.25));
+                // Dot
+                let dot = Path::circle(Point::new(snap_x, dot_y), 4.0);
+                frame.fill(&dot, *color);
+                let ring = Path::circle(Point::new(snap_x, dot_y), 4.0);
+                frame.stroke(&ring, Stroke::default().with_color(c.text).with_width(1.2));
+
+                // Tooltip
+                let tooltip_str = if several {
+                    format!("{}: {:.1}{}", label, val, axis.unit)
+                } else {
+                    format!("{:.1}{}", val, axis.unit)
+                };
+                self.paint_tooltip(frame, snap_x, tooltip_y, tooltip_str, *color);
+                tooltip_y += 20.0;
+            }
+
+            // Labels of the markers on this sample
+            let half_step = 0.5 / (n - 1) as f32;
+            let frac = idx as f32 / (n - 1) as f32;
+            for m in self.markers.iter().filter(|m| (m.pos - frac).abs() <= half_step) {
+                self.paint_tooltip(frame, snap_x, tooltip_y, m.label.clone(), m.color);
+                tooltip_y += 20.0;
+            }
+        }
+    }
 
-        vec![frame.into_geometry()]
+    /// A value box next to the crosshair at `x`, kept inside the plot.
+    fn paint_tooltip<R: geometry::Renderer>(&self, frame: &mut Frame<R>, x: f32, y: f32, label: String, color: Color) {
+        let c = &self.colors;
+        let Some((chart_w, _)) = self.plot_size(frame.size()) else { return };
+        let text_w = label.len() as f32 * 6.6 + 20.0;
+        let tx = (x + 14.0).min(PAD_LEFT + chart_w - text_w);
+
+        // Shadow box (offset slightly)
+        let shadow_path = Path::rectangle(
+            Point::new(tx - 3.0, y - 0.0),
+            Size::new(text_w, 18.0),
+        );
+        frame.fill(&shadow_path, Color::from_rgba(0.0, 0.0, 0.0, 0.15));
+
+        // Background box with better styling
+        let box_path = Path::rectangle(
+            Point::new(tx - 4.0, y - 2.0),
+            Size::new(text_w, 18.0),
+        );
+        frame.fill(&box_path, Color::from_rgba(c.bg.r, c.bg.g, c.bg.b, 0.95));
+        // Subtle border on tooltip
+        frame.stroke(&box_path, Stroke::default()
+            .with_color(Color::from_rgba(color.r, color.g, color.b, 0.4))
+            .with_width(0.8));
+
+        let mut tt = Text::from(label);
+        tt.position = Point::new(tx, y);
+        tt.color = color;
+        tt.size = 11.0.into();
+        tt.font = NERD_FONT_MONO;
+        frame.fill_text(tt);
+    }
+}
+
+/// Markers for events at epoch seconds, each on the nearest sample of a
+/// series sampled at `timestamps` (oldest first). Events outside the series
+/// are left out.
+pub fn time_markers(timestamps: &[f64], events: impl IntoIterator<Item = (f64, Color, String)>) -> Vec<ChartMarker> {
+    let n = timestamps.len();
+    if n < 2 {
+        return Vec::new();
+    }
+    events
+        .into_iter()
+        .filter(|(at, _, _)| (timestamps[0]..=timestamps[n - 1]).contains(at))
+        .map(|(at, color, label)| {
+            let after = timestamps.partition_point(|&ts| ts < at).min(n - 1);
+            let idx = if after > 0 && at - timestamps[after - 1] < timestamps[after] - at { after - 1 } else { after };
+            ChartMarker { pos: idx as f32 / (n - 1) as f32, color, label }
+        })
+        .collect()
+}
+
+/// Start, middle and end labels for a series sampled at `timestamps`
+/// (epoch seconds, oldest first), in the time zone chosen in settings.
+/// Labels carry the date when the series spans more than a day.
+pub fn time_labels(timestamps: &[f64]) -> Vec<(f32, String)> {
+    let n = timestamps.len();
+    if n < 2 || timestamps[n - 1] <= timestamps[0] {
+        return Vec::new();
+    }
+    let with_date = timestamps[n - 1] - timestamps[0] > 86400.0;
+    [0, (n - 1) / 2, n - 1]
+        .into_iter()
+        .map(|i| (i as f32 / (n - 1) as f32, locale::axis_time(timestamps[i], with_date)))
+        .collect()
+}
+
+/// Exponential moving average in place: each value becomes
+/// `alpha * value + (1 - alpha) * previous`. Smaller `alpha` smooths more;
+/// 1 leaves the data unchanged.
+pub fn smooth_ema(data: &mut [f32], alpha: f32) {
+    let alpha = alpha.clamp(0.0, 1.0);
+    let mut prev = match data.first() {
+        Some(&v) => v,
+        None => return,
+    };
+    for v in data.iter_mut().skip(1) {
+        prev = alpha * *v + (1.0 - alpha) * prev;
+        *v = prev;
     }
 }
 
@@ -351,3 +723,149 @@ fn nice_tick_step(range: f32, max_ticks: usize) -> f32 {
     let nice = if norm <= 1.0 { 1.0 } else if norm <= 2.0 { 2.0 } else if norm <= 5.0 { 5.0 } else { 10.0 };
     (nice * mag).max(f32::EPSILON)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_chart() -> LineChart {
+        LineChart {
+            series: vec![("CPU".into(), Color::from_rgb(0.2, 0.6, 1.0), vec![10.0, 40.0, 25.0, 80.0])],
+            y_min: 0.0,
+            y_max: 100.0,
+            title: "CPU".into(),
+            filled: true,
+            stacked: false,
+            unit: "%".into(),
+            colors: ChartColors {
+                bg: Color::BLACK,
+                border: Color::WHITE,
+                grid: Color::from_rgb(0.3, 0.3, 0.3),
+                label: Color::WHITE,
+                text: Color::WHITE,
+            },
+            show_avg: true,
+            x_labels: Vec::new(),
+            bands: Vec::new(),
+            markers: Vec::new(),
+            right_axis: None,
+        }
+    }
+
+    #[test]
+    fn test_time_markers() {
+        let ts = [100.0, 110.0, 120.0, 130.0, 140.0];
+        let events = [
+            (104.0, Color::WHITE, "near first".to_string()),
+            (126.0, Color::WHITE, "near fourth".to_string()),
+            (140.0, Color::WHITE, "last".to_string()),
+            (99.0, Color::WHITE, "before".to_string()),
+            (141.0, Color::WHITE, "after".to_string()),
+        ];
+        let markers = time_markers(&ts, events);
+        let placed: Vec<(f32, &str)> = markers.iter().map(|m| (m.pos, m.label.as_str())).collect();
+        assert_eq!(placed, vec![(0.0, "near first"), (0.75, "near fourth"), (1.0, "last")]);
+        assert!(time_markers(&ts[..1], [(100.0, Color::WHITE, String::new())]).is_empty());
+    }
+
+    #[test]
+    fn test_smooth_ema() {
+        let mut data = vec![0.0, 100.0, 100.0, 0.0];
+        smooth_ema(&mut data, 0.5);
+        assert_eq!(data, vec![0.0, 50.0, 75.0, 37.5]);
+
+        let mut raw = vec![3.0, 9.0];
+        smooth_ema(&mut raw, 1.0);
+        assert_eq!(raw, vec![3.0, 9.0]);
+        smooth_ema(&mut [], 0.3);
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let mut chart = sample_chart();
+        chart.unit = " KB/s".into();
+        chart.series = vec![
+            ("\u{f063} rx".into(), Color::WHITE, vec![1.0, 2.5]),
+            ("\u{f062} tx".into(), Color::WHITE, vec![0.25]),
+        ];
+        assert_eq!(chart.to_csv(), "sample,rx (KB/s),tx (KB/s)\n0,1.00,0.25\n1,2.50,\n");
+    }
+
+    #[test]
+    fn test_right_axis() {
+        let mut chart = sample_chart();
+        chart.unit = "W".into();
+        chart.series = vec![("Power".into(), Color::WHITE, vec![12.0, 30.0])];
+        chart.right_axis = Some(RightAxis {
+            series: vec![("CPU".into(), Color::WHITE, vec![5.0, 90.0])],
+            y_min: 0.0,
+            y_max: 100.0,
+            unit: "%".into(),
+        });
+        assert_eq!(chart.to_csv(), "sample,Power (W),CPU (%)\n0,12.00,5.00\n1,30.00,90.00\n");
+        assert_ne!(chart.cache_key(), sample_chart().cache_key());
+        // The right labels take the same room as the left ones
+        let (w, _) = chart.plot_size(Size::new(320.0, 120.0)).unwrap();
+        assert_eq!(w, 320.0 - 2.0 * PAD_LEFT);
+    }
+
+    #[test]
+    fn test_stacked() {
+        let mut chart = sample_chart();
+        chart.series.push(("Sys".into(), Color::WHITE, vec![5.0, 10.0, 5.0, 10.0]));
+        assert_eq!(chart.drawn()[1], vec![5.0, 10.0, 5.0, 10.0]);
+        chart.stacked = true;
+        assert_eq!(chart.drawn(), vec![vec![10.0, 40.0, 25.0, 80.0], vec![15.0, 50.0, 30.0, 90.0]]);
+        // Exported values stay each series' own
+        assert!(chart.to_csv().ends_with("3,80.00,10.00\n"));
+    }
+
+    #[test]
+    fn test_cache_key_tracks_data() {
+        let chart = sample_chart();
+        assert_eq!(chart.cache_key(), sample_chart().cache_key());
+
+        let mut changed = sample_chart();
+        changed.series[0].2.push(12.0);
+        assert_ne!(chart.cache_key(), changed.cache_key());
+
+        let mut rescaled = sample_chart();
+        rescaled.y_max = 50.0;
+        assert_ne!(chart.cache_key(), rescaled.cache_key());
+
+        let mut labelled = sample_chart();
+        labelled.x_labels = vec![(0.0, "14:05".into())];
+        assert_ne!(chart.cache_key(), labelled.cache_key());
+
+        let mut banded = sample_chart();
+        banded.bands = vec![(Color::WHITE, vec![5.0, 30.0, 20.0, 70.0], vec![15.0, 100.0, 30.0, 90.0])];
+        assert_ne!(chart.cache_key(), banded.cache_key());
+
+        let mut marked = sample_chart();
+        marked.markers = vec![ChartMarker { pos: 0.5, color: Color::WHITE, label: "CPU spike".into() }];
+        assert_ne!(chart.cache_key(), marked.cache_key());
+        assert!(labelled.plot_size(Size::new(320.0, 120.0)).unwrap().1 < chart.plot_size(Size::new(320.0, 120.0)).unwrap().1);
+    }
+
+    #[test]
+    fn test_time_labels() {
+        assert!(time_labels(&[1000.0]).is_empty());
+        let labels = time_labels(&[0.0, 600.0, 1200.0, 1800.0, 2400.0]);
+        let positions: Vec<f32> = labels.iter().map(|(pos, _)| *pos).collect();
+        assert_eq!(positions, [0.0, 0.5, 1.0]);
+        assert!(labels.iter().all(|(_, label)| !label.is_empty() && !label.contains('-')));
+        assert!(time_labels(&[0.0, 2.0 * 86400.0])[0].1.contains('-'));
+    }
+
+    #[test]
+    fn test_save_png() {
+        let chart = sample_chart();
+        let path = std::env::temp_dir().join(format!("digger_chart_{}.png", std::process::id()));
+        chart.save_png(320, 120, &path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert!(bytes.starts_with(b"\x89PNG"));
+        // IHDR width/height (big-endian) follow the 16-byte signature + chunk header.
+        assert_eq!(&bytes[16..24], &[0, 0, 1, 64, 0, 0, 0, 120]);
+    }
+}
@@ -0,0 +1,129 @@
+//! CPU time split by kind: user, system, I/O wait, interrupts and steal.
+//!
+//! Utilization alone doesn't tell a machine that computes from one that
+//! waits on its disks. Linux reads the aggregate `cpu` line of `/proc/stat`;
+//! Windows asks `GetSystemTimes`, which only tells user from kernel time, so
+//! I/O wait, interrupts and steal stay at zero there. macOS has no reading.
+
+/// Shares of all CPU time since the previous sample, 0–100 each; the rest
+/// was idle.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CpuTimes {
+    /// Including niced processes.
+    pub user: f32,
+    pub system: f32,
+    /// Idle while a disk request was outstanding.
+    pub iowait: f32,
+    /// Hard and soft interrupts.
+    pub irq: f32,
+    /// Taken by the hypervisor for other guests.
+    pub steal: f32,
+}
+
+/// Cumulative counters, in whatever unit the platform counts in.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Counters {
+    user: u64,
+    system: u64,
+    iowait: u64,
+    irq: u64,
+    steal: u64,
+    total: u64,
+}
+
+/// Turns successive counter readings into shares. The first call after
+/// start returns `None`, as there is nothing to compare against yet.
+#[derive(Default)]
+pub struct CpuTimeSampler {
+    last: Option<Counters>,
+}
+
+impl CpuTimeSampler {
+    pub fn sample(&mut self) -> Option<CpuTimes> {
+        let now = read_counters()?;
+        shares(self.last.replace(now)?, now)
+    }
+}
+
+fn shares(prev: Counters, now: Counters) -> Option<CpuTimes> {
+    let total = now.total.checked_sub(prev.total).filter(|&t| t > 0)? as f32;
+    let pct = |a: u64, b: u64| a.saturating_sub(b) as f32 / total * 100.0;
+    Some(CpuTimes {
+        user: pct(now.user, prev.user),
+        system: pct(now.system, prev.system),
+        iowait: pct(now.iowait, prev.iowait),
+        irq: pct(now.irq, prev.irq),
+        steal: pct(now.steal, prev.steal),
+    })
+}
+
+#[cfg(target_os = "linux")]
+fn read_counters() -> Option<Counters> {
+    parse_proc_stat(&std::fs::read_to_string("/proc/stat").ok()?)
+}
+
+/// The aggregate `cpu` line: user nice system idle iowait irq softirq steal,
+/// then guest time, which is already counted in user.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_proc_stat(stat: &str) -> Option<Counters> {
+    let line = stat.lines().find(|l| l.starts_with("cpu "))?;
+    let v: Vec<u64> = line.split_whitespace().skip(1).take(8).filter_map(|f| f.parse().ok()).collect();
+    if v.len() < 4 {
+        return None;
+    }
+    // Kernels before 2.6.11 stop after softirq
+    let field = |i: usize| v.get(i).copied().unwrap_or(0);
+    Some(Counters {
+        user: field(0) + field(1),
+        system: field(2),
+        iowait: field(4),
+        irq: field(5) + field(6),
+        steal: field(7),
+        total: v.iter().sum(),
+    })
+}
+
+#[cfg(target_os = "windows")]
+fn read_counters() -> Option<Counters> {
+    use windows_sys::Win32::Foundation::FILETIME;
+    use windows_sys::Win32::System::Threading::GetSystemTimes;
+
+    let mut idle = FILETIME { dwLowDateTime: 0, dwHighDateTime: 0 };
+    let (mut kernel, mut user) = (idle, idle);
+    // SAFETY: all three pointers are to live, writable FILETIMEs
+    if unsafe { GetSystemTimes(&mut idle, &mut kernel, &mut user) } == 0 {
+        return None;
+    }
+    let ticks = |t: FILETIME| (u64::from(t.dwHighDateTime) << 32) | u64::from(t.dwLowDateTime);
+    let (idle, kernel, user) = (ticks(idle), ticks(kernel), ticks(user));
+    // Kernel time includes the idle time
+    Some(Counters { user, system: kernel.saturating_sub(idle), total: kernel + user, ..Default::default() })
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "windows")))]
+fn read_counters() -> Option<Counters> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_proc_stat() {
+        let stat = "cpu  100 20 50 800 10 5 5 10 0 0\ncpu0 50 10 25 400 5 2 3 5 0 0\nintr 12345\n";
+        let c = parse_proc_stat(stat).unwrap();
+        assert_eq!(c, Counters { user: 120, system: 50, iowait: 10, irq: 10, steal: 10, total: 1000 });
+        assert_eq!(parse_proc_stat("intr 1\n"), None);
+    }
+
+    #[test]
+    fn test_shares() {
+        let prev = Counters { user: 100, system: 50, iowait: 10, irq: 10, steal: 0, total: 1000 };
+        let now = Counters { user: 150, system: 60, iowait: 30, irq: 10, steal: 10, total: 1200 };
+        let s = shares(prev, now).unwrap();
+        assert_eq!((s.user, s.system, s.iowait, s.irq, s.steal), (25.0, 5.0, 10.0, 0.0, 5.0));
+        // No time passed
+        assert_eq!(shares(now, now), None);
+    }
+}
@@ -0,0 +1,119 @@
+//! Panic reports.
+//!
+//! Release builds on Windows have no console, so a panic used to close the
+//! window without a trace. The panic hook writes the message, location,
+//! backtrace, version and OS to a report file in the data directory and
+//! leaves a marker pointing at it; the next start picks the marker up and
+//! offers to open the report.
+
+use std::backtrace::Backtrace;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Reports kept on disk; older ones are deleted when a new one is written.
+const MAX_REPORTS: usize = 10;
+const PENDING_MARKER: &str = "pending";
+
+fn crash_dir() -> PathBuf {
+    dirs::data_local_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("digger")
+        .join("crashes")
+}
+
+/// Write a report for every panic, after the default hook has printed it.
+/// Panics on background threads are recorded too.
+pub fn install() {
+    let dir = crash_dir();
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        default_hook(info);
+        let payload = info.payload();
+        let message = payload
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+            .unwrap_or("(non-string panic payload)");
+        let location = info.location().map(|l| l.to_string()).unwrap_or_default();
+        let report = format_report(message, &location, &Backtrace::force_capture().to_string());
+        match write_report(&dir, &report) {
+            Ok(path) => eprintln!("[digger] Crash report written to {}", path.display()),
+            Err(e) => eprintln!("[digger] Failed to write crash report: {e}"),
+        }
+    }));
+}
+
+fn format_report(message: &str, location: &str, backtrace: &str) -> String {
+    let thread = std::thread::current();
+    format!(
+        "Digger {version} panicked\n\
+         time: {time}\n\
+         os: {os} {arch} ({long_os}, kernel {kernel})\n\
+         thread: {thread}\n\
+         location: {location}\n\
+         message: {message}\n\
+         \n\
+         backtrace:\n{backtrace}\n",
+        version = env!("CARGO_PKG_VERSION"),
+        time = chrono::Local::now().to_rfc3339(),
+        os = std::env::consts::OS,
+        arch = std::env::consts::ARCH,
+        long_os = sysinfo::System::long_os_version().unwrap_or_default(),
+        kernel = sysinfo::System::kernel_version().unwrap_or_default(),
+        thread = thread.name().unwrap_or("unnamed"),
+    )
+}
+
+fn write_report(dir: &Path, report: &str) -> std::io::Result<PathBuf> {
+    fs::create_dir_all(dir)?;
+    let path = dir.join(format!("crash_{}.txt", chrono::Local::now().format("%Y%m%d_%H%M%S")));
+    fs::write(&path, report)?;
+    fs::write(dir.join(PENDING_MARKER), path.to_string_lossy().as_bytes())?;
+
+    // File names sort by time
+    let mut reports: Vec<PathBuf> = fs::read_dir(dir)?
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|e| e == "txt"))
+        .collect();
+    reports.sort();
+    for old in reports.iter().rev().skip(MAX_REPORTS) {
+        let _ = fs::remove_file(old);
+    }
+    Ok(path)
+}
+
+/// Report left by a panic since the last start, if any. The marker is
+/// removed, so each report is only offered once.
+pub fn take_pending() -> Option<PathBuf> {
+    take_pending_in(&crash_dir())
+}
+
+fn take_pending_in(dir: &Path) -> Option<PathBuf> {
+    let marker = dir.join(PENDING_MARKER);
+    let path = PathBuf::from(fs::read_to_string(&marker).ok()?.trim());
+    let _ = fs::remove_file(&marker);
+    path.exists().then_some(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_roundtrip() {
+        let dir = std::env::temp_dir().join(format!("digger_crash_test_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        assert_eq!(take_pending_in(&dir), None);
+
+        let report = format_report("index out of bounds", "src/ui.rs:10:5", "0: main");
+        assert!(report.contains(env!("CARGO_PKG_VERSION")));
+        assert!(report.contains("location: src/ui.rs:10:5"));
+        let path = write_report(&dir, &report).unwrap();
+        assert_eq!(take_pending_in(&dir), Some(path.clone()));
+        // Offered only once
+        assert_eq!(take_pending_in(&dir), None);
+        assert_eq!(fs::read_to_string(&path).unwrap(), report);
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
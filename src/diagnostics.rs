@@ -0,0 +1,201 @@
+//! Diagnostics bundle for bug reports.
+//!
+//! A zip archive of plain-text files (preferences, recent events, collection
+//! timings, platform info, recent history rows) that users can attach to an
+//! issue. The caller assembles the file contents; this module redacts the
+//! preferences and writes the archive.
+
+use std::io::Write;
+
+use flate2::write::DeflateEncoder;
+use flate2::{Compression, Crc};
+use serde_json::Value;
+
+use crate::preferences::Preferences;
+
+/// Preferences as pretty JSON, with the home directory and host name
+/// replaced in every string (log paths, watched names, filters) so the
+/// bundle can be shared publicly.
+pub fn redacted_prefs(prefs: &Preferences, home: Option<&str>, hostname: &str) -> String {
+    let mut value = serde_json::to_value(prefs).unwrap_or(Value::Null);
+    let mut replacements: Vec<(&str, &str)> = Vec::new();
+    if let Some(home) = home.filter(|h| h.len() > 1) {
+        replacements.push((home, "~"));
+    }
+    if !hostname.is_empty() {
+        replacements.push((hostname, "<hostname>"));
+    }
+    redact(&mut value, &replacements);
+    serde_json::to_string_pretty(&value).unwrap_or_default()
+}
+
+fn redact(value: &mut Value, replacements: &[(&str, &str)]) {
+    match value {
+        Value::String(s) => {
+            for (from, to) in replacements {
+                if s.contains(from) {
+                    *s = s.replace(from, to);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(|v| redact(v, replacements)),
+        Value::Object(map) => map.values_mut().for_each(|v| redact(v, replacements)),
+        _ => {}
+    }
+}
+
+/// Central directory record of one archived file.
+struct Entry {
+    name: String,
+    crc: u32,
+    compressed: u32,
+    size: u32,
+    offset: u32,
+}
+
+/// Zip archive (deflate, no ZIP64) of `(name, contents)` pairs, all
+/// stamped with `time`. Fails rather than writing a corrupt archive when a
+/// size or offset doesn't fit the format's 32 bits.
+pub fn zip(files: &[(&str, String)], time: chrono::NaiveDateTime) -> std::io::Result<Vec<u8>> {
+    use chrono::{Datelike, Timelike};
+
+    // MS-DOS date and time; the format can't go before 1980
+    let dos_time = ((time.hour() << 11) | (time.minute() << 5) | (time.second() / 2)) as u16;
+    let dos_date = ((((time.year().max(1980) - 1980) as u32) << 9) | (time.month() << 5) | time.day()) as u16;
+
+    let mut out = Vec::new();
+    let mut entries = Vec::new();
+    for (name, contents) in files {
+        let mut crc = Crc::new();
+        crc.update(contents.as_bytes());
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(contents.as_bytes())?;
+        let data = encoder.finish()?;
+        let entry = Entry {
+            name: name.to_string(),
+            crc: crc.sum(),
+            compressed: fit_u32(data.len(), name)?,
+            size: fit_u32(contents.len(), name)?,
+            offset: fit_u32(out.len(), name)?,
+        };
+
+        out.extend_from_slice(&0x0403_4b50u32.to_le_bytes());
+        out.extend_from_slice(&20u16.to_le_bytes()); // version needed
+        out.extend_from_slice(&0x0800u16.to_le_bytes()); // UTF-8 names
+        out.extend_from_slice(&8u16.to_le_bytes()); // deflate
+        out.extend_from_slice(&dos_time.to_le_bytes());
+        out.extend_from_slice(&dos_date.to_le_bytes());
+        out.extend_from_slice(&entry.crc.to_le_bytes());
+        out.extend_from_slice(&entry.compressed.to_le_bytes());
+        out.extend_from_slice(&entry.size.to_le_bytes());
+        out.extend_from_slice(&(entry.name.len() as u16).to_le_bytes());
+        out.extend_from_slice(&0u16.to_le_bytes()); // extra field
+        out.extend_from_slice(entry.name.as_bytes());
+        out.extend_from_slice(&data);
+        entries.push(entry);
+    }
+
+    let central_start = fit_u32(out.len(), "central directory")?;
+    for entry in &entries {
+        out.extend_from_slice(&0x0201_4b50u32.to_le_bytes());
+        out.extend_from_slice(&20u16.to_le_bytes()); // version made by
+        out.extend_from_slice(&20u16.to_le_bytes()); // version needed
+        out.extend_from_slice(&0x0800u16.to_le_bytes());
+        out.extend_from_slice(&8u16.to_le_bytes());
+        out.extend_from_slice(&dos_time.to_le_bytes());
+        out.extend_from_slice(&dos_date.to_le_bytes());
+        out.extend_from_slice(&entry.crc.to_le_bytes());
+        out.extend_from_slice(&entry.compressed.to_le_bytes());
+        out.extend_from_slice(&entry.size.to_le_bytes());
+        out.extend_from_slice(&(entry.name.len() as u16).to_le_bytes());
+        out.extend_from_slice(&[0; 12]); // extra, comment, disk, internal attrs, external attrs
+        out.extend_from_slice(&entry.offset.to_le_bytes());
+        out.extend_from_slice(entry.name.as_bytes());
+    }
+    let central_size = fit_u32(out.len(), "central directory")? - central_start;
+    let count = u16::try_from(entries.len()).map_err(|_| too_large("file list"))?;
+
+    out.extend_from_slice(&0x0605_4b50u32.to_le_bytes());
+    out.extend_from_slice(&[0; 4]); // disk numbers
+    out.extend_from_slice(&count.to_le_bytes());
+    out.extend_from_slice(&count.to_le_bytes());
+    out.extend_from_slice(&central_size.to_le_bytes());
+    out.extend_from_slice(&central_start.to_le_bytes());
+    out.extend_from_slice(&0u16.to_le_bytes()); // comment
+    Ok(out)
+}
+
+/// `n` as a zip size or offset field.
+fn fit_u32(n: usize, what: &str) -> std::io::Result<u32> {
+    u32::try_from(n).map_err(|_| too_large(what))
+}
+
+fn too_large(what: &str) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, format!("{what} is too large for a zip archive without ZIP64"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    fn u16_at(b: &[u8], i: usize) -> usize {
+        u16::from_le_bytes([b[i], b[i + 1]]) as usize
+    }
+
+    fn u32_at(b: &[u8], i: usize) -> usize {
+        u32::from_le_bytes([b[i], b[i + 1], b[i + 2], b[i + 3]]) as usize
+    }
+
+    #[test]
+    fn test_zip_roundtrip() {
+        let files = [("a.txt", "hello ".repeat(100)), ("dir/b.json", "{}".to_string())];
+        let time = chrono::NaiveDate::from_ymd_opt(2024, 5, 17).unwrap().and_hms_opt(13, 45, 30).unwrap();
+        let zip = zip(&files, time).unwrap();
+
+        // End of central directory: entry count and directory offset
+        let eocd = zip.len() - 22;
+        assert_eq!(u32_at(&zip, eocd), 0x0605_4b50);
+        assert_eq!(u16_at(&zip, eocd + 10), 2);
+        let mut at = u32_at(&zip, eocd + 16);
+        for (name, contents) in &files {
+            assert_eq!(u32_at(&zip, at), 0x0201_4b50);
+            let name_len = u16_at(&zip, at + 28);
+            assert_eq!(&zip[at + 46..at + 46 + name_len], name.as_bytes());
+            let local = u32_at(&zip, at + 42);
+            let compressed = u32_at(&zip, local + 18);
+            let data_start = local + 30 + u16_at(&zip, local + 26);
+            let mut inflated = String::new();
+            flate2::read::DeflateDecoder::new(&zip[data_start..data_start + compressed])
+                .read_to_string(&mut inflated)
+                .unwrap();
+            assert_eq!(&inflated, contents);
+            let mut crc = Crc::new();
+            crc.update(inflated.as_bytes());
+            assert_eq!(crc.sum() as usize, u32_at(&zip, local + 14));
+            at += 46 + name_len;
+        }
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn test_oversized_entries_are_rejected() {
+        assert_eq!(fit_u32(u32::MAX as usize, "a").unwrap(), u32::MAX);
+        let err = fit_u32(u32::MAX as usize + 1, "history.csv").unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("history.csv"));
+    }
+
+    #[test]
+    fn test_redacted_prefs() {
+        let prefs = Preferences {
+            log_tail_path: "/home/alice/logs/app.log".into(),
+            watched_processes: vec!["backup-workstation7".into()],
+            ..Preferences::default()
+        };
+        let json = redacted_prefs(&prefs, Some("/home/alice"), "workstation7");
+        assert!(json.contains("\"~/logs/app.log\""));
+        assert!(json.contains("backup-<hostname>"));
+        assert!(!json.contains("alice"));
+    }
+}
@@ -0,0 +1,207 @@
+//! On-demand "what is filling my disk" scanner.
+//!
+//! Walks the top-level directories of a mount point on a background thread
+//! and reports their on-disk size (du-style: allocated blocks on Unix) as it
+//! goes. The walk never crosses into other filesystems and never follows
+//! symlinks. The UI polls `DiskScan::progress()` on each frame.
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Size of one directory directly below the scanned mount.
+#[derive(Clone, Debug)]
+pub struct DirUsage {
+    pub path: PathBuf,
+    pub bytes: u64,
+}
+
+/// Snapshot of a running or finished scan.
+#[derive(Clone, Debug, Default)]
+pub struct ScanProgress {
+    pub root: PathBuf,
+    pub files_scanned: u64,
+    pub bytes_scanned: u64,
+    /// Finished top-level entries, largest first.
+    pub entries: Vec<DirUsage>,
+    pub done: bool,
+}
+
+/// Handle to a background scan. Dropping it cancels the walk.
+pub struct DiskScan {
+    shared: Arc<Mutex<ScanProgress>>,
+    cancel: Arc<AtomicBool>,
+}
+
+/// Publish progress to the UI every N files to keep lock traffic low.
+const PROGRESS_BATCH: u64 = 512;
+
+impl DiskScan {
+    /// Start scanning `root` on a new thread.
+    pub fn start(root: impl Into<PathBuf>) -> Self {
+        let root = root.into();
+        let shared = Arc::new(Mutex::new(ScanProgress { root: root.clone(), ..Default::default() }));
+        let cancel = Arc::new(AtomicBool::new(false));
+
+        let thread_shared = Arc::clone(&shared);
+        let thread_cancel = Arc::clone(&cancel);
+        std::thread::spawn(move || {
+            scan_root(&root, &thread_shared, &thread_cancel);
+            if let Ok(mut progress) = thread_shared.lock() {
+                progress.done = true;
+            }
+        });
+
+        Self { shared, cancel }
+    }
+
+    pub fn progress(&self) -> ScanProgress {
+        self.shared.lock().map(|p| p.clone()).unwrap_or_default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+}
+
+impl Drop for DiskScan {
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
+
+fn scan_root(root: &Path, shared: &Mutex<ScanProgress>, cancel: &AtomicBool) {
+    let Ok(root_meta) = std::fs::symlink_metadata(root) else { return };
+    let root_dev = device_id(&root_meta);
+    let Ok(children) = std::fs::read_dir(root) else { return };
+
+    // Files directly inside the mount are grouped under the mount itself.
+    let mut loose_bytes = 0u64;
+    let mut pending = (0u64, 0u64);
+    for child in children.flatten() {
+        if cancel.load(Ordering::Relaxed) {
+            return;
+        }
+        let Ok(meta) = child.metadata() else { continue };
+        if meta.is_dir() {
+            if device_id(&meta) != root_dev {
+                continue;
+            }
+            let bytes = dir_size(&child.path(), root_dev, shared, cancel, &mut pending);
+            if let Ok(mut progress) = shared.lock() {
+                let pos = progress.entries.partition_point(|e| e.bytes >= bytes);
+                progress.entries.insert(pos, DirUsage { path: child.path(), bytes });
+            }
+        } else {
+            let bytes = disk_usage(&meta);
+            loose_bytes += bytes;
+            pending.0 += 1;
+            pending.1 += bytes;
+        }
+    }
+    if let Ok(mut progress) = shared.lock() {
+        progress.files_scanned += pending.0;
+        progress.bytes_scanned += pending.1;
+        if loose_bytes > 0 {
+            let pos = progress.entries.partition_point(|e| e.bytes >= loose_bytes);
+            progress.entries.insert(pos, DirUsage { path: root.to_path_buf(), bytes: loose_bytes });
+        }
+    }
+}
+
+/// Recursive size of `dir`, staying on device `dev`. `pending` holds
+/// (files, bytes) not yet published to `shared`.
+fn dir_size(
+    dir: &Path,
+    dev: u64,
+    shared: &Mutex<ScanProgress>,
+    cancel: &AtomicBool,
+    pending: &mut (u64, u64),
+) -> u64 {
+    let mut total = 0u64;
+    let mut stack = vec![dir.to_path_buf()];
+    while let Some(path) = stack.pop() {
+        if cancel.load(Ordering::Relaxed) {
+            break;
+        }
+        let Ok(entries) = std::fs::read_dir(&path) else { continue };
+        for entry in entries.flatten() {
+            // DirEntry::metadata does not follow symlinks
+            let Ok(meta) = entry.metadata() else { continue };
+            if meta.is_dir() {
+                if device_id(&meta) == dev {
+                    stack.push(entry.path());
+                }
+                continue;
+            }
+            let bytes = disk_usage(&meta);
+            total += bytes;
+            pending.0 += 1;
+            pending.1 += bytes;
+            if pending.0 >= PROGRESS_BATCH {
+                if let Ok(mut progress) = shared.lock() {
+                    progress.files_scanned += pending.0;
+                    progress.bytes_scanned += pending.1;
+                }
+                *pending = (0, 0);
+            }
+        }
+    }
+    total
+}
+
+#[cfg(unix)]
+fn device_id(meta: &std::fs::Metadata) -> u64 {
+    use std::os::unix::fs::MetadataExt;
+    meta.dev()
+}
+
+#[cfg(not(unix))]
+fn device_id(_meta: &std::fs::Metadata) -> u64 {
+    0
+}
+
+/// Allocated size (du-style) where available, apparent size otherwise.
+#[cfg(unix)]
+fn disk_usage(meta: &std::fs::Metadata) -> u64 {
+    use std::os::unix::fs::MetadataExt;
+    meta.blocks() * 512
+}
+
+#[cfg(not(unix))]
+fn disk_usage(meta: &std::fs::Metadata) -> u64 {
+    meta.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scan_orders_directories_by_size() {
+        let root = std::env::temp_dir().join(format!("digger_scan_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("small")).unwrap();
+        std::fs::create_dir_all(root.join("big/nested")).unwrap();
+        std::fs::write(root.join("small/a.bin"), vec![1u8; 4096]).unwrap();
+        std::fs::write(root.join("big/nested/b.bin"), vec![1u8; 256 * 1024]).unwrap();
+        std::fs::write(root.join("loose.bin"), vec![1u8; 4096]).unwrap();
+
+        let scan = DiskScan::start(&root);
+        let mut progress = scan.progress();
+        for _ in 0..500 {
+            if progress.done {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+            progress = scan.progress();
+        }
+        let _ = std::fs::remove_dir_all(&root);
+
+        assert!(progress.done);
+        assert_eq!(progress.files_scanned, 3);
+        assert_eq!(progress.entries.len(), 3);
+        assert_eq!(progress.entries[0].path, root.join("big"));
+        assert!(progress.entries.windows(2).all(|w| w[0].bytes >= w[1].bytes));
+    }
+}
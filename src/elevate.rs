@@ -0,0 +1,167 @@
+//! Stopping another user's process with administrator rights.
+//!
+//! When a kill fails for lack of permission, the UI explains what will run
+//! and only then hands a single `kill` to the platform's elevation prompt:
+//! pkexec (polkit) on Linux, the administrator password dialog on macOS and
+//! a UAC prompt for `taskkill` on Windows. Digger itself never runs
+//! elevated. The prompt blocks until answered, so it runs on a background
+//! thread that the UI polls.
+
+use std::process::{Command, Stdio};
+use std::sync::{Arc, Mutex};
+
+/// Name of the prompt shown to the user, or None when this platform has no
+/// way to ask (no pkexec on Linux).
+pub fn prompt_name() -> Option<&'static str> {
+    #[cfg(target_os = "linux")]
+    {
+        let path = std::env::var_os("PATH")?;
+        std::env::split_paths(&path).any(|dir| dir.join("pkexec").is_file()).then_some("pkexec")
+    }
+    #[cfg(target_os = "macos")]
+    {
+        Some("macOS")
+    }
+    #[cfg(target_os = "windows")]
+    {
+        Some("UAC")
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+    {
+        None
+    }
+}
+
+/// The command run with administrator rights, as shown in the consent text.
+pub fn kill_command_line(pid: u32) -> String {
+    if cfg!(windows) { format!("taskkill /PID {pid} /F") } else { format!("kill -TERM {pid}") }
+}
+
+/// Name and start time of a process, to tell it apart from a later one
+/// that was given the same PID.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProcessIdentity {
+    pub name: String,
+    /// Seconds since the epoch.
+    pub start_time: u64,
+}
+
+impl ProcessIdentity {
+    /// The process running as `pid` now, or None when there is none.
+    pub fn of(pid: u32) -> Option<Self> {
+        use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+
+        let pid = Pid::from_u32(pid);
+        let mut sys = System::new();
+        sys.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), true, ProcessRefreshKind::new());
+        sys.process(pid).map(|p| Self { name: p.name().to_string_lossy().into_owned(), start_time: p.start_time() })
+    }
+}
+
+/// An elevated kill waiting on the prompt; the UI polls `result()`.
+pub struct ElevatedKill {
+    pub pid: u32,
+    shared: Arc<Mutex<Option<Result<(), String>>>>,
+}
+
+impl ElevatedKill {
+    pub fn start(pid: u32) -> Self {
+        let shared = Arc::new(Mutex::new(None));
+        let thread_shared = Arc::clone(&shared);
+        std::thread::spawn(move || {
+            let result = match kill_command(pid).stdin(Stdio::null()).stdout(Stdio::null()).output() {
+                Ok(output) if output.status.success() => Ok(()),
+                Ok(output) => Err(describe_failure(output.status.code(), &String::from_utf8_lossy(&output.stderr))),
+                Err(e) => Err(e.to_string()),
+            };
+            if let Ok(mut slot) = thread_shared.lock() {
+                *slot = Some(result);
+            }
+        });
+        Self { pid, shared }
+    }
+
+    /// The outcome once the prompt was answered and the command finished.
+    pub fn result(&self) -> Option<Result<(), String>> {
+        self.shared.lock().ok().and_then(|slot| slot.clone())
+    }
+}
+
+fn kill_command(pid: u32) -> Command {
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+
+        // Start-Process -Verb RunAs raises the UAC prompt and fails when it
+        // is declined; -Wait surfaces taskkill's exit code
+        let script = format!(
+            "$p = Start-Process -FilePath taskkill -ArgumentList '/PID','{pid}','/F' -Verb RunAs -WindowStyle Hidden -Wait -PassThru; exit $p.ExitCode"
+        );
+        let mut cmd = Command::new("powershell");
+        cmd.args(["-NoProfile", "-NonInteractive", "-Command", &script]);
+        // CREATE_NO_WINDOW: don't flash a console
+        cmd.creation_flags(0x0800_0000);
+        cmd
+    }
+    #[cfg(target_os = "macos")]
+    {
+        let mut cmd = Command::new("osascript");
+        cmd.args(["-e", &format!("do shell script \"kill -TERM {pid}\" with administrator privileges")]);
+        cmd
+    }
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    {
+        let mut cmd = Command::new("pkexec");
+        cmd.args(["kill", "-TERM", &pid.to_string()]);
+        cmd
+    }
+}
+
+/// Readable reason for a failed elevated command. pkexec exits with 126
+/// when the prompt was dismissed and 127 when polkit refused.
+fn describe_failure(code: Option<i32>, stderr: &str) -> String {
+    match (cfg!(target_os = "linux"), code) {
+        (true, Some(126)) => "the authentication prompt was dismissed".to_string(),
+        (true, Some(127)) => "not authorized by polkit".to_string(),
+        _ => {
+            let stderr = stderr.trim();
+            if stderr.is_empty() {
+                code.map_or_else(|| "terminated by a signal".to_string(), |c| format!("exit code {c}"))
+            } else {
+                stderr.lines().last().unwrap_or(stderr).to_string()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_kill_command() {
+        let cmd = kill_command(4242);
+        assert_eq!(cmd.get_program(), "pkexec");
+        let args: Vec<_> = cmd.get_args().collect();
+        assert_eq!(args, ["kill", "-TERM", "4242"]);
+        assert_eq!(kill_command_line(4242), "kill -TERM 4242");
+    }
+
+    #[test]
+    fn test_identity_of_own_process() {
+        let me = ProcessIdentity::of(std::process::id()).expect("own process");
+        assert!(!me.name.is_empty());
+        assert_eq!(ProcessIdentity::of(std::process::id()), Some(me));
+    }
+
+    #[test]
+    fn test_describe_failure() {
+        if cfg!(target_os = "linux") {
+            assert_eq!(describe_failure(Some(126), ""), "the authentication prompt was dismissed");
+        }
+        assert_eq!(describe_failure(Some(1), "kill: (4242): No such process\n"), "kill: (4242): No such process");
+        assert_eq!(describe_failure(Some(3), ""), "exit code 3");
+        assert_eq!(describe_failure(None, ""), "terminated by a signal");
+    }
+}
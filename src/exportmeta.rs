@@ -0,0 +1,152 @@
+//! Self-describing exports.
+//!
+//! JSON exports wrap their rows in `{"meta": …, "rows": […]}`; CSV exports
+//! can get a `<file>.schema.json` sidecar with the same block. The block
+//! names the export kind, Digger version, host, time zone and, for every
+//! column, its unit and meaning, so scripts don't have to guess.
+
+use serde_json::{json, Value};
+
+use crate::locale;
+use crate::metrics::SystemInfo;
+
+/// Bumped when columns are renamed or change meaning.
+const SCHEMA_VERSION: u32 = 1;
+
+/// One exported column. CSV headers and JSON keys differ for some columns.
+pub struct Column {
+    pub csv: &'static str,
+    pub json: &'static str,
+    pub unit: &'static str,
+    pub description: &'static str,
+}
+
+const fn col(csv: &'static str, json: &'static str, unit: &'static str, description: &'static str) -> Column {
+    Column { csv, json, unit, description }
+}
+
+pub const HISTORY_COLUMNS: &[Column] = &[
+    col("timestamp", "timestamp", "s", "Sample time as seconds since the Unix epoch (UTC)"),
+    col("time", "time", "", "Sample time as RFC 3339, in UTC or local time as set in Digger"),
+    col("cpu_percent", "cpu", "%", "CPU usage across all cores, 0-100"),
+    col("mem_used_bytes", "mem_used", "B", "Memory in use"),
+    col("mem_total_bytes", "mem_total", "B", "Installed memory"),
+    col("net_rx_bytes", "net_rx", "B", "Bytes received on all interfaces since the previous sample"),
+    col("net_tx_bytes", "net_tx", "B", "Bytes sent on all interfaces since the previous sample"),
+    col("power_watts", "power", "W", "System power draw; empty (null) when unavailable"),
+];
+
+pub const PROCESS_COLUMNS: &[Column] = &[
+    col("group", "group", "", "Process table group (apps, background, system); only when grouped"),
+    col("pid", "pid", "", "Process ID"),
+    col("ppid", "ppid", "", "Parent process ID; empty (null) when unknown"),
+    col("name", "name", "", "Process name"),
+    col("command", "command", "", "Command line (an argument array in JSON); only when its column is shown"),
+    col("cpu_percent", "cpu", "%", "CPU usage as a share of the whole machine, 0-100"),
+    col("mem_bytes", "mem_bytes", "B", "Resident memory"),
+    col("mem_percent", "mem_percent", "%", "Resident memory as a share of installed memory"),
+    col("disk_bytes", "disk_bytes", "B", "Bytes read and written during the refresh interval before the export"),
+    col("status", "status", "", "State letter: R running, S sleeping, D disk wait, Z zombie, T stopped, I idle"),
+    col("threads", "threads", "", "Thread count"),
+];
+
+/// What an export contains, for the metadata block.
+pub struct ExportMeta<'a> {
+    /// `history` or `processes`.
+    pub kind: &'static str,
+    pub sys_info: &'a SystemInfo,
+    pub generated_at: chrono::DateTime<chrono::FixedOffset>,
+    /// Covered time range in epoch seconds, for history exports.
+    pub range: Option<(f64, f64)>,
+}
+
+impl ExportMeta<'_> {
+    /// The metadata block, listing the columns included in the file by
+    /// their CSV or JSON names.
+    pub fn to_value(&self, columns: &[&Column], json_names: bool) -> Value {
+        let columns: Vec<Value> = columns
+            .iter()
+            .map(|c| json!({
+                "name": if json_names { c.json } else { c.csv },
+                "unit": c.unit,
+                "description": c.description,
+            }))
+            .collect();
+        let mut meta = json!({
+            "schema_version": SCHEMA_VERSION,
+            "kind": self.kind,
+            "digger_version": env!("CARGO_PKG_VERSION"),
+            "generated_at": self.generated_at.to_rfc3339(),
+            "timezone": {
+                "utc_offset": self.generated_at.format("%:z").to_string(),
+                "name": if locale::utc() { Some(String::from("UTC")) } else { std::env::var("TZ").ok() },
+            },
+            "host": {
+                "hostname": self.sys_info.hostname,
+                "os": format!("{} {}", self.sys_info.os_name, self.sys_info.os_version).trim(),
+                "kernel": self.sys_info.kernel_version,
+            },
+            "columns": columns,
+        });
+        if let Some((from, to)) = self.range {
+            meta["range"] = json!({ "from": from, "to": to });
+        }
+        meta
+    }
+}
+
+/// Wrap an already serialized JSON array of rows with its metadata.
+pub fn wrap_json(meta: &Value, rows: &str) -> String {
+    let meta = serde_json::to_string_pretty(meta).unwrap_or_else(|_| String::from("{}"));
+    format!("{{\n\"meta\": {meta},\n\"rows\": {}\n}}\n", rows.trim_end())
+}
+
+/// Path of the schema sidecar written next to a CSV export.
+pub fn sidecar_path(csv: &std::path::Path) -> std::path::PathBuf {
+    let mut name = csv.file_name().unwrap_or_default().to_os_string();
+    name.push(".schema.json");
+    csv.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn meta(sys_info: &SystemInfo) -> ExportMeta<'_> {
+        ExportMeta {
+            kind: "history",
+            sys_info,
+            generated_at: locale::now(),
+            range: Some((100.0, 200.0)),
+        }
+    }
+
+    #[test]
+    fn test_wrap_json() {
+        let sys_info = SystemInfo {
+            os_name: "Linux".into(),
+            os_version: "6.1".into(),
+            kernel_version: "6.1.0".into(),
+            hostname: "box".into(),
+            hypervisor: None,
+        };
+        let columns: Vec<&Column> = HISTORY_COLUMNS.iter().collect();
+        let wrapped = wrap_json(&meta(&sys_info).to_value(&columns, true), "[\n  {\"cpu\":1.00}\n]\n");
+        let value: Value = serde_json::from_str(&wrapped).unwrap();
+        assert_eq!(value["rows"][0]["cpu"], 1.0);
+        assert_eq!(value["meta"]["kind"], "history");
+        assert_eq!(value["meta"]["host"]["hostname"], "box");
+        assert_eq!(value["meta"]["range"]["to"], 200.0);
+        assert_eq!(value["meta"]["columns"][2]["name"], "cpu");
+        assert_eq!(value["meta"]["columns"][2]["unit"], "%");
+
+        let csv = meta(&sys_info).to_value(&columns, false);
+        assert_eq!(csv["columns"][2]["name"], "cpu_percent");
+    }
+
+    #[test]
+    fn test_sidecar_path() {
+        let path = std::path::Path::new("/tmp/digger_export.csv");
+        assert_eq!(sidecar_path(path), std::path::Path::new("/tmp/digger_export.csv.schema.json"));
+    }
+}
@@ -0,0 +1,114 @@
+//! Font fallback chain for mixed-script text.
+//!
+//! The UI font follows the selected language, but process names, command
+//! lines, log lines and event messages can be written in any script. Text
+//! containing a script the preferred font lacks is drawn with the embedded
+//! (or installed) font that covers it, so a Chinese UI still shows Arabic
+//! process names properly and an English UI shows CJK window titles with
+//! Sarasa instead of whatever the renderer happens to fall back to.
+
+use iced::Font;
+
+use crate::i18n::Language;
+use crate::sysfonts;
+use crate::{DEJAVU_FONT, NERD_FONT_MONO, NOTO_SANS_FONT, SARASA_FONT};
+
+/// Writing systems that need a font other than the Latin default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Script {
+    /// Latin, Greek, Cyrillic, symbols and Nerd Font icons: every embedded
+    /// font covers these.
+    Common,
+    Cjk,
+    Arabic,
+    Devanagari,
+    /// A script only installed system fonts cover, keyed by a language that
+    /// uses it (see `sysfonts`).
+    System(Language),
+}
+
+fn script_of(c: char) -> Script {
+    match c as u32 {
+        0x0590..=0x05FF | 0xFB1D..=0xFB4F => Script::System(Language::He),
+        0x0600..=0x06FF | 0x0750..=0x077F | 0x08A0..=0x08FF | 0xFB50..=0xFDFF | 0xFE70..=0xFEFE => Script::Arabic,
+        0x0900..=0x097F => Script::Devanagari,
+        0x0980..=0x09FF => Script::System(Language::Bn),
+        0x0A00..=0x0A7F => Script::System(Language::Pa),
+        0x0B80..=0x0BFF => Script::System(Language::Ta),
+        0x0C00..=0x0C7F => Script::System(Language::Te),
+        0x0E00..=0x0E7F => Script::System(Language::Th),
+        0x1200..=0x139F => Script::System(Language::Am),
+        // Hangul Jamo, CJK radicals through Hangul syllables, compatibility
+        // ideographs, full-width forms and the supplementary ideograph planes
+        0x1100..=0x11FF | 0x2E80..=0x9FFF | 0xA960..=0xA97F | 0xAC00..=0xD7FF | 0xF900..=0xFAFF
+        | 0xFE30..=0xFE4F | 0xFF00..=0xFFEF | 0x20000..=0x3FFFF => Script::Cjk,
+        _ => Script::Common,
+    }
+}
+
+fn font_for_script(script: Script) -> Option<Font> {
+    match script {
+        Script::Common => None,
+        Script::Cjk => Some(SARASA_FONT),
+        Script::Arabic => Some(DEJAVU_FONT),
+        Script::Devanagari => Some(NOTO_SANS_FONT),
+        Script::System(lang) => sysfonts::family_for(lang).map(Font::with_name),
+    }
+}
+
+/// Returns the best available monospace font for a given language's script.
+pub fn font_for_lang(lang: Language) -> Font {
+    let script = match lang {
+        Language::ZhCn | Language::ZhTw | Language::Ja | Language::Ko => Script::Cjk,
+        Language::Ar | Language::Fa => Script::Arabic,
+        Language::Hi | Language::Mr => Script::Devanagari,
+        _ if sysfonts::needs_system_font(lang) => Script::System(lang),
+        // Latin, Cyrillic → Iosevka
+        _ => Script::Common,
+    };
+    font_for_script(script).unwrap_or(NERD_FONT_MONO)
+}
+
+/// Font to draw `text` with: `preferred` unless the text contains a script
+/// it can't render, in which case the first font of the chain covering that
+/// script. Every font of the chain also covers Latin, so mixed text such as
+/// `chrome — 新标签页` stays in a single font.
+pub fn font_for_text(text: &str, preferred: Font) -> Font {
+    if text.is_ascii() {
+        return preferred;
+    }
+    text.chars()
+        .map(script_of)
+        .filter(|s| *s != Script::Common)
+        .filter_map(font_for_script)
+        .find(|font| *font != preferred)
+        .unwrap_or(preferred)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_script_of() {
+        assert_eq!(script_of('a'), Script::Common);
+        assert_eq!(script_of('Ж'), Script::Common);
+        assert_eq!(script_of('\u{f2db}'), Script::Common);
+        assert_eq!(script_of('新'), Script::Cjk);
+        assert_eq!(script_of('ア'), Script::Cjk);
+        assert_eq!(script_of('한'), Script::Cjk);
+        assert_eq!(script_of('ع'), Script::Arabic);
+        assert_eq!(script_of('ह'), Script::Devanagari);
+    }
+
+    #[test]
+    fn test_font_for_text() {
+        assert_eq!(font_for_text("firefox", SARASA_FONT), SARASA_FONT);
+        assert_eq!(font_for_text("Café — naïve", NERD_FONT_MONO), NERD_FONT_MONO);
+        assert_eq!(font_for_text("chrome 新标签页", NERD_FONT_MONO), SARASA_FONT);
+        // Already covered by the preferred font
+        assert_eq!(font_for_text("微信", SARASA_FONT), SARASA_FONT);
+        assert_eq!(font_for_text("متصفح", SARASA_FONT), DEJAVU_FONT);
+        assert_eq!(font_for_text("कार्य", NERD_FONT_MONO), NOTO_SANS_FONT);
+    }
+}
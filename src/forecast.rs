@@ -0,0 +1,134 @@
+//! Capacity forecasts from history.
+//!
+//! Fits a least-squares line through recent used-bytes samples of a drive or
+//! of swap and extrapolates it to the capacity. Only growing usage with
+//! enough history behind it yields a forecast; a flat or shrinking trend
+//! means "not filling up".
+
+use serde::{Deserialize, Serialize};
+
+use crate::history::UsageSample;
+
+/// History considered for the trend.
+pub const LOOKBACK_SECS: f64 = 86400.0;
+/// Samples must span at least this long before a trend is trusted.
+const MIN_SPAN_SECS: f64 = 3600.0;
+const MIN_SAMPLES: usize = 6;
+/// Forecasts further out than this are not shown.
+const MAX_ETA_SECS: f64 = 365.0 * 86400.0;
+
+/// ETA below which an early-warning event is raised.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Horizon {
+    Day,
+    #[default]
+    ThreeDays,
+    Week,
+}
+
+impl Horizon {
+    pub const ALL: &'static [Horizon] = &[Horizon::Day, Horizon::ThreeDays, Horizon::Week];
+
+    pub fn secs(self) -> f64 {
+        match self {
+            Horizon::Day => 86400.0,
+            Horizon::ThreeDays => 3.0 * 86400.0,
+            Horizon::Week => 7.0 * 86400.0,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Horizon::Day => "1d",
+            Horizon::ThreeDays => "3d",
+            Horizon::Week => "7d",
+        }
+    }
+}
+
+/// Slope (bytes per second) and intercept of the least-squares line through
+/// `(timestamp, used)`, with time measured from the first sample.
+fn linear_fit(samples: &[UsageSample]) -> Option<(f64, f64)> {
+    let t0 = samples.first()?.timestamp;
+    let n = samples.len() as f64;
+    let (mut sx, mut sy, mut sxx, mut sxy) = (0.0, 0.0, 0.0, 0.0);
+    for s in samples {
+        let x = s.timestamp - t0;
+        let y = s.used as f64;
+        sx += x;
+        sy += y;
+        sxx += x * x;
+        sxy += x * y;
+    }
+    let denom = n * sxx - sx * sx;
+    if denom.abs() < f64::EPSILON {
+        return None;
+    }
+    let slope = (n * sxy - sx * sy) / denom;
+    Some((slope, (sy - slope * sx) / n))
+}
+
+/// Seconds from `now` until usage reaches capacity, or `None` when usage is
+/// not growing, there is too little history, or it is more than a year out.
+pub fn time_to_full(samples: &[UsageSample], now: f64) -> Option<f64> {
+    let (first, last) = (samples.first()?, samples.last()?);
+    if samples.len() < MIN_SAMPLES || last.timestamp - first.timestamp < MIN_SPAN_SECS || last.total == 0 {
+        return None;
+    }
+    let (slope, intercept) = linear_fit(samples)?;
+    if slope <= 0.0 {
+        return None;
+    }
+    let full_at = first.timestamp + (last.total as f64 - intercept) / slope;
+    let eta = (full_at - now).max(0.0);
+    (eta <= MAX_ETA_SECS).then_some(eta)
+}
+
+/// Rough human form of an ETA: minutes, hours or days.
+pub fn format_eta(secs: f64) -> String {
+    let hours = secs / 3600.0;
+    if hours >= 48.0 {
+        format!("{:.0}d", hours / 24.0)
+    } else if hours >= 1.0 {
+        format!("{:.0}h", hours)
+    } else {
+        format!("{:.0}m", (secs / 60.0).max(1.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series(start_used: u64, per_hour: i64, hours: usize, total: u64) -> Vec<UsageSample> {
+        (0..=hours)
+            .map(|h| UsageSample {
+                timestamp: h as f64 * 3600.0,
+                used: (start_used as i64 + per_hour * h as i64) as u64,
+                total,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_time_to_full() {
+        // 10 GB free, growing 1 GB/h: full 10 h after the last sample
+        let gb = 1_000_000_000;
+        let s = series(80 * gb, gb as i64, 10, 100 * gb);
+        let now = s.last().unwrap().timestamp;
+        let eta = time_to_full(&s, now).unwrap();
+        assert!((eta - 10.0 * 3600.0).abs() < 1.0, "eta {eta}");
+
+        assert_eq!(time_to_full(&series(80 * gb, 0, 10, 100 * gb), now), None);
+        assert_eq!(time_to_full(&series(80 * gb, -(gb as i64), 10, 100 * gb), now), None);
+        // Too little history
+        assert_eq!(time_to_full(&series(80 * gb, gb as i64, 2, 100 * gb), now), None);
+    }
+
+    #[test]
+    fn test_format_eta() {
+        assert_eq!(format_eta(90.0), "2m");
+        assert_eq!(format_eta(5.0 * 3600.0), "5h");
+        assert_eq!(format_eta(3.2 * 86400.0), "3d");
+    }
+}
@@ -14,6 +14,29 @@ pub struct GaugeColors {
     pub bar_bg: Color,
 }
 
+/// Which way a gauge's value has been heading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Rising,
+    Falling,
+    Steady,
+}
+
+impl Trend {
+    /// Direction from `baseline` to `recent`; changes of no more than
+    /// `dead_band` points count as steady.
+    pub fn between(baseline: f32, recent: f32, dead_band: f32) -> Self {
+        let delta = recent - baseline;
+        if delta > dead_band {
+            Trend::Rising
+        } else if delta < -dead_band {
+            Trend::Falling
+        } else {
+            Trend::Steady
+        }
+    }
+}
+
 /// A radial arc gauge drawn via iced Canvas.
 #[derive(Debug, Clone)]
 pub struct RadialGauge {
@@ -25,6 +48,12 @@ pub struct RadialGauge {
     pub color: Color,
     /// Colors from palette
     pub colors: GaugeColors,
+    /// Threshold drawn as a tick across the track
+    pub target: Option<f32>,
+    /// Lowest and highest value seen, drawn as a faint arc outside the track
+    pub range: Option<(f32, f32)>,
+    /// Arrow next to the value; nothing is drawn while steady
+    pub trend: Option<Trend>,
 }
 
 impl<Message: 'static> canvas::Program<Message> for RadialGauge {
@@ -68,6 +97,30 @@ impl<Message: 'static> canvas::Program<Message> for RadialGauge {
             draw_arc(&mut frame, cx, cy, radius, thickness, start_angle, value_sweep, self.color);
         }
 
+        // Session min/max, just outside the track
+        if let Some((min, max)) = self.range {
+            let from = (min / 100.0).clamp(0.0, 1.0);
+            let to = (max / 100.0).clamp(0.0, 1.0);
+            let faint = Color::from_rgba(self.color.r, self.color.g, self.color.b, 0.35);
+            draw_arc(
+                &mut frame, cx, cy,
+                radius + thickness / 2.0 + 3.0, 2.0,
+                start_angle - total_sweep * from, total_sweep * (to - from).max(0.005),
+                faint,
+            );
+        }
+
+        // Target tick across the track
+        if let Some(target) = self.target {
+            let angle = -(start_angle - total_sweep * (target / 100.0).clamp(0.0, 1.0));
+            let (inner, outer) = (radius - thickness / 2.0 - 2.0, radius + thickness / 2.0 + 2.0);
+            let tick = Path::line(
+                Point::new(cx + inner * angle.cos(), cy + inner * angle.sin()),
+                Point::new(cx + outer * angle.cos(), cy + outer * angle.sin()),
+            );
+            frame.stroke(&tick, Stroke::default().with_color(c.text).with_width(2.0));
+        }
+
         // Center value text
         let val_str = format!("{:.0}%", self.value);
         let mut val_text = Text::from(val_str);
@@ -79,6 +132,23 @@ impl<Message: 'static> canvas::Program<Message> for RadialGauge {
         val_text.vertical_alignment = iced::alignment::Vertical::Center;
         frame.fill_text(val_text);
 
+        // Trend arrow right of the value
+        let arrow = match self.trend {
+            Some(Trend::Rising) => Some(-1.0),
+            Some(Trend::Falling) => Some(1.0),
+            _ => None,
+        };
+        if let Some(dir) = arrow {
+            let size = (radius * 0.12).max(3.0);
+            let (ax, ay) = (cx + radius * 0.62, cy - 6.0);
+            let mut builder = canvas::path::Builder::new();
+            builder.move_to(Point::new(ax, ay + dir * size));
+            builder.line_to(Point::new(ax - size, ay - dir * size * 0.6));
+            builder.line_to(Point::new(ax + size, ay - dir * size * 0.6));
+            builder.close();
+            frame.fill(&builder.build(), c.label);
+        }
+
         // Label below
         let mut label_text = Text::from(self.label.clone());
         label_text.position = Point::new(cx, cy + radius * 0.45);
@@ -193,3 +263,16 @@ impl<Message: 'static> canvas::Program<Message> for Sparkline {
         vec![frame.into_geometry()]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trend_between() {
+        assert_eq!(Trend::between(40.0, 50.0, 3.0), Trend::Rising);
+        assert_eq!(Trend::between(50.0, 40.0, 3.0), Trend::Falling);
+        assert_eq!(Trend::between(40.0, 42.0, 3.0), Trend::Steady);
+        assert_eq!(Trend::between(42.0, 40.0, 3.0), Trend::Steady);
+    }
+}
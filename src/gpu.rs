@@ -6,6 +6,16 @@
 //! 3. Linux: nvidia-smi CLI — fills in gaps for NVIDIA when sysfs is incomplete
 //! 4. Windows: nvidia-smi CLI — full NVIDIA data
 //! 5. Windows: WMI (Win32_VideoController) — all GPUs including integrated
+//! 6. macOS: `ioreg` IOAccelerator entries, with power and temperature from
+//!    `powermetrics` (root only)
+//!
+//! Per-process VRAM comes from NVML, `nvidia-smi --query-compute-apps` and,
+//! on Linux, DRM fdinfo (`/proc/<pid>/fdinfo`) for AMD and Intel. On
+//! Windows the GPU performance counters add per-process memory and engine
+//! utilization for every vendor.
+//!
+//! Both are refreshed on background threads; `collect_gpu_info` only reads
+//! the cached results so the metrics tick never waits on a backend.
 
 #[cfg(target_os = "linux")]
 use std::fs;
@@ -15,7 +25,12 @@ use std::path::Path;
 use std::sync::RwLock;
 #[cfg(target_os = "windows")]
 use std::sync::Mutex;
-use std::time::Instant;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+#[cfg(any(target_os = "linux", target_os = "windows", target_os = "macos"))]
+use crate::metrics::SensorIssue;
+use crate::metrics::SensorStatus;
 
 #[derive(Clone, Debug, Default)]
 pub struct GpuInfo {
@@ -25,15 +40,108 @@ pub struct GpuInfo {
     pub memory_used: u64,
     pub memory_total: u64,
     pub power_watts: f32,
+    /// Fan duty, 0–100%; `None` when the backend doesn't report a fan.
+    pub fan_percent: Option<u32>,
+    /// Current graphics and memory clocks, MHz.
+    pub core_clock_mhz: Option<u32>,
+    pub memory_clock_mhz: Option<u32>,
+}
+
+impl GpuInfo {
+    /// VRAM in use, percent of total; 0 when the total is unknown.
+    pub fn memory_percent(&self) -> f32 {
+        if self.memory_total > 0 {
+            self.memory_used as f32 / self.memory_total as f32 * 100.0
+        } else {
+            0.0
+        }
+    }
+}
+
+/// A process holding GPU memory or running work on a GPU engine.
+#[derive(Clone, Debug, Default)]
+pub struct GpuProcess {
+    pub pid: u32,
+    /// Resolved by the collector from the system process table.
+    pub name: String,
+    pub vram_bytes: u64,
+    /// Utilization of the busiest engine the process uses, 0–100, like
+    /// Task Manager's GPU column. Only known on Windows.
+    pub utilization: Option<f32>,
 }
 
 #[derive(Clone, Debug, Default)]
 pub struct GpuSnapshot {
     pub gpus: Vec<GpuInfo>,
+    pub processes: Vec<GpuProcess>,
+    /// Why each backend found nothing; only filled in when `gpus` is empty.
+    pub status: Vec<SensorStatus>,
+    /// How long the background poll that produced this took.
+    pub poll_time: Duration,
+    /// Time since that poll finished; `None` before the first one.
+    pub age: Option<Duration>,
 }
 
-/// Collect GPU information using the best available backend.
+/// Device polling (sysfs, nvidia-smi, NVML) can take tens of milliseconds,
+/// so it runs on a background thread at its own cadence and the collector
+/// merges the latest cached result into each snapshot.
+static GPU_CACHE: std::sync::Mutex<Option<(Instant, GpuSnapshot)>> = std::sync::Mutex::new(None);
+
+static GPU_REFRESH_RUNNING: AtomicBool = AtomicBool::new(false);
+
+const GPU_TTL_MS: u128 = 1000;
+
+/// Return the latest GPU information, kicking off a refresh when stale.
+/// Empty until the first background refresh completes.
 pub fn collect_gpu_info() -> GpuSnapshot {
+    let (cached, fresh) = match GPU_CACHE.lock() {
+        Ok(guard) => match *guard {
+            Some((ts, ref data)) => (GpuSnapshot { age: Some(ts.elapsed()), ..data.clone() }, ts.elapsed().as_millis() < GPU_TTL_MS),
+            None => (GpuSnapshot::default(), false),
+        },
+        Err(_) => (GpuSnapshot::default(), true),
+    };
+
+    if !fresh && !GPU_REFRESH_RUNNING.swap(true, Ordering::AcqRel) {
+        std::thread::spawn(|| {
+            let started = Instant::now();
+            let mut result = collect_gpu_info_blocking();
+            result.poll_time = started.elapsed();
+            if let Ok(mut guard) = GPU_CACHE.lock() {
+                *guard = Some((Instant::now(), result));
+            }
+            GPU_REFRESH_RUNNING.store(false, Ordering::Release);
+        });
+    }
+
+    cached
+}
+
+/// Restart the age of the cached result, after polling was paused: time
+/// spent off is not the backend hanging. The next call refreshes it.
+pub fn reset_age() {
+    if let Ok(mut guard) = GPU_CACHE.lock() {
+        if let Some((ts, _)) = guard.as_mut() {
+            let now = Instant::now();
+            *ts = now.checked_sub(Duration::from_millis(GPU_TTL_MS as u64)).unwrap_or(now);
+        }
+    }
+}
+
+/// Collect GPU information using the best available backend.
+fn collect_gpu_info_blocking() -> GpuSnapshot {
+    let mut snap = collect_gpu_devices();
+    if !snap.gpus.is_empty() {
+        let has_nvidia = snap.gpus.iter().any(|g| g.name.contains("NVIDIA"));
+        snap.processes = collect_gpu_processes(has_nvidia);
+    }
+    snap
+}
+
+fn collect_gpu_devices() -> GpuSnapshot {
+    #[allow(unused_mut)]
+    let mut status = Vec::new();
+
     // 1. Try NVML (feature-gated, NVIDIA only)
     #[cfg(feature = "gpu")]
     {
@@ -52,11 +160,13 @@ pub fn collect_gpu_info() -> GpuSnapshot {
             enrich_with_nvidia_smi(&mut snap);
             return snap;
         }
+        status.push(SensorStatus { backend: "DRM (sysfs)", issue: SensorIssue::DriverMissing });
 
         // 4. No sysfs cards found — try nvidia-smi standalone (e.g. container without sysfs)
-        let snap = collect_nvidia_smi();
-        if !snap.gpus.is_empty() {
-            return snap;
+        match query_nvidia_smi() {
+            Ok(gpus) if !gpus.is_empty() => return GpuSnapshot { gpus, ..Default::default() },
+            Ok(_) => {}
+            Err(issue) => status.push(SensorStatus { backend: "nvidia-smi", issue }),
         }
     }
 
@@ -72,21 +182,25 @@ pub fn collect_gpu_info() -> GpuSnapshot {
         if !snap.gpus.is_empty() {
             return snap;
         }
+        status.push(SensorStatus { backend: "WMI", issue: SensorIssue::DriverMissing });
+    }
+
+    // 6. macOS: IOAccelerator (Apple Silicon and discrete GPUs)
+    #[cfg(target_os = "macos")]
+    {
+        let gpus = collect_macos_gpu(&mut status);
+        if !gpus.is_empty() {
+            return GpuSnapshot { gpus, ..Default::default() };
+        }
     }
 
-    GpuSnapshot::default()
+    GpuSnapshot { status, ..Default::default() }
 }
 
 // ---------------------------------------------------------------------------
 // nvidia-smi backend — parses CSV output from the CLI tool
 // ---------------------------------------------------------------------------
 
-#[cfg(target_os = "linux")]
-fn collect_nvidia_smi() -> GpuSnapshot {
-    let gpus = query_nvidia_smi();
-    GpuSnapshot { gpus }
-}
-
 /// Enrich existing sysfs-detected GPUs with nvidia-smi data where sysfs is incomplete.
 #[cfg(target_os = "linux")]
 fn enrich_with_nvidia_smi(snap: &mut GpuSnapshot) {
@@ -95,13 +209,14 @@ fn enrich_with_nvidia_smi(snap: &mut GpuSnapshot) {
         g.name.contains("NVIDIA")
             && (g.temperature == 0.0
                 || g.memory_total == 0
-                || g.power_watts == 0.0)
+                || g.power_watts == 0.0
+                || g.core_clock_mhz.is_none())
     });
     if !needs_enrichment {
         return;
     }
 
-    let smi_gpus = query_nvidia_smi();
+    let smi_gpus = query_nvidia_smi().unwrap_or_default();
 
     // Match by index (nvidia-smi lists GPUs in order)
     let mut smi_idx = 0;
@@ -133,21 +248,60 @@ fn enrich_with_nvidia_smi(snap: &mut GpuSnapshot) {
         if gpu.power_watts == 0.0 && smi.power_watts != 0.0 {
             gpu.power_watts = smi.power_watts;
         }
+        gpu.fan_percent = gpu.fan_percent.or(smi.fan_percent);
+        gpu.core_clock_mhz = gpu.core_clock_mhz.or(smi.core_clock_mhz);
+        gpu.memory_clock_mhz = gpu.memory_clock_mhz.or(smi.memory_clock_mhz);
     }
 }
 
+/// Fields requested from `nvidia-smi --query-gpu`, in the order
+/// `parse_nvidia_smi` expects them.
+#[cfg(any(target_os = "linux", target_os = "windows"))]
+const NVIDIA_SMI_QUERY: &str = "--query-gpu=name,temperature.gpu,utilization.gpu,memory.used,memory.total,power.draw,\
+     fan.speed,clocks.gr,clocks.mem";
+
+/// Parse `nvidia-smi --query-gpu` CSV output (no header, no units). Fields
+/// the card doesn't support read `[N/A]` and come out as 0 or `None`.
+#[cfg_attr(not(any(target_os = "linux", target_os = "windows")), allow(dead_code))]
+fn parse_nvidia_smi(stdout: &str) -> Vec<GpuInfo> {
+    let mut gpus = Vec::new();
+    for line in stdout.lines() {
+        let fields: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+        if fields.len() < 6 {
+            continue;
+        }
+        let opt = |i: usize| fields.get(i).and_then(|f| f.parse::<f32>().ok()).map(|v| v.round() as u32);
+        gpus.push(GpuInfo {
+            name: fields[0].to_string(),
+            temperature: fields[1].parse().unwrap_or(0.0),
+            utilization: fields[2].parse().unwrap_or(0),
+            // nvidia-smi reports memory in MiB
+            memory_used: fields[3].parse::<u64>().map(|m| m * 1024 * 1024).unwrap_or(0),
+            memory_total: fields[4].parse::<u64>().map(|m| m * 1024 * 1024).unwrap_or(0),
+            power_watts: fields[5].parse().unwrap_or(0.0),
+            fan_percent: opt(6),
+            core_clock_mhz: opt(7),
+            memory_clock_mhz: opt(8),
+        });
+    }
+    gpus
+}
+
 /// Opt #3: Cache nvidia-smi results with a 5-second TTL to avoid
 /// spawning a subprocess every metrics tick.
 /// Uses RwLock for multiple concurrent readers.
 #[cfg(target_os = "linux")]
-static NVIDIA_SMI_CACHE: RwLock<Option<(Instant, Vec<GpuInfo>)>> = RwLock::new(None);
+static NVIDIA_SMI_CACHE: RwLock<Option<(Instant, NvidiaSmiResult)>> = RwLock::new(None);
+
+#[cfg(target_os = "linux")]
+type NvidiaSmiResult = Result<Vec<GpuInfo>, SensorIssue>;
 
 #[cfg(target_os = "linux")]
 const NVIDIA_SMI_TTL_SECS: u64 = 5;
 
 /// Run nvidia-smi and parse the CSV output into GpuInfo structs (cached).
 #[cfg(target_os = "linux")]
-fn query_nvidia_smi() -> Vec<GpuInfo> {
+fn query_nvidia_smi() -> NvidiaSmiResult {
     // Fast path: read-only check with RwLock (no writer contention)
     if let Ok(guard) = NVIDIA_SMI_CACHE.read() {
         if let Some((ts, ref cached)) = *guard {
@@ -167,55 +321,34 @@ fn query_nvidia_smi() -> Vec<GpuInfo> {
 }
 
 #[cfg(target_os = "linux")]
-fn query_nvidia_smi_uncached() -> Vec<GpuInfo> {
+fn query_nvidia_smi_uncached() -> NvidiaSmiResult {
     use std::process::Command;
 
     let output = Command::new("nvidia-smi")
         .args([
-            "--query-gpu=name,temperature.gpu,utilization.gpu,memory.used,memory.total,power.draw",
+            NVIDIA_SMI_QUERY,
             "--format=csv,noheader,nounits",
         ])
         .output();
 
     let output = match output {
         Ok(o) if o.status.success() => o,
-        _ => return Vec::new(),
-    };
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let mut gpus = Vec::new();
-
-    for line in stdout.lines() {
-        let fields: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
-        if fields.len() < 6 {
-            continue;
+        // nvidia-smi prints driver errors to stdout
+        Ok(o) => {
+            let msg = String::from_utf8_lossy(if o.stdout.is_empty() { &o.stderr } else { &o.stdout })
+                .lines()
+                .next()
+                .unwrap_or("nvidia-smi failed")
+                .trim()
+                .to_string();
+            return Err(SensorIssue::Failed(msg));
         }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Err(SensorIssue::ServiceMissing("nvidia-smi")),
+        Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => return Err(SensorIssue::PermissionDenied),
+        Err(e) => return Err(SensorIssue::Failed(e.to_string())),
+    };
 
-        let name = fields[0].to_string();
-        let temperature = fields[1].parse::<f32>().unwrap_or(0.0);
-        let utilization = fields[2].parse::<u32>().unwrap_or(0);
-        // nvidia-smi reports memory in MiB
-        let memory_used = fields[3]
-            .parse::<u64>()
-            .map(|m| m * 1024 * 1024)
-            .unwrap_or(0);
-        let memory_total = fields[4]
-            .parse::<u64>()
-            .map(|m| m * 1024 * 1024)
-            .unwrap_or(0);
-        let power_watts = fields[5].parse::<f32>().unwrap_or(0.0);
-
-        gpus.push(GpuInfo {
-            name,
-            temperature,
-            utilization,
-            memory_used,
-            memory_total,
-            power_watts,
-        });
-    }
-
-    gpus
+    Ok(parse_nvidia_smi(&String::from_utf8_lossy(&output.stdout)))
 }
 
 // ---------------------------------------------------------------------------
@@ -258,7 +391,7 @@ fn collect_sysfs() -> GpuSnapshot {
         gpus.push(gpu);
     }
 
-    GpuSnapshot { gpus }
+    GpuSnapshot { gpus, ..Default::default() }
 }
 
 #[cfg(target_os = "linux")]
@@ -276,6 +409,9 @@ fn read_gpu_from_sysfs(card_path: &Path, device_path: &Path) -> GpuInfo {
         memory_used,
         memory_total,
         power_watts,
+        fan_percent: read_gpu_fan(device_path),
+        core_clock_mhz: read_sysfs_str(&device_path.join("pp_dpm_sclk")).and_then(|s| parse_dpm_clock(&s)),
+        memory_clock_mhz: read_sysfs_str(&device_path.join("pp_dpm_mclk")).and_then(|s| parse_dpm_clock(&s)),
     }
 }
 
@@ -387,6 +523,32 @@ fn read_gpu_power(device_path: &Path) -> f32 {
     0.0
 }
 
+/// AMD: fan duty from the hwmon PWM value (0–`pwm1_max`, usually 255).
+#[cfg(target_os = "linux")]
+fn read_gpu_fan(device_path: &Path) -> Option<u32> {
+    let entries = fs::read_dir(device_path.join("hwmon")).ok()?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Some(pwm) = read_sysfs_str(&path.join("pwm1")).and_then(|v| v.parse::<u32>().ok()) else {
+            continue;
+        };
+        let max = read_sysfs_str(&path.join("pwm1_max")).and_then(|v| v.parse::<u32>().ok()).unwrap_or(255);
+        if let Some(pct) = (pwm * 100).checked_div(max) {
+            return Some(pct.min(100));
+        }
+    }
+    None
+}
+
+/// Active level of an AMD `pp_dpm_sclk` / `pp_dpm_mclk` table, whose lines
+/// look like `1: 1800Mhz *` with the current level starred.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_dpm_clock(table: &str) -> Option<u32> {
+    let line = table.lines().find(|l| l.trim_end().ends_with('*'))?;
+    let freq = line.split_whitespace().nth(1)?;
+    freq.trim_end_matches(|c: char| c.is_ascii_alphabetic()).parse().ok()
+}
+
 // ---------------------------------------------------------------------------
 // NVML backend (optional, NVIDIA only)
 // ---------------------------------------------------------------------------
@@ -425,6 +587,7 @@ fn collect_nvml() -> GpuSnapshot {
             .power_usage()
             .map(|mw| mw as f32 / 1000.0)
             .unwrap_or(0.0);
+        let clock = |c| device.clock_info(c).ok();
 
         gpus.push(GpuInfo {
             name,
@@ -433,10 +596,13 @@ fn collect_nvml() -> GpuSnapshot {
             memory_used,
             memory_total,
             power_watts,
+            fan_percent: device.fan_speed(0).ok(),
+            core_clock_mhz: clock(nvml_wrapper::enum_wrappers::device::Clock::Graphics),
+            memory_clock_mhz: clock(nvml_wrapper::enum_wrappers::device::Clock::Memory),
         });
     }
 
-    GpuSnapshot { gpus }
+    GpuSnapshot { gpus, ..Default::default() }
 }
 
 // ---------------------------------------------------------------------------
@@ -458,7 +624,7 @@ fn collect_nvidia_smi_windows() -> GpuSnapshot {
     let cached = if let Ok(guard) = NVIDIA_SMI_CACHE_WIN.lock() {
         if let Some((ts, ref data)) = *guard {
             if ts.elapsed().as_secs() < NVIDIA_SMI_TTL_SECS_WIN {
-                return GpuSnapshot { gpus: data.clone() };
+                return GpuSnapshot { gpus: data.clone(), ..Default::default() };
             }
             Some(data.clone())
         } else {
@@ -489,7 +655,7 @@ fn collect_nvidia_smi_windows() -> GpuSnapshot {
     }
 
     match cached {
-        Some(gpus) if !gpus.is_empty() => GpuSnapshot { gpus },
+        Some(gpus) if !gpus.is_empty() => GpuSnapshot { gpus, ..Default::default() },
         _ => GpuSnapshot::default(),
     }
 }
@@ -500,7 +666,7 @@ fn collect_nvidia_smi_windows_blocking() -> Vec<GpuInfo> {
 
     let output = Command::new("nvidia-smi")
         .args([
-            "--query-gpu=name,temperature.gpu,utilization.gpu,memory.used,memory.total,power.draw",
+            NVIDIA_SMI_QUERY,
             "--format=csv,noheader,nounits",
         ])
         .output();
@@ -510,26 +676,7 @@ fn collect_nvidia_smi_windows_blocking() -> Vec<GpuInfo> {
         _ => return Vec::new(),
     };
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let mut gpus = Vec::new();
-
-    for line in stdout.lines() {
-        let fields: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
-        if fields.len() < 6 {
-            continue;
-        }
-
-        gpus.push(GpuInfo {
-            name: fields[0].to_string(),
-            temperature: fields[1].parse().unwrap_or(0.0),
-            utilization: fields[2].parse().unwrap_or(0),
-            memory_used: fields[3].parse::<u64>().map(|m| m * 1024 * 1024).unwrap_or(0),
-            memory_total: fields[4].parse::<u64>().map(|m| m * 1024 * 1024).unwrap_or(0),
-            power_watts: fields[5].parse().unwrap_or(0.0),
-        });
-    }
-
-    gpus
+    parse_nvidia_smi(&String::from_utf8_lossy(&output.stdout))
 }
 
 // ---------------------------------------------------------------------------
@@ -553,7 +700,7 @@ fn collect_wmi_gpu() -> GpuSnapshot {
     let cached = if let Ok(guard) = WMI_GPU_CACHE.lock() {
         if let Some((ts, ref data)) = *guard {
             if ts.elapsed().as_secs() < WMI_GPU_TTL_SECS {
-                return GpuSnapshot { gpus: data.clone() };
+                return GpuSnapshot { gpus: data.clone(), ..Default::default() };
             }
             Some(data.clone())
         } else {
@@ -584,7 +731,7 @@ fn collect_wmi_gpu() -> GpuSnapshot {
         });
     }
 
-    GpuSnapshot { gpus: cached.unwrap_or_default() }
+    GpuSnapshot { gpus: cached.unwrap_or_default(), ..Default::default() }
 }
 
 #[cfg(target_os = "windows")]
@@ -631,6 +778,9 @@ fn collect_wmi_gpu_native() -> Vec<GpuInfo> {
                 memory_used: 0,
                 memory_total: vc.adapter_ram.unwrap_or(0),
                 power_watts: 0.0,
+                fan_percent: None,
+                core_clock_mhz: None,
+                memory_clock_mhz: None,
             })
         })
         .collect();
@@ -775,6 +925,341 @@ fn enrich_gpu_temps_wmi(
     }
 }
 
+// ---------------------------------------------------------------------------
+// macOS backend — IOAccelerator registry entries + powermetrics for power
+// ---------------------------------------------------------------------------
+
+/// Apple Silicon GPUs via `ioreg`. Utilization and in-use memory come from
+/// the accelerator's `PerformanceStatistics`; power from `powermetrics`
+/// when running as root. Memory is unified, so the collector fills in the
+/// total from system RAM.
+#[cfg(target_os = "macos")]
+fn collect_macos_gpu(status: &mut Vec<SensorStatus>) -> Vec<GpuInfo> {
+    let output = std::process::Command::new("ioreg")
+        .args(["-r", "-d", "1", "-w", "0", "-c", "IOAccelerator"])
+        .output();
+    let mut gpus = match output {
+        Ok(o) if o.status.success() => parse_ioreg_accelerators(&String::from_utf8_lossy(&o.stdout)),
+        Ok(_) => Vec::new(),
+        Err(e) => {
+            status.push(SensorStatus { backend: "IOAccelerator", issue: SensorIssue::Failed(e.to_string()) });
+            return Vec::new();
+        }
+    };
+    if gpus.is_empty() {
+        status.push(SensorStatus { backend: "IOAccelerator", issue: SensorIssue::DriverMissing });
+        return gpus;
+    }
+
+    // Without root there is no power or temperature, but the GPUs were
+    // found, so that isn't reported as a backend problem
+    if let Ok(reading) = crate::powermetrics::latest() {
+        if let (Some(watts), [gpu]) = (reading.gpu_watts, gpus.as_mut_slice()) {
+            gpu.power_watts = watts;
+        }
+        if let Some(t) = reading.temps.iter().find(|t| t.label.starts_with("GPU")) {
+            for gpu in &mut gpus {
+                gpu.temperature = t.temp_c;
+            }
+        }
+    }
+    gpus
+}
+
+/// Parse `ioreg -r -d 1 -c IOAccelerator` output. Each `+-o` line starts an
+/// entry; `"model"` gives the name and the `PerformanceStatistics` dictionary
+/// carries `"Device Utilization %"` and `"In use system memory"`.
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn parse_ioreg_accelerators(output: &str) -> Vec<GpuInfo> {
+    let mut gpus: Vec<GpuInfo> = Vec::new();
+    for line in output.lines() {
+        let line = line.trim();
+        if line.starts_with("+-o") {
+            gpus.push(GpuInfo::default());
+            continue;
+        }
+        let Some(gpu) = gpus.last_mut() else { continue };
+        if let Some(model) = line.strip_prefix("\"model\" = \"") {
+            gpu.name = model.trim_end_matches('"').to_string();
+        } else if line.starts_with("\"PerformanceStatistics\"") {
+            let stat = |key: &str| -> Option<u64> {
+                let rest = &line[line.find(&format!("\"{key}\"="))? + key.len() + 3..];
+                let end = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+                rest[..end].parse().ok()
+            };
+            gpu.utilization = stat("Device Utilization %").unwrap_or(0).min(100) as u32;
+            gpu.memory_used = stat("In use system memory").unwrap_or(0);
+        }
+    }
+    gpus.retain(|g| !g.name.is_empty());
+    gpus
+}
+
+// ---------------------------------------------------------------------------
+// Per-process VRAM — NVML, nvidia-smi compute apps, DRM fdinfo (Linux)
+// ---------------------------------------------------------------------------
+
+/// Walking /proc fdinfo and spawning nvidia-smi is too slow for the UI tick,
+/// so the process list is refreshed on a background thread and cached.
+static GPU_PROC_CACHE: std::sync::Mutex<Option<(Instant, Vec<GpuProcess>)>> = std::sync::Mutex::new(None);
+
+static GPU_PROC_REFRESH_RUNNING: AtomicBool = AtomicBool::new(false);
+
+const GPU_PROC_TTL_SECS: u64 = 3;
+
+/// Return the cached GPU process list, kicking off a refresh when stale.
+fn collect_gpu_processes(has_nvidia: bool) -> Vec<GpuProcess> {
+    let (cached, fresh) = match GPU_PROC_CACHE.lock() {
+        Ok(guard) => match *guard {
+            Some((ts, ref data)) => (data.clone(), ts.elapsed().as_secs() < GPU_PROC_TTL_SECS),
+            None => (Vec::new(), false),
+        },
+        Err(_) => (Vec::new(), true),
+    };
+
+    if !fresh && !GPU_PROC_REFRESH_RUNNING.swap(true, Ordering::AcqRel) {
+        std::thread::spawn(move || {
+            let result = collect_gpu_processes_blocking(has_nvidia);
+            if let Ok(mut guard) = GPU_PROC_CACHE.lock() {
+                *guard = Some((Instant::now(), result));
+            }
+            GPU_PROC_REFRESH_RUNNING.store(false, Ordering::Release);
+        });
+    }
+
+    cached
+}
+
+#[cfg_attr(not(any(target_os = "linux", target_os = "windows")), allow(unused_variables))]
+fn collect_gpu_processes_blocking(has_nvidia: bool) -> Vec<GpuProcess> {
+    #[allow(unused_mut)]
+    let mut procs: Vec<GpuProcess> = Vec::new();
+
+    #[cfg(feature = "gpu")]
+    merge_gpu_processes(&mut procs, query_nvml_processes());
+
+    #[cfg(any(target_os = "linux", target_os = "windows"))]
+    if has_nvidia {
+        merge_gpu_processes(&mut procs, query_nvidia_smi_compute_apps());
+    }
+
+    #[cfg(target_os = "linux")]
+    merge_gpu_processes(&mut procs, query_drm_fdinfo());
+
+    #[cfg(target_os = "windows")]
+    merge_gpu_processes(&mut procs, query_wmi_gpu_processes());
+
+    procs
+}
+
+/// Windows: per-process engine utilization and dedicated memory from the
+/// GPU performance counter classes (Win10 1709+), whose instance names
+/// embed the PID. Runs on the process refresh thread, off the UI thread.
+#[cfg(target_os = "windows")]
+fn query_wmi_gpu_processes() -> Vec<GpuProcess> {
+    use std::collections::HashMap;
+    use wmi::{COMLibrary, Variant, WMIConnection};
+
+    let Ok(com_lib) = COMLibrary::without_security() else { return Vec::new() };
+    let Ok(wmi_con) = WMIConnection::new(com_lib) else { return Vec::new() };
+    let query = |sql: &str, field: &str| -> Vec<(String, f64)> {
+        let rows = wmi_con.raw_query::<HashMap<String, Variant>>(sql).unwrap_or_default();
+        rows.iter()
+            .filter_map(|row| {
+                let Some(Variant::String(name)) = row.get("Name") else { return None };
+                let value = match row.get(field)? {
+                    Variant::UI8(v) => *v as f64,
+                    Variant::UI4(v) => *v as f64,
+                    Variant::R8(v) => *v,
+                    Variant::String(s) => s.parse().ok()?,
+                    _ => return None,
+                };
+                Some((name.clone(), value))
+            })
+            .collect()
+    };
+    let engines = query(
+        "SELECT Name, UtilizationPercentage FROM Win32_PerfFormattedData_GPUPerformanceCounters_GPUEngine",
+        "UtilizationPercentage",
+    );
+    let memory = query(
+        "SELECT Name, DedicatedUsage FROM Win32_PerfFormattedData_GPUPerformanceCounters_GPUProcessMemory",
+        "DedicatedUsage",
+    );
+    per_process_gpu_usage(&engines, &memory)
+}
+
+/// PID from a GPU counter instance name such as
+/// `pid_1234_luid_0x00000000_0x0000D1B1_phys_0_eng_3_engtype_VideoDecode`.
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn counter_instance_pid(name: &str) -> Option<u32> {
+    name.strip_prefix("pid_")?.split('_').next()?.parse().ok()
+}
+
+/// Fold per-engine utilization and per-adapter memory instances into one
+/// entry per PID: the busiest engine (Task Manager's GPU column) and the
+/// summed dedicated memory. Idle processes without memory are left out.
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn per_process_gpu_usage(engines: &[(String, f64)], memory: &[(String, f64)]) -> Vec<GpuProcess> {
+    fn entry(procs: &mut Vec<GpuProcess>, pid: u32) -> &mut GpuProcess {
+        let i = procs.iter().position(|p| p.pid == pid).unwrap_or_else(|| {
+            procs.push(GpuProcess { pid, name: String::new(), vram_bytes: 0, utilization: Some(0.0) });
+            procs.len() - 1
+        });
+        &mut procs[i]
+    }
+
+    let mut procs: Vec<GpuProcess> = Vec::new();
+    for (name, util) in engines {
+        let Some(pid) = counter_instance_pid(name) else { continue };
+        if *util > 0.0 {
+            let proc = entry(&mut procs, pid);
+            proc.utilization = Some(proc.utilization.unwrap_or(0.0).max(util.min(100.0) as f32));
+        }
+    }
+    for (name, bytes) in memory {
+        let Some(pid) = counter_instance_pid(name) else { continue };
+        if *bytes > 0.0 {
+            entry(&mut procs, pid).vram_bytes += *bytes as u64;
+        }
+    }
+    procs
+}
+
+/// Merge `extra` into `procs`, keeping the larger figures when two backends
+/// report the same PID.
+#[cfg_attr(not(any(target_os = "linux", target_os = "windows", feature = "gpu")), allow(dead_code))]
+fn merge_gpu_processes(procs: &mut Vec<GpuProcess>, extra: Vec<GpuProcess>) {
+    for proc in extra {
+        match procs.iter_mut().find(|p| p.pid == proc.pid) {
+            Some(existing) => {
+                existing.vram_bytes = existing.vram_bytes.max(proc.vram_bytes);
+                existing.utilization = match (existing.utilization, proc.utilization) {
+                    (Some(a), Some(b)) => Some(a.max(b)),
+                    (a, b) => a.or(b),
+                };
+            }
+            None => procs.push(proc),
+        }
+    }
+}
+
+#[cfg(feature = "gpu")]
+fn query_nvml_processes() -> Vec<GpuProcess> {
+    use nvml_wrapper::enums::device::UsedGpuMemory;
+    use nvml_wrapper::Nvml;
+
+    let Ok(nvml) = Nvml::init() else { return Vec::new() };
+    let count = nvml.device_count().unwrap_or(0);
+    let mut procs = Vec::new();
+    for i in 0..count {
+        let Ok(device) = nvml.device_by_index(i) else { continue };
+        let compute = device.running_compute_processes().unwrap_or_default();
+        let graphics = device.running_graphics_processes().unwrap_or_default();
+        let found = compute.into_iter().chain(graphics).map(|p| GpuProcess {
+            pid: p.pid,
+            name: String::new(),
+            vram_bytes: match p.used_gpu_memory {
+                UsedGpuMemory::Used(bytes) => bytes,
+                UsedGpuMemory::Unavailable => 0,
+            },
+            utilization: None,
+        });
+        merge_gpu_processes(&mut procs, found.collect());
+    }
+    procs
+}
+
+#[cfg(any(target_os = "linux", target_os = "windows"))]
+fn query_nvidia_smi_compute_apps() -> Vec<GpuProcess> {
+    use std::process::Command;
+
+    let output = Command::new("nvidia-smi")
+        .args(["--query-compute-apps=pid,used_memory", "--format=csv,noheader,nounits"])
+        .output();
+    match output {
+        Ok(o) if o.status.success() => parse_compute_apps(&String::from_utf8_lossy(&o.stdout)),
+        _ => Vec::new(),
+    }
+}
+
+/// Parse `pid, used_memory` CSV lines (memory in MiB).
+#[cfg_attr(not(any(target_os = "linux", target_os = "windows")), allow(dead_code))]
+fn parse_compute_apps(stdout: &str) -> Vec<GpuProcess> {
+    stdout
+        .lines()
+        .filter_map(|line| {
+            let (pid, mem) = line.split_once(',')?;
+            Some(GpuProcess {
+                pid: pid.trim().parse().ok()?,
+                name: String::new(),
+                vram_bytes: mem.trim().parse::<u64>().map(|m| m * 1024 * 1024).unwrap_or(0),
+                utilization: None,
+            })
+        })
+        .collect()
+}
+
+/// Linux: sum device-local memory from DRM fdinfo for every process that has
+/// a /dev/dri node open. Clients shared across fds are counted once.
+#[cfg(target_os = "linux")]
+fn query_drm_fdinfo() -> Vec<GpuProcess> {
+    use std::collections::HashMap;
+
+    let Ok(proc_dir) = fs::read_dir("/proc") else { return Vec::new() };
+    let mut procs = Vec::new();
+    for entry in proc_dir.flatten() {
+        let Some(pid) = entry.file_name().to_str().and_then(|s| s.parse::<u32>().ok()) else { continue };
+        let Ok(fds) = fs::read_dir(entry.path().join("fd")) else { continue };
+
+        let mut clients: HashMap<String, u64> = HashMap::new();
+        for fd in fds.flatten() {
+            let is_dri = fs::read_link(fd.path())
+                .map(|target| target.starts_with("/dev/dri/"))
+                .unwrap_or(false);
+            if !is_dri {
+                continue;
+            }
+            let fdinfo = entry.path().join("fdinfo").join(fd.file_name());
+            if let Some((client, bytes)) = fs::read_to_string(fdinfo).ok().as_deref().and_then(parse_drm_fdinfo) {
+                clients.insert(client, bytes);
+            }
+        }
+
+        let vram_bytes: u64 = clients.values().sum();
+        if vram_bytes > 0 {
+            procs.push(GpuProcess { pid, name: String::new(), vram_bytes, utilization: None });
+        }
+    }
+    procs
+}
+
+/// Parse one DRM fdinfo file into (client id, device-local bytes).
+/// amdgpu reports `drm-memory-vram`, xe/i915 report `drm-total-vram0` or
+/// `drm-total-local0`; system memory regions are ignored.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_drm_fdinfo(content: &str) -> Option<(String, u64)> {
+    let mut client = None;
+    let mut bytes = 0u64;
+    for line in content.lines() {
+        let Some((key, value)) = line.split_once(':') else { continue };
+        let key = key.trim();
+        if key == "drm-client-id" {
+            client = Some(value.trim().to_string());
+        } else if key == "drm-memory-vram" || key.starts_with("drm-total-vram") || key.starts_with("drm-total-local") {
+            let mut parts = value.split_whitespace();
+            let n: u64 = parts.next().and_then(|v| v.parse().ok()).unwrap_or(0);
+            bytes += match parts.next() {
+                Some("KiB") => n * 1024,
+                Some("MiB") => n * 1024 * 1024,
+                Some("GiB") => n * 1024 * 1024 * 1024,
+                _ => n,
+            };
+        }
+    }
+    client.map(|c| (c, bytes))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -791,17 +1276,103 @@ mod tests {
         let _ = snap.gpus.len();
     }
 
+    #[test]
+    fn test_parse_ioreg_accelerators() {
+        let out = r#"+-o AGXAcceleratorG13X  <class AGXAcceleratorG13X, id 0x1000003a1, registered, matched, active, busy 0 (0 ms), retain 90>
+    {
+      "gpu-core-count" = 16
+      "model" = "Apple M1 Pro"
+      "PerformanceStatistics" = {"In use system memory"=734003200,"Tiler Utilization %"=4,"Device Utilization %"=12,"Alloc system memory"=1503657984}
+    }
+"#;
+        let gpus = parse_ioreg_accelerators(out);
+        assert_eq!(gpus.len(), 1);
+        assert_eq!(gpus[0].name, "Apple M1 Pro");
+        assert_eq!(gpus[0].utilization, 12);
+        assert_eq!(gpus[0].memory_used, 734_003_200);
+        assert_eq!(gpus[0].memory_total, 0);
+        assert!(parse_ioreg_accelerators("").is_empty());
+    }
+
     #[test]
     fn test_parse_nvidia_smi_output() {
-        // Simulate what query_nvidia_smi would parse
-        let line = "NVIDIA GeForce RTX 2080 SUPER, 45, 3, 1024, 8192, 30.50";
-        let fields: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
-        assert_eq!(fields.len(), 6);
-        assert_eq!(fields[0], "NVIDIA GeForce RTX 2080 SUPER");
-        assert_eq!(fields[1].parse::<f32>().unwrap(), 45.0);
-        assert_eq!(fields[2].parse::<u32>().unwrap(), 3);
-        assert_eq!(fields[3].parse::<u64>().unwrap() * 1024 * 1024, 1024 * 1024 * 1024);
-        assert_eq!(fields[4].parse::<u64>().unwrap() * 1024 * 1024, 8192 * 1024 * 1024);
-        assert_eq!(fields[5].parse::<f32>().unwrap(), 30.50);
+        let out = "NVIDIA GeForce RTX 2080 SUPER, 45, 3, 1024, 8192, 30.50, 27, 1350, 7000\n\
+                   Tesla T4, 38, 0, 0, 15360, 12.00, [N/A], 300, 405\n";
+        let gpus = parse_nvidia_smi(out);
+        assert_eq!(gpus.len(), 2);
+        assert_eq!(gpus[0].name, "NVIDIA GeForce RTX 2080 SUPER");
+        assert_eq!(gpus[0].temperature, 45.0);
+        assert_eq!(gpus[0].utilization, 3);
+        assert_eq!(gpus[0].memory_used, 1024 * 1024 * 1024);
+        assert_eq!(gpus[0].memory_total, 8192 * 1024 * 1024);
+        assert_eq!(gpus[0].power_watts, 30.50);
+        assert_eq!((gpus[0].fan_percent, gpus[0].core_clock_mhz, gpus[0].memory_clock_mhz), (Some(27), Some(1350), Some(7000)));
+        // Passively cooled: no fan
+        assert_eq!(gpus[1].fan_percent, None);
+        assert_eq!(gpus[1].core_clock_mhz, Some(300));
+    }
+
+    #[test]
+    fn test_parse_dpm_clock() {
+        assert_eq!(parse_dpm_clock("0: 500Mhz\n1: 1800Mhz *\n2: 2100Mhz\n"), Some(1800));
+        assert_eq!(parse_dpm_clock("0: 96Mhz *\n"), Some(96));
+        assert_eq!(parse_dpm_clock("0: 500Mhz\n"), None);
+    }
+
+    #[test]
+    fn test_parse_compute_apps() {
+        let procs = parse_compute_apps("1234, 512\n5678, 2048\ngarbage\n");
+        assert_eq!(procs.len(), 2);
+        assert_eq!(procs[0].pid, 1234);
+        assert_eq!(procs[0].vram_bytes, 512 * 1024 * 1024);
+        assert_eq!(procs[1].vram_bytes, 2048 * 1024 * 1024);
+    }
+
+    #[test]
+    fn test_parse_drm_fdinfo() {
+        let amd = "pos:\t0\ndrm-driver:\tamdgpu\ndrm-client-id:\t42\ndrm-memory-vram:\t2048 KiB\ndrm-memory-gtt:\t512 KiB\n";
+        assert_eq!(parse_drm_fdinfo(amd), Some(("42".into(), 2048 * 1024)));
+
+        let xe = "drm-driver:\txe\ndrm-client-id:\t7\ndrm-total-system:\t8 MiB\ndrm-total-vram0:\t16 MiB\n";
+        assert_eq!(parse_drm_fdinfo(xe), Some(("7".into(), 16 * 1024 * 1024)));
+
+        assert_eq!(parse_drm_fdinfo("flags:\t02\n"), None);
+    }
+
+    #[test]
+    fn test_per_process_gpu_usage() {
+        assert_eq!(counter_instance_pid("pid_1234_luid_0x00000000_0x0000D1B1_phys_0_eng_0_engtype_3D"), Some(1234));
+        assert_eq!(counter_instance_pid("luid_0x00000000_0x0000D1B1_phys_0"), None);
+
+        let engines = [
+            ("pid_10_luid_0x0_0xD1B1_phys_0_eng_0_engtype_3D".to_string(), 30.0),
+            ("pid_10_luid_0x0_0xD1B1_phys_0_eng_3_engtype_VideoDecode".to_string(), 45.0),
+            ("pid_20_luid_0x0_0xD1B1_phys_0_eng_0_engtype_3D".to_string(), 0.0),
+            ("pid_30_luid_0x0_0xD1B1_phys_0_eng_0_engtype_3D".to_string(), 5.0),
+        ];
+        let memory = [
+            ("pid_10_luid_0x0_0xD1B1_phys_0".to_string(), 1024.0),
+            ("pid_20_luid_0x0_0xD1B1_phys_0".to_string(), 2048.0),
+        ];
+        let procs = per_process_gpu_usage(&engines, &memory);
+        assert_eq!(procs.len(), 3);
+        let get = |pid| procs.iter().find(|p| p.pid == pid).unwrap();
+        assert_eq!(get(10).utilization, Some(45.0));
+        assert_eq!(get(10).vram_bytes, 1024);
+        assert_eq!(get(20).utilization, Some(0.0));
+        assert_eq!(get(20).vram_bytes, 2048);
+        assert_eq!(get(30).vram_bytes, 0);
+    }
+
+    #[test]
+    fn test_merge_gpu_processes_keeps_max() {
+        let mut procs = vec![GpuProcess { pid: 1, name: String::new(), vram_bytes: 100, utilization: None }];
+        merge_gpu_processes(&mut procs, vec![
+            GpuProcess { pid: 1, name: String::new(), vram_bytes: 300, utilization: Some(12.0) },
+            GpuProcess { pid: 2, name: String::new(), vram_bytes: 50, utilization: None },
+        ]);
+        assert_eq!(procs.len(), 2);
+        assert_eq!(procs[0].vram_bytes, 300);
+        assert_eq!(procs[0].utilization, Some(12.0));
     }
 }
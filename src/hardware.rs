@@ -0,0 +1,765 @@
+//! Static hardware inventory for the Hardware tab.
+//!
+//! Read once, on a background thread, the first time the tab is shown:
+//! nothing here changes while Digger runs. Linux reads sysfs (DMI, CPU
+//! topology and caches, block devices, PCI display controllers) and asks
+//! `dmidecode` for the memory modules, which only works as root. macOS asks
+//! `system_profiler` and `sysctl`; Windows runs one CIM query through
+//! PowerShell. Whatever can't be read is left out rather than guessed.
+
+use std::collections::{BTreeMap, HashSet};
+use std::process::Command;
+use std::sync::{Arc, Mutex};
+
+/// Vendor, model and version of the machine or its motherboard.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Board {
+    pub vendor: Option<String>,
+    pub model: Option<String>,
+    pub version: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Bios {
+    pub vendor: Option<String>,
+    pub version: Option<String>,
+    pub date: Option<String>,
+}
+
+/// One level of CPU cache, e.g. eight 48 KiB L1d caches.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cache {
+    /// `L1d`, `L1i`, `L2`, `L3`.
+    pub name: String,
+    /// Size of each instance, in bytes.
+    pub size: u64,
+    pub instances: usize,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CpuTopology {
+    pub sockets: usize,
+    /// Physical cores across all sockets.
+    pub cores: usize,
+    pub threads: usize,
+    pub max_mhz: Option<u32>,
+    pub caches: Vec<Cache>,
+}
+
+/// An installed memory module.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Dimm {
+    /// Slot label as printed on the board, e.g. `DIMM A1`.
+    pub slot: String,
+    pub size: u64,
+    /// `DDR4`, `LPDDR5`…
+    pub kind: Option<String>,
+    /// Configured speed in MT/s, or the rated one when that's unknown.
+    pub speed_mts: Option<u32>,
+    pub manufacturer: Option<String>,
+    pub part: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Memory {
+    pub dimms: Vec<Dimm>,
+    /// Slots on the board, filled or not.
+    pub slots: Option<usize>,
+    /// Why the modules couldn't be listed.
+    pub error: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gpu {
+    pub name: String,
+    /// Kernel driver on Linux, driver version on Windows.
+    pub driver: Option<String>,
+}
+
+/// A physical drive.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Drive {
+    /// Device name: `nvme0n1`, `sda`, `disk0`, `\\.\PHYSICALDRIVE0`.
+    pub name: String,
+    pub model: Option<String>,
+    pub size: u64,
+    /// `NVMe`, `SATA`, `USB`, `virtio`…
+    pub bus: Option<String>,
+    /// Spinning disk; `None` when the system doesn't say.
+    pub rotational: Option<bool>,
+    pub removable: bool,
+}
+
+impl Drive {
+    /// Bus and media, e.g. "NVMe SSD" or "USB".
+    pub fn kind(&self) -> String {
+        let media = match self.rotational {
+            Some(true) => Some("HDD"),
+            Some(false) if self.bus.as_deref() != Some("USB") => Some("SSD"),
+            _ => None,
+        };
+        [self.bus.as_deref(), media].into_iter().flatten().collect::<Vec<_>>().join(" ")
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Inventory {
+    /// The machine as sold: vendor and product name.
+    pub system: Board,
+    pub board: Board,
+    pub bios: Bios,
+    pub cpu: CpuTopology,
+    pub memory: Memory,
+    pub gpus: Vec<Gpu>,
+    pub drives: Vec<Drive>,
+}
+
+/// Handle to the background read; the UI polls `result()` on each tick.
+pub struct InventoryRead {
+    shared: Arc<Mutex<Option<Inventory>>>,
+}
+
+impl InventoryRead {
+    pub fn start() -> Self {
+        let shared = Arc::new(Mutex::new(None));
+        let thread_shared = Arc::clone(&shared);
+        std::thread::spawn(move || {
+            let inventory = collect();
+            if let Ok(mut slot) = thread_shared.lock() {
+                *slot = Some(inventory);
+            }
+        });
+        Self { shared }
+    }
+
+    /// The inventory once it has been read.
+    pub fn result(&self) -> Option<Inventory> {
+        self.shared.lock().ok().and_then(|slot| slot.clone())
+    }
+}
+
+/// Firmware placeholders that mean "not filled in", compared ignoring case.
+const PLACEHOLDERS: &[&str] = &[
+    "to be filled by o.e.m.",
+    "default string",
+    "not specified",
+    "not applicable",
+    "system product name",
+    "system manufacturer",
+    "system version",
+    "o.e.m.",
+    "none",
+    "unknown",
+    "n/a",
+];
+
+/// A trimmed firmware string, or None for blanks and placeholders.
+fn clean(s: &str) -> Option<String> {
+    let s = s.trim_matches(|c: char| c.is_whitespace() || c == '\0');
+    (!s.is_empty() && !PLACEHOLDERS.contains(&s.to_lowercase().as_str())).then(|| s.to_string())
+}
+
+/// Bytes from a size such as `16 GB`, `8192 MB` or `48K`.
+fn parse_size(s: &str) -> Option<u64> {
+    let s = s.trim();
+    let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let value: u64 = s[..split].parse().ok()?;
+    let unit = match s[split..].trim().to_ascii_uppercase().trim_end_matches("IB").trim_end_matches('B') {
+        "" => 1,
+        "K" => 1 << 10,
+        "M" => 1 << 20,
+        "G" => 1 << 30,
+        "T" => 1 << 40,
+        _ => return None,
+    };
+    Some(value * unit)
+}
+
+/// Leading number of a speed such as `3200 MT/s` or `2667 MHz`.
+fn parse_speed(s: &str) -> Option<u32> {
+    s.split_whitespace().next()?.parse().ok().filter(|&v| v > 0)
+}
+
+/// Output of a helper command, or why it failed.
+fn run(cmd: &mut Command) -> Result<String, String> {
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        // CREATE_NO_WINDOW: don't flash a console
+        cmd.creation_flags(0x0800_0000);
+    }
+    let program = cmd.get_program().to_string_lossy().into_owned();
+    let output = cmd.output().map_err(|e| format!("{program}: {e}"))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let reason = stderr.lines().map(str::trim).find(|l| !l.is_empty()).unwrap_or("failed");
+        return Err(format!("{program}: {reason}"));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+// ─── Linux ──────────────────────────────────────────────────────
+
+#[cfg(target_os = "linux")]
+fn collect() -> Inventory {
+    use std::path::Path;
+
+    let read = |path: &Path| std::fs::read_to_string(path).ok();
+    let dmi = |name: &str| read(&Path::new("/sys/class/dmi/id").join(name)).and_then(|s| clean(&s));
+    let mut system = Board { vendor: dmi("sys_vendor"), model: dmi("product_name"), version: dmi("product_version") };
+    if system.model.is_none() {
+        // Boards without DMI (ARM) name themselves in the device tree
+        system.model = read(Path::new("/sys/firmware/devicetree/base/model")).and_then(|s| clean(&s));
+    }
+    let memory = match run(Command::new("dmidecode").args(["-t", "17"])) {
+        Ok(out) => parse_dmidecode(&out),
+        Err(e) => Memory { error: Some(e), ..Default::default() },
+    };
+    Inventory {
+        system,
+        board: Board { vendor: dmi("board_vendor"), model: dmi("board_name"), version: dmi("board_version") },
+        bios: Bios { vendor: dmi("bios_vendor"), version: dmi("bios_version"), date: dmi("bios_date") },
+        cpu: linux_cpu(),
+        memory,
+        gpus: linux_gpus(),
+        drives: linux_drives(),
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn linux_cpu() -> CpuTopology {
+    let cpus: Vec<std::path::PathBuf> = std::fs::read_dir("/sys/devices/system/cpu")
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| {
+            let name = p.file_name().and_then(|n| n.to_str()).unwrap_or("");
+            name.strip_prefix("cpu").is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+        })
+        .collect();
+    let read = |path: std::path::PathBuf| std::fs::read_to_string(path).map(|s| s.trim().to_string()).ok();
+
+    let mut packages = HashSet::new();
+    let mut cores = HashSet::new();
+    let mut caches = Vec::new();
+    for cpu in &cpus {
+        let package = read(cpu.join("topology/physical_package_id")).unwrap_or_default();
+        let core = read(cpu.join("topology/core_id")).unwrap_or_default();
+        packages.insert(package.clone());
+        cores.insert((package, core));
+        for index in std::fs::read_dir(cpu.join("cache")).into_iter().flatten().flatten() {
+            let dir = index.path();
+            let (Some(level), Some(kind), Some(size)) =
+                (read(dir.join("level")), read(dir.join("type")), read(dir.join("size")))
+            else {
+                continue;
+            };
+            let shared = read(dir.join("shared_cpu_list")).unwrap_or_default();
+            caches.push((level, kind, size, shared));
+        }
+    }
+    let max_khz = read("/sys/devices/system/cpu/cpu0/cpufreq/cpuinfo_max_freq".into()).and_then(|s| s.parse::<u32>().ok());
+    CpuTopology {
+        sockets: packages.len(),
+        cores: cores.len(),
+        threads: cpus.len(),
+        max_mhz: max_khz.map(|khz| khz / 1000),
+        caches: group_caches(caches),
+    }
+}
+
+/// Caches from sysfs `(level, type, size, shared_cpu_list)` entries, one
+/// per CPU and cache. CPUs sharing a cache list the same CPUs, so each
+/// distinct list is one instance.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn group_caches(entries: Vec<(String, String, String, String)>) -> Vec<Cache> {
+    let mut seen = HashSet::new();
+    let mut levels: BTreeMap<String, Cache> = BTreeMap::new();
+    for (level, kind, size, shared) in entries {
+        let name = match kind.as_str() {
+            "Data" => format!("L{level}d"),
+            "Instruction" => format!("L{level}i"),
+            _ => format!("L{level}"),
+        };
+        let Some(size) = parse_size(&size) else { continue };
+        if !seen.insert((name.clone(), shared)) {
+            continue;
+        }
+        levels.entry(name.clone()).or_insert(Cache { name, size, instances: 0 }).instances += 1;
+    }
+    levels.into_values().collect()
+}
+
+/// Memory modules from `dmidecode -t 17`. Every Memory Device block is a
+/// slot; empty ones say `No Module Installed`.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_dmidecode(output: &str) -> Memory {
+    let mut memory = Memory { slots: Some(0), ..Default::default() };
+    for block in output.split("\n\n").filter(|b| b.lines().any(|l| l.trim() == "Memory Device")) {
+        *memory.slots.get_or_insert(0) += 1;
+        let field = |key: &str| {
+            block.lines().find_map(|l| l.trim().strip_prefix(key)?.strip_prefix(':').map(str::trim))
+        };
+        let Some(size) = field("Size").and_then(parse_size).filter(|&s| s > 0) else { continue };
+        let speed = field("Configured Memory Speed")
+            .or_else(|| field("Configured Clock Speed"))
+            .and_then(parse_speed)
+            .or_else(|| field("Speed").and_then(parse_speed));
+        memory.dimms.push(Dimm {
+            slot: field("Locator").and_then(clean).unwrap_or_else(|| format!("#{}", memory.dimms.len())),
+            size,
+            kind: field("Type").and_then(clean),
+            speed_mts: speed,
+            manufacturer: field("Manufacturer").and_then(clean),
+            part: field("Part Number").and_then(clean),
+        });
+    }
+    memory
+}
+
+/// Human name of a PCI device from a `pci.ids` database.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn pci_name(ids: &str, vendor: &str, device: &str) -> Option<String> {
+    let mut lines = ids.lines().skip_while(|l| !l.starts_with(vendor));
+    let vendor_name = lines.next()?[vendor.len()..].trim().to_string();
+    for line in lines {
+        if !line.starts_with('\t') {
+            break;
+        }
+        if let Some(rest) = line.strip_prefix('\t').and_then(|l| l.strip_prefix(device)) {
+            return Some(format!("{vendor_name} {}", rest.trim()));
+        }
+    }
+    Some(vendor_name)
+}
+
+#[cfg(target_os = "linux")]
+fn linux_gpus() -> Vec<Gpu> {
+    let ids = ["/usr/share/hwdata/pci.ids", "/usr/share/misc/pci.ids", "/usr/share/pci.ids"]
+        .iter()
+        .find_map(|p| std::fs::read_to_string(p).ok())
+        .unwrap_or_default();
+    let mut gpus = Vec::new();
+    for entry in std::fs::read_dir("/sys/bus/pci/devices").into_iter().flatten().flatten() {
+        let dir = entry.path();
+        let read = |name: &str| std::fs::read_to_string(dir.join(name)).map(|s| s.trim().to_string()).unwrap_or_default();
+        // PCI class 0x03: display controllers
+        if !read("class").starts_with("0x03") {
+            continue;
+        }
+        let vendor = read("vendor").trim_start_matches("0x").to_string();
+        let device = read("device").trim_start_matches("0x").to_string();
+        let name = pci_name(&ids, &vendor, &device).unwrap_or_else(|| {
+            let vendor_name = match vendor.as_str() {
+                "10de" => "NVIDIA",
+                "1002" => "AMD",
+                "8086" => "Intel",
+                "1af4" => "Red Hat",
+                "1234" => "QEMU",
+                "15ad" => "VMware",
+                "1414" => "Microsoft",
+                _ => "Display controller",
+            };
+            format!("{vendor_name} [{vendor}:{device}]")
+        });
+        let driver = std::fs::read_link(dir.join("driver"))
+            .ok()
+            .and_then(|l| l.file_name().map(|n| n.to_string_lossy().into_owned()));
+        gpus.push(Gpu { name, driver });
+    }
+    gpus
+}
+
+#[cfg(target_os = "linux")]
+fn linux_drives() -> Vec<Drive> {
+    let mut drives = Vec::new();
+    for entry in std::fs::read_dir("/sys/block").into_iter().flatten().flatten() {
+        let dir = entry.path();
+        // Loop, RAM, zram and device-mapper devices have no backing device
+        if !dir.join("device").exists() {
+            continue;
+        }
+        let read = |name: &str| std::fs::read_to_string(dir.join(name)).map(|s| s.trim().to_string()).ok();
+        let size = read("size").and_then(|s| s.parse::<u64>().ok()).unwrap_or(0) * 512;
+        if size == 0 {
+            continue;
+        }
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let path = std::fs::canonicalize(&dir).unwrap_or_default().to_string_lossy().into_owned();
+        let bus = if path.contains("/usb") {
+            "USB"
+        } else if name.starts_with("nvme") {
+            "NVMe"
+        } else if name.starts_with("mmcblk") {
+            "MMC"
+        } else if name.starts_with("vd") {
+            "virtio"
+        } else if path.contains("/ata") {
+            "SATA"
+        } else {
+            "SCSI"
+        };
+        drives.push(Drive {
+            model: read("device/model").and_then(|m| clean(&m)),
+            size,
+            bus: Some(bus.to_string()),
+            rotational: read("queue/rotational").map(|r| r == "1"),
+            removable: read("removable").as_deref() == Some("1"),
+            name,
+        });
+    }
+    drives.sort_by(|a, b| a.name.cmp(&b.name));
+    drives
+}
+
+// ─── macOS ──────────────────────────────────────────────────────
+
+#[cfg(target_os = "macos")]
+fn collect() -> Inventory {
+    let mut inventory = match run(Command::new("system_profiler").args([
+        "-json",
+        "SPHardwareDataType",
+        "SPMemoryDataType",
+        "SPDisplaysDataType",
+        "SPNVMeDataType",
+        "SPSerialATADataType",
+    ])) {
+        Ok(json) => parse_system_profiler(&json),
+        Err(e) => Inventory { memory: Memory { error: Some(e), ..Default::default() }, ..Default::default() },
+    };
+    // sysctl exits non-zero when one of the names is missing (no L3 on
+    // Apple silicon) but still prints the others
+    let keys = ["hw.packages", "hw.physicalcpu", "hw.logicalcpu", "hw.cpufrequency_max", "hw.l1dcachesize", "hw.l1icachesize", "hw.l2cachesize", "hw.l3cachesize"];
+    if let Ok(output) = Command::new("sysctl").args(keys).output() {
+        inventory.cpu = parse_sysctl(&String::from_utf8_lossy(&output.stdout));
+    }
+    inventory
+}
+
+/// CPU topology from `sysctl hw.…` lines such as `hw.physicalcpu: 8`.
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn parse_sysctl(output: &str) -> CpuTopology {
+    let value = |key: &str| -> Option<u64> {
+        output.lines().find_map(|l| l.strip_prefix(key)?.strip_prefix(':')?.trim().parse().ok())
+    };
+    let caches = [("L1d", "hw.l1dcachesize"), ("L1i", "hw.l1icachesize"), ("L2", "hw.l2cachesize"), ("L3", "hw.l3cachesize")]
+        .into_iter()
+        .filter_map(|(name, key)| value(key).filter(|&s| s > 0).map(|size| Cache { name: name.into(), size, instances: 1 }))
+        .collect();
+    CpuTopology {
+        sockets: value("hw.packages").unwrap_or(1) as usize,
+        cores: value("hw.physicalcpu").unwrap_or(0) as usize,
+        threads: value("hw.logicalcpu").unwrap_or(0) as usize,
+        max_mhz: value("hw.cpufrequency_max").map(|hz| (hz / 1_000_000) as u32),
+        caches,
+    }
+}
+
+/// Machine, memory, graphics and drives from `system_profiler -json`.
+/// Intel Macs list each DIMM under `_items`; Apple silicon reports one
+/// on-package block.
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn parse_system_profiler(json: &str) -> Inventory {
+    use serde_json::Value;
+
+    let Ok(root) = serde_json::from_str::<Value>(json) else { return Inventory::default() };
+    let items = |key: &str| root.get(key).and_then(Value::as_array).cloned().unwrap_or_default();
+    let text = |v: &Value, key: &str| v.get(key).and_then(Value::as_str).and_then(clean);
+    let mut inventory = Inventory::default();
+
+    if let Some(hw) = items("SPHardwareDataType").first() {
+        let model = match (text(hw, "machine_name"), text(hw, "machine_model")) {
+            (Some(name), Some(id)) => Some(format!("{name} ({id})")),
+            (name, id) => name.or(id),
+        };
+        inventory.system = Board { vendor: Some("Apple".into()), model, version: text(hw, "model_number") };
+        inventory.bios = Bios { vendor: Some("Apple".into()), version: text(hw, "boot_rom_version"), date: None };
+    }
+
+    for block in items("SPMemoryDataType") {
+        match block.get("_items").and_then(Value::as_array) {
+            Some(dimms) => {
+                inventory.memory.slots = Some(dimms.len());
+                for dimm in dimms {
+                    let Some(size) = text(dimm, "dimm_size").and_then(|s| parse_size(&s)) else { continue };
+                    inventory.memory.dimms.push(Dimm {
+                        slot: text(dimm, "_name").unwrap_or_default(),
+                        size,
+                        kind: text(dimm, "dimm_type"),
+                        speed_mts: text(dimm, "dimm_speed").and_then(|s| parse_speed(&s)),
+                        manufacturer: text(dimm, "dimm_manufacturer"),
+                        part: text(dimm, "dimm_part_number"),
+                    });
+                }
+            }
+            None => {
+                let Some(size) = text(&block, "SPMemoryDataType").and_then(|s| parse_size(&s)) else { continue };
+                inventory.memory.dimms.push(Dimm {
+                    slot: "On package".into(),
+                    size,
+                    kind: text(&block, "dimm_type"),
+                    manufacturer: text(&block, "dimm_manufacturer"),
+                    ..Default::default()
+                });
+            }
+        }
+    }
+
+    inventory.gpus = items("SPDisplaysDataType")
+        .iter()
+        .filter_map(|gpu| text(gpu, "sppci_model").or_else(|| text(gpu, "_name")))
+        .map(|name| Gpu { name, driver: None })
+        .collect();
+
+    for (key, bus) in [("SPNVMeDataType", "NVMe"), ("SPSerialATADataType", "SATA")] {
+        for controller in items(key) {
+            for drive in controller.get("_items").and_then(Value::as_array).into_iter().flatten() {
+                let Some(size) = drive.get("size_in_bytes").and_then(Value::as_u64) else { continue };
+                let medium = text(drive, "spsata_medium_type");
+                inventory.drives.push(Drive {
+                    name: text(drive, "bsd_name").unwrap_or_default(),
+                    model: text(drive, "device_model").or_else(|| text(drive, "_name")),
+                    size,
+                    bus: Some(bus.into()),
+                    rotational: if bus == "NVMe" { Some(false) } else { medium.map(|m| m == "Rotational") },
+                    removable: text(drive, "removable_media").is_some_and(|r| r == "yes"),
+                });
+            }
+        }
+    }
+    inventory
+}
+
+// ─── Windows ────────────────────────────────────────────────────
+
+#[cfg(target_os = "windows")]
+fn collect() -> Inventory {
+    let script = "@{\
+        system = Get-CimInstance Win32_ComputerSystem | Select-Object Manufacturer, Model, SystemFamily; \
+        board = Get-CimInstance Win32_BaseBoard | Select-Object Manufacturer, Product, Version; \
+        bios = Get-CimInstance Win32_BIOS | Select-Object Manufacturer, SMBIOSBIOSVersion, @{n='ReleaseDate'; e={$_.ReleaseDate.ToString('yyyy-MM-dd')}}; \
+        cpus = @(Get-CimInstance Win32_Processor | Select-Object NumberOfCores, NumberOfLogicalProcessors, MaxClockSpeed, L2CacheSize, L3CacheSize); \
+        slots = (Get-CimInstance Win32_PhysicalMemoryArray | Measure-Object MemoryDevices -Sum).Sum; \
+        dimms = @(Get-CimInstance Win32_PhysicalMemory | Select-Object DeviceLocator, Capacity, ConfiguredClockSpeed, Speed, SMBIOSMemoryType, Manufacturer, PartNumber); \
+        gpus = @(Get-CimInstance Win32_VideoController | Select-Object Name, DriverVersion); \
+        disks = @(Get-CimInstance Win32_DiskDrive | Select-Object DeviceID, Model, Size, InterfaceType, MediaType)\
+        } | ConvertTo-Json -Depth 3 -Compress";
+    match run(Command::new("powershell").args(["-NoProfile", "-NonInteractive", "-Command", script])) {
+        Ok(json) => parse_windows(&json),
+        Err(e) => Inventory { memory: Memory { error: Some(e), ..Default::default() }, ..Default::default() },
+    }
+}
+
+/// DDR generation from an SMBIOS memory type code.
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn smbios_memory_type(code: u64) -> Option<&'static str> {
+    Some(match code {
+        20 => "DDR",
+        21 => "DDR2",
+        24 => "DDR3",
+        26 => "DDR4",
+        27 => "LPDDR",
+        28 => "LPDDR2",
+        29 => "LPDDR3",
+        30 => "LPDDR4",
+        34 => "DDR5",
+        35 => "LPDDR5",
+        _ => return None,
+    })
+}
+
+/// The CIM query's JSON. Cache sizes come in KiB per socket.
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn parse_windows(json: &str) -> Inventory {
+    use serde_json::Value;
+
+    let Ok(root) = serde_json::from_str::<Value>(json) else { return Inventory::default() };
+    let text = |v: &Value, key: &str| v.get(key).and_then(Value::as_str).and_then(clean);
+    let number = |v: &Value, key: &str| v.get(key).and_then(Value::as_u64).filter(|&n| n > 0);
+    let list = |key: &str| root.get(key).and_then(Value::as_array).cloned().unwrap_or_default();
+    let mut inventory = Inventory::default();
+
+    if let Some(sys) = root.get("system") {
+        inventory.system = Board { vendor: text(sys, "Manufacturer"), model: text(sys, "Model"), version: text(sys, "SystemFamily") };
+    }
+    if let Some(board) = root.get("board") {
+        inventory.board = Board { vendor: text(board, "Manufacturer"), model: text(board, "Product"), version: text(board, "Version") };
+    }
+    if let Some(bios) = root.get("bios") {
+        inventory.bios = Bios { vendor: text(bios, "Manufacturer"), version: text(bios, "SMBIOSBIOSVersion"), date: text(bios, "ReleaseDate") };
+    }
+
+    let cpus = list("cpus");
+    let sum = |key: &str| cpus.iter().filter_map(|c| number(c, key)).sum::<u64>() as usize;
+    let mut caches = Vec::new();
+    for (name, key) in [("L2", "L2CacheSize"), ("L3", "L3CacheSize")] {
+        if let Some(kib) = cpus.first().and_then(|c| number(c, key)) {
+            caches.push(Cache { name: name.into(), size: kib * 1024, instances: cpus.len() });
+        }
+    }
+    inventory.cpu = CpuTopology {
+        sockets: cpus.len(),
+        cores: sum("NumberOfCores"),
+        threads: sum("NumberOfLogicalProcessors"),
+        max_mhz: cpus.first().and_then(|c| number(c, "MaxClockSpeed")).map(|m| m as u32),
+        caches,
+    };
+
+    inventory.memory.slots = root.get("slots").and_then(Value::as_u64).map(|n| n as usize);
+    inventory.memory.dimms = list("dimms")
+        .iter()
+        .filter_map(|d| {
+            Some(Dimm {
+                slot: text(d, "DeviceLocator").unwrap_or_default(),
+                size: number(d, "Capacity")?,
+                kind: number(d, "SMBIOSMemoryType").and_then(smbios_memory_type).map(String::from),
+                speed_mts: number(d, "ConfiguredClockSpeed").or_else(|| number(d, "Speed")).map(|s| s as u32),
+                manufacturer: text(d, "Manufacturer"),
+                part: text(d, "PartNumber"),
+            })
+        })
+        .collect();
+
+    inventory.gpus = list("gpus")
+        .iter()
+        .filter_map(|g| Some(Gpu { name: text(g, "Name")?, driver: text(g, "DriverVersion") }))
+        .collect();
+    inventory.drives = list("disks")
+        .iter()
+        .map(|d| {
+            let media = text(d, "MediaType").unwrap_or_default();
+            Drive {
+                name: text(d, "DeviceID").unwrap_or_default(),
+                model: text(d, "Model"),
+                size: number(d, "Size").unwrap_or(0),
+                bus: text(d, "InterfaceType"),
+                rotational: None,
+                removable: media.starts_with("Removable") || media.starts_with("External"),
+            }
+        })
+        .collect();
+    inventory
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+fn collect() -> Inventory {
+    Inventory::default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clean_and_sizes() {
+        assert_eq!(clean("  ASUSTeK COMPUTER INC.\n"), Some("ASUSTeK COMPUTER INC.".into()));
+        assert_eq!(clean("To Be Filled By O.E.M."), None);
+        assert_eq!(clean("Default string"), None);
+        assert_eq!(clean("\0"), None);
+        assert_eq!(parse_size("16 GB"), Some(16 << 30));
+        assert_eq!(parse_size("8192 MB"), Some(8 << 30));
+        assert_eq!(parse_size("48K"), Some(48 << 10));
+        assert_eq!(parse_size("No Module Installed"), None);
+        assert_eq!(parse_speed("3200 MT/s"), Some(3200));
+        assert_eq!(parse_speed("Unknown"), None);
+    }
+
+    #[test]
+    fn test_group_caches() {
+        let entry = |level: &str, kind: &str, size: &str, shared: &str| (level.into(), kind.into(), size.into(), shared.into());
+        // Two cores with two threads each: private L1/L2, one shared L3
+        let mut entries = Vec::new();
+        for core in ["0,2", "1,3", "0,2", "1,3"] {
+            entries.push(entry("1", "Data", "48K", core));
+            entries.push(entry("1", "Instruction", "32K", core));
+            entries.push(entry("2", "Unified", "2048K", core));
+            entries.push(entry("3", "Unified", "30720K", "0-3"));
+        }
+        let caches = group_caches(entries);
+        assert_eq!(caches.len(), 4);
+        assert_eq!(caches[0], Cache { name: "L1d".into(), size: 48 << 10, instances: 2 });
+        assert_eq!(caches[1].name, "L1i");
+        assert_eq!((caches[2].name.as_str(), caches[2].instances), ("L2", 2));
+        assert_eq!(caches[3], Cache { name: "L3".into(), size: 30 << 20, instances: 1 });
+    }
+
+    #[test]
+    fn test_parse_dmidecode() {
+        let output = "# dmidecode 3.5\nGetting SMBIOS data from sysfs.\n\n\
+            Handle 0x0011, DMI type 17, 92 bytes\nMemory Device\n\
+            \tSize: 16 GB\n\tLocator: DIMM A1\n\tType: DDR4\n\tSpeed: 3200 MT/s\n\
+            \tManufacturer: Samsung\n\tPart Number: M471A2K43DB1-CWE    \n\
+            \tConfigured Memory Speed: 2933 MT/s\n\n\
+            Handle 0x0012, DMI type 17, 92 bytes\nMemory Device\n\
+            \tSize: No Module Installed\n\tLocator: DIMM B1\n\tType: Unknown\n\n";
+        let memory = parse_dmidecode(output);
+        assert_eq!(memory.slots, Some(2));
+        assert_eq!(memory.dimms.len(), 1);
+        let dimm = &memory.dimms[0];
+        assert_eq!(dimm.slot, "DIMM A1");
+        assert_eq!(dimm.size, 16 << 30);
+        assert_eq!(dimm.kind.as_deref(), Some("DDR4"));
+        assert_eq!(dimm.speed_mts, Some(2933));
+        assert_eq!(dimm.part.as_deref(), Some("M471A2K43DB1-CWE"));
+    }
+
+    #[test]
+    fn test_pci_name() {
+        let ids = "# comment\n10de  NVIDIA Corporation\n\t2684  AD102 [GeForce RTX 4090]\n\t\t10de 167c  sub\n\
+                   1002  Advanced Micro Devices, Inc. [AMD/ATI]\n";
+        assert_eq!(pci_name(ids, "10de", "2684").as_deref(), Some("NVIDIA Corporation AD102 [GeForce RTX 4090]"));
+        assert_eq!(pci_name(ids, "10de", "ffff").as_deref(), Some("NVIDIA Corporation"));
+        assert_eq!(pci_name(ids, "8086", "46a6"), None);
+    }
+
+    #[test]
+    fn test_parse_sysctl() {
+        let cpu = parse_sysctl("hw.packages: 1\nhw.physicalcpu: 8\nhw.logicalcpu: 8\nhw.l1dcachesize: 65536\nhw.l2cachesize: 4194304\n");
+        assert_eq!((cpu.sockets, cpu.cores, cpu.threads), (1, 8, 8));
+        assert_eq!(cpu.caches.iter().map(|c| c.name.as_str()).collect::<Vec<_>>(), ["L1d", "L2"]);
+        assert_eq!(cpu.max_mhz, None);
+    }
+
+    #[test]
+    fn test_parse_system_profiler() {
+        let json = r#"{
+            "SPHardwareDataType": [{"machine_name": "MacBook Air", "machine_model": "Mac14,2", "boot_rom_version": "10151.41.12"}],
+            "SPMemoryDataType": [{"SPMemoryDataType": "16 GB", "dimm_manufacturer": "Hynix", "dimm_type": "LPDDR5"}],
+            "SPDisplaysDataType": [{"_name": "Apple M2", "sppci_model": "Apple M2"}],
+            "SPNVMeDataType": [{"_name": "Apple SSD Controller", "_items": [
+                {"_name": "APPLE SSD AP0512Z", "bsd_name": "disk0", "device_model": "APPLE SSD AP0512Z", "size_in_bytes": 500277790720}]}]
+        }"#;
+        let inv = parse_system_profiler(json);
+        assert_eq!(inv.system.model.as_deref(), Some("MacBook Air (Mac14,2)"));
+        assert_eq!(inv.bios.version.as_deref(), Some("10151.41.12"));
+        assert_eq!(inv.memory.dimms[0].size, 16 << 30);
+        assert_eq!(inv.memory.dimms[0].kind.as_deref(), Some("LPDDR5"));
+        assert_eq!(inv.gpus[0].name, "Apple M2");
+        assert_eq!(inv.drives[0].kind(), "NVMe SSD");
+        assert_eq!(inv.drives[0].size, 500_277_790_720);
+    }
+
+    #[test]
+    fn test_parse_windows() {
+        let json = r#"{"system":{"Manufacturer":"Dell Inc.","Model":"XPS 15 9520","SystemFamily":"XPS"},
+            "board":{"Manufacturer":"Dell Inc.","Product":"0RH1JY","Version":"A00"},
+            "bios":{"Manufacturer":"Dell Inc.","SMBIOSBIOSVersion":"1.14.0","ReleaseDate":"2023-06-07"},
+            "cpus":[{"NumberOfCores":14,"NumberOfLogicalProcessors":20,"MaxClockSpeed":2300,"L2CacheSize":11776,"L3CacheSize":24576}],
+            "slots":2,
+            "dimms":[{"DeviceLocator":"DIMM A","Capacity":17179869184,"ConfiguredClockSpeed":4800,"Speed":4800,"SMBIOSMemoryType":34,"Manufacturer":"Hynix","PartNumber":"HMCG78AEBSA095N"}],
+            "gpus":[{"Name":"NVIDIA GeForce RTX 3050 Ti Laptop GPU","DriverVersion":"31.0.15.3179"}],
+            "disks":[{"DeviceID":"\\\\.\\PHYSICALDRIVE0","Model":"KXG8AZNV1T02","Size":1024203640320,"InterfaceType":"SCSI","MediaType":"Fixed hard disk media"}]}"#;
+        let inv = parse_windows(json);
+        assert_eq!(inv.system.model.as_deref(), Some("XPS 15 9520"));
+        assert_eq!(inv.bios.date.as_deref(), Some("2023-06-07"));
+        assert_eq!((inv.cpu.sockets, inv.cpu.cores, inv.cpu.threads, inv.cpu.max_mhz), (1, 14, 20, Some(2300)));
+        assert_eq!(inv.cpu.caches[1], Cache { name: "L3".into(), size: 24 << 20, instances: 1 });
+        assert_eq!(inv.memory.slots, Some(2));
+        assert_eq!(inv.memory.dimms[0].kind.as_deref(), Some("DDR5"));
+        assert_eq!(inv.memory.dimms[0].speed_mts, Some(4800));
+        assert_eq!(inv.gpus[0].driver.as_deref(), Some("31.0.15.3179"));
+        assert_eq!(inv.drives[0].name, r"\\.\PHYSICALDRIVE0");
+        assert!(!inv.drives[0].removable);
+    }
+}
@@ -0,0 +1,115 @@
+use iced::mouse;
+use iced::widget::canvas::{self, Frame, Geometry, Path, Text};
+use iced::{Color, Point, Rectangle, Renderer, Size, Theme};
+
+use crate::history::WeeklyGrid;
+use crate::NERD_FONT_MONO;
+
+/// Colors needed by the heatmap from the active palette.
+#[derive(Debug, Clone, Copy)]
+pub struct HeatmapColors {
+    pub bg: Color,
+    /// Cells without data.
+    pub empty: Color,
+    /// 0% usage.
+    pub low: Color,
+    /// 100% usage.
+    pub high: Color,
+    pub label: Color,
+}
+
+/// Hour-of-day × day-of-week grid of average values (0–100), Monday first.
+/// Hovering a cell shows its day, hour and value.
+#[derive(Debug, Clone)]
+pub struct Heatmap {
+    pub grid: WeeklyGrid,
+    /// Short day names, Monday first.
+    pub days: [String; 7],
+    pub colors: HeatmapColors,
+}
+
+const LABEL_W: f32 = 36.0;
+const HEADER_H: f32 = 14.0;
+const FOOTER_H: f32 = 16.0;
+const GAP: f32 = 2.0;
+
+impl Heatmap {
+    fn cell_size(bounds: Size) -> Size {
+        Size::new(
+            ((bounds.width - LABEL_W) / 24.0).max(1.0),
+            ((bounds.height - HEADER_H - FOOTER_H) / 7.0).max(1.0),
+        )
+    }
+
+    /// The (day, hour) cell under `p`, if any.
+    fn cell_at(bounds: Size, p: Point) -> Option<(usize, usize)> {
+        let cell = Self::cell_size(bounds);
+        if p.x < LABEL_W || p.y < HEADER_H {
+            return None;
+        }
+        let hour = ((p.x - LABEL_W) / cell.width) as usize;
+        let day = ((p.y - HEADER_H) / cell.height) as usize;
+        (hour < 24 && day < 7).then_some((day, hour))
+    }
+
+    fn shade(&self, value: f32) -> Color {
+        let t = (value / 100.0).clamp(0.0, 1.0);
+        let (lo, hi) = (self.colors.low, self.colors.high);
+        Color::from_rgb(lo.r + (hi.r - lo.r) * t, lo.g + (hi.g - lo.g) * t, lo.b + (hi.b - lo.b) * t)
+    }
+}
+
+impl<Message: 'static> canvas::Program<Message> for Heatmap {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let c = &self.colors;
+        frame.fill(&Path::rectangle(Point::ORIGIN, bounds.size()), c.bg);
+
+        let cell = Self::cell_size(bounds.size());
+        let label = |content: String, position: Point| Text {
+            content,
+            position,
+            color: c.label,
+            size: iced::Pixels(9.0),
+            font: NERD_FONT_MONO,
+            ..Text::default()
+        };
+
+        for hour in (0..24).step_by(3) {
+            frame.fill_text(label(format!("{hour:02}"), Point::new(LABEL_W + hour as f32 * cell.width, 1.0)));
+        }
+        for (day, row) in self.grid.iter().enumerate() {
+            let y = HEADER_H + day as f32 * cell.height;
+            frame.fill_text(label(self.days[day].clone(), Point::new(0.0, y + (cell.height - 9.0) / 2.0)));
+            for (hour, value) in row.iter().enumerate() {
+                let x = LABEL_W + hour as f32 * cell.width;
+                let color = value.map_or(c.empty, |v| self.shade(v));
+                let rect = Path::rectangle(
+                    Point::new(x, y),
+                    Size::new((cell.width - GAP).max(1.0), (cell.height - GAP).max(1.0)),
+                );
+                frame.fill(&rect, color);
+            }
+        }
+
+        if let Some((day, hour)) = cursor.position_in(bounds).and_then(|p| Self::cell_at(bounds.size(), p)) {
+            let value = self.grid[day][hour].map_or_else(|| "—".to_string(), |v| format!("{v:.0}%"));
+            let footer_y = HEADER_H + 7.0 * cell.height + 3.0;
+            frame.fill_text(label(
+                format!("{} {hour:02}:00–{:02}:00  {value}", self.days[day], (hour + 1) % 24),
+                Point::new(LABEL_W, footer_y),
+            ));
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
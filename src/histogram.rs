@@ -0,0 +1,171 @@
+//! Value distribution of a history metric.
+//!
+//! Bins raw samples into equal-width buckets and marks the p50/p95/p99
+//! percentiles, so "steadily 50%" and "idle with spikes to 100%" look
+//! different even when their averages match.
+
+use iced::mouse;
+use iced::widget::canvas::{self, Frame, Geometry, Path, Stroke, Text};
+use iced::{Color, Point, Rectangle, Renderer, Size, Theme};
+
+use crate::chart::ChartColors;
+use crate::NERD_FONT_MONO;
+
+const BINS: usize = 40;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Distribution {
+    /// Sample counts per bin, spanning `0..max`.
+    pub bins: Vec<u32>,
+    pub max: f32,
+    pub samples: usize,
+    pub p50: f32,
+    pub p95: f32,
+    pub p99: f32,
+}
+
+/// Nearest-rank percentile of an ascending slice; `q` in 0–100.
+fn percentile(sorted: &[f32], q: f32) -> f32 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = ((q / 100.0) * sorted.len() as f32).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+impl Distribution {
+    /// Bin `values` over `0..max`; with `max` of `None` the range ends at
+    /// the largest value.
+    pub fn new(mut values: Vec<f32>, max: Option<f32>) -> Self {
+        values.retain(|v| v.is_finite());
+        if values.is_empty() {
+            return Self::default();
+        }
+        values.sort_by(f32::total_cmp);
+        let max = max.unwrap_or_else(|| values[values.len() - 1]).max(f32::EPSILON);
+        let mut bins = vec![0u32; BINS];
+        for &v in &values {
+            let i = ((v / max) * BINS as f32) as usize;
+            bins[i.min(BINS - 1)] += 1;
+        }
+        Self {
+            bins,
+            max,
+            samples: values.len(),
+            p50: percentile(&values, 50.0),
+            p95: percentile(&values, 95.0),
+            p99: percentile(&values, 99.0),
+        }
+    }
+}
+
+/// Bar chart of a `Distribution` with labelled percentile markers.
+#[derive(Debug, Clone)]
+pub struct HistogramChart {
+    pub dist: Distribution,
+    pub color: Color,
+    pub marker: Color,
+    pub unit: String,
+    pub colors: ChartColors,
+}
+
+const PAD_LEFT: f32 = 8.0;
+const PAD_RIGHT: f32 = 8.0;
+const PAD_TOP: f32 = 16.0;
+const PAD_BOTTOM: f32 = 16.0;
+
+impl<Message: 'static> canvas::Program<Message> for HistogramChart {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let c = &self.colors;
+        frame.fill(&Path::rectangle(Point::ORIGIN, bounds.size()), c.bg);
+
+        let d = &self.dist;
+        let plot_w = (bounds.width - PAD_LEFT - PAD_RIGHT).max(1.0);
+        let plot_h = (bounds.height - PAD_TOP - PAD_BOTTOM).max(1.0);
+        let base_y = PAD_TOP + plot_h;
+        let label = |content: String, position: Point, color: Color| Text {
+            content,
+            position,
+            color,
+            size: iced::Pixels(9.0),
+            font: NERD_FONT_MONO,
+            ..Text::default()
+        };
+
+        let peak = d.bins.iter().copied().max().unwrap_or(0);
+        if peak > 0 {
+            let bar_w = plot_w / d.bins.len() as f32;
+            for (i, &count) in d.bins.iter().enumerate() {
+                if count == 0 {
+                    continue;
+                }
+                // Square root scale keeps rare spikes visible next to a tall idle bar
+                let h = plot_h * (count as f32 / peak as f32).sqrt();
+                let rect = Path::rectangle(
+                    Point::new(PAD_LEFT + i as f32 * bar_w, base_y - h),
+                    Size::new((bar_w - 1.0).max(1.0), h),
+                );
+                frame.fill(&rect, self.color);
+            }
+
+            for (name, value) in [("p50", d.p50), ("p95", d.p95), ("p99", d.p99)] {
+                let x = PAD_LEFT + plot_w * (value / d.max).clamp(0.0, 1.0);
+                let line = Path::line(Point::new(x, PAD_TOP), Point::new(x, base_y));
+                frame.stroke(&line, Stroke::default().with_color(self.marker).with_width(1.0));
+                frame.fill_text(label(format!("{name} {value:.1}{}", self.unit), Point::new(x + 2.0, 2.0), self.marker));
+            }
+        }
+
+        let axis = Path::line(Point::new(PAD_LEFT, base_y), Point::new(PAD_LEFT + plot_w, base_y));
+        frame.stroke(&axis, Stroke::default().with_color(c.border).with_width(1.0));
+        frame.fill_text(label(format!("0{}", self.unit), Point::new(PAD_LEFT, base_y + 3.0), c.label));
+        let max_label = format!("{:.0}{}", d.max, self.unit);
+        frame.fill_text(label(
+            max_label.clone(),
+            Point::new(PAD_LEFT + plot_w - max_label.chars().count() as f32 * 5.5, base_y + 3.0),
+            c.label,
+        ));
+
+        vec![frame.into_geometry()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distribution() {
+        // 90 idle samples and 10 spikes to 100%
+        let mut values = vec![2.0; 90];
+        values.extend([100.0; 10]);
+        let d = Distribution::new(values, Some(100.0));
+        assert_eq!(d.samples, 100);
+        assert_eq!(d.bins[0], 90);
+        assert_eq!(d.bins[BINS - 1], 10);
+        assert_eq!((d.p50, d.p95, d.p99), (2.0, 100.0, 100.0));
+
+        let steady = Distribution::new(vec![50.0; 100], Some(100.0));
+        assert_eq!((steady.p50, steady.p95, steady.p99), (50.0, 50.0, 50.0));
+        assert_eq!(steady.bins[BINS / 2], 100);
+    }
+
+    #[test]
+    fn test_percentile() {
+        let sorted: Vec<f32> = (1..=100).map(|v| v as f32).collect();
+        assert_eq!(percentile(&sorted, 50.0), 50.0);
+        assert_eq!(percentile(&sorted, 99.0), 99.0);
+        assert_eq!(percentile(&[], 50.0), 0.0);
+        assert_eq!(Distribution::new(vec![f32::NAN], None), Distribution::default());
+    }
+}
@@ -1,7 +1,105 @@
 use rusqlite::{Connection, params};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, SyncSender, TrySendError};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
 
-use crate::metrics::Snapshot;
+use crate::locale;
+use crate::metrics::{RecordedMetrics, Snapshot};
+
+/// Drive usage is sampled this often; it changes slowly and is only used
+/// for trends.
+const DISK_SAMPLE_INTERVAL_SECS: f64 = 300.0;
+
+/// With adaptive recording, rows are written this far apart while CPU is
+/// below the threshold.
+pub const ADAPTIVE_IDLE_INTERVAL_SECS: f64 = 10.0;
+
+/// Which snapshots reach the database. Without a threshold every snapshot
+/// is written; with one, every snapshot while CPU is at or above it and one
+/// per `ADAPTIVE_IDLE_INTERVAL_SECS` otherwise.
+#[derive(Debug, Default)]
+pub struct RecordingPolicy {
+    pub threshold: Option<f32>,
+    last_recorded: f64,
+    /// Network bytes of the snapshots skipped since the last written one.
+    skipped_rx: u64,
+    skipped_tx: u64,
+}
+
+impl RecordingPolicy {
+    pub fn new(threshold: Option<f32>) -> Self {
+        Self { threshold, ..Self::default() }
+    }
+
+    /// The snapshot to write for `snap`, if any: `snap` itself, or a copy
+    /// that also carries the traffic of the snapshots skipped before it,
+    /// so byte totals over a range stay exact.
+    pub fn admit(&mut self, snap: &Arc<Snapshot>) -> Option<Arc<Snapshot>> {
+        if !self.should_record(snap.timestamp, snap.cpu_usage_global) {
+            self.skipped_rx += snap.net_rx_bytes;
+            self.skipped_tx += snap.net_tx_bytes;
+            return None;
+        }
+        if self.skipped_rx == 0 && self.skipped_tx == 0 {
+            return Some(Arc::clone(snap));
+        }
+        let mut carried = Snapshot::clone(snap);
+        carried.net_rx_bytes += std::mem::take(&mut self.skipped_rx);
+        carried.net_tx_bytes += std::mem::take(&mut self.skipped_tx);
+        Some(Arc::new(carried))
+    }
+
+    /// Whether to write the snapshot taken at `timestamp`.
+    pub fn should_record(&mut self, timestamp: f64, cpu: f32) -> bool {
+        // Ticks drift by a few milliseconds; half a second of slack keeps
+        // idle rows on a steady 10 s cadence instead of every 11 s
+        let due = timestamp - self.last_recorded >= ADAPTIVE_IDLE_INTERVAL_SECS - 0.5
+            || timestamp < self.last_recorded;
+        let record = match self.threshold {
+            Some(threshold) => cpu >= threshold || due,
+            None => true,
+        };
+        if record {
+            self.last_recorded = timestamp;
+        }
+        record
+    }
+}
+
+/// A used/total byte pair at a point in time, for capacity trends.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UsageSample {
+    pub timestamp: f64,
+    pub used: u64,
+    pub total: u64,
+}
+
+/// A metric whose raw samples can be read back for distributions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoryMetric {
+    Cpu,
+    /// Memory used, as percent of total.
+    Memory,
+    Power,
+}
+
+impl HistoryMetric {
+    pub const ALL: [HistoryMetric; 3] = [HistoryMetric::Cpu, HistoryMetric::Memory, HistoryMetric::Power];
+
+    fn sql_expr(self) -> &'static str {
+        match self {
+            HistoryMetric::Cpu => "cpu",
+            HistoryMetric::Memory => "CASE WHEN mem_total > 0 THEN mem_used * 100.0 / mem_total END",
+            HistoryMetric::Power => "power",
+        }
+    }
+}
+
+/// Average per weekday (Monday first) and hour of day; `None` where no
+/// samples exist.
+pub type WeeklyGrid = [[Option<f32>; 24]; 7];
 
 /// Stored point for a single metric at a given time.
 #[derive(Clone, Debug)]
@@ -10,8 +108,57 @@ pub struct HistoryPoint {
     pub cpu: f32,
     pub mem_used: u64,
     pub mem_total: u64,
+    /// Bytes per second; rows written apart carry the traffic in between.
     pub net_rx: u64,
     pub net_tx: u64,
+    /// Watts; `None` for rows recorded without a power reading.
+    pub power: Option<f32>,
+    /// Lowest and highest values behind a downsampled point; `None` for
+    /// raw rows.
+    pub range: Option<BucketRange>,
+}
+
+/// Extremes of the rows averaged into one downsampled point, so that a
+/// short spike stays visible over long ranges.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct BucketRange {
+    pub cpu: (f32, f32),
+    /// Memory used, as percent of total.
+    pub mem_percent: (f32, f32),
+    pub net_rx: (u64, u64),
+    pub net_tx: (u64, u64),
+    pub power: Option<(f32, f32)>,
+}
+
+/// Aggregate statistics over a time range, computed in SQL over raw rows.
+/// Averages weigh each row by the time it stands for, so the denser rows
+/// of busy periods don't outweigh idle ones.
+#[derive(Clone, Debug, Default)]
+pub struct HistorySummary {
+    pub samples: u64,
+    pub first_ts: f64,
+    pub last_ts: f64,
+    pub cpu_avg: f32,
+    pub cpu_max: f32,
+    /// Memory usage as percent of total.
+    pub mem_avg: f32,
+    pub mem_max: f32,
+    /// Bytes transferred over the range (rows store deltas since the
+    /// previous row).
+    pub net_rx_total: u64,
+    pub net_tx_total: u64,
+    /// Highest rate, in bytes per second.
+    pub net_rx_peak: u64,
+    pub net_tx_peak: u64,
+}
+
+/// A logged warning or critical event, kept so History charts can mark it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StoredEvent {
+    pub timestamp: f64,
+    pub critical: bool,
+    /// The event as shown in the log: icon and message.
+    pub text: String,
 }
 
 /// Persistent error state for the history subsystem.
@@ -21,6 +168,8 @@ pub enum HistoryError {
     InitFailed(String),
     /// A write (INSERT/DELETE) failed.
     WriteFailed(String),
+    /// The writer thread fell behind and queued snapshots were discarded.
+    Dropped(usize),
 }
 
 impl std::fmt::Display for HistoryError {
@@ -28,23 +177,66 @@ impl std::fmt::Display for HistoryError {
         match self {
             HistoryError::InitFailed(e) => write!(f, "History DB init failed: {e}"),
             HistoryError::WriteFailed(e) => write!(f, "History write failed: {e}"),
+            HistoryError::Dropped(n) => write!(f, "History writes are falling behind; {n} snapshots dropped"),
         }
     }
 }
 
+/// Why a requested history window can't be shown.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RangeError {
+    /// The start is not before the end.
+    Empty,
+    /// The window starts in the future.
+    Future,
+    /// The whole window is older than the retention period.
+    Expired,
+}
+
+/// Clamp a requested window to the part that can hold data, from the
+/// oldest retained sample to now.
+pub fn clamp_range(from: f64, to: f64, now: f64, retention_secs: f64) -> Result<(f64, f64), RangeError> {
+    if from >= to {
+        Err(RangeError::Empty)
+    } else if from >= now {
+        Err(RangeError::Future)
+    } else if to <= now - retention_secs {
+        Err(RangeError::Expired)
+    } else {
+        Ok((from.max(now - retention_secs), to.min(now)))
+    }
+}
+
 pub struct History {
     conn: Option<Connection>,
     /// How many seconds of history to keep (default: 24h)
     retention_secs: f64,
     /// Timestamp of last prune operation
     last_prune_time: f64,
+    /// Timestamp of the last per-drive usage sample
+    last_disk_sample: f64,
+    /// Timestamp of the last row written, for the next row's `span`
+    last_written: Option<f64>,
     /// Last error encountered, exposed to the UI for user feedback.
     pub last_error: Option<HistoryError>,
+    /// Where a corrupt database was moved before starting a fresh one.
+    pub recovered_backup: Option<PathBuf>,
+    /// Optional groups written; the others are stored as zero or NULL.
+    pub recorded: RecordedMetrics,
 }
 
 impl History {
     pub fn open() -> Self {
-        let path = Self::db_path();
+        Self::open_path(&Self::db_path(), true)
+    }
+
+    /// Another connection to the database `open` has already checked,
+    /// without walking every page a second time.
+    pub fn open_checked_elsewhere() -> Self {
+        Self::open_path(&Self::db_path(), false)
+    }
+
+    fn open_path(path: &Path, check: bool) -> Self {
         if let Some(parent) = path.parent() {
             let _ = std::fs::create_dir_all(parent);
         }
@@ -56,56 +248,89 @@ impl History {
             let _ = std::fs::set_permissions(parent, std::fs::Permissions::from_mode(0o700));
         }
 
-        let conn = match Connection::open(&path) {
+        let mut recovered_backup = None;
+        let mut result = Self::init_connection(path).map_err(|e| (is_corruption(&e), e.to_string()));
+        // Opening only reads the schema; quick_check also walks the data pages
+        if let (Ok(conn), true) = (&result, check) {
+            match quick_check(conn) {
+                Ok(()) => {}
+                Err((true, problem)) => result = Err((true, problem)),
+                // A busy or unreadable file says nothing about its pages
+                Err((false, e)) => eprintln!("[digger] Could not check the history database: {e}"),
+            }
+        }
+        if let Err((true, problem)) = &result {
+            eprintln!("[digger] History database is corrupt ({problem}), starting a fresh one");
+            recovered_backup = backup_corrupt(path);
+            result = Self::init_connection(path).map_err(|e| (false, e.to_string()));
+        }
+
+        let conn = match result {
             Ok(c) => c,
-            Err(e) => {
+            Err((_, e)) => {
                 eprintln!("[digger] Failed to open history database: {e}");
                 return Self {
                     conn: None,
                     retention_secs: 86400.0,
                     last_prune_time: 0.0,
-                    last_error: Some(HistoryError::InitFailed(e.to_string())),
+                    last_disk_sample: 0.0,
+                    last_written: None,
+                    last_error: Some(HistoryError::InitFailed(e)),
+                    recovered_backup,
+                    recorded: RecordedMetrics::default(),
                 };
             }
         };
 
-        if let Err(e) = conn.execute_batch(
-            "PRAGMA journal_mode=WAL;
-            PRAGMA synchronous=NORMAL;
-            CREATE TABLE IF NOT EXISTS snapshots (
-                timestamp REAL PRIMARY KEY,
-                cpu REAL NOT NULL,
-                mem_used INTEGER NOT NULL,
-                mem_total INTEGER NOT NULL,
-                net_rx INTEGER NOT NULL,
-                net_tx INTEGER NOT NULL
-            );
-            CREATE INDEX IF NOT EXISTS idx_ts ON snapshots(timestamp);",
-        ) {
-            eprintln!("[digger] Failed to initialize history tables: {e}");
-            return Self {
-                conn: None,
-                retention_secs: 86400.0,
-                last_prune_time: 0.0,
-                last_error: Some(HistoryError::InitFailed(e.to_string())),
-            };
-        }
-
         // Set restrictive permissions on the DB file itself (Unix only)
         #[cfg(unix)]
         {
             use std::os::unix::fs::PermissionsExt;
-            let _ = std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600));
+            let _ = std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600));
         }
 
         Self {
             conn: Some(conn),
             retention_secs: 86400.0,
             last_prune_time: 0.0,
+            last_disk_sample: 0.0,
+            last_written: None,
             last_error: None,
+            recovered_backup,
+            recorded: RecordedMetrics::default(),
         }
     }
 
+    /// Move the database aside and start an empty one, the way out when it
+    /// can't be opened at all. `recovered_backup` names the old file.
+    pub fn reset() -> Self {
+        let path = Self::db_path();
+        let backup = if path.exists() { backup_corrupt(&path) } else { None };
+        let mut history = Self::open_path(&path, true);
+        history.recovered_backup = history.recovered_backup.take().or(backup);
+        history
+    }
+
+    fn init_connection(path: &Path) -> rusqlite::Result<Connection> {
+        let conn = Connection::open(path)?;
+        conn.execute_batch(
+            "PRAGMA journal_mode=WAL;
+            PRAGMA synchronous=NORMAL;
+            CREATE TABLE IF NOT EXISTS snapshots (
+                timestamp REAL PRIMARY KEY,
+                cpu REAL NOT NULL,
+                mem_used INTEGER NOT NULL,
+                mem_total INTEGER NOT NULL,
+                net_rx INTEGER NOT NULL,
+                net_tx INTEGER NOT NULL,
+                power REAL
+            );
+            CREATE INDEX IF NOT EXISTS idx_ts ON snapshots(timestamp);",
+        )?;
+        migrate(&conn)?;
+        Ok(conn)
+    }
+
     fn db_path() -> PathBuf {
         dirs::data_local_dir()
             .unwrap_or_else(|| PathBuf::from("."))
@@ -113,11 +338,31 @@ impl History {
             .join("history.db")
     }
 
+    /// Fold the WAL back into the main database file so a crash after a clean
+    /// shutdown cannot lose or tear recent writes.
+    pub fn checkpoint(&mut self) {
+        let Some(conn) = &self.conn else { return };
+        if let Err(e) = conn.execute_batch("PRAGMA wal_checkpoint(TRUNCATE);") {
+            eprintln!("[digger] Failed to checkpoint history database: {e}");
+        }
+    }
+
+    /// Keep `hours` of history; older rows go at the next prune.
+    pub fn set_retention_hours(&mut self, hours: u64) {
+        self.retention_secs = hours as f64 * 3600.0;
+    }
+
+    /// How far back rows are kept, in seconds.
+    pub fn retention_secs(&self) -> f64 {
+        self.retention_secs
+    }
+
     /// Returns true if the history backend is operational.
     pub fn is_available(&self) -> bool {
         self.conn.is_some()
     }
 
+    #[cfg(test)]
     pub fn record(&mut self, snap: &Snapshot) {
         self.record_batch(&[snap]);
     }
@@ -135,17 +380,29 @@ impl History {
         }
 
         let mut any_error = false;
+        let mut last_written = self.last_written;
         for snap in snapshots {
+            // The time since the previous row, which this one stands for;
+            // gaps longer than any recording cadence are time Digger wasn't running
+            let span = last_written
+                .map(|prev| snap.timestamp - prev)
+                .filter(|&span| span > 0.0)
+                .map(|span| span.min(ADAPTIVE_IDLE_INTERVAL_SECS));
+            last_written = Some(snap.timestamp);
             if let Err(e) = conn.execute(
-                "INSERT OR REPLACE INTO snapshots (timestamp, cpu, mem_used, mem_total, net_rx, net_tx)
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                "INSERT OR REPLACE INTO snapshots (timestamp, cpu, mem_used, mem_total, net_rx, net_tx, power, swap_used, swap_total, span)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
                 params![
                     snap.timestamp,
                     snap.cpu_usage_global,
                     snap.memory_used,
                     snap.memory_total,
-                    snap.net_rx_bytes,
-                    snap.net_tx_bytes,
+                    if self.recorded.network { snap.net_rx_bytes } else { 0 },
+                    if self.recorded.network { snap.net_tx_bytes } else { 0 },
+                    snap.power.filter(|_| self.recorded.power).map(|p| p.watts),
+                    snap.swap_used,
+                    snap.swap_total,
+                    span,
                 ],
             ) {
                 eprintln!("[digger] Failed to record snapshot: {e}");
@@ -153,6 +410,23 @@ impl History {
                 any_error = true;
                 break;
             }
+            if self.recorded.disk_usage && snap.timestamp - self.last_disk_sample >= DISK_SAMPLE_INTERVAL_SECS {
+                self.last_disk_sample = snap.timestamp;
+                for disk in snap.disks.iter() {
+                    if let Err(e) = conn.execute(
+                        "INSERT OR REPLACE INTO disk_usage (timestamp, mount, used, total) VALUES (?1, ?2, ?3, ?4)",
+                        params![snap.timestamp, disk.mount, disk.total.saturating_sub(disk.available), disk.total],
+                    ) {
+                        eprintln!("[digger] Failed to record disk usage: {e}");
+                        self.last_error = Some(HistoryError::WriteFailed(e.to_string()));
+                        any_error = true;
+                        break;
+                    }
+                }
+                if any_error {
+                    break;
+                }
+            }
         }
 
         let _ = if any_error {
@@ -162,6 +436,7 @@ impl History {
         };
 
         if !any_error {
+            self.last_written = last_written;
             // Clear error on success
             if self.last_error.is_some() {
                 self.last_error = None;
@@ -176,7 +451,8 @@ impl History {
                 if let Err(e) = conn.execute(
                     "DELETE FROM snapshots WHERE timestamp < ?1",
                     params![cutoff],
-                ) {
+                ).and_then(|_| conn.execute("DELETE FROM disk_usage WHERE timestamp < ?1", params![cutoff]))
+                .and_then(|_| conn.execute("DELETE FROM events WHERE timestamp < ?1", params![cutoff])) {
                     eprintln!("[digger] Failed to prune old history: {e}");
                     self.last_error = Some(HistoryError::WriteFailed(e.to_string()));
                 }
@@ -184,11 +460,48 @@ impl History {
         }
     }
 
+    /// Store logged events in one transaction.
+    pub fn record_events(&mut self, events: &[StoredEvent]) {
+        let Some(conn) = &mut self.conn else { return };
+        if events.is_empty() { return; }
+        let result = conn.transaction().and_then(|tx| {
+            for event in events {
+                tx.execute(
+                    "INSERT INTO events (timestamp, critical, text) VALUES (?1, ?2, ?3)",
+                    params![event.timestamp, event.critical, event.text],
+                )?;
+            }
+            tx.commit()
+        });
+        if let Err(e) = result {
+            eprintln!("[digger] Failed to record events: {e}");
+            self.last_error = Some(HistoryError::WriteFailed(e.to_string()));
+        }
+    }
+
+    /// Events logged within a time range, oldest first.
+    pub fn events(&self, from: f64, to: f64) -> Vec<StoredEvent> {
+        let Some(conn) = &self.conn else { return Vec::new() };
+        let result = conn
+            .prepare("SELECT timestamp, critical, text FROM events WHERE timestamp >= ?1 AND timestamp <= ?2 ORDER BY timestamp ASC")
+            .and_then(|mut stmt| {
+                stmt.query_map(params![from, to], |row| {
+                    Ok(StoredEvent { timestamp: row.get(0)?, critical: row.get(1)?, text: row.get(2)? })
+                })?
+                .collect::<rusqlite::Result<Vec<_>>>()
+            });
+        result.unwrap_or_else(|e| {
+            eprintln!("[digger] Failed to load events: {e}");
+            Vec::new()
+        })
+    }
+
     pub fn load_range(&self, from: f64, to: f64) -> Vec<HistoryPoint> {
         let Some(conn) = &self.conn else { return Vec::new() };
 
         let mut stmt = match conn.prepare(
-            "SELECT timestamp, cpu, mem_used, mem_total, net_rx, net_tx
+            "SELECT timestamp, cpu, mem_used, mem_total,
+                CAST(net_rx / COALESCE(span, 1.0) AS INTEGER), CAST(net_tx / COALESCE(span, 1.0) AS INTEGER), power
              FROM snapshots WHERE timestamp >= ?1 AND timestamp <= ?2
              ORDER BY timestamp ASC",
         ) {
@@ -207,6 +520,8 @@ impl History {
                 mem_total: row.get(3)?,
                 net_rx: row.get(4)?,
                 net_tx: row.get(5)?,
+                power: row.get(6)?,
+                range: None,
             })
         });
         match result {
@@ -219,7 +534,9 @@ impl History {
     }
 
     /// Load history with DB-side downsampling using bucket averaging.
-    /// Returns at most `max_points` data points by grouping into time buckets.
+    /// Returns at most `max_points` data points by grouping into time buckets,
+    /// each carrying the minimum and maximum of its bucket. Averages are
+    /// weighted by the time each row stands for.
     pub fn load_range_downsampled(&self, from: f64, to: f64, max_points: usize) -> Vec<HistoryPoint> {
         let Some(conn) = &self.conn else { return Vec::new() };
         if max_points == 0 {
@@ -234,11 +551,17 @@ impl History {
         // Use SQL to bucket and average
         let mut stmt = match conn.prepare(
             "SELECT
-                AVG(timestamp), AVG(cpu),
-                CAST(AVG(mem_used) AS INTEGER), CAST(AVG(mem_total) AS INTEGER),
-                CAST(AVG(net_rx) AS INTEGER), CAST(AVG(net_tx) AS INTEGER)
-             FROM snapshots
-             WHERE timestamp >= ?1 AND timestamp <= ?2
+                AVG(timestamp), SUM(cpu * w) / SUM(w),
+                CAST(SUM(mem_used * w) / SUM(w) AS INTEGER), CAST(SUM(mem_total * w) / SUM(w) AS INTEGER),
+                CAST(SUM(net_rx) / SUM(w) AS INTEGER), CAST(SUM(net_tx) / SUM(w) AS INTEGER),
+                SUM(power * w) / SUM(CASE WHEN power IS NOT NULL THEN w END),
+                MIN(cpu), MAX(cpu),
+                MIN(CASE WHEN mem_total > 0 THEN mem_used * 100.0 / mem_total END),
+                MAX(CASE WHEN mem_total > 0 THEN mem_used * 100.0 / mem_total END),
+                CAST(MIN(net_rx / w) AS INTEGER), CAST(MAX(net_rx / w) AS INTEGER),
+                CAST(MIN(net_tx / w) AS INTEGER), CAST(MAX(net_tx / w) AS INTEGER),
+                MIN(power), MAX(power)
+             FROM (SELECT *, COALESCE(span, 1.0) AS w FROM snapshots WHERE timestamp >= ?1 AND timestamp <= ?2)
              GROUP BY CAST((timestamp - ?1) / ?3 AS INTEGER)
              ORDER BY 1 ASC",
         ) {
@@ -257,6 +580,17 @@ impl History {
                 mem_total: row.get(3)?,
                 net_rx: row.get(4)?,
                 net_tx: row.get(5)?,
+                power: row.get(6)?,
+                range: Some(BucketRange {
+                    cpu: (row.get(7)?, row.get(8)?),
+                    mem_percent: (
+                        row.get::<_, Option<f32>>(9)?.unwrap_or(0.0),
+                        row.get::<_, Option<f32>>(10)?.unwrap_or(0.0),
+                    ),
+                    net_rx: (row.get(11)?, row.get(12)?),
+                    net_tx: (row.get(13)?, row.get(14)?),
+                    power: row.get::<_, Option<f32>>(15)?.zip(row.get::<_, Option<f32>>(16)?),
+                }),
             })
         });
         match result {
@@ -268,9 +602,109 @@ impl History {
         }
     }
 
-    pub fn load_last_n_seconds_downsampled(&self, seconds: f64, max_points: usize) -> Vec<HistoryPoint> {
-        let now = chrono::Utc::now().timestamp_millis() as f64 / 1000.0;
-        self.load_range_downsampled(now - seconds, now, max_points)
+    /// Swap usage since `from`, oldest first. Rows recorded before swap was
+    /// stored are skipped.
+    pub fn swap_usage(&self, from: f64) -> Vec<UsageSample> {
+        let Some(conn) = &self.conn else { return Vec::new() };
+        usage_query(
+            conn,
+            "SELECT timestamp, swap_used, swap_total FROM snapshots
+             WHERE timestamp >= ?1 AND swap_total > 0 ORDER BY timestamp ASC",
+            params![from],
+        )
+    }
+
+    /// Usage of the drive mounted at `mount` since `from`, oldest first.
+    pub fn disk_usage(&self, mount: &str, from: f64) -> Vec<UsageSample> {
+        let Some(conn) = &self.conn else { return Vec::new() };
+        usage_query(
+            conn,
+            "SELECT timestamp, used, total FROM disk_usage
+             WHERE mount = ?2 AND timestamp >= ?1 ORDER BY timestamp ASC",
+            params![from, mount],
+        )
+    }
+
+    /// Raw values of `metric` within a time range, skipping rows without one.
+    pub fn values(&self, metric: HistoryMetric, from: f64, to: f64) -> Vec<f32> {
+        let Some(conn) = &self.conn else { return Vec::new() };
+        let sql = format!(
+            "SELECT v FROM (SELECT {} AS v FROM snapshots WHERE timestamp >= ?1 AND timestamp <= ?2) WHERE v IS NOT NULL",
+            metric.sql_expr(),
+        );
+        let result = conn.prepare(&sql).and_then(|mut stmt| {
+            stmt.query_map(params![from, to], |row| row.get::<_, f64>(0).map(|v| v as f32))?
+                .collect::<rusqlite::Result<Vec<_>>>()
+        });
+        result.unwrap_or_else(|e| {
+            eprintln!("[digger] Failed to load history values: {e}");
+            Vec::new()
+        })
+    }
+
+    /// Average CPU by local weekday and hour since `from`, weighted by the
+    /// time each row stands for.
+    pub fn cpu_by_weekday_hour(&self, from: f64) -> WeeklyGrid {
+        let mut grid: WeeklyGrid = [[None; 24]; 7];
+        let Some(conn) = &self.conn else { return grid };
+        // strftime('%w') counts from Sunday = 0
+        let result = conn.prepare(
+            "SELECT CAST(strftime('%w', timestamp, 'unixepoch', 'localtime') AS INTEGER),
+                CAST(strftime('%H', timestamp, 'unixepoch', 'localtime') AS INTEGER),
+                SUM(cpu * COALESCE(span, 1.0)) / SUM(COALESCE(span, 1.0))
+             FROM snapshots WHERE timestamp >= ?1
+             GROUP BY 1, 2",
+        ).and_then(|mut stmt| {
+            stmt.query_map(params![from], |row| Ok((row.get::<_, usize>(0)?, row.get::<_, usize>(1)?, row.get::<_, f64>(2)?)))?
+                .collect::<rusqlite::Result<Vec<_>>>()
+        });
+        match result {
+            Ok(rows) => {
+                for (weekday, hour, avg) in rows {
+                    if weekday < 7 && hour < 24 {
+                        grid[(weekday + 6) % 7][hour] = Some(avg as f32);
+                    }
+                }
+            }
+            Err(e) => eprintln!("[digger] Failed to load CPU heatmap: {e}"),
+        }
+        grid
+    }
+
+    /// Summary statistics for a time range, or `None` when it holds no data.
+    pub fn summary(&self, from: f64, to: f64) -> Option<HistorySummary> {
+        let conn = self.conn.as_ref()?;
+        let result = conn.query_row(
+            "SELECT COUNT(*), MIN(timestamp), MAX(timestamp), SUM(cpu * w) / SUM(w), MAX(cpu),
+                SUM(mem_used * 100.0 / mem_total * w) / SUM(w), MAX(mem_used * 100.0 / mem_total),
+                SUM(net_rx), SUM(net_tx), CAST(MAX(net_rx / w) AS INTEGER), CAST(MAX(net_tx / w) AS INTEGER)
+             FROM (SELECT *, COALESCE(span, 1.0) AS w FROM snapshots
+                WHERE timestamp >= ?1 AND timestamp <= ?2 AND mem_total > 0)",
+            params![from, to],
+            |row| {
+                Ok(HistorySummary {
+                    samples: row.get(0)?,
+                    first_ts: row.get::<_, Option<f64>>(1)?.unwrap_or(0.0),
+                    last_ts: row.get::<_, Option<f64>>(2)?.unwrap_or(0.0),
+                    cpu_avg: row.get::<_, Option<f64>>(3)?.unwrap_or(0.0) as f32,
+                    cpu_max: row.get::<_, Option<f64>>(4)?.unwrap_or(0.0) as f32,
+                    mem_avg: row.get::<_, Option<f64>>(5)?.unwrap_or(0.0) as f32,
+                    mem_max: row.get::<_, Option<f64>>(6)?.unwrap_or(0.0) as f32,
+                    net_rx_total: row.get::<_, Option<i64>>(7)?.unwrap_or(0) as u64,
+                    net_tx_total: row.get::<_, Option<i64>>(8)?.unwrap_or(0) as u64,
+                    net_rx_peak: row.get::<_, Option<i64>>(9)?.unwrap_or(0) as u64,
+                    net_tx_peak: row.get::<_, Option<i64>>(10)?.unwrap_or(0) as u64,
+                })
+            },
+        );
+        match result {
+            Ok(summary) if summary.samples > 0 => Some(summary),
+            Ok(_) => None,
+            Err(e) => {
+                eprintln!("[digger] Failed to summarize history: {e}");
+                None
+            }
+        }
     }
 
     /// Export history within a time range to CSV format.
@@ -278,9 +712,9 @@ impl History {
     pub fn export_csv(&self, from: f64, to: f64) -> String {
         let Some(conn) = &self.conn else { return String::new() };
 
-        let mut out = String::from("timestamp,cpu_percent,mem_used_bytes,mem_total_bytes,net_rx_bytes,net_tx_bytes\n");
+        let mut out = String::from("timestamp,time,cpu_percent,mem_used_bytes,mem_total_bytes,net_rx_bytes,net_tx_bytes,power_watts\n");
         let mut stmt = match conn.prepare(
-            "SELECT timestamp, cpu, mem_used, mem_total, net_rx, net_tx
+            "SELECT timestamp, cpu, mem_used, mem_total, net_rx, net_tx, power
              FROM snapshots WHERE timestamp >= ?1 AND timestamp <= ?2
              ORDER BY timestamp ASC",
         ) {
@@ -296,25 +730,42 @@ impl History {
                 row.get::<_, u64>(3)?,
                 row.get::<_, u64>(4)?,
                 row.get::<_, u64>(5)?,
+                row.get::<_, Option<f32>>(6)?,
             ))
         });
 
         if let Ok(rows) = rows {
             for row in rows.flatten() {
                 use std::fmt::Write;
-                let _ = writeln!(out, "{},{:.2},{},{},{},{}", row.0, row.1, row.2, row.3, row.4, row.5);
+                let power = row.6.map(|w| format!("{w:.2}")).unwrap_or_default();
+                let time = locale::iso_time(row.0);
+                let _ = writeln!(out, "{},{time},{:.2},{},{},{},{},{power}", row.0, row.1, row.2, row.3, row.4, row.5);
             }
         }
         out
     }
 
+    /// Export the latest `rows` snapshots to CSV, oldest first.
+    pub fn export_recent_csv(&self, rows: u32) -> String {
+        let from = self.conn.as_ref().and_then(|conn| {
+            conn.query_row(
+                "SELECT MIN(timestamp) FROM (SELECT timestamp FROM snapshots ORDER BY timestamp DESC LIMIT ?1)",
+                params![rows],
+                |row| row.get::<_, Option<f64>>(0),
+            )
+            .ok()
+            .flatten()
+        });
+        self.export_csv(from.unwrap_or(f64::MAX), f64::MAX)
+    }
+
     /// Export history within a time range to JSON format.
     /// Opt #12: Streams rows directly from the query.
     pub fn export_json(&self, from: f64, to: f64) -> String {
         let Some(conn) = &self.conn else { return String::from("[]") };
 
         let mut stmt = match conn.prepare(
-            "SELECT timestamp, cpu, mem_used, mem_total, net_rx, net_tx
+            "SELECT timestamp, cpu, mem_used, mem_total, net_rx, net_tx, power
              FROM snapshots WHERE timestamp >= ?1 AND timestamp <= ?2
              ORDER BY timestamp ASC",
         ) {
@@ -330,6 +781,7 @@ impl History {
                 row.get::<_, u64>(3)?,
                 row.get::<_, u64>(4)?,
                 row.get::<_, u64>(5)?,
+                row.get::<_, Option<f32>>(6)?,
             ))
         });
 
@@ -340,10 +792,11 @@ impl History {
                 use std::fmt::Write;
                 if !first { out.push_str(",\n"); }
                 first = false;
+                let power = row.6.map(|w| format!("{w:.2}")).unwrap_or_else(|| "null".into());
                 let _ = write!(
                     out,
-                    r#"  {{"timestamp":{:.3},"cpu":{:.2},"mem_used":{},"mem_total":{},"net_rx":{},"net_tx":{}}}"#,
-                    row.0, row.1, row.2, row.3, row.4, row.5,
+                    r#"  {{"timestamp":{:.3},"time":"{}","cpu":{:.2},"mem_used":{},"mem_total":{},"net_rx":{},"net_tx":{},"power":{power}}}"#,
+                    row.0, locale::iso_time(row.0), row.1, row.2, row.3, row.4, row.5,
                 );
             }
         }
@@ -352,9 +805,277 @@ impl History {
     }
 }
 
+impl Drop for History {
+    fn drop(&mut self) {
+        self.checkpoint();
+    }
+}
+
+fn usage_query(conn: &Connection, sql: &str, args: impl rusqlite::Params) -> Vec<UsageSample> {
+    let result = conn.prepare(sql).and_then(|mut stmt| {
+        stmt.query_map(args, |row| {
+            Ok(UsageSample { timestamp: row.get(0)?, used: row.get(1)?, total: row.get(2)? })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()
+    });
+    result.unwrap_or_else(|e| {
+        eprintln!("[digger] Failed to load usage history: {e}");
+        Vec::new()
+    })
+}
+
+/// Bring databases created by older versions up to the current schema.
+fn migrate(conn: &Connection) -> rusqlite::Result<()> {
+    let has_power = conn
+        .prepare("SELECT 1 FROM pragma_table_info('snapshots') WHERE name = 'power'")?
+        .exists([])?;
+    if !has_power {
+        conn.execute_batch("ALTER TABLE snapshots ADD COLUMN power REAL;")?;
+    }
+    let has_swap = conn
+        .prepare("SELECT 1 FROM pragma_table_info('snapshots') WHERE name = 'swap_used'")?
+        .exists([])?;
+    if !has_swap {
+        conn.execute_batch(
+            "ALTER TABLE snapshots ADD COLUMN swap_used INTEGER;
+            ALTER TABLE snapshots ADD COLUMN swap_total INTEGER;",
+        )?;
+    }
+    // Seconds of the timeline a row stands for; NULL for the first row of
+    // a session and for rows written before it existed, counted as 1 s
+    let has_span = conn
+        .prepare("SELECT 1 FROM pragma_table_info('snapshots') WHERE name = 'span'")?
+        .exists([])?;
+    if !has_span {
+        conn.execute_batch("ALTER TABLE snapshots ADD COLUMN span REAL;")?;
+    }
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS disk_usage (
+            timestamp REAL NOT NULL,
+            mount TEXT NOT NULL,
+            used INTEGER NOT NULL,
+            total INTEGER NOT NULL,
+            PRIMARY KEY (mount, timestamp)
+        );
+        CREATE TABLE IF NOT EXISTS events (
+            timestamp REAL NOT NULL,
+            critical INTEGER NOT NULL,
+            text TEXT NOT NULL
+        );
+        CREATE INDEX IF NOT EXISTS idx_events_ts ON events(timestamp);",
+    )?;
+    Ok(())
+}
+
+/// Batches waiting in the channel before the writer stops accepting more.
+const WRITE_QUEUE_DEPTH: usize = 4;
+/// Snapshots held back while the queue is full before the oldest are dropped.
+const MAX_BACKLOG: usize = 600;
+
+/// Runs `record_batch` on a dedicated thread so a slow disk never stalls the
+/// UI. Batches are handed over through a bounded channel; while it is full
+/// they accumulate in a backlog capped at `MAX_BACKLOG` snapshots.
+pub struct HistoryWriter {
+    tx: Option<SyncSender<Vec<Arc<Snapshot>>>>,
+    handle: Option<JoinHandle<History>>,
+    backlog: Vec<Arc<Snapshot>>,
+    /// Mirrors the thread's `History::last_error` after every batch.
+    error: Arc<Mutex<Option<HistoryError>>>,
+    /// Bits of the newest timestamp written so far, for live-following views.
+    written_until: Arc<AtomicU64>,
+    /// Picked up by the thread before each batch.
+    recorded: Arc<Mutex<RecordedMetrics>>,
+    /// Logged events waiting to be written with the next batch.
+    events: Arc<Mutex<Vec<StoredEvent>>>,
+}
+
+impl HistoryWriter {
+    /// Open the database on the writer thread, so the UI doesn't wait for
+    /// it. The reading connection is opened first and has already run the
+    /// integrity check and any recovery.
+    pub fn open(retention_hours: u64) -> Self {
+        Self::spawn(move || {
+            let mut history = History::open_checked_elsewhere();
+            history.set_retention_hours(retention_hours);
+            history
+        })
+    }
+
+    /// Take ownership of `history` and start writing to it in the background.
+    #[cfg(test)]
+    pub fn start(history: History) -> Self {
+        Self::spawn(move || history)
+    }
+
+    fn spawn(open: impl FnOnce() -> History + Send + 'static) -> Self {
+        let (tx, rx) = mpsc::sync_channel::<Vec<Arc<Snapshot>>>(WRITE_QUEUE_DEPTH);
+        let error = Arc::new(Mutex::new(None));
+        let thread_error = Arc::clone(&error);
+        let written_until = Arc::new(AtomicU64::new(0));
+        let thread_written = Arc::clone(&written_until);
+        let recorded = Arc::new(Mutex::new(RecordedMetrics::default()));
+        let thread_recorded = Arc::clone(&recorded);
+        let events: Arc<Mutex<Vec<StoredEvent>>> = Arc::new(Mutex::new(Vec::new()));
+        let thread_events = Arc::clone(&events);
+        let take_events = move || thread_events.lock().map(|mut e| std::mem::take(&mut *e)).unwrap_or_default();
+        let handle = std::thread::Builder::new()
+            .name("digger-history".into())
+            .spawn(move || {
+                let mut history = open();
+                if let Ok(mut e) = thread_error.lock() {
+                    *e = history.last_error.clone();
+                }
+                for batch in rx {
+                    if let Ok(r) = thread_recorded.lock() {
+                        history.recorded = *r;
+                    }
+                    let refs: Vec<&Snapshot> = batch.iter().map(|a| a.as_ref()).collect();
+                    history.record_batch(&refs);
+                    history.record_events(&take_events());
+                    // A success clears the error; otherwise the first one
+                    // (such as dropped snapshots) stays until then
+                    if let Ok(mut e) = thread_error.lock() {
+                        if history.last_error.is_none() || e.is_none() {
+                            *e = history.last_error.clone();
+                        }
+                    }
+                    if let Some(newest) = batch.iter().map(|s| s.timestamp).reduce(f64::max) {
+                        thread_written.store(newest.to_bits(), Ordering::Release);
+                    }
+                }
+                history.record_events(&take_events());
+                history
+            });
+        let handle = match handle {
+            Ok(h) => Some(h),
+            Err(e) => {
+                eprintln!("[digger] Failed to start history writer: {e}");
+                if let Ok(mut slot) = error.lock() {
+                    *slot = Some(HistoryError::InitFailed(e.to_string()));
+                }
+                None
+            }
+        };
+        Self { tx: handle.as_ref().map(|_| tx), handle, backlog: Vec::new(), error, written_until, recorded, events }
+    }
+
+    /// Queue snapshots for writing without blocking.
+    pub fn submit(&mut self, batch: Vec<Arc<Snapshot>>) {
+        let Some(tx) = &self.tx else { return };
+        self.backlog.extend(batch);
+        if self.backlog.is_empty() {
+            return;
+        }
+        match tx.try_send(std::mem::take(&mut self.backlog)) {
+            Ok(()) => {}
+            Err(TrySendError::Full(batch)) => {
+                self.backlog = batch;
+                if self.backlog.len() > MAX_BACKLOG {
+                    let dropped = self.backlog.len() - MAX_BACKLOG;
+                    self.backlog.drain(..dropped);
+                    eprintln!("[digger] History writer is behind, dropped {dropped} snapshots");
+                    if let Ok(mut e) = self.error.lock() {
+                        match &mut *e {
+                            Some(HistoryError::Dropped(total)) => *total += dropped,
+                            Some(_) => {}
+                            None => *e = Some(HistoryError::Dropped(dropped)),
+                        }
+                    }
+                }
+            }
+            Err(TrySendError::Disconnected(_)) => {
+                eprintln!("[digger] History writer thread exited");
+                self.tx = None;
+                self.backlog.clear();
+                if let Ok(mut e) = self.error.lock() {
+                    *e = Some(HistoryError::WriteFailed("writer thread exited".into()));
+                }
+            }
+        }
+    }
+
+    /// Queue logged events; they are written with the next batch.
+    pub fn log_events(&self, events: impl IntoIterator<Item = StoredEvent>) {
+        if let Ok(mut queued) = self.events.lock() {
+            queued.extend(events);
+        }
+    }
+
+    /// Timestamp of the newest snapshot handed to the database; 0 until the
+    /// first batch is written.
+    pub fn written_until(&self) -> f64 {
+        f64::from_bits(self.written_until.load(Ordering::Acquire))
+    }
+
+    /// Optional groups written from the next batch on.
+    pub fn set_recorded(&self, recorded: RecordedMetrics) {
+        if let Ok(mut r) = self.recorded.lock() {
+            *r = recorded;
+        }
+    }
+
+    /// Last error reported by the writer thread.
+    pub fn last_error(&self) -> Option<HistoryError> {
+        self.error.lock().ok().and_then(|e| e.clone())
+    }
+
+    /// Write everything still queued, stop the thread and hand back the
+    /// database. Blocks until the writes are done.
+    pub fn finish(&mut self) -> Option<History> {
+        if let Some(tx) = self.tx.take() {
+            let backlog = std::mem::take(&mut self.backlog);
+            if !backlog.is_empty() {
+                let _ = tx.send(backlog);
+            }
+        }
+        self.handle.take().and_then(|h| h.join().ok())
+    }
+}
+
+impl Drop for HistoryWriter {
+    fn drop(&mut self) {
+        self.finish();
+    }
+}
+
+/// Run `PRAGMA quick_check`; the first problem it reports, if any, and
+/// whether it means the file is corrupt. Only a finished check that found
+/// damage, or a query error SQLite itself classifies as corruption, counts.
+fn quick_check(conn: &Connection) -> Result<(), (bool, String)> {
+    let first: String = conn
+        .query_row("PRAGMA quick_check(1)", [], |row| row.get(0))
+        .map_err(|e| (is_corruption(&e), e.to_string()))?;
+    if first == "ok" { Ok(()) } else { Err((true, first)) }
+}
+
+fn is_corruption(e: &rusqlite::Error) -> bool {
+    matches!(
+        e.sqlite_error_code(),
+        Some(rusqlite::ErrorCode::DatabaseCorrupt | rusqlite::ErrorCode::NotADatabase)
+    )
+}
+
+/// Move a corrupt database (and its WAL/SHM side files) out of the way.
+/// Returns the backup path of the main file.
+fn backup_corrupt(path: &Path) -> Option<PathBuf> {
+    let stamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+    let backup = path.with_extension(format!("db.corrupt-{stamp}"));
+    if let Err(e) = std::fs::rename(path, &backup) {
+        eprintln!("[digger] Failed to back up corrupt history database: {e}");
+        return None;
+    }
+    for suffix in ["-wal", "-shm"] {
+        let mut side = path.as_os_str().to_owned();
+        side.push(suffix);
+        let _ = std::fs::remove_file(side);
+    }
+    Some(backup)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::TimeZone;
 
     fn make_test_db() -> History {
         let conn = Connection::open_in_memory().unwrap();
@@ -365,55 +1086,28 @@ mod tests {
                 mem_used INTEGER NOT NULL,
                 mem_total INTEGER NOT NULL,
                 net_rx INTEGER NOT NULL,
-                net_tx INTEGER NOT NULL
+                net_tx INTEGER NOT NULL,
+                power REAL
             );
             CREATE INDEX IF NOT EXISTS idx_ts ON snapshots(timestamp);",
         ).unwrap();
+        migrate(&conn).unwrap();
         History {
             conn: Some(conn),
             retention_secs: 86400.0,
             last_prune_time: 0.0,
+            last_disk_sample: 0.0,
+            last_written: None,
             last_error: None,
-        }
-    }
-
-    fn make_snapshot(ts: f64, cpu: f32) -> Snapshot {
-        use std::sync::Arc;
-        Snapshot {
-            timestamp: ts,
-            cpu_usage_per_core: vec![cpu],
-            cpu_usage_global: cpu,
-            cpu_name: String::new(),
-            cpu_core_count: 1,
-            cpu_frequency_mhz: 0,
-            memory_used: 4_000_000_000,
-            memory_total: 8_000_000_000,
-            swap_used: 0,
-            swap_total: 0,
-            disks: vec![],
-            disk_io: crate::metrics::DiskIoSnapshot { read_bytes: 0, write_bytes: 0 },
-            net_rx_bytes: 1000,
-            net_tx_bytes: 2000,
-            net_interfaces: vec![],
-            temperatures: vec![],
-            processes: vec![],
-            gpu: crate::gpu::GpuSnapshot::default(),
-            uptime_secs: 3600,
-            process_count: 100,
-            sys_info: Arc::new(crate::metrics::SystemInfo {
-                os_name: String::new(),
-                os_version: String::new(),
-                kernel_version: String::new(),
-                hostname: String::new(),
-            }),
-            load_avg: [0.0, 0.0, 0.0],
+            recovered_backup: None,
+            recorded: RecordedMetrics::default(),
         }
     }
 
     #[test]
     fn test_record_and_load() {
         let mut db = make_test_db();
-        let snap = make_snapshot(1000.0, 42.5);
+        let snap = Snapshot::for_test(1000.0, 42.5);
         db.record(&snap);
         assert!(db.last_error.is_none());
 
@@ -437,7 +1131,7 @@ mod tests {
 
         // Record snapshots spanning 120 seconds to trigger time-based pruning
         for i in 0..120 {
-            let snap = make_snapshot(1000.0 + i as f64, 50.0);
+            let snap = Snapshot::for_test(1000.0 + i as f64, 50.0);
             db.record(&snap);
         }
         // Pruning triggers at ~60s intervals.
@@ -449,38 +1143,344 @@ mod tests {
         assert!(!points.is_empty());
     }
 
+    #[test]
+    fn test_downsampled_keeps_extremes() {
+        let mut db = make_test_db();
+        for i in 0..60 {
+            db.record(&Snapshot::for_test(1000.0 + i as f64, if i == 17 { 100.0 } else { 10.0 }));
+        }
+        let points = db.load_range_downsampled(1000.0, 1059.0, 6);
+        assert!(points.len() <= 7);
+        let spike = points.iter().find(|p| p.range.is_some_and(|r| r.cpu.1 == 100.0)).expect("spike bucket");
+        assert!(spike.cpu < 20.0);
+        let range = spike.range.unwrap();
+        assert_eq!(range.cpu.0, 10.0);
+        assert_eq!(range.mem_percent, (50.0, 50.0));
+        assert_eq!(range.power, None);
+    }
+
+    #[test]
+    fn test_recording_policy() {
+        let mut all = RecordingPolicy::new(None);
+        assert!((0..5).all(|i| all.should_record(1000.0 + i as f64, 5.0)));
+
+        let mut adaptive = RecordingPolicy::new(Some(80.0));
+        let cpu = [5.0, 5.0, 90.0, 95.0, 10.0, 10.0];
+        let kept: Vec<f64> = (0..6)
+            .map(|i| 1000.0 + i as f64)
+            .zip(cpu)
+            .filter(|&(ts, cpu)| adaptive.should_record(ts, cpu))
+            .map(|(ts, _)| ts)
+            .collect();
+        assert_eq!(kept, vec![1000.0, 1002.0, 1003.0]);
+        // Idle again: the next row is due 10 s after the last one, give or
+        // take tick jitter
+        assert!(!adaptive.should_record(1012.0, 10.0));
+        assert!(adaptive.should_record(1012.98, 10.0));
+        // A clock set back doesn't stall recording
+        assert!(adaptive.should_record(900.0, 10.0));
+    }
+
+    #[test]
+    fn test_skipped_traffic_is_carried() {
+        let mut adaptive = RecordingPolicy::new(Some(80.0));
+        let written: Vec<Arc<Snapshot>> = (0..11)
+            .filter_map(|i| adaptive.admit(&Arc::new(Snapshot::for_test(1000.0 + i as f64, 5.0))))
+            .collect();
+        assert_eq!(written.len(), 2);
+        assert_eq!(written[0].net_rx_bytes, 1000);
+        // Nine skipped snapshots plus its own
+        assert_eq!(written[1].net_rx_bytes, 10 * 1000);
+        assert_eq!(written[1].net_tx_bytes, 10 * 2000);
+    }
+
+    #[test]
+    fn test_averages_are_time_weighted() {
+        let mut db = make_test_db();
+        let mut adaptive = RecordingPolicy::new(Some(80.0));
+        // 10 s busy at 90%, then 60 s idle at 10%
+        for i in 0..70 {
+            let cpu = if i < 10 { 90.0 } else { 10.0 };
+            if let Some(snap) = adaptive.admit(&Arc::new(Snapshot::for_test(1000.0 + i as f64, cpu))) {
+                db.record(&snap);
+            }
+        }
+        let summary = db.summary(0.0, 2000.0).unwrap();
+        assert!(summary.samples < 20);
+        // Unweighted, the busy rows would pull this to about 60%
+        assert!((summary.cpu_avg - 21.6).abs() < 1.0, "cpu_avg = {}", summary.cpu_avg);
+        assert_eq!(summary.net_rx_total, 70 * 1000);
+        assert_eq!(summary.net_rx_peak, 1000);
+        let points = db.load_range(0.0, 2000.0);
+        assert!(points.iter().all(|p| p.net_rx == 1000));
+    }
+
+    #[test]
+    fn test_clamp_range() {
+        let day = 86400.0;
+        assert_eq!(clamp_range(5000.0, 6000.0, 10_000.0, day), Ok((5000.0, 6000.0)));
+        assert_eq!(clamp_range(-day, 20_000.0, 10_000.0, day), Ok((10_000.0 - day, 10_000.0)));
+        assert_eq!(clamp_range(6000.0, 6000.0, 10_000.0, day), Err(RangeError::Empty));
+        assert_eq!(clamp_range(11_000.0, 12_000.0, 10_000.0, day), Err(RangeError::Future));
+        assert_eq!(clamp_range(0.0, 1000.0, 10_000.0 + day, day), Err(RangeError::Expired));
+    }
+
     #[test]
     fn test_export_csv() {
         let mut db = make_test_db();
-        db.record(&make_snapshot(1000.0, 55.0));
-        db.record(&make_snapshot(1001.0, 60.0));
+        db.record(&Snapshot::for_test(1000.0, 55.0));
+        db.record(&Snapshot::for_test(1001.0, 60.0));
 
         let csv = db.export_csv(999.0, 1002.0);
         let lines: Vec<&str> = csv.lines().collect();
         assert_eq!(lines.len(), 3); // header + 2 rows
-        assert!(lines[0].starts_with("timestamp"));
+        assert!(lines[0].starts_with("timestamp,time,"));
         assert!(lines[1].contains("55.00"));
+        let time = lines[1].split(',').nth(1).unwrap();
+        assert_eq!(chrono::DateTime::parse_from_rfc3339(time).unwrap().timestamp(), 1000);
+    }
+
+    #[test]
+    fn test_export_recent_csv() {
+        let mut db = make_test_db();
+        for i in 0..5 {
+            db.record(&Snapshot::for_test(1000.0 + i as f64, 10.0 * i as f32));
+        }
+        let csv = db.export_recent_csv(2);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].starts_with("1003"));
+        assert!(lines[2].contains("40.00"));
+        assert_eq!(make_test_db().export_recent_csv(2).lines().count(), 1);
     }
 
     #[test]
     fn test_export_json() {
         let mut db = make_test_db();
-        db.record(&make_snapshot(1000.0, 55.0));
+        db.record(&Snapshot::for_test(1000.0, 55.0));
 
         let json = db.export_json(999.0, 1002.0);
         assert!(json.starts_with('['));
         assert!(json.contains("\"cpu\":55.00"));
     }
 
+    #[test]
+    fn test_summary() {
+        let mut db = make_test_db();
+        assert!(db.summary(0.0, 2000.0).is_none());
+        db.record(&Snapshot::for_test(1000.0, 20.0));
+        db.record(&Snapshot::for_test(1001.0, 60.0));
+
+        let s = db.summary(0.0, 2000.0).unwrap();
+        assert_eq!(s.samples, 2);
+        assert_eq!((s.first_ts, s.last_ts), (1000.0, 1001.0));
+        assert!((s.cpu_avg - 40.0).abs() < 0.01);
+        assert!((s.cpu_max - 60.0).abs() < 0.01);
+        assert!((s.mem_avg - 50.0).abs() < 0.01);
+        assert_eq!(s.net_rx_total, 2000);
+        assert_eq!(s.net_tx_peak, 2000);
+    }
+
+    #[test]
+    fn test_migrate_adds_power_column() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE snapshots (
+                timestamp REAL PRIMARY KEY,
+                cpu REAL NOT NULL,
+                mem_used INTEGER NOT NULL,
+                mem_total INTEGER NOT NULL,
+                net_rx INTEGER NOT NULL,
+                net_tx INTEGER NOT NULL
+            );
+            INSERT INTO snapshots VALUES (999.0, 1.0, 1, 2, 0, 0);",
+        ).unwrap();
+        migrate(&conn).unwrap();
+        migrate(&conn).unwrap();
+        let mut db = History {
+            conn: Some(conn),
+            retention_secs: 86400.0,
+            last_prune_time: 0.0,
+            last_disk_sample: 0.0,
+            last_written: None,
+            last_error: None,
+            recovered_backup: None,
+            recorded: RecordedMetrics::default(),
+        };
+
+        let mut snap = Snapshot::for_test(1000.0, 10.0);
+        snap.power = Some(crate::power::SystemPower { watts: 14.5, source: crate::power::PowerSource::Rapl });
+        db.record(&snap);
+        let points = db.load_range(0.0, 2000.0);
+        assert_eq!(points.iter().map(|p| p.power).collect::<Vec<_>>(), vec![None, Some(14.5)]);
+        assert!(db.export_csv(0.0, 2000.0).lines().nth(2).unwrap().ends_with(",14.50"));
+    }
+
+    #[test]
+    fn test_usage_series() {
+        let mut db = make_test_db();
+        for i in 0..3 {
+            let mut snap = Snapshot::for_test(1000.0 + i as f64 * 200.0, 10.0);
+            snap.swap_used = 100 * (i + 1);
+            snap.swap_total = 1000;
+            snap.disks = Arc::new([crate::metrics::DiskInfo {
+                name: "sda1".into(),
+                mount: "/".into(),
+                fs_type: "ext4".into(),
+                total: 500,
+                available: 400 - 10 * i,
+                is_removable: false,
+            }]);
+            db.record(&snap);
+        }
+
+        let swap: Vec<u64> = db.swap_usage(0.0).iter().map(|s| s.used).collect();
+        assert_eq!(swap, vec![100, 200, 300]);
+        // Drives are sampled every DISK_SAMPLE_INTERVAL_SECS: 1000 and 1400
+        let disk = db.disk_usage("/", 0.0);
+        assert_eq!(disk.iter().map(|s| (s.timestamp, s.used)).collect::<Vec<_>>(), vec![(1000.0, 100), (1400.0, 120)]);
+        assert!(db.disk_usage("/home", 0.0).is_empty());
+    }
+
+    #[test]
+    fn test_disabled_groups_are_not_stored() {
+        let mut db = make_test_db();
+        db.recorded = RecordedMetrics { network: false, power: false, disk_usage: false, ..Default::default() };
+        let mut snap = Snapshot::for_test(1000.0, 10.0);
+        snap.power = Some(crate::power::SystemPower { watts: 14.5, source: crate::power::PowerSource::Rapl });
+        snap.disks = Arc::new([crate::metrics::DiskInfo {
+            name: "sda1".into(),
+            mount: "/".into(),
+            fs_type: "ext4".into(),
+            total: 500,
+            available: 400,
+            is_removable: false,
+        }]);
+        db.record(&snap);
+
+        let points = db.load_range(0.0, 2000.0);
+        assert_eq!((points[0].net_rx, points[0].net_tx, points[0].power), (0, 0, None));
+        assert!((points[0].cpu - 10.0).abs() < 0.01);
+        assert!(db.disk_usage("/", 0.0).is_empty());
+    }
+
+    #[test]
+    fn test_values() {
+        let mut db = make_test_db();
+        db.record(&Snapshot::for_test(1000.0, 20.0));
+        let mut snap = Snapshot::for_test(1001.0, 40.0);
+        snap.power = Some(crate::power::SystemPower { watts: 9.5, source: crate::power::PowerSource::Battery });
+        db.record(&snap);
+
+        assert_eq!(db.values(HistoryMetric::Cpu, 0.0, 2000.0), vec![20.0, 40.0]);
+        assert_eq!(db.values(HistoryMetric::Memory, 0.0, 2000.0), vec![50.0, 50.0]);
+        assert_eq!(db.values(HistoryMetric::Power, 0.0, 2000.0), vec![9.5]);
+        assert_eq!(db.values(HistoryMetric::Cpu, 1000.5, 2000.0), vec![40.0]);
+    }
+
+    #[test]
+    fn test_cpu_by_weekday_hour() {
+        let mut db = make_test_db();
+        // Two samples in the same hour, one an hour later
+        let base = chrono::Local.with_ymd_and_hms(2024, 1, 1, 3, 10, 0).unwrap().timestamp() as f64; // a Monday
+        db.record(&Snapshot::for_test(base, 20.0));
+        db.record(&Snapshot::for_test(base + 1.0, 40.0));
+        db.record(&Snapshot::for_test(base + 3600.0, 90.0));
+
+        let grid = db.cpu_by_weekday_hour(0.0);
+        assert_eq!(grid[0][3], Some(30.0));
+        assert_eq!(grid[0][4], Some(90.0));
+        assert_eq!(grid[0][5], None);
+        assert!(grid[1..].iter().flatten().all(Option::is_none));
+    }
+
     #[test]
     fn test_unavailable_db_graceful() {
         let db = History {
             conn: None,
             retention_secs: 86400.0,
             last_prune_time: 0.0,
+            last_disk_sample: 0.0,
+            last_written: None,
             last_error: Some(HistoryError::InitFailed("test".into())),
+            recovered_backup: None,
+            recorded: RecordedMetrics::default(),
         };
         assert!(!db.is_available());
         assert!(db.load_range(0.0, 1000.0).is_empty());
     }
+
+    #[test]
+    fn test_writer_flushes_on_finish() {
+        let mut writer = HistoryWriter::start(make_test_db());
+        for i in 0..3 {
+            let batch = (0..10).map(|j| Arc::new(Snapshot::for_test(1000.0 + (i * 10 + j) as f64, 5.0))).collect();
+            writer.submit(batch);
+        }
+        let db = writer.finish().expect("writer thread should hand back the database");
+        assert!(writer.last_error().is_none());
+        assert_eq!(writer.written_until(), 1029.0);
+        assert_eq!(db.load_range(0.0, 2000.0).len(), 30);
+    }
+
+    #[test]
+    fn test_events_are_stored_and_pruned() {
+        let mut writer = HistoryWriter::start(make_test_db());
+        let event = |timestamp, critical| StoredEvent { timestamp, critical, text: format!("event at {timestamp}") };
+        writer.log_events([event(1000.0, false), event(1500.0, true)]);
+        let mut db = writer.finish().expect("writer thread should hand back the database");
+        assert_eq!(db.events(0.0, 2000.0), vec![event(1000.0, false), event(1500.0, true)]);
+        assert_eq!(db.events(1200.0, 2000.0).len(), 1);
+
+        db.retention_secs = 1000.0;
+        db.record(&Snapshot::for_test(2200.0, 5.0));
+        assert_eq!(db.events(0.0, 3000.0), vec![event(1500.0, true)]);
+    }
+
+    #[test]
+    fn test_corrupt_db_is_backed_up_and_recreated() {
+        let dir = std::env::temp_dir().join(format!("digger_corrupt_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("history.db");
+        std::fs::write(&path, b"definitely not an sqlite database, just garbage bytes").unwrap();
+
+        let mut db = History::open_path(&path, true);
+        assert!(db.is_available());
+        let backup = db.recovered_backup.clone().expect("corrupt file should be backed up");
+        assert!(backup.exists());
+        db.record(&Snapshot::for_test(1000.0, 10.0));
+        assert_eq!(db.load_range(0.0, 2000.0).len(), 1);
+
+        drop(db);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_damaged_pages_are_caught_by_quick_check() {
+        let dir = std::env::temp_dir().join(format!("digger_damaged_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("history.db");
+        {
+            let mut db = History::open_path(&path, true);
+            assert_eq!(quick_check(db.conn.as_ref().unwrap()), Ok(()));
+            for i in 0..2000 {
+                db.record(&Snapshot::for_test(1000.0 + i as f64, 10.0));
+            }
+        }
+        // Trash a page in the middle of the table; the header stays intact
+        let mut bytes = std::fs::read(&path).unwrap();
+        let page = 4096 * 3;
+        bytes[page..page + 4096].fill(0xA5);
+        std::fs::write(&path, &bytes).unwrap();
+
+        let db = History::open_path(&path, true);
+        assert!(db.is_available());
+        assert!(db.recovered_backup.as_ref().is_some_and(|b| b.exists()));
+        assert!(db.load_range(0.0, 5000.0).is_empty());
+
+        drop(db);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
 }
@@ -6,8 +6,9 @@
 use serde::{Deserialize, Serialize};
 
 /// All supported languages.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub enum Language {
+    #[default]
     En, Fr, Es, Pt, De, It, Nl, Ru, ZhCn, ZhTw,
     Ja, Ko, Ar, Hi, Bn, Pa, Ta, Te, Mr, Tr,
     Pl, Uk, Ro, Hu, Cs, Th, Vi, Fil, Ms, Id,
@@ -15,10 +16,6 @@ pub enum Language {
     Ca, Gl, Eu, Sl, Hr, Sr, Bg, Lt, Lv, Et,
 }
 
-impl Default for Language {
-    fn default() -> Self { Language::En }
-}
-
 impl Language {
     /// All 50 languages in display order.
     pub const ALL: &'static [Language] = &[
@@ -136,6 +133,33 @@ impl Language {
         }
     }
 
+    /// Decimal separator used when formatting numbers.
+    pub fn decimal_separator(self) -> char {
+        match self {
+            Language::Fr | Language::Es | Language::Pt | Language::De | Language::It
+            | Language::Nl | Language::Ru | Language::Tr | Language::Pl | Language::Uk
+            | Language::Ro | Language::Hu | Language::Cs | Language::Vi | Language::Id
+            | Language::Af | Language::Uz | Language::Kk | Language::Ca | Language::Gl
+            | Language::Eu | Language::Sl | Language::Hr | Language::Sr | Language::Bg
+            | Language::Lt | Language::Lv | Language::Et => ',',
+            Language::Fa => '\u{066b}',
+            _ => '.',
+        }
+    }
+
+    /// Whether clock times use the 24-hour format (otherwise 12-hour with AM/PM).
+    pub fn uses_24h_clock(self) -> bool {
+        !matches!(self,
+            Language::En | Language::Ar | Language::Hi | Language::Bn | Language::Pa
+            | Language::Ta | Language::Te | Language::Mr | Language::Fil | Language::Am
+        )
+    }
+
+    /// Whether the script is written right-to-left.
+    pub fn is_rtl(self) -> bool {
+        matches!(self, Language::Ar | Language::Fa | Language::He)
+    }
+
     /// Get the translation table for this language.
     pub fn strings(self) -> &'static Strings {
         match self {
@@ -160,13 +184,223 @@ impl Language {
     }
 }
 
-/// All translatable strings in the application.
-pub struct Strings {
+/// CLDR plural category of a count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl Language {
+    /// Plural category for an integer count (CLDR cardinal rules).
+    pub fn plural_category(self, n: u64) -> PluralCategory {
+        use PluralCategory::*;
+        let (n10, n100) = (n % 10, n % 100);
+        match self {
+            // No grammatical plural
+            Language::ZhCn | Language::ZhTw | Language::Ja | Language::Ko | Language::Th
+            | Language::Vi | Language::Ms | Language::Id | Language::Yo | Language::Ig => Other,
+            // 0 and 1 are singular
+            Language::Fr | Language::Pt | Language::Hi | Language::Bn | Language::Pa
+            | Language::Mr | Language::Fa | Language::Am | Language::Zu => {
+                if n <= 1 { One } else { Other }
+            }
+            Language::Ru | Language::Uk | Language::Sr | Language::Hr => {
+                if n10 == 1 && n100 != 11 {
+                    One
+                } else if (2..=4).contains(&n10) && !(12..=14).contains(&n100) {
+                    Few
+                } else {
+                    Many
+                }
+            }
+            Language::Pl => {
+                if n == 1 {
+                    One
+                } else if (2..=4).contains(&n10) && !(12..=14).contains(&n100) {
+                    Few
+                } else {
+                    Many
+                }
+            }
+            Language::Cs => match n {
+                1 => One,
+                2..=4 => Few,
+                _ => Other,
+            },
+            Language::Sl => match n100 {
+                1 => One,
+                2 => Two,
+                3 | 4 => Few,
+                _ => Other,
+            },
+            Language::Lt => {
+                if (11..=19).contains(&n100) {
+                    Other
+                } else if n10 == 1 {
+                    One
+                } else if n10 >= 2 {
+                    Few
+                } else {
+                    Other
+                }
+            }
+            Language::Lv => {
+                if n10 == 0 || (11..=19).contains(&n100) {
+                    Zero
+                } else if n10 == 1 {
+                    One
+                } else {
+                    Other
+                }
+            }
+            Language::Ro => {
+                if n == 1 {
+                    One
+                } else if n == 0 || (2..=19).contains(&n100) {
+                    Few
+                } else {
+                    Other
+                }
+            }
+            Language::Ar => match (n, n100) {
+                (0, _) => Zero,
+                (1, _) => One,
+                (2, _) => Two,
+                (_, 3..=10) => Few,
+                (_, 11..=99) => Many,
+                _ => Other,
+            },
+            Language::He => match n {
+                1 => One,
+                2 => Two,
+                _ => Other,
+            },
+            _ => {
+                if n == 1 { One } else { Other }
+            }
+        }
+    }
+}
+
+/// A count-dependent string. Each form is a template where `{n}` is
+/// replaced by the count; forms a language doesn't use fall back to `other`.
+#[derive(Debug, Clone, Copy)]
+pub struct Plural {
+    pub zero: &'static str,
+    pub one: &'static str,
+    pub two: &'static str,
+    pub few: &'static str,
+    pub many: &'static str,
+    pub other: &'static str,
+}
+
+impl Plural {
+    /// Singular / plural, for languages with two forms.
+    pub const fn new(one: &'static str, other: &'static str) -> Self {
+        Self { zero: other, one, two: other, few: other, many: other, other }
+    }
+
+    /// Same text for every count.
+    pub const fn invariant(text: &'static str) -> Self {
+        Self::new(text, text)
+    }
+
+    /// East/West Slavic one / few / many (fractions use the `few` form).
+    pub const fn slavic(one: &'static str, few: &'static str, many: &'static str) -> Self {
+        Self { zero: many, one, two: few, few, many, other: few }
+    }
+
+    fn form(&self, category: PluralCategory) -> &'static str {
+        match category {
+            PluralCategory::Zero => self.zero,
+            PluralCategory::One => self.one,
+            PluralCategory::Two => self.two,
+            PluralCategory::Few => self.few,
+            PluralCategory::Many => self.many,
+            PluralCategory::Other => self.other,
+        }
+    }
+
+    /// Pick the form for `n` in `lang` and substitute the count.
+    pub fn format(&self, lang: Language, n: u64) -> String {
+        fill(self.form(lang.plural_category(n)), &[("n", &n)])
+    }
+}
+
+/// Replace `{name}` placeholders in `template` with the matching argument.
+/// Unknown placeholders are left untouched.
+pub fn fill(template: &str, args: &[(&str, &dyn std::fmt::Display)]) -> String {
+    let mut out = String::with_capacity(template.len() + 8);
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        match after.find('}').and_then(|end| args.iter().find(|(k, _)| *k == &after[..end]).map(|a| (end, a))) {
+            Some((end, (_, value))) => {
+                out.push_str(&value.to_string());
+                rest = &after[end + 1..];
+            }
+            None => {
+                out.push('{');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+// Declares `Strings`, plus a test-only listing of every field so each
+// language's table can be checked field by field.
+macro_rules! strings {
+    ($(pub $field:ident: $ty:ty,)*) => {
+        /// All translatable strings in the application.
+        pub struct Strings {
+            $(pub $field: $ty,)*
+        }
+
+        #[cfg(test)]
+        impl Strings {
+            /// Every field by name, with its text (both forms for plurals).
+            fn fields(&self) -> Vec<(&'static str, Vec<&'static str>)> {
+                vec![$((stringify!($field), tests::Texts::texts(&self.$field)),)*]
+            }
+        }
+    };
+}
+
+strings! {
     // ─── Tabs ───
     pub tab_overview: &'static str,
     pub tab_processes: &'static str,
     pub tab_history: &'static str,
     pub tab_events: &'static str,
+    pub tab_logs: &'static str,
+    pub tab_hardware: &'static str,
+    pub hw_reading: &'static str,
+    pub hw_system: &'static str,
+    pub hw_motherboard: &'static str,
+    pub hw_bios: &'static str,
+    pub hw_release_date: &'static str,
+    pub hw_sockets: &'static str,
+    pub hw_physical_cores: &'static str,
+    pub hw_max_clock: &'static str,
+    pub hw_caches: &'static str,
+    pub hw_slots_used: &'static str,
+    pub hw_slot: &'static str,
+    pub hw_size: &'static str,
+    pub hw_speed: &'static str,
+    pub hw_part_number: &'static str,
+    pub hw_no_dimms: &'static str,
+    pub hw_graphics: &'static str,
+    pub hw_driver: &'static str,
+    pub hw_storage: &'static str,
+    pub hw_no_drives: &'static str,
 
     // ─── Overview sidebar ───
     pub cpu: &'static str,
@@ -176,8 +410,14 @@ pub struct Strings {
     pub temp: &'static str,
     pub gpu: &'static str,
     pub load: &'static str,
-    pub sensors: &'static str,
+    pub cpu_queue: &'static str,
+    pub cpu_queue_label: &'static str,
+    pub sensors: Plural,
+    pub process_count: Plural,
+    pub gpu_count: Plural,
     pub n_a: &'static str,
+    pub top_cpu: &'static str,
+    pub top_memory: &'static str,
 
     // ─── CPU detail ───
     pub per_core_usage: &'static str,
@@ -186,6 +426,15 @@ pub struct Strings {
     pub logical_cores: &'static str,
     pub base_speed: &'static str,
     pub utilization: &'static str,
+    pub cpu_time_split: &'static str,
+    pub io_wait: &'static str,
+    pub steal_time: &'static str,
+    pub virtualized: &'static str,
+    pub page_faults: &'static str,
+    pub minor_faults: &'static str,
+    pub major_faults: &'static str,
+    pub hugepages: &'static str,
+    pub transparent_hugepages: &'static str,
     pub processes: &'static str,
     pub uptime: &'static str,
     pub load_avg: &'static str,
@@ -202,6 +451,30 @@ pub struct Strings {
     // ─── Network detail ───
     pub throughput: &'static str,
     pub interfaces: &'static str,
+    pub connection_quality: &'static str,
+    pub latency: &'static str,
+    pub jitter: &'static str,
+    pub packet_loss: &'static str,
+    pub quality_score: &'static str,
+    pub waiting_for_ping: &'static str,
+    pub quality: &'static str,
+    pub lan_devices: &'static str,
+    pub lan_scan_desc: &'static str,
+    pub scan_network: &'static str,
+    pub addresses_probed: &'static str,
+    pub no_lan_devices: &'static str,
+    pub ip_address: &'static str,
+    pub mac_address: &'static str,
+    pub vendor: &'static str,
+    pub private_mac: &'static str,
+    pub sessions: &'static str,
+    pub no_sessions: &'static str,
+    pub session_user: &'static str,
+    pub session_line: &'static str,
+    pub session_from: &'static str,
+    pub session_since: &'static str,
+    pub session_duration: &'static str,
+    pub session_local: &'static str,
     pub receive: &'static str,
     pub send: &'static str,
 
@@ -223,22 +496,44 @@ pub struct Strings {
     pub type_label: &'static str,
     pub removable: &'static str,
     pub fixed: &'static str,
-    pub drives: &'static str,
+    pub drives: Plural,
+    pub scan_disk: &'static str,
+    pub largest_directories: &'static str,
+    pub scanning: &'static str,
+    pub scan_complete: &'static str,
+    pub files: Plural,
+    pub cancel: &'static str,
+    pub close: &'static str,
 
     // ─── Temperature detail ───
     pub temperatures: &'static str,
     pub temperature_overview: &'static str,
     pub all_sensors: &'static str,
     pub no_sensors: &'static str,
+    pub stale_since: &'static str,
     pub minimum: &'static str,
     pub maximum: &'static str,
     pub average: &'static str,
 
     // ─── GPU detail ───
     pub no_gpu: &'static str,
+    pub system_power: &'static str,
+    pub power_source: &'static str,
+    pub power_rapl: &'static str,
+    pub power_battery: &'static str,
+    pub no_power: &'static str,
+    pub no_power_hint: &'static str,
     pub vram: &'static str,
     pub vram_usage: &'static str,
+    pub gpu_processes: &'static str,
+    pub no_gpu_processes: &'static str,
+    pub sensor_permission_denied: &'static str,
+    pub sensor_driver_missing: &'static str,
+    pub sensor_service_missing: &'static str,
     pub power: &'static str,
+    pub fan_speed: &'static str,
+    pub core_clock: &'static str,
+    pub memory_clock: &'static str,
     pub temperature: &'static str,
 
     // ─── Processes tab ───
@@ -250,19 +545,49 @@ pub struct Strings {
     pub background_processes: &'static str,
     pub system: &'static str,
     pub command: &'static str,
+    pub command_line: &'static str,
+    pub export_processes: &'static str,
     pub action: &'static str,
 
     // ─── History tab ───
     pub range: &'static str,
+    pub range_custom: &'static str,
+    pub follow_live: &'static str,
+    pub chart_average: &'static str,
+    pub chart_min_max: &'static str,
+    pub range_invalid_date: &'static str,
+    pub range_empty: &'static str,
+    pub range_future: &'static str,
+    pub range_expired: &'static str,
+    pub show_in_history: &'static str,
     pub no_history_data: &'static str,
     pub cpu_history: &'static str,
     pub memory_history: &'static str,
     pub network_history: &'static str,
+    pub power_history: &'static str,
+    pub cpu_heatmap: &'static str,
+    pub cpu_heatmap_desc: &'static str,
+    pub weekdays_short: &'static str,
+    pub distribution: &'static str,
 
     // ─── Event log ───
     pub event_log: &'static str,
-    pub events: &'static str,
+    pub events: Plural,
     pub no_events: &'static str,
+    pub event_repeated: &'static str,
+    pub system_tag: &'static str,
+    pub background_activity: &'static str,
+    pub session_idle: &'static str,
+    pub session_active: &'static str,
+    pub idle_time: &'static str,
+    pub cpu_time: &'static str,
+    pub wakeups: &'static str,
+    pub no_background_activity: &'static str,
+    pub reset: &'static str,
+    pub remove: &'static str,
+    pub end_process: &'static str,
+    pub pin_process: &'static str,
+    pub unpin_process: &'static str,
 
     // ─── Settings ───
     pub general_settings: &'static str,
@@ -270,14 +595,103 @@ pub struct Strings {
     pub refresh_rate: &'static str,
     pub refresh_rate_desc: &'static str,
     pub temperature_unit: &'static str,
+    pub sensor_calibration: &'static str,
+    pub sensor_calibration_desc: &'static str,
     pub celsius: &'static str,
     pub fahrenheit: &'static str,
+    pub kelvin: &'static str,
+    pub rate_bits: &'static str,
+    pub rate_bits_desc: &'static str,
+    pub rate_si: &'static str,
+    pub rate_si_desc: &'static str,
+    pub utc_times: &'static str,
+    pub utc_times_desc: &'static str,
+    pub memory_unit: &'static str,
+    pub memory_unit_desc: &'static str,
+    pub cpu_per_core: &'static str,
+    pub cpu_per_core_desc: &'static str,
+    pub chart_smoothing: &'static str,
+    pub chart_smoothing_desc: &'static str,
+    pub smoothing_alpha: &'static str,
+    pub smoothing_alpha_desc: &'static str,
+    pub offer_elevation: &'static str,
+    pub offer_elevation_desc: &'static str,
+    pub read_only_mode: &'static str,
+    pub read_only_mode_desc: &'static str,
+    pub read_only_flag: &'static str,
+    pub read_only: &'static str,
+    pub schema_sidecar: &'static str,
+    pub schema_sidecar_desc: &'static str,
+    pub recorded_metrics: &'static str,
+    pub recorded_metrics_desc: &'static str,
+    pub record_network: &'static str,
+    pub record_network_desc: &'static str,
+    pub record_gpu: &'static str,
+    pub record_gpu_desc: &'static str,
+    pub record_power: &'static str,
+    pub record_power_desc: &'static str,
+    pub record_disk_usage: &'static str,
+    pub record_disk_usage_desc: &'static str,
+    pub collection_off: &'static str,
+    pub adaptive_recording: &'static str,
+    pub adaptive_recording_desc: &'static str,
+    pub full_rate_above: &'static str,
+    pub full_rate_above_desc: &'static str,
+    pub elevation_title: &'static str,
+    pub elevation_desc: &'static str,
+    pub run_as_admin: &'static str,
+    pub threads_loading: &'static str,
+    pub threads_unavailable: &'static str,
+    pub proc_executable: &'static str,
+    pub proc_cwd: &'static str,
+    pub proc_stdout: &'static str,
+    pub proc_stderr: &'static str,
+    pub proc_listening: &'static str,
+    pub proc_connections: &'static str,
+    pub proc_no_sockets: &'static str,
+    pub proc_more_sockets: &'static str,
+    pub output_terminal: &'static str,
+    pub output_journal: &'static str,
+    pub output_pipe: &'static str,
+    pub output_socket: &'static str,
+    pub output_discarded: &'static str,
+    pub row_density: &'static str,
+    pub row_density_desc: &'static str,
+    pub density_comfortable: &'static str,
+    pub density_compact: &'static str,
+    pub touch_mode: &'static str,
+    pub touch_mode_desc: &'static str,
+    pub pull_to_refresh: &'static str,
+    pub release_to_refresh: &'static str,
+    pub auto_unit: &'static str,
+    pub changes: &'static str,
+    pub no_changes: &'static str,
+    pub started_processes: &'static str,
+    pub exited_processes: &'static str,
+    pub cpu_movers: &'static str,
+    pub memory_movers: &'static str,
+    pub link_up: &'static str,
+    pub link_down: &'static str,
+    pub watched_processes: &'static str,
+    pub watched_processes_desc: &'static str,
+    pub port_watch: &'static str,
+    pub port_watch_desc: &'static str,
+    pub port_watch_notify: &'static str,
+    pub port_watch_notify_desc: &'static str,
+    pub port_allowlist: &'static str,
+    pub port_allowlist_desc: &'static str,
+    pub port_or_process: &'static str,
+    pub process_name: &'static str,
+    pub add: &'static str,
     pub monitoring: &'static str,
     pub monitoring_desc: &'static str,
     pub process_limit: &'static str,
     pub process_limit_desc: &'static str,
+    pub search_all_processes: &'static str,
+    pub search_all_processes_desc: &'static str,
     pub history_buffer: &'static str,
     pub history_buffer_desc: &'static str,
+    pub history_buffer_span: &'static str,
     pub history_retention: &'static str,
     pub history_retention_desc: &'static str,
     pub display: &'static str,
@@ -285,6 +699,19 @@ pub struct Strings {
     pub data: &'static str,
     pub history_database: &'static str,
     pub history_database_desc: &'static str,
+    pub log_file: &'static str,
+    pub log_file_desc: &'static str,
+    pub ping_host: &'static str,
+    pub ping_host_desc: &'static str,
+    pub apply: &'static str,
+    pub save_png: &'static str,
+    pub copy_data: &'static str,
+    pub chart_data_copied: &'static str,
+    pub copy_system_summary: &'static str,
+    pub system_summary_copied: &'static str,
+    pub report_weekly: &'static str,
+    pub report_monthly: &'static str,
+    pub lines: Plural,
     pub active: &'static str,
     pub unavailable: &'static str,
     pub alerts: &'static str,
@@ -293,6 +720,32 @@ pub struct Strings {
     pub cpu_threshold_desc: &'static str,
     pub memory_threshold: &'static str,
     pub memory_threshold_desc: &'static str,
+    pub vram_threshold: &'static str,
+    pub vram_threshold_desc: &'static str,
+    pub gpu_temp_threshold: &'static str,
+    pub gpu_temp_threshold_desc: &'static str,
+    pub temp_threshold: &'static str,
+    pub temp_threshold_desc: &'static str,
+    pub invalid_temperature: &'static str,
+    pub net_notifications: &'static str,
+    pub net_notifications_desc: &'static str,
+    pub notify_level: &'static str,
+    pub notify_level_desc: &'static str,
+    pub notify_all: &'static str,
+    pub notify_warnings: &'static str,
+    pub notify_critical: &'static str,
+    pub notify_off: &'static str,
+    pub anomaly_sensitivity: &'static str,
+    pub anomaly_sensitivity_desc: &'static str,
+    pub sensitivity_low: &'static str,
+    pub sensitivity_medium: &'static str,
+    pub sensitivity_high: &'static str,
+    pub forecast_horizon: &'static str,
+    pub forecast_horizon_desc: &'static str,
+    pub projected_full: &'static str,
+    pub full_in: &'static str,
+    pub system_log_events: &'static str,
+    pub system_log_events_desc: &'static str,
 
     // ─── Appearance settings ───
     pub appearance: &'static str,
@@ -301,6 +754,11 @@ pub struct Strings {
     pub theme_desc: &'static str,
     pub accent_color: &'static str,
     pub accent_color_desc: &'static str,
+    pub custom_accent_desc: &'static str,
+    pub accent_hue: &'static str,
+    pub accent_saturation: &'static str,
+    pub accent_brightness: &'static str,
+    pub invalid_color: &'static str,
 
     // ─── Accessibility settings ───
     pub accessibility: &'static str,
@@ -309,6 +767,12 @@ pub struct Strings {
     pub fonts_desc: &'static str,
     pub dyslexic_font: &'static str,
     pub dyslexic_font_desc: &'static str,
+    pub color_vision: &'static str,
+    pub color_vision_desc: &'static str,
+    pub color_vision_normal: &'static str,
+    pub color_vision_deuteranopia: &'static str,
+    pub color_vision_protanopia: &'static str,
+    pub color_vision_tritanopia: &'static str,
     pub enabled: &'static str,
     pub disabled: &'static str,
 
@@ -324,6 +788,39 @@ pub struct Strings {
     pub dyslexic_font_label: &'static str,
     pub nerd_fonts: &'static str,
     pub system_information: &'static str,
+    pub collection_timings: &'static str,
+    pub collection_timings_desc: &'static str,
+    pub diagnostics: &'static str,
+    pub diagnostics_desc: &'static str,
+    pub create_diagnostics_bundle: &'static str,
+    pub crash_title: &'static str,
+    pub crash_desc: &'static str,
+    pub open_report: &'static str,
+    pub dismiss: &'static str,
+    pub history_unavailable_title: &'static str,
+    pub history_unavailable_desc: &'static str,
+    pub retry: &'static str,
+    pub reset_history_db: &'static str,
+    pub history_reset_title: &'static str,
+    pub history_reset_desc: &'static str,
+    pub open_folder: &'static str,
+    pub history_restored: &'static str,
+    pub updates: &'static str,
+    pub updates_desc: &'static str,
+    pub check_for_updates: &'static str,
+    pub check_weekly: &'static str,
+    pub check_weekly_desc: &'static str,
+    pub checking_updates: &'static str,
+    pub up_to_date: &'static str,
+    pub update_available: &'static str,
+    pub update_failed: &'static str,
+    pub release_notes: &'static str,
+    pub kiosk_mode: &'static str,
+    pub kiosk_mode_desc: &'static str,
+    pub rotate_panels: &'static str,
+    pub rotate_panels_desc: &'static str,
+    pub panel_rotation: &'static str,
+    pub panel_rotation_desc: &'static str,
     pub hostname: &'static str,
     pub os: &'static str,
     pub os_version: &'static str,
@@ -348,6 +845,27 @@ static EN: Strings = Strings {
     tab_processes: "Processes",
     tab_history: "History",
     tab_events: "Events",
+    tab_logs: "Logs",
+    tab_hardware: "Hardware",
+    hw_reading: "Reading hardware inventory…",
+    hw_system: "System",
+    hw_motherboard: "Motherboard",
+    hw_bios: "BIOS",
+    hw_release_date: "Release date",
+    hw_sockets: "Sockets",
+    hw_physical_cores: "Physical cores",
+    hw_max_clock: "Max clock",
+    hw_caches: "Caches",
+    hw_slots_used: "{used} of {total} slots used",
+    hw_slot: "Slot",
+    hw_size: "Size",
+    hw_speed: "Speed",
+    hw_part_number: "Part number",
+    hw_no_dimms: "Memory modules unavailable",
+    hw_graphics: "Graphics",
+    hw_driver: "Driver",
+    hw_storage: "Storage",
+    hw_no_drives: "No drives found",
     cpu: "CPU",
     memory: "Memory",
     network: "Network",
@@ -355,14 +873,29 @@ static EN: Strings = Strings {
     temp: "Temp",
     gpu: "GPU",
     load: "Load",
-    sensors: "sensors",
+    cpu_queue: "CPU queue",
+    cpu_queue_label: "Processor queue:",
+    sensors: Plural::new("{n} sensor", "{n} sensors"),
+    process_count: Plural::new("{n} process", "{n} processes"),
+    gpu_count: Plural::new("{n} GPU", "{n} GPUs"),
     n_a: "N/A",
+    top_cpu: "Top CPU",
+    top_memory: "Top memory",
     per_core_usage: "Per-core usage",
     system_info: "System info",
     model: "Model",
     logical_cores: "Logical cores",
     base_speed: "Base speed",
     utilization: "Utilization",
+    cpu_time_split: "CPU time",
+    io_wait: "I/O wait",
+    steal_time: "Steal time",
+    virtualized: "Virtualized",
+    page_faults: "Page faults",
+    minor_faults: "Minor",
+    major_faults: "Major",
+    hugepages: "Hugepages",
+    transparent_hugepages: "Transparent hugepages",
     processes: "Processes",
     uptime: "Uptime",
     load_avg: "Load avg:",
@@ -375,6 +908,30 @@ static EN: Strings = Strings {
     virtual_memory_total: "Virtual memory (total)",
     throughput: "Throughput",
     interfaces: "Interfaces",
+    connection_quality: "Connection quality",
+    latency: "Latency",
+    jitter: "Jitter",
+    packet_loss: "Packet loss",
+    quality_score: "Score (MOS)",
+    waiting_for_ping: "Waiting for the first reply from {host}...",
+    quality: "Quality",
+    lan_devices: "Devices on this network",
+    lan_scan_desc: "Probe every address of the local subnet to list the devices on it. Only runs when you start it.",
+    scan_network: "Scan network",
+    addresses_probed: "{done} of {total} addresses probed",
+    no_lan_devices: "No devices answered",
+    ip_address: "Address",
+    mac_address: "MAC",
+    vendor: "Vendor",
+    private_mac: "Private (randomized)",
+    sessions: "Sessions",
+    no_sessions: "No logged-in users found",
+    session_user: "User",
+    session_line: "Terminal",
+    session_from: "From",
+    session_since: "Logged in",
+    session_duration: "Duration",
+    session_local: "local",
     receive: "Receive",
     send: "Send",
     disk_drives: "Disk drives",
@@ -394,18 +951,40 @@ static EN: Strings = Strings {
     type_label: "Type",
     removable: "Removable",
     fixed: "Fixed",
-    drives: "drives",
+    drives: Plural::new("{n} drive", "{n} drives"),
+    scan_disk: "Find large folders",
+    largest_directories: "Largest directories",
+    scanning: "Scanning",
+    scan_complete: "Scan complete",
+    files: Plural::new("{n} file", "{n} files"),
+    cancel: "Cancel",
+    close: "Close",
     temperatures: "Temperatures",
     temperature_overview: "Temperature overview",
     all_sensors: "All sensors",
     no_sensors: "No sensors detected",
+    stale_since: "Stale since {age}: the backend stopped updating",
     minimum: "Minimum",
     maximum: "Maximum",
     average: "Average",
     no_gpu: "No GPU detected.",
+    system_power: "System Power",
+    power_source: "Source",
+    power_rapl: "CPU package (RAPL)",
+    power_battery: "Battery discharge",
+    no_power: "No power reading available.",
+    no_power_hint: "RAPL counters may need root; battery discharge is shown only while unplugged.",
     vram: "VRAM",
     vram_usage: "VRAM usage",
+    gpu_processes: "GPU processes",
+    no_gpu_processes: "No process is using GPU memory.",
+    sensor_permission_denied: "{backend}: permission denied. Run Digger with elevated privileges or grant read access to the sensors.",
+    sensor_driver_missing: "{backend}: no driver exposes any sensors. Install or load the hardware monitoring driver.",
+    sensor_service_missing: "{backend}: {service} is not installed or not running. Start it to read these sensors.",
     power: "Power",
+    fan_speed: "Fan",
+    core_clock: "Core clock",
+    memory_clock: "Memory clock",
     temperature: "Temperature",
     filter: "Filter:",
     search: "search...",
@@ -415,28 +994,147 @@ static EN: Strings = Strings {
     background_processes: "Background processes",
     system: "System",
     command: "Command",
+    command_line: "Command line",
+    export_processes: "Export",
     action: "Action",
     range: "Range:",
+    range_custom: "Custom",
+    follow_live: "Follow",
+    chart_average: "Average",
+    chart_min_max: "Min/max",
+    range_invalid_date: "Enter dates as YYYY-MM-DD HH:MM",
+    range_empty: "The start must be before the end",
+    range_future: "The range starts in the future",
+    range_expired: "This range is older than the kept history",
+    show_in_history: "Show in History",
     no_history_data: "No history data yet.",
     cpu_history: "CPU History",
     memory_history: "Memory History",
     network_history: "Network History",
+    power_history: "Power History",
+    cpu_heatmap: "CPU by hour of week",
+    cpu_heatmap_desc: "Average CPU per hour over the last 7 days (limited by history retention)",
+    weekdays_short: "Mon,Tue,Wed,Thu,Fri,Sat,Sun",
+    distribution: "Distribution",
     event_log: "Event Log",
-    events: "events",
+    events: Plural::new("{n} event", "{n} events"),
     no_events: "No events recorded yet.",
+    event_repeated: "×{n} since {time}",
+    system_tag: "system",
+    background_activity: "Background activity",
+    session_idle: "Idle",
+    session_active: "Active",
+    idle_time: "Idle time",
+    cpu_time: "CPU time",
+    wakeups: "Wake-ups",
+    no_background_activity: "No background activity recorded while idle.",
+    reset: "Reset",
+    remove: "Remove",
+    end_process: "End process",
+    pin_process: "Pin to top",
+    unpin_process: "Unpin",
     general_settings: "General Settings",
     settings_saved_auto: "Settings are saved automatically.",
     refresh_rate: "Refresh rate",
     refresh_rate_desc: "How often metrics are collected.",
     temperature_unit: "Temperature unit",
+    sensor_calibration: "Sensor calibration",
+    sensor_calibration_desc: "Offset added to a sensor's reading before it is shown, recorded or checked against alerts",
     celsius: "Celsius",
     fahrenheit: "Fahrenheit",
+    kelvin: "Kelvin",
+    rate_bits: "Network rates in bits",
+    rate_bits_desc: "Show throughput in bit/s (Mbit/s) instead of bytes/s",
+    rate_si: "Decimal rate prefixes",
+    rate_si_desc: "Use k/M/G (1000) instead of Ki/Mi/Gi (1024)",
+    utc_times: "Times in UTC",
+    utc_times_desc: "Show chart axes, event times and exports in UTC instead of local time",
+    memory_unit: "Process memory unit",
+    memory_unit_desc: "Unit of the memory column in the process list",
+    cpu_per_core: "Per-core process CPU",
+    cpu_per_core_desc: "Show process CPU as a share of one core, like htop's Irix mode: a busy single thread reads 100%, not 100% ÷ cores",
+    chart_smoothing: "Smooth live charts",
+    chart_smoothing_desc: "Draw live charts as an exponential moving average to calm per-second noise; readouts and alerts keep the raw values",
+    smoothing_alpha: "Smoothing factor",
+    smoothing_alpha_desc: "Weight of the newest sample (α); lower is smoother but lags more",
+    offer_elevation: "Offer administrator rights",
+    offer_elevation_desc: "When stopping another user's process is denied, offer to retry through the system's administrator prompt",
+    read_only_mode: "Read-only mode",
+    read_only_mode_desc: "Hide and disable actions that affect other processes, such as stopping them, for shared or demo machines",
+    read_only_flag: "Set by --read-only",
+    read_only: "Read-only",
+    schema_sidecar: "CSV schema file",
+    schema_sidecar_desc: "Write a .schema.json file next to CSV exports with units, column meanings, host and time zone (JSON exports always include them)",
+    recorded_metrics: "Recorded metrics",
+    recorded_metrics_desc: "Optional metrics that are collected and kept in history; turning one off saves collection time and database space. CPU and memory are always recorded.",
+    record_network: "Network",
+    record_network_desc: "Interface counters and throughput",
+    record_gpu: "GPU",
+    record_gpu_desc: "GPU devices and GPU processes",
+    record_power: "Power",
+    record_power_desc: "System power draw",
+    record_disk_usage: "Drive usage",
+    record_disk_usage_desc: "Per-drive usage samples every 5 minutes, used for fill-up forecasts. The Disks panel keeps working.",
+    collection_off: "Collection is turned off in Settings > Data.",
+    adaptive_recording: "Adaptive recording",
+    adaptive_recording_desc: "Keep every sample in history only while CPU is at or above the threshold, otherwise one every 10 s, so the database stays small but busy periods stay detailed",
+    full_rate_above: "Full rate above",
+    full_rate_above_desc: "CPU usage at which every sample is recorded",
+    elevation_title: "Administrator rights needed",
+    elevation_desc: "Stopping {name} (PID {pid}) was denied. Digger can ask {prompt} for your password to run this command once as administrator:",
+    run_as_admin: "Run as administrator",
+    threads_loading: "Reading threads…",
+    threads_unavailable: "Thread details are only available on Linux",
+    proc_executable: "Executable",
+    proc_cwd: "Working directory",
+    proc_stdout: "Standard output",
+    proc_stderr: "Standard error",
+    proc_listening: "Listening on",
+    proc_connections: "Connections",
+    proc_no_sockets: "None",
+    proc_more_sockets: "+{count} more",
+    output_terminal: "Terminal {device}",
+    output_journal: "systemd journal",
+    output_pipe: "Pipe",
+    output_socket: "Socket",
+    output_discarded: "Discarded (/dev/null)",
+    row_density: "Row density",
+    row_density_desc: "Padding and text size of the process and event tables",
+    density_comfortable: "Comfortable",
+    density_compact: "Compact",
+    touch_mode: "Touch mode",
+    touch_mode_desc: "Larger controls and tap-sized table rows; pull the process list down from the top to refresh. For convertibles and tablets.",
+    pull_to_refresh: "Pull to refresh",
+    release_to_refresh: "Release to refresh",
+    auto_unit: "Auto",
+    changes: "Changes",
+    no_changes: "Nothing changed",
+    started_processes: "Started",
+    exited_processes: "Exited",
+    cpu_movers: "Biggest CPU changes",
+    memory_movers: "Biggest memory changes",
+    link_up: "link up",
+    link_down: "link down",
+    watched_processes: "Watched processes",
+    watched_processes_desc: "Notify when a process with this name starts or exits (W on a selected process)",
+    port_watch: "New listening ports",
+    port_watch_desc: "Log a warning when a program starts listening on a TCP port that was closed on the previous refresh",
+    port_watch_notify: "Notify on new ports",
+    port_watch_notify_desc: "Also send a desktop notification for each new port",
+    port_allowlist: "Allowed ports and programs",
+    port_allowlist_desc: "Port numbers or process names that never raise the warning",
+    port_or_process: "Port or process name",
+    process_name: "Process name",
+    add: "Add",
     monitoring: "Monitoring",
     monitoring_desc: "Configure data collection and display preferences.",
     process_limit: "Process limit",
     process_limit_desc: "Maximum processes shown in the list.",
+    search_all_processes: "Search all processes",
+    search_all_processes_desc: "Match the search against every process before the limit is applied, so idle ones can be found by name",
     history_buffer: "History buffer",
     history_buffer_desc: "Number of live data points kept in memory.",
+    history_buffer_span: "Live charts cover {span} at the current refresh interval.",
     history_retention: "History retention",
     history_retention_desc: "How long historical data is kept.",
     display: "Display",
@@ -444,6 +1142,19 @@ static EN: Strings = Strings {
     data: "Data",
     history_database: "History database",
     history_database_desc: "Metrics are stored in a local SQLite database.",
+    log_file: "Log file",
+    log_file_desc: "Follow this file in a Logs tab. Leave empty to hide it.",
+    ping_host: "Ping host",
+    ping_host_desc: "Ping this host every 2 s to rate connection quality in the Network panel. Leave empty to turn off.",
+    apply: "Apply",
+    save_png: "Save as PNG",
+    copy_data: "Copy data",
+    chart_data_copied: "Chart data copied to clipboard (CSV)",
+    copy_system_summary: "Copy system summary",
+    system_summary_copied: "System summary copied to clipboard (Markdown)",
+    report_weekly: "Weekly report",
+    report_monthly: "Monthly report",
+    lines: Plural::new("{n} line", "{n} lines"),
     active: "Active",
     unavailable: "Unavailable",
     alerts: "Alerts",
@@ -452,18 +1163,55 @@ static EN: Strings = Strings {
     cpu_threshold_desc: "Warn when CPU usage exceeds this.",
     memory_threshold: "Memory threshold",
     memory_threshold_desc: "Warn when memory usage exceeds this.",
+    vram_threshold: "VRAM threshold",
+    vram_threshold_desc: "Warn when GPU memory usage exceeds this.",
+    gpu_temp_threshold: "GPU temperature threshold",
+    gpu_temp_threshold_desc: "Warn when a GPU gets hotter than this.",
+    temp_threshold: "Sensor temperature threshold",
+    temp_threshold_desc: "Alert when any sensor gets hotter than this. Type a value in the display unit and press Enter.",
+    invalid_temperature: "Not a temperature: {value}",
+    net_notifications: "Network change notifications",
+    net_notifications_desc: "Notify when an interface goes up or down.",
+    notify_level: "Desktop notifications",
+    notify_level_desc: "Lowest event severity shown as a notification; critical ones stay until dismissed",
+    notify_all: "All",
+    notify_warnings: "Warnings",
+    notify_critical: "Critical",
+    notify_off: "Off",
+    anomaly_sensitivity: "Spike sensitivity",
+    anomaly_sensitivity_desc: "How far above its recent average CPU or memory must go to be logged as a spike",
+    sensitivity_low: "Low",
+    sensitivity_medium: "Medium",
+    sensitivity_high: "High",
+    forecast_horizon: "Capacity warning",
+    forecast_horizon_desc: "Warn when a drive or swap is projected to fill up within this time",
+    projected_full: "Projected full",
+    full_in: "Full in ~{time}",
+    system_log_events: "System log errors",
+    system_log_events_desc: "Show recent OS error entries in the Event Log.",
     appearance: "Appearance",
     appearance_desc: "Customize the look and feel.",
     theme: "Theme",
     theme_desc: "Select a color theme.",
     accent_color: "Accent color",
     accent_color_desc: "Choose the accent color used for highlights.",
+    custom_accent_desc: "Drag the sliders or type a hex code such as #1E90FF.",
+    accent_hue: "Hue",
+    accent_saturation: "Saturation",
+    accent_brightness: "Brightness",
+    invalid_color: "Not a hex color: {value}",
     accessibility: "Accessibility",
     accessibility_desc: "Options for improved readability.",
     fonts: "Fonts",
     fonts_desc: "Alternative font options for better readability.",
     dyslexic_font: "OpenDyslexic font",
     dyslexic_font_desc: "Use a dyslexia-friendly font.",
+    color_vision: "Color vision",
+    color_vision_desc: "Replace the green, yellow and red status colors of gauges, charts and lists with ones that stay distinguishable.",
+    color_vision_normal: "Default",
+    color_vision_deuteranopia: "Deuteranopia",
+    color_vision_protanopia: "Protanopia",
+    color_vision_tritanopia: "Tritanopia",
     enabled: "Enabled",
     disabled: "Disabled",
     about_digger: "About Digger",
@@ -477,6 +1225,39 @@ static EN: Strings = Strings {
     dyslexic_font_label: "Dyslexic font",
     nerd_fonts: "Nerd Fonts",
     system_information: "System information",
+    collection_timings: "Collection timings",
+    collection_timings_desc: "Average and worst time per stage over the last minute",
+    diagnostics: "Diagnostics",
+    diagnostics_desc: "Zip of preferences (home folder and host name removed), recent events, collection timings, platform info and the latest history rows, to attach to bug reports",
+    create_diagnostics_bundle: "Create diagnostics bundle",
+    crash_title: "Digger closed unexpectedly last time",
+    crash_desc: "A report with the error and a backtrace was saved. Attaching it to a bug report helps get the problem fixed.",
+    open_report: "Open report",
+    dismiss: "Dismiss",
+    history_unavailable_title: "History is unavailable",
+    history_unavailable_desc: "The history database could not be opened, so nothing is being recorded. Retry after fixing the cause, or move the file aside and start an empty database.",
+    retry: "Retry",
+    reset_history_db: "Reset database",
+    history_reset_title: "History was reset",
+    history_reset_desc: "The history database was damaged, so a fresh one was started. The old file was kept here:",
+    open_folder: "Open folder",
+    history_restored: "History database reopened",
+    updates: "Updates",
+    updates_desc: "Look for a newer release on GitHub. Nothing is downloaded or installed",
+    check_for_updates: "Check for updates",
+    check_weekly: "Check weekly",
+    check_weekly_desc: "Check in the background once a week and notify when a new release is out",
+    checking_updates: "Checking…",
+    up_to_date: "Digger {version} is up to date",
+    update_available: "Digger {version} is available",
+    update_failed: "Update check failed: {error}",
+    release_notes: "Release notes",
+    kiosk_mode: "Kiosk mode",
+    kiosk_mode_desc: "Fullscreen wall display: larger text, no settings button, Overview panels rotate on their own. Esc leaves it until the next start",
+    rotate_panels: "Rotate Overview panels",
+    rotate_panels_desc: "Step through CPU, Memory, Disk, Network… on their own, e.g. on a second monitor. Pauses while the pointer is over the Overview",
+    panel_rotation: "Panel rotation",
+    panel_rotation_desc: "How long each Overview panel stays on screen when rotating",
     hostname: "Hostname",
     os: "OS",
     os_version: "OS version",
@@ -502,11 +1283,33 @@ macro_rules! lang {
 }
 
 // ─── FRENCH ─────────────────────────────────────────────────────────
-lang!(FR {
+// Complete, so written out in full like English rather than through `lang!`.
+static FR: Strings = Strings {
     tab_overview: "Vue d'ensemble",
     tab_processes: "Processus",
     tab_history: "Historique",
     tab_events: "Événements",
+    tab_logs: "Journaux",
+    tab_hardware: "Matériel",
+    hw_reading: "Lecture de l'inventaire matériel…",
+    hw_system: "Système",
+    hw_motherboard: "Carte mère",
+    hw_bios: "BIOS",
+    hw_release_date: "Date de publication",
+    hw_sockets: "Sockets",
+    hw_physical_cores: "Cœurs physiques",
+    hw_max_clock: "Fréquence max",
+    hw_caches: "Caches",
+    hw_slots_used: "{used} emplacements utilisés sur {total}",
+    hw_slot: "Emplacement",
+    hw_size: "Taille",
+    hw_speed: "Vitesse",
+    hw_part_number: "Référence",
+    hw_no_dimms: "Modules mémoire indisponibles",
+    hw_graphics: "Graphique",
+    hw_driver: "Pilote",
+    hw_storage: "Stockage",
+    hw_no_drives: "Aucun disque trouvé",
     cpu: "CPU",
     memory: "Mémoire",
     network: "Réseau",
@@ -514,14 +1317,29 @@ lang!(FR {
     temp: "Temp",
     gpu: "GPU",
     load: "Charge",
-    sensors: "capteurs",
+    cpu_queue: "File CPU",
+    cpu_queue_label: "File du processeur :",
+    sensors: Plural::new("{n} capteur", "{n} capteurs"),
+    process_count: Plural::new("{n} processus", "{n} processus"),
+    gpu_count: Plural::new("{n} GPU", "{n} GPU"),
     n_a: "N/D",
+    top_cpu: "Top CPU",
+    top_memory: "Top mémoire",
     per_core_usage: "Utilisation par cœur",
     system_info: "Infos système",
     model: "Modèle",
     logical_cores: "Cœurs logiques",
     base_speed: "Fréquence de base",
     utilization: "Utilisation",
+    cpu_time_split: "Temps CPU",
+    io_wait: "Attente E/S",
+    steal_time: "Temps volé",
+    virtualized: "Virtualisé",
+    page_faults: "Défauts de page",
+    minor_faults: "Mineurs",
+    major_faults: "Majeurs",
+    hugepages: "Pages énormes",
+    transparent_hugepages: "Pages énormes transparentes",
     processes: "Processus",
     uptime: "Temps de fonctionnement",
     load_avg: "Charge moy. :",
@@ -534,6 +1352,30 @@ lang!(FR {
     virtual_memory_total: "Mémoire virtuelle (total)",
     throughput: "Débit",
     interfaces: "Interfaces",
+    connection_quality: "Qualité de connexion",
+    latency: "Latence",
+    jitter: "Gigue",
+    packet_loss: "Perte de paquets",
+    quality_score: "Score (MOS)",
+    waiting_for_ping: "En attente de la première réponse de {host}...",
+    quality: "Qualité",
+    lan_devices: "Appareils sur ce réseau",
+    lan_scan_desc: "Sonder chaque adresse du sous-réseau local pour lister les appareils présents. Ne s'exécute que lorsque vous le lancez.",
+    scan_network: "Analyser le réseau",
+    addresses_probed: "{done} adresses sondées sur {total}",
+    no_lan_devices: "Aucun appareil n'a répondu",
+    ip_address: "Adresse",
+    mac_address: "MAC",
+    vendor: "Fabricant",
+    private_mac: "Privée (aléatoire)",
+    sessions: "Sessions",
+    no_sessions: "Aucun utilisateur connecté trouvé",
+    session_user: "Utilisateur",
+    session_line: "Terminal",
+    session_from: "Depuis",
+    session_since: "Connecté",
+    session_duration: "Durée",
+    session_local: "local",
     receive: "Réception",
     send: "Envoi",
     disk_drives: "Lecteurs de disque",
@@ -553,18 +1395,40 @@ lang!(FR {
     type_label: "Type",
     removable: "Amovible",
     fixed: "Fixe",
-    drives: "lecteurs",
+    drives: Plural::new("{n} lecteur", "{n} lecteurs"),
+    scan_disk: "Trouver les gros dossiers",
+    largest_directories: "Plus gros dossiers",
+    scanning: "Analyse en cours",
+    scan_complete: "Analyse terminée",
+    files: Plural::new("{n} fichier", "{n} fichiers"),
+    cancel: "Annuler",
+    close: "Fermer",
     temperatures: "Températures",
     temperature_overview: "Aperçu des températures",
     all_sensors: "Tous les capteurs",
     no_sensors: "Aucun capteur détecté",
+    stale_since: "Figé depuis {age} : le module ne se met plus à jour",
     minimum: "Minimum",
     maximum: "Maximum",
     average: "Moyenne",
     no_gpu: "Aucun GPU détecté.",
+    system_power: "Puissance système",
+    power_source: "Source",
+    power_rapl: "Package CPU (RAPL)",
+    power_battery: "Décharge de la batterie",
+    no_power: "Aucune mesure de puissance disponible.",
+    no_power_hint: "Les compteurs RAPL peuvent nécessiter root ; la décharge n'est affichée que sur batterie.",
     vram: "VRAM",
     vram_usage: "Utilisation VRAM",
+    gpu_processes: "Processus GPU",
+    no_gpu_processes: "Aucun processus n'utilise la mémoire GPU.",
+    sensor_permission_denied: "{backend} : accès refusé. Lancez Digger avec des privilèges élevés ou autorisez la lecture des capteurs.",
+    sensor_driver_missing: "{backend} : aucun pilote n'expose de capteurs. Installez ou chargez le pilote de surveillance matérielle.",
+    sensor_service_missing: "{backend} : {service} n'est pas installé ou n'est pas lancé. Démarrez-le pour lire ces capteurs.",
     power: "Puissance",
+    fan_speed: "Ventilateur",
+    core_clock: "Fréquence GPU",
+    memory_clock: "Fréquence mémoire",
     temperature: "Température",
     filter: "Filtre :",
     search: "rechercher...",
@@ -574,28 +1438,147 @@ lang!(FR {
     background_processes: "Processus en arrière-plan",
     system: "Système",
     command: "Commande",
+    command_line: "Ligne de commande",
+    export_processes: "Exporter",
     action: "Action",
     range: "Plage :",
+    range_custom: "Personnalisée",
+    follow_live: "Suivre",
+    chart_average: "Moyenne",
+    chart_min_max: "Min/max",
+    range_invalid_date: "Saisissez les dates au format AAAA-MM-JJ HH:MM",
+    range_empty: "Le début doit précéder la fin",
+    range_future: "La plage commence dans le futur",
+    range_expired: "Cette plage est antérieure à l'historique conservé",
+    show_in_history: "Afficher dans l'historique",
     no_history_data: "Pas encore de données historiques.",
     cpu_history: "Historique CPU",
     memory_history: "Historique mémoire",
     network_history: "Historique réseau",
+    power_history: "Historique de puissance",
+    cpu_heatmap: "CPU par heure de la semaine",
+    cpu_heatmap_desc: "CPU moyen par heure sur les 7 derniers jours (selon la durée de conservation)",
+    weekdays_short: "lun,mar,mer,jeu,ven,sam,dim",
+    distribution: "Distribution",
     event_log: "Journal d'événements",
-    events: "événements",
+    events: Plural::new("{n} événement", "{n} événements"),
     no_events: "Aucun événement enregistré.",
+    event_repeated: "×{n} depuis {time}",
+    system_tag: "système",
+    background_activity: "Activité en arrière-plan",
+    session_idle: "Inactif",
+    session_active: "Actif",
+    idle_time: "Temps d'inactivité",
+    cpu_time: "Temps CPU",
+    wakeups: "Réveils",
+    no_background_activity: "Aucune activité en arrière-plan pendant l'inactivité.",
+    reset: "Réinitialiser",
+    remove: "Retirer",
+    end_process: "Terminer le processus",
+    pin_process: "Épingler en haut",
+    unpin_process: "Désépingler",
     general_settings: "Paramètres généraux",
     settings_saved_auto: "Les paramètres sont enregistrés automatiquement.",
     refresh_rate: "Taux de rafraîchissement",
     refresh_rate_desc: "Fréquence de collecte des métriques.",
     temperature_unit: "Unité de température",
+    sensor_calibration: "Étalonnage des capteurs",
+    sensor_calibration_desc: "Décalage ajouté à la mesure d'un capteur avant affichage, enregistrement et vérification des alertes",
     celsius: "Celsius",
     fahrenheit: "Fahrenheit",
+    kelvin: "Kelvin",
+    rate_bits: "Débits réseau en bits",
+    rate_bits_desc: "Afficher les débits en bit/s (Mbit/s) plutôt qu'en octets/s",
+    rate_si: "Préfixes de débit décimaux",
+    rate_si_desc: "Utiliser k/M/G (1000) au lieu de Ki/Mi/Gi (1024)",
+    utc_times: "Heures en UTC",
+    utc_times_desc: "Afficher les axes des graphiques, l'heure des événements et les exports en UTC plutôt qu'en heure locale",
+    memory_unit: "Unité mémoire des processus",
+    memory_unit_desc: "Unité de la colonne mémoire de la liste des processus",
+    cpu_per_core: "CPU des processus par cœur",
+    cpu_per_core_desc: "Afficher le CPU des processus en part d'un cœur, comme le mode Irix de htop : un thread occupé affiche 100 %, et non 100 % ÷ cœurs",
+    chart_smoothing: "Lisser les graphiques en direct",
+    chart_smoothing_desc: "Tracer les graphiques en direct en moyenne mobile exponentielle pour atténuer le bruit de chaque seconde ; les valeurs affichées et les alertes restent brutes",
+    smoothing_alpha: "Facteur de lissage",
+    smoothing_alpha_desc: "Poids du relevé le plus récent (α) ; plus bas, c'est plus lisse mais avec plus de retard",
+    offer_elevation: "Proposer les droits administrateur",
+    offer_elevation_desc: "Quand l'arrêt du processus d'un autre utilisateur est refusé, proposer de réessayer via l'invite administrateur du système",
+    read_only_mode: "Mode lecture seule",
+    read_only_mode_desc: "Masquer et désactiver les actions qui agissent sur d'autres processus, comme leur arrêt, pour les machines partagées ou de démonstration",
+    read_only_flag: "Imposé par --read-only",
+    read_only: "Lecture seule",
+    schema_sidecar: "Fichier de schéma CSV",
+    schema_sidecar_desc: "Écrire un fichier .schema.json à côté des exports CSV avec les unités, le sens des colonnes, l'hôte et le fuseau horaire (toujours inclus dans les exports JSON)",
+    recorded_metrics: "Métriques enregistrées",
+    recorded_metrics_desc: "Métriques facultatives collectées et conservées dans l'historique ; en désactiver une économise du temps de collecte et de l'espace disque. Le CPU et la mémoire sont toujours enregistrés.",
+    record_network: "Réseau",
+    record_network_desc: "Compteurs des interfaces et débit",
+    record_gpu: "GPU",
+    record_gpu_desc: "Cartes graphiques et processus GPU",
+    record_power: "Consommation",
+    record_power_desc: "Consommation électrique du système",
+    record_disk_usage: "Occupation des disques",
+    record_disk_usage_desc: "Relevés d'occupation par disque toutes les 5 minutes, utilisés pour les prévisions de remplissage. Le panneau Disques reste actif.",
+    collection_off: "La collecte est désactivée dans Paramètres > Données.",
+    adaptive_recording: "Enregistrement adaptatif",
+    adaptive_recording_desc: "Conserver chaque relevé dans l'historique seulement quand le CPU atteint le seuil, sinon un toutes les 10 s : la base reste petite et les périodes chargées restent détaillées",
+    full_rate_above: "Pleine résolution au-dessus de",
+    full_rate_above_desc: "Utilisation CPU à partir de laquelle chaque relevé est enregistré",
+    elevation_title: "Droits administrateur requis",
+    elevation_desc: "L'arrêt de {name} (PID {pid}) a été refusé. Digger peut demander votre mot de passe via {prompt} pour exécuter une seule fois cette commande en administrateur :",
+    run_as_admin: "Exécuter en administrateur",
+    threads_loading: "Lecture des threads…",
+    threads_unavailable: "Le détail des threads n'est disponible que sous Linux",
+    proc_executable: "Exécutable",
+    proc_cwd: "Répertoire de travail",
+    proc_stdout: "Sortie standard",
+    proc_stderr: "Sortie d'erreur",
+    proc_listening: "En écoute sur",
+    proc_connections: "Connexions",
+    proc_no_sockets: "Aucune",
+    proc_more_sockets: "+{count} de plus",
+    output_terminal: "Terminal {device}",
+    output_journal: "Journal systemd",
+    output_pipe: "Tube",
+    output_socket: "Socket",
+    output_discarded: "Ignorée (/dev/null)",
+    row_density: "Densité des lignes",
+    row_density_desc: "Espacement et taille du texte des tableaux de processus et d'événements",
+    density_comfortable: "Confortable",
+    density_compact: "Compacte",
+    touch_mode: "Mode tactile",
+    touch_mode_desc: "Commandes plus grandes et lignes de tableau adaptées au toucher ; tirer la liste des processus vers le bas pour l'actualiser. Pour les convertibles et tablettes.",
+    pull_to_refresh: "Tirer pour actualiser",
+    release_to_refresh: "Relâcher pour actualiser",
+    auto_unit: "Auto",
+    changes: "Changements",
+    no_changes: "Aucun changement",
+    started_processes: "Démarrés",
+    exited_processes: "Terminés",
+    cpu_movers: "Plus fortes variations CPU",
+    memory_movers: "Plus fortes variations mémoire",
+    link_up: "lien actif",
+    link_down: "lien coupé",
+    watched_processes: "Processus surveillés",
+    watched_processes_desc: "Notifier quand un processus de ce nom démarre ou se termine (W sur un processus sélectionné)",
+    port_watch: "Nouveaux ports en écoute",
+    port_watch_desc: "Journaliser un avertissement quand un programme se met à écouter sur un port TCP fermé lors de l'actualisation précédente",
+    port_watch_notify: "Notifier les nouveaux ports",
+    port_watch_notify_desc: "Envoyer aussi une notification de bureau pour chaque nouveau port",
+    port_allowlist: "Ports et programmes autorisés",
+    port_allowlist_desc: "Numéros de port ou noms de processus qui ne déclenchent jamais l'avertissement",
+    port_or_process: "Port ou nom de processus",
+    process_name: "Nom du processus",
+    add: "Ajouter",
     monitoring: "Surveillance",
     monitoring_desc: "Configurer la collecte de données et les préférences d'affichage.",
     process_limit: "Limite de processus",
     process_limit_desc: "Nombre maximum de processus affichés.",
+    search_all_processes: "Rechercher dans tous les processus",
+    search_all_processes_desc: "Appliquer la recherche à tous les processus avant la limite, pour retrouver par leur nom ceux qui sont inactifs",
     history_buffer: "Tampon d'historique",
     history_buffer_desc: "Nombre de points de données en mémoire.",
+    history_buffer_span: "Les graphiques en direct couvrent {span} à l'intervalle de rafraîchissement actuel.",
     history_retention: "Rétention de l'historique",
     history_retention_desc: "Durée de conservation des données.",
     display: "Affichage",
@@ -603,6 +1586,19 @@ lang!(FR {
     data: "Données",
     history_database: "Base de données historique",
     history_database_desc: "Les métriques sont stockées dans une base SQLite locale.",
+    log_file: "Fichier journal",
+    log_file_desc: "Suivre ce fichier dans un onglet Journaux. Laisser vide pour le masquer.",
+    ping_host: "Hôte à pinger",
+    ping_host_desc: "Pinger cet hôte toutes les 2 s pour évaluer la qualité de connexion dans le panneau Réseau. Laisser vide pour désactiver.",
+    apply: "Appliquer",
+    save_png: "Enregistrer en PNG",
+    copy_data: "Copier les données",
+    chart_data_copied: "Données du graphique copiées (CSV)",
+    copy_system_summary: "Copier le résumé système",
+    system_summary_copied: "Résumé système copié dans le presse-papiers (Markdown)",
+    report_weekly: "Rapport hebdomadaire",
+    report_monthly: "Rapport mensuel",
+    lines: Plural::new("{n} ligne", "{n} lignes"),
     active: "Active",
     unavailable: "Indisponible",
     alerts: "Alertes",
@@ -611,18 +1607,55 @@ lang!(FR {
     cpu_threshold_desc: "Avertir quand l'utilisation CPU dépasse ce seuil.",
     memory_threshold: "Seuil mémoire",
     memory_threshold_desc: "Avertir quand l'utilisation mémoire dépasse ce seuil.",
+    vram_threshold: "Seuil VRAM",
+    vram_threshold_desc: "Avertir quand l'utilisation de la mémoire GPU dépasse cette valeur.",
+    gpu_temp_threshold: "Seuil de température GPU",
+    gpu_temp_threshold_desc: "Avertir quand un GPU dépasse cette température.",
+    temp_threshold: "Seuil de température des capteurs",
+    temp_threshold_desc: "Alerter quand un capteur dépasse cette température. Saisissez une valeur dans l'unité d'affichage puis Entrée.",
+    invalid_temperature: "Température invalide : {value}",
+    net_notifications: "Notifications réseau",
+    net_notifications_desc: "Notifier quand une interface se connecte ou se déconnecte.",
+    notify_level: "Notifications du bureau",
+    notify_level_desc: "Gravité minimale affichée en notification ; les critiques restent jusqu'à leur fermeture",
+    notify_all: "Toutes",
+    notify_warnings: "Avertissements",
+    notify_critical: "Critiques",
+    notify_off: "Désactivées",
+    anomaly_sensitivity: "Sensibilité aux pics",
+    anomaly_sensitivity_desc: "Écart au-dessus de la moyenne récente du CPU ou de la mémoire pour signaler un pic",
+    sensitivity_low: "Faible",
+    sensitivity_medium: "Moyenne",
+    sensitivity_high: "Élevée",
+    forecast_horizon: "Alerte de capacité",
+    forecast_horizon_desc: "Avertir si un disque ou le swap doit être plein dans ce délai",
+    projected_full: "Plein prévu",
+    full_in: "Plein dans ~{time}",
+    system_log_events: "Erreurs du journal système",
+    system_log_events_desc: "Afficher les erreurs récentes du système dans le journal d'événements.",
     appearance: "Apparence",
     appearance_desc: "Personnaliser l'aspect visuel.",
     theme: "Thème",
     theme_desc: "Sélectionner un thème de couleurs.",
     accent_color: "Couleur d'accentuation",
     accent_color_desc: "Choisir la couleur d'accentuation pour les éléments en surbrillance.",
+    custom_accent_desc: "Déplacer les curseurs ou saisir un code hexadécimal comme #1E90FF.",
+    accent_hue: "Teinte",
+    accent_saturation: "Saturation",
+    accent_brightness: "Luminosité",
+    invalid_color: "Couleur hexadécimale invalide : {value}",
     accessibility: "Accessibilité",
     accessibility_desc: "Options pour une meilleure lisibilité.",
     fonts: "Polices",
     fonts_desc: "Polices alternatives pour une meilleure lisibilité.",
     dyslexic_font: "Police OpenDyslexic",
     dyslexic_font_desc: "Utiliser une police adaptée à la dyslexie.",
+    color_vision: "Vision des couleurs",
+    color_vision_desc: "Remplacer le vert, le jaune et le rouge des jauges, graphiques et listes par des couleurs qui restent distinguables.",
+    color_vision_normal: "Par défaut",
+    color_vision_deuteranopia: "Deutéranopie",
+    color_vision_protanopia: "Protanopie",
+    color_vision_tritanopia: "Tritanopie",
     enabled: "Activé",
     disabled: "Désactivé",
     about_digger: "À propos de Digger",
@@ -636,6 +1669,39 @@ lang!(FR {
     dyslexic_font_label: "Police dyslexie",
     nerd_fonts: "Nerd Fonts",
     system_information: "Informations système",
+    collection_timings: "Temps de collecte",
+    collection_timings_desc: "Temps moyen et maximal par étape sur la dernière minute",
+    diagnostics: "Diagnostic",
+    diagnostics_desc: "Archive zip des préférences (dossier personnel et nom d'hôte retirés), des événements récents, des temps de collecte, des infos plateforme et des dernières lignes d'historique, à joindre aux rapports de bug",
+    create_diagnostics_bundle: "Créer un paquet de diagnostic",
+    crash_title: "Digger s'est fermé de manière inattendue la dernière fois",
+    crash_desc: "Un rapport contenant l'erreur et une trace d'appels a été enregistré. Le joindre à un rapport de bug aide à corriger le problème.",
+    open_report: "Ouvrir le rapport",
+    dismiss: "Ignorer",
+    history_unavailable_title: "L'historique est indisponible",
+    history_unavailable_desc: "La base de l'historique n'a pas pu être ouverte, rien n'est enregistré. Réessayez après avoir corrigé la cause, ou mettez le fichier de côté et repartez d'une base vide.",
+    retry: "Réessayer",
+    reset_history_db: "Réinitialiser la base",
+    history_reset_title: "L'historique a été réinitialisé",
+    history_reset_desc: "La base de l'historique était endommagée, une nouvelle a été créée. L'ancien fichier a été conservé ici :",
+    open_folder: "Ouvrir le dossier",
+    history_restored: "Base de l'historique rouverte",
+    updates: "Mises à jour",
+    updates_desc: "Rechercher une version plus récente sur GitHub. Rien n'est téléchargé ni installé",
+    check_for_updates: "Rechercher des mises à jour",
+    check_weekly: "Vérifier chaque semaine",
+    check_weekly_desc: "Vérifier en arrière-plan une fois par semaine et prévenir à la sortie d'une nouvelle version",
+    checking_updates: "Vérification…",
+    up_to_date: "Digger {version} est à jour",
+    update_available: "Digger {version} est disponible",
+    update_failed: "Échec de la vérification : {error}",
+    release_notes: "Notes de version",
+    kiosk_mode: "Mode kiosque",
+    kiosk_mode_desc: "Affichage mural plein écran : texte agrandi, sans bouton de paramètres, les panneaux de l'aperçu défilent seuls. Échap le quitte jusqu'au prochain démarrage",
+    rotate_panels: "Faire défiler les panneaux de l'aperçu",
+    rotate_panels_desc: "Passer seul de CPU à Mémoire, Disque, Réseau…, par exemple sur un second écran. Se met en pause quand le pointeur survole l'aperçu",
+    panel_rotation: "Rotation des panneaux",
+    panel_rotation_desc: "Durée d'affichage de chaque panneau de l'aperçu pendant la rotation",
     hostname: "Nom d'hôte",
     os: "SE",
     os_version: "Version SE",
@@ -647,7 +1713,7 @@ lang!(FR {
     language_desc: "Sélectionner la langue de l'interface.",
     collecting_data: "Collecte des données...",
     currently: "Actuellement :",
-});
+};
 
 // ─── SPANISH ────────────────────────────────────────────────────────
 lang!(ES {
@@ -660,7 +1726,7 @@ lang!(ES {
     disk: "Disco",
     temp: "Temp",
     load: "Carga",
-    sensors: "sensores",
+    sensors: Plural::new("{n} sensor", "{n} sensores"),
     n_a: "N/D",
     per_core_usage: "Uso por núcleo",
     system_info: "Info del sistema",
@@ -698,7 +1764,7 @@ lang!(ES {
     type_label: "Tipo",
     removable: "Extraíble",
     fixed: "Fijo",
-    drives: "unidades",
+    drives: Plural::new("{n} unidad", "{n} unidades"),
     temperatures: "Temperaturas",
     temperature_overview: "Resumen de temperaturas",
     all_sensors: "Todos los sensores",
@@ -725,7 +1791,7 @@ lang!(ES {
     memory_history: "Historial de memoria",
     network_history: "Historial de red",
     event_log: "Registro de eventos",
-    events: "eventos",
+    events: Plural::new("{n} evento", "{n} eventos"),
     no_events: "No se han registrado eventos.",
     general_settings: "Configuración general",
     settings_saved_auto: "La configuración se guarda automáticamente.",
@@ -795,7 +1861,7 @@ lang!(PT {
     network: "Rede",
     disk: "Disco",
     load: "Carga",
-    sensors: "sensores",
+    sensors: Plural::new("{n} sensor", "{n} sensores"),
     per_core_usage: "Uso por núcleo",
     system_info: "Info do sistema",
     model: "Modelo",
@@ -831,7 +1897,7 @@ lang!(PT {
     type_label: "Tipo",
     removable: "Removível",
     fixed: "Fixo",
-    drives: "unidades",
+    drives: Plural::new("{n} unidade", "{n} unidades"),
     temperatures: "Temperaturas",
     temperature_overview: "Visão geral das temperaturas",
     all_sensors: "Todos os sensores",
@@ -858,7 +1924,7 @@ lang!(PT {
     memory_history: "Histórico de memória",
     network_history: "Histórico de rede",
     event_log: "Registo de eventos",
-    events: "eventos",
+    events: Plural::new("{n} evento", "{n} eventos"),
     no_events: "Nenhum evento registado.",
     general_settings: "Definições gerais",
     settings_saved_auto: "As definições são guardadas automaticamente.",
@@ -919,7 +1985,7 @@ lang!(DE {
     disk: "Festplatte",
     temp: "Temp",
     load: "Last",
-    sensors: "Sensoren",
+    sensors: Plural::new("{n} Sensor", "{n} Sensoren"),
     per_core_usage: "Nutzung pro Kern",
     system_info: "Systeminfo",
     model: "Modell",
@@ -956,7 +2022,7 @@ lang!(DE {
     type_label: "Typ",
     removable: "Wechselbar",
     fixed: "Fest",
-    drives: "Laufwerke",
+    drives: Plural::new("{n} Laufwerk", "{n} Laufwerke"),
     temperatures: "Temperaturen",
     temperature_overview: "Temperaturübersicht",
     all_sensors: "Alle Sensoren",
@@ -983,7 +2049,7 @@ lang!(DE {
     memory_history: "Speicher-Verlauf",
     network_history: "Netzwerk-Verlauf",
     event_log: "Ereignisprotokoll",
-    events: "Ereignisse",
+    events: Plural::new("{n} Ereignis", "{n} Ereignisse"),
     no_events: "Keine Ereignisse aufgezeichnet.",
     general_settings: "Allgemeine Einstellungen",
     settings_saved_auto: "Einstellungen werden automatisch gespeichert.",
@@ -1051,7 +2117,7 @@ lang!(IT {
     network: "Rete",
     disk: "Disco",
     load: "Carico",
-    sensors: "sensori",
+    sensors: Plural::new("{n} sensore", "{n} sensori"),
     per_core_usage: "Uso per nucleo",
     system_info: "Info sistema",
     model: "Modello",
@@ -1087,7 +2153,7 @@ lang!(IT {
     type_label: "Tipo",
     removable: "Rimovibile",
     fixed: "Fisso",
-    drives: "unità",
+    drives: Plural::new("{n} unità", "{n} unità"),
     temperatures: "Temperature",
     temperature_overview: "Panoramica temperature",
     all_sensors: "Tutti i sensori",
@@ -1108,7 +2174,7 @@ lang!(IT {
     range: "Intervallo:",
     no_history_data: "Nessun dato storico.",
     event_log: "Registro eventi",
-    events: "eventi",
+    events: Plural::new("{n} evento", "{n} eventi"),
     no_events: "Nessun evento registrato.",
     general_settings: "Impostazioni generali",
     settings_saved_auto: "Le impostazioni vengono salvate automaticamente.",
@@ -1153,7 +2219,7 @@ lang!(NL {
     network: "Netwerk",
     disk: "Schijf",
     load: "Belasting",
-    sensors: "sensoren",
+    sensors: Plural::new("{n} sensor", "{n} sensoren"),
     per_core_usage: "Gebruik per kern",
     system_info: "Systeeminfo",
     model: "Model",
@@ -1187,7 +2253,7 @@ lang!(NL {
     device: "Apparaat",
     removable: "Verwijderbaar",
     fixed: "Vast",
-    drives: "stations",
+    drives: Plural::new("{n} station", "{n} stations"),
     temperatures: "Temperaturen",
     temperature_overview: "Temperatuuroverzicht",
     all_sensors: "Alle sensoren",
@@ -1206,7 +2272,7 @@ lang!(NL {
     range: "Bereik:",
     no_history_data: "Nog geen historische gegevens.",
     event_log: "Gebeurtenissenlogboek",
-    events: "gebeurtenissen",
+    events: Plural::new("{n} gebeurtenis", "{n} gebeurtenissen"),
     no_events: "Geen gebeurtenissen geregistreerd.",
     general_settings: "Algemene instellingen",
     settings_saved_auto: "Instellingen worden automatisch opgeslagen.",
@@ -1252,7 +2318,7 @@ lang!(RU {
     disk: "Диск",
     temp: "Темп",
     load: "Нагрузка",
-    sensors: "датчиков",
+    sensors: Plural::slavic("{n} датчик", "{n} датчика", "{n} датчиков"),
     per_core_usage: "Загрузка по ядрам",
     system_info: "Информация о системе",
     model: "Модель",
@@ -1287,7 +2353,7 @@ lang!(RU {
     type_label: "Тип",
     removable: "Съёмный",
     fixed: "Фиксированный",
-    drives: "накопителей",
+    drives: Plural::slavic("{n} накопитель", "{n} накопителя", "{n} накопителей"),
     temperatures: "Температуры",
     temperature_overview: "Обзор температур",
     all_sensors: "Все датчики",
@@ -1309,7 +2375,7 @@ lang!(RU {
     memory_history: "История памяти",
     network_history: "История сети",
     event_log: "Журнал событий",
-    events: "событий",
+    events: Plural::slavic("{n} событие", "{n} события", "{n} событий"),
     no_events: "Событий не зарегистрировано.",
     general_settings: "Общие настройки",
     settings_saved_auto: "Настройки сохраняются автоматически.",
@@ -1348,7 +2414,7 @@ lang!(RU {
 lang!(ZH_CN {
     tab_overview: "概览", tab_processes: "进程", tab_history: "历史", tab_events: "事件",
     memory: "内存", network: "网络", disk: "磁盘", temp: "温度", load: "负载",
-    sensors: "传感器", per_core_usage: "每核使用率", system_info: "系统信息",
+    sensors: Plural::invariant("{n} 传感器"), per_core_usage: "每核使用率", system_info: "系统信息",
     model: "型号", logical_cores: "逻辑核心", base_speed: "基础频率",
     utilization: "使用率", processes: "进程", uptime: "运行时间",
     in_use: "已使用", available: "可用", usage: "使用率",
@@ -1359,7 +2425,7 @@ lang!(ZH_CN {
     io_throughput: "I/O吞吐量", read: "读取", write: "写入",
     used: "已用", free: "空闲", total: "总计",
     file_system: "文件系统", mount_point: "挂载点", device: "设备",
-    type_label: "类型", removable: "可移动", fixed: "固定", drives: "驱动器",
+    type_label: "类型", removable: "可移动", fixed: "固定", drives: Plural::invariant("{n} 驱动器"),
     temperatures: "温度", temperature_overview: "温度概览",
     all_sensors: "所有传感器", no_sensors: "未检测到传感器",
     no_gpu: "未检测到GPU。", temperature: "温度", power: "功耗",
@@ -1369,7 +2435,7 @@ lang!(ZH_CN {
     command: "命令", action: "操作",
     range: "范围：", no_history_data: "暂无历史数据。",
     cpu_history: "CPU历史", memory_history: "内存历史", network_history: "网络历史",
-    event_log: "事件日志", events: "事件", no_events: "尚无事件记录。",
+    event_log: "事件日志", events: Plural::invariant("{n} 事件"), no_events: "尚无事件记录。",
     general_settings: "常规设置", settings_saved_auto: "设置自动保存。",
     refresh_rate: "刷新频率", refresh_rate_desc: "数据采集频率。",
     temperature_unit: "温度单位",
@@ -1392,7 +2458,7 @@ lang!(ZH_CN {
 lang!(ZH_TW {
     tab_overview: "概覽", tab_processes: "程序", tab_history: "歷史", tab_events: "事件",
     memory: "記憶體", network: "網路", disk: "磁碟", temp: "溫度", load: "負載",
-    sensors: "感測器", per_core_usage: "每核使用率", system_info: "系統資訊",
+    sensors: Plural::invariant("{n} 感測器"), per_core_usage: "每核使用率", system_info: "系統資訊",
     model: "型號", logical_cores: "邏輯核心", base_speed: "基礎頻率",
     utilization: "使用率", processes: "程序", uptime: "運行時間",
     in_use: "使用中", available: "可用", usage: "使用率",
@@ -1414,7 +2480,7 @@ lang!(ZH_TW {
 lang!(JA {
     tab_overview: "概要", tab_processes: "プロセス", tab_history: "履歴", tab_events: "イベント",
     memory: "メモリ", network: "ネットワーク", disk: "ディスク", temp: "温度", load: "負荷",
-    sensors: "センサー", per_core_usage: "コア別使用率", system_info: "システム情報",
+    sensors: Plural::invariant("{n} センサー"), per_core_usage: "コア別使用率", system_info: "システム情報",
     model: "モデル", logical_cores: "論理コア", base_speed: "基本速度",
     utilization: "使用率", processes: "プロセス", uptime: "稼働時間",
     in_use: "使用中", available: "利用可能", usage: "使用率",
@@ -1438,7 +2504,7 @@ lang!(JA {
 lang!(KO {
     tab_overview: "개요", tab_processes: "프로세스", tab_history: "기록", tab_events: "이벤트",
     memory: "메모리", network: "네트워크", disk: "디스크", temp: "온도", load: "부하",
-    sensors: "센서", per_core_usage: "코어별 사용률", system_info: "시스템 정보",
+    sensors: Plural::invariant("{n} 센서"), per_core_usage: "코어별 사용률", system_info: "시스템 정보",
     model: "모델", logical_cores: "논리 코어", base_speed: "기본 속도",
     utilization: "사용률", processes: "프로세스", uptime: "가동 시간",
     in_use: "사용 중", available: "사용 가능", usage: "사용률",
@@ -1462,7 +2528,7 @@ lang!(KO {
 lang!(AR {
     tab_overview: "نظرة عامة", tab_processes: "العمليات", tab_history: "السجل", tab_events: "الأحداث",
     memory: "الذاكرة", network: "الشبكة", disk: "القرص", temp: "الحرارة", load: "الحمل",
-    sensors: "مستشعرات", per_core_usage: "الاستخدام لكل نواة", system_info: "معلومات النظام",
+    sensors: Plural::invariant("{n} مستشعرات"), per_core_usage: "الاستخدام لكل نواة", system_info: "معلومات النظام",
     processes: "العمليات", utilization: "الاستخدام", uptime: "وقت التشغيل",
     in_use: "قيد الاستخدام", available: "متاح", usage: "الاستخدام",
     throughput: "الإنتاجية", receive: "استقبال", send: "إرسال",
@@ -1483,7 +2549,7 @@ lang!(AR {
 lang!(HI {
     tab_overview: "अवलोकन", tab_processes: "प्रक्रियाएं", tab_history: "इतिहास", tab_events: "घटनाएं",
     memory: "मेमोरी", network: "नेटवर्क", disk: "डिस्क", load: "लोड",
-    sensors: "सेंसर", system_info: "सिस्टम जानकारी",
+    sensors: Plural::invariant("{n} सेंसर"), system_info: "सिस्टम जानकारी",
     processes: "प्रक्रियाएं", utilization: "उपयोग", uptime: "अपटाइम",
     in_use: "उपयोग में", available: "उपलब्ध",
     filter: "फ़िल्टर:", search: "खोजें...", grouped: "समूहित", all: "सभी",
@@ -1547,7 +2613,7 @@ lang!(MR {
 lang!(TR {
     tab_overview: "Genel Bakış", tab_processes: "İşlemler", tab_history: "Geçmiş", tab_events: "Olaylar",
     memory: "Bellek", network: "Ağ", disk: "Disk", load: "Yük",
-    sensors: "sensör", per_core_usage: "Çekirdek başına kullanım", system_info: "Sistem bilgisi",
+    sensors: Plural::invariant("{n} sensör"), per_core_usage: "Çekirdek başına kullanım", system_info: "Sistem bilgisi",
     processes: "İşlemler", utilization: "Kullanım", uptime: "Çalışma süresi",
     in_use: "Kullanımda", available: "Kullanılabilir",
     filter: "Filtre:", search: "ara...", grouped: "Gruplu", all: "Tümü",
@@ -1566,7 +2632,7 @@ lang!(TR {
 lang!(PL {
     tab_overview: "Przegląd", tab_processes: "Procesy", tab_history: "Historia", tab_events: "Zdarzenia",
     memory: "Pamięć", network: "Sieć", disk: "Dysk", load: "Obciążenie",
-    sensors: "czujników", per_core_usage: "Użycie na rdzeń", system_info: "Info o systemie",
+    sensors: Plural::slavic("{n} czujnik", "{n} czujniki", "{n} czujników"), per_core_usage: "Użycie na rdzeń", system_info: "Info o systemie",
     processes: "Procesy", utilization: "Wykorzystanie", uptime: "Czas pracy",
     in_use: "W użyciu", available: "Dostępne",
     filter: "Filtr:", search: "szukaj...", grouped: "Grupowane", all: "Wszystkie",
@@ -1585,7 +2651,7 @@ lang!(PL {
 lang!(UK {
     tab_overview: "Огляд", tab_processes: "Процеси", tab_history: "Історія", tab_events: "Події",
     memory: "Пам'ять", network: "Мережа", disk: "Диск", load: "Навантаження",
-    sensors: "датчиків", system_info: "Інформація про систему",
+    sensors: Plural::slavic("{n} датчик", "{n} датчики", "{n} датчиків"), system_info: "Інформація про систему",
     processes: "Процеси", utilization: "Використання", uptime: "Час роботи",
     in_use: "Використовується", available: "Доступно",
     filter: "Фільтр:", search: "пошук...", grouped: "Згруповано", all: "Усі",
@@ -1865,3 +2931,91 @@ lang!(ET {
     enabled: "Lubatud", disabled: "Keelatud",
     alerts: "Hoiatused",
 });
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// What a field holds, as plain text.
+    pub(super) trait Texts {
+        fn texts(&self) -> Vec<&'static str>;
+    }
+
+    impl Texts for &'static str {
+        fn texts(&self) -> Vec<&'static str> {
+            vec![self]
+        }
+    }
+
+    impl Texts for Plural {
+        fn texts(&self) -> Vec<&'static str> {
+            vec![self.one, self.other]
+        }
+    }
+
+    #[test]
+    fn test_no_empty_strings() {
+        for &lang in Language::ALL {
+            for (field, texts) in lang.strings().fields() {
+                assert!(texts.iter().all(|t| !t.trim().is_empty()), "{lang:?}.{field} is empty");
+            }
+        }
+    }
+
+    /// French fields spelled as in English: acronyms, units and cognates.
+    const FR_SAME_AS_EN: &[&str] = &[
+        "hw_bios", "hw_sockets", "hw_caches", "cpu", "temp", "gpu", "top_cpu", "swap", "interfaces",
+        "quality_score", "mac_address", "sessions", "session_line", "session_local", "total", "type_label",
+        "minimum", "maximum", "power_source", "vram", "applications", "action", "chart_min_max",
+        "distribution", "celsius", "fahrenheit", "kelvin", "record_gpu", "output_terminal", "output_socket",
+        "auto_unit", "active", "accent_saturation", "version", "application", "framework", "nerd_fonts",
+    ];
+
+    /// French is complete: anything still in English was missed.
+    #[test]
+    fn test_french_is_translated() {
+        let en = Language::En.strings().fields();
+        let fr = Language::Fr.strings().fields();
+        let untranslated: Vec<_> = fr
+            .iter()
+            .zip(&en)
+            .filter(|((field, fr), (_, en))| fr == en && !FR_SAME_AS_EN.contains(field))
+            .map(|((field, _), _)| field)
+            .collect();
+        assert!(untranslated.is_empty(), "FR still in English: {untranslated:?}");
+    }
+
+    #[test]
+    fn test_plural_forms_keep_placeholder() {
+        for &lang in Language::ALL {
+            let t = lang.strings();
+            for plural in [t.sensors, t.process_count, t.gpu_count, t.drives, t.files, t.events, t.lines] {
+                for n in [0, 1, 2, 3, 5, 11, 21, 22, 25, 101] {
+                    let text = plural.format(lang, n);
+                    assert!(text.contains(&n.to_string()), "{lang:?}: {text:?} lacks the count");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_plural_categories() {
+        use PluralCategory::*;
+        let cats = |lang: Language| [0, 1, 2, 5, 11, 21, 22, 25].map(|n| lang.plural_category(n));
+        assert_eq!(cats(Language::En), [Other, One, Other, Other, Other, Other, Other, Other]);
+        assert_eq!(cats(Language::Fr), [One, One, Other, Other, Other, Other, Other, Other]);
+        assert_eq!(cats(Language::Ru), [Many, One, Few, Many, Many, One, Few, Many]);
+        assert_eq!(cats(Language::Pl), [Many, One, Few, Many, Many, Many, Few, Many]);
+        assert_eq!(cats(Language::Ja), [Other; 8]);
+        assert_eq!(Language::Ar.plural_category(2), Two);
+    }
+
+    #[test]
+    fn test_slavic_plural_format() {
+        let t = Language::Ru.strings();
+        assert_eq!(t.sensors.format(Language::Ru, 1), "1 датчик");
+        assert_eq!(t.sensors.format(Language::Ru, 3), "3 датчика");
+        assert_eq!(t.sensors.format(Language::Ru, 5), "5 датчиков");
+        assert_eq!(fill("{a} of {b} {x}", &[("a", &1), ("b", &"two")]), "1 of two {x}");
+    }
+}
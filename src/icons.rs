@@ -13,6 +13,7 @@ pub const ICON_SEARCH: &str = "\u{f002}";        // nf-fa-search
 pub const ICON_BARS: &str = "\u{f0c9}";          // nf-fa-bars
 pub const ICON_LIST: &str = "\u{f0ae}";          // nf-fa-tasks
 pub const ICON_CLOCK: &str = "\u{f017}";         // nf-fa-clock-o
+pub const ICON_CALENDAR: &str = "\u{f073}";      // nf-fa-calendar
 pub const ICON_APPS: &str = "\u{f108}";          // nf-fa-desktop
 pub const ICON_BACKGROUND: &str = "\u{f0e8}";    // nf-fa-sitemap
 pub const ICON_SYSTEM: &str = "\u{f013}";        // nf-fa-gear
@@ -20,6 +21,7 @@ pub const ICON_USB: &str = "\u{f287}";           // nf-fa-usb
 pub const ICON_LOADING: &str = "\u{f254}";       // nf-fa-hourglass-2
 pub const ICON_ARROW_DOWN: &str = "\u{f063}";    // nf-fa-arrow-down
 pub const ICON_ARROW_UP: &str = "\u{f062}";      // nf-fa-arrow-up
+pub const ICON_ARROW_RIGHT: &str = "\u{f061}";   // nf-fa-arrow-right
 pub const ICON_SORT_UP: &str = "\u{f0de}";       // nf-fa-sort-asc
 pub const ICON_SORT_DOWN: &str = "\u{f0dd}";     // nf-fa-sort-desc
 pub const ICON_CLOSE: &str = "\u{f00d}";         // nf-fa-times
@@ -31,6 +33,7 @@ pub const ICON_TOGGLE_ON: &str = "\u{f205}";     // nf-fa-toggle-on
 pub const ICON_TOGGLE_OFF: &str = "\u{f204}";    // nf-fa-toggle-off
 pub const ICON_CHECK: &str = "\u{f00c}";         // nf-fa-check
 pub const ICON_CHEVRON_RIGHT: &str = "\u{f054}"; // nf-fa-chevron-right
+pub const ICON_CHEVRON_LEFT: &str = "\u{f053}";  // nf-fa-chevron-left
 pub const ICON_CHEVRON_DOWN: &str = "\u{f078}";  // nf-fa-chevron-down
 pub const ICON_SEPARATOR: &str = "\u{2502}";     // box-drawing vertical
 pub const ICON_DASH: &str = "\u{2500}";          // box-drawing horizontal
@@ -44,3 +47,13 @@ pub const ICON_THREAD: &str = "\u{f126}";        // nf-fa-code-fork
 pub const ICON_LOG: &str = "\u{f0ca}";           // nf-fa-list-ul
 pub const ICON_LOAD: &str = "\u{f080}";          // nf-fa-bar-chart
 pub const ICON_GPU: &str = "\u{f26c}";           // nf-fa-tv (GPU display)
+pub const ICON_FILE: &str = "\u{f15c}";          // nf-fa-file-text
+pub const ICON_IMAGE: &str = "\u{f03e}";         // nf-fa-picture-o
+pub const ICON_COPY: &str = "\u{f0c5}";          // nf-fa-files-o
+pub const ICON_EYE: &str = "\u{f06e}";           // nf-fa-eye
+pub const ICON_SHIELD: &str = "\u{f132}";        // nf-fa-shield
+pub const ICON_USERS: &str = "\u{f0c0}";         // nf-fa-users
+pub const ICON_SERVER: &str = "\u{f233}";        // nf-fa-server
+pub const ICON_REFRESH: &str = "\u{f021}";       // nf-fa-refresh
+pub const ICON_STAR: &str = "\u{f005}";          // nf-fa-star
+pub const ICON_STAR_EMPTY: &str = "\u{f006}";    // nf-fa-star-o
@@ -0,0 +1,318 @@
+//! Idle detection and background-activity accounting ("what woke my CPU").
+//!
+//! The session counts as idle when the screen is locked or no user input has
+//! been seen for `IDLE_THRESHOLD_SECS`. While idle, CPU time is attributed to
+//! processes so battery drains can be traced back to their source.
+//!
+//! Backends:
+//! 1. Linux: `loginctl show-session` (IdleHint / LockedHint)
+//! 2. macOS: `ioreg -c IOHIDSystem` (HIDIdleTime)
+//! 3. Windows: `GetLastInputInfo`
+//!
+//! The Linux and macOS probes spawn a process, so they run on a background
+//! thread and `is_session_idle` only reads their cached result.
+
+use std::collections::{HashMap, HashSet};
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+use std::sync::Mutex;
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+use std::time::Instant;
+
+use crate::metrics::ProcessInfo;
+
+/// Seconds without user input before the session is considered idle.
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+const IDLE_THRESHOLD_SECS: u64 = 300;
+
+/// Upper bound for a single accounting interval. Larger gaps (suspend,
+/// stalled ticks) are clamped so they don't inflate the totals.
+const MAX_TICK_GAP_SECS: f64 = 10.0;
+
+/// A process is "awake" above this share of one core (percent).
+const WAKE_THRESHOLD_PCT: f32 = 1.0;
+
+/// Result of the last idle probe, refreshed in the background.
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+static IDLE_CACHE: Mutex<Option<(Instant, bool)>> = Mutex::new(None);
+
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+static IDLE_REFRESH_RUNNING: AtomicBool = AtomicBool::new(false);
+
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+const IDLE_CACHE_TTL_SECS: u64 = 10;
+
+/// Whether the user session is currently idle or locked. On Linux and
+/// macOS this is the last probe's answer, `false` until the first one is
+/// done; a stale answer starts a new probe.
+pub fn is_session_idle() -> bool {
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    {
+        let (idle, fresh) = match IDLE_CACHE.lock() {
+            Ok(guard) => match *guard {
+                Some((ts, idle)) => (idle, ts.elapsed().as_secs() < IDLE_CACHE_TTL_SECS),
+                None => (false, false),
+            },
+            Err(_) => (false, true),
+        };
+        if !fresh && !IDLE_REFRESH_RUNNING.swap(true, Ordering::AcqRel) {
+            std::thread::spawn(|| {
+                let idle = query_idle_uncached();
+                if let Ok(mut guard) = IDLE_CACHE.lock() {
+                    *guard = Some((Instant::now(), idle));
+                }
+                IDLE_REFRESH_RUNNING.store(false, Ordering::Release);
+            });
+        }
+        idle
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        query_idle_uncached()
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+    {
+        false
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn query_idle_uncached() -> bool {
+    use std::process::Command;
+
+    let session = std::env::var("XDG_SESSION_ID").unwrap_or_else(|_| "self".to_string());
+    let output = Command::new("loginctl")
+        .args(["show-session", &session, "-p", "IdleHint", "-p", "LockedHint"])
+        .output();
+    match output {
+        Ok(o) if o.status.success() => parse_loginctl(&String::from_utf8_lossy(&o.stdout)),
+        _ => false,
+    }
+}
+
+/// Parse `loginctl show-session` properties: idle if either hint is set.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_loginctl(output: &str) -> bool {
+    output.lines().any(|line| {
+        matches!(line.trim(), "IdleHint=yes" | "LockedHint=yes")
+    })
+}
+
+#[cfg(target_os = "macos")]
+fn query_idle_uncached() -> bool {
+    use std::process::Command;
+
+    let output = Command::new("ioreg").args(["-c", "IOHIDSystem", "-d", "4"]).output();
+    match output {
+        Ok(o) if o.status.success() => parse_hid_idle_ns(&String::from_utf8_lossy(&o.stdout))
+            .map(|ns| ns / 1_000_000_000 >= IDLE_THRESHOLD_SECS)
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+/// Extract `"HIDIdleTime" = <nanoseconds>` from ioreg output.
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn parse_hid_idle_ns(output: &str) -> Option<u64> {
+    output.lines().find_map(|line| {
+        let rest = line.split("\"HIDIdleTime\"").nth(1)?;
+        rest.trim_start().strip_prefix('=')?.trim().parse().ok()
+    })
+}
+
+#[cfg(target_os = "windows")]
+fn query_idle_uncached() -> bool {
+    use windows_sys::Win32::System::SystemInformation::GetTickCount;
+    use windows_sys::Win32::UI::Input::KeyboardAndMouse::{GetLastInputInfo, LASTINPUTINFO};
+
+    unsafe {
+        let mut info = LASTINPUTINFO {
+            cbSize: std::mem::size_of::<LASTINPUTINFO>() as u32,
+            dwTime: 0,
+        };
+        if GetLastInputInfo(&mut info) == 0 {
+            return false;
+        }
+        let idle_ms = GetTickCount().wrapping_sub(info.dwTime);
+        u64::from(idle_ms) / 1000 >= IDLE_THRESHOLD_SECS
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Background activity accounting
+// ---------------------------------------------------------------------------
+
+/// CPU consumed by one process (grouped by name) while the session was idle.
+#[derive(Clone, Debug, Default)]
+pub struct BackgroundEntry {
+    pub name: String,
+    /// Accumulated CPU time in core-seconds.
+    pub cpu_secs: f64,
+    /// Number of times the process went from quiet to active.
+    pub wakeups: u32,
+}
+
+/// Accumulates per-process CPU time across idle periods.
+#[derive(Debug, Default)]
+pub struct IdleTracker {
+    idle: bool,
+    /// Timestamp the current idle period started.
+    idle_since: Option<f64>,
+    /// Idle seconds from finished periods (current period excluded).
+    finished_idle_secs: f64,
+    last_ts: Option<f64>,
+    entries: HashMap<Arc<str>, BackgroundEntry>,
+    /// Process names above `WAKE_THRESHOLD_PCT` on the previous idle tick.
+    active_prev: HashSet<Arc<str>>,
+}
+
+impl IdleTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed one tick. `processes` report CPU as a share of the whole machine,
+    /// so usage is scaled by `core_count` to get core-seconds.
+    pub fn update(&mut self, timestamp: f64, core_count: usize, processes: &[ProcessInfo], idle: bool) {
+        let dt = self
+            .last_ts
+            .map(|prev| (timestamp - prev).clamp(0.0, MAX_TICK_GAP_SECS))
+            .unwrap_or(0.0);
+        self.last_ts = Some(timestamp);
+
+        match (self.idle, idle) {
+            (false, true) => {
+                self.idle_since = Some(timestamp);
+                self.active_prev.clear();
+            }
+            (true, false) => {
+                self.finished_idle_secs += self.current_period_secs(timestamp);
+                self.idle_since = None;
+            }
+            _ => {}
+        }
+        let was_idle = self.idle;
+        self.idle = idle;
+        if !idle {
+            return;
+        }
+
+        let cores = core_count.max(1) as f64;
+        let mut active_now = HashSet::new();
+        for proc in processes {
+            let core_pct = proc.cpu_usage * cores as f32;
+            if core_pct < WAKE_THRESHOLD_PCT {
+                continue;
+            }
+            let entry = self.entries.entry(Arc::clone(&proc.name)).or_insert_with(|| BackgroundEntry {
+                name: proc.name.to_string(),
+                ..Default::default()
+            });
+            if was_idle {
+                entry.cpu_secs += core_pct as f64 / 100.0 * dt;
+            }
+            if !self.active_prev.contains(&proc.name) && !active_now.contains(&proc.name) {
+                entry.wakeups += 1;
+            }
+            active_now.insert(Arc::clone(&proc.name));
+        }
+        self.active_prev = active_now;
+    }
+
+    pub fn is_idle(&self) -> bool {
+        self.idle
+    }
+
+    /// Total idle time recorded, including the ongoing period.
+    pub fn idle_secs(&self) -> f64 {
+        self.finished_idle_secs + self.last_ts.map(|ts| self.current_period_secs(ts)).unwrap_or(0.0)
+    }
+
+    fn current_period_secs(&self, now: f64) -> f64 {
+        self.idle_since.map(|start| (now - start).max(0.0)).unwrap_or(0.0)
+    }
+
+    /// Top `n` processes by CPU time while idle.
+    pub fn top(&self, n: usize) -> Vec<&BackgroundEntry> {
+        let mut list: Vec<&BackgroundEntry> = self.entries.values().filter(|e| e.cpu_secs > 0.0).collect();
+        list.sort_by(|a, b| b.cpu_secs.total_cmp(&a.cpu_secs));
+        list.truncate(n);
+        list
+    }
+
+    /// Clear accumulated totals; the current idle state is kept.
+    pub fn reset(&mut self) {
+        self.entries.clear();
+        self.active_prev.clear();
+        self.finished_idle_secs = 0.0;
+        if self.idle {
+            self.idle_since = self.last_ts;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_loginctl() {
+        assert!(parse_loginctl("IdleHint=yes\nLockedHint=no\n"));
+        assert!(parse_loginctl("IdleHint=no\nLockedHint=yes\n"));
+        assert!(!parse_loginctl("IdleHint=no\nLockedHint=no\n"));
+        assert!(!parse_loginctl(""));
+    }
+
+    #[test]
+    fn test_parse_hid_idle_ns() {
+        let out = "    | |   \"HIDIdleTime\" = 4213000000\n";
+        assert_eq!(parse_hid_idle_ns(out), Some(4_213_000_000));
+        assert_eq!(parse_hid_idle_ns("\"Other\" = 1"), None);
+    }
+
+    #[test]
+    fn test_tracker_accumulates_only_while_idle() {
+        let mut t = IdleTracker::new();
+        // 4 cores, process at 25% of the machine = one full core.
+        let procs = vec![
+            ProcessInfo { cpu_usage: 25.0, ..ProcessInfo::for_test(1, "indexer") },
+            ProcessInfo { cpu_usage: 0.1, ..ProcessInfo::for_test(2, "quiet") },
+        ];
+        t.update(0.0, 4, &procs, false);
+        t.update(2.0, 4, &procs, false);
+        assert!(t.top(5).is_empty());
+
+        t.update(4.0, 4, &procs, true);
+        t.update(6.0, 4, &procs, true);
+        t.update(8.0, 4, &procs, true);
+        let top = t.top(5);
+        assert_eq!(top.len(), 1);
+        assert_eq!(top[0].name, "indexer");
+        assert!((top[0].cpu_secs - 4.0).abs() < 1e-6);
+        assert_eq!(top[0].wakeups, 1);
+        assert!((t.idle_secs() - 4.0).abs() < 1e-6);
+
+        t.update(10.0, 4, &procs, false);
+        assert!(!t.is_idle());
+        assert!((t.idle_secs() - 6.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_tracker_clamps_gaps_and_resets() {
+        let mut t = IdleTracker::new();
+        let procs = vec![ProcessInfo { cpu_usage: 50.0, ..ProcessInfo::for_test(1, "backup") }];
+        t.update(0.0, 2, &procs, true);
+        // A one-hour gap (e.g. suspend) only counts as MAX_TICK_GAP_SECS.
+        t.update(3600.0, 2, &procs, true);
+        assert!((t.top(1)[0].cpu_secs - MAX_TICK_GAP_SECS).abs() < 1e-6);
+
+        t.reset();
+        assert!(t.top(1).is_empty());
+        assert!(t.is_idle());
+        assert_eq!(t.idle_secs(), 0.0);
+    }
+}
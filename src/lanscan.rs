@@ -0,0 +1,408 @@
+//! On-demand scan of the local network.
+//!
+//! Only runs when started from the Network panel. Every address of the
+//! local IPv4 subnets (at most the /24 around each of our addresses) gets a
+//! TCP connection attempt with a short timeout from a small pool of
+//! threads. Whether or not anything listens, the kernel resolves the MAC
+//! address on the way, so afterwards the neighbour table (`/proc/net/arp`
+//! on Linux, `arp -a` elsewhere) lists the devices that are present.
+//! Vendors come from the IEEE OUI list when a copy is installed (hwdata,
+//! ieee-data or nmap), host names from reverse DNS on Unix. The UI polls
+//! `LanScan::progress()` on each frame and leaves out the vendor and host
+//! name columns where these lookups aren't available.
+
+use std::collections::{HashMap, HashSet};
+use std::io::{BufRead, BufReader};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, TcpStream};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Threads probing addresses at the same time.
+const WORKERS: usize = 32;
+/// How long a probe waits for an answer.
+const PROBE_TIMEOUT: Duration = Duration::from_millis(400);
+/// Port probed; any port works since only the ARP resolution matters,
+/// but a refusal from a common one answers faster than silence.
+const PROBE_PORT: u16 = 80;
+/// Installed copies of the IEEE OUI registry, tried in order.
+const OUI_FILES: &[&str] = &[
+    "/usr/share/hwdata/oui.txt",
+    "/usr/share/ieee-data/oui.txt",
+    "/usr/share/misc/oui.txt",
+    "/usr/share/nmap/nmap-mac-prefixes",
+    "/opt/homebrew/share/nmap/nmap-mac-prefixes",
+    "/usr/local/share/nmap/nmap-mac-prefixes",
+    "C:\\Program Files (x86)\\Nmap\\nmap-mac-prefixes",
+    "C:\\Program Files\\Nmap\\nmap-mac-prefixes",
+];
+/// Whether `LanDevice::hostname` can be filled in on this platform.
+pub const HOSTNAMES_SUPPORTED: bool = cfg!(unix);
+
+/// One device seen on the local network.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LanDevice {
+    pub ip: Ipv4Addr,
+    /// Lowercase, colon separated; None when the device answered but its
+    /// MAC isn't in the neighbour table.
+    pub mac: Option<String>,
+    pub vendor: Option<String>,
+    pub hostname: Option<String>,
+}
+
+impl LanDevice {
+    /// Locally administered MAC, as phones use for privacy; such addresses
+    /// have no vendor.
+    pub fn randomized_mac(&self) -> bool {
+        self.mac
+            .as_deref()
+            .and_then(|mac| u8::from_str_radix(mac.get(..2)?, 16).ok())
+            .is_some_and(|first| first & 0x02 != 0)
+    }
+}
+
+/// Snapshot of a running or finished scan.
+#[derive(Clone, Debug, Default)]
+pub struct LanScanProgress {
+    /// Scanned subnets, as `192.168.1.0/24`.
+    pub subnets: Vec<String>,
+    pub probed: usize,
+    pub total: usize,
+    /// Devices by address, filled in once probing is over.
+    pub devices: Vec<LanDevice>,
+    /// Whether an OUI registry was found to name vendors with.
+    pub vendors_available: bool,
+    pub done: bool,
+}
+
+/// Handle to a background scan. Dropping it cancels the scan.
+pub struct LanScan {
+    shared: Arc<Mutex<LanScanProgress>>,
+    cancel: Arc<AtomicBool>,
+}
+
+impl LanScan {
+    /// Start scanning the subnets of every interface on a new thread.
+    pub fn start() -> Self {
+        let shared = Arc::new(Mutex::new(LanScanProgress::default()));
+        let cancel = Arc::new(AtomicBool::new(false));
+
+        let thread_shared = Arc::clone(&shared);
+        let thread_cancel = Arc::clone(&cancel);
+        std::thread::spawn(move || {
+            scan(&thread_shared, &thread_cancel);
+            if let Ok(mut progress) = thread_shared.lock() {
+                progress.done = true;
+            }
+        });
+
+        Self { shared, cancel }
+    }
+
+    pub fn progress(&self) -> LanScanProgress {
+        self.shared.lock().map(|p| p.clone()).unwrap_or_default()
+    }
+}
+
+impl Drop for LanScan {
+    fn drop(&mut self) {
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+}
+
+fn scan(shared: &Mutex<LanScanProgress>, cancel: &AtomicBool) {
+    let networks = sysinfo::Networks::new_with_refreshed_list();
+    let own: Vec<(Ipv4Addr, u8)> = networks
+        .values()
+        .flat_map(|data| data.ip_networks())
+        .filter_map(|net| match net.addr {
+            IpAddr::V4(addr) if !addr.is_loopback() && !addr.is_link_local() => Some((addr, net.prefix)),
+            _ => None,
+        })
+        .collect();
+    let own_addrs: HashSet<Ipv4Addr> = own.iter().map(|(addr, _)| *addr).collect();
+    let mut subnets = Vec::new();
+    let mut targets = Vec::new();
+    for &(addr, prefix) in &own {
+        let Some((network, hosts)) = subnet_hosts(addr, prefix) else { continue };
+        let name = format!("{network}/{}", prefix.max(24));
+        if subnets.contains(&name) {
+            continue;
+        }
+        subnets.push(name);
+        targets.extend(hosts.filter(|ip| !own_addrs.contains(ip)));
+    }
+    if let Ok(mut progress) = shared.lock() {
+        progress.subnets = subnets;
+        progress.total = targets.len();
+        progress.vendors_available = OUI_FILES.iter().any(|path| std::path::Path::new(path).is_file());
+    }
+
+    // Probe everything, remembering who answered in case the neighbour
+    // table can't be read
+    let next = AtomicUsize::new(0);
+    let answered = Mutex::new(HashSet::new());
+    std::thread::scope(|s| {
+        for _ in 0..WORKERS.min(targets.len()) {
+            s.spawn(|| loop {
+                if cancel.load(Ordering::Relaxed) {
+                    return;
+                }
+                let Some(&ip) = targets.get(next.fetch_add(1, Ordering::Relaxed)) else { return };
+                let addr = SocketAddr::new(IpAddr::V4(ip), PROBE_PORT);
+                let alive = match TcpStream::connect_timeout(&addr, PROBE_TIMEOUT) {
+                    Ok(_) => true,
+                    Err(e) => e.kind() == std::io::ErrorKind::ConnectionRefused,
+                };
+                if alive {
+                    if let Ok(mut answered) = answered.lock() {
+                        answered.insert(ip);
+                    }
+                }
+                if let Ok(mut progress) = shared.lock() {
+                    progress.probed += 1;
+                }
+            });
+        }
+    });
+    if cancel.load(Ordering::Relaxed) {
+        return;
+    }
+
+    let wanted: HashSet<Ipv4Addr> = targets.iter().copied().collect();
+    let mut macs: HashMap<Ipv4Addr, String> = parse_neighbours(&read_neighbour_table())
+        .into_iter()
+        .filter(|(ip, _)| wanted.contains(ip))
+        .collect();
+    let mut ips: Vec<Ipv4Addr> = answered.into_inner().unwrap_or_default().into_iter().chain(macs.keys().copied()).collect();
+    ips.sort();
+    ips.dedup();
+    let vendors = lookup_vendors(macs.values().map(String::as_str));
+
+    // Reverse DNS can take seconds per address, so it gets the pool too
+    let next = AtomicUsize::new(0);
+    let hostnames: Mutex<HashMap<Ipv4Addr, String>> = Mutex::new(HashMap::new());
+    std::thread::scope(|s| {
+        for _ in 0..WORKERS.min(ips.len()) {
+            s.spawn(|| loop {
+                if cancel.load(Ordering::Relaxed) {
+                    return;
+                }
+                let Some(&ip) = ips.get(next.fetch_add(1, Ordering::Relaxed)) else { return };
+                if let (Some(name), Ok(mut names)) = (reverse_dns(ip), hostnames.lock()) {
+                    names.insert(ip, name);
+                }
+            });
+        }
+    });
+    let mut hostnames = hostnames.into_inner().unwrap_or_default();
+
+    let devices = ips
+        .into_iter()
+        .map(|ip| {
+            let mac = macs.remove(&ip);
+            let vendor = mac.as_deref().and_then(|m| vendors.get(&oui(m)?).cloned());
+            LanDevice { ip, mac, vendor, hostname: hostnames.remove(&ip) }
+        })
+        .collect();
+    if let Ok(mut progress) = shared.lock() {
+        progress.devices = devices;
+    }
+}
+
+/// Network address and host addresses of the subnet around `addr`. Larger
+/// subnets are narrowed to the /24 holding `addr`; point-to-point links
+/// (/31, /32) have nothing to scan.
+fn subnet_hosts(addr: Ipv4Addr, prefix: u8) -> Option<(Ipv4Addr, impl Iterator<Item = Ipv4Addr>)> {
+    if prefix > 30 {
+        return None;
+    }
+    let prefix = prefix.max(24);
+    let mask = u32::MAX << (32 - prefix);
+    let network = u32::from(addr) & mask;
+    let broadcast = network | !mask;
+    Some((Ipv4Addr::from(network), (network + 1..broadcast).map(Ipv4Addr::from)))
+}
+
+#[cfg(target_os = "linux")]
+fn read_neighbour_table() -> String {
+    std::fs::read_to_string("/proc/net/arp").unwrap_or_default()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_neighbour_table() -> String {
+    let mut cmd = std::process::Command::new("arp");
+    cmd.arg("-a");
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        // CREATE_NO_WINDOW: don't flash a console
+        cmd.creation_flags(0x0800_0000);
+    }
+    cmd.output().map(|o| String::from_utf8_lossy(&o.stdout).into_owned()).unwrap_or_default()
+}
+
+/// Address and MAC pairs from `/proc/net/arp` or `arp -a` output on
+/// macOS, the BSDs or Windows: any line holding both an IPv4 address and a
+/// MAC. Incomplete entries (all-zero MACs) and broadcast are left out.
+fn parse_neighbours(table: &str) -> Vec<(Ipv4Addr, String)> {
+    table
+        .lines()
+        .filter_map(|line| {
+            let mut ip = None;
+            let mut mac = None;
+            for token in line.split_whitespace() {
+                let token = token.trim_matches(|c| c == '(' || c == ')');
+                ip = ip.or_else(|| token.parse::<Ipv4Addr>().ok());
+                mac = mac.or_else(|| normalize_mac(token));
+            }
+            Some((ip?, mac?))
+        })
+        .filter(|(_, mac)| mac != "00:00:00:00:00:00" && mac != "ff:ff:ff:ff:ff:ff")
+        .collect()
+}
+
+/// `aa:bb:cc:dd:ee:ff` from a MAC written with `:` or `-` separators and
+/// possibly without leading zeros (`0:1c:b3:9:85:15` on macOS).
+fn normalize_mac(token: &str) -> Option<String> {
+    let parts: Vec<&str> = token.split([':', '-']).collect();
+    if parts.len() != 6 {
+        return None;
+    }
+    let octets: Option<Vec<String>> = parts
+        .iter()
+        .map(|p| if p.is_empty() || p.len() > 2 { None } else { u8::from_str_radix(p, 16).ok().map(|b| format!("{b:02x}")) })
+        .collect();
+    Some(octets?.join(":"))
+}
+
+/// First three octets of a normalized MAC.
+fn oui(mac: &str) -> Option<[u8; 3]> {
+    let mut octets = mac.split(':').map(|p| u8::from_str_radix(p, 16).ok());
+    Some([octets.next()??, octets.next()??, octets.next()??])
+}
+
+/// Vendor names for the OUIs of `macs` from the first OUI registry found.
+fn lookup_vendors<'a>(macs: impl Iterator<Item = &'a str>) -> HashMap<[u8; 3], String> {
+    let wanted: HashSet<[u8; 3]> = macs.filter_map(oui).collect();
+    let mut found = HashMap::new();
+    if wanted.is_empty() {
+        return found;
+    }
+    let Some(file) = OUI_FILES.iter().find_map(|path| std::fs::File::open(path).ok()) else { return found };
+    for line in BufReader::new(file).lines().map_while(Result::ok) {
+        if let Some((prefix, vendor)) = parse_oui_line(&line) {
+            if wanted.contains(&prefix) {
+                found.insert(prefix, vendor.to_string());
+                if found.len() == wanted.len() {
+                    break;
+                }
+            }
+        }
+    }
+    found
+}
+
+/// Prefix and vendor from an IEEE `oui.txt` line
+/// (`00-1B-63   (hex)  Apple, Inc.`) or an nmap one (`001B63 Apple`).
+/// The `(base 16)` line repeating each IEEE entry is skipped.
+fn parse_oui_line(line: &str) -> Option<([u8; 3], &str)> {
+    if line.contains("(base 16)") {
+        return None;
+    }
+    let (hex, vendor) = if let Some((hex, rest)) = line.split_once("(hex)") {
+        (hex.trim().replace('-', ""), rest.trim())
+    } else {
+        let (hex, rest) = line.split_once(char::is_whitespace)?;
+        (hex.to_string(), rest.trim())
+    };
+    if hex.len() != 6 || vendor.is_empty() {
+        return None;
+    }
+    let byte = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+    Some(([byte(0)?, byte(2)?, byte(4)?], vendor))
+}
+
+#[cfg(unix)]
+fn reverse_dns(ip: Ipv4Addr) -> Option<String> {
+    // SAFETY: sockaddr_in is plain data, zeroed is a valid value
+    let mut addr: libc::sockaddr_in = unsafe { std::mem::zeroed() };
+    addr.sin_family = libc::AF_INET as libc::sa_family_t;
+    addr.sin_addr.s_addr = u32::from(ip).to_be();
+    let mut host = [0 as libc::c_char; 256];
+    // SAFETY: addr and host outlive the call and their sizes are passed along
+    let rc = unsafe {
+        libc::getnameinfo(
+            &addr as *const libc::sockaddr_in as *const libc::sockaddr,
+            std::mem::size_of::<libc::sockaddr_in>() as libc::socklen_t,
+            host.as_mut_ptr(),
+            host.len() as libc::socklen_t,
+            std::ptr::null_mut(),
+            0,
+            libc::NI_NAMEREQD,
+        )
+    };
+    if rc != 0 {
+        return None;
+    }
+    // SAFETY: getnameinfo NUL-terminates host on success
+    let name = unsafe { std::ffi::CStr::from_ptr(host.as_ptr()) };
+    Some(name.to_string_lossy().into_owned())
+}
+
+#[cfg(not(unix))]
+fn reverse_dns(_ip: Ipv4Addr) -> Option<String> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_neighbours() {
+        let linux = "IP address       HW type     Flags       HW address            Mask     Device\n\
+                     192.168.1.1      0x1         0x2         a4:91:b1:0c:22:7e     *        eth0\n\
+                     192.168.1.40     0x1         0x0         00:00:00:00:00:00     *        eth0\n";
+        assert_eq!(parse_neighbours(linux), vec![("192.168.1.1".parse().unwrap(), "a4:91:b1:0c:22:7e".into())]);
+
+        let macos = "? (192.168.1.7) at 0:1c:b3:9:85:15 on en0 ifscope [ethernet]\n\
+                     ? (192.168.1.9) at (incomplete) on en0 ifscope [ethernet]\n";
+        assert_eq!(parse_neighbours(macos), vec![("192.168.1.7".parse().unwrap(), "00:1c:b3:09:85:15".into())]);
+
+        let windows = "Interface: 192.168.1.20 --- 0xb\n\
+                       \x20 Internet Address      Physical Address      Type\n\
+                       \x20 192.168.1.1           a4-91-b1-0c-22-7e     dynamic\n\
+                       \x20 192.168.1.255         ff-ff-ff-ff-ff-ff     static\n";
+        assert_eq!(parse_neighbours(windows), vec![("192.168.1.1".parse().unwrap(), "a4:91:b1:0c:22:7e".into())]);
+    }
+
+    #[test]
+    fn test_subnet_hosts() {
+        let (network, hosts) = subnet_hosts("10.0.3.17".parse().unwrap(), 16).unwrap();
+        assert_eq!(network, Ipv4Addr::new(10, 0, 3, 0));
+        let hosts: Vec<Ipv4Addr> = hosts.collect();
+        assert_eq!(hosts.len(), 254);
+        assert_eq!(hosts[0], Ipv4Addr::new(10, 0, 3, 1));
+        assert_eq!(hosts[253], Ipv4Addr::new(10, 0, 3, 254));
+
+        let (_, hosts) = subnet_hosts("192.168.1.5".parse().unwrap(), 30).unwrap();
+        assert_eq!(hosts.count(), 2);
+        assert!(subnet_hosts("192.168.1.5".parse().unwrap(), 32).is_none());
+    }
+
+    #[test]
+    fn test_parse_oui_line() {
+        assert_eq!(parse_oui_line("00-1B-63   (hex)\t\tApple, Inc."), Some(([0x00, 0x1b, 0x63], "Apple, Inc.")));
+        assert_eq!(parse_oui_line("001B63 Apple"), Some(([0x00, 0x1b, 0x63], "Apple")));
+        assert_eq!(parse_oui_line("001B63     (base 16)\t\tApple, Inc."), None);
+        assert_eq!(parse_oui_line(""), None);
+    }
+
+    #[test]
+    fn test_randomized_mac() {
+        let device = |mac: &str| LanDevice { ip: Ipv4Addr::LOCALHOST, mac: Some(mac.into()), vendor: None, hostname: None };
+        assert!(device("da:a1:19:00:00:01").randomized_mac());
+        assert!(!device("a4:91:b1:0c:22:7e").randomized_mac());
+    }
+}
@@ -0,0 +1,32 @@
+//! Opening files and URLs with the desktop's default handler.
+
+use std::ffi::OsStr;
+use std::process::{Command, Stdio};
+
+/// Hand `target` (a path or URL) to the platform opener without waiting for
+/// it to exit.
+pub fn open(target: impl AsRef<OsStr>) -> std::io::Result<()> {
+    #[cfg(target_os = "windows")]
+    let mut cmd = {
+        // `start` treats its first quoted argument as the window title
+        let mut cmd = Command::new("cmd");
+        cmd.args([OsStr::new("/C"), OsStr::new("start"), OsStr::new("")]).arg(target);
+        cmd
+    };
+    #[cfg(target_os = "macos")]
+    let mut cmd = {
+        let mut cmd = Command::new("open");
+        cmd.arg(target);
+        cmd
+    };
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    let mut cmd = {
+        let mut cmd = Command::new("xdg-open");
+        cmd.arg(target);
+        cmd
+    };
+    let mut child = cmd.stdin(Stdio::null()).stdout(Stdio::null()).stderr(Stdio::null()).spawn()?;
+    // Reap the opener so it doesn't linger as a zombie
+    std::thread::spawn(move || child.wait());
+    Ok(())
+}
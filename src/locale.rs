@@ -0,0 +1,389 @@
+//! Locale-aware formatting of numbers, percentages and clock times.
+//!
+//! Times are shown in local time or, when the user asks for it, in UTC; the
+//! same choice applies to chart axes, the event log and exports.
+//!
+//! The active locale follows the selected `Language`. It is stored globally so
+//! free-standing helpers such as `format_bytes` can use it without threading
+//! the language through every view function.
+
+use std::sync::atomic::{AtomicBool, AtomicU8, AtomicUsize, Ordering};
+
+use chrono::{DateTime, FixedOffset, Local, NaiveDate, NaiveDateTime, SecondsFormat, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::Language;
+
+/// Index into `Language::ALL` of the active language.
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+
+/// Active `RateUnits`, packed as bit flags.
+static RATE_UNITS: AtomicU8 = AtomicU8::new(0);
+const RATE_BITS: u8 = 1;
+const RATE_SI: u8 = 2;
+
+/// Show and export times in UTC instead of the local time zone.
+static UTC: AtomicBool = AtomicBool::new(false);
+
+/// First-strong-isolate / pop-directional-isolate, used so numbers and units
+/// keep their order inside right-to-left text.
+const FSI: char = '\u{2068}';
+const PDI: char = '\u{2069}';
+
+pub fn set_language(lang: Language) {
+    let idx = Language::ALL.iter().position(|&l| l == lang).unwrap_or(0);
+    CURRENT.store(idx, Ordering::Relaxed);
+}
+
+pub fn language() -> Language {
+    Language::ALL.get(CURRENT.load(Ordering::Relaxed)).copied().unwrap_or_default()
+}
+
+/// Whether the active language is written right-to-left; view builders
+/// mirror their layout when it is.
+pub fn is_rtl() -> bool {
+    language().is_rtl()
+}
+
+/// `value` with `decimals` fractional digits, e.g. "12.5" or "12,5".
+pub fn number(value: f64, decimals: usize) -> String {
+    number_in(language(), value, decimals)
+}
+
+/// Percentage, e.g. "42.0%".
+pub fn percent(value: f32, decimals: usize) -> String {
+    with_unit(value as f64, decimals, "%")
+}
+
+/// Number followed by a unit suffix (included verbatim, e.g. " GiB", "°C").
+pub fn with_unit(value: f64, decimals: usize, unit: &str) -> String {
+    with_unit_in(language(), value, decimals, unit)
+}
+
+/// Unit used to display temperatures. Sensors always report Celsius.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TempUnit {
+    #[default]
+    Celsius,
+    Fahrenheit,
+    Kelvin,
+}
+
+impl TempUnit {
+    pub const ALL: &'static [TempUnit] = &[TempUnit::Celsius, TempUnit::Fahrenheit, TempUnit::Kelvin];
+
+    pub fn symbol(self) -> &'static str {
+        match self {
+            TempUnit::Celsius => "\u{00b0}C",
+            TempUnit::Fahrenheit => "\u{00b0}F",
+            TempUnit::Kelvin => " K",
+        }
+    }
+
+    /// Convert a Celsius reading to this unit.
+    pub fn convert(self, celsius: f64) -> f64 {
+        match self {
+            TempUnit::Celsius => celsius,
+            TempUnit::Fahrenheit => celsius * 9.0 / 5.0 + 32.0,
+            TempUnit::Kelvin => celsius + 273.15,
+        }
+    }
+
+    /// Convert a reading in this unit back to Celsius.
+    pub fn to_celsius(self, value: f64) -> f64 {
+        match self {
+            TempUnit::Celsius => value,
+            TempUnit::Fahrenheit => (value - 32.0) * 5.0 / 9.0,
+            TempUnit::Kelvin => value - 273.15,
+        }
+    }
+
+    /// Convert a difference in Celsius degrees to this unit.
+    pub fn convert_delta(self, celsius: f64) -> f64 {
+        match self {
+            TempUnit::Fahrenheit => celsius * 9.0 / 5.0,
+            TempUnit::Celsius | TempUnit::Kelvin => celsius,
+        }
+    }
+}
+
+/// Temperature given in Celsius, converted to `unit`, e.g. "72°C" or "345 K".
+pub fn temperature(celsius: f32, unit: TempUnit) -> String {
+    with_unit(unit.convert(celsius as f64), 0, unit.symbol())
+}
+
+/// A temperature typed in `unit`, in Celsius. Takes either decimal
+/// separator and an optional unit symbol: "185", "85,5", "185 °F".
+pub fn parse_temperature(input: &str, unit: TempUnit) -> Option<f32> {
+    let number = input.trim().trim_end_matches(|c: char| c.is_alphabetic() || c == '\u{00b0}' || c.is_whitespace());
+    let value: f64 = number.replace(',', ".").parse().ok()?;
+    value.is_finite().then(|| unit.to_celsius(value) as f32)
+}
+
+/// Signed temperature difference given in Celsius, e.g. "+0.9°F".
+pub fn temperature_delta(celsius: f32, unit: TempUnit) -> String {
+    temperature_delta_in(language(), celsius, unit)
+}
+
+fn temperature_delta_in(lang: Language, celsius: f32, unit: TempUnit) -> String {
+    let delta = unit.convert_delta(celsius as f64);
+    let sign = if delta > 0.0 { "+" } else if delta < 0.0 { "\u{2212}" } else { "" };
+    format!("{sign}{}", with_unit_in(lang, delta.abs(), 1, unit.symbol()))
+}
+
+/// Unit of the process memory column. `Auto` picks a prefix per value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum MemUnit {
+    #[default]
+    Auto,
+    MiB,
+    GiB,
+}
+
+impl MemUnit {
+    pub const ALL: &'static [MemUnit] = &[MemUnit::Auto, MemUnit::MiB, MemUnit::GiB];
+}
+
+/// How network throughput is displayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RateUnits {
+    /// Bits per second (Mbit/s) instead of bytes per second (MiB/s).
+    pub bits: bool,
+    /// Decimal prefixes (k = 1000) instead of binary ones (Ki = 1024).
+    pub si: bool,
+}
+
+impl RateUnits {
+    fn base(self) -> f64 {
+        if self.si { 1000.0 } else { 1024.0 }
+    }
+
+    fn label(self, power: usize) -> &'static str {
+        const LABELS: [[&str; 5]; 4] = [
+            [" B/s", " KiB/s", " MiB/s", " GiB/s", " TiB/s"],
+            [" B/s", " kB/s", " MB/s", " GB/s", " TB/s"],
+            [" bit/s", " Kibit/s", " Mibit/s", " Gibit/s", " Tibit/s"],
+            [" bit/s", " kbit/s", " Mbit/s", " Gbit/s", " Tbit/s"],
+        ];
+        LABELS[self.bits as usize * 2 + self.si as usize][power]
+    }
+
+    /// Largest prefix power (0 = none, 4 = tera) not exceeding `value`.
+    fn power(self, value: f64, min: usize) -> usize {
+        let base = self.base();
+        (min..=4).rev().find(|&k| value >= base.powi(k as i32)).unwrap_or(min)
+    }
+
+    /// Divisor turning bytes/s into the unit returned alongside it, chosen so
+    /// that `max_bytes_per_sec` reads naturally. Used to scale rate charts.
+    pub fn scale(self, max_bytes_per_sec: f64) -> (f64, &'static str) {
+        let per_byte = if self.bits { 8.0 } else { 1.0 };
+        let power = self.power(max_bytes_per_sec * per_byte, 1);
+        (self.base().powi(power as i32) / per_byte, self.label(power))
+    }
+}
+
+pub fn set_rate_units(units: RateUnits) {
+    let flags = if units.bits { RATE_BITS } else { 0 } | if units.si { RATE_SI } else { 0 };
+    RATE_UNITS.store(flags, Ordering::Relaxed);
+}
+
+pub fn rate_units() -> RateUnits {
+    let flags = RATE_UNITS.load(Ordering::Relaxed);
+    RateUnits { bits: flags & RATE_BITS != 0, si: flags & RATE_SI != 0 }
+}
+
+/// Throughput given in bytes per second, e.g. "1.2 MiB/s" or "9.8 Mbit/s".
+pub fn rate(bytes_per_sec: u64) -> String {
+    rate_in(language(), rate_units(), bytes_per_sec)
+}
+
+/// Wall-clock time with seconds, 24h or 12h depending on the locale.
+pub fn clock_time<Tz: TimeZone>(dt: &DateTime<Tz>) -> String
+where
+    Tz::Offset: std::fmt::Display,
+{
+    clock_time_in(language(), dt)
+}
+
+pub fn set_utc(on: bool) {
+    UTC.store(on, Ordering::Relaxed);
+}
+
+pub fn utc() -> bool {
+    UTC.load(Ordering::Relaxed)
+}
+
+/// The current time in the selected zone.
+pub fn now() -> DateTime<FixedOffset> {
+    now_in(utc())
+}
+
+/// Epoch seconds in the selected zone.
+pub fn from_epoch(ts: f64) -> Option<DateTime<FixedOffset>> {
+    from_epoch_in(ts, utc())
+}
+
+/// The current wall-clock time in the selected zone, for the event log and
+/// the menu bar clock.
+pub fn clock_now() -> String {
+    clock_time(&now())
+}
+
+/// Time label for a chart axis, with the date in front when the chart
+/// spans more than a day.
+pub fn axis_time(ts: f64, with_date: bool) -> String {
+    from_epoch(ts).map(|dt| axis_time_in(language(), &dt, with_date)).unwrap_or_default()
+}
+
+/// Date and time to the minute, e.g. "2024-05-01 14:05", for reports.
+pub fn date_time(ts: f64) -> String {
+    date_time_in(ts, utc())
+}
+
+/// Epoch seconds of a date and time typed in the selected zone, in the
+/// format `date_time` writes; seconds and the time are optional.
+pub fn parse_date_time(s: &str) -> Option<f64> {
+    parse_date_time_in(s, utc())
+}
+
+/// Epoch seconds as RFC 3339 in the selected zone, for exports: `Z` in UTC,
+/// the numeric offset otherwise.
+pub fn iso_time(ts: f64) -> String {
+    iso_time_in(ts, utc())
+}
+
+fn number_in(lang: Language, value: f64, decimals: usize) -> String {
+    let s = format!("{value:.decimals$}");
+    match lang.decimal_separator() {
+        '.' => s,
+        sep => s.replace('.', &sep.to_string()),
+    }
+}
+
+fn with_unit_in(lang: Language, value: f64, decimals: usize, unit: &str) -> String {
+    isolate(lang, format!("{}{unit}", number_in(lang, value, decimals)))
+}
+
+fn rate_in(lang: Language, units: RateUnits, bytes_per_sec: u64) -> String {
+    let value = bytes_per_sec as f64 * if units.bits { 8.0 } else { 1.0 };
+    let power = units.power(value, 0);
+    let decimals = if power == 0 { 0 } else { 1 };
+    with_unit_in(lang, value / units.base().powi(power as i32), decimals, units.label(power))
+}
+
+fn clock_time_in<Tz: TimeZone>(lang: Language, dt: &DateTime<Tz>) -> String
+where
+    Tz::Offset: std::fmt::Display,
+{
+    let pattern = if lang.uses_24h_clock() { "%H:%M:%S" } else { "%-I:%M:%S %p" };
+    isolate(lang, dt.format(pattern).to_string())
+}
+
+fn now_in(utc: bool) -> DateTime<FixedOffset> {
+    if utc { Utc::now().fixed_offset() } else { Local::now().fixed_offset() }
+}
+
+fn from_epoch_in(ts: f64, utc: bool) -> Option<DateTime<FixedOffset>> {
+    let dt = DateTime::from_timestamp_millis((ts * 1000.0).round() as i64)?;
+    Some(if utc { dt.fixed_offset() } else { dt.with_timezone(&Local).fixed_offset() })
+}
+
+fn date_time_in(ts: f64, utc: bool) -> String {
+    from_epoch_in(ts, utc).map(|dt| dt.format("%Y-%m-%d %H:%M").to_string()).unwrap_or_default()
+}
+
+fn iso_time_in(ts: f64, utc: bool) -> String {
+    from_epoch_in(ts, utc).map(|dt| dt.to_rfc3339_opts(SecondsFormat::Millis, true)).unwrap_or_default()
+}
+
+fn parse_date_time_in(s: &str, utc: bool) -> Option<f64> {
+    let s = s.trim();
+    let naive = ["%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M", "%Y-%m-%dT%H:%M:%S", "%Y-%m-%dT%H:%M"]
+        .iter()
+        .find_map(|f| NaiveDateTime::parse_from_str(s, f).ok())
+        .or_else(|| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok()?.and_hms_opt(0, 0, 0))?;
+    let secs = if utc { naive.and_utc().timestamp() } else { Local.from_local_datetime(&naive).earliest()?.timestamp() };
+    Some(secs as f64)
+}
+
+fn axis_time_in(lang: Language, dt: &DateTime<FixedOffset>, with_date: bool) -> String {
+    let time = if lang.uses_24h_clock() { "%H:%M" } else { "%-I:%M %p" };
+    let pattern = if with_date { format!("%m-%d {time}") } else { time.to_string() };
+    isolate(lang, dt.format(&pattern).to_string())
+}
+
+/// Wrap `s` in a directional isolate for right-to-left languages.
+fn isolate(lang: Language, s: String) -> String {
+    if lang.is_rtl() {
+        format!("{FSI}{s}{PDI}")
+    } else {
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number_separators() {
+        assert_eq!(number_in(Language::En, 12.345, 1), "12.3");
+        assert_eq!(number_in(Language::Fr, 12.345, 1), "12,3");
+        assert_eq!(number_in(Language::De, 7.0, 0), "7");
+        assert_eq!(with_unit_in(Language::Ru, 1.5, 1, " GiB"), "1,5 GiB");
+    }
+
+    #[test]
+    fn test_temp_units() {
+        assert_eq!(TempUnit::Fahrenheit.convert(100.0), 212.0);
+        assert!((TempUnit::Kelvin.convert(-273.15)).abs() < 1e-9);
+        assert_eq!(with_unit_in(Language::En, TempUnit::Kelvin.convert(50.0), 0, TempUnit::Kelvin.symbol()), "323 K");
+        assert_eq!(TempUnit::Fahrenheit.to_celsius(212.0), 100.0);
+        assert_eq!(parse_temperature("185", TempUnit::Fahrenheit), Some(85.0));
+        assert_eq!(parse_temperature(" 185 \u{00b0}F", TempUnit::Fahrenheit), Some(85.0));
+        assert_eq!(parse_temperature("85,5", TempUnit::Celsius), Some(85.5));
+        assert_eq!(parse_temperature("358.15 K", TempUnit::Kelvin).map(f32::round), Some(85.0));
+        assert_eq!(parse_temperature("hot", TempUnit::Celsius), None);
+        assert_eq!(temperature_delta_in(Language::En, 0.5, TempUnit::Fahrenheit), "+0.9\u{00b0}F");
+        assert_eq!(temperature_delta_in(Language::Fr, -2.0, TempUnit::Kelvin), "\u{2212}2,0 K");
+        assert_eq!(temperature_delta_in(Language::En, 0.0, TempUnit::Celsius), "0.0\u{00b0}C");
+    }
+
+    #[test]
+    fn test_rate_units() {
+        let binary = RateUnits::default();
+        let si_bits = RateUnits { bits: true, si: true };
+        assert_eq!(rate_in(Language::En, binary, 512), "512 B/s");
+        assert_eq!(rate_in(Language::En, binary, 1536), "1.5 KiB/s");
+        assert_eq!(rate_in(Language::En, si_bits, 1_250_000), "10.0 Mbit/s");
+        assert_eq!(rate_in(Language::En, RateUnits { bits: false, si: true }, 2_500_000_000), "2.5 GB/s");
+        assert_eq!(si_bits.scale(250_000.0), (125_000.0, " Mbit/s"));
+        assert_eq!(binary.scale(10.0), (1024.0, " KiB/s"));
+    }
+
+    #[test]
+    fn test_clock_and_rtl() {
+        let dt = chrono::Utc.with_ymd_and_hms(2024, 5, 1, 14, 5, 9).unwrap();
+        assert_eq!(clock_time_in(Language::Fr, &dt), "14:05:09");
+        assert_eq!(clock_time_in(Language::En, &dt), "2:05:09 PM");
+        assert_eq!(with_unit_in(Language::Ar, 50.0, 0, "%"), "\u{2068}50%\u{2069}");
+    }
+
+    #[test]
+    fn test_zoned_times() {
+        let dt = Utc.with_ymd_and_hms(2024, 5, 1, 14, 5, 9).unwrap().fixed_offset();
+        assert_eq!(axis_time_in(Language::Fr, &dt, false), "14:05");
+        assert_eq!(axis_time_in(Language::En, &dt, true), "05-01 2:05 PM");
+
+        assert_eq!(iso_time_in(1_714_572_309.5, true), "2024-05-01T14:05:09.500Z");
+        assert_eq!(date_time_in(1_714_572_309.0, true), "2024-05-01 14:05");
+        assert_eq!(now_in(true).offset().local_minus_utc(), 0);
+
+        assert_eq!(parse_date_time_in(" 2024-05-01 14:05 ", true), Some(1_714_572_300.0));
+        assert_eq!(parse_date_time_in("2024-05-01T14:05:09", true), Some(1_714_572_309.0));
+        assert_eq!(parse_date_time_in("2024-05-01", true), Some(1_714_521_600.0));
+        assert_eq!(parse_date_time_in("yesterday", true), None);
+        assert_eq!(parse_date_time_in("2024-13-01 10:00", false), None);
+    }
+}
@@ -0,0 +1,354 @@
+//! Follow a log file (`tail -F` style) on a background thread.
+//!
+//! The watcher wakes when the file changes (inotify on its directory, on
+//! Linux; polling every `POLL_INTERVAL_MS` elsewhere), reads appended bytes,
+//! and reopens the file when it is truncated or rotated. The most recent
+//! `MAX_LINES` lines are kept in a shared buffer the UI reads from.
+
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Lines kept in memory.
+const MAX_LINES: usize = 500;
+/// Bytes read from the end of the file when tailing starts.
+const INITIAL_TAIL_BYTES: u64 = 64 * 1024;
+/// Check interval where the file cannot be watched.
+const POLL_INTERVAL_MS: u64 = 500;
+/// Longest wait for a change notification, so `stop` is still noticed.
+#[cfg(target_os = "linux")]
+const WATCH_TIMEOUT_MS: u64 = 1000;
+
+/// Severity guessed from a log line's text, used for highlighting.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LineLevel {
+    Error,
+    Warning,
+    Normal,
+}
+
+/// Classify a line by the usual severity keywords (case-insensitive).
+pub fn classify(line: &str) -> LineLevel {
+    let upper = line.to_ascii_uppercase();
+    if ["ERROR", "CRIT", "FATAL", "PANIC", "EMERG", "ALERT"].iter().any(|k| upper.contains(k)) {
+        LineLevel::Error
+    } else if upper.contains("WARN") {
+        LineLevel::Warning
+    } else {
+        LineLevel::Normal
+    }
+}
+
+#[derive(Default)]
+struct TailState {
+    lines: VecDeque<String>,
+    error: Option<String>,
+}
+
+/// Handle to a running tail. Dropping it stops the watcher thread.
+pub struct LogTail {
+    path: PathBuf,
+    state: Arc<Mutex<TailState>>,
+    stop: Arc<AtomicBool>,
+}
+
+impl LogTail {
+    pub fn start(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let state = Arc::new(Mutex::new(TailState::default()));
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let thread_path = path.clone();
+        let thread_state = Arc::clone(&state);
+        let thread_stop = Arc::clone(&stop);
+        std::thread::spawn(move || follow(&thread_path, &thread_state, &thread_stop));
+
+        Self { path, state, stop }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Buffered lines (oldest first) containing `filter`, case-insensitive.
+    pub fn lines(&self, filter: &str) -> Vec<String> {
+        let Ok(state) = self.state.lock() else { return Vec::new() };
+        if filter.is_empty() {
+            return state.lines.iter().cloned().collect();
+        }
+        let needle = filter.to_lowercase();
+        state
+            .lines
+            .iter()
+            .filter(|l| l.to_lowercase().contains(&needle))
+            .cloned()
+            .collect()
+    }
+
+    /// Last I/O error, cleared once the file becomes readable again.
+    pub fn error(&self) -> Option<String> {
+        self.state.lock().ok().and_then(|s| s.error.clone())
+    }
+}
+
+impl Drop for LogTail {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+fn follow(path: &Path, state: &Mutex<TailState>, stop: &AtomicBool) {
+    let set_error = |err: Option<String>| {
+        if let Ok(mut s) = state.lock() {
+            s.error = err;
+        }
+    };
+
+    let mut file: Option<File> = None;
+    let mut pos = 0u64;
+    let mut identity = None;
+    let mut partial = String::new();
+    let mut first_open = true;
+    let watcher = Watcher::new(path);
+
+    while !stop.load(Ordering::Relaxed) {
+        if file.is_none() {
+            match File::open(path) {
+                Ok(f) => {
+                    let len = f.metadata().map(|m| m.len()).unwrap_or(0);
+                    identity = f.metadata().ok().map(|m| file_identity(&m));
+                    // Start near the end on first open; read rotated files from the start.
+                    pos = if first_open { len.saturating_sub(INITIAL_TAIL_BYTES) } else { 0 };
+                    // Drop the first, likely partial, line when starting mid-file.
+                    partial.clear();
+                    let skip_first = first_open && pos > 0;
+                    first_open = false;
+                    file = Some(f);
+                    set_error(None);
+                    if skip_first {
+                        partial.push('\u{0}');
+                    }
+                }
+                Err(e) => {
+                    set_error(Some(e.to_string()));
+                    watcher.wait();
+                    continue;
+                }
+            }
+        }
+
+        // Detect rotation (path now points to a new file) or truncation.
+        let current = std::fs::metadata(path).ok();
+        if let Some(meta) = &current {
+            if Some(file_identity(meta)) != identity {
+                file = None;
+                continue;
+            }
+            if meta.len() < pos {
+                pos = 0;
+                partial.clear();
+            }
+        }
+
+        if let Some(f) = file.as_mut() {
+            let mut buf = Vec::new();
+            let read = f.seek(SeekFrom::Start(pos)).and_then(|_| f.read_to_end(&mut buf));
+            match read {
+                Ok(n) if n > 0 => {
+                    pos += n as u64;
+                    partial.push_str(&String::from_utf8_lossy(&buf));
+                    let complete = split_complete_lines(&mut partial);
+                    if let Ok(mut s) = state.lock() {
+                        for line in complete {
+                            if s.lines.len() >= MAX_LINES {
+                                s.lines.pop_front();
+                            }
+                            s.lines.push_back(line);
+                        }
+                    }
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    set_error(Some(e.to_string()));
+                    file = None;
+                }
+            }
+        }
+
+        watcher.wait();
+    }
+}
+
+/// Wakes the follower when the file may have changed.
+///
+/// On Linux this watches the file's directory, so that appends, truncation
+/// and rotation (a new file under the same name) are all seen; without
+/// inotify, or off Linux, it polls.
+struct Watcher {
+    #[cfg(target_os = "linux")]
+    inotify: Option<std::os::fd::OwnedFd>,
+    #[cfg(target_os = "linux")]
+    name: Vec<u8>,
+}
+
+#[cfg(target_os = "linux")]
+impl Watcher {
+    fn new(path: &Path) -> Self {
+        use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+        use std::os::unix::ffi::OsStrExt;
+
+        let dir = path.parent().filter(|d| !d.as_os_str().is_empty()).unwrap_or(Path::new("."));
+        let name = path.file_name().map(|n| n.as_bytes().to_vec()).unwrap_or_default();
+        let inotify = std::ffi::CString::new(dir.as_os_str().as_bytes()).ok().and_then(|dir| {
+            // SAFETY: the descriptor is owned by the `OwnedFd` from here on.
+            let fd = unsafe { libc::inotify_init1(libc::IN_CLOEXEC) };
+            if fd < 0 {
+                return None;
+            }
+            let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+            let mask = libc::IN_MODIFY | libc::IN_CREATE | libc::IN_DELETE | libc::IN_MOVED_FROM | libc::IN_MOVED_TO;
+            // SAFETY: `dir` is a NUL-terminated path that outlives the call.
+            let wd = unsafe { libc::inotify_add_watch(fd.as_raw_fd(), dir.as_ptr(), mask) };
+            (wd >= 0).then_some(fd)
+        });
+        Self { inotify, name }
+    }
+
+    /// Block until the file changes, or for at most `WATCH_TIMEOUT_MS`.
+    fn wait(&self) {
+        use std::os::fd::AsRawFd;
+
+        let Some(fd) = &self.inotify else {
+            std::thread::sleep(Duration::from_millis(POLL_INTERVAL_MS));
+            return;
+        };
+        let deadline = std::time::Instant::now() + Duration::from_millis(WATCH_TIMEOUT_MS);
+        let mut buf = [0u8; 4096];
+        loop {
+            let left = deadline.saturating_duration_since(std::time::Instant::now());
+            let mut pollfd = libc::pollfd { fd: fd.as_raw_fd(), events: libc::POLLIN, revents: 0 };
+            // SAFETY: one valid pollfd; `buf` is writable for its whole length.
+            if left.is_zero() || unsafe { libc::poll(&mut pollfd, 1, left.as_millis() as i32) } <= 0 {
+                return;
+            }
+            let n = unsafe { libc::read(fd.as_raw_fd(), buf.as_mut_ptr().cast(), buf.len()) };
+            if n <= 0 || self.mentions_file(&buf[..n as usize]) {
+                return;
+            }
+        }
+    }
+
+    /// Whether a batch of inotify events concerns the followed file (other
+    /// files in its directory change too).
+    fn mentions_file(&self, mut events: &[u8]) -> bool {
+        let header = std::mem::size_of::<libc::inotify_event>();
+        while events.len() >= header {
+            // SAFETY: the kernel writes whole events; the buffer has no alignment.
+            let event: libc::inotify_event = unsafe { std::ptr::read_unaligned(events.as_ptr().cast()) };
+            let end = (header + event.len as usize).min(events.len());
+            let name = events[header..end].split(|&b| b == 0).next().unwrap_or_default();
+            if event.mask & libc::IN_Q_OVERFLOW != 0 || name == self.name.as_slice() {
+                return true;
+            }
+            events = &events[end..];
+        }
+        false
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+impl Watcher {
+    fn new(_path: &Path) -> Self {
+        Self {}
+    }
+
+    fn wait(&self) {
+        std::thread::sleep(Duration::from_millis(POLL_INTERVAL_MS));
+    }
+}
+
+/// Drain complete lines from `buf`, leaving any trailing partial line.
+/// A leading NUL marks a partial first line that must be discarded.
+fn split_complete_lines(buf: &mut String) -> Vec<String> {
+    let Some(last_nl) = buf.rfind('\n') else { return Vec::new() };
+    let rest = buf.split_off(last_nl + 1);
+    let mut lines: Vec<String> = buf
+        .lines()
+        .map(|l| l.trim_end_matches('\r').to_string())
+        .collect();
+    if buf.starts_with('\u{0}') && !lines.is_empty() {
+        lines.remove(0);
+    }
+    *buf = rest;
+    lines.retain(|l| !l.is_empty());
+    lines
+}
+
+#[cfg(unix)]
+fn file_identity(meta: &std::fs::Metadata) -> u64 {
+    use std::os::unix::fs::MetadataExt;
+    meta.ino()
+}
+
+#[cfg(not(unix))]
+fn file_identity(meta: &std::fs::Metadata) -> u64 {
+    // No inode on Windows — fall back to creation time.
+    meta.created()
+        .ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify() {
+        assert_eq!(classify("kernel: EXT4-fs error (device sda1)"), LineLevel::Error);
+        assert_eq!(classify("[WARN] low disk space"), LineLevel::Warning);
+        assert_eq!(classify("systemd: Started session 3"), LineLevel::Normal);
+    }
+
+    #[test]
+    fn test_split_complete_lines() {
+        let mut buf = String::from("one\ntwo\r\nthr");
+        assert_eq!(split_complete_lines(&mut buf), vec!["one", "two"]);
+        assert_eq!(buf, "thr");
+        buf.push_str("ee\n");
+        assert_eq!(split_complete_lines(&mut buf), vec!["three"]);
+        assert!(buf.is_empty());
+
+        let mut mid = String::from("\u{0}tial\nfull\n");
+        assert_eq!(split_complete_lines(&mut mid), vec!["full"]);
+    }
+
+    #[test]
+    fn test_tail_follows_appends() {
+        use std::io::Write;
+
+        let path = std::env::temp_dir().join(format!("digger_tail_{}.log", std::process::id()));
+        std::fs::write(&path, "old line\n").unwrap();
+        let tail = LogTail::start(&path);
+        std::thread::sleep(Duration::from_millis(100));
+        let mut f = std::fs::OpenOptions::new().append(true).open(&path).unwrap();
+        writeln!(f, "ERROR new line").unwrap();
+
+        let mut lines = Vec::new();
+        for _ in 0..40 {
+            std::thread::sleep(Duration::from_millis(100));
+            lines = tail.lines("error");
+            if !lines.is_empty() {
+                break;
+            }
+        }
+        drop(tail);
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(lines, vec!["ERROR new line"]);
+    }
+}
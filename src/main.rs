@@ -1,17 +1,53 @@
 #![windows_subsystem = "windows"]
 
+mod anomaly;
 mod chart;
+mod cputime;
+mod crash;
+mod diagnostics;
+mod diskscan;
+mod elevate;
+mod exportmeta;
+mod fontchain;
+mod forecast;
 mod gauge;
 mod gpu;
+mod hardware;
+mod heatmap;
+mod histogram;
 mod history;
+mod idle;
 pub mod i18n;
 pub mod icons;
+mod lanscan;
+mod launch;
+mod locale;
+mod logtail;
 mod metrics;
+mod notify;
+mod ping;
+mod power;
+mod powermetrics;
 mod preferences;
+mod procdetail;
+mod procexport;
+mod report;
 mod ringbuf;
+mod sampler;
+mod sessions;
+mod snapdiff;
+mod sockets;
+mod summary;
+mod sysevents;
+mod sysfonts;
 pub mod theme;
+mod threads;
 mod ui;
+mod update;
+mod virt;
+mod vmstat;
 
+use preferences::Preferences;
 use ui::Digger;
 
 // ─── Iosevka Nerd Font (default Latin/symbols) ─────────────────────
@@ -110,6 +146,13 @@ pub const DYSLEXIC_FONT: iced::Font = iced::Font {
 };
 
 fn main() -> iced::Result {
+    crash::install();
+    let prefs = Preferences::load();
+    // The flags only apply to this run; the settings persist
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let kiosk = prefs.kiosk_mode || args.iter().any(|arg| arg == "--kiosk");
+    let read_only = args.iter().any(|arg| arg == "--read-only");
+
     let icon = iced::window::icon::from_file_data(
         include_bytes!("ui/assets/icons/digger.png"),
         None,
@@ -118,7 +161,11 @@ fn main() -> iced::Result {
 
     iced::application(Digger::title, Digger::update, Digger::view)
         .subscription(Digger::subscription)
+        // Closing the window goes through Message::CloseRequested so pending
+        // history is flushed before exit
+        .exit_on_close_request(false)
         .theme(Digger::theme)
+        .scale_factor(Digger::scale_factor)
         .font(NERD_FONT_BYTES)
         .font(NERD_FONT_MONO_BYTES)
         .font(SARASA_FONT_BYTES)
@@ -131,7 +178,10 @@ fn main() -> iced::Result {
         .default_font(NERD_FONT)
         .window(iced::window::Settings {
             icon,
-            size: (950.0, 680.0).into(),
+            size: (prefs.window_width, prefs.window_height).into(),
+            position: prefs
+                .window_position
+                .map_or(iced::window::Position::Default, |(x, y)| iced::window::Position::Specific((x, y).into())),
             #[cfg(target_os = "linux")]
             platform_specific: iced::window::settings::PlatformSpecific {
                 application_id: String::from("digger"),
@@ -139,5 +189,16 @@ fn main() -> iced::Result {
             },
             ..Default::default()
         })
-        .run_with(|| (Digger::new(), iced::Task::none()))
+        .run_with(move || {
+            // There's no maximized or fullscreen window setting; apply
+            // them once the window exists
+            let startup = if kiosk {
+                iced::window::get_oldest().and_then(|id| iced::window::change_mode(id, iced::window::Mode::Fullscreen))
+            } else if prefs.window_maximized {
+                iced::window::get_oldest().and_then(|id| iced::window::maximize(id, true))
+            } else {
+                iced::Task::none()
+            };
+            (Digger::new(prefs, kiosk, read_only), startup)
+        })
 }
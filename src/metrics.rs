@@ -1,17 +1,29 @@
 use sysinfo::{System, Disks, Networks, Components, RefreshKind, CpuRefreshKind, MemoryRefreshKind, ProcessRefreshKind};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::ffi::{OsStr, OsString};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 #[cfg(any(target_os = "linux", target_os = "macos"))]
 use std::path::Path;
 
+use serde::{Deserialize, Serialize};
+
+use crate::cputime::{CpuTimeSampler, CpuTimes};
+use crate::power::{PowerMeter, SystemPower};
+use crate::threads::{ThreadInfo, ThreadSampler};
+use crate::virt::Hypervisor;
+use crate::vmstat::{MemoryActivity, VmSampler};
+
 /// Static system information that never changes at runtime.
 /// Wrapped in Arc to avoid cloning on every tick.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct SystemInfo {
     pub os_name: String,
     pub os_version: String,
     pub kernel_version: String,
     pub hostname: String,
+    /// Set when running inside a virtual machine.
+    pub hypervisor: Option<Hypervisor>,
 }
 
 /// A snapshot of system metrics at a point in time.
@@ -20,6 +32,8 @@ pub struct Snapshot {
     pub timestamp: f64,
     pub cpu_usage_per_core: Vec<f32>,
     pub cpu_usage_global: f32,
+    /// Where the CPU time went; `None` before the second tick and on macOS.
+    pub cpu_times: Option<CpuTimes>,
     pub cpu_name: String,
     pub cpu_core_count: usize,
     pub cpu_frequency_mhz: u64,
@@ -27,20 +41,236 @@ pub struct Snapshot {
     pub memory_total: u64,
     pub swap_used: u64,
     pub swap_total: u64,
-    pub disks: Vec<DiskInfo>,
+    /// Page fault rates and hugepages; empty outside Linux.
+    pub memory_activity: MemoryActivity,
+    /// Shared with the collector; only copied when a value changes.
+    pub disks: Arc<[DiskInfo]>,
     pub disk_io: DiskIoSnapshot,
     pub net_rx_bytes: u64,
     pub net_tx_bytes: u64,
     pub net_interfaces: Vec<NetIfaceInfo>,
     pub temperatures: Vec<TempInfo>,
+    /// Backend problems, filled in when `temperatures` is empty.
+    pub temp_status: Vec<SensorStatus>,
     pub processes: Vec<ProcessInfo>,
+    /// Every process on the system (`processes` is truncated to the process
+    /// limit), sorted by PID. Used to detect starts and exits.
+    pub all_processes: Vec<ProcessId>,
+    /// The busiest processes matching the search set with
+    /// `Collector::set_process_filter`, looked up past the process limit;
+    /// empty without one. Only the process table shows these.
+    pub search_matches: Vec<ProcessInfo>,
+    /// Threads of the processes set with `Collector::set_thread_pids`.
+    pub threads: HashMap<u32, Vec<ThreadInfo>>,
     pub gpu: crate::gpu::GpuSnapshot,
     pub uptime_secs: u64,
     pub process_count: usize,
     /// Static system info (shared via Arc, zero-cost clone).
     pub sys_info: Arc<SystemInfo>,
-    /// System load averages (1m, 5m, 15m). On unsupported platforms, all zeros.
-    pub load_avg: [f64; 3],
+    /// Load figure for the platform: load averages or processor queue.
+    pub load: SystemLoad,
+    /// Package (RAPL) or battery discharge power, when either is available.
+    pub power: Option<SystemPower>,
+    /// How long collecting this snapshot took, per stage.
+    pub timings: CollectTimings,
+    /// Age of the readings that background pollers supplied.
+    pub data_age: DataAge,
+}
+
+#[cfg(test)]
+impl Snapshot {
+    /// A one-core machine at `ts` with `cpu`% CPU and half of 8 GB memory
+    /// used; tests override the fields they care about.
+    pub fn for_test(ts: f64, cpu: f32) -> Self {
+        Self {
+            timestamp: ts,
+            cpu_usage_per_core: vec![cpu],
+            cpu_usage_global: cpu,
+            cpu_times: None,
+            cpu_name: String::new(),
+            cpu_core_count: 1,
+            cpu_frequency_mhz: 0,
+            memory_used: 4_000_000_000,
+            memory_total: 8_000_000_000,
+            swap_used: 0,
+            swap_total: 0,
+            memory_activity: Default::default(),
+            disks: Arc::new([]),
+            disk_io: DiskIoSnapshot { read_bytes: 0, write_bytes: 0 },
+            net_rx_bytes: 1000,
+            net_tx_bytes: 2000,
+            net_interfaces: vec![],
+            temperatures: vec![],
+            temp_status: vec![],
+            processes: vec![],
+            all_processes: vec![],
+            search_matches: vec![],
+            threads: Default::default(),
+            gpu: crate::gpu::GpuSnapshot::default(),
+            uptime_secs: 3600,
+            process_count: 100,
+            sys_info: Arc::new(SystemInfo {
+                os_name: String::new(),
+                os_version: String::new(),
+                kernel_version: String::new(),
+                hostname: String::new(),
+                hypervisor: None,
+            }),
+            load: SystemLoad::Unavailable,
+            power: None,
+            timings: Default::default(),
+            data_age: Default::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+impl ProcessInfo {
+    /// A sleeping, idle, single-threaded root process with no command
+    /// line; tests override the fields they care about.
+    pub fn for_test(pid: u32, name: &str) -> Self {
+        Self {
+            pid,
+            parent_pid: None,
+            name: name.into(),
+            cmd: Arc::new([]),
+            cpu_usage: 0.0,
+            memory_bytes: 0,
+            virtual_memory_bytes: 0,
+            disk_bytes: 0,
+            uid: 0,
+            is_desktop_app: false,
+            packaging: Packaging::Native,
+            thread_count: 1,
+            status: 'S',
+        }
+    }
+}
+
+/// Backends that poll on a thread of their own. When one hangs, its last
+/// values keep being merged into every snapshot.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Subsystem {
+    Gpu,
+    /// WMI on Windows, `powermetrics` on macOS.
+    Sensors,
+}
+
+impl Subsystem {
+    pub const ALL: [Subsystem; 2] = [Subsystem::Gpu, Subsystem::Sensors];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Subsystem::Gpu => "GPU",
+            Subsystem::Sensors => "Sensors",
+        }
+    }
+}
+
+/// Time since each background poller last delivered; `None` when the
+/// subsystem is read inline on this platform or hasn't delivered yet.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DataAge {
+    pub gpu: Option<Duration>,
+    pub sensors: Option<Duration>,
+}
+
+impl DataAge {
+    pub fn get(&self, subsystem: Subsystem) -> Option<Duration> {
+        match subsystem {
+            Subsystem::Gpu => self.gpu,
+            Subsystem::Sensors => self.sensors,
+        }
+    }
+}
+
+/// Stages of `Collector::collect`, timed on every tick.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CollectStage {
+    /// sysinfo CPU, memory and process refresh.
+    Refresh,
+    Network,
+    Disks,
+    Sensors,
+    /// Building the process table from the refreshed data.
+    Processes,
+    /// Last background GPU poll; it runs off the tick, so it isn't part of
+    /// the total.
+    Gpu,
+    /// Load average and power meter reads.
+    Power,
+}
+
+impl CollectStage {
+    pub const ALL: [CollectStage; 7] = [
+        CollectStage::Refresh,
+        CollectStage::Network,
+        CollectStage::Disks,
+        CollectStage::Sensors,
+        CollectStage::Processes,
+        CollectStage::Gpu,
+        CollectStage::Power,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            CollectStage::Refresh => "sysinfo refresh",
+            CollectStage::Network => "network",
+            CollectStage::Disks => "disks",
+            CollectStage::Sensors => "sensors",
+            CollectStage::Processes => "processes",
+            CollectStage::Gpu => "GPU (background)",
+            CollectStage::Power => "load & power",
+        }
+    }
+
+    /// Time above which the stage is reported as slow.
+    pub fn budget(self) -> Duration {
+        Duration::from_millis(match self {
+            CollectStage::Refresh => 150,
+            CollectStage::Gpu => 500,
+            CollectStage::Processes | CollectStage::Disks | CollectStage::Sensors => 50,
+            CollectStage::Network | CollectStage::Power => 20,
+        })
+    }
+}
+
+/// Per-stage durations of one `Collector::collect` call.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CollectTimings {
+    stages: [Duration; CollectStage::ALL.len()],
+    /// Wall time of the whole call.
+    pub total: Duration,
+}
+
+impl CollectTimings {
+    pub fn get(&self, stage: CollectStage) -> Duration {
+        self.stages[stage as usize]
+    }
+
+    /// Charge the time since `lap` to `stage` and restart the lap. Stages
+    /// split across the function accumulate.
+    fn lap(&mut self, stage: CollectStage, lap: &mut Instant) {
+        let now = Instant::now();
+        self.stages[stage as usize] += now - *lap;
+        *lap = now;
+    }
+
+    /// Stages that took longer than their budget.
+    pub fn over_budget(&self) -> impl Iterator<Item = (CollectStage, Duration)> + '_ {
+        CollectStage::ALL.into_iter().map(|s| (s, self.get(s))).filter(|(s, d)| *d > s.budget())
+    }
+}
+
+/// System load in whatever form the platform provides.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SystemLoad {
+    /// 1, 5 and 15 minute load averages (Linux/macOS).
+    Average([f64; 3]),
+    /// Threads waiting for a processor (Windows `\System\Processor Queue Length`).
+    #[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+    QueueLength(u32),
+    Unavailable,
 }
 
 /// Lightweight point for the live rolling charts (no allocations).
@@ -52,6 +282,15 @@ pub struct LivePoint {
     pub net_tx: u64,
     pub disk_read: u64,
     pub disk_write: u64,
+    /// Watts, 0 when no power reading is available.
+    pub power_watts: f32,
+    /// Hottest sensor in °C, 0 when there are none.
+    pub max_temp_c: f32,
+    /// All zero while no split is available.
+    pub cpu_times: CpuTimes,
+    /// System-wide page faults per second, 0 while unknown.
+    pub minor_faults: f32,
+    pub major_faults: f32,
 }
 
 #[derive(Clone, Debug)]
@@ -71,10 +310,34 @@ pub struct DiskIoSnapshot {
     pub write_bytes: u64,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct TempInfo {
     pub label: String,
     pub temp_c: f32,
+    /// Unique within a snapshot: the label, numbered when several sensors
+    /// share it. Calibration offsets are keyed by it.
+    pub id: String,
+}
+
+/// Why a sensor backend came back empty.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SensorIssue {
+    /// Sensors exist but reading them was refused.
+    PermissionDenied,
+    /// No driver exposes any sensors.
+    DriverMissing,
+    /// A helper program or service the backend relies on is not running
+    /// or not installed.
+    ServiceMissing(&'static str),
+    /// Any other failure, with the backend's own message.
+    Failed(String),
+}
+
+/// Status of one sensor backend, reported when a panel has nothing to show.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SensorStatus {
+    pub backend: &'static str,
+    pub issue: SensorIssue,
 }
 
 #[derive(Clone, Debug)]
@@ -82,25 +345,258 @@ pub struct NetIfaceInfo {
     pub name: String,
     pub rx_bytes: u64,
     pub tx_bytes: u64,
+    /// Link state. Platforms without link information report `true`.
+    pub is_up: bool,
+}
+
+/// A change in the set of network interfaces between two snapshots.
+#[derive(Clone, Debug, PartialEq)]
+pub enum IfaceChange {
+    Added(String),
+    Removed(String),
+    LinkUp(String),
+    LinkDown(String),
+}
+
+/// Compare two interface lists and report appearances, disappearances and
+/// link state transitions.
+pub fn diff_interfaces(prev: &[NetIfaceInfo], cur: &[NetIfaceInfo]) -> Vec<IfaceChange> {
+    let mut changes = Vec::new();
+    for iface in cur {
+        match prev.iter().find(|p| p.name == iface.name) {
+            None => changes.push(IfaceChange::Added(iface.name.clone())),
+            Some(old) if old.is_up && !iface.is_up => changes.push(IfaceChange::LinkDown(iface.name.clone())),
+            Some(old) if !old.is_up && iface.is_up => changes.push(IfaceChange::LinkUp(iface.name.clone())),
+            Some(_) => {}
+        }
+    }
+    for old in prev {
+        if !cur.iter().any(|c| c.name == old.name) {
+            changes.push(IfaceChange::Removed(old.name.clone()));
+        }
+    }
+    changes
+}
+
+/// Linux: read the link state from sysfs. "unknown" (loopback, tun/wireguard)
+/// counts as up since those interfaces never report a carrier.
+#[cfg(target_os = "linux")]
+fn read_iface_up(name: &str) -> bool {
+    std::fs::read_to_string(format!("/sys/class/net/{name}/operstate"))
+        .map(|s| !matches!(s.trim(), "down" | "lowerlayerdown" | "notpresent"))
+        .unwrap_or(true)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_iface_up(_name: &str) -> bool {
+    true
 }
 
 #[derive(Clone, Debug)]
 pub struct ProcessInfo {
     pub pid: u32,
     pub parent_pid: Option<u32>,
-    pub name: String,
-    pub cmd: Vec<String>,
+    /// Shared with the collector's per-PID cache; see `ProcessStrings`.
+    pub name: Arc<str>,
+    pub cmd: Arc<[String]>,
     pub cpu_usage: f32,
     pub memory_bytes: u64,
     pub virtual_memory_bytes: u64,
+    /// Bytes read and written since the previous tick.
+    pub disk_bytes: u64,
     pub uid: u32,
     pub is_desktop_app: bool,
+    /// Sandbox or bundle the process was started from (Linux only).
+    pub packaging: Packaging,
     /// Number of tasks/threads for this process.
     pub thread_count: u32,
     /// Process status: R(unning), S(leeping), Z(ombie), D(isk-wait), etc.
     pub status: char,
 }
 
+impl ProcessInfo {
+    /// Whether the name or command line contains `needle`, which is
+    /// already lowercased. An empty needle matches everything.
+    pub fn matches(&self, needle: &str) -> bool {
+        needle.is_empty()
+            || self.name.to_lowercase().contains(needle)
+            || self.cmd.iter().any(|c| c.to_lowercase().contains(needle))
+    }
+}
+
+/// How an application process was packaged. Sandboxed apps see their own
+/// filesystem and may respawn or be confined differently when killed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Packaging {
+    #[default]
+    Native,
+    Flatpak,
+    Snap,
+    AppImage,
+}
+
+impl Packaging {
+    /// Badge text in the process table; None for native processes.
+    pub fn badge(self) -> Option<&'static str> {
+        match self {
+            Packaging::Native => None,
+            Packaging::Flatpak => Some("flatpak"),
+            Packaging::Snap => Some("snap"),
+            Packaging::AppImage => Some("AppImage"),
+        }
+    }
+}
+
+/// Packaging of `pid` from its cgroup (Flatpak and Snap run apps in
+/// `app-flatpak-<id>-<n>.scope` and `snap.<name>.<app>-<n>.scope` units)
+/// and executable path (AppImages run from a `/tmp/.mount_*` FUSE mount).
+#[cfg(target_os = "linux")]
+fn read_packaging(pid: u32, cmd: &[String]) -> Packaging {
+    let cgroup = std::fs::read_to_string(format!("/proc/{pid}/cgroup")).unwrap_or_default();
+    let exe = std::fs::read_link(format!("/proc/{pid}/exe"))
+        .map(|p| p.to_string_lossy().into_owned())
+        .unwrap_or_else(|_| cmd.first().cloned().unwrap_or_default());
+    packaging_from(&cgroup, &exe)
+}
+
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn packaging_from(cgroup: &str, exe: &str) -> Packaging {
+    let unit = |prefix: &str| cgroup.lines().any(|line| line.rsplit('/').any(|part| part.starts_with(prefix)));
+    if unit("app-flatpak-") || exe.starts_with("/app/") {
+        Packaging::Flatpak
+    } else if unit("snap.") || exe.starts_with("/snap/") {
+        Packaging::Snap
+    } else if exe.starts_with("/tmp/.mount_") {
+        Packaging::AppImage
+    } else {
+        Packaging::Native
+    }
+}
+
+/// PID and name of a running process.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProcessId {
+    pub pid: u32,
+    pub name: Arc<str>,
+}
+
+/// Join a command line into at most `max_chars` characters. Long path
+/// arguments lose their middle directories first (`/usr/lib/…/foo.conf`) so
+/// the file names that tell daemons apart survive; anything still too long
+/// is cut in the middle, keeping the executable and the last arguments.
+pub fn elide_command_line(cmd: &[String], max_chars: usize) -> String {
+    let width = |parts: &[String]| {
+        parts.iter().map(|p| p.chars().count()).sum::<usize>() + parts.len().saturating_sub(1)
+    };
+    let mut parts = cmd.to_vec();
+    while width(&parts) > max_chars {
+        // Shorten the longest argument that still has directories to drop
+        let Some((i, shorter)) = parts
+            .iter()
+            .enumerate()
+            .filter_map(|(i, arg)| shorten_path(arg).map(|s| (i, s)))
+            .max_by_key(|(i, _)| parts[*i].chars().count())
+        else {
+            break;
+        };
+        parts[i] = shorter;
+    }
+    truncate_middle(&parts.join(" "), max_chars)
+}
+
+/// One step of eliding a path: the directory before the file name becomes
+/// `…`, then directories next to the `…` are dropped. `None` once only the
+/// first and last components are left.
+fn shorten_path(arg: &str) -> Option<String> {
+    let sep = ['/', '\\'].into_iter().find(|&c| arg.contains(c))?;
+    let mut parts: Vec<&str> = arg.split(sep).collect();
+    if parts.len() < 3 {
+        return None;
+    }
+    match parts.iter().position(|&p| p == "\u{2026}") {
+        None => {
+            let n = parts.len();
+            parts[n - 2] = "\u{2026}";
+        }
+        Some(1) => return None,
+        Some(i) => {
+            parts.remove(i - 1);
+        }
+    }
+    Some(parts.join(&sep.to_string()))
+}
+
+/// Cut `s` to `max_chars` characters by replacing its middle with `…`.
+fn truncate_middle(s: &str, max_chars: usize) -> String {
+    let len = s.chars().count();
+    if len <= max_chars {
+        return s.to_string();
+    }
+    let keep = max_chars.saturating_sub(1);
+    let tail = keep / 2;
+    let head = keep - tail;
+    let mut out: String = s.chars().take(head).collect();
+    out.push('\u{2026}');
+    out.extend(s.chars().skip(len - tail));
+    out
+}
+
+/// Optional metric groups that can be switched off to save collection time
+/// and database space. CPU, memory, drives and processes are always
+/// collected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MetricGroup {
+    /// Interface counters and throughput.
+    Network,
+    /// GPU devices and their processes.
+    Gpu,
+    /// System power draw.
+    Power,
+    /// Per-drive usage samples in history (the Disks panel keeps working).
+    DiskUsage,
+}
+
+impl MetricGroup {
+    pub const ALL: [MetricGroup; 4] = [MetricGroup::Network, MetricGroup::Gpu, MetricGroup::Power, MetricGroup::DiskUsage];
+}
+
+/// Which optional groups are collected and written to history.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RecordedMetrics {
+    pub network: bool,
+    pub gpu: bool,
+    pub power: bool,
+    pub disk_usage: bool,
+}
+
+impl Default for RecordedMetrics {
+    fn default() -> Self {
+        Self { network: true, gpu: true, power: true, disk_usage: true }
+    }
+}
+
+impl RecordedMetrics {
+    pub fn contains(self, group: MetricGroup) -> bool {
+        match group {
+            MetricGroup::Network => self.network,
+            MetricGroup::Gpu => self.gpu,
+            MetricGroup::Power => self.power,
+            MetricGroup::DiskUsage => self.disk_usage,
+        }
+    }
+
+    pub fn toggle(&mut self, group: MetricGroup) {
+        let flag = match group {
+            MetricGroup::Network => &mut self.network,
+            MetricGroup::Gpu => &mut self.gpu,
+            MetricGroup::Power => &mut self.power,
+            MetricGroup::DiskUsage => &mut self.disk_usage,
+        };
+        *flag = !*flag;
+    }
+}
+
 pub struct Collector {
     sys: System,
     disks: Disks,
@@ -110,14 +606,127 @@ pub struct Collector {
     desktop_app_names: HashSet<String>,
     /// Configurable process list limit.
     process_limit: usize,
+    /// Lowercased search text the limit is applied after; empty for none.
+    process_filter: String,
+    /// Process names kept in every snapshot, past the limit.
+    pinned: HashSet<String>,
     /// Cached system info (doesn't change at runtime, shared via Arc).
     pub sys_info: Arc<SystemInfo>,
     /// Tick counter — used to skip expensive refreshes on most ticks.
     tick_count: u64,
-    /// Cached disk info (disks rarely change).
-    cached_disks: Vec<DiskInfo>,
+    /// Cached disk info (disks rarely change), shared with every snapshot.
+    cached_disks: Arc<[DiskInfo]>,
     /// Tick at which disks were last refreshed.
     disks_last_refresh: u64,
+    /// Processes carried over from the previous tick.
+    process_table: ProcessTable,
+    /// Processes whose threads are listed, and their CPU counters.
+    thread_pids: Vec<u32>,
+    thread_sampler: ThreadSampler,
+    /// Windows has no load average; the processor queue length stands in.
+    #[cfg(target_os = "windows")]
+    queue_counter: Option<QueueLengthCounter>,
+    power_meter: PowerMeter,
+    cpu_times: CpuTimeSampler,
+    vm: VmSampler,
+    /// Optional groups to collect; the others are left empty.
+    recorded: RecordedMetrics,
+    /// Calibration added to sensor readings (°C), by sensor label.
+    sensor_offsets: BTreeMap<String, f32>,
+}
+
+/// Process list kept across ticks, keyed (and ordered) by PID.
+///
+/// sysinfo's process set is walked once per tick: new PIDs get an entry,
+/// existing ones only have their usage counters updated, and PIDs that were
+/// not seen are swept. Names and command lines are compared against the
+/// stored values and only re-allocated when they differ, so unchanged
+/// processes share the same `Arc`s from one snapshot to the next.
+#[derive(Default)]
+struct ProcessTable {
+    by_pid: BTreeMap<u32, TrackedProcess>,
+}
+
+struct TrackedProcess {
+    info: ProcessInfo,
+    /// Tick the PID was last seen, for sweeping exited processes.
+    seen: u64,
+}
+
+impl ProcessTable {
+    /// Entry for `pid`, marked as seen on `tick`. Returns true when the entry
+    /// is new or the PID was reused by a process with a different name, in
+    /// which case the caller fills in the fields that never change.
+    fn update(&mut self, pid: u32, name: &OsStr, cmd: &[OsString], tick: u64) -> (&mut ProcessInfo, bool) {
+        let mut fresh = false;
+        let entry = self.by_pid.entry(pid).or_insert_with(|| {
+            fresh = true;
+            TrackedProcess {
+                info: ProcessInfo {
+                    pid,
+                    parent_pid: None,
+                    name: name.to_string_lossy().into(),
+                    cmd: cmd.iter().map(|s| s.to_string_lossy().into_owned()).collect(),
+                    cpu_usage: 0.0,
+                    memory_bytes: 0,
+                    virtual_memory_bytes: 0,
+                    disk_bytes: 0,
+                    uid: 0,
+                    is_desktop_app: false,
+                    packaging: Packaging::Native,
+                    thread_count: 1,
+                    status: 'S',
+                },
+                seen: tick,
+            }
+        });
+        let info = &mut entry.info;
+        if name.to_string_lossy() != *info.name {
+            info.name = name.to_string_lossy().into();
+            fresh = true;
+        }
+        let same_cmd = info.cmd.len() == cmd.len()
+            && info.cmd.iter().zip(cmd).all(|(cached, live)| live.to_string_lossy() == cached.as_str());
+        if !same_cmd {
+            info.cmd = cmd.iter().map(|s| s.to_string_lossy().into_owned()).collect();
+        }
+        entry.seen = tick;
+        (info, fresh)
+    }
+
+    /// Forget PIDs not seen on `tick`.
+    fn sweep(&mut self, tick: u64) {
+        self.by_pid.retain(|_, e| e.seen == tick);
+    }
+
+    fn len(&self) -> usize {
+        self.by_pid.len()
+    }
+
+    /// PID and name of every process, in PID order.
+    fn ids(&self) -> Vec<ProcessId> {
+        self.by_pid.values().map(|e| ProcessId { pid: e.info.pid, name: Arc::clone(&e.info.name) }).collect()
+    }
+
+    /// The `limit` busiest processes among those matching `filter`, plus
+    /// any matching `pinned` ones, which don't count toward the limit.
+    fn top_by_cpu(&self, limit: usize, filter: &str, pinned: &HashSet<String>) -> Vec<ProcessInfo> {
+        let by_cpu = |a: &&ProcessInfo, b: &&ProcessInfo| {
+            b.cpu_usage.partial_cmp(&a.cpu_usage).unwrap_or(std::cmp::Ordering::Equal)
+        };
+        let (mut kept, mut refs): (Vec<&ProcessInfo>, Vec<&ProcessInfo>) = self.by_pid.values()
+            .map(|e| &e.info)
+            .filter(|p| p.matches(filter))
+            .partition(|p| pinned.contains(&*p.name));
+        // Partial sort: only find the top N instead of sorting everything
+        if limit < refs.len() {
+            refs.select_nth_unstable_by(limit, by_cpu);
+            refs.truncate(limit);
+        }
+        refs.append(&mut kept);
+        refs.sort_by(by_cpu);
+        refs.into_iter().cloned().collect()
+    }
 }
 
 /// Scan all .desktop files from standard XDG directories and extract
@@ -496,6 +1105,7 @@ impl Collector {
             os_version: System::os_version().unwrap_or_else(|| "Unknown".into()),
             kernel_version: System::kernel_version().unwrap_or_else(|| "Unknown".into()),
             hostname: System::host_name().unwrap_or_else(|| "Unknown".into()),
+            hypervisor: crate::virt::detect(),
         });
 
         let disks = Disks::new_with_refreshed_list();
@@ -515,19 +1125,75 @@ impl Collector {
             components: Components::new_with_refreshed_list(),
             desktop_app_names,
             process_limit: limit,
+            process_filter: String::new(),
+            pinned: HashSet::new(),
             sys_info,
             tick_count: 0,
             cached_disks,
             disks_last_refresh: 0,
+            process_table: ProcessTable::default(),
+            thread_pids: Vec::new(),
+            thread_sampler: ThreadSampler::default(),
+            #[cfg(target_os = "windows")]
+            queue_counter: QueueLengthCounter::open(),
+            power_meter: PowerMeter::new(),
+            cpu_times: CpuTimeSampler::default(),
+            vm: VmSampler::default(),
+            recorded: RecordedMetrics::default(),
+            sensor_offsets: BTreeMap::new(),
+        }
+    }
+
+    /// Process whose page faults the following snapshots report.
+    pub fn set_fault_pid(&mut self, pid: Option<u32>) {
+        self.vm.set_pid(pid);
+    }
+
+    /// Processes whose threads the following snapshots list.
+    pub fn set_thread_pids(&mut self, pids: Vec<u32>) {
+        self.thread_pids = pids;
+    }
+
+    /// Also list the processes matching `filter` in `search_matches`,
+    /// truncated to the limit only after matching, so a quiet process can
+    /// still be found by name.
+    pub fn set_process_filter(&mut self, filter: &str) {
+        self.process_filter = filter.to_lowercase();
+    }
+
+    /// Processes with these names are included even when the limit
+    /// would drop them.
+    pub fn set_pinned(&mut self, names: HashSet<String>) {
+        self.pinned = names;
+    }
+
+    /// Optional groups the following snapshots include.
+    pub fn set_recorded(&mut self, recorded: RecordedMetrics) {
+        // Counters are deltas since the previous refresh; take a fresh
+        // baseline so the first sample doesn't span the time spent off
+        if recorded.network && !self.recorded.network {
+            self.networks.refresh_list();
         }
+        // The GPU cache stopped refreshing while off; its age isn't a hang
+        if recorded.gpu && !self.recorded.gpu {
+            crate::gpu::reset_age();
+        }
+        self.recorded = recorded;
+    }
+
+    pub fn set_sensor_offsets(&mut self, offsets: BTreeMap<String, f32>) {
+        self.sensor_offsets = offsets;
     }
 
     pub fn collect(&mut self) -> Snapshot {
+        let start = Instant::now();
+        let mut lap = start;
+        let mut timings = CollectTimings::default();
         self.tick_count += 1;
 
         // Opt #1 & #8: Only refresh what we actually use.
         // CPU frequency rarely changes — refresh it every 10 ticks.
-        let cpu_refresh = if self.tick_count % 10 == 0 {
+        let cpu_refresh = if self.tick_count.is_multiple_of(10) {
             CpuRefreshKind::everything()
         } else {
             CpuRefreshKind::new().with_cpu_usage()
@@ -545,8 +1211,19 @@ impl Collector {
                 .with_memory(MemoryRefreshKind::everything())
                 .with_processes(proc_refresh),
         );
-        self.networks.refresh();
+        timings.lap(CollectStage::Refresh, &mut lap);
+        // Re-scan the interface list every 5 ticks so added/removed
+        // interfaces (VPN, USB tethering, Wi-Fi) are picked up.
+        if self.recorded.network {
+            if self.tick_count.is_multiple_of(5) {
+                self.networks.refresh_list();
+            } else {
+                self.networks.refresh();
+            }
+        }
+        timings.lap(CollectStage::Network, &mut lap);
         self.components.refresh();
+        timings.lap(CollectStage::Sensors, &mut lap);
 
         // Opt #9: Only rebuild DiskInfo every 30 ticks (disks rarely change).
         if self.tick_count - self.disks_last_refresh >= 30 {
@@ -561,12 +1238,18 @@ impl Collector {
                 is_removable: d.is_removable(),
             }).collect();
         } else {
-            // Just refresh available space (cheap)
+            // Just refresh available space (cheap). Snapshots still hold the
+            // previous list, so only copy it when something actually changed.
             self.disks.refresh();
-            for (cached, live) in self.cached_disks.iter_mut().zip(self.disks.iter()) {
-                cached.available = live.available_space();
+            let changed = self.cached_disks.iter().zip(self.disks.iter())
+                .any(|(cached, live)| cached.available != live.available_space());
+            if changed {
+                for (cached, live) in Arc::make_mut(&mut self.cached_disks).iter_mut().zip(self.disks.iter()) {
+                    cached.available = live.available_space();
+                }
             }
         }
+        timings.lap(CollectStage::Disks, &mut lap);
 
         let cpu_usage_per_core: Vec<f32> = self.sys.cpus().iter().map(|c| c.cpu_usage()).collect();
         let cpu_usage_global = if cpu_usage_per_core.is_empty() {
@@ -574,41 +1257,69 @@ impl Collector {
         } else {
             cpu_usage_per_core.iter().sum::<f32>() / cpu_usage_per_core.len() as f32
         };
+        let cpu_times = self.cpu_times.sample();
 
         let (mut rx, mut tx) = (0u64, 0u64);
         let mut net_interfaces = Vec::new();
-        for (name, data) in self.networks.iter() {
+        for (name, data) in self.networks.iter().filter(|_| self.recorded.network) {
             rx += data.received();
             tx += data.transmitted();
             net_interfaces.push(NetIfaceInfo {
                 name: name.clone(),
                 rx_bytes: data.received(),
                 tx_bytes: data.transmitted(),
+                is_up: read_iface_up(name),
             });
         }
+        timings.lap(CollectStage::Network, &mut lap);
 
-        #[allow(unused_mut)]
         let mut temperatures: Vec<TempInfo> = self
             .components
             .iter()
             .map(|c| TempInfo {
                 label: c.label().to_string(),
                 temp_c: c.temperature(),
+                id: String::new(),
             })
             .collect();
 
+        #[allow(unused_mut)]
+        let mut temp_status = Vec::new();
+        #[allow(unused_mut)]
+        let mut sensors_age = None;
+
         // On Windows, sysinfo may return no components — fall back to WMI
         #[cfg(target_os = "windows")]
         if temperatures.is_empty() {
-            temperatures = collect_wmi_temperatures();
+            (temperatures, temp_status, sensors_age) = collect_wmi_temperatures();
         }
 
+        // macOS: Apple Silicon exposes no SMC sensors to sysinfo — use powermetrics
+        #[cfg(target_os = "macos")]
+        if temperatures.is_empty() {
+            match crate::powermetrics::latest() {
+                Ok(reading) => {
+                    temperatures = reading.temps;
+                    sensors_age = reading.age;
+                }
+                Err(issue) => temp_status.push(SensorStatus { backend: "powermetrics", issue }),
+            }
+        }
+
+        // Linux: explain why hwmon produced nothing
+        #[cfg(target_os = "linux")]
+        if temperatures.is_empty() {
+            temp_status.extend(diagnose_hwmon(Path::new("/sys/class/hwmon")));
+        }
+        assign_sensor_ids(&mut temperatures);
+        apply_sensor_offsets(&mut temperatures, &self.sensor_offsets);
+        timings.lap(CollectStage::Sensors, &mut lap);
+
         let cpus = self.sys.cpus();
         let num_cpus = cpus.len().max(1) as f32;
         let cpu_name = cpus.first().map(|c| c.brand().to_string()).unwrap_or_default();
         let cpu_frequency_mhz = cpus.first().map(|c| c.frequency()).unwrap_or(0);
         let cpu_core_count = cpus.len();
-        let process_count = self.sys.processes().values().filter(|p| p.thread_kind().is_none()).count();
         let uptime_secs = System::uptime();
 
         // Opt #2: Pre-build thread count map in O(n) instead of O(n²).
@@ -640,41 +1351,33 @@ impl Collector {
         #[cfg(target_os = "windows")]
         let system_pids = get_system_pids(&all_procs);
 
-        let mut processes: Vec<ProcessInfo> = self
-            .sys
-            .processes()
-            .values()
-            .filter(|p| p.thread_kind().is_none())
-            .map(|p| {
-                let name = p.name().to_string_lossy().to_string();
-                let pid_u32 = p.pid().as_u32();
+        let tick = self.tick_count;
+        for p in self.sys.processes().values().filter(|p| p.thread_kind().is_none()) {
+            let pid_u32 = p.pid().as_u32();
+            let (info, fresh) = self.process_table.update(pid_u32, p.name(), p.cmd(), tick);
 
+            // Fields that never change for a given process are filled in once
+            if fresh {
                 // Determine if this is a desktop app:
                 // - Linux/macOS: match binary name against .desktop/.app list
-                // - Windows: check if process owns a visible window (like Task Manager)
-                let is_desktop_app = {
-                    #[cfg(not(target_os = "windows"))]
-                    { self.desktop_app_names.contains(&name) }
-                    #[cfg(target_os = "windows")]
-                    { windowed_pids.contains(&pid_u32) }
-                };
-
-                let status_char = match p.status() {
-                    sysinfo::ProcessStatus::Run => 'R',
-                    sysinfo::ProcessStatus::Sleep => 'S',
-                    sysinfo::ProcessStatus::Zombie => 'Z',
-                    sysinfo::ProcessStatus::Idle => 'I',
-                    sysinfo::ProcessStatus::Stop => 'T',
-                    _ => 'S',
-                };
-                // O(1) thread count lookup instead of O(n) inner loop
-                let task_count = thread_counts.get(&p.pid()).copied().unwrap_or(0) + 1;
+                // - Windows: checked every tick below, windows come and go
+                #[cfg(not(target_os = "windows"))]
+                { info.is_desktop_app = self.desktop_app_names.contains(&*info.name); }
+
+                // Flatpak .desktop files run `flatpak run <id>` and AppImages
+                // are rarely installed, so neither matches an Exec line.
+                // Snaps do (`/snap/bin/<name>`), and snap services aren't apps.
+                #[cfg(target_os = "linux")]
+                {
+                    info.packaging = read_packaging(pid_u32, &info.cmd);
+                    info.is_desktop_app |= matches!(info.packaging, Packaging::Flatpak | Packaging::AppImage);
+                }
 
                 // UID: used for grouping (user vs system processes)
                 // - Linux: real UID from /proc
                 // - Windows: 0 = user process, 1 = system process (sentinel values)
                 // - macOS: 0 for all (no grouping by owner)
-                let uid = {
+                info.uid = {
                     #[cfg(target_os = "linux")]
                     { p.user_id().map(|u| **u).unwrap_or(0) }
                     #[cfg(target_os = "windows")]
@@ -682,43 +1385,71 @@ impl Collector {
                     #[cfg(target_os = "macos")]
                     { 0u32 }
                 };
+            }
+            // Windows: a process is a desktop app if it owns a visible window (like Task Manager)
+            #[cfg(target_os = "windows")]
+            { info.is_desktop_app = windowed_pids.contains(&pid_u32); }
+
+            info.status = match p.status() {
+                sysinfo::ProcessStatus::Run => 'R',
+                sysinfo::ProcessStatus::Sleep => 'S',
+                sysinfo::ProcessStatus::Zombie => 'Z',
+                sysinfo::ProcessStatus::Idle => 'I',
+                sysinfo::ProcessStatus::Stop => 'T',
+                _ => 'S',
+            };
+            // Orphans are re-parented, so the parent is refreshed too
+            info.parent_pid = p.parent().map(|pid| pid.as_u32());
+            info.cpu_usage = p.cpu_usage() / num_cpus;
+            info.memory_bytes = p.memory();
+            info.virtual_memory_bytes = p.virtual_memory();
+            let du = p.disk_usage();
+            info.disk_bytes = du.read_bytes + du.written_bytes;
+            // O(1) thread count lookup instead of O(n) inner loop
+            info.thread_count = thread_counts.get(&p.pid()).copied().unwrap_or(0) + 1;
+        }
+        self.process_table.sweep(tick);
+        let process_count = self.process_table.len();
+
+        // GPU backends only know PIDs — resolve names from the full process table
+        let mut gpu = if self.recorded.gpu { crate::gpu::collect_gpu_info() } else { Default::default() };
+        for gp in &mut gpu.processes {
+            if let Some(proc) = self.sys.process(sysinfo::Pid::from_u32(gp.pid)) {
+                gp.name = proc.name().to_string_lossy().to_string();
+            }
+        }
 
-                ProcessInfo {
-                    pid: pid_u32,
-                    parent_pid: p.parent().map(|pid| pid.as_u32()),
-                    name,
-                    cmd: p.cmd().iter().map(|s| s.to_string_lossy().to_string()).collect(),
-                    cpu_usage: p.cpu_usage() / num_cpus,
-                    memory_bytes: p.memory(),
-                    virtual_memory_bytes: p.virtual_memory(),
-                    uid,
-                    is_desktop_app,
-                    thread_count: task_count,
-                    status: status_char,
-                }
-            })
-            .collect();
-
-        // Use partial sort: only find top N by CPU usage instead of sorting everything.
-        let limit = self.process_limit.min(processes.len());
-        if limit < processes.len() {
-            processes.select_nth_unstable_by(limit, |a, b| {
-                b.cpu_usage.partial_cmp(&a.cpu_usage).unwrap_or(std::cmp::Ordering::Equal)
-            });
-            processes.truncate(limit);
+        // Apple Silicon GPUs share system memory
+        #[cfg(target_os = "macos")]
+        for g in gpu.gpus.iter_mut().filter(|g| g.memory_total == 0) {
+            g.memory_total = self.sys.total_memory();
         }
-        // Sort the top N for display
-        processes.sort_by(|a, b| b.cpu_usage.partial_cmp(&a.cpu_usage).unwrap_or(std::cmp::Ordering::Equal));
+
+        // Only the top N rows (and the top N matching the search) are copied into the snapshot
+        let all_processes = self.process_table.ids();
+        let processes = self.process_table.top_by_cpu(self.process_limit, "", &self.pinned);
+        let search_matches = if self.process_filter.is_empty() {
+            Vec::new()
+        } else {
+            self.process_table.top_by_cpu(self.process_limit, &self.process_filter, &self.pinned)
+        };
+        let threads = self.thread_sampler.sample(&self.thread_pids, num_cpus);
+        timings.lap(CollectStage::Processes, &mut lap);
 
         let now = chrono::Utc::now().timestamp_millis() as f64 / 1000.0;
 
-        // Load averages (Linux/macOS); zeros on unsupported platforms
-        let load_avg = read_load_avg();
+        let load = self.read_system_load();
+        let power = if self.recorded.power { self.power_meter.read() } else { None };
+        timings.lap(CollectStage::Power, &mut lap);
+        timings.stages[CollectStage::Gpu as usize] = gpu.poll_time;
+        timings.total = start.elapsed();
+        let data_age = DataAge { gpu: gpu.age, sensors: sensors_age };
 
         Snapshot {
             timestamp: now,
             cpu_usage_per_core,
             cpu_usage_global,
+            cpu_times,
             cpu_name,
             cpu_core_count,
             cpu_frequency_mhz,
@@ -726,7 +1457,8 @@ impl Collector {
             memory_total: self.sys.total_memory(),
             swap_used: self.sys.used_swap(),
             swap_total: self.sys.total_swap(),
-            disks: self.cached_disks.clone(),
+            memory_activity: self.vm.sample(),
+            disks: Arc::clone(&self.cached_disks),
             disk_io: DiskIoSnapshot {
                 read_bytes: total_disk_read,
                 write_bytes: total_disk_write,
@@ -735,29 +1467,128 @@ impl Collector {
             net_tx_bytes: tx,
             net_interfaces,
             temperatures,
+            temp_status,
             processes,
-            gpu: crate::gpu::collect_gpu_info(),
+            all_processes,
+            search_matches,
+            threads,
+            gpu,
             uptime_secs,
             process_count,
             sys_info: Arc::clone(&self.sys_info),
-            load_avg,
+            load,
+            power,
+            timings,
+            data_age,
         }
     }
 }
 
-/// Read system load averages (1m, 5m, 15m).
-fn read_load_avg() -> [f64; 3] {
-    #[cfg(any(target_os = "linux", target_os = "macos"))]
-    {
-        let mut avg: [libc::c_double; 3] = [0.0; 3];
-        // SAFETY: getloadavg is a standard POSIX function that writes load
-        // averages into the provided array. We pass a valid array of 3 elements.
-        let ret = unsafe { libc::getloadavg(avg.as_mut_ptr(), 3) };
-        if ret == 3 {
-            return [avg[0], avg[1], avg[2]];
+impl Collector {
+    /// Load averages on Linux/macOS, processor queue length on Windows.
+    fn read_system_load(&self) -> SystemLoad {
+        #[cfg(any(target_os = "linux", target_os = "macos"))]
+        {
+            let mut avg: [libc::c_double; 3] = [0.0; 3];
+            // SAFETY: getloadavg is a standard POSIX function that writes load
+            // averages into the provided array. We pass a valid array of 3 elements.
+            let ret = unsafe { libc::getloadavg(avg.as_mut_ptr(), 3) };
+            if ret == 3 {
+                return SystemLoad::Average([avg[0], avg[1], avg[2]]);
+            }
+        }
+        #[cfg(target_os = "windows")]
+        if let Some(queue) = self.queue_counter.as_ref().and_then(QueueLengthCounter::read) {
+            return SystemLoad::QueueLength(queue);
+        }
+        SystemLoad::Unavailable
+    }
+}
+
+/// PDH query for `\System\Processor Queue Length`, opened once and
+/// sampled every tick. The counter is instantaneous, so a single
+/// collection is enough for a valid value.
+#[cfg(target_os = "windows")]
+struct QueueLengthCounter {
+    query: isize,
+    counter: isize,
+}
+
+#[cfg(target_os = "windows")]
+impl QueueLengthCounter {
+    fn open() -> Option<Self> {
+        use windows_sys::Win32::System::Performance::{PdhAddEnglishCounterW, PdhCloseQuery, PdhOpenQueryW};
+
+        let path: Vec<u16> = "\\System\\Processor Queue Length".encode_utf16().chain(Some(0)).collect();
+        let mut query = 0isize;
+        let mut counter = 0isize;
+        // SAFETY: the out-pointers reference live locals and `path` is a
+        // NUL-terminated UTF-16 buffer that outlives the call.
+        unsafe {
+            if PdhOpenQueryW(std::ptr::null(), 0, &mut query) != 0 {
+                return None;
+            }
+            if PdhAddEnglishCounterW(query, path.as_ptr(), 0, &mut counter) != 0 {
+                PdhCloseQuery(query);
+                return None;
+            }
+        }
+        Some(Self { query, counter })
+    }
+
+    fn read(&self) -> Option<u32> {
+        use windows_sys::Win32::System::Performance::{
+            PdhCollectQueryData, PdhGetFormattedCounterValue, PDH_FMT_COUNTERVALUE, PDH_FMT_LONG,
+        };
+
+        // SAFETY: the handles were returned by PdhOpenQueryW/PdhAddEnglishCounterW
+        // and stay valid until Drop; `value` is a plain C struct we own.
+        unsafe {
+            if PdhCollectQueryData(self.query) != 0 {
+                return None;
+            }
+            let mut value: PDH_FMT_COUNTERVALUE = std::mem::zeroed();
+            if PdhGetFormattedCounterValue(self.counter, PDH_FMT_LONG, std::ptr::null_mut(), &mut value) != 0 {
+                return None;
+            }
+            Some(value.Anonymous.longValue.max(0) as u32)
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+impl Drop for QueueLengthCounter {
+    fn drop(&mut self) {
+        // SAFETY: the query handle is valid and closed exactly once.
+        unsafe {
+            windows_sys::Win32::System::Performance::PdhCloseQuery(self.query);
+        }
+    }
+}
+
+/// Fill in each sensor's `id`: its label, with ` #2`, ` #3`... appended to
+/// later sensors of the same name (e.g. two drives' "Composite"). The
+/// first keeps the bare label, which is how offsets used to be keyed.
+fn assign_sensor_ids(temperatures: &mut [TempInfo]) {
+    let mut seen: HashMap<String, u32> = HashMap::new();
+    for t in temperatures {
+        let n = seen.entry(t.label.clone()).or_insert(0);
+        *n += 1;
+        t.id = if *n == 1 { t.label.clone() } else { format!("{} #{n}", t.label) };
+    }
+}
+
+/// Add the calibration offset set for each sensor, whichever backend
+/// read it.
+fn apply_sensor_offsets(temperatures: &mut [TempInfo], offsets: &BTreeMap<String, f32>) {
+    if offsets.is_empty() {
+        return;
+    }
+    for t in temperatures {
+        if let Some(offset) = offsets.get(&t.id) {
+            t.temp_c += offset;
         }
     }
-    [0.0, 0.0, 0.0]
 }
 
 /// Windows temperature sensors via native WMI — no PowerShell subprocess.
@@ -767,20 +1598,22 @@ fn read_load_avg() -> [f64; 3] {
 /// 2. Win32_PerfFormattedData_Counters_ThermalZoneInformation (no admin, Win10+)
 /// 3. MSAcpi_ThermalZoneTemperature in root\WMI namespace (requires admin)
 #[cfg(target_os = "windows")]
-fn collect_wmi_temperatures() -> Vec<TempInfo> {
+fn collect_wmi_temperatures() -> (Vec<TempInfo>, Vec<SensorStatus>, Option<Duration>) {
     use std::sync::Mutex;
     use std::time::Instant;
 
-    static CACHE: Mutex<Option<(Instant, Vec<TempInfo>)>> = Mutex::new(None);
+    type Reading = (Vec<TempInfo>, Vec<SensorStatus>);
+    static CACHE: Mutex<Option<(Instant, Reading)>> = Mutex::new(None);
     static REFRESH_RUNNING: Mutex<bool> = Mutex::new(false);
     const TTL_SECS: u64 = 3;
 
     let cached = if let Ok(guard) = CACHE.lock() {
         if let Some((ts, ref data)) = *guard {
+            let (temps, status) = data.clone();
             if ts.elapsed().as_secs() < TTL_SECS {
-                return data.clone();
+                return (temps, status, Some(ts.elapsed()));
             }
-            Some(data.clone())
+            Some((temps, status, Some(ts.elapsed())))
         } else {
             None
         }
@@ -797,9 +1630,9 @@ fn collect_wmi_temperatures() -> Vec<TempInfo> {
             *g = true;
         }
         std::thread::spawn(move || {
-            let temps = collect_wmi_temperatures_native();
+            let reading = collect_wmi_temperatures_native();
             if let Ok(mut guard) = CACHE.lock() {
-                *guard = Some((Instant::now(), temps));
+                *guard = Some((Instant::now(), reading));
             }
             if let Ok(mut g) = REFRESH_RUNNING.lock() {
                 *g = false;
@@ -810,8 +1643,10 @@ fn collect_wmi_temperatures() -> Vec<TempInfo> {
     cached.unwrap_or_default()
 }
 
+/// Temperatures from the first WMI source that has any, or the reason
+/// each source came back empty.
 #[cfg(target_os = "windows")]
-fn collect_wmi_temperatures_native() -> Vec<TempInfo> {
+fn collect_wmi_temperatures_native() -> (Vec<TempInfo>, Vec<SensorStatus>) {
     use std::collections::HashMap;
     use wmi::{COMLibrary, Variant, WMIConnection};
 
@@ -819,8 +1654,22 @@ fn collect_wmi_temperatures_native() -> Vec<TempInfo> {
     // and winit/iced may have already called it on the UI thread.
     let com_lib = match COMLibrary::without_security() {
         Ok(c) => c,
-        Err(_) => return Vec::new(),
+        Err(e) => {
+            let issue = SensorIssue::Failed(e.to_string());
+            return (Vec::new(), vec![SensorStatus { backend: "WMI", issue }]);
+        }
+    };
+
+    // WBEM_E_ACCESS_DENIED shows up as this HRESULT in the error text
+    let wmi_issue = |e: &dyn std::fmt::Display| {
+        let msg = e.to_string();
+        if msg.contains("80041003") || msg.to_ascii_lowercase().contains("access denied") {
+            SensorIssue::PermissionDenied
+        } else {
+            SensorIssue::Failed(msg)
+        }
     };
+    let mut status = Vec::new();
 
     // Helper: extract f64 from common WMI Variant types
     fn variant_f64(v: &Variant) -> Option<f64> {
@@ -845,9 +1694,12 @@ fn collect_wmi_temperatures_native() -> Vec<TempInfo> {
         }
     }
 
-    // 1. Try LibreHardwareMonitor / OpenHardwareMonitor (all sensors)
+    // 1. Try LibreHardwareMonitor / OpenHardwareMonitor (all sensors).
+    //    The namespace only exists while one of them is running.
+    let mut monitor_running = false;
     for ns in &["ROOT\\LibreHardwareMonitor", "ROOT\\OpenHardwareMonitor"] {
         if let Ok(con) = WMIConnection::with_namespace_path(ns, com_lib) {
+            monitor_running = true;
             if let Ok(results) = con.raw_query::<HashMap<String, Variant>>(
                 "SELECT Name, SensorType, Value FROM Sensor WHERE SensorType = 'Temperature'",
             ) {
@@ -857,7 +1709,7 @@ fn collect_wmi_temperatures_native() -> Vec<TempInfo> {
                         let label = variant_string(row.get("Name")?)?;
                         let temp_c = variant_f64(row.get("Value")?)? as f32;
                         if temp_c > 0.0 && temp_c < 150.0 {
-                            Some(TempInfo { label, temp_c })
+                            Some(TempInfo { label, temp_c, id: String::new() })
                         } else {
                             None
                         }
@@ -865,85 +1717,186 @@ fn collect_wmi_temperatures_native() -> Vec<TempInfo> {
                     .collect();
 
                 if !temps.is_empty() {
-                    return temps;
+                    return (temps, Vec::new());
                 }
             }
         }
     }
+    if !monitor_running {
+        status.push(SensorStatus {
+            backend: "LibreHardwareMonitor",
+            issue: SensorIssue::ServiceMissing("LibreHardwareMonitor"),
+        });
+    }
 
     // 2. Thermal zone performance counters (Kelvin → Celsius, no admin)
     //    Use raw_query for maximum compatibility across Windows versions.
     if let Ok(con) = WMIConnection::new(com_lib) {
-        if let Ok(results) = con.raw_query::<HashMap<String, Variant>>(
+        match con.raw_query::<HashMap<String, Variant>>(
             "SELECT Name, Temperature FROM Win32_PerfFormattedData_Counters_ThermalZoneInformation",
         ) {
-            let temps: Vec<TempInfo> = results
-                .iter()
-                .filter_map(|row| {
-                    let kelvin = variant_f64(row.get("Temperature")?)? as u32;
-                    if kelvin == 0 {
-                        return None;
-                    }
-                    let celsius = kelvin as f32 - 273.15;
-                    if celsius > 0.0 && celsius < 150.0 {
-                        let label = row
-                            .get("Name")
-                            .and_then(variant_string)
-                            .unwrap_or_else(|| "Thermal Zone".into());
-                        Some(TempInfo {
-                            label,
-                            temp_c: celsius,
-                        })
-                    } else {
-                        None
-                    }
-                })
-                .collect();
+            Ok(results) => {
+                let temps: Vec<TempInfo> = results
+                    .iter()
+                    .filter_map(|row| {
+                        let kelvin = variant_f64(row.get("Temperature")?)? as u32;
+                        if kelvin == 0 {
+                            return None;
+                        }
+                        let celsius = kelvin as f32 - 273.15;
+                        if celsius > 0.0 && celsius < 150.0 {
+                            let label = row
+                                .get("Name")
+                                .and_then(variant_string)
+                                .unwrap_or_else(|| "Thermal Zone".into());
+                            Some(TempInfo {
+                                label,
+                                temp_c: celsius,
+                                id: String::new(),
+                            })
+                        } else {
+                            None
+                        }
+                    })
+                    .collect();
 
-            if !temps.is_empty() {
-                return temps;
+                if !temps.is_empty() {
+                    return (temps, Vec::new());
+                }
             }
+            Err(e) => status.push(SensorStatus { backend: "Thermal zone counters", issue: wmi_issue(&e) }),
         }
     }
 
     // 3. ACPI thermal zones (requires admin, ROOT\WMI namespace)
     if let Ok(con) = WMIConnection::with_namespace_path("ROOT\\WMI", com_lib) {
-        if let Ok(results) = con.raw_query::<HashMap<String, Variant>>(
+        match con.raw_query::<HashMap<String, Variant>>(
             "SELECT InstanceName, CurrentTemperature FROM MSAcpi_ThermalZoneTemperature",
         ) {
-            let temps: Vec<TempInfo> = results
-                .iter()
-                .filter_map(|row| {
-                    let raw = variant_f64(row.get("CurrentTemperature")?)?;
-                    let celsius = (raw - 2732.0) / 10.0;
-                    if celsius > 0.0 && celsius < 150.0 {
-                        let label = row
-                            .get("InstanceName")
-                            .and_then(variant_string)
-                            .unwrap_or_else(|| "ACPI Thermal Zone".into());
-                        Some(TempInfo {
-                            label,
-                            temp_c: celsius as f32,
-                        })
-                    } else {
-                        None
-                    }
-                })
-                .collect();
+            Ok(results) => {
+                let temps: Vec<TempInfo> = results
+                    .iter()
+                    .filter_map(|row| {
+                        let raw = variant_f64(row.get("CurrentTemperature")?)?;
+                        let celsius = (raw - 2732.0) / 10.0;
+                        if celsius > 0.0 && celsius < 150.0 {
+                            let label = row
+                                .get("InstanceName")
+                                .and_then(variant_string)
+                                .unwrap_or_else(|| "ACPI Thermal Zone".into());
+                            Some(TempInfo {
+                                label,
+                                temp_c: celsius as f32,
+                                id: String::new(),
+                            })
+                        } else {
+                            None
+                        }
+                    })
+                    .collect();
 
-            if !temps.is_empty() {
-                return temps;
+                if !temps.is_empty() {
+                    return (temps, Vec::new());
+                }
             }
+            Err(e) => status.push(SensorStatus { backend: "ACPI", issue: wmi_issue(&e) }),
         }
     }
 
-    Vec::new()
+    (Vec::new(), status)
 }
 
+/// Explain an empty sysinfo component list from the hwmon tree: no chips
+/// with temperature inputs means no driver is loaded, an input that cannot
+/// be read means permissions. Returns None when inputs are readable.
+#[cfg(target_os = "linux")]
+fn diagnose_hwmon(root: &Path) -> Option<SensorStatus> {
+    let status = |issue| Some(SensorStatus { backend: "hwmon", issue });
+    let chips = match std::fs::read_dir(root) {
+        Ok(c) => c,
+        Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => return status(SensorIssue::PermissionDenied),
+        Err(_) => return status(SensorIssue::DriverMissing),
+    };
+    let mut inputs = chips
+        .flatten()
+        .filter_map(|chip| std::fs::read_dir(chip.path()).ok())
+        .flat_map(|files| files.flatten())
+        .filter(|f| {
+            let name = f.file_name();
+            let name = name.to_string_lossy();
+            name.starts_with("temp") && name.ends_with("_input")
+        })
+        .peekable();
+    if inputs.peek().is_none() {
+        return status(SensorIssue::DriverMissing);
+    }
+    for input in inputs {
+        match std::fs::read_to_string(input.path()) {
+            Ok(_) => return None,
+            Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => {}
+            Err(e) => return status(SensorIssue::Failed(e.to_string())),
+        }
+    }
+    status(SensorIssue::PermissionDenied)
+}
+
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_elide_command_line() {
+        let cmd: Vec<String> = ["/usr/sbin/nginx", "-c", "/etc/nginx/sites/example.org/nginx.conf"]
+            .iter().map(|s| s.to_string()).collect();
+        assert_eq!(elide_command_line(&cmd, 80), cmd.join(" "));
+        assert_eq!(elide_command_line(&cmd, 48), "/usr/sbin/nginx -c /etc/nginx/sites/\u{2026}/nginx.conf");
+        assert_eq!(elide_command_line(&cmd, 35), "/usr/sbin/nginx -c /\u{2026}/nginx.conf");
+        assert_eq!(elide_command_line(&cmd, 20), "/\u{2026}/nginx -\u{2026}ginx.conf");
+        assert_eq!(shorten_path("--config=C:\\Users\\me\\app.toml").as_deref(), Some("--config=C:\\Users\\\u{2026}\\app.toml"));
+        assert_eq!(shorten_path("-v"), None);
+    }
+
+    #[test]
+    fn test_apply_sensor_offsets() {
+        let sensor = |label: &str, temp_c| TempInfo { label: label.into(), temp_c, id: String::new() };
+        let mut temps = vec![sensor("Tctl", 60.0), sensor("Composite", 40.0), sensor("Composite", 45.0)];
+        assign_sensor_ids(&mut temps);
+        assert_eq!(temps.iter().map(|t| t.id.as_str()).collect::<Vec<_>>(), ["Tctl", "Composite", "Composite #2"]);
+
+        let offsets = BTreeMap::from([("Tctl".to_string(), -10.0), ("Composite #2".to_string(), 2.0), ("gone".to_string(), 5.0)]);
+        apply_sensor_offsets(&mut temps, &offsets);
+        assert_eq!(temps[0].temp_c, 50.0);
+        assert_eq!(temps[1].temp_c, 40.0);
+        assert_eq!(temps[2].temp_c, 47.0);
+    }
+
+    #[test]
+    fn test_collect_timings() {
+        let mut timings = CollectTimings::default();
+        let mut lap = Instant::now() - Duration::from_millis(200);
+        timings.lap(CollectStage::Processes, &mut lap);
+        timings.lap(CollectStage::Network, &mut lap);
+        assert!(timings.get(CollectStage::Processes) >= Duration::from_millis(200));
+        let slow: Vec<CollectStage> = timings.over_budget().map(|(s, _)| s).collect();
+        assert_eq!(slow, vec![CollectStage::Processes]);
+
+        let snap = Collector::with_process_limit(10).collect();
+        assert!(snap.timings.total >= snap.timings.get(CollectStage::Refresh));
+    }
+
+    #[test]
+    fn test_packaging_from() {
+        let flatpak = "0::/user.slice/user-1000.slice/user@1000.service/app.slice/app-flatpak-org.mozilla.firefox-4242.scope\n";
+        assert_eq!(packaging_from(flatpak, "/app/lib/firefox/firefox"), Packaging::Flatpak);
+        let snap = "0::/user.slice/user-1000.slice/user@1000.service/app.slice/snap.spotify.spotify-1f2e.scope\n";
+        assert_eq!(packaging_from(snap, "/snap/spotify/80/usr/share/spotify/spotify"), Packaging::Snap);
+        assert_eq!(packaging_from("", "/tmp/.mount_KritaAbC123/usr/bin/krita"), Packaging::AppImage);
+        let native = "0::/user.slice/user-1000.slice/user@1000.service/app.slice/app-gnome-firefox-4242.scope\n";
+        assert_eq!(packaging_from(native, "/usr/lib/firefox/firefox"), Packaging::Native);
+        assert_eq!(Packaging::Native.badge(), None);
+    }
+
     #[test]
     fn test_desktop_app_names_loads() {
         // Just ensure it doesn't panic
@@ -964,10 +1917,96 @@ mod tests {
         }
     }
 
+    fn iface(name: &str, is_up: bool) -> NetIfaceInfo {
+        NetIfaceInfo { name: name.into(), rx_bytes: 0, tx_bytes: 0, is_up }
+    }
+
+    #[test]
+    fn test_diff_interfaces() {
+        let prev = vec![iface("eth0", true), iface("wlan0", true), iface("tun0", true)];
+        let cur = vec![iface("eth0", true), iface("wlan0", false), iface("usb0", true)];
+        let changes = diff_interfaces(&prev, &cur);
+        assert_eq!(changes, vec![
+            IfaceChange::LinkDown("wlan0".into()),
+            IfaceChange::Added("usb0".into()),
+            IfaceChange::Removed("tun0".into()),
+        ]);
+        assert!(diff_interfaces(&cur, &cur).is_empty());
+    }
+
+    #[test]
+    fn test_process_table_updates_in_place() {
+        let mut table = ProcessTable::default();
+        let cmd = [OsString::from("vim"), OsString::from("notes.txt")];
+        let (info, fresh) = table.update(7, OsStr::new("vim"), &cmd, 1);
+        assert!(fresh);
+        info.cpu_usage = 3.0;
+        let (name1, cmd1) = (Arc::clone(&info.name), Arc::clone(&info.cmd));
+
+        let (info, fresh) = table.update(7, OsStr::new("vim"), &cmd, 2);
+        assert!(!fresh);
+        assert!(Arc::ptr_eq(&name1, &info.name));
+        assert!(Arc::ptr_eq(&cmd1, &info.cmd));
+        assert_eq!(info.cpu_usage, 3.0);
+
+        // Changed arguments are re-allocated, the unchanged name is kept
+        let (info, _) = table.update(7, OsStr::new("vim"), &cmd[..1], 3);
+        assert!(Arc::ptr_eq(&name1, &info.name));
+        assert_eq!(&*info.cmd, ["vim".to_string()]);
+
+        // A reused PID with a new name counts as a new process
+        assert!(table.update(7, OsStr::new("sh"), &[], 4).1);
+
+        table.update(3, OsStr::new("init"), &[], 5).0.cpu_usage = 1.0;
+        table.update(9, OsStr::new("cargo"), &[], 5).0.cpu_usage = 80.0;
+        table.sweep(5);
+        assert_eq!(table.ids().iter().map(|p| p.pid).collect::<Vec<_>>(), vec![3, 9]);
+        let none = HashSet::new();
+        assert_eq!(table.top_by_cpu(1, "", &none).iter().map(|p| p.pid).collect::<Vec<_>>(), vec![9]);
+        // A search looks past the busier processes the limit would keep
+        assert_eq!(table.top_by_cpu(1, "ini", &none).iter().map(|p| p.pid).collect::<Vec<_>>(), vec![3]);
+        // So do pinned names, on top of the limit
+        let pinned = HashSet::from(["init".to_string()]);
+        assert_eq!(table.top_by_cpu(1, "", &pinned).iter().map(|p| p.pid).collect::<Vec<_>>(), vec![9, 3]);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_diagnose_hwmon() {
+        let root = std::env::temp_dir().join(format!("digger_hwmon_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        let chip = root.join("hwmon0");
+        std::fs::create_dir_all(&chip).unwrap();
+        std::fs::write(chip.join("name"), "acpitz\n").unwrap();
+        let missing = Some(SensorStatus { backend: "hwmon", issue: SensorIssue::DriverMissing });
+        assert_eq!(diagnose_hwmon(&root.join("absent")), missing);
+        assert_eq!(diagnose_hwmon(&root), missing);
+
+        std::fs::write(chip.join("temp1_input"), "42000\n").unwrap();
+        assert_eq!(diagnose_hwmon(&root), None);
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
     #[test]
     fn test_process_limit() {
         // Verify the limit field is stored correctly
         let collector = Collector::with_process_limit(50);
         assert_eq!(collector.process_limit, 50);
     }
+
+    #[test]
+    fn test_recorded_metrics() {
+        let mut recorded: RecordedMetrics = serde_json::from_str(r#"{"gpu": false}"#).unwrap();
+        assert!(!recorded.contains(MetricGroup::Gpu));
+        assert!(MetricGroup::ALL.iter().filter(|&&g| g != MetricGroup::Gpu).all(|&g| recorded.contains(g)));
+        recorded.toggle(MetricGroup::Network);
+        assert!(!recorded.network);
+
+        let mut collector = Collector::with_process_limit(10);
+        collector.set_recorded(recorded);
+        let snap = collector.collect();
+        assert!(snap.net_interfaces.is_empty());
+        assert_eq!(snap.net_rx_bytes, 0);
+        assert!(snap.gpu.gpus.is_empty());
+    }
 }
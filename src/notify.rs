@@ -0,0 +1,75 @@
+//! Desktop notifications.
+//!
+//! Events are routed to the OS notification center by severity: the user
+//! picks the lowest severity that still raises a notification, and critical
+//! ones are sent as urgent (critical urgency on freedesktop servers, which
+//! keeps them on screen until dismissed; a long-duration toast on Windows).
+
+use serde::{Deserialize, Serialize};
+
+/// How serious an event is, from the notification center's point of view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Urgency {
+    Low,
+    Normal,
+    Critical,
+}
+
+/// Lowest severity that raises a desktop notification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum NotifyLevel {
+    /// Every event, including informational ones (watched processes,
+    /// interfaces coming up).
+    All,
+    #[default]
+    Warning,
+    Critical,
+    Off,
+}
+
+impl NotifyLevel {
+    pub const ALL: &'static [NotifyLevel] = &[NotifyLevel::All, NotifyLevel::Warning, NotifyLevel::Critical, NotifyLevel::Off];
+
+    pub fn allows(self, urgency: Urgency) -> bool {
+        match self {
+            NotifyLevel::All => true,
+            NotifyLevel::Warning => urgency >= Urgency::Normal,
+            NotifyLevel::Critical => urgency == Urgency::Critical,
+            NotifyLevel::Off => false,
+        }
+    }
+}
+
+/// Send a desktop notification (non-blocking, best-effort).
+pub fn send(title: &str, body: &str, urgency: Urgency) {
+    let mut notification = notify_rust::Notification::new();
+    notification.summary(title).body(body).appname("Digger");
+    if urgency == Urgency::Critical {
+        notification.timeout(notify_rust::Timeout::Never);
+    } else {
+        notification.timeout(notify_rust::Timeout::Milliseconds(5000));
+    }
+    #[cfg(all(unix, not(target_os = "macos")))]
+    notification.urgency(match urgency {
+        Urgency::Low => notify_rust::Urgency::Low,
+        Urgency::Normal => notify_rust::Urgency::Normal,
+        Urgency::Critical => notify_rust::Urgency::Critical,
+    });
+    let _ = notification.show();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notify_level_allows() {
+        assert!(NotifyLevel::All.allows(Urgency::Low));
+        assert!(!NotifyLevel::Warning.allows(Urgency::Low));
+        assert!(NotifyLevel::Warning.allows(Urgency::Normal));
+        assert!(NotifyLevel::Warning.allows(Urgency::Critical));
+        assert!(!NotifyLevel::Critical.allows(Urgency::Normal));
+        assert!(NotifyLevel::Critical.allows(Urgency::Critical));
+        assert!(!NotifyLevel::Off.allows(Urgency::Critical));
+    }
+}
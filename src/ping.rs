@@ -0,0 +1,268 @@
+//! Connection quality from periodic pings.
+//!
+//! A background thread runs the system `ping` against a host chosen in
+//! Settings every `PING_INTERVAL_SECS` and hands each round-trip time, or a
+//! loss, to the UI through a channel. Over the last `QUALITY_WINDOW`
+//! replies the UI derives latency, jitter (the mean change between
+//! consecutive replies, as in RFC 3550) and a MOS-like score from a
+//! simplified ITU-T G.107 E-model: 1 is unusable, 4.5 is as good as a call
+//! gets.
+
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::Arc;
+
+use crate::ringbuf::RingBuffer;
+
+/// Seconds between two pings.
+pub const PING_INTERVAL_SECS: u64 = 2;
+/// Replies (and losses) the quality figures are computed over.
+pub const QUALITY_WINDOW: usize = 30;
+/// Pings kept for the quality chart, 30 minutes at the default interval.
+const HISTORY_LEN: usize = 900;
+
+/// Latency, jitter, loss and score over a window of pings.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Quality {
+    /// Mean round-trip time of the replies, in ms; 0 when none came back.
+    pub latency_ms: f32,
+    pub jitter_ms: f32,
+    /// Share of pings without a reply, 0–100.
+    pub loss_pct: f32,
+    /// Mean opinion score, 1–4.5.
+    pub mos: f32,
+}
+
+impl Quality {
+    /// Figures for pings oldest first; `None` entries are losses. None when
+    /// there are no pings yet.
+    pub fn from_rtts(rtts: impl IntoIterator<Item = Option<f32>>) -> Option<Self> {
+        let (mut count, mut replies, mut sum, mut diff_sum, mut diffs) = (0, 0, 0.0, 0.0, 0);
+        let mut prev: Option<f32> = None;
+        for rtt in rtts {
+            count += 1;
+            let Some(rtt) = rtt else { continue };
+            replies += 1;
+            sum += rtt;
+            if let Some(prev) = prev {
+                diff_sum += (rtt - prev).abs();
+                diffs += 1;
+            }
+            prev = Some(rtt);
+        }
+        if count == 0 {
+            return None;
+        }
+        let latency_ms = if replies > 0 { sum / replies as f32 } else { 0.0 };
+        let jitter_ms = if diffs > 0 { diff_sum / diffs as f32 } else { 0.0 };
+        let loss_pct = (count - replies) as f32 / count as f32 * 100.0;
+        Some(Self { latency_ms, jitter_ms, loss_pct, mos: mos(latency_ms, jitter_ms, loss_pct) })
+    }
+
+    /// The score as a 0–100 share of the best possible, for the gauge.
+    pub fn score_pct(&self) -> f32 {
+        ((self.mos - 1.0) / 3.5 * 100.0).clamp(0.0, 100.0)
+    }
+}
+
+/// E-model R factor from one-way delay (half the RTT plus jitter buffer and
+/// codec allowances) and loss, mapped to a MOS.
+fn mos(latency_ms: f32, jitter_ms: f32, loss_pct: f32) -> f32 {
+    let effective = latency_ms + 2.0 * jitter_ms + 10.0;
+    let r = if effective < 160.0 { 93.2 - effective / 40.0 } else { 93.2 - (effective - 120.0) / 10.0 };
+    let r = (r - 2.5 * loss_pct).clamp(0.0, 100.0);
+    (1.0 + 0.035 * r + 0.000_007 * r * (r - 60.0) * (100.0 - r)).clamp(1.0, 4.5)
+}
+
+/// One ping, with the quality over the window it closed.
+#[derive(Clone, Copy, Debug)]
+pub struct PingPoint {
+    pub timestamp: f64,
+    pub rtt_ms: Option<f32>,
+    pub quality: Quality,
+}
+
+/// Handle to the pinging thread. Dropping it stops the thread.
+pub struct PingMonitor {
+    host: String,
+    rx: Receiver<Result<Option<f32>, String>>,
+    stop: Arc<AtomicBool>,
+    history: RingBuffer<PingPoint>,
+    error: Option<String>,
+}
+
+impl PingMonitor {
+    /// Starts pinging `host`, unless it is neither an IP address nor a host
+    /// name; that is reported through `error`.
+    pub fn start(host: &str) -> Self {
+        let (tx, rx) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        if !is_valid_host(host) {
+            let error = Some(format!("Not a host name or IP address: {host}"));
+            return Self { host: host.to_string(), rx, stop, history: RingBuffer::new(HISTORY_LEN), error };
+        }
+        let thread_stop = Arc::clone(&stop);
+        let thread_host = host.to_string();
+        std::thread::spawn(move || {
+            while !thread_stop.load(Ordering::Relaxed) {
+                let result = ping_once(&thread_host);
+                let failed = result.is_err();
+                if tx.send(result).is_err() || failed {
+                    return;
+                }
+                for _ in 0..PING_INTERVAL_SECS * 4 {
+                    if thread_stop.load(Ordering::Relaxed) {
+                        return;
+                    }
+                    std::thread::sleep(std::time::Duration::from_millis(250));
+                }
+            }
+        });
+        Self { host: host.to_string(), rx, stop, history: RingBuffer::new(HISTORY_LEN), error: None }
+    }
+
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    /// Move finished pings into the history, stamped `now`.
+    pub fn poll(&mut self, now: f64) {
+        while let Ok(result) = self.rx.try_recv() {
+            match result {
+                Ok(rtt_ms) => {
+                    let window = self.history.last(QUALITY_WINDOW - 1).map(|p| p.rtt_ms);
+                    let Some(quality) = Quality::from_rtts(window.chain([rtt_ms])) else { continue };
+                    self.history.push(PingPoint { timestamp: now, rtt_ms, quality });
+                }
+                Err(e) => self.error = Some(e),
+            }
+        }
+    }
+
+    /// Quality after the newest ping.
+    pub fn quality(&self) -> Option<Quality> {
+        self.history.last(1).next().map(|p| p.quality)
+    }
+
+    pub fn history(&self) -> &RingBuffer<PingPoint> {
+        &self.history
+    }
+
+    /// Why pinging stopped, when `ping` could not be run.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
+
+impl Drop for PingMonitor {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Round-trip time of a single ping in ms, None when no reply came back.
+/// Err when `ping` itself could not be started.
+fn ping_once(host: &str) -> Result<Option<f32>, String> {
+    let output = ping_command(host).output().map_err(|e| format!("ping: {e}"))?;
+    Ok(parse_rtt(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// An IP address, or a DNS name: dot-separated labels of letters, digits
+/// and inner hyphens. Anything else, e.g. `-f`, never reaches `ping`.
+fn is_valid_host(host: &str) -> bool {
+    if host.parse::<std::net::IpAddr>().is_ok() {
+        return true;
+    }
+    let name = host.strip_suffix('.').unwrap_or(host);
+    !name.is_empty()
+        && name.len() <= 253
+        && name.split('.').all(|label| {
+            (1..=63).contains(&label.len())
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+}
+
+fn ping_command(host: &str) -> Command {
+    let mut cmd = Command::new("ping");
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        cmd.args(["-n", "1", "-w", "2000", host]);
+        // CREATE_NO_WINDOW: don't flash a console
+        cmd.creation_flags(0x0800_0000);
+    }
+    #[cfg(target_os = "macos")]
+    cmd.args(["-c", "1", "-t", "2", "--", host]);
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    cmd.args(["-c", "1", "-W", "2", "--", host]);
+    cmd
+}
+
+/// The first `=<n> ms` or `<<n>ms` in ping's output, which is the reply
+/// line's time on every platform and in every language ping speaks
+/// (`time=12.3 ms`, `Zeit=12ms`, `time<1ms`).
+fn parse_rtt(output: &str) -> Option<f32> {
+    output.match_indices(['=', '<']).find_map(|(i, sep)| {
+        let rest = output[i + 1..].trim_start();
+        let end = rest.find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ',')).unwrap_or(rest.len());
+        let (number, unit) = rest.split_at(end);
+        if !unit.trim_start().starts_with("ms") {
+            return None;
+        }
+        let value: f32 = number.replace(',', ".").parse().ok()?;
+        // "time<1ms": below the resolution, count it as half of it
+        Some(if sep == "<" { value / 2.0 } else { value })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rtt() {
+        let linux = "PING 1.1.1.1 (1.1.1.1) 56(84) bytes of data.\n\
+                     64 bytes from 1.1.1.1: icmp_seq=1 ttl=57 time=12.3 ms\n\n\
+                     rtt min/avg/max/mdev = 12.3/12.3/12.3/0.000 ms\n";
+        assert_eq!(parse_rtt(linux), Some(12.3));
+        let windows = "Reply from 1.1.1.1: bytes=32 time=14ms TTL=57\n\
+                       Minimum = 14ms, Maximum = 14ms, Average = 14ms\n";
+        assert_eq!(parse_rtt(windows), Some(14.0));
+        assert_eq!(parse_rtt("Reply from 192.168.1.1: bytes=32 time<1ms TTL=64"), Some(0.5));
+        assert_eq!(parse_rtt("Antwort von 1.1.1.1: Bytes=32 Zeit=9ms TTL=57"), Some(9.0));
+        assert_eq!(parse_rtt("1 packets transmitted, 0 received, 100% packet loss"), None);
+        assert_eq!(parse_rtt("Request timed out."), None);
+    }
+
+    #[test]
+    fn test_is_valid_host() {
+        for host in ["1.1.1.1", "::1", "2606:4700::1111", "example.com", "router.lan.", "my-nas"] {
+            assert!(is_valid_host(host), "{host}");
+        }
+        for host in ["", "-f", "--flood", "-i0.01 host", "host name", "a..b", "-bad.example", "bad-.example"] {
+            assert!(!is_valid_host(host), "{host}");
+        }
+        assert!(PingMonitor::start("-f").error().is_some());
+    }
+
+    #[test]
+    fn test_quality() {
+        assert_eq!(Quality::from_rtts([]), None);
+
+        let q = Quality::from_rtts([Some(20.0), Some(30.0), None, Some(20.0)]).unwrap();
+        assert!((q.latency_ms - 70.0 / 3.0).abs() < 0.01);
+        assert_eq!(q.jitter_ms, 10.0);
+        assert_eq!(q.loss_pct, 25.0);
+
+        let good = Quality::from_rtts([Some(15.0); 10]).unwrap();
+        assert!(good.mos > 4.3);
+        assert!(good.score_pct() > 90.0);
+        let laggy = Quality::from_rtts([Some(300.0), Some(400.0), Some(300.0)]).unwrap();
+        assert!(laggy.mos < good.mos);
+        let lost = Quality::from_rtts([None, None]).unwrap();
+        assert_eq!((lost.mos, lost.loss_pct), (1.0, 100.0));
+    }
+}
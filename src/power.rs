@@ -0,0 +1,342 @@
+//! Whole-system power draw.
+//!
+//! Linux reads package energy counters from RAPL (`/sys/class/powercap`,
+//! Intel and AMD) and turns the energy delta between ticks into watts. When
+//! RAPL is missing or unreadable (recent kernels restrict `energy_uj` to
+//! root), and on other platforms, the battery discharge rate is used instead:
+//! `power_supply` on Linux, `AppleSmartBattery` via `ioreg` on macOS and the
+//! WMI `BatteryStatus` class on Windows. On AC power without RAPL there is no
+//! reading.
+
+#[cfg(target_os = "linux")]
+use std::fs;
+#[cfg(target_os = "linux")]
+use std::path::{Path, PathBuf};
+#[cfg(target_os = "linux")]
+use std::time::Instant;
+
+/// Where a power figure came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PowerSource {
+    /// CPU package energy counters (excludes display, storage, etc.).
+    #[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+    Rapl,
+    /// Battery discharge rate (whole machine, only while unplugged).
+    Battery,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SystemPower {
+    pub watts: f32,
+    pub source: PowerSource,
+}
+
+/// Samples power once per collector tick. RAPL needs two readings to form a
+/// rate, so the first tick after start returns `None` on RAPL machines.
+#[derive(Default)]
+pub struct PowerMeter {
+    #[cfg(target_os = "linux")]
+    rapl: Vec<RaplZone>,
+    /// When the zones were last read.
+    #[cfg(target_os = "linux")]
+    last: Option<Instant>,
+}
+
+#[cfg(target_os = "linux")]
+struct RaplZone {
+    energy: PathBuf,
+    /// Counter wraps back to zero after this many microjoules; `None` when
+    /// the kernel doesn't say, so a wrap can't be corrected.
+    max_range_uj: Option<u64>,
+    /// Previous counter reading.
+    last_uj: Option<u64>,
+}
+
+impl PowerMeter {
+    pub fn new() -> Self {
+        #[cfg(target_os = "linux")]
+        {
+            Self { rapl: find_rapl_packages(Path::new("/sys/class/powercap")), last: None }
+        }
+        #[cfg(not(target_os = "linux"))]
+        {
+            Self::default()
+        }
+    }
+
+    pub fn read(&mut self) -> Option<SystemPower> {
+        #[cfg(target_os = "linux")]
+        {
+            if let Some(watts) = self.read_rapl() {
+                return Some(SystemPower { watts, source: PowerSource::Rapl });
+            }
+            if !self.rapl.is_empty() && self.last.is_some() {
+                // Waiting for the second RAPL sample
+                return None;
+            }
+            linux_battery_watts(Path::new("/sys/class/power_supply"))
+                .map(|watts| SystemPower { watts, source: PowerSource::Battery })
+        }
+        #[cfg(any(target_os = "macos", target_os = "windows"))]
+        {
+            battery::cached_watts().map(|watts| SystemPower { watts, source: PowerSource::Battery })
+        }
+        #[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+        {
+            None
+        }
+    }
+
+    /// Average package power since the previous call. Zones whose counter
+    /// cannot be read (permission denied) are dropped for good. Each
+    /// package's counter wraps on its own, so deltas are taken per package;
+    /// a sample where a wrap can't be corrected is skipped.
+    #[cfg(target_os = "linux")]
+    fn read_rapl(&mut self) -> Option<f32> {
+        let before = self.rapl.len();
+        let mut total_uj = Some(0u64);
+        self.rapl.retain_mut(|zone| match read_u64(&zone.energy) {
+            Some(uj) => {
+                let delta = zone.delta(uj);
+                total_uj = total_uj.zip(delta).map(|(sum, d)| sum + d);
+                true
+            }
+            None => false,
+        });
+        if self.rapl.len() < before {
+            if self.rapl.is_empty() {
+                eprintln!("[digger] RAPL energy counters are not readable; using battery discharge instead");
+            }
+            self.last = None;
+        }
+        if self.rapl.is_empty() {
+            return None;
+        }
+
+        let now = Instant::now();
+        let prev = self.last.replace(now)?;
+        let secs = now.duration_since(prev).as_secs_f64();
+        if secs <= 0.0 {
+            return None;
+        }
+        Some((total_uj? as f64 / 1e6 / secs) as f32)
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl RaplZone {
+    /// Microjoules used since the previous reading, which `uj` replaces;
+    /// `None` for the first reading and for a wrap that can't be corrected.
+    fn delta(&mut self, uj: u64) -> Option<u64> {
+        energy_delta(self.last_uj.replace(uj)?, uj, self.max_range_uj)
+    }
+}
+
+/// Microjoules consumed between two readings of one counter, allowing for
+/// one wrap-around when the counter's range is known.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn energy_delta(prev: u64, cur: u64, max_range: Option<u64>) -> Option<u64> {
+    if cur >= prev {
+        Some(cur - prev)
+    } else {
+        max_range.map(|max| max.saturating_sub(prev) + cur)
+    }
+}
+
+/// Top-level RAPL zones (`intel-rapl:N`, one per package). Subzones such as
+/// `intel-rapl:0:0` (cores) are already included in their package.
+#[cfg(target_os = "linux")]
+fn find_rapl_packages(root: &Path) -> Vec<RaplZone> {
+    let Ok(entries) = fs::read_dir(root) else { return Vec::new() };
+    let mut zones: Vec<(String, RaplZone)> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if !name.starts_with("intel-rapl:") || name.matches(':').count() != 1 {
+                return None;
+            }
+            let dir = entry.path();
+            let max_range_uj = read_u64(&dir.join("max_energy_range_uj"));
+            Some((name, RaplZone { energy: dir.join("energy_uj"), max_range_uj, last_uj: None }))
+        })
+        .collect();
+    zones.sort_by(|a, b| a.0.cmp(&b.0));
+    zones.into_iter().map(|(_, zone)| zone).collect()
+}
+
+#[cfg(target_os = "linux")]
+fn read_u64(path: &Path) -> Option<u64> {
+    fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+/// Sum of discharge rates over all discharging batteries, in watts.
+#[cfg(target_os = "linux")]
+fn linux_battery_watts(root: &Path) -> Option<f32> {
+    let entries = fs::read_dir(root).ok()?;
+    let mut total = None;
+    for entry in entries.flatten() {
+        let dir = entry.path();
+        let read = |file: &str| fs::read_to_string(dir.join(file)).ok();
+        if read("type").as_deref().map(str::trim) != Some("Battery") {
+            continue;
+        }
+        let num = |file: &str| read(file).and_then(|s| s.trim().parse::<i64>().ok());
+        let watts = battery_discharge_watts(
+            read("status").as_deref().unwrap_or("").trim(),
+            num("power_now"),
+            num("current_now"),
+            num("voltage_now"),
+        );
+        if let Some(w) = watts {
+            *total.get_or_insert(0.0) += w;
+        }
+    }
+    total
+}
+
+/// Discharge rate from `power_supply` attributes (µW, µA, µV). Some drivers
+/// only report current and voltage; some report negative values while
+/// discharging.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn battery_discharge_watts(status: &str, power_uw: Option<i64>, current_ua: Option<i64>, voltage_uv: Option<i64>) -> Option<f32> {
+    if status != "Discharging" {
+        return None;
+    }
+    let watts = match (power_uw, current_ua, voltage_uv) {
+        (Some(p), _, _) if p != 0 => p.unsigned_abs() as f64 / 1e6,
+        (_, Some(i), Some(v)) => i.unsigned_abs() as f64 * v.unsigned_abs() as f64 / 1e12,
+        _ => return None,
+    };
+    Some(watts as f32)
+}
+
+/// Discharge rate from `ioreg -rn AppleSmartBattery` output. `Amperage` is in
+/// mA and negative while discharging (ioreg prints it as an unsigned 64-bit
+/// value), `Voltage` in mV.
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn parse_smart_battery(output: &str) -> Option<f32> {
+    let field = |key: &str| -> Option<&str> {
+        output.lines().find_map(|line| line.trim().strip_prefix(&format!("\"{key}\" = ")))
+    };
+    let amperage = field("Amperage")?;
+    let ma = amperage.parse::<i64>().or_else(|_| amperage.parse::<u64>().map(|v| v as i64)).ok()?;
+    let mv: i64 = field("Voltage")?.parse().ok()?;
+    (ma < 0).then(|| (ma.unsigned_abs() as f64 * mv as f64 / 1e6) as f32)
+}
+
+/// Battery readings need a subprocess (macOS) or WMI (Windows), so they run
+/// on a background thread and the collector reads the cached value.
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+mod battery {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Mutex;
+    use std::time::Instant;
+
+    static CACHE: Mutex<Option<(Instant, Option<f32>)>> = Mutex::new(None);
+    static REFRESH_RUNNING: AtomicBool = AtomicBool::new(false);
+    const TTL_SECS: u64 = 2;
+
+    pub fn cached_watts() -> Option<f32> {
+        let (cached, fresh) = match CACHE.lock() {
+            Ok(guard) => match *guard {
+                Some((ts, watts)) => (watts, ts.elapsed().as_secs() < TTL_SECS),
+                None => (None, false),
+            },
+            Err(_) => (None, true),
+        };
+
+        if !fresh && !REFRESH_RUNNING.swap(true, Ordering::AcqRel) {
+            std::thread::spawn(|| {
+                let watts = read_blocking();
+                if let Ok(mut guard) = CACHE.lock() {
+                    *guard = Some((Instant::now(), watts));
+                }
+                REFRESH_RUNNING.store(false, Ordering::Release);
+            });
+        }
+
+        cached
+    }
+
+    #[cfg(target_os = "macos")]
+    fn read_blocking() -> Option<f32> {
+        let output = std::process::Command::new("ioreg")
+            .args(["-rn", "AppleSmartBattery"])
+            .output()
+            .ok()?;
+        super::parse_smart_battery(&String::from_utf8_lossy(&output.stdout))
+    }
+
+    /// `BatteryStatus.DischargeRate` is in mW and only meaningful while
+    /// `Discharging` is true.
+    #[cfg(target_os = "windows")]
+    fn read_blocking() -> Option<f32> {
+        use std::collections::HashMap;
+        use wmi::{COMLibrary, Variant, WMIConnection};
+
+        // See collect_wmi_temperatures_native for why security is skipped
+        let com_lib = COMLibrary::without_security().ok()?;
+        let con = WMIConnection::with_namespace_path("ROOT\\WMI", com_lib).ok()?;
+        let rows = con
+            .raw_query::<HashMap<String, Variant>>("SELECT DischargeRate, Discharging FROM BatteryStatus")
+            .ok()?;
+        let mut total = None;
+        for row in &rows {
+            if !matches!(row.get("Discharging"), Some(Variant::Bool(true))) {
+                continue;
+            }
+            let mw = match row.get("DischargeRate") {
+                Some(Variant::I4(n)) => n.unsigned_abs() as f32,
+                Some(Variant::UI4(n)) => *n as f32,
+                _ => continue,
+            };
+            *total.get_or_insert(0.0) += mw / 1000.0;
+        }
+        total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_energy_delta_wraps() {
+        assert_eq!(energy_delta(1_000, 5_000, Some(262_143_328_850)), Some(4_000));
+        assert_eq!(energy_delta(262_143_000_000, 1_000, Some(262_143_328_850)), Some(329_850));
+        // Without the range a wrap can't be measured
+        assert_eq!(energy_delta(1_000, 5_000, None), Some(4_000));
+        assert_eq!(energy_delta(262_143_000_000, 1_000, None), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_packages_wrap_independently() {
+        let zone = |max_range_uj| RaplZone { energy: PathBuf::new(), max_range_uj, last_uj: None };
+        let (mut a, mut b) = (zone(Some(1_000_000)), zone(Some(50_000_000)));
+        assert_eq!(a.delta(999_000), None);
+        assert_eq!(b.delta(20_000_000), None);
+        // Package 0 wraps while package 1 keeps counting
+        assert_eq!(a.delta(4_000), Some(5_000));
+        assert_eq!(b.delta(20_003_000), Some(3_000));
+        let mut unknown = zone(None);
+        unknown.delta(10);
+        assert_eq!(unknown.delta(5), None);
+    }
+
+    #[test]
+    fn test_battery_discharge_watts() {
+        assert_eq!(battery_discharge_watts("Discharging", Some(12_500_000), None, None), Some(12.5));
+        assert_eq!(battery_discharge_watts("Discharging", None, Some(-1_000_000), Some(12_000_000)), Some(12.0));
+        assert_eq!(battery_discharge_watts("Charging", Some(12_500_000), None, None), None);
+        assert_eq!(battery_discharge_watts("Discharging", None, None, Some(12_000_000)), None);
+    }
+
+    #[test]
+    fn test_parse_smart_battery() {
+        let out = "  \"Voltage\" = 12000\n  \"Amperage\" = 18446744073709550616\n  \"ExternalConnected\" = No\n";
+        assert_eq!(parse_smart_battery(out), Some(12.0));
+        let charging = "  \"Voltage\" = 12000\n  \"Amperage\" = 1500\n";
+        assert_eq!(parse_smart_battery(charging), None);
+    }
+}
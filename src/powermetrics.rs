@@ -0,0 +1,133 @@
+//! macOS `powermetrics` sampling for GPU power and SMC temperatures.
+//!
+//! `powermetrics` needs root, takes a few hundred milliseconds per sample
+//! and is the only stock tool that reports GPU power on Apple Silicon, so it
+//! runs on a background thread every `TTL_SECS` and callers read the cached
+//! result. The `smc` sampler (die temperatures) is missing on some machines;
+//! in that case only `gpu_power` is sampled.
+
+use crate::metrics::TempInfo;
+#[cfg(target_os = "macos")]
+use crate::metrics::SensorIssue;
+
+/// Values picked out of one `powermetrics` sample.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PowerReading {
+    pub gpu_watts: Option<f32>,
+    pub temps: Vec<TempInfo>,
+    /// Time since the sample was taken, for cached readings.
+    pub age: Option<std::time::Duration>,
+}
+
+/// Parse `powermetrics` text output. Power lines look like
+/// `GPU Power: 1234 mW`, temperature lines like `CPU die temperature: 52.31 C`.
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+pub fn parse(output: &str) -> PowerReading {
+    let mut reading = PowerReading::default();
+    for line in output.lines() {
+        let Some((key, value)) = line.split_once(':') else { continue };
+        let (key, value) = (key.trim(), value.trim());
+        if key == "GPU Power" {
+            if let Some(mw) = value.strip_suffix("mW").and_then(|v| v.trim().parse::<f32>().ok()) {
+                reading.gpu_watts = Some(mw / 1000.0);
+            }
+        } else if let Some(label) = key.strip_suffix(" temperature") {
+            if let Some(c) = value.strip_suffix('C').and_then(|v| v.trim().parse::<f32>().ok()) {
+                reading.temps.push(TempInfo { label: label.to_string(), temp_c: c, id: String::new() });
+            }
+        }
+    }
+    reading
+}
+
+#[cfg(target_os = "macos")]
+mod sampler {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Mutex;
+    use std::time::Instant;
+
+    use super::{parse, PowerReading};
+
+    static CACHE: Mutex<Option<(Instant, PowerReading)>> = Mutex::new(None);
+    static REFRESH_RUNNING: AtomicBool = AtomicBool::new(false);
+    const TTL_SECS: u64 = 5;
+
+    /// Latest cached reading, kicking off a refresh when stale.
+    pub fn latest() -> PowerReading {
+        let (cached, fresh) = match CACHE.lock() {
+            Ok(guard) => match *guard {
+                Some((ts, ref data)) => (PowerReading { age: Some(ts.elapsed()), ..data.clone() }, ts.elapsed().as_secs() < TTL_SECS),
+                None => (PowerReading::default(), false),
+            },
+            Err(_) => (PowerReading::default(), true),
+        };
+
+        if !fresh && !REFRESH_RUNNING.swap(true, Ordering::AcqRel) {
+            std::thread::spawn(|| {
+                let reading = sample(&["gpu_power,smc", "gpu_power"]);
+                if let Ok(mut guard) = CACHE.lock() {
+                    *guard = Some((Instant::now(), reading));
+                }
+                REFRESH_RUNNING.store(false, Ordering::Release);
+            });
+        }
+
+        cached
+    }
+
+    /// Run one sample with the first sampler set `powermetrics` accepts.
+    fn sample(sampler_sets: &[&str]) -> PowerReading {
+        for samplers in sampler_sets {
+            let output = std::process::Command::new("powermetrics")
+                .args(["-n", "1", "-i", "500", "--samplers", samplers])
+                .output();
+            match output {
+                Ok(o) if o.status.success() => return parse(&String::from_utf8_lossy(&o.stdout)),
+                Ok(_) => continue,
+                Err(e) => {
+                    eprintln!("[digger] Failed to run powermetrics: {e}");
+                    break;
+                }
+            }
+        }
+        PowerReading::default()
+    }
+}
+
+/// Cached `powermetrics` reading, or PermissionDenied when not running as
+/// root (the tool refuses to start otherwise).
+#[cfg(target_os = "macos")]
+pub fn latest() -> Result<PowerReading, SensorIssue> {
+    // SAFETY: geteuid has no preconditions and cannot fail.
+    if unsafe { libc::geteuid() } != 0 {
+        return Err(SensorIssue::PermissionDenied);
+    }
+    Ok(sampler::latest())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let out = "\
+**** GPU usage ****
+
+GPU HW active frequency: 389 MHz
+GPU HW active residency:   3.47% (389 MHz: 3.5%)
+GPU Power: 1520 mW
+
+**** SMC sensors ****
+
+CPU die temperature: 52.31 C
+GPU die temperature: 48.00 C
+Fan: 1200 rpm
+";
+        let reading = parse(out);
+        assert_eq!(reading.gpu_watts, Some(1.52));
+        let temps: Vec<(&str, f32)> = reading.temps.iter().map(|t| (t.label.as_str(), t.temp_c)).collect();
+        assert_eq!(temps, vec![("CPU die", 52.31), ("GPU die", 48.0)]);
+        assert_eq!(parse("no samples"), PowerReading::default());
+    }
+}
@@ -1,16 +1,52 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 
+use crate::anomaly::Sensitivity;
+use crate::forecast::Horizon;
 use crate::i18n::Language;
-use crate::theme::{AccentColor, ThemeVariant};
+use crate::locale::{MemUnit, RateUnits, TempUnit};
+use crate::metrics::RecordedMetrics;
+use crate::notify::NotifyLevel;
+use crate::theme::{AccentColor, ColorVision, RowDensity, ThemeVariant};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Preferences {
     pub theme: ThemeVariant,
     pub accent: AccentColor,
+    /// RGB of the `Custom` accent, kept while a preset is selected.
+    #[serde(default = "default_custom_accent")]
+    pub custom_accent: [u8; 3],
     pub refresh_interval_secs: u64,
-    pub temp_celsius: bool,
+    #[serde(default)]
+    pub temp_unit: TempUnit,
+    /// Unit flags of files from before `temp_unit`; read once by
+    /// `sanitize` and never written.
+    #[serde(default, skip_serializing)]
+    pub temp_celsius: Option<bool>,
+    #[serde(default, skip_serializing)]
+    pub temp_kelvin: Option<bool>,
+    /// Calibration offsets in °C added to sensor readings, by sensor id
+    /// (`TempInfo::id`), within ±`SENSOR_OFFSET_MAX_C`.
+    #[serde(default)]
+    pub sensor_offsets: BTreeMap<String, f32>,
+    /// Show network throughput in bits per second.
+    #[serde(default)]
+    pub rate_bits: bool,
+    /// Use decimal (SI) prefixes for throughput instead of binary ones.
+    #[serde(default)]
+    pub rate_si: bool,
+    /// Show and export times in UTC instead of local time.
+    #[serde(default)]
+    pub utc_times: bool,
+    /// Unit of the process list memory column.
+    #[serde(default)]
+    pub process_mem_unit: MemUnit,
+    /// Row spacing of the process and event tables.
+    #[serde(default)]
+    pub row_density: RowDensity,
     /// Maximum number of processes displayed in the process list.
     #[serde(default = "default_process_limit")]
     pub process_limit: usize,
@@ -26,53 +62,247 @@ pub struct Preferences {
     /// Memory usage threshold (%) for alert highlighting.
     #[serde(default = "default_mem_alert_threshold")]
     pub mem_alert_threshold: f32,
+    /// GPU VRAM usage threshold (%) for alerts.
+    #[serde(default = "default_vram_alert_threshold")]
+    pub vram_alert_threshold: f32,
+    /// GPU temperature threshold (°C) for alerts.
+    #[serde(default = "default_gpu_temp_alert_threshold")]
+    pub gpu_temp_alert_threshold: f32,
+    /// Sensor temperature threshold (°C) for alerts, whatever the display unit.
+    #[serde(default = "default_temp_alert_threshold")]
+    pub temp_alert_threshold: f32,
     /// Whether to use the OpenDyslexic font.
     #[serde(default)]
     pub use_dyslexic_font: bool,
+    /// Status color substitutes for color-vision deficiencies.
+    #[serde(default)]
+    pub color_vision: ColorVision,
     /// Whether the process list is grouped (Apps/Background/System).
     #[serde(default)]
     pub process_grouped: bool,
-    /// Process sort column: "pid", "name", "cpu", "memory".
+    /// Process sort column: "pid", "name", "cpu", "memory", "disk_io", "threads".
     #[serde(default = "default_process_sort")]
     pub process_sort: String,
     /// Whether process sort is ascending.
     #[serde(default)]
     pub process_sort_asc: bool,
+    /// Filter text of the process list.
+    #[serde(default)]
+    pub process_filter: String,
+    /// Vertical scroll offset of the process list, in pixels.
+    #[serde(default)]
+    pub process_scroll_y: f32,
+    /// Collapsed sections of the grouped process list: "apps", "background", "system".
+    #[serde(default)]
+    pub collapsed_process_groups: Vec<String>,
+    /// Names of processes kept at the top of the process table.
+    #[serde(default)]
+    pub pinned_processes: Vec<String>,
+    /// Process table column widths in pixels (PID, PPID, name, CPU, memory,
+    /// memory %, state, threads, command line, disk I/O); empty means the defaults.
+    #[serde(default)]
+    pub process_column_widths: Vec<f32>,
+    /// Smooth live charts with an exponential moving average.
+    #[serde(default)]
+    pub chart_smoothing: bool,
+    /// Weight of the newest sample when smoothing (0.05–1, lower is smoother).
+    #[serde(default = "default_chart_smoothing_alpha")]
+    pub chart_smoothing_alpha: f32,
+    /// Show process CPU as a share of one core (htop's Irix mode) rather
+    /// than of the whole machine.
+    #[serde(default)]
+    pub process_cpu_per_core: bool,
+    /// Search every process, not just the ones kept by `process_limit`.
+    #[serde(default)]
+    pub search_all_processes: bool,
+    /// When a kill is denied, offer to retry it through pkexec, the macOS
+    /// password dialog or UAC.
+    #[serde(default = "default_offer_elevation")]
+    pub offer_elevation: bool,
+    /// Disable killing processes, for shared or demo machines.
+    #[serde(default)]
+    pub read_only: bool,
+    /// Larger controls, tap-sized table rows and pull-to-refresh on the
+    /// process list, for touch screens.
+    #[serde(default)]
+    pub touch_mode: bool,
+    /// Write a `.schema.json` sidecar next to CSV exports.
+    #[serde(default)]
+    pub export_schema_sidecar: bool,
+    /// Optional metric groups that are collected and written to history.
+    #[serde(default)]
+    pub recorded_metrics: RecordedMetrics,
+    /// Write every snapshot only while CPU is above
+    /// `adaptive_recording_threshold`, otherwise one every 10 seconds.
+    #[serde(default)]
+    pub adaptive_recording: bool,
+    /// CPU usage (%) at which adaptive recording keeps every snapshot.
+    #[serde(default = "default_adaptive_recording_threshold")]
+    pub adaptive_recording_threshold: f32,
+    /// Show the full command line column in the process table.
+    #[serde(default)]
+    pub show_command_column: bool,
     /// Auto-detect system dark/light theme.
     #[serde(default)]
     pub auto_theme: bool,
     /// Interface language.
     #[serde(default)]
     pub language: Language,
+    /// Send a desktop notification when a network interface goes up or down.
+    #[serde(default)]
+    pub notify_net_changes: bool,
+    /// Lowest event severity sent as a desktop notification.
+    #[serde(default)]
+    pub notify_level: NotifyLevel,
+    /// How far above its rolling baseline a metric must go to log a spike.
+    #[serde(default)]
+    pub anomaly_sensitivity: Sensitivity,
+    /// Warn when a drive or swap is projected to fill up within this time.
+    #[serde(default)]
+    pub forecast_horizon: Horizon,
+    /// Merge OS log errors (journald) into the event log.
+    #[serde(default = "default_system_log_events")]
+    pub system_log_events: bool,
+    /// Log file followed in the Logs tab (empty = tab hidden).
+    #[serde(default)]
+    pub log_tail_path: String,
+    /// Keyword filter applied to the followed log file.
+    #[serde(default)]
+    pub log_tail_filter: String,
+    /// Host pinged for the connection quality widget (empty = off).
+    #[serde(default)]
+    pub ping_host: String,
+    /// Process names that raise an event when they start or exit.
+    #[serde(default)]
+    pub watched_processes: Vec<String>,
+    /// Log a warning when a new TCP port starts listening.
+    #[serde(default)]
+    pub port_watch: bool,
+    /// Also send a desktop notification for new listening ports.
+    #[serde(default)]
+    pub port_watch_notify: bool,
+    /// Ports and process names that never raise the new port warning.
+    #[serde(default)]
+    pub port_allowlist: Vec<String>,
+    /// Check GitHub for a new release once a week.
+    #[serde(default)]
+    pub check_updates: bool,
+    /// When the last update check finished (Unix seconds).
+    #[serde(default)]
+    pub last_update_check: f64,
+    /// Window size in logical pixels, as last left when not maximized.
+    #[serde(default = "default_window_width")]
+    pub window_width: f32,
+    #[serde(default = "default_window_height")]
+    pub window_height: f32,
+    /// Top-left corner of the window; `None` lets the platform place it
+    /// (always the case on Wayland, which doesn't report positions).
+    #[serde(default)]
+    pub window_position: Option<(f32, f32)>,
+    #[serde(default)]
+    pub window_maximized: bool,
+    /// Start fullscreen as a wall display (see also the `--kiosk` flag).
+    #[serde(default)]
+    pub kiosk_mode: bool,
+    /// Rotate the Overview panels outside kiosk mode too.
+    #[serde(default)]
+    pub overview_cycle: bool,
+    /// Seconds each Overview panel is shown when the panels rotate.
+    #[serde(default = "default_overview_cycle_secs")]
+    pub overview_cycle_secs: u64,
 }
 
 fn default_process_limit() -> usize { 200 }
 const MAX_PROCESS_LIMIT: usize = 5000;
+/// Largest sensor calibration offset either way, °C.
+pub const SENSOR_OFFSET_MAX_C: f32 = 20.0;
 const REFRESH_OPTIONS: &[u64] = &[1, 2, 5];
 fn default_live_buffer_size() -> usize { 120 }
 fn default_retention_hours() -> u64 { 24 }
 fn default_cpu_alert_threshold() -> f32 { 90.0 }
 fn default_mem_alert_threshold() -> f32 { 90.0 }
+fn default_vram_alert_threshold() -> f32 { 90.0 }
+fn default_gpu_temp_alert_threshold() -> f32 { 85.0 }
+fn default_temp_alert_threshold() -> f32 { 85.0 }
+fn default_adaptive_recording_threshold() -> f32 { 80.0 }
+fn default_chart_smoothing_alpha() -> f32 { 0.3 }
 fn default_process_sort() -> String { "cpu".into() }
+fn default_system_log_events() -> bool { true }
+fn default_offer_elevation() -> bool { true }
+fn default_window_width() -> f32 { 950.0 }
+fn default_window_height() -> f32 { 680.0 }
+fn default_custom_accent() -> [u8; 3] { [0x54, 0xa1, 0xf2] }
+fn default_overview_cycle_secs() -> u64 { 15 }
 
 impl Default for Preferences {
     fn default() -> Self {
         Self {
             theme: ThemeVariant::CatppuccinMocha,
             accent: AccentColor::Blue,
+            custom_accent: default_custom_accent(),
             refresh_interval_secs: 1,
-            temp_celsius: true,
+            temp_unit: TempUnit::Celsius,
+            temp_celsius: None,
+            temp_kelvin: None,
+            sensor_offsets: BTreeMap::new(),
+            rate_bits: false,
+            rate_si: false,
+            utc_times: false,
+            process_mem_unit: MemUnit::Auto,
+            row_density: RowDensity::Comfortable,
             process_limit: default_process_limit(),
             live_buffer_size: default_live_buffer_size(),
             retention_hours: default_retention_hours(),
             cpu_alert_threshold: default_cpu_alert_threshold(),
             mem_alert_threshold: default_mem_alert_threshold(),
+            vram_alert_threshold: default_vram_alert_threshold(),
+            gpu_temp_alert_threshold: default_gpu_temp_alert_threshold(),
+            temp_alert_threshold: default_temp_alert_threshold(),
             use_dyslexic_font: false,
+            color_vision: ColorVision::Normal,
             process_grouped: false,
             process_sort: default_process_sort(),
             process_sort_asc: false,
+            process_filter: String::new(),
+            process_scroll_y: 0.0,
+            collapsed_process_groups: Vec::new(),
+            pinned_processes: Vec::new(),
+            process_column_widths: Vec::new(),
+            chart_smoothing: false,
+            chart_smoothing_alpha: default_chart_smoothing_alpha(),
+            process_cpu_per_core: false,
+            search_all_processes: false,
+            offer_elevation: default_offer_elevation(),
+            read_only: false,
+            touch_mode: false,
+            export_schema_sidecar: false,
+            recorded_metrics: RecordedMetrics::default(),
+            adaptive_recording: false,
+            adaptive_recording_threshold: default_adaptive_recording_threshold(),
+            show_command_column: false,
             auto_theme: false,
             language: Language::default(),
+            notify_net_changes: false,
+            notify_level: NotifyLevel::Warning,
+            anomaly_sensitivity: Sensitivity::Medium,
+            forecast_horizon: Horizon::ThreeDays,
+            system_log_events: default_system_log_events(),
+            log_tail_path: String::new(),
+            log_tail_filter: String::new(),
+            ping_host: String::new(),
+            watched_processes: Vec::new(),
+            port_watch: false,
+            port_watch_notify: false,
+            port_allowlist: Vec::new(),
+            check_updates: false,
+            last_update_check: 0.0,
+            window_width: default_window_width(),
+            window_height: default_window_height(),
+            window_position: None,
+            window_maximized: false,
+            kiosk_mode: false,
+            overview_cycle: false,
+            overview_cycle_secs: default_overview_cycle_secs(),
         }
     }
 }
@@ -97,6 +327,8 @@ impl Preferences {
             Ok(contents) => {
                 let mut prefs: Self = serde_json::from_str(&contents).unwrap_or_else(|e| {
                     eprintln!("[digger] Invalid preferences file, using defaults: {e}");
+                    // Keep the broken file for inspection; the next save replaces it
+                    let _ = fs::copy(&path, path.with_extension("json.bak"));
                     Self::default()
                 });
                 prefs.sanitize();
@@ -106,13 +338,40 @@ impl Preferences {
         }
     }
 
+    pub fn rate_units(&self) -> RateUnits {
+        RateUnits { bits: self.rate_bits, si: self.rate_si }
+    }
+
     /// Clamp all numeric fields to valid ranges.
     fn sanitize(&mut self) {
+        // Kelvin took precedence over the Celsius flag
+        match (self.temp_celsius.take(), self.temp_kelvin.take()) {
+            (_, Some(true)) => self.temp_unit = TempUnit::Kelvin,
+            (Some(true), _) => self.temp_unit = TempUnit::Celsius,
+            (Some(false), _) => self.temp_unit = TempUnit::Fahrenheit,
+            (None, _) => {}
+        }
         self.process_limit = self.process_limit.clamp(10, MAX_PROCESS_LIMIT);
         self.live_buffer_size = self.live_buffer_size.clamp(30, 1000);
         self.retention_hours = self.retention_hours.clamp(1, 168); // 1h to 7 days
         self.cpu_alert_threshold = self.cpu_alert_threshold.clamp(10.0, 100.0);
         self.mem_alert_threshold = self.mem_alert_threshold.clamp(10.0, 100.0);
+        self.vram_alert_threshold = self.vram_alert_threshold.clamp(10.0, 100.0);
+        self.gpu_temp_alert_threshold = self.gpu_temp_alert_threshold.clamp(50.0, 110.0);
+        self.temp_alert_threshold = self.temp_alert_threshold.clamp(40.0, 120.0);
+        self.adaptive_recording_threshold = self.adaptive_recording_threshold.clamp(10.0, 100.0);
+        self.chart_smoothing_alpha = self.chart_smoothing_alpha.clamp(0.05, 1.0);
+        self.window_width = self.window_width.clamp(400.0, 16384.0);
+        self.window_height = self.window_height.clamp(300.0, 16384.0);
+        self.overview_cycle_secs = self.overview_cycle_secs.clamp(5, 600);
+        // Touch rows come with touch mode and are no longer a setting
+        if self.row_density == RowDensity::Touch {
+            self.row_density = RowDensity::Comfortable;
+        }
+        self.sensor_offsets.retain(|_, o| o.is_finite() && *o != 0.0);
+        for offset in self.sensor_offsets.values_mut() {
+            *offset = offset.clamp(-SENSOR_OFFSET_MAX_C, SENSOR_OFFSET_MAX_C);
+        }
         if !REFRESH_OPTIONS.contains(&self.refresh_interval_secs) {
             self.refresh_interval_secs = 1;
         }
@@ -135,15 +394,8 @@ impl Preferences {
         let path = Self::config_path();
         match serde_json::to_string_pretty(self) {
             Ok(json) => {
-                if let Err(e) = fs::write(&path, &json) {
+                if let Err(e) = write_atomic(&path, json.as_bytes()) {
                     eprintln!("[digger] Failed to save preferences: {e}");
-                    return;
-                }
-                // Set restrictive permissions on the file (Unix only)
-                #[cfg(unix)]
-                {
-                    use std::os::unix::fs::PermissionsExt;
-                    let _ = fs::set_permissions(&path, fs::Permissions::from_mode(0o600));
                 }
             }
             Err(e) => {
@@ -153,6 +405,26 @@ impl Preferences {
     }
 }
 
+/// Write `contents` to a temporary file next to `path`, flush it to disk and
+/// rename it over `path`, so a crash mid-write leaves the old file intact.
+fn write_atomic(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    let tmp = path.with_extension("json.tmp");
+    {
+        let mut file = fs::File::create(&tmp)?;
+        // Set restrictive permissions before any content is written (Unix only)
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            file.set_permissions(fs::Permissions::from_mode(0o600))?;
+        }
+        file.write_all(contents)?;
+        file.sync_all()?;
+    }
+    fs::rename(&tmp, path).inspect_err(|_| {
+        let _ = fs::remove_file(&tmp);
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -164,7 +436,7 @@ mod tests {
         assert_eq!(prefs.live_buffer_size, 120);
         assert_eq!(prefs.retention_hours, 24);
         assert!((prefs.cpu_alert_threshold - 90.0).abs() < 0.01);
-        assert!(prefs.temp_celsius);
+        assert_eq!(prefs.temp_unit, TempUnit::Celsius);
         assert!(!prefs.use_dyslexic_font);
     }
 
@@ -181,12 +453,47 @@ mod tests {
     fn test_backwards_compat_missing_fields() {
         // Simulate an old config without new fields
         let old_json = r#"{"theme":"CatppuccinMocha","accent":"Blue","refresh_interval_secs":2,"temp_celsius":false}"#;
-        let prefs: Preferences = serde_json::from_str(old_json).unwrap();
+        let mut prefs: Preferences = serde_json::from_str(old_json).unwrap();
+        prefs.sanitize();
         assert_eq!(prefs.refresh_interval_secs, 2);
-        assert!(!prefs.temp_celsius);
+        assert_eq!(prefs.temp_unit, TempUnit::Fahrenheit);
         // New fields should use defaults
         assert_eq!(prefs.process_limit, 200);
         assert_eq!(prefs.live_buffer_size, 120);
         assert!(!prefs.use_dyslexic_font);
+        assert_eq!((prefs.window_width, prefs.window_height), (950.0, 680.0));
+        assert_eq!(prefs.window_position, None);
+    }
+
+    #[test]
+    fn test_temp_unit_migration() {
+        let old_json = r#"{"theme":"CatppuccinMocha","accent":"Blue","refresh_interval_secs":1,"temp_celsius":true,"temp_kelvin":true}"#;
+        let mut prefs: Preferences = serde_json::from_str(old_json).unwrap();
+        prefs.sanitize();
+        assert_eq!(prefs.temp_unit, TempUnit::Kelvin);
+
+        let json = serde_json::to_string(&prefs).unwrap();
+        assert!(json.contains(r#""temp_unit":"Kelvin""#));
+        assert!(!json.contains("temp_celsius") && !json.contains("temp_kelvin"));
+    }
+
+    #[test]
+    fn test_sensor_offsets_clamped() {
+        let json = r#"{"theme":"CatppuccinMocha","accent":"Blue","refresh_interval_secs":1,"sensor_offsets":{"Tctl":-45.0,"Composite":3.5,"Composite #2":0.0}}"#;
+        let mut prefs: Preferences = serde_json::from_str(json).unwrap();
+        prefs.sanitize();
+        assert_eq!(prefs.sensor_offsets, BTreeMap::from([("Composite".to_string(), 3.5), ("Tctl".to_string(), -SENSOR_OFFSET_MAX_C)]));
+    }
+
+    #[test]
+    fn test_write_atomic_replaces_file() {
+        let dir = std::env::temp_dir().join(format!("digger_prefs_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("preferences.json");
+        fs::write(&path, "old").unwrap();
+        write_atomic(&path, b"{\"new\":true}").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "{\"new\":true}");
+        assert!(!path.with_extension("json.tmp").exists());
+        let _ = fs::remove_dir_all(&dir);
     }
 }
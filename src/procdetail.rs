@@ -0,0 +1,146 @@
+//! Extra details for the selected process: executable, working directory,
+//! where its standard output and error go and its sockets.
+//!
+//! Read on demand for one PID rather than for every process each tick. On
+//! Linux everything comes from `/proc/<pid>`; elsewhere sysinfo provides
+//! the executable and working directory and the output targets are unknown.
+//! Sockets come from a cache refreshed in the background, since listing
+//! them runs `lsof` or `netstat` outside Linux.
+//! Processes of other users usually can't be inspected without root.
+
+use std::path::PathBuf;
+
+use crate::sockets::SocketInfo;
+
+/// What a standard stream of a process is connected to.
+#[derive(Clone, Debug, PartialEq)]
+pub enum OutputTarget {
+    /// A terminal device such as `/dev/pts/3`.
+    Terminal(PathBuf),
+    /// The systemd journal (a socket matching `JOURNAL_STREAM`).
+    Journal,
+    File(PathBuf),
+    Pipe,
+    Socket,
+    /// `/dev/null`.
+    Discarded,
+    /// Anything else, as the kernel names it (`anon_inode:[eventfd]`).
+    Other(String),
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProcessDetails {
+    pub exe: Option<PathBuf>,
+    pub cwd: Option<PathBuf>,
+    pub stdout: Option<OutputTarget>,
+    pub stderr: Option<OutputTarget>,
+    /// Listening ports and connections, listening ones first.
+    pub sockets: Vec<SocketInfo>,
+}
+
+#[cfg(target_os = "linux")]
+pub fn read(pid: u32) -> ProcessDetails {
+    let dir = PathBuf::from(format!("/proc/{pid}"));
+    let journal_inode = std::fs::read(dir.join("environ")).ok().and_then(|env| journal_stream_inode(&env));
+    let target = |fd: u32| {
+        let link = std::fs::read_link(dir.join("fd").join(fd.to_string())).ok()?;
+        Some(classify(&link.to_string_lossy(), journal_inode))
+    };
+    ProcessDetails {
+        exe: std::fs::read_link(dir.join("exe")).ok(),
+        cwd: std::fs::read_link(dir.join("cwd")).ok(),
+        stdout: target(1),
+        stderr: target(2),
+        sockets: crate::sockets::for_pid_cached(pid),
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn read(pid: u32) -> ProcessDetails {
+    use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind};
+
+    let pid = Pid::from_u32(pid);
+    let mut sys = System::new();
+    sys.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        true,
+        ProcessRefreshKind::new().with_exe(UpdateKind::Always).with_cwd(UpdateKind::Always),
+    );
+    let Some(process) = sys.process(pid) else { return ProcessDetails::default() };
+    ProcessDetails {
+        exe: process.exe().map(PathBuf::from),
+        cwd: process.cwd().map(PathBuf::from),
+        stdout: None,
+        stderr: None,
+        sockets: crate::sockets::for_pid_cached(pid.as_u32()),
+    }
+}
+
+/// Inode of the journal socket from a `JOURNAL_STREAM=<dev>:<inode>` entry
+/// in a NUL-separated environment block. systemd sets it for services
+/// whose output goes to the journal.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn journal_stream_inode(environ: &[u8]) -> Option<u64> {
+    environ
+        .split(|&b| b == 0)
+        .find_map(|var| var.strip_prefix(b"JOURNAL_STREAM="))
+        .and_then(|value| std::str::from_utf8(value).ok()?.split_once(':')?.1.parse().ok())
+}
+
+/// Classify the target of a `/proc/<pid>/fd/<n>` link.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn classify(link: &str, journal_inode: Option<u64>) -> OutputTarget {
+    if let Some(inode) = link.strip_prefix("socket:[").and_then(|s| s.strip_suffix(']')) {
+        return match (inode.parse::<u64>().ok(), journal_inode) {
+            (Some(inode), Some(journal)) if inode == journal => OutputTarget::Journal,
+            _ => OutputTarget::Socket,
+        };
+    }
+    if link.starts_with("pipe:[") {
+        return OutputTarget::Pipe;
+    }
+    if link == "/dev/null" {
+        return OutputTarget::Discarded;
+    }
+    if link.starts_with("/dev/pts/") || link.starts_with("/dev/tty") || link == "/dev/console" {
+        return OutputTarget::Terminal(PathBuf::from(link));
+    }
+    if link.starts_with('/') {
+        return OutputTarget::File(PathBuf::from(link));
+    }
+    OutputTarget::Other(link.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify() {
+        assert_eq!(classify("/dev/pts/3", None), OutputTarget::Terminal("/dev/pts/3".into()));
+        assert_eq!(classify("/dev/tty1", None), OutputTarget::Terminal("/dev/tty1".into()));
+        assert_eq!(classify("/dev/null", None), OutputTarget::Discarded);
+        assert_eq!(classify("pipe:[81234]", None), OutputTarget::Pipe);
+        assert_eq!(classify("socket:[5512]", Some(5512)), OutputTarget::Journal);
+        assert_eq!(classify("socket:[5512]", Some(77)), OutputTarget::Socket);
+        assert_eq!(classify("socket:[5512]", None), OutputTarget::Socket);
+        assert_eq!(classify("/var/log/app.log (deleted)", None), OutputTarget::File("/var/log/app.log (deleted)".into()));
+        assert_eq!(classify("anon_inode:[eventfd]", None), OutputTarget::Other("anon_inode:[eventfd]".into()));
+    }
+
+    #[test]
+    fn test_journal_stream_inode() {
+        assert_eq!(journal_stream_inode(b"HOME=/root\0JOURNAL_STREAM=8:5512\0LANG=C\0"), Some(5512));
+        assert_eq!(journal_stream_inode(b"HOME=/root\0"), None);
+        assert_eq!(journal_stream_inode(b"JOURNAL_STREAM=garbage\0"), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_read_self() {
+        let details = read(std::process::id());
+        assert_eq!(details.exe, std::env::current_exe().ok());
+        assert_eq!(details.cwd, std::env::current_dir().ok());
+        assert!(details.stdout.is_some());
+    }
+}
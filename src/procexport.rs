@@ -0,0 +1,146 @@
+//! Process table export.
+//!
+//! Writes the rows shown in the Processes tab, in the order shown, to CSV or
+//! JSON for attaching to bug reports. Columns follow the table: the command
+//! line is only included while its column is visible, and the group is only
+//! included while the table is grouped. Memory and disk I/O are written in
+//! bytes so the file does not depend on the display unit.
+
+use std::fmt::Write;
+
+use crate::metrics::ProcessInfo;
+
+/// One exported row: the process and, when grouped, its group key.
+pub struct ExportRow<'a> {
+    pub group: Option<&'static str>,
+    pub process: &'a ProcessInfo,
+}
+
+/// Columns to include beyond the fixed ones.
+#[derive(Clone, Copy, Debug)]
+pub struct ExportColumns {
+    pub group: bool,
+    pub command: bool,
+}
+
+fn mem_percent(bytes: u64, total: u64) -> f64 {
+    if total == 0 { 0.0 } else { bytes as f64 / total as f64 * 100.0 }
+}
+
+/// Quote a CSV field when it contains a delimiter, quote or line break.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+pub fn to_csv(rows: &[ExportRow], columns: ExportColumns, mem_total: u64) -> String {
+    let mut out = String::new();
+    if columns.group {
+        out.push_str("group,");
+    }
+    out.push_str("pid,ppid,name,");
+    if columns.command {
+        out.push_str("command,");
+    }
+    out.push_str("cpu_percent,mem_bytes,mem_percent,disk_bytes,status,threads\n");
+
+    for row in rows {
+        let p = row.process;
+        if columns.group {
+            let _ = write!(out, "{},", row.group.unwrap_or_default());
+        }
+        let ppid = p.parent_pid.map(|pid| pid.to_string()).unwrap_or_default();
+        let _ = write!(out, "{},{ppid},{},", p.pid, csv_field(&p.name));
+        if columns.command {
+            let _ = write!(out, "{},", csv_field(&p.cmd.join(" ")));
+        }
+        let _ = writeln!(
+            out,
+            "{:.2},{},{:.2},{},{},{}",
+            p.cpu_usage,
+            p.memory_bytes,
+            mem_percent(p.memory_bytes, mem_total),
+            p.disk_bytes,
+            p.status,
+            p.thread_count,
+        );
+    }
+    out
+}
+
+pub fn to_json(rows: &[ExportRow], columns: ExportColumns, mem_total: u64) -> String {
+    let values: Vec<serde_json::Value> = rows
+        .iter()
+        .map(|row| {
+            let p = row.process;
+            let mut obj = serde_json::Map::new();
+            if columns.group {
+                obj.insert("group".into(), row.group.into());
+            }
+            obj.insert("pid".into(), p.pid.into());
+            obj.insert("ppid".into(), p.parent_pid.into());
+            obj.insert("name".into(), p.name.as_ref().into());
+            if columns.command {
+                obj.insert("command".into(), p.cmd.to_vec().into());
+            }
+            // Round like the CSV so values don't carry f32 noise
+            obj.insert("cpu".into(), ((p.cpu_usage as f64 * 100.0).round() / 100.0).into());
+            obj.insert("mem_bytes".into(), p.memory_bytes.into());
+            obj.insert("mem_percent".into(), ((mem_percent(p.memory_bytes, mem_total) * 100.0).round() / 100.0).into());
+            obj.insert("disk_bytes".into(), p.disk_bytes.into());
+            obj.insert("status".into(), p.status.to_string().into());
+            obj.insert("threads".into(), p.thread_count.into());
+            serde_json::Value::Object(obj)
+        })
+        .collect();
+    serde_json::to_string_pretty(&values).unwrap_or_else(|_| String::from("[]"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_to_csv() {
+        let a = ProcessInfo {
+            parent_pid: Some(1),
+            cmd: Arc::new(["/bin/bash".into(), "-c".into(), "echo \"hi\", there".into()]),
+            cpu_usage: 12.5,
+            memory_bytes: 256 * 1024 * 1024,
+            thread_count: 4,
+            ..ProcessInfo::for_test(42, "bash")
+        };
+        let rows = [ExportRow { group: Some("background"), process: &a }];
+        let mem_total = 1024 * 1024 * 1024;
+
+        let plain = to_csv(&rows, ExportColumns { group: false, command: false }, mem_total);
+        assert_eq!(plain, "pid,ppid,name,cpu_percent,mem_bytes,mem_percent,disk_bytes,status,threads\n42,1,bash,12.50,268435456,25.00,0,S,4\n");
+
+        let full = to_csv(&rows, ExportColumns { group: true, command: true }, mem_total);
+        assert_eq!(
+            full.lines().nth(1),
+            Some("background,42,1,bash,\"/bin/bash -c echo \"\"hi\"\", there\",12.50,268435456,25.00,0,S,4"),
+        );
+    }
+
+    #[test]
+    fn test_to_json() {
+        let a = ProcessInfo {
+            cmd: Arc::new(["/bin/bash".into(), "-l".into()]),
+            cpu_usage: 12.5,
+            ..ProcessInfo::for_test(42, "bash")
+        };
+        let rows = [ExportRow { group: None, process: &a }];
+        let json = to_json(&rows, ExportColumns { group: false, command: true }, 0);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value[0]["pid"], 42);
+        assert_eq!(value[0]["command"], serde_json::json!(["/bin/bash", "-l"]));
+        assert_eq!(value[0]["cpu"], 12.5);
+        assert_eq!(value[0]["mem_percent"], 0.0);
+        assert!(value[0].get("group").is_none());
+    }
+}
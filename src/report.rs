@@ -0,0 +1,315 @@
+//! Weekly / monthly usage reports.
+//!
+//! Builds a self-contained HTML file from the history database: summary
+//! statistics, CPU / memory / network charts (rendered offscreen and embedded
+//! as PNG data URIs), the busiest processes by CPU at generation time and the alerts
+//! raised during the current session.
+
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use iced::Color;
+
+use crate::chart::{time_labels, ChartColors, LineChart};
+use crate::history::{History, HistoryPoint, HistorySummary};
+use crate::theme::Palette;
+use crate::locale;
+use crate::ui::{format_bytes, scale_rates};
+
+/// Points per embedded chart.
+const CHART_POINTS: usize = 600;
+const CHART_SIZE: (u32, u32) = (960, 260);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ReportPeriod {
+    Week,
+    Month,
+}
+
+impl ReportPeriod {
+    pub fn secs(self) -> f64 {
+        match self {
+            ReportPeriod::Week => 7.0 * 86400.0,
+            ReportPeriod::Month => 30.0 * 86400.0,
+        }
+    }
+
+    fn title(self) -> &'static str {
+        match self {
+            ReportPeriod::Week => "Weekly usage report",
+            ReportPeriod::Month => "Monthly usage report",
+        }
+    }
+
+    fn slug(self) -> &'static str {
+        match self {
+            ReportPeriod::Week => "weekly",
+            ReportPeriod::Month => "monthly",
+        }
+    }
+}
+
+/// A process listed in the report (taken from the latest snapshot).
+pub struct ReportProcess {
+    pub name: String,
+    pub cpu_usage: f32,
+    pub memory_bytes: u64,
+}
+
+/// A warning or critical event from the event log.
+pub struct ReportAlert {
+    pub timestamp: String,
+    pub message: String,
+    pub critical: bool,
+}
+
+pub struct ReportInput {
+    pub period: ReportPeriod,
+    pub hostname: String,
+    pub palette: Palette,
+    pub processes: Vec<ReportProcess>,
+    pub alerts: Vec<ReportAlert>,
+}
+
+/// Generate the report on its own thread with its own database
+/// connection; the SQL aggregation and chart rendering take too long for
+/// the UI thread.
+pub async fn generate_in_background(input: ReportInput) -> Result<PathBuf, String> {
+    let (tx, rx) = iced::futures::channel::oneshot::channel();
+    std::thread::Builder::new()
+        .name("digger-report".into())
+        .spawn(move || {
+            let _ = tx.send(generate(&History::open_checked_elsewhere(), &input));
+        })
+        .map_err(|e| e.to_string())?;
+    rx.await.unwrap_or_else(|_| Err("report thread exited".into()))
+}
+
+/// Generate the report and write it to the downloads (or home) directory.
+pub fn generate(history: &History, input: &ReportInput) -> Result<PathBuf, String> {
+    let now = chrono::Utc::now().timestamp_millis() as f64 / 1000.0;
+    let from = now - input.period.secs();
+    let summary = history.summary(from, now);
+    let points = history.load_range_downsampled(from, now, CHART_POINTS);
+    let html = render_html(input, summary.as_ref(), &points);
+
+    let dir = dirs::download_dir().or_else(dirs::home_dir).ok_or("no output directory")?;
+    let stamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+    let path = dir.join(format!("digger_report_{}_{stamp}.html", input.period.slug()));
+    std::fs::write(&path, html).map_err(|e| e.to_string())?;
+    Ok(path)
+}
+
+fn render_html(input: &ReportInput, summary: Option<&HistorySummary>, points: &[HistoryPoint]) -> String {
+    let p = &input.palette;
+    let mut out = String::new();
+    let _ = write!(
+        out,
+        "<!DOCTYPE html>\n<html><head><meta charset=\"utf-8\"><title>Digger — {title}</title>\n\
+         <style>body{{font-family:sans-serif;background:{bg};color:{fg};max-width:1000px;margin:24px auto;padding:0 16px}}\
+         h1,h2{{color:{accent}}}table{{border-collapse:collapse;width:100%;margin-bottom:16px}}\
+         th,td{{text-align:left;padding:4px 8px;border-bottom:1px solid {border}}}\
+         .muted{{color:{label}}}.crit{{color:{red}}}.warn{{color:{yellow}}}img{{width:100%}}</style>\n\
+         </head><body>\n<h1>{title}</h1>\n",
+        title = input.period.title(),
+        bg = hex(p.bg),
+        fg = hex(p.text),
+        accent = hex(p.accent),
+        border = hex(p.border),
+        label = hex(p.label),
+        red = hex(p.red),
+        yellow = hex(p.yellow),
+    );
+    let _ = writeln!(
+        out,
+        "<p class=\"muted\">{} · generated {}</p>",
+        escape_html(&input.hostname),
+        locale::now().format(if locale::utc() { "%Y-%m-%d %H:%M UTC" } else { "%Y-%m-%d %H:%M" }),
+    );
+
+    out.push_str("<h2>Summary</h2>\n");
+    match summary {
+        Some(s) => {
+            let _ = writeln!(
+                out,
+                "<p class=\"muted\">Data from {} to {} ({} samples)</p>",
+                locale::date_time(s.first_ts),
+                locale::date_time(s.last_ts),
+                s.samples,
+            );
+            out.push_str("<table><tr><th>Metric</th><th>Average</th><th>Peak</th><th>Total</th></tr>\n");
+            let _ = writeln!(
+                out,
+                "<tr><td>CPU</td><td>{}</td><td>{}</td><td></td></tr>",
+                locale::percent(s.cpu_avg, 1),
+                locale::percent(s.cpu_max, 1),
+            );
+            let _ = writeln!(
+                out,
+                "<tr><td>Memory</td><td>{}</td><td>{}</td><td></td></tr>",
+                locale::percent(s.mem_avg, 1),
+                locale::percent(s.mem_max, 1),
+            );
+            let _ = writeln!(
+                out,
+                "<tr><td>Download</td><td></td><td>{}</td><td>{}</td></tr>",
+                locale::rate(s.net_rx_peak),
+                format_bytes(s.net_rx_total),
+            );
+            let _ = writeln!(
+                out,
+                "<tr><td>Upload</td><td></td><td>{}</td><td>{}</td></tr>",
+                locale::rate(s.net_tx_peak),
+                format_bytes(s.net_tx_total),
+            );
+            out.push_str("</table>\n");
+        }
+        None => out.push_str("<p class=\"muted\">No history recorded for this period.</p>\n"),
+    }
+
+    if points.len() >= 2 {
+        out.push_str("<h2>Charts</h2>\n");
+        let colors = ChartColors { bg: p.panel_bg, border: p.border, grid: p.grid, label: p.label, text: p.text };
+        let cpu: Vec<f32> = points.iter().map(|h| h.cpu).collect();
+        let mem: Vec<f32> = points
+            .iter()
+            .map(|h| if h.mem_total > 0 { h.mem_used as f32 / h.mem_total as f32 * 100.0 } else { 0.0 })
+            .collect();
+        let (rx, tx, net_unit, net_max) = scale_rates(
+            points.iter().map(|h| h.net_rx as f32).collect(),
+            points.iter().map(|h| h.net_tx as f32).collect(),
+        );
+
+        let timestamps: Vec<f64> = points.iter().map(|h| h.timestamp).collect();
+        let charts = [
+            chart("CPU", vec![("CPU".into(), p.accent, cpu)], 100.0, "%", colors),
+            chart("Memory", vec![("RAM".into(), p.green, mem)], 100.0, "%", colors),
+            chart("Network", vec![("rx".into(), p.green, rx), ("tx".into(), p.red, tx)], net_max, net_unit, colors),
+        ];
+        for mut c in charts {
+            c.x_labels = time_labels(&timestamps);
+            match c.render_png(CHART_SIZE.0, CHART_SIZE.1) {
+                Ok(png) => {
+                    let _ = writeln!(out, "<img alt=\"{}\" src=\"data:image/png;base64,{}\">", c.title, base64(&png));
+                }
+                Err(e) => eprintln!("[digger] Failed to render report chart: {e}"),
+            }
+        }
+    }
+
+    out.push_str("<h2>Top processes by CPU</h2>\n");
+    if input.processes.is_empty() {
+        out.push_str("<p class=\"muted\">No process data.</p>\n");
+    } else {
+        out.push_str("<p class=\"muted\">At the time the report was generated.</p>\n");
+        out.push_str("<table><tr><th>Process</th><th>CPU</th><th>Memory</th></tr>\n");
+        for proc in &input.processes {
+            let _ = writeln!(
+                out,
+                "<tr><td>{}</td><td>{}</td><td>{}</td></tr>",
+                escape_html(&proc.name),
+                locale::percent(proc.cpu_usage, 1),
+                format_bytes(proc.memory_bytes),
+            );
+        }
+        out.push_str("</table>\n");
+    }
+
+    out.push_str("<h2>Alerts</h2>\n");
+    if input.alerts.is_empty() {
+        out.push_str("<p class=\"muted\">No alerts this session.</p>\n");
+    } else {
+        out.push_str("<table><tr><th>Time</th><th>Event</th></tr>\n");
+        for alert in &input.alerts {
+            let class = if alert.critical { "crit" } else { "warn" };
+            let _ = writeln!(
+                out,
+                "<tr><td>{}</td><td class=\"{class}\">{}</td></tr>",
+                escape_html(&alert.timestamp),
+                escape_html(&alert.message),
+            );
+        }
+        out.push_str("</table>\n");
+    }
+
+    out.push_str("</body></html>\n");
+    out
+}
+
+fn chart(title: &str, series: Vec<(String, Color, Vec<f32>)>, y_max: f32, unit: &str, colors: ChartColors) -> LineChart {
+    LineChart {
+        series,
+        y_min: 0.0,
+        y_max,
+        title: title.into(),
+        filled: true,
+        stacked: false,
+        unit: unit.into(),
+        colors,
+        show_avg: true,
+        x_labels: Vec::new(),
+        bands: Vec::new(),
+        markers: Vec::new(),
+        right_axis: None,
+    }
+}
+
+fn hex(c: Color) -> String {
+    let [r, g, b, _] = c.into_rgba8();
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+/// Standard base64 (RFC 4648) with padding.
+fn base64(bytes: &[u8]) -> String {
+    const TABLE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = (u32::from(chunk[0]) << 16)
+            | (u32::from(*chunk.get(1).unwrap_or(&0)) << 8)
+            | u32::from(*chunk.get(2).unwrap_or(&0));
+        out.push(TABLE[(n >> 18) as usize & 63] as char);
+        out.push(TABLE[(n >> 12) as usize & 63] as char);
+        out.push(if chunk.len() > 1 { TABLE[(n >> 6) as usize & 63] as char } else { '=' });
+        out.push(if chunk.len() > 2 { TABLE[n as usize & 63] as char } else { '=' });
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::{build_palette, AccentColor, ColorVision, ThemeVariant};
+
+    #[test]
+    fn test_base64() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn test_render_html() {
+        let palette = build_palette(ThemeVariant::CatppuccinMocha, AccentColor::Blue, [0, 0, 0], ColorVision::Normal);
+        let input = ReportInput {
+            period: ReportPeriod::Week,
+            hostname: "box".into(),
+            palette,
+            processes: vec![ReportProcess { name: "<build>".into(), cpu_usage: 12.5, memory_bytes: 2048 }],
+            alerts: vec![ReportAlert { timestamp: "10:00".into(), message: "CPU high".into(), critical: true }],
+        };
+        let point = |ts: f64, cpu: f32| HistoryPoint { timestamp: ts, cpu, mem_used: 1, mem_total: 2, net_rx: 10, net_tx: 5, power: None, range: None };
+        let summary = HistorySummary { samples: 2, cpu_avg: 30.0, cpu_max: 40.0, ..Default::default() };
+        let html = render_html(&input, Some(&summary), &[point(0.0, 20.0), point(1.0, 40.0)]);
+
+        assert!(html.contains("Weekly usage report"));
+        assert!(html.contains("<td>30.0%</td><td>40.0%</td>"));
+        assert_eq!(html.matches("data:image/png;base64,").count(), 3);
+        assert!(html.contains("&lt;build&gt;"));
+        assert!(html.contains("class=\"crit\">CPU high"));
+    }
+}
@@ -24,14 +24,60 @@ impl<T> RingBuffer<T> {
         self.buf.push_back(item);
     }
 
+    /// Change the capacity. Shrinking drops the oldest elements, so the most
+    /// recent ones are kept either way.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        let excess = self.buf.len().saturating_sub(capacity);
+        self.buf.drain(..excess);
+        self.buf.shrink_to(capacity);
+        self.capacity = capacity;
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = &T> {
         self.buf.iter()
     }
 
-    #[cfg(test)]
+    /// The newest `n` elements (all of them when there are fewer), oldest
+    /// first.
+    pub fn last(&self, n: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        self.buf.range(self.len().saturating_sub(n)..)
+    }
+
+    /// Min, max and mean of `value` over the newest `n` elements, in one
+    /// pass and without allocating. `None` when the buffer is empty.
+    pub fn stats(&self, n: usize, value: impl Fn(&T) -> f32) -> Option<WindowStats> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut window = self.last(n);
+        let count = window.len();
+        let first = value(window.next()?);
+        let (min, max, sum) = window.fold((first, first, first), |(min, max, sum), item| {
+            let v = value(item);
+            (min.min(v), max.max(v), sum + v)
+        });
+        Some(WindowStats { min, max, avg: sum / count as f32 })
+    }
+
     pub fn len(&self) -> usize {
         self.buf.len()
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+/// Aggregate of a window of a `RingBuffer`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WindowStats {
+    pub min: f32,
+    pub max: f32,
+    pub avg: f32,
 }
 
 #[cfg(test)]
@@ -51,10 +97,40 @@ mod tests {
         assert_eq!(items, vec![2, 3, 4]);
     }
 
+    #[test]
+    fn test_ringbuffer_set_capacity() {
+        let mut rb = RingBuffer::new(4);
+        for i in 1..=4 {
+            rb.push(i);
+        }
+        rb.set_capacity(2);
+        assert_eq!(rb.iter().copied().collect::<Vec<_>>(), vec![3, 4]);
+        rb.set_capacity(3);
+        rb.push(5);
+        rb.push(6);
+        assert_eq!(rb.iter().copied().collect::<Vec<_>>(), vec![4, 5, 6]);
+    }
+
     #[test]
     fn test_ringbuffer_empty() {
         let rb: RingBuffer<i32> = RingBuffer::new(5);
         assert_eq!(rb.len(), 0);
+        assert!(rb.is_empty());
+        assert_eq!(rb.capacity(), 5);
         assert_eq!(rb.iter().count(), 0);
+        assert_eq!(rb.last(3).count(), 0);
+        assert_eq!(rb.stats(3, |&v| v as f32), None);
+    }
+
+    #[test]
+    fn test_ringbuffer_window() {
+        let mut rb = RingBuffer::new(5);
+        for v in [4.0, 1.0, 9.0, 2.0, 6.0, 3.0] {
+            rb.push(v);
+        }
+        assert_eq!(rb.last(2).copied().collect::<Vec<_>>(), vec![6.0, 3.0]);
+        assert_eq!(rb.last(10).count(), 5);
+        assert_eq!(rb.stats(3, |&v| v), Some(WindowStats { min: 2.0, max: 6.0, avg: 11.0 / 3.0 }));
+        assert_eq!(rb.stats(100, |&v| v), Some(WindowStats { min: 1.0, max: 9.0, avg: 4.2 }));
     }
 }
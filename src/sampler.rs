@@ -0,0 +1,296 @@
+//! Runs the `Collector` on a thread of its own.
+//!
+//! A read that never returns (a disk stuck in D state, a wedged sysfs or
+//! procfs file) used to freeze the whole window. The UI now asks for a
+//! snapshot on each tick and picks it up on a later one; when a request has
+//! been outstanding for `HUNG_AFTER`, or the thread died, the old thread is
+//! abandoned and a fresh collector is started with the same settings.
+//!
+//! A read that stays stuck blocks every replacement too, so each restart
+//! without a snapshot in between doubles the wait, and no more threads are
+//! started while `MAX_THREADS` are still alive.
+
+use std::collections::{BTreeMap, HashSet};
+use std::sync::mpsc::{self, Receiver, Sender, SyncSender, TryRecvError};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::metrics::{Collector, RecordedMetrics, Snapshot};
+
+/// A collection outstanding this long is considered hung. Building a new
+/// collector re-reads the whole process table, so this stays well above
+/// the slowest healthy tick.
+const HUNG_AFTER: Duration = Duration::from_secs(20);
+
+/// Longest wait between restarts while no snapshot arrives.
+const MAX_BACKOFF: Duration = Duration::from_secs(320);
+
+/// Collector threads alive at once, abandoned ones included. Each holds a
+/// full `System`, so a read that never returns must not pile them up.
+const MAX_THREADS: usize = 4;
+
+enum Command {
+    Collect,
+    ThreadPids(Vec<u32>),
+    FaultPid(Option<u32>),
+    ProcessFilter(String),
+    Pinned(HashSet<String>),
+    Recorded(RecordedMetrics),
+    SensorOffsets(BTreeMap<String, f32>),
+}
+
+/// Everything needed to rebuild an equivalent collector after a restart.
+#[derive(Clone)]
+struct Settings {
+    process_limit: usize,
+    thread_pids: Vec<u32>,
+    fault_pid: Option<u32>,
+    process_filter: String,
+    pinned: HashSet<String>,
+    recorded: RecordedMetrics,
+    sensor_offsets: BTreeMap<String, f32>,
+}
+
+impl Settings {
+    fn build(&self) -> Collector {
+        let mut collector = Collector::with_process_limit(self.process_limit);
+        collector.set_recorded(self.recorded);
+        collector.set_sensor_offsets(self.sensor_offsets.clone());
+        collector.set_thread_pids(self.thread_pids.clone());
+        collector.set_fault_pid(self.fault_pid);
+        collector.set_process_filter(&self.process_filter);
+        collector.set_pinned(self.pinned.clone());
+        collector
+    }
+}
+
+/// Outcome of `Sampler::poll`.
+pub enum Poll {
+    Ready(Arc<Snapshot>),
+    /// The last request is still being collected.
+    Pending,
+    /// The collector was replaced because a collection took this long.
+    /// Only the first restart until the next snapshot is reported.
+    Hung(Duration),
+    /// The collector was replaced because its thread exited (a panic).
+    /// Only the first restart until the next snapshot is reported.
+    Died,
+}
+
+pub struct Sampler {
+    tx: Sender<Command>,
+    rx: Receiver<Arc<Snapshot>>,
+    settings: Settings,
+    /// When the outstanding collection was requested; `None` while idle.
+    requested: Option<Instant>,
+    /// A snapshot taken off the channel by `has_result`, not yet delivered.
+    ready: Option<Arc<Snapshot>>,
+    /// Restarts since the last snapshot, for the backoff.
+    restarts: u32,
+    last_restart: Option<Instant>,
+    /// Collector threads that haven't exited yet.
+    threads: Arc<AtomicUsize>,
+}
+
+impl Sampler {
+    /// Build the collector and take the first snapshot on the calling thread,
+    /// so the UI never starts empty, then move the collector to its thread.
+    pub fn start(process_limit: usize, recorded: RecordedMetrics, sensor_offsets: BTreeMap<String, f32>) -> (Self, Snapshot) {
+        let settings = Settings { process_limit, thread_pids: Vec::new(), fault_pid: None, process_filter: String::new(), pinned: HashSet::new(), recorded, sensor_offsets };
+        let mut collector = settings.build();
+        let first = collector.collect();
+        let threads = Arc::new(AtomicUsize::new(0));
+        let (tx, rx) = spawn(Some(collector), settings.clone(), &threads);
+        let sampler = Self { tx, rx, settings, requested: None, ready: None, restarts: 0, last_restart: None, threads };
+        (sampler, first)
+    }
+
+    /// The snapshot requested earlier if it is done, after asking for the
+    /// next one. Restarts the collector when the request is overdue.
+    pub fn poll(&mut self) -> Poll {
+        let received = match self.ready.take() {
+            Some(snap) => Ok(snap),
+            None => self.rx.try_recv(),
+        };
+        match received {
+            Ok(snap) => {
+                self.requested = None;
+                self.restarts = 0;
+                self.last_restart = None;
+                self.request();
+                return Poll::Ready(snap);
+            }
+            Err(TryRecvError::Disconnected) => {
+                if self.last_restart.is_some_and(|at| at.elapsed() < self.backoff()) {
+                    return Poll::Pending;
+                }
+                eprintln!("[digger] Collector thread exited, restarting it");
+                let first = self.restarts == 0;
+                self.restart();
+                return if first { Poll::Died } else { Poll::Pending };
+            }
+            Err(TryRecvError::Empty) => {}
+        }
+        match self.requested {
+            Some(since) if since.elapsed() >= self.backoff() => {
+                // Wait for a stuck thread to come back before adding another
+                if self.threads.load(Ordering::Acquire) >= MAX_THREADS {
+                    return Poll::Pending;
+                }
+                let stalled = since.elapsed();
+                eprintln!("[digger] Collection hung for {}s, restarting the collector", stalled.as_secs());
+                let first = self.restarts == 0;
+                self.restart();
+                if first { Poll::Hung(stalled) } else { Poll::Pending }
+            }
+            Some(_) => Poll::Pending,
+            None => {
+                self.request();
+                Poll::Pending
+            }
+        }
+    }
+
+    /// Whether the next `poll` will deliver a snapshot.
+    pub fn has_result(&mut self) -> bool {
+        if self.ready.is_none() {
+            self.ready = self.rx.try_recv().ok();
+        }
+        self.ready.is_some()
+    }
+
+    /// `HUNG_AFTER`, doubled for every restart since the last snapshot.
+    fn backoff(&self) -> Duration {
+        HUNG_AFTER.saturating_mul(1 << self.restarts.min(8)).min(MAX_BACKOFF)
+    }
+
+    pub fn set_thread_pids(&mut self, pids: Vec<u32>) {
+        self.settings.thread_pids.clone_from(&pids);
+        let _ = self.tx.send(Command::ThreadPids(pids));
+    }
+
+    pub fn set_fault_pid(&mut self, pid: Option<u32>) {
+        if self.settings.fault_pid != pid {
+            self.settings.fault_pid = pid;
+            let _ = self.tx.send(Command::FaultPid(pid));
+        }
+    }
+
+    pub fn set_process_filter(&mut self, filter: &str) {
+        if self.settings.process_filter != filter {
+            self.settings.process_filter = filter.to_string();
+            let _ = self.tx.send(Command::ProcessFilter(filter.to_string()));
+        }
+    }
+
+    pub fn set_pinned(&mut self, names: HashSet<String>) {
+        self.settings.pinned.clone_from(&names);
+        let _ = self.tx.send(Command::Pinned(names));
+    }
+
+    pub fn set_recorded(&mut self, recorded: RecordedMetrics) {
+        self.settings.recorded = recorded;
+        let _ = self.tx.send(Command::Recorded(recorded));
+    }
+
+    pub fn set_sensor_offsets(&mut self, offsets: BTreeMap<String, f32>) {
+        self.settings.sensor_offsets.clone_from(&offsets);
+        let _ = self.tx.send(Command::SensorOffsets(offsets));
+    }
+
+    fn request(&mut self) {
+        // A failed send means the thread is gone; the next poll notices
+        let _ = self.tx.send(Command::Collect);
+        self.requested = Some(Instant::now());
+    }
+
+    /// Leave the old thread to finish (or not) on its own: it exits as soon
+    /// as it finds its channels closed.
+    fn restart(&mut self) {
+        (self.tx, self.rx) = spawn(None, self.settings.clone(), &self.threads);
+        self.requested = None;
+        self.restarts = self.restarts.saturating_add(1);
+        self.last_restart = Some(Instant::now());
+    }
+}
+
+/// Decrements the live thread count when a collector thread ends, by
+/// returning or by panicking.
+struct ThreadGuard(Arc<AtomicUsize>);
+
+impl Drop for ThreadGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// Start a collector thread, building the collector there when none is
+/// given so a restart doesn't stall the UI.
+fn spawn(collector: Option<Collector>, settings: Settings, threads: &Arc<AtomicUsize>) -> (Sender<Command>, Receiver<Arc<Snapshot>>) {
+    let (tx, commands) = mpsc::channel();
+    // One request is outstanding at a time, so sends never block
+    let (results, rx) = mpsc::sync_channel(1);
+    threads.fetch_add(1, Ordering::AcqRel);
+    let guard = ThreadGuard(Arc::clone(threads));
+    let spawned = std::thread::Builder::new()
+        .name("digger-collector".into())
+        .spawn(move || {
+            let _guard = guard;
+            run(collector.unwrap_or_else(|| settings.build()), commands, results);
+        });
+    if let Err(e) = spawned {
+        eprintln!("[digger] Failed to start collector thread: {e}");
+    }
+    (tx, rx)
+}
+
+fn run(mut collector: Collector, commands: Receiver<Command>, results: SyncSender<Arc<Snapshot>>) {
+    for command in commands {
+        match command {
+            Command::Collect => {
+                if results.send(Arc::new(collector.collect())).is_err() {
+                    return;
+                }
+            }
+            Command::ThreadPids(pids) => collector.set_thread_pids(pids),
+            Command::FaultPid(pid) => collector.set_fault_pid(pid),
+            Command::ProcessFilter(filter) => collector.set_process_filter(&filter),
+            Command::Pinned(names) => collector.set_pinned(names),
+            Command::Recorded(recorded) => collector.set_recorded(recorded),
+            Command::SensorOffsets(offsets) => collector.set_sensor_offsets(offsets),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_poll_delivers_requested_snapshot() {
+        let (mut sampler, first) = Sampler::start(10, RecordedMetrics::default(), BTreeMap::new());
+        assert!(matches!(sampler.poll(), Poll::Pending));
+        let deadline = Instant::now() + Duration::from_secs(10);
+        let snap = loop {
+            match sampler.poll() {
+                Poll::Ready(snap) => break snap,
+                Poll::Pending if Instant::now() < deadline => std::thread::sleep(Duration::from_millis(10)),
+                _ => panic!("no snapshot from the collector thread"),
+            }
+        };
+        assert!(snap.timestamp >= first.timestamp);
+        // The next one was requested along with the delivery
+        assert!(sampler.requested.is_some());
+    }
+
+    #[test]
+    fn test_backoff_doubles_and_caps() {
+        let (mut sampler, _) = Sampler::start(10, RecordedMetrics::default(), BTreeMap::new());
+        assert_eq!(sampler.backoff(), HUNG_AFTER);
+        sampler.restarts = 2;
+        assert_eq!(sampler.backoff(), HUNG_AFTER * 4);
+        sampler.restarts = 30;
+        assert_eq!(sampler.backoff(), MAX_BACKOFF);
+    }
+}
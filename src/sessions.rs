@@ -0,0 +1,245 @@
+//! Logged-in users for the Sessions panel.
+//!
+//! Linux and macOS read the utmp login records, as `who` does. Systems
+//! that no longer keep utmp (recent systemd without utmp support) fall
+//! back to `loginctl list-sessions`. Windows enumerates Remote Desktop
+//! Services sessions, which include the console. The `loginctl` fallback
+//! spawns a process, so the list is read on a background thread and
+//! `list_cached` only returns its last result.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Logged-in users are re-read this often.
+const SESSIONS_TTL: Duration = Duration::from_secs(5);
+
+/// Result of the last background read.
+static SESSIONS: Mutex<Option<(Instant, Vec<Session>)>> = Mutex::new(None);
+
+static SESSIONS_RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// One login session.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Session {
+    /// Stable for the life of the session, to tell new logins apart.
+    pub id: String,
+    pub user: String,
+    /// Terminal, seat or station: `pts/0`, `tty2`, `console`, `RDP-Tcp#3`.
+    pub line: String,
+    /// Remote host or client name for SSH and RDP logins.
+    pub host: Option<String>,
+    /// Login time as epoch seconds, when known.
+    pub login_time: Option<i64>,
+}
+
+/// Sessions in `new` that aren't in `old`.
+pub fn new_logins<'a>(old: &[Session], new: &'a [Session]) -> Vec<&'a Session> {
+    new.iter().filter(|s| !old.iter().any(|o| o.id == s.id)).collect()
+}
+
+/// Current sessions as of the last background read, `None` until the
+/// first one is done; a stale result starts a new read.
+pub fn list_cached() -> Option<Vec<Session>> {
+    let (cached, fresh) = match SESSIONS.lock() {
+        Ok(guard) => match &*guard {
+            Some((ts, sessions)) => (Some(sessions.clone()), ts.elapsed() < SESSIONS_TTL),
+            None => (None, false),
+        },
+        Err(_) => (None, true),
+    };
+
+    if !fresh && !SESSIONS_RUNNING.swap(true, Ordering::AcqRel) {
+        std::thread::spawn(|| {
+            let sessions = list();
+            if let Ok(mut guard) = SESSIONS.lock() {
+                *guard = Some((Instant::now(), sessions));
+            }
+            SESSIONS_RUNNING.store(false, Ordering::Release);
+        });
+    }
+
+    cached
+}
+
+/// Current sessions, oldest login first.
+fn list() -> Vec<Session> {
+    let mut sessions = read_sessions();
+    sessions.sort_by(|a, b| a.login_time.cmp(&b.login_time).then_with(|| a.line.cmp(&b.line)));
+    sessions
+}
+
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn read_sessions() -> Vec<Session> {
+    let sessions = read_utmp();
+    #[cfg(target_os = "linux")]
+    if sessions.is_empty() {
+        return read_loginctl();
+    }
+    sessions
+}
+
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn read_utmp() -> Vec<Session> {
+    let field = |chars: &[libc::c_char]| -> String {
+        let bytes: Vec<u8> = chars.iter().take_while(|&&c| c != 0).map(|&c| c as u8).collect();
+        String::from_utf8_lossy(&bytes).into_owned()
+    };
+    let mut sessions = Vec::new();
+    // SAFETY: the utmp iterator is only used by one background read at a
+    // time, and each record is copied out before the next call
+    unsafe {
+        libc::setutxent();
+        loop {
+            let entry = libc::getutxent();
+            if entry.is_null() {
+                break;
+            }
+            let entry = &*entry;
+            if entry.ut_type != libc::USER_PROCESS {
+                continue;
+            }
+            let line = field(&entry.ut_line);
+            let login = entry.ut_tv.tv_sec as i64;
+            let host = field(&entry.ut_host);
+            sessions.push(Session {
+                id: format!("{line}@{login}"),
+                user: field(&entry.ut_user),
+                line,
+                host: (!host.is_empty()).then_some(host),
+                login_time: (login > 0).then_some(login),
+            });
+        }
+        libc::endutxent();
+    }
+    sessions
+}
+
+#[cfg(target_os = "linux")]
+fn read_loginctl() -> Vec<Session> {
+    let output = std::process::Command::new("loginctl")
+        .args(["list-sessions", "--no-legend", "--no-pager", "-o", "json"])
+        .output();
+    match output {
+        Ok(o) if o.status.success() => parse_loginctl(&String::from_utf8_lossy(&o.stdout)),
+        _ => Vec::new(),
+    }
+}
+
+/// Sessions from `loginctl list-sessions`: JSON where supported, the
+/// `SESSION UID USER SEAT TTY` table otherwise.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_loginctl(output: &str) -> Vec<Session> {
+    if let Ok(serde_json::Value::Array(rows)) = serde_json::from_str(output) {
+        return rows
+            .iter()
+            .filter_map(|row| {
+                let text = |key: &str| row.get(key).and_then(|v| v.as_str()).filter(|s| !s.is_empty());
+                let id = text("session")?.to_string();
+                let line = text("tty").or_else(|| text("seat")).unwrap_or("-").to_string();
+                Some(Session { id, user: text("user")?.to_string(), line, host: None, login_time: None })
+            })
+            .collect();
+    }
+    output
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let id = fields.first()?.to_string();
+            let user = fields.get(2)?.to_string();
+            let tty = fields.get(4).or(fields.get(3)).map_or("-", |s| *s).to_string();
+            Some(Session { id, user, line: tty, host: None, login_time: None })
+        })
+        .collect()
+}
+
+#[cfg(target_os = "windows")]
+fn read_sessions() -> Vec<Session> {
+    use windows_sys::Win32::System::RemoteDesktop::{
+        WTSActive, WTSClientName, WTSDisconnected, WTSEnumerateSessionsW, WTSFreeMemory,
+        WTSQuerySessionInformationW, WTSSessionInfo, WTSINFOW, WTS_CURRENT_SERVER_HANDLE, WTS_SESSION_INFOW,
+    };
+
+    let wide = |chars: &[u16]| String::from_utf16_lossy(&chars[..chars.iter().position(|&c| c == 0).unwrap_or(chars.len())]);
+    let mut sessions = Vec::new();
+    // SAFETY: buffers returned by WTS calls are read within their reported
+    // size and released with WTSFreeMemory
+    unsafe {
+        let mut list: *mut WTS_SESSION_INFOW = std::ptr::null_mut();
+        let mut count = 0u32;
+        if WTSEnumerateSessionsW(WTS_CURRENT_SERVER_HANDLE, 0, 1, &mut list, &mut count) == 0 {
+            return sessions;
+        }
+        for entry in std::slice::from_raw_parts(list, count as usize) {
+            if entry.State != WTSActive && entry.State != WTSDisconnected {
+                continue;
+            }
+            let mut buf: *mut u16 = std::ptr::null_mut();
+            let mut len = 0u32;
+            if WTSQuerySessionInformationW(WTS_CURRENT_SERVER_HANDLE, entry.SessionId, WTSSessionInfo, &mut buf, &mut len) == 0 {
+                continue;
+            }
+            let info = *(buf as *const WTSINFOW);
+            WTSFreeMemory(buf.cast());
+            let user = wide(&info.UserName);
+            if user.is_empty() {
+                continue;
+            }
+            let mut host = None;
+            if WTSQuerySessionInformationW(WTS_CURRENT_SERVER_HANDLE, entry.SessionId, WTSClientName, &mut buf, &mut len) != 0 {
+                let chars = std::slice::from_raw_parts(buf, len as usize / 2);
+                host = Some(wide(chars)).filter(|h| !h.is_empty());
+                WTSFreeMemory(buf.cast());
+            }
+            // FILETIME: 100 ns ticks since 1601
+            let login_time = (info.LogonTime > 0).then(|| info.LogonTime / 10_000_000 - 11_644_473_600);
+            sessions.push(Session {
+                id: format!("{}@{}", entry.SessionId, info.LogonTime),
+                user,
+                line: wide(&info.WinStationName),
+                host,
+                login_time,
+            });
+        }
+        WTSFreeMemory(list.cast());
+    }
+    sessions
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+fn read_sessions() -> Vec<Session> {
+    Vec::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(id: &str) -> Session {
+        Session { id: id.into(), user: "alice".into(), line: "pts/0".into(), host: None, login_time: None }
+    }
+
+    #[test]
+    fn test_new_logins() {
+        let old = vec![session("pts/0@100")];
+        let new = vec![session("pts/0@100"), session("pts/1@200")];
+        assert_eq!(new_logins(&old, &new), vec![&new[1]]);
+        assert!(new_logins(&new, &old).is_empty());
+    }
+
+    #[test]
+    fn test_parse_loginctl() {
+        let json = r#"[{"session":"2","uid":1000,"user":"alice","seat":"seat0","tty":"tty2","state":"active"},
+                       {"session":"c4","uid":1001,"user":"bob","seat":null,"tty":"pts/1"}]"#;
+        let sessions = parse_loginctl(json);
+        assert_eq!(sessions.len(), 2);
+        assert_eq!((sessions[0].id.as_str(), sessions[0].user.as_str(), sessions[0].line.as_str()), ("2", "alice", "tty2"));
+        assert_eq!(sessions[1].line, "pts/1");
+
+        let table = "     2 1000 alice seat0 tty2\n    c4 1001 bob         pts/1\n";
+        let sessions = parse_loginctl(table);
+        assert_eq!(sessions.len(), 2);
+        assert_eq!((sessions[0].user.as_str(), sessions[0].line.as_str()), ("alice", "tty2"));
+        assert_eq!((sessions[1].user.as_str(), sessions[1].line.as_str()), ("bob", "pts/1"));
+    }
+}
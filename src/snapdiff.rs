@@ -0,0 +1,191 @@
+//! "What changed" between two snapshots.
+//!
+//! Compares the current snapshot against one retained from a few seconds
+//! earlier: processes that started or exited, the biggest CPU and memory
+//! movers, and network interfaces that appeared, disappeared or changed link
+//! state. Also detects watched process names starting or exiting between
+//! ticks.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::metrics::{diff_interfaces, IfaceChange, ProcessId, ProcessInfo, Snapshot};
+
+/// Movers listed per category.
+const TOP_MOVERS: usize = 5;
+/// CPU changes below this many percentage points are not reported.
+const MIN_CPU_DELTA: f32 = 1.0;
+/// Memory changes below this many bytes are not reported.
+const MIN_MEM_DELTA: i64 = 1024 * 1024;
+
+/// A process whose usage changed between the two snapshots.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mover<T> {
+    pub pid: u32,
+    pub name: Arc<str>,
+    pub delta: T,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct SnapshotDiff {
+    /// Seconds between the two snapshots.
+    pub elapsed_secs: f64,
+    pub started: Vec<ProcessId>,
+    pub exited: Vec<ProcessId>,
+    /// CPU change in percentage points, largest absolute change first.
+    pub cpu_movers: Vec<Mover<f32>>,
+    /// Resident memory change in bytes, largest absolute change first.
+    pub mem_movers: Vec<Mover<i64>>,
+    pub interfaces: Vec<IfaceChange>,
+}
+
+impl SnapshotDiff {
+    pub fn is_empty(&self) -> bool {
+        self.started.is_empty()
+            && self.exited.is_empty()
+            && self.cpu_movers.is_empty()
+            && self.mem_movers.is_empty()
+            && self.interfaces.is_empty()
+    }
+}
+
+pub fn diff(old: &Snapshot, new: &Snapshot) -> SnapshotDiff {
+    let (started, exited) = started_exited(&old.all_processes, &new.all_processes);
+    let (cpu_movers, mem_movers) = movers(&old.processes, &new.processes);
+    SnapshotDiff {
+        elapsed_secs: new.timestamp - old.timestamp,
+        started,
+        exited,
+        cpu_movers,
+        mem_movers,
+        interfaces: diff_interfaces(&old.net_interfaces, &new.net_interfaces),
+    }
+}
+
+/// A watched process name that started or exited.
+#[derive(Clone, Debug, PartialEq)]
+pub enum WatchEvent {
+    /// First instance appeared; carries the number of instances now running.
+    Started(String, usize),
+    /// Last instance exited.
+    Exited(String),
+}
+
+/// Compare watched names between two process tables. A name counts as
+/// started when it goes from zero to at least one instance and as exited
+/// when its last instance disappears. Matching is case-insensitive.
+pub fn watch_events(watched: &[String], old: &[ProcessId], new: &[ProcessId]) -> Vec<WatchEvent> {
+    let count = |list: &[ProcessId], name: &str| list.iter().filter(|p| p.name.eq_ignore_ascii_case(name)).count();
+    watched
+        .iter()
+        .filter_map(|name| match (count(old, name), count(new, name)) {
+            (0, n) if n > 0 => Some(WatchEvent::Started(name.clone(), n)),
+            (n, 0) if n > 0 => Some(WatchEvent::Exited(name.clone())),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Processes only in `new` (started) and only in `old` (exited).
+/// Both lists must be sorted by PID.
+fn started_exited(old: &[ProcessId], new: &[ProcessId]) -> (Vec<ProcessId>, Vec<ProcessId>) {
+    let (mut started, mut exited) = (Vec::new(), Vec::new());
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        match (old.get(i), new.get(j)) {
+            (Some(o), Some(n)) if o.pid == n.pid => {
+                // A reused PID with a different name is a new process
+                if o.name != n.name {
+                    exited.push(o.clone());
+                    started.push(n.clone());
+                }
+                i += 1;
+                j += 1;
+            }
+            (Some(o), Some(n)) if o.pid < n.pid => {
+                exited.push(o.clone());
+                i += 1;
+            }
+            (Some(o), None) => {
+                exited.push(o.clone());
+                i += 1;
+            }
+            (_, Some(n)) => {
+                started.push(n.clone());
+                j += 1;
+            }
+            (None, None) => break,
+        }
+    }
+    (started, exited)
+}
+
+/// Biggest CPU and memory changes among processes present in both lists.
+fn movers(old: &[ProcessInfo], new: &[ProcessInfo]) -> (Vec<Mover<f32>>, Vec<Mover<i64>>) {
+    let before: HashMap<u32, &ProcessInfo> = old.iter().map(|p| (p.pid, p)).collect();
+    let mut cpu = Vec::new();
+    let mut mem = Vec::new();
+    for proc in new {
+        let Some(prev) = before.get(&proc.pid).filter(|p| p.name == proc.name) else { continue };
+        let cpu_delta = proc.cpu_usage - prev.cpu_usage;
+        if cpu_delta.abs() >= MIN_CPU_DELTA {
+            cpu.push(Mover { pid: proc.pid, name: Arc::clone(&proc.name), delta: cpu_delta });
+        }
+        let mem_delta = proc.memory_bytes as i64 - prev.memory_bytes as i64;
+        if mem_delta.abs() >= MIN_MEM_DELTA {
+            mem.push(Mover { pid: proc.pid, name: Arc::clone(&proc.name), delta: mem_delta });
+        }
+    }
+    cpu.sort_by(|a, b| b.delta.abs().total_cmp(&a.delta.abs()));
+    mem.sort_by_key(|m| std::cmp::Reverse(m.delta.abs()));
+    cpu.truncate(TOP_MOVERS);
+    mem.truncate(TOP_MOVERS);
+    (cpu, mem)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn id(pid: u32, name: &str) -> ProcessId {
+        ProcessId { pid, name: name.into() }
+    }
+
+    #[test]
+    fn test_started_exited() {
+        let old = [id(1, "init"), id(10, "backup"), id(20, "sh")];
+        let new = [id(1, "init"), id(20, "vim"), id(30, "cargo")];
+        let (started, exited) = started_exited(&old, &new);
+        assert_eq!(started, vec![id(20, "vim"), id(30, "cargo")]);
+        assert_eq!(exited, vec![id(10, "backup"), id(20, "sh")]);
+    }
+
+    #[test]
+    fn test_watch_events() {
+        let watched = vec!["restic".to_string(), "Firefox".to_string(), "make".to_string()];
+        let old = [id(5, "restic"), id(9, "make")];
+        let new = [id(9, "make"), id(12, "firefox"), id(13, "firefox")];
+        assert_eq!(
+            watch_events(&watched, &old, &new),
+            vec![WatchEvent::Exited("restic".into()), WatchEvent::Started("Firefox".into(), 2)]
+        );
+    }
+
+    #[test]
+    fn test_movers() {
+        const MB: u64 = 1024 * 1024;
+        let old = [
+            ProcessInfo { cpu_usage: 5.0, memory_bytes: 100 * MB, ..ProcessInfo::for_test(1, "a") },
+            ProcessInfo { cpu_usage: 50.0, memory_bytes: 10 * MB, ..ProcessInfo::for_test(2, "b") },
+            ProcessInfo { cpu_usage: 1.0, memory_bytes: MB, ..ProcessInfo::for_test(3, "c") },
+        ];
+        let new = [
+            ProcessInfo { cpu_usage: 5.5, memory_bytes: 400 * MB, ..ProcessInfo::for_test(1, "a") },
+            ProcessInfo { cpu_usage: 10.0, memory_bytes: 10 * MB, ..ProcessInfo::for_test(2, "b") },
+            ProcessInfo { cpu_usage: 90.0, memory_bytes: MB, ..ProcessInfo::for_test(4, "d") },
+        ];
+        let (cpu, mem) = movers(&old, &new);
+        assert_eq!(cpu, vec![Mover { pid: 2, name: "b".into(), delta: -40.0 }]);
+        assert_eq!(mem, vec![Mover { pid: 1, name: "a".into(), delta: 300 * MB as i64 }]);
+    }
+}
@@ -0,0 +1,586 @@
+//! Listening ports and connections of a process, and new TCP listeners
+//! across the system for the port watch.
+//!
+//! On Linux the kernel's tables in `/proc/<pid>/net/{tcp,tcp6,udp,udp6}`
+//! are joined with the `socket:[inode]` links in `/proc/<pid>/fd`; macOS
+//! asks `lsof` and Windows `netstat -ano`. Sockets of other users'
+//! processes usually can't be attributed without root.
+
+use std::collections::HashSet;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Protocol {
+    Tcp,
+    Udp,
+}
+
+impl Protocol {
+    pub fn name(self) -> &'static str {
+        match self {
+            Protocol::Tcp => "TCP",
+            Protocol::Udp => "UDP",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SocketInfo {
+    pub protocol: Protocol,
+    pub local: SocketAddr,
+    /// None for listening TCP and unconnected UDP sockets.
+    pub remote: Option<SocketAddr>,
+    /// TCP state as the platform names it (`ESTABLISHED`, `LISTEN`);
+    /// empty for UDP.
+    pub state: String,
+    pub pid: Option<u32>,
+}
+
+impl SocketInfo {
+    /// Waiting for connections (TCP) or datagrams from anyone (UDP).
+    pub fn listening(&self) -> bool {
+        self.remote.is_none()
+    }
+}
+
+/// Sockets owned by `pid`, listening ones first.
+pub fn for_pid(pid: u32) -> Vec<SocketInfo> {
+    let mut sockets = read_pid(pid);
+    sockets.sort_by(|a, b| {
+        b.listening()
+            .cmp(&a.listening())
+            .then(a.protocol.cmp(&b.protocol))
+            .then(a.local.port().cmp(&b.local.port()))
+    });
+    sockets
+}
+
+/// How long the sockets read by `for_pid_cached` are shown before being
+/// read again.
+const PID_SOCKETS_TTL: Duration = Duration::from_secs(2);
+
+/// Sockets of the last PID asked for through `for_pid_cached`. `lsof` and
+/// `netstat` take long enough to stall the UI, so they run on a background
+/// thread and the UI shows the latest result.
+static PID_SOCKETS: Mutex<Option<(Instant, u32, Vec<SocketInfo>)>> = Mutex::new(None);
+
+static PID_SOCKETS_RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// The latest sockets read for `pid`, kicking off a fresh read when they
+/// are stale or belong to another process. Empty until that read is done.
+pub fn for_pid_cached(pid: u32) -> Vec<SocketInfo> {
+    let (cached, fresh) = match PID_SOCKETS.lock() {
+        Ok(guard) => match &*guard {
+            Some((ts, cached_pid, sockets)) if *cached_pid == pid => (sockets.clone(), ts.elapsed() < PID_SOCKETS_TTL),
+            _ => (Vec::new(), false),
+        },
+        Err(_) => (Vec::new(), true),
+    };
+
+    if !fresh && !PID_SOCKETS_RUNNING.swap(true, Ordering::AcqRel) {
+        std::thread::spawn(move || {
+            let sockets = for_pid(pid);
+            if let Ok(mut guard) = PID_SOCKETS.lock() {
+                *guard = Some((Instant::now(), pid, sockets));
+            }
+            PID_SOCKETS_RUNNING.store(false, Ordering::Release);
+        });
+    }
+
+    cached
+}
+
+/// Whether an allowlist entry covers a listener: its port number, or the
+/// name of the owning process (case-insensitive).
+pub fn allowlisted(allowlist: &[String], socket: &SocketInfo, process: Option<&str>) -> bool {
+    allowlist.iter().any(|entry| match entry.parse::<u16>() {
+        Ok(port) => port == socket.local.port(),
+        Err(_) => process.is_some_and(|name| name.eq_ignore_ascii_case(entry)),
+    })
+}
+
+/// Time between two checks of the port watch.
+const PORT_WATCH_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Reports TCP listeners as they appear. Listing them runs `lsof` or
+/// `netstat` outside Linux, so the checks run on a background thread every
+/// `PORT_WATCH_INTERVAL` and the UI drains the results on each tick.
+/// Dropping the handle stops the thread.
+pub struct PortWatch {
+    rx: Receiver<SocketInfo>,
+    stop: Arc<AtomicBool>,
+}
+
+impl PortWatch {
+    /// Start watching; listeners open at this point aren't reported.
+    pub fn start() -> Self {
+        let (tx, rx) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = Arc::clone(&stop);
+        std::thread::spawn(move || {
+            let mut listeners = ListenerSet::default();
+            while !thread_stop.load(Ordering::Relaxed) {
+                for socket in listeners.check() {
+                    if tx.send(socket).is_err() {
+                        return;
+                    }
+                }
+                std::thread::sleep(PORT_WATCH_INTERVAL);
+            }
+        });
+        Self { rx, stop }
+    }
+
+    /// New listeners found since the previous call.
+    pub fn drain(&self) -> Vec<SocketInfo> {
+        self.rx.try_iter().collect()
+    }
+}
+
+impl Drop for PortWatch {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+/// TCP listeners seen on the previous check, to report new ones.
+#[derive(Default)]
+struct ListenerSet {
+    known: Option<HashSet<SocketAddr>>,
+}
+
+impl ListenerSet {
+    /// TCP sockets listening now that weren't on the previous call, with
+    /// their owner where it can be found. The first call only takes stock.
+    fn check(&mut self) -> Vec<SocketInfo> {
+        let current = read_listeners();
+        let added = added_listeners(self.known.as_ref(), &current);
+        self.known = Some(current.iter().map(|(s, _)| s.local).collect());
+        attribute(added)
+    }
+}
+
+/// Listeners in `current` whose address isn't in `known`, once each.
+/// Nothing is new before there's a previous check to compare with.
+fn added_listeners<T: Clone>(known: Option<&HashSet<SocketAddr>>, current: &[(SocketInfo, T)]) -> Vec<(SocketInfo, T)> {
+    let Some(known) = known else { return Vec::new() };
+    let mut seen = HashSet::new();
+    current
+        .iter()
+        .filter(|(s, _)| !known.contains(&s.local) && seen.insert(s.local))
+        .cloned()
+        .collect()
+}
+
+/// Listening TCP sockets with their inode (Linux) or owner (elsewhere).
+#[cfg(target_os = "linux")]
+fn read_listeners() -> Vec<(SocketInfo, Option<u64>)> {
+    ["tcp", "tcp6"]
+        .into_iter()
+        .flat_map(|table| parse_proc_net(&std::fs::read_to_string(format!("/proc/net/{table}")).unwrap_or_default(), Protocol::Tcp))
+        .filter(|(s, _)| s.listening())
+        .map(|(s, inode)| (s, Some(inode)))
+        .collect()
+}
+
+#[cfg(target_os = "macos")]
+fn read_listeners() -> Vec<(SocketInfo, Option<u64>)> {
+    let output = std::process::Command::new("lsof").args(["-nP", "-iTCP", "-sTCP:LISTEN", "-F", "pPnT"]).output();
+    output
+        .map(|o| parse_lsof(&String::from_utf8_lossy(&o.stdout)))
+        .unwrap_or_default()
+        .into_iter()
+        .map(|s| (s, None))
+        .collect()
+}
+
+#[cfg(target_os = "windows")]
+fn read_listeners() -> Vec<(SocketInfo, Option<u64>)> {
+    netstat()
+        .into_iter()
+        .filter(|s| s.protocol == Protocol::Tcp && s.listening())
+        .map(|s| (s, None))
+        .collect()
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+fn read_listeners() -> Vec<(SocketInfo, Option<u64>)> {
+    Vec::new()
+}
+
+/// Fill in the owning PID of Linux sockets from their inodes. That means
+/// reading every process's fd links, so it's only done for new listeners.
+fn attribute(sockets: Vec<(SocketInfo, Option<u64>)>) -> Vec<SocketInfo> {
+    #[cfg(target_os = "linux")]
+    {
+        let wanted: HashSet<u64> = sockets.iter().filter_map(|(_, inode)| *inode).collect();
+        let owners = socket_owners(&wanted);
+        sockets
+            .into_iter()
+            .map(|(s, inode)| SocketInfo { pid: s.pid.or_else(|| owners.get(&inode?).copied()), ..s })
+            .collect()
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        sockets.into_iter().map(|(s, _)| s).collect()
+    }
+}
+
+/// PID holding each of the `wanted` socket inodes.
+#[cfg(target_os = "linux")]
+fn socket_owners(wanted: &HashSet<u64>) -> std::collections::HashMap<u64, u32> {
+    let mut owners = std::collections::HashMap::new();
+    if wanted.is_empty() {
+        return owners;
+    }
+    let Ok(procs) = std::fs::read_dir("/proc") else { return owners };
+    for entry in procs.flatten() {
+        let Some(pid) = entry.file_name().to_str().and_then(|n| n.parse::<u32>().ok()) else { continue };
+        for inode in socket_inodes(pid) {
+            if wanted.contains(&inode) {
+                owners.insert(inode, pid);
+            }
+        }
+        if owners.len() == wanted.len() {
+            break;
+        }
+    }
+    owners
+}
+
+/// Inodes of the sockets `pid` has open.
+#[cfg(target_os = "linux")]
+fn socket_inodes(pid: u32) -> HashSet<u64> {
+    let Ok(fds) = std::fs::read_dir(format!("/proc/{pid}/fd")) else { return HashSet::new() };
+    fds.flatten()
+        .filter_map(|fd| {
+            let link = std::fs::read_link(fd.path()).ok()?;
+            link.to_str()?.strip_prefix("socket:[")?.strip_suffix(']')?.parse().ok()
+        })
+        .collect()
+}
+
+#[cfg(target_os = "linux")]
+fn read_pid(pid: u32) -> Vec<SocketInfo> {
+    let dir = format!("/proc/{pid}");
+    let inodes = socket_inodes(pid);
+    if inodes.is_empty() {
+        return Vec::new();
+    }
+    [("tcp", Protocol::Tcp), ("tcp6", Protocol::Tcp), ("udp", Protocol::Udp), ("udp6", Protocol::Udp)]
+        .into_iter()
+        .flat_map(|(table, protocol)| {
+            let contents = std::fs::read_to_string(format!("{dir}/net/{table}")).unwrap_or_default();
+            parse_proc_net(&contents, protocol)
+        })
+        .filter(|(_, inode)| inodes.contains(inode))
+        .map(|(socket, _)| SocketInfo { pid: Some(pid), ..socket })
+        .collect()
+}
+
+#[cfg(target_os = "macos")]
+fn read_pid(pid: u32) -> Vec<SocketInfo> {
+    let output = std::process::Command::new("lsof")
+        .args(["-nP", "-a", "-i", "-F", "pPnT", "-p", &pid.to_string()])
+        .output();
+    output.map(|o| parse_lsof(&String::from_utf8_lossy(&o.stdout))).unwrap_or_default()
+}
+
+#[cfg(target_os = "windows")]
+fn read_pid(pid: u32) -> Vec<SocketInfo> {
+    netstat().into_iter().filter(|s| s.pid == Some(pid)).collect()
+}
+
+/// Every socket with its owner, from `netstat -ano`.
+#[cfg(target_os = "windows")]
+fn netstat() -> Vec<SocketInfo> {
+    use std::os::windows::process::CommandExt;
+
+    let mut cmd = std::process::Command::new("netstat");
+    cmd.arg("-ano");
+    // CREATE_NO_WINDOW: don't flash a console
+    cmd.creation_flags(0x0800_0000);
+    let Ok(output) = cmd.output() else { return Vec::new() };
+    parse_netstat(&String::from_utf8_lossy(&output.stdout))
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+fn read_pid(_pid: u32) -> Vec<SocketInfo> {
+    Vec::new()
+}
+
+/// Sockets and their inodes from a `/proc/net/{tcp,udp}[6]` table.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_proc_net(contents: &str, protocol: Protocol) -> Vec<(SocketInfo, u64)> {
+    contents
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let local = parse_hex_addr(fields.get(1)?)?;
+            let remote = parse_hex_addr(fields.get(2)?)?;
+            let st = u8::from_str_radix(fields.get(3)?, 16).ok()?;
+            let inode: u64 = fields.get(9)?.parse().ok()?;
+            let state = match protocol {
+                Protocol::Tcp => tcp_state_name(st).to_string(),
+                Protocol::Udp => String::new(),
+            };
+            // Unconnected UDP sockets are in state 7 (TCP_CLOSE)
+            let unconnected = match protocol {
+                Protocol::Tcp => st == 0x0A,
+                Protocol::Udp => st == 0x07,
+            };
+            let remote = (!unconnected).then_some(remote);
+            Some((SocketInfo { protocol, local, remote, state, pid: None }, inode))
+        })
+        .collect()
+}
+
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn tcp_state_name(st: u8) -> &'static str {
+    match st {
+        0x01 => "ESTABLISHED",
+        0x02 => "SYN_SENT",
+        0x03 => "SYN_RECV",
+        0x04 => "FIN_WAIT1",
+        0x05 => "FIN_WAIT2",
+        0x06 => "TIME_WAIT",
+        0x07 => "CLOSE",
+        0x08 => "CLOSE_WAIT",
+        0x09 => "LAST_ACK",
+        0x0A => "LISTEN",
+        0x0B => "CLOSING",
+        _ => "UNKNOWN",
+    }
+}
+
+/// `0100007F:1F90` (IPv4) or 32 hex digits and a port (IPv6). Addresses
+/// are 32-bit words in host byte order, the port is big-endian.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_hex_addr(s: &str) -> Option<SocketAddr> {
+    let (addr, port) = s.split_once(':')?;
+    let port = u16::from_str_radix(port, 16).ok()?;
+    let word = |i: usize| u32::from_str_radix(addr.get(i * 8..i * 8 + 8)?, 16).ok().map(u32::to_ne_bytes);
+    let ip = match addr.len() {
+        8 => IpAddr::V4(Ipv4Addr::from(word(0)?)),
+        32 => {
+            let mut octets = [0u8; 16];
+            for i in 0..4 {
+                octets[i * 4..i * 4 + 4].copy_from_slice(&word(i)?);
+            }
+            IpAddr::V6(Ipv6Addr::from(octets))
+        }
+        _ => return None,
+    };
+    Some(SocketAddr::new(ip, port))
+}
+
+/// Sockets from `lsof -F pPnT` output: a `p` line starts each process and
+/// a `f` line each of its files, then `P` gives the protocol, `n` the
+/// addresses (`local->remote`) and `TST=` the TCP state.
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn parse_lsof(output: &str) -> Vec<SocketInfo> {
+    let mut sockets = Vec::new();
+    let mut current: Option<SocketInfo> = None;
+    let mut protocol = None;
+    let mut pid = None;
+    for line in output.lines() {
+        let (tag, value) = line.split_at(line.len().min(1));
+        match tag {
+            "p" => {
+                sockets.extend(current.take());
+                pid = value.parse().ok();
+            }
+            "f" => {
+                sockets.extend(current.take());
+                protocol = None;
+            }
+            "P" => {
+                protocol = match value {
+                    "TCP" => Some(Protocol::Tcp),
+                    "UDP" => Some(Protocol::Udp),
+                    _ => None,
+                };
+            }
+            "n" => {
+                let Some(protocol) = protocol else { continue };
+                let (local, remote) = match value.split_once("->") {
+                    Some((local, remote)) => (local, Some(remote)),
+                    None => (value, None),
+                };
+                let Some(local) = parse_addr(local) else { continue };
+                current = Some(SocketInfo {
+                    protocol,
+                    local,
+                    remote: remote.and_then(parse_addr),
+                    state: String::new(),
+                    pid,
+                });
+            }
+            "T" => {
+                if let (Some(socket), Some(state)) = (current.as_mut(), value.strip_prefix("ST=")) {
+                    socket.state = state.to_string();
+                }
+            }
+            _ => {}
+        }
+    }
+    sockets.extend(current);
+    sockets
+}
+
+/// Sockets from `netstat -ano`. TCP states are translated on localized
+/// Windows, so listening is told by the remote port being 0.
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn parse_netstat(output: &str) -> Vec<SocketInfo> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let protocol = match *fields.first()? {
+                "TCP" => Protocol::Tcp,
+                "UDP" => Protocol::Udp,
+                _ => return None,
+            };
+            let local = parse_addr(fields.get(1)?)?;
+            let remote = parse_addr(fields.get(2)?).filter(|addr| addr.port() != 0);
+            let pid: u32 = fields.last()?.parse().ok()?;
+            let state = match protocol {
+                Protocol::Tcp if fields.len() == 5 => fields[3].to_string(),
+                _ => String::new(),
+            };
+            Some(SocketInfo { protocol, local, remote, state, pid: Some(pid) })
+        })
+        .collect()
+}
+
+/// `10.0.0.2:443`, `[::1]:631` or `*:5353` (any address).
+#[cfg_attr(target_os = "linux", allow(dead_code))]
+fn parse_addr(s: &str) -> Option<SocketAddr> {
+    if let Some(port) = s.strip_prefix("*:") {
+        return Some(SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), port.parse().ok()?));
+    }
+    s.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn addr(s: &str) -> SocketAddr {
+        s.parse().unwrap()
+    }
+
+    #[cfg(target_endian = "little")]
+    #[test]
+    fn test_parse_proc_net() {
+        let tcp = "  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode\n\
+                   \x20  0: 00000000:1F90 00000000:0000 0A 00000000:00000000 00:00000000 00000000  1000        0 4242 1 0 100 0 0 10 0\n\
+                   \x20  1: 0200000A:C350 0403020A:01BB 01 00000000:00000000 00:00000000 00000000  1000        0 4343 1 0 100 0 0 10 0\n";
+        let sockets = parse_proc_net(tcp, Protocol::Tcp);
+        assert_eq!(sockets.len(), 2);
+        assert_eq!(sockets[0].0.local, addr("0.0.0.0:8080"));
+        assert!(sockets[0].0.listening());
+        assert_eq!(sockets[0].0.state, "LISTEN");
+        assert_eq!(sockets[0].1, 4242);
+        assert_eq!(sockets[1].0.local, addr("10.0.0.2:50000"));
+        assert_eq!(sockets[1].0.remote, Some(addr("10.2.3.4:443")));
+        assert_eq!(sockets[1].0.state, "ESTABLISHED");
+
+        let udp6 = "  sl  local_address                         remote_address                        st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode ref pointer drops\n\
+                    \x20 12: 00000000000000000000000001000000:0035 00000000000000000000000000000000:0000 07 00000000:00000000 00:00000000 00000000   101        0 777 2 0 0\n";
+        let sockets = parse_proc_net(udp6, Protocol::Udp);
+        assert_eq!(sockets[0].0.local, addr("[::1]:53"));
+        assert!(sockets[0].0.listening());
+        assert_eq!(sockets[0].1, 777);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_for_pid_finds_own_listener() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let local = listener.local_addr().unwrap();
+        let sockets = for_pid(std::process::id());
+        assert!(sockets.iter().any(|s| s.local == local && s.listening() && s.protocol == Protocol::Tcp));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_for_pid_cached_fills_in_the_background() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let local = listener.local_addr().unwrap();
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while !for_pid_cached(std::process::id()).iter().any(|s| s.local == local) {
+            assert!(Instant::now() < deadline, "cached sockets never showed the listener");
+            std::thread::sleep(Duration::from_millis(20));
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_port_watch_reports_new_listener() {
+        let mut watch = ListenerSet::default();
+        assert!(watch.check().is_empty());
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let local = listener.local_addr().unwrap();
+        let added = watch.check();
+        let socket = added.iter().find(|s| s.local == local).expect("new listener reported");
+        assert_eq!(socket.pid, Some(std::process::id()));
+        assert!(!watch.check().iter().any(|s| s.local == local));
+    }
+
+    #[test]
+    fn test_parse_lsof() {
+        let output = "p4242\nf12\nPTCP\nn*:8080\nTST=LISTEN\nf13\nPTCP\nn10.0.0.2:50000->93.184.216.34:443\nTST=ESTABLISHED\nf14\nPUDP\nn[::1]:5353\n";
+        let sockets = parse_lsof(output);
+        assert_eq!(sockets.len(), 3);
+        assert_eq!(sockets[0].local, addr("0.0.0.0:8080"));
+        assert!(sockets[0].listening());
+        assert_eq!(sockets[1].remote, Some(addr("93.184.216.34:443")));
+        assert_eq!(sockets[1].state, "ESTABLISHED");
+        assert_eq!((sockets[2].protocol, sockets[2].local), (Protocol::Udp, addr("[::1]:5353")));
+        assert!(sockets.iter().all(|s| s.pid == Some(4242)));
+    }
+
+    fn listener(local: &str) -> SocketInfo {
+        SocketInfo { protocol: Protocol::Tcp, local: addr(local), remote: None, state: "LISTEN".into(), pid: None }
+    }
+
+    #[test]
+    fn test_added_listeners() {
+        let current = vec![(listener("0.0.0.0:22"), 1), (listener("0.0.0.0:8080"), 2), (listener("0.0.0.0:8080"), 3)];
+        assert!(added_listeners(None, &current).is_empty());
+        let known: HashSet<SocketAddr> = [addr("0.0.0.0:22")].into();
+        let added = added_listeners(Some(&known), &current);
+        assert_eq!(added, vec![(listener("0.0.0.0:8080"), 2)]);
+    }
+
+    #[test]
+    fn test_allowlisted() {
+        let allowlist = vec!["8080".to_string(), "sshd".to_string()];
+        assert!(allowlisted(&allowlist, &listener("0.0.0.0:8080"), None));
+        assert!(allowlisted(&allowlist, &listener("0.0.0.0:2222"), Some("SSHD")));
+        assert!(!allowlisted(&allowlist, &listener("0.0.0.0:2222"), Some("nc")));
+        assert!(!allowlisted(&allowlist, &listener("0.0.0.0:2222"), None));
+    }
+
+    #[test]
+    fn test_parse_netstat() {
+        let output = "\nActive Connections\n\n  Proto  Local Address          Foreign Address        State           PID\n\
+                      \x20 TCP    0.0.0.0:135            0.0.0.0:0              LISTENING       1000\n\
+                      \x20 TCP    10.0.0.2:50000         93.184.216.34:443      ESTABLISHED     2000\n\
+                      \x20 UDP    [::]:5353              *:*                                    3000\n";
+        let sockets = parse_netstat(output);
+        assert_eq!(sockets.len(), 3);
+        assert!(sockets[0].listening());
+        assert_eq!(sockets[0].pid, Some(1000));
+        assert_eq!(sockets[1].remote, Some(addr("93.184.216.34:443")));
+        assert_eq!(sockets[1].state, "ESTABLISHED");
+        assert!(sockets[2].listening());
+        assert_eq!(sockets[2].local, addr("[::]:5353"));
+    }
+}
@@ -0,0 +1,124 @@
+//! "Copy system summary": the machine and its current load as Markdown,
+//! for pasting into forum posts and bug reports.
+//!
+//! Labels and number formats stay in English whatever the UI language, like
+//! the diagnostics bundle, since the text usually ends up in an
+//! English-speaking tracker.
+
+use std::fmt::Write;
+
+use crate::metrics::{Snapshot, SystemLoad};
+use crate::ui::{byte_scale, format_duration};
+
+/// Markdown table of the system in `snap`, then its current utilization.
+pub fn markdown(snap: &Snapshot) -> String {
+    let sys = &snap.sys_info;
+    let mut rows: Vec<(&str, String)> = vec![
+        ("Host", sys.hostname.clone()),
+        ("OS", format!("{} {}", sys.os_name, sys.os_version).trim().to_string()),
+        ("Kernel", sys.kernel_version.clone()),
+    ];
+    if let Some(hypervisor) = &sys.hypervisor {
+        rows.push(("Virtualized", hypervisor.name().to_string()));
+    }
+    let mut cpu = format!("{} ({} threads", snap.cpu_name.trim(), snap.cpu_core_count);
+    if snap.cpu_frequency_mhz > 0 {
+        let _ = write!(cpu, " @ {:.2} GHz", snap.cpu_frequency_mhz as f64 / 1000.0);
+    }
+    cpu.push(')');
+    rows.push(("CPU", cpu));
+    rows.push(("RAM", format_bytes(snap.memory_total)));
+    if snap.swap_total > 0 {
+        rows.push(("Swap", format_bytes(snap.swap_total)));
+    }
+    for gpu in &snap.gpu.gpus {
+        let gpu = if gpu.memory_total > 0 { format!("{} ({})", gpu.name, format_bytes(gpu.memory_total)) } else { gpu.name.clone() };
+        rows.push(("GPU", gpu));
+    }
+    for disk in snap.disks.iter() {
+        let used = disk.total.saturating_sub(disk.available);
+        rows.push((
+            "Disk",
+            format!("`{}` {}, {} used of {}", disk.mount, disk.fs_type, format_bytes(used), format_bytes(disk.total)),
+        ));
+    }
+    rows.push(("Uptime", format_duration(snap.uptime_secs)));
+
+    let mut out = String::from("### System summary\n\n| | |\n|---|---|\n");
+    for (label, value) in rows {
+        let _ = writeln!(out, "| **{label}** | {} |", escape(&value));
+    }
+
+    let mem_pct = if snap.memory_total > 0 { snap.memory_used as f64 / snap.memory_total as f64 * 100.0 } else { 0.0 };
+    let _ = write!(
+        out,
+        "\n**Current utilization:** CPU {:.0}%, RAM {:.0}% ({} used), {} processes",
+        snap.cpu_usage_global,
+        mem_pct,
+        format_bytes(snap.memory_used),
+        snap.process_count,
+    );
+    if let SystemLoad::Average(avg) = snap.load {
+        let _ = write!(out, ", load {:.2} / {:.2} / {:.2}", avg[0], avg[1], avg[2]);
+    }
+    if let Some(power) = snap.power {
+        let _ = write!(out, ", {:.1} W", power.watts);
+    }
+    let _ = writeln!(out, "\n\n<sub>Digger {}</sub>", env!("CARGO_PKG_VERSION"));
+    out
+}
+
+/// Bytes in binary units, with a `.` decimal point in every language.
+fn format_bytes(bytes: u64) -> String {
+    let (value, decimals, unit) = byte_scale(bytes);
+    format!("{value:.decimals$}{unit}")
+}
+
+/// Keep a value inside its table cell.
+fn escape(s: &str) -> String {
+    s.replace('|', "\\|").replace('\n', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_markdown() {
+        let mut snap = Snapshot::for_test(0.0, 25.0);
+        snap.cpu_usage_per_core = vec![25.0, 25.0];
+        snap.cpu_name = "Test CPU | v2".into();
+        snap.cpu_core_count = 2;
+        snap.cpu_frequency_mhz = 3400;
+        snap.memory_used = 4 << 30;
+        snap.memory_total = 16 << 30;
+        snap.disks = Arc::new([crate::metrics::DiskInfo {
+            name: "sda1".into(),
+            mount: "/".into(),
+            fs_type: "ext4".into(),
+            total: 100 << 30,
+            available: 40 << 30,
+            is_removable: false,
+        }]);
+        snap.uptime_secs = 90_000;
+        snap.process_count = 123;
+        snap.sys_info = Arc::new(crate::metrics::SystemInfo {
+            os_name: "Linux".into(),
+            os_version: "24.04".into(),
+            kernel_version: "6.8.0".into(),
+            hostname: "box".into(),
+            hypervisor: Some(crate::virt::Hypervisor::Kvm),
+        });
+        snap.load = SystemLoad::Average([0.5, 0.25, 0.75]);
+        let md = markdown(&snap);
+        assert!(md.contains("| **OS** | Linux 24.04 |"));
+        assert!(md.contains("| **Virtualized** | KVM |"));
+        assert!(md.contains("| **CPU** | Test CPU \\| v2 (2 threads @ 3.40 GHz) |"));
+        assert!(md.contains("| **RAM** | 16.0 GiB |"));
+        assert!(md.contains("`/` ext4, 60.0 GiB used of 100.0 GiB"));
+        assert!(md.contains("| **Uptime** | 1d 1h 0m |"));
+        assert!(md.contains("CPU 25%, RAM 25% (4.0 GiB used), 123 processes, load 0.50 / 0.25 / 0.75"));
+        assert!(!md.contains("Swap"));
+    }
+}
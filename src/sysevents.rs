@@ -0,0 +1,373 @@
+//! Operating-system error feed merged into the Event Log tab.
+//!
+//! Backends:
+//! - Linux: `journalctl -p err -o json`, resumed from the last cursor
+//! - Windows: `EvtQuery` on the System and Application channels
+//!   (critical + error levels), resumed from the last EventRecordID
+//!
+//! Polling runs on a background thread every `POLL_INTERVAL_SECS`; new
+//! entries are handed to the UI through a channel and drained on each tick.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver};
+#[cfg(any(target_os = "linux", target_os = "windows"))]
+use std::sync::mpsc::Sender;
+use std::sync::Arc;
+
+/// Seconds between two polls of the system log.
+#[cfg(any(target_os = "linux", target_os = "windows"))]
+const POLL_INTERVAL_SECS: u64 = 15;
+
+/// Upper bound on entries fetched per poll (protects against log storms).
+#[cfg(any(target_os = "linux", target_os = "windows"))]
+const MAX_ENTRIES_PER_POLL: usize = 50;
+
+/// One error-level entry from the system log.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SystemEvent {
+    /// Unit, service or provider that logged the entry.
+    pub source: String,
+    pub message: String,
+    /// Critical/alert/emergency rather than plain error.
+    pub critical: bool,
+}
+
+/// Handle to the polling thread. Dropping it stops the thread.
+pub struct SystemEventFeed {
+    rx: Receiver<SystemEvent>,
+    stop: Arc<AtomicBool>,
+}
+
+impl SystemEventFeed {
+    /// Start polling. Only entries logged after this call are reported.
+    pub fn start() -> Self {
+        #[cfg_attr(not(any(target_os = "linux", target_os = "windows")), allow(unused_variables))]
+        let (tx, rx) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+
+        #[cfg(target_os = "linux")]
+        {
+            let thread_stop = Arc::clone(&stop);
+            std::thread::spawn(move || poll_journald(tx, &thread_stop));
+        }
+
+        #[cfg(target_os = "windows")]
+        {
+            let thread_stop = Arc::clone(&stop);
+            std::thread::spawn(move || poll_windows_event_log(tx, &thread_stop));
+        }
+
+        Self { rx, stop }
+    }
+
+    /// Take up to `max` pending entries; returns them and the number skipped.
+    pub fn drain(&self, max: usize) -> (Vec<SystemEvent>, usize) {
+        let mut events = Vec::new();
+        let mut skipped = 0;
+        while let Ok(ev) = self.rx.try_recv() {
+            if events.len() < max {
+                events.push(ev);
+            } else {
+                skipped += 1;
+            }
+        }
+        (events, skipped)
+    }
+}
+
+impl Drop for SystemEventFeed {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Sleep for one poll interval in short steps so the thread exits promptly.
+/// Returns false once the feed has been stopped.
+#[cfg(any(target_os = "linux", target_os = "windows"))]
+fn wait_next_poll(stop: &AtomicBool) -> bool {
+    for _ in 0..POLL_INTERVAL_SECS * 4 {
+        if stop.load(Ordering::Relaxed) {
+            return false;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(250));
+    }
+    !stop.load(Ordering::Relaxed)
+}
+
+// ---------------------------------------------------------------------------
+// Linux: journald
+// ---------------------------------------------------------------------------
+
+#[cfg(target_os = "linux")]
+fn poll_journald(tx: Sender<SystemEvent>, stop: &AtomicBool) {
+    use std::process::Command;
+
+    let since = format!("--since=@{}", chrono::Utc::now().timestamp());
+    let mut cursor: Option<String> = None;
+
+    while wait_next_poll(stop) {
+        let mut cmd = Command::new("journalctl");
+        cmd.args(["-p", "err", "-o", "json", "--no-pager", "-q"]);
+        cmd.arg(format!("-n{MAX_ENTRIES_PER_POLL}"));
+        match &cursor {
+            Some(c) => cmd.arg(format!("--after-cursor={c}")),
+            None => cmd.arg(&since),
+        };
+        let output = match cmd.output() {
+            Ok(o) if o.status.success() => o,
+            Ok(_) => continue,
+            Err(e) => {
+                eprintln!("[digger] journalctl unavailable, system log feed disabled: {e}");
+                return;
+            }
+        };
+
+        for line in String::from_utf8_lossy(&output.stdout).lines() {
+            let Some((entry_cursor, event)) = parse_journal_line(line) else { continue };
+            cursor = Some(entry_cursor);
+            if let Some(event) = event {
+                if tx.send(event).is_err() {
+                    return;
+                }
+            }
+        }
+    }
+}
+
+/// Parse one `journalctl -o json` line into (cursor, event). The event is
+/// `None` for entries without a printable message.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_journal_line(line: &str) -> Option<(String, Option<SystemEvent>)> {
+    let value: serde_json::Value = serde_json::from_str(line).ok()?;
+    let cursor = value.get("__CURSOR")?.as_str()?.to_string();
+    let field = |key: &str| value.get(key).and_then(|v| v.as_str());
+
+    // Binary messages are encoded as byte arrays — skip them.
+    let Some(message) = field("MESSAGE") else { return Some((cursor, None)) };
+    let source = field("SYSLOG_IDENTIFIER")
+        .or_else(|| field("_SYSTEMD_UNIT"))
+        .or_else(|| field("_COMM"))
+        .unwrap_or("journal")
+        .to_string();
+    let priority: u8 = field("PRIORITY").and_then(|p| p.parse().ok()).unwrap_or(3);
+
+    Some((cursor, Some(SystemEvent {
+        source,
+        message: message.trim().to_string(),
+        critical: priority <= 2,
+    })))
+}
+
+// ---------------------------------------------------------------------------
+// Windows: Event Log (wevtapi)
+// ---------------------------------------------------------------------------
+
+#[cfg(target_os = "windows")]
+const WINDOWS_CHANNELS: &[&str] = &["System", "Application"];
+
+#[cfg(target_os = "windows")]
+fn poll_windows_event_log(tx: Sender<SystemEvent>, stop: &AtomicBool) {
+    // Start after the newest existing record so only new errors are reported.
+    let mut last_ids: Vec<u64> = WINDOWS_CHANNELS
+        .iter()
+        .map(|ch| evt::query(ch, "*", true, 1).first().map(|e| e.record_id).unwrap_or(0))
+        .collect();
+
+    while wait_next_poll(stop) {
+        for (channel, last_id) in WINDOWS_CHANNELS.iter().zip(last_ids.iter_mut()) {
+            // Level 1 = critical, 2 = error
+            let xpath = format!("*[System[(Level=1 or Level=2) and EventRecordID > {last_id}]]");
+            for entry in evt::query(channel, &xpath, false, MAX_ENTRIES_PER_POLL) {
+                *last_id = (*last_id).max(entry.record_id);
+                let event = SystemEvent {
+                    source: entry.provider,
+                    message: entry.message.unwrap_or_else(|| format!("Event ID {}", entry.event_id)),
+                    critical: entry.level == 1,
+                };
+                if tx.send(event).is_err() {
+                    return;
+                }
+            }
+        }
+    }
+}
+
+/// Fields extracted from an event's XML rendering.
+#[derive(Debug, PartialEq)]
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+struct EventXmlInfo {
+    record_id: u64,
+    provider: String,
+    level: u8,
+    event_id: u32,
+    message: Option<String>,
+}
+
+/// Extract the System block fields from `EvtRender(EvtRenderEventXml)` output.
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn parse_event_xml(xml: &str) -> Option<EventXmlInfo> {
+    fn element<'a>(xml: &'a str, tag: &str) -> Option<&'a str> {
+        let start = xml.find(&format!("<{tag}"))?;
+        let body = &xml[start..];
+        let open_end = body.find('>')?;
+        if body[..open_end].ends_with('/') {
+            return None;
+        }
+        let close = body.find(&format!("</{tag}>"))?;
+        Some(body[open_end + 1..close].trim())
+    }
+    let provider = {
+        let start = xml.find("<Provider ")?;
+        let attrs = &xml[start..start + xml[start..].find('>')?];
+        let name_at = attrs.find("Name=")? + 5;
+        let quote = attrs[name_at..].chars().next()?;
+        let rest = &attrs[name_at + 1..];
+        rest[..rest.find(quote)?].to_string()
+    };
+    Some(EventXmlInfo {
+        record_id: element(xml, "EventRecordID")?.parse().ok()?,
+        provider,
+        level: element(xml, "Level").and_then(|l| l.parse().ok()).unwrap_or(2),
+        event_id: element(xml, "EventID").and_then(|id| id.parse().ok()).unwrap_or(0),
+        message: None,
+    })
+}
+
+#[cfg(target_os = "windows")]
+mod evt {
+    use super::{parse_event_xml, EventXmlInfo};
+    use windows_sys::Win32::System::EventLog::{
+        EvtClose, EvtFormatMessage, EvtFormatMessageEvent, EvtNext, EvtOpenPublisherMetadata,
+        EvtQuery, EvtQueryChannelPath, EvtQueryReverseDirection, EvtRender, EvtRenderEventXml,
+        EVT_HANDLE,
+    };
+
+    fn wide(s: &str) -> Vec<u16> {
+        s.encode_utf16().chain(std::iter::once(0)).collect()
+    }
+
+    /// Run an XPath query against `channel` and return up to `max` events.
+    pub(super) fn query(channel: &str, xpath: &str, newest_first: bool, max: usize) -> Vec<EventXmlInfo> {
+        let channel_w = wide(channel);
+        let xpath_w = wide(xpath);
+        let mut flags = EvtQueryChannelPath;
+        if newest_first {
+            flags |= EvtQueryReverseDirection;
+        }
+        let mut out = Vec::new();
+        // SAFETY: all pointers reference live, NUL-terminated UTF-16 buffers;
+        // every handle returned by wevtapi is closed before returning.
+        unsafe {
+            let results = EvtQuery(0, channel_w.as_ptr(), xpath_w.as_ptr(), flags);
+            if results == 0 {
+                return out;
+            }
+            let mut handles: [EVT_HANDLE; 16] = [0; 16];
+            while out.len() < max {
+                let mut returned = 0u32;
+                if EvtNext(results, handles.len() as u32, handles.as_mut_ptr(), 1000, 0, &mut returned) == 0 {
+                    break;
+                }
+                for &event in &handles[..returned as usize] {
+                    if out.len() < max {
+                        if let Some(mut info) = render_xml(event).as_deref().and_then(parse_event_xml) {
+                            info.message = format_message(&info.provider, event);
+                            out.push(info);
+                        }
+                    }
+                    EvtClose(event);
+                }
+            }
+            EvtClose(results);
+        }
+        out
+    }
+
+    unsafe fn render_xml(event: EVT_HANDLE) -> Option<String> {
+        let mut used = 0u32;
+        let mut props = 0u32;
+        // First call sizes the buffer (in bytes).
+        EvtRender(0, event, EvtRenderEventXml, 0, std::ptr::null_mut(), &mut used, &mut props);
+        if used == 0 {
+            return None;
+        }
+        let mut buf: Vec<u16> = vec![0; (used as usize).div_ceil(2)];
+        if EvtRender(0, event, EvtRenderEventXml, used, buf.as_mut_ptr().cast(), &mut used, &mut props) == 0 {
+            return None;
+        }
+        let len = buf.iter().position(|&c| c == 0).unwrap_or(buf.len());
+        Some(String::from_utf16_lossy(&buf[..len]))
+    }
+
+    unsafe fn format_message(provider: &str, event: EVT_HANDLE) -> Option<String> {
+        let provider_w = wide(provider);
+        let metadata = EvtOpenPublisherMetadata(0, provider_w.as_ptr(), std::ptr::null(), 0, 0);
+        if metadata == 0 {
+            return None;
+        }
+        let mut used = 0u32;
+        EvtFormatMessage(metadata, event, 0, 0, std::ptr::null(), EvtFormatMessageEvent, 0, std::ptr::null_mut(), &mut used);
+        let mut result = None;
+        if used > 0 {
+            let mut buf: Vec<u16> = vec![0; used as usize];
+            if EvtFormatMessage(metadata, event, 0, 0, std::ptr::null(), EvtFormatMessageEvent, used, buf.as_mut_ptr(), &mut used) != 0 {
+                let len = buf.iter().position(|&c| c == 0).unwrap_or(buf.len());
+                let text = String::from_utf16_lossy(&buf[..len]);
+                // Keep the first line; full messages can span paragraphs.
+                result = text.lines().next().map(|l| l.trim().to_string()).filter(|l| !l.is_empty());
+            }
+        }
+        EvtClose(metadata);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_journal_line() {
+        let line = r#"{"__CURSOR":"s=abc;i=1","PRIORITY":"2","SYSLOG_IDENTIFIER":"kernel","MESSAGE":"EXT4-fs error "}"#;
+        let (cursor, event) = parse_journal_line(line).unwrap();
+        assert_eq!(cursor, "s=abc;i=1");
+        assert_eq!(event, Some(SystemEvent {
+            source: "kernel".into(),
+            message: "EXT4-fs error".into(),
+            critical: true,
+        }));
+
+        let binary = r#"{"__CURSOR":"s=abc;i=2","PRIORITY":"3","MESSAGE":[104,105]}"#;
+        assert_eq!(parse_journal_line(binary).unwrap().1, None);
+        assert!(parse_journal_line("not json").is_none());
+    }
+
+    #[test]
+    fn test_parse_event_xml() {
+        let xml = "<Event xmlns='http://schemas.microsoft.com/win/2004/08/events/event'><System>\
+            <Provider Name='disk'/><EventID Qualifiers='49156'>7</EventID><Level>2</Level>\
+            <TimeCreated SystemTime='2024-05-01T10:00:00.000Z'/><EventRecordID>12345</EventRecordID>\
+            <Channel>System</Channel></System></Event>";
+        assert_eq!(parse_event_xml(xml), Some(EventXmlInfo {
+            record_id: 12345,
+            provider: "disk".into(),
+            level: 2,
+            event_id: 7,
+            message: None,
+        }));
+        assert_eq!(parse_event_xml("<Event/>"), None);
+    }
+
+    #[test]
+    fn test_drain_rate_limits() {
+        let (tx, rx) = mpsc::channel();
+        let feed = SystemEventFeed { rx, stop: Arc::new(AtomicBool::new(false)) };
+        for i in 0..7 {
+            tx.send(SystemEvent { source: "test".into(), message: i.to_string(), critical: false }).unwrap();
+        }
+        let (events, skipped) = feed.drain(5);
+        assert_eq!(events.len(), 5);
+        assert_eq!(skipped, 2);
+        assert_eq!(feed.drain(5), (Vec::new(), 0));
+    }
+}
@@ -0,0 +1,74 @@
+//! System fonts for scripts without a bundled font.
+//!
+//! Hebrew, Bengali, Gurmukhi, Tamil, Telugu, Thai and Ethiopic are not
+//! covered by the embedded Nerd Fonts. The renderer's font database already
+//! contains the installed system fonts, so on first use we look for a known
+//! family covering each script (Noto, Lohit, Windows and macOS defaults) and
+//! use it for that language.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
+
+use crate::i18n::Language;
+
+/// Preferred families per language, most specific first.
+const CANDIDATES: &[(Language, &[&str])] = &[
+    (Language::He, &["Noto Sans Hebrew", "Arial Hebrew", "DejaVu Sans", "Segoe UI", "Arial"]),
+    (Language::Bn, &["Noto Sans Bengali", "Lohit Bengali", "Nirmala UI", "Vrinda", "Bangla Sangam MN"]),
+    (Language::Pa, &["Noto Sans Gurmukhi", "Lohit Gurmukhi", "Nirmala UI", "Raavi", "Gurmukhi MN"]),
+    (Language::Ta, &["Noto Sans Tamil", "Lohit Tamil", "Nirmala UI", "Latha", "Tamil Sangam MN"]),
+    (Language::Te, &["Noto Sans Telugu", "Lohit Telugu", "Nirmala UI", "Gautami", "Telugu Sangam MN"]),
+    (Language::Th, &["Noto Sans Thai", "Loma", "Leelawadee UI", "Tahoma", "Thonburi"]),
+    (Language::Am, &["Noto Sans Ethiopic", "Abyssinica SIL", "Ebrima", "Kefa"]),
+];
+
+static DETECTED: OnceLock<HashMap<Language, &'static str>> = OnceLock::new();
+
+/// Whether `lang` is written in a script that has no embedded font.
+pub fn needs_system_font(lang: Language) -> bool {
+    CANDIDATES.iter().any(|(l, _)| *l == lang)
+}
+
+/// Installed family that can render `lang`, if any.
+pub fn family_for(lang: Language) -> Option<&'static str> {
+    DETECTED.get_or_init(detect).get(&lang).copied()
+}
+
+fn detect() -> HashMap<Language, &'static str> {
+    let installed: HashSet<String> = match iced_tiny_skia::graphics::text::font_system().write() {
+        Ok(mut fs) => fs
+            .raw()
+            .db()
+            .faces()
+            .flat_map(|face| face.families.iter().map(|(name, _)| name.clone()))
+            .collect(),
+        Err(_) => return HashMap::new(),
+    };
+    pick_families(&installed)
+}
+
+fn pick_families(installed: &HashSet<String>) -> HashMap<Language, &'static str> {
+    CANDIDATES
+        .iter()
+        .filter_map(|(lang, families)| {
+            families.iter().find(|f| installed.contains(**f)).map(|f| (*lang, *f))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pick_families() {
+        let installed: HashSet<String> =
+            ["Tahoma", "Noto Sans Thai", "Lohit Tamil", "Iosevka"].iter().map(|s| s.to_string()).collect();
+        let picked = pick_families(&installed);
+        assert_eq!(picked.get(&Language::Th), Some(&"Noto Sans Thai"));
+        assert_eq!(picked.get(&Language::Ta), Some(&"Lohit Tamil"));
+        assert_eq!(picked.get(&Language::He), None);
+        assert!(needs_system_font(Language::Am));
+        assert!(!needs_system_font(Language::Fr));
+    }
+}
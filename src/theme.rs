@@ -13,6 +13,8 @@ pub enum AccentColor {
     Indigo,
     Violet,
     Amber,
+    /// Any color, picked in Settings; its RGB value lives in the preferences.
+    Custom,
 }
 
 impl AccentColor {
@@ -37,10 +39,12 @@ impl AccentColor {
             AccentColor::Indigo => "Indigo",
             AccentColor::Violet => "Violet",
             AccentColor::Amber => "Amber",
+            AccentColor::Custom => "Custom",
         }
     }
 
-    pub fn color(&self) -> Color {
+    /// The accent as a color, with `custom` standing in for `Custom`.
+    pub fn color(&self, custom: [u8; 3]) -> Color {
         match self {
             AccentColor::Red => Color::from_rgb(0.93, 0.30, 0.35),
             AccentColor::Orange => Color::from_rgb(0.96, 0.52, 0.20),
@@ -50,10 +54,71 @@ impl AccentColor {
             AccentColor::Indigo => Color::from_rgb(0.40, 0.35, 0.90),
             AccentColor::Violet => Color::from_rgb(0.65, 0.45, 0.85),
             AccentColor::Amber => Color::from_rgb(1.0, 0.75, 0.03),
+            AccentColor::Custom => hex(custom[0], custom[1], custom[2]),
         }
     }
 }
 
+/// `#rrggbb`, `rrggbb` or the `#rgb` shorthand, in either case.
+pub fn parse_hex(input: &str) -> Option<[u8; 3]> {
+    let digits = input.trim().trim_start_matches('#');
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    match digits.len() {
+        6 => {
+            let byte = |i: usize| u8::from_str_radix(&digits[i..i + 2], 16).ok();
+            Some([byte(0)?, byte(2)?, byte(4)?])
+        }
+        3 => {
+            let nibble = |i: usize| u8::from_str_radix(&digits[i..i + 1], 16).ok().map(|n| n * 17);
+            Some([nibble(0)?, nibble(1)?, nibble(2)?])
+        }
+        _ => None,
+    }
+}
+
+/// `#RRGGBB`.
+pub fn to_hex(rgb: [u8; 3]) -> String {
+    format!("#{:02X}{:02X}{:02X}", rgb[0], rgb[1], rgb[2])
+}
+
+/// Hue in degrees (0–360), saturation and value in 0–1.
+pub fn rgb_to_hsv(rgb: [u8; 3]) -> [f32; 3] {
+    let [r, g, b] = rgb.map(|c| c as f32 / 255.0);
+    let max = r.max(g).max(b);
+    let delta = max - r.min(g).min(b);
+    let hue = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+    let saturation = if max == 0.0 { 0.0 } else { delta / max };
+    [hue, saturation, max]
+}
+
+/// Inverse of [`rgb_to_hsv`]; out-of-range inputs are wrapped or clamped.
+pub fn hsv_to_rgb(hsv: [f32; 3]) -> [u8; 3] {
+    let hue = hsv[0].rem_euclid(360.0) / 60.0;
+    let (s, v) = (hsv[1].clamp(0.0, 1.0), hsv[2].clamp(0.0, 1.0));
+    let c = v * s;
+    let x = c * (1.0 - (hue % 2.0 - 1.0).abs());
+    let (r, g, b) = match hue as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let m = v - c;
+    [r, g, b].map(|ch| ((ch + m) * 255.0).round() as u8)
+}
+
 // ─── THEME VARIANTS ─────────────────────────────────────────────
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -131,6 +196,78 @@ impl ThemeVariant {
     }
 }
 
+// ─── ROW DENSITY ────────────────────────────────────────────────
+
+/// Spacing of table rows (process list, event log).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum RowDensity {
+    #[default]
+    Comfortable,
+    /// Tighter padding and one point smaller text, for small screens.
+    Compact,
+    /// Rows tall enough to tap, one point larger text. Only touch mode
+    /// uses it, whatever the setting, so it is not offered on its own.
+    Touch,
+}
+
+impl RowDensity {
+    /// The densities offered in Settings.
+    pub const ALL: &'static [RowDensity] = &[RowDensity::Comfortable, RowDensity::Compact];
+
+    /// Vertical row padding, given the comfortable value.
+    pub fn pad_y(self, comfortable: u16) -> u16 {
+        match self {
+            RowDensity::Comfortable => comfortable,
+            RowDensity::Compact => comfortable.saturating_sub(2),
+            RowDensity::Touch => comfortable + 6,
+        }
+    }
+
+    /// Text size, given the comfortable value.
+    pub fn text_size(self, comfortable: u16) -> u16 {
+        match self {
+            RowDensity::Comfortable => comfortable,
+            RowDensity::Compact => comfortable - 1,
+            RowDensity::Touch => comfortable + 1,
+        }
+    }
+}
+
+// ─── COLOR VISION ───────────────────────────────────────────────
+
+/// Replacement for the green → yellow → red status colors, for users who
+/// can't tell them apart. The substitutes follow the Okabe–Ito palette.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ColorVision {
+    #[default]
+    Normal,
+    /// Green-weak: blue → yellow → vermillion.
+    Deuteranopia,
+    /// Red-weak: as deuteranopia, with a brighter orange since reds look dark.
+    Protanopia,
+    /// Blue-yellow-weak: teal → pink → red.
+    Tritanopia,
+}
+
+impl ColorVision {
+    pub const ALL: &'static [ColorVision] =
+        &[ColorVision::Normal, ColorVision::Deuteranopia, ColorVision::Protanopia, ColorVision::Tritanopia];
+
+    /// Good, warning and critical colors, darker on light themes so they
+    /// keep their contrast; `None` keeps the theme's own.
+    fn semantic(self, light: bool) -> Option<[Color; 3]> {
+        Some(match (self, light) {
+            (ColorVision::Normal, _) => return None,
+            (ColorVision::Deuteranopia, false) => [hex(0x56, 0xb4, 0xe9), hex(0xf0, 0xe4, 0x42), hex(0xe3, 0x70, 0x2d)],
+            (ColorVision::Deuteranopia, true) => [hex(0x00, 0x72, 0xb2), hex(0xb8, 0x86, 0x0b), hex(0xd5, 0x5e, 0x00)],
+            (ColorVision::Protanopia, false) => [hex(0x56, 0xb4, 0xe9), hex(0xf0, 0xe4, 0x42), hex(0xff, 0x9a, 0x3c)],
+            (ColorVision::Protanopia, true) => [hex(0x00, 0x72, 0xb2), hex(0xb8, 0x86, 0x0b), hex(0xe0, 0x70, 0x00)],
+            (ColorVision::Tritanopia, false) => [hex(0x3c, 0xc8, 0xc8), hex(0xf4, 0xa6, 0xc6), hex(0xe8, 0x38, 0x4f)],
+            (ColorVision::Tritanopia, true) => [hex(0x00, 0x85, 0x8a), hex(0xc7, 0x50, 0x7f), hex(0xc8, 0x10, 0x2e)],
+        })
+    }
+}
+
 // ─── PALETTE ────────────────────────────────────────────────────
 
 /// All semantic colors the app uses, derived from theme + accent.
@@ -154,10 +291,16 @@ pub struct Palette {
     pub blue: Color,
 }
 
-pub fn build_palette(theme: ThemeVariant, accent: AccentColor) -> Palette {
+/// `custom` is the RGB accent used when `accent` is `Custom`; `vision`
+/// swaps the green/yellow/red status colors.
+pub fn build_palette(theme: ThemeVariant, accent: AccentColor, custom: [u8; 3], vision: ColorVision) -> Palette {
     let base = base_palette(theme);
+    let [green, yellow, red] = vision.semantic(theme.is_light()).unwrap_or([base.green, base.yellow, base.red]);
     Palette {
-        accent: accent.color(),
+        accent: accent.color(custom),
+        green,
+        yellow,
+        red,
         ..base
     }
 }
@@ -368,3 +511,48 @@ fn base_palette(theme: ThemeVariant) -> Palette {
 const fn hex(r: u8, g: u8, b: u8) -> Color {
     Color::from_rgb(r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hex() {
+        assert_eq!(parse_hex("#1e90ff"), Some([0x1e, 0x90, 0xff]));
+        assert_eq!(parse_hex(" 1E90FF "), Some([0x1e, 0x90, 0xff]));
+        assert_eq!(parse_hex("#f80"), Some([0xff, 0x88, 0x00]));
+        assert_eq!(parse_hex("#12345"), None);
+        assert_eq!(parse_hex("#gg0000"), None);
+        assert_eq!(parse_hex("#ééé"), None);
+        assert_eq!(to_hex([0x1e, 0x90, 0xff]), "#1E90FF");
+    }
+
+    #[test]
+    fn test_hsv_round_trip() {
+        assert_eq!(rgb_to_hsv([255, 0, 0]), [0.0, 1.0, 1.0]);
+        assert_eq!(rgb_to_hsv([0, 0, 255]), [240.0, 1.0, 1.0]);
+        assert_eq!(rgb_to_hsv([128, 128, 128])[..2], [0.0, 0.0]);
+        assert_eq!(hsv_to_rgb([120.0, 1.0, 1.0]), [0, 255, 0]);
+        assert_eq!(hsv_to_rgb([360.0, 1.0, 1.0]), [255, 0, 0]);
+        for rgb in [[0x1e, 0x90, 0xff], [0xf3, 0x8b, 0xa8], [0x12, 0x34, 0x56], [0, 0, 0], [255, 255, 255]] {
+            assert_eq!(hsv_to_rgb(rgb_to_hsv(rgb)), rgb);
+        }
+    }
+
+    #[test]
+    fn test_color_vision() {
+        let theme = ThemeVariant::CatppuccinMocha;
+        let normal = build_palette(theme, AccentColor::Blue, [0, 0, 0], ColorVision::Normal);
+        let base = base_palette(theme);
+        assert_eq!((normal.green, normal.yellow, normal.red), (base.green, base.yellow, base.red));
+        for &vision in &ColorVision::ALL[1..] {
+            for theme in [ThemeVariant::CatppuccinMocha, ThemeVariant::CatppuccinLatte] {
+                let p = build_palette(theme, AccentColor::Blue, [0, 0, 0], vision);
+                assert_ne!(p.green, base_palette(theme).green);
+                assert_ne!(p.green, p.red);
+                // Only the status colors change
+                assert_eq!((p.bg, p.text, p.blue), (base_palette(theme).bg, base_palette(theme).text, base_palette(theme).blue));
+            }
+        }
+    }
+}
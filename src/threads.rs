@@ -0,0 +1,134 @@
+//! Per-thread CPU for processes expanded in the Processes tab.
+//!
+//! Only the processes the user expanded are sampled: each tick reads
+//! `/proc/<pid>/task/<tid>/{comm,stat}` and turns the change in
+//! `utime + stime` since the previous tick into a CPU share, normalized to
+//! the whole machine like process CPU. Other platforms report nothing.
+
+use std::collections::HashMap;
+use std::time::Instant;
+
+/// One thread of an expanded process.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThreadInfo {
+    pub tid: u32,
+    pub name: String,
+    /// Share of total CPU capacity, 0–100; 0 on the first sample.
+    pub cpu_usage: f32,
+    /// Same letters as `ProcessInfo::status`.
+    pub status: char,
+}
+
+/// CPU time counters from the previous sample, per thread.
+#[derive(Default)]
+pub struct ThreadSampler {
+    prev_ticks: HashMap<u32, u64>,
+    last_sample: Option<Instant>,
+}
+
+impl ThreadSampler {
+    /// Threads of each of `pids`, busiest first. PIDs that have exited (or
+    /// can't be read) are left out.
+    pub fn sample(&mut self, pids: &[u32], num_cpus: f32) -> HashMap<u32, Vec<ThreadInfo>> {
+        let now = Instant::now();
+        let elapsed = self.last_sample.map(|t| now.duration_since(t).as_secs_f32());
+        self.last_sample = Some(now);
+        let mut ticks_now = HashMap::new();
+        let mut out = HashMap::new();
+        if pids.is_empty() {
+            self.prev_ticks.clear();
+            return out;
+        }
+        let ticks_per_sec = clock_ticks_per_sec();
+        for &pid in pids {
+            let Some(tasks) = read_tasks(pid) else { continue };
+            let mut threads: Vec<ThreadInfo> = tasks
+                .into_iter()
+                .map(|(tid, name, status, ticks)| {
+                    let cpu_usage = match (self.prev_ticks.get(&tid), elapsed) {
+                        (Some(&prev), Some(secs)) if secs > 0.0 => {
+                            ticks.saturating_sub(prev) as f32 / ticks_per_sec / secs / num_cpus.max(1.0) * 100.0
+                        }
+                        _ => 0.0,
+                    };
+                    ticks_now.insert(tid, ticks);
+                    ThreadInfo { tid, name, cpu_usage, status }
+                })
+                .collect();
+            threads.sort_by(|a, b| b.cpu_usage.total_cmp(&a.cpu_usage).then(a.tid.cmp(&b.tid)));
+            out.insert(pid, threads);
+        }
+        self.prev_ticks = ticks_now;
+        out
+    }
+}
+
+/// `(tid, name, status, utime + stime)` of every task of `pid`.
+#[cfg(target_os = "linux")]
+fn read_tasks(pid: u32) -> Option<Vec<(u32, String, char, u64)>> {
+    let dir = std::fs::read_dir(format!("/proc/{pid}/task")).ok()?;
+    let tasks = dir
+        .flatten()
+        .filter_map(|entry| {
+            let tid: u32 = entry.file_name().to_str()?.parse().ok()?;
+            let (status, ticks) = parse_stat(&std::fs::read_to_string(entry.path().join("stat")).ok()?)?;
+            let name = std::fs::read_to_string(entry.path().join("comm")).unwrap_or_default();
+            Some((tid, name.trim_end().to_string(), status, ticks))
+        })
+        .collect();
+    Some(tasks)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_tasks(_pid: u32) -> Option<Vec<(u32, String, char, u64)>> {
+    None
+}
+
+#[cfg(target_os = "linux")]
+fn clock_ticks_per_sec() -> f32 {
+    // SAFETY: sysconf has no preconditions
+    let hz = unsafe { libc::sysconf(libc::_SC_CLK_TCK) };
+    if hz > 0 { hz as f32 } else { 100.0 }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn clock_ticks_per_sec() -> f32 {
+    100.0
+}
+
+/// State and `utime + stime` from a `stat` line. The name field may
+/// contain spaces and parentheses, so fields are counted from the last `)`.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_stat(stat: &str) -> Option<(char, u64)> {
+    let rest = &stat[stat.rfind(')')? + 1..];
+    let fields: Vec<&str> = rest.split_whitespace().collect();
+    let status = fields.first()?.chars().next()?;
+    let utime: u64 = fields.get(11)?.parse().ok()?;
+    let stime: u64 = fields.get(12)?.parse().ok()?;
+    Some((status, utime + stime))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_stat() {
+        let stat = "4242 (Web Content (1)) S 4000 4000 4000 0 -1 4194560 1234 0 0 0 250 40 0 0 20 0 30 0 123456 0 0";
+        assert_eq!(parse_stat(stat), Some(('S', 290)));
+        assert_eq!(parse_stat("garbage"), None);
+        assert_eq!(parse_stat("1 (x) R 0"), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_sample_own_threads() {
+        let pid = std::process::id();
+        let mut sampler = ThreadSampler::default();
+        let threads = sampler.sample(&[pid], 1.0);
+        let own = &threads[&pid];
+        assert!(own.iter().any(|t| t.tid == pid));
+        assert!(own.iter().all(|t| t.cpu_usage == 0.0));
+        assert!(sampler.sample(&[], 1.0).is_empty());
+    }
+}
@@ -1,45 +1,60 @@
 use iced::widget::canvas::Canvas;
 use iced::widget::{
-    button, column, container, progress_bar, row, scrollable, text, text_input,
-    tooltip, Column, Row, Space,
+    button, column, container, mouse_area, progress_bar, row, scrollable, slider, text,
+    text_input, tooltip, Column, Row, Space,
 };
 use iced::keyboard;
-use iced::{Alignment, Background, Border, Color, Element, Length, Shadow, Subscription, Theme, Vector};
-use std::collections::{HashSet, VecDeque};
+use iced::{Alignment, Background, Border, Color, Element, Length, Shadow, Subscription, Task, Theme, Vector};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
 
-use crate::chart::{ChartColors, LineChart};
-use crate::gauge::{GaugeColors, RadialGauge, Sparkline};
-use crate::history::History;
-use crate::i18n::{Language, Strings};
+use crate::anomaly::{Baseline, Sensitivity};
+use crate::chart::{smooth_ema, time_labels, time_markers, ChartColors, ChartMarker, LineChart, RightAxis};
+use crate::crash;
+use crate::diagnostics;
+use crate::diskscan::DiskScan;
+use crate::lanscan::LanScan;
+use crate::elevate::{self, ElevatedKill, ProcessIdentity};
+use crate::exportmeta;
+use crate::fontchain::{font_for_lang, font_for_text};
+use crate::forecast::{self, Horizon};
+use crate::gauge::{GaugeColors, RadialGauge, Sparkline, Trend};
+use crate::hardware::{Inventory, InventoryRead};
+use crate::heatmap::{Heatmap, HeatmapColors};
+use crate::histogram::HistogramChart;
+use crate::history::{self, History, HistoryError, HistoryMetric, HistoryPoint, HistoryWriter, RangeError, RecordingPolicy, StoredEvent};
+use crate::idle::IdleTracker;
+use crate::i18n::{fill, Language, Strings};
 use crate::icons::*;
-use crate::metrics::{Collector, LivePoint, Snapshot};
-use crate::preferences::Preferences;
+use crate::launch;
+use crate::locale::{self, MemUnit, RateUnits, TempUnit};
+use crate::logtail::{LineLevel, LogTail};
+use crate::sysevents::SystemEventFeed;
+use crate::snapdiff::{SnapshotDiff, WatchEvent};
+use crate::sysfonts;
+use crate::metrics::{CollectStage, CollectTimings, IfaceChange, LivePoint, MetricGroup, RecordedMetrics, SensorIssue, SensorStatus, Snapshot, Subsystem, SystemLoad};
+use crate::notify::{NotifyLevel, Urgency};
+use crate::ping::PingMonitor;
+use crate::sockets::PortWatch;
+use crate::preferences::{Preferences, SENSOR_OFFSET_MAX_C};
+use crate::procexport;
+use crate::report::{ReportAlert, ReportInput, ReportPeriod, ReportProcess};
 use crate::ringbuf::RingBuffer;
-use crate::theme::{AccentColor, Palette, ThemeVariant, build_palette};
-use crate::{NERD_FONT_MONO, SARASA_FONT, DEJAVU_FONT, NOTO_SANS_FONT};
+use crate::sampler::{Poll, Sampler};
+use crate::sessions::Session;
+use crate::theme::{self as theme_colors, AccentColor, ColorVision, Palette, RowDensity, ThemeVariant, build_palette};
+use crate::update::{self, UpdateCheck, UpdateStatus};
+use crate::NERD_FONT;
 
-/// Returns the best available monospace font for a given language's script.
-fn font_for_lang(lang: Language) -> iced::Font {
-    match lang {
-        // CJK → Sarasa
-        Language::ZhCn | Language::ZhTw | Language::Ja | Language::Ko => SARASA_FONT,
-        // Arabic / Persian → DejaVu
-        Language::Ar | Language::Fa => DEJAVU_FONT,
-        // Devanagari (Hindi, Marathi) → NotoSans NF
-        Language::Hi | Language::Mr => NOTO_SANS_FONT,
-        // Everything else (Latin, Cyrillic, and scripts without coverage) → Iosevka
-        _ => NERD_FONT_MONO,
-    }
-}
+mod tabs;
+use tabs::{HistoryTab, ProcessesTab};
 
-/// Returns true if the language's native script can be rendered by an embedded font.
+/// Returns true if the language's native script can be rendered by an
+/// embedded font or an installed system font.
 fn has_native_font(lang: Language) -> bool {
-    !matches!(lang,
-        Language::He | Language::Bn | Language::Pa | Language::Ta
-        | Language::Te | Language::Th | Language::Am
-    )
+    !sysfonts::needs_system_font(lang) || sysfonts::family_for(lang).is_some()
 }
 
 /// Detect if the system prefers dark mode.
@@ -67,24 +82,38 @@ fn system_prefers_dark() -> bool {
     true
 }
 
-/// Send a desktop notification (non-blocking, best-effort).
-fn send_notification(title: &str, body: &str) {
-    let _ = notify_rust::Notification::new()
-        .summary(title)
-        .body(body)
-        .appname("Digger")
-        .timeout(notify_rust::Timeout::Milliseconds(5000))
-        .show();
-}
-
 // ─── ANIMATION CONSTANTS ────────────────────────────────────────
 const ANIM_TICK_MS: u64 = 33; // ~30fps for animations
+const ANIM_IDLE_TICK_MS: u64 = 250; // 4fps once tweens settle; only the heartbeat moves
 const TWEEN_SPEED: f32 = 0.12; // lerp factor per animation tick
 const FADE_SPEED: f32 = 0.08; // fade-in speed per tick
 const PULSE_SPEED: f32 = 0.05; // pulse cycle speed
 
 const EVENT_LOG_MAX: usize = 100;
+/// How many recent entries a new event is checked against for repeats.
+const EVENT_DEDUP_LOOKBACK: usize = 8;
+/// System log entries added to the event log per tick; the rest are summarized.
+const SYSTEM_EVENTS_PER_TICK: usize = 5;
 const HISTORY_RELOAD_INTERVAL_SECS: f64 = 10.0;
+/// The weekly CPU heatmap aggregates a week of rows, so it reloads slowly.
+const HEATMAP_RELOAD_INTERVAL_SECS: f64 = 300.0;
+const HEATMAP_RANGE_SECS: f64 = 7.0 * 86400.0;
+/// Capacity trends are refitted this often.
+const FORECAST_INTERVAL_SECS: f64 = 300.0;
+/// Step of the sensor calibration buttons, °C.
+const SENSOR_OFFSET_STEP_C: f32 = 0.5;
+/// Panels fed by a background poller show its age past this many
+/// seconds; the slowest poller (`powermetrics`) delivers every ~6 s.
+const STALE_AFTER_SECS: u64 = 15;
+/// A poller this far behind is logged as hung, once until it recovers.
+const STALE_EVENT_SECS: u64 = 60;
+/// Snapshots further apart than this mean the machine was suspended; the
+/// slowest refresh interval is 5 s.
+const SUSPEND_GAP_SECS: f64 = 30.0;
+/// Ticks of collection timings kept for the About panel.
+const TIMINGS_WINDOW: usize = 60;
+/// Latest history rows included in a diagnostics bundle.
+const DIAGNOSTICS_HISTORY_ROWS: u32 = 500;
 
 const HISTORY_RANGES: &[(f64, &str)] = &[
     (60.0, "1m"),
@@ -93,8 +122,64 @@ const HISTORY_RANGES: &[(f64, &str)] = &[
     (3600.0, "1h"),
     (86400.0, "24h"),
 ];
+/// Longest preset range that can follow new samples live; longer ones
+/// reload on the interval.
+const HISTORY_FOLLOW_MAX_SECS: f64 = 3600.0;
+/// History shown on each side of an event opened from the Event Log.
+const HISTORY_JUMP_SECS: f64 = 600.0;
+
+/// Look-back windows offered by the Changes panel.
+const DIFF_WINDOWS: &[(f64, &str)] = &[
+    (10.0, "10s"),
+    (30.0, "30s"),
+    (60.0, "1m"),
+];
 
 const REFRESH_OPTIONS: &[u64] = &[1, 2, 5];
+/// Seconds per Overview panel offered for the panel rotation.
+const OVERVIEW_CYCLE_OPTIONS: &[u64] = &[10, 15, 30, 60];
+/// Newest live points drawn in the Overview sidebar sparklines.
+const SPARKLINE_POINTS: usize = 60;
+/// Connections listed under the selected process before "+N more".
+const MAX_DETAIL_CONNECTIONS: usize = 8;
+/// Live points compared for the gauge trend arrows, and the change in
+/// percentage points below which the arrow stays hidden.
+const TREND_RECENT_POINTS: usize = 5;
+const TREND_BASELINE_POINTS: usize = 30;
+const TREND_DEAD_BAND: f32 = 3.0;
+/// Smoothing factors offered in Settings, lightest first.
+const SMOOTHING_ALPHAS: &[f32] = &[0.5, 0.3, 0.15, 0.05];
+/// Live buffer sizes offered in Settings; preferences clamp to 30–1000.
+const LIVE_BUFFER_OPTIONS: &[usize] = &[60, 120, 300, 600, 900];
+/// Whole-UI zoom in kiosk mode, to read the wall from across the room.
+const KIOSK_SCALE: f64 = 1.3;
+/// Whole-UI zoom in touch mode, so buttons are big enough for a finger.
+const TOUCH_SCALE: f64 = 1.2;
+/// How far the process list must be pulled down, at the top, to refresh.
+const PULL_REFRESH_PX: f32 = 80.0;
+
+fn process_scroll_id() -> scrollable::Id {
+    scrollable::Id::new("processes")
+}
+
+/// Container of the process rows, whose bounds limit pull-to-refresh.
+fn process_rows_id() -> container::Id {
+    container::Id::new("process-rows")
+}
+
+/// The process table header scrolls horizontally in step with the rows.
+fn process_header_scroll_id() -> scrollable::Id {
+    scrollable::Id::new("process-header")
+}
+
+fn process_filter_id() -> text_input::Id {
+    text_input::Id::new("process-filter")
+}
+
+/// Clear keyboard focus: focusing an id no widget has unfocuses the rest.
+fn unfocus() -> Task<Message> {
+    text_input::focus(text_input::Id::unique())
+}
 
 // ─── EVENT LOG ──────────────────────────────────────────────────
 
@@ -105,6 +190,74 @@ struct LogEvent {
     icon: &'static str,
     message: String,
     severity: EventSeverity,
+    source: EventSource,
+    /// Identifies repeats of this event.
+    key: EventKey,
+}
+
+/// An event log entry; repeats of the same event are folded into it.
+#[derive(Clone, Debug)]
+struct LogEntry {
+    /// The latest occurrence.
+    event: LogEvent,
+    /// When the event first occurred.
+    first_seen: Arc<str>,
+    count: u32,
+    /// Epoch seconds of the latest occurrence, for opening it in History.
+    at: f64,
+}
+
+impl LogEntry {
+    fn new(event: LogEvent, at: f64) -> Self {
+        Self { first_seen: Arc::clone(&event.timestamp), event, count: 1, at }
+    }
+}
+
+/// What makes two events the same: which message they are (`template`)
+/// and what it is about (`subject`: a process, interface, GPU...), but not
+/// the figures in the text. Events with no particular subject use `""`.
+#[derive(Clone, Debug, PartialEq)]
+struct EventKey {
+    template: &'static str,
+    subject: String,
+}
+
+impl EventKey {
+    fn new(template: &'static str, subject: impl Into<String>) -> Self {
+        Self { template, subject: subject.into() }
+    }
+}
+
+impl LogEvent {
+    /// Events repeat when they share a source and key, e.g. two CPU spikes
+    /// with different percentages.
+    fn repeats(&self, other: &LogEvent) -> bool {
+        self.source == other.source && self.key == other.key
+    }
+}
+
+/// Append an event to the bounded log. A repeat of one of the last few
+/// entries is counted in that entry, where it stands, instead of adding a row.
+fn push_event(log: &mut VecDeque<LogEntry>, event: LogEvent) {
+    let at = chrono::Utc::now().timestamp_millis() as f64 / 1000.0;
+    let start = log.len().saturating_sub(EVENT_DEDUP_LOOKBACK);
+    if let Some(entry) = log.range_mut(start..).rev().find(|entry| entry.event.repeats(&event)) {
+        entry.count += 1;
+        entry.at = at;
+        entry.event = event;
+        return;
+    }
+    if log.len() >= EVENT_LOG_MAX {
+        log.pop_front();
+    }
+    log.push_back(LogEntry::new(event, at));
+}
+
+/// Where an event came from: Digger's own detectors or the OS log.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum EventSource {
+    Digger,
+    System,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -114,6 +267,17 @@ enum EventSeverity {
     Critical,
 }
 
+impl EventSeverity {
+    /// How the event is presented by the OS notification center.
+    fn urgency(self) -> Urgency {
+        match self {
+            EventSeverity::Info => Urgency::Low,
+            EventSeverity::Warning => Urgency::Normal,
+            EventSeverity::Critical => Urgency::Critical,
+        }
+    }
+}
+
 /// Compute a heartbeat BPM (80–160) based on system load.
 /// Resting heart rate is 80 BPM; CPU and memory usage increase it.
 fn compute_heartbeat_bpm(cpu: f32, mem_pct: f32) -> f32 {
@@ -138,41 +302,179 @@ fn dynamic_color(base: Color, intensity: f32) -> Color {
 pub enum Message {
     Tick,
     AnimTick,
+    /// The window close button was pressed.
+    CloseRequested,
     TabSelected(Tab),
     OverviewSection(OverviewPanel),
     ProcessFilterChanged(String),
+    /// Process list scrolled to this offset.
+    ProcessesScrolled(scrollable::Viewport),
+    /// Enter in the process filter: move on to the rows.
+    FocusProcessTable,
+    /// Mouse pressed on a process table column divider.
+    ColumnResizeStart(ProcessColumn),
+    /// Cursor moved (window x coordinate) while a divider is held.
+    ColumnResizeMove(f32),
+    ColumnResizeEnd,
+    ToggleCommandColumn,
     SortBy(ProcessSort),
     ToggleGrouped,
+    /// Collapse or expand a section of the grouped process list.
+    ToggleProcessGroup(ProcessGroup),
+    /// Select a row in the process table (clicking it again clears the selection).
+    SelectProcess(u32),
+    /// Show or hide the threads of a process under its row.
+    ToggleThreads(u32),
+    /// Pin or unpin processes with this name to the top of the table.
+    TogglePinned(Arc<str>),
+    /// Jump to the Processes tab sorted by the given column with `pid` selected.
+    ShowProcess(u32, ProcessSort),
+    /// Sort the GPU process table (Cpu sorts by GPU utilization there).
+    SortGpuProcesses(ProcessSort),
     HistoryRangeSelected(usize),
+    HistoryFromInput(String),
+    HistoryToInput(String),
+    ApplyHistoryRange,
+    ToggleHistoryFollow,
+    ToggleChartBand(HistoryChart),
+    /// Open the History tab around an event, given in epoch seconds.
+    JumpToHistory(f64),
+    HistogramMetricSelected(HistoryMetric),
+    DiffWindowSelected(usize),
     // Settings
     ToggleSettings,
     SettingsPanelSelected(SettingsPanel),
     SetRefreshInterval(u64),
-    ToggleTempUnit,
+    SetTempUnit(TempUnit),
+    /// Change a sensor's calibration offset by the given °C.
+    AdjustSensorOffset(String, f32),
+    ResetSensorOffset(String),
+    ToggleRateBits,
+    ToggleRateSi,
+    ToggleUtcTimes,
+    SetProcessMemUnit(MemUnit),
+    ToggleProcessCpuPerCore,
+    ToggleSearchAllProcesses,
+    ToggleChartSmoothing,
+    SetChartSmoothingAlpha(f32),
+    ToggleOfferElevation,
+    ToggleReadOnly,
+    ToggleTouchMode,
+    /// Finger events over the Processes tab, for pull-to-refresh.
+    Touch(iced::touch::Event),
+    /// A finger went down at this point with the list at its top; the
+    /// bounds of the rows decide whether it starts a pull.
+    PullPressed(iced::touch::Finger, iced::Point, Option<iced::Rectangle>),
+    ToggleSchemaSidecar,
+    ToggleRecorded(MetricGroup),
+    ToggleAdaptiveRecording,
+    SetAdaptiveRecordingThreshold(f32),
+    SetRowDensity(RowDensity),
+    ToggleKioskMode,
+    ToggleOverviewCycle,
+    SetOverviewCycle(u64),
+    SetLiveBufferSize(usize),
+    OverviewHovered(bool),
+    /// Show the next Overview panel of the rotation.
+    CycleOverview,
+    SetNotifyLevel(NotifyLevel),
+    SetAnomalySensitivity(Sensitivity),
+    SetForecastHorizon(Horizon),
     ToggleSection(SettingsSection),
     SetTheme(ThemeVariant),
     SetAccent(AccentColor),
+    /// Picker sliders moved: hue in degrees, saturation and value in 0–1.
+    SetCustomAccentHsv([f32; 3]),
+    /// Picker slider released.
+    SaveCustomAccent,
+    CustomAccentInput(String),
+    ApplyCustomAccentHex,
     ToggleDyslexicFont,
+    SetColorVision(ColorVision),
     // Export
     ExportCsv,
     ExportJson,
+    ExportProcesses { json: bool },
+    CreateDiagnosticsBundle,
+    OpenCrashReport,
+    WindowMoved(iced::Point),
+    WindowResized(iced::window::Id, iced::Size),
+    WindowGeometry { size: iced::Size, maximized: bool },
+    CheckForUpdates,
+    ToggleUpdateChecks,
+    OpenReleasePage,
+    DismissCrashReport,
+    RetryHistory,
+    ResetHistory,
+    OpenHistoryBackup,
+    DismissHistoryBackup,
+    SaveChartPng(ChartId),
+    CopyChartData(ChartId),
+    CopySystemSummary,
+    GenerateReport(ReportPeriod),
+    /// Where the report was saved, or why it failed.
+    ReportGenerated(Result<PathBuf, String>),
     // Process management
     KillProcess(u32),
+    /// Retry the denied kill in `elevation_request` with administrator rights.
+    ConfirmElevatedKill,
+    CancelElevatedKill,
+    // Disk usage scanner
+    ScanDisk(String),
+    CloseDiskScan,
+    ScanLan,
+    CloseLanScan,
     // Alerts
     SetCpuAlertThreshold(f32),
     SetMemAlertThreshold(f32),
+    SetVramAlertThreshold(f32),
+    SetGpuTempAlertThreshold(f32),
+    SetTempAlertThreshold(f32),
+    /// Threshold typed in the current temperature unit.
+    TempThresholdInput(TempAlert, String),
+    ApplyTempThreshold(TempAlert),
+    ToggleNetNotifications,
+    ToggleSystemLogEvents,
+    TogglePortWatch,
+    TogglePortWatchNotify,
+    PortAllowInputChanged(String),
+    AddPortAllow,
+    RemovePortAllow(String),
     // Language
     SetLanguage(Language),
+    // Idle report
+    ResetIdleReport,
+    // Log file tail
+    LogTailPathChanged(String),
+    PingHostChanged(String),
+    ApplyPingHost,
+    WatchInputChanged(String),
+    AddWatch,
+    RemoveWatch(String),
+    ApplyLogTail,
+    LogTailFilterChanged(String),
     // Keyboard
     KeyPressed(keyboard::Key, keyboard::Modifiers),
 }
 
+/// Where Tab moves on from. The Processes tab steps through its filter
+/// and rows before the next tab; other tabs go straight to the next one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KeyboardFocus {
+    Tabs,
+    Filter,
+    Table,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Tab {
     Overview,
     Processes,
     History,
     EventLog,
+    Hardware,
+    /// Only reachable while a log file is being followed.
+    Logs,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -183,6 +485,63 @@ pub enum OverviewPanel {
     Disk,
     Temperature,
     Gpu,
+    Power,
+    Changes,
+    Sessions,
+}
+
+impl OverviewPanel {
+    /// Sidebar order.
+    const ALL: [OverviewPanel; 9] = [
+        OverviewPanel::Cpu,
+        OverviewPanel::Memory,
+        OverviewPanel::Disk,
+        OverviewPanel::Network,
+        OverviewPanel::Temperature,
+        OverviewPanel::Gpu,
+        OverviewPanel::Power,
+        OverviewPanel::Changes,
+        OverviewPanel::Sessions,
+    ];
+}
+
+/// Temperature alert thresholds that can be typed in the display unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TempAlert {
+    Sensor,
+    Gpu,
+}
+
+impl TempAlert {
+    /// Accepted range, °C.
+    fn range(self) -> (f32, f32) {
+        match self {
+            TempAlert::Sensor => (40.0, 120.0),
+            TempAlert::Gpu => (50.0, 110.0),
+        }
+    }
+}
+
+/// Charts with "Copy data" and "Save as PNG" buttons. The exported data is
+/// rebuilt from the live buffer or loaded history when a button is pressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChartId {
+    Cpu,
+    CpuSplit,
+    Memory,
+    Network,
+    Latency,
+    Power,
+    History(HistoryChart),
+}
+
+/// History tab charts that can switch between averages and a min/max band.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HistoryChart {
+    Cpu,
+    Memory,
+    Network,
+    Power,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -191,6 +550,204 @@ pub enum ProcessSort {
     Name,
     Cpu,
     Memory,
+    /// Bytes read and written in the last tick; process table only.
+    DiskIo,
+    /// Process table only.
+    Threads,
+}
+
+/// Process table columns whose width can be dragged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessColumn {
+    Pid,
+    Ppid,
+    Name,
+    Cpu,
+    Memory,
+    MemPct,
+    Status,
+    Threads,
+    /// Full command line; optional, shown after the name.
+    Command,
+    DiskIo,
+    /// Busiest GPU engine; shown while a backend reports it (Windows).
+    Gpu,
+}
+
+impl ProcessColumn {
+    /// Storage order of saved widths; new columns go at the end.
+    const ALL: [ProcessColumn; 11] = [
+        ProcessColumn::Pid,
+        ProcessColumn::Ppid,
+        ProcessColumn::Name,
+        ProcessColumn::Cpu,
+        ProcessColumn::Memory,
+        ProcessColumn::MemPct,
+        ProcessColumn::Status,
+        ProcessColumn::Threads,
+        ProcessColumn::Command,
+        ProcessColumn::DiskIo,
+        ProcessColumn::Gpu,
+    ];
+
+    fn default_width(self) -> f32 {
+        match self {
+            ProcessColumn::Pid => 60.0,
+            ProcessColumn::Ppid => 50.0,
+            ProcessColumn::Name => 180.0,
+            ProcessColumn::Cpu => 70.0,
+            ProcessColumn::Memory => 90.0,
+            ProcessColumn::MemPct => 55.0,
+            ProcessColumn::Status => 25.0,
+            ProcessColumn::Threads => 40.0,
+            ProcessColumn::Command => 320.0,
+            ProcessColumn::DiskIo => 80.0,
+            ProcessColumn::Gpu => 55.0,
+        }
+    }
+}
+
+/// Current process table column widths, in `ProcessColumn::ALL` order.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ColumnWidths([f32; ProcessColumn::ALL.len()]);
+
+impl ColumnWidths {
+    const MIN: f32 = 24.0;
+    /// Kill button column; not resizable.
+    const ACTION: f32 = 60.0;
+    /// Grab handle at the right edge of each header cell.
+    const DIVIDER: f32 = 6.0;
+
+    /// Widths saved in preferences. Columns added since they were saved
+    /// keep their defaults; a longer list than expected is ignored.
+    fn from_prefs(saved: &[f32]) -> Self {
+        let mut widths = Self::default();
+        if saved.len() <= widths.0.len() {
+            for (w, s) in widths.0.iter_mut().zip(saved) {
+                *w = s.max(Self::MIN);
+            }
+        }
+        widths
+    }
+
+    fn get(&self, col: ProcessColumn) -> f32 {
+        self.0[col as usize]
+    }
+
+    fn set(&mut self, col: ProcessColumn, width: f32) {
+        self.0[col as usize] = width.max(Self::MIN);
+    }
+
+    /// Width of a full row: the visible columns, the action column, row
+    /// spacing and padding.
+    fn total(&self, show_command: bool, show_gpu: bool) -> f32 {
+        let hidden: Vec<ProcessColumn> = [(ProcessColumn::Command, show_command), (ProcessColumn::Gpu, show_gpu)]
+            .into_iter()
+            .filter_map(|(col, shown)| (!shown).then_some(col))
+            .collect();
+        let visible = self.0.len() - hidden.len();
+        let sum: f32 = self.0.iter().sum::<f32>() - hidden.iter().map(|&col| self.get(col)).sum::<f32>();
+        sum + Self::ACTION + 2.0 * visible as f32 + 20.0
+    }
+}
+
+impl Default for ColumnWidths {
+    fn default() -> Self {
+        Self(ProcessColumn::ALL.map(ProcessColumn::default_width))
+    }
+}
+
+/// Per-render settings shared by every process table row.
+struct TableLayout {
+    density: RowDensity,
+    columns: ColumnWidths,
+    show_command: bool,
+    /// Multiplier from the collected CPU share to the displayed one.
+    cpu_scale: f32,
+    /// Seconds between refreshes, which per-process disk bytes cover.
+    refresh_secs: u64,
+    /// GPU utilization by PID; empty, and the column hidden, when no
+    /// backend reports it.
+    gpu: HashMap<u32, f32>,
+    /// Hide the kill buttons.
+    read_only: bool,
+}
+
+impl TableLayout {
+    fn show_gpu(&self) -> bool {
+        !self.gpu.is_empty()
+    }
+
+    /// Width of a full row with the columns currently shown.
+    fn width(&self) -> f32 {
+        self.columns.total(self.show_command, self.show_gpu())
+    }
+}
+
+/// A finger pulling the process list down from its top.
+#[derive(Debug, Clone, Copy)]
+struct PullGesture {
+    finger: iced::touch::Finger,
+    start_y: f32,
+    distance: f32,
+}
+
+/// A column divider being dragged.
+#[derive(Debug, Clone, Copy)]
+struct ColumnResize {
+    column: ProcessColumn,
+    start_width: f32,
+    /// Cursor x at the first move event; mouse presses don't carry a position.
+    anchor_x: Option<f32>,
+}
+
+/// Sections of the grouped process list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProcessGroup {
+    Apps,
+    Background,
+    System,
+}
+
+impl ProcessGroup {
+    const ALL: [ProcessGroup; 3] = [ProcessGroup::Apps, ProcessGroup::Background, ProcessGroup::System];
+
+    /// Name stored in preferences.
+    fn key(self) -> &'static str {
+        match self {
+            ProcessGroup::Apps => "apps",
+            ProcessGroup::Background => "background",
+            ProcessGroup::System => "system",
+        }
+    }
+
+    /// Processes owned by another user are System; the user's own are Apps
+    /// when they have a window, Background otherwise.
+    fn of(proc: &crate::metrics::ProcessInfo, current_uid: u32) -> Self {
+        if proc.uid != current_uid {
+            ProcessGroup::System
+        } else if proc.is_desktop_app {
+            ProcessGroup::Apps
+        } else {
+            ProcessGroup::Background
+        }
+    }
+
+    fn current_uid() -> u32 {
+        // SAFETY: libc::getuid() is a simple POSIX syscall that returns the real
+        // user ID of the calling process. It is always safe to call, has no side
+        // effects, cannot fail, and requires no special resources or permissions.
+        // It is used here to separate user-owned processes from system processes.
+        #[cfg(unix)]
+        let uid = unsafe { libc::getuid() };
+        // On Windows, metrics.rs sets uid=0 for user processes and uid=1
+        // for system processes (SYSTEM/LOCAL SERVICE/NETWORK SERVICE).
+        // uid=0 makes the grouping logic work correctly:
+        // uid != 0 → System, is_desktop_app → Apps, else → Background.
+        #[cfg(not(unix))]
+        let uid = 0u32;
+        uid
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -202,6 +759,23 @@ pub enum SettingsPanel {
     About,
 }
 
+impl SettingsPanel {
+    const ALL: [SettingsPanel; 5] = [
+        SettingsPanel::General,
+        SettingsPanel::Appearance,
+        SettingsPanel::Accessibility,
+        SettingsPanel::Language,
+        SettingsPanel::About,
+    ];
+
+    /// The next panel in sidebar order, or the previous one; wraps around.
+    fn cycle(self, forward: bool) -> Self {
+        let i = Self::ALL.iter().position(|&p| p == self).unwrap_or(0);
+        let n = Self::ALL.len();
+        Self::ALL[if forward { (i + 1) % n } else { (i + n - 1) % n }]
+    }
+}
+
 /// Identifiers for collapsible settings sections.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SettingsSection {
@@ -214,37 +788,130 @@ pub enum SettingsSection {
     Accent,
     // Accessibility
     Fonts,
+    ColorVision,
     // About
     Version,
     FontInfo,
     SystemInfo,
+    Timings,
+    Updates,
+    Diagnostics,
 }
 
 // ─── APP STATE ──────────────────────────────────────────────────
 
 pub struct Digger {
-    collector: Collector,
+    /// Collection runs on its own thread; see `sampler`.
+    sampler: Sampler,
+    /// Read connection for charts, exports and reports.
     history: History,
+    /// Owns the write connection; inserts run on its thread.
+    history_writer: HistoryWriter,
     current: Option<Arc<Snapshot>>,
     live_buffer: RingBuffer<LivePoint>,
-    live_max: usize,
+    /// Snapshots from the last `DIFF_WINDOWS` seconds, oldest first.
+    recent_snapshots: VecDeque<Arc<Snapshot>>,
+    diff_window_idx: usize,
+    /// Current snapshot against the selected window's baseline; recomputed
+    /// on each tick rather than each frame.
+    snapshot_diff: Option<SnapshotDiff>,
     tab: Tab,
     overview_panel: OverviewPanel,
-    process_filter: String,
-    process_sort: ProcessSort,
-    process_sort_asc: bool,
-    process_grouped: bool,
-    history_range_idx: usize,
-    history_points: Vec<crate::history::HistoryPoint>,
+    process_view: ProcessesTab,
+    /// PID highlighted in the process table.
+    selected_pid: Option<u32>,
+    /// Executable, working directory and output targets of `selected_pid`.
+    selected_details: Option<crate::procdetail::ProcessDetails>,
+    gpu_proc_sort: ProcessSort,
+    gpu_proc_sort_asc: bool,
+    /// Background largest-directories scan for the Disk panel.
+    disk_scan: Option<DiskScan>,
+    lan_scan: Option<LanScan>,
+    /// Log file path as typed in Settings (applied on submit).
+    log_tail_path: String,
+    log_tail_filter: String,
+    /// Process names watched for start/exit (see `snapdiff::watch_events`).
+    watched_processes: Vec<String>,
+    /// Listening ports from the previous tick, when the port watch is on
+    port_watch: Option<PortWatch>,
+    port_watch_notify: bool,
+    port_allowlist: Vec<String>,
+    port_allow_input: String,
+    watch_input: String,
+    /// Follower for the Logs tab; `None` hides the tab.
+    log_tail: Option<LogTail>,
+    /// Host typed in Settings, applied on submit
+    ping_host: String,
+    /// Pings for the connection quality widget, when a host is set
+    ping: Option<PingMonitor>,
+    /// Kiosk mode is active: fullscreen, zoomed, no settings button, only
+    /// Esc as a shortcut. `kiosk_mode` is the saved setting; `--kiosk`
+    /// turns kiosk on for one run without it.
+    kiosk: bool,
+    kiosk_mode: bool,
+    /// Rotate the Overview panels (always on in kiosk mode), paused while
+    /// the pointer is over them.
+    overview_cycle: bool,
+    overview_cycle_secs: u64,
+    overview_hovered: bool,
+    /// Window geometry restored on the next start. Size and position are
+    /// those of the restored (not maximized) window.
+    window_size: iced::Size,
+    window_position: Option<iced::Point>,
+    window_maximized: bool,
+    /// Weekly background release check.
+    check_updates: bool,
+    last_update_check: f64,
+    update_check: Option<UpdateCheck>,
+    update_status: Option<UpdateStatus>,
+    history_view: HistoryTab,
     // Settings
     show_settings: bool,
     settings_panel: SettingsPanel,
+    keyboard_focus: KeyboardFocus,
     refresh_interval_secs: u64,
-    temp_celsius: bool,
+    temp_unit: TempUnit,
+    sensor_offsets: BTreeMap<String, f32>,
+    process_mem_unit: MemUnit,
+    process_cpu_per_core: bool,
+    /// Apply the process search in the collector, before the process limit.
+    search_all_processes: bool,
+    chart_smoothing: bool,
+    chart_smoothing_alpha: f32,
+    offer_elevation: bool,
+    /// A denied kill the user may retry as administrator: PID and which
+    /// process it belonged to.
+    elevation_request: Option<(u32, ProcessIdentity)>,
+    elevated_kill: Option<ElevatedKill>,
+    /// Read-only mode hides kill buttons. `read_only` is the saved setting;
+    /// `--read-only` locks it on for one run.
+    read_only: bool,
+    read_only_flag: bool,
+    touch_mode: bool,
+    pull: Option<PullGesture>,
+    /// A pull-to-refresh asked for a snapshot; picked up on the next
+    /// animation frame once it is collected.
+    refresh_pending: bool,
+    export_schema_sidecar: bool,
+    /// Optional metric groups being collected and recorded.
+    recorded: RecordedMetrics,
+    adaptive_recording: bool,
+    adaptive_recording_threshold: f32,
+    /// Thins out the snapshots sent to the history writer.
+    recording_policy: RecordingPolicy,
+    row_density: RowDensity,
     collapsed_sections: HashSet<SettingsSection>,
     // Theme
     theme_variant: ThemeVariant,
+    /// Theme follows the system light/dark setting until one is picked.
+    auto_theme: bool,
     accent_color: AccentColor,
+    custom_accent: [u8; 3],
+    /// Picker state; kept apart from `custom_accent` so the hue survives
+    /// dragging saturation or value to zero.
+    custom_accent_hsv: [f32; 3],
+    custom_accent_input: String,
+    color_vision: ColorVision,
     pal: Palette,
     // Language
     language: Language,
@@ -256,22 +923,49 @@ pub struct Digger {
     retention_hours: u64,
     cpu_alert_threshold: f32,
     mem_alert_threshold: f32,
+    vram_alert_threshold: f32,
+    /// °C.
+    gpu_temp_alert_threshold: f32,
+    temp_alert_threshold: f32,
+    /// Thresholds being typed, in the display unit.
+    gpu_temp_alert_input: String,
+    temp_alert_input: String,
+    notify_net_changes: bool,
+    notify_level: NotifyLevel,
+    /// OS error feed merged into the event log; `None` when disabled.
+    system_events: Option<SystemEventFeed>,
     // Status message for user feedback
     status_message: Option<String>,
+    /// Panic report from the previous run, offered until opened or dismissed.
+    crash_report: Option<PathBuf>,
+    /// Where a damaged history database was moved, shown until dismissed.
+    history_backup: Option<PathBuf>,
     // ─── Health & Events ───
     /// Health score 0–100 (higher is better)
     health_score: f32,
     /// Recent event log entries (bounded VecDeque, opt #5)
-    event_log: VecDeque<LogEvent>,
-    /// Previous CPU reading for spike detection
+    event_log: VecDeque<LogEntry>,
+    /// Previous CPU reading for threshold crossings
     prev_cpu: f32,
-    /// Previous memory % for leak detection
+    /// Previous memory % for threshold crossings
     prev_mem_pct: f32,
+    /// Previous VRAM % and temperature of each GPU, by index
+    prev_gpu: Vec<(f32, f32)>,
+    /// Rolling baselines for spike detection
+    cpu_baseline: Baseline,
+    mem_baseline: Baseline,
+    anomaly_sensitivity: Sensitivity,
+    /// Per-process CPU time accumulated while the session is idle
+    idle_tracker: IdleTracker,
     // ─── Animation state ───
     /// Smoothly interpolated CPU usage for display
     anim_cpu: f32,
     /// Smoothly interpolated memory percentage for display
     anim_mem_pct: f32,
+    /// Lowest and highest CPU usage since launch, for the gauge
+    session_cpu: (f32, f32),
+    /// Lowest and highest memory percentage since launch
+    session_mem: (f32, f32),
     /// Smoothly interpolated per-core CPU values
     anim_cores: Vec<f32>,
     /// Page fade-in opacity (0.0 → 1.0)
@@ -280,14 +974,41 @@ pub struct Digger {
     pulse_phase: f32,
     /// Heart beat phase (0.0 → 2*PI), advances based on BPM
     heart_phase: f32,
+    /// Tweens have converged and nothing is pulsing; the animation tick
+    /// drops to `ANIM_IDLE_TICK_MS` until new data arrives.
+    anim_settled: bool,
     /// Previous tab (to detect page transitions)
     prev_tab: Tab,
     /// Previous settings visibility
     prev_show_settings: bool,
-    /// Opt #7: Timestamp of last history reload to throttle SQL queries.
-    history_last_reload: f64,
+    /// Projected time (Unix seconds) each drive, by mount point, fills up.
+    disk_full_at: HashMap<String, f64>,
+    /// Projected time swap runs out.
+    swap_full_at: Option<f64>,
+    forecast_last_update: f64,
+    forecast_horizon: Horizon,
+    /// Hardware tab inventory, read the first time the tab is shown.
+    hardware: Option<Inventory>,
+    hardware_read: Option<InventoryRead>,
+    /// Logged-in users as of the last background read, oldest login first.
+    sessions: Vec<Session>,
+    /// Whether `sessions` holds a first read; logins before it aren't logged.
+    sessions_loaded: bool,
+    /// Background pollers logged as stale and not yet recovered.
+    stale_subsystems: HashSet<Subsystem>,
+    /// When the last suspend ended (epoch secs); poller ages are not
+    /// counted from before it.
+    resumed_at: f64,
+    /// Forecasts already reported as inside the horizon.
+    forecast_warned: HashSet<String>,
+    /// Collection timings of the last `TIMINGS_WINDOW` ticks.
+    collect_timings: RingBuffer<CollectTimings>,
+    /// Stages currently over budget, so a slow stretch is logged once.
+    slow_stages: HashSet<CollectStage>,
     /// Opt #10: Pending snapshots for batched SQLite inserts.
     pending_snapshots: Vec<Arc<Snapshot>>,
+    /// Newest event log time handed to the history writer.
+    events_stored_until: f64,
     /// Opt #10: Timestamp of last DB flush.
     last_db_flush: f64,
     // ─── Cached UI strings (avoid format! every frame) ───
@@ -295,6 +1016,8 @@ pub struct Digger {
     cached_tab_processes: String,
     cached_tab_history: String,
     cached_tab_events: String,
+    cached_tab_hardware: String,
+    cached_tab_logs: String,
     cached_digger_label: String,
     cached_digger_label_settings: String,
     /// Cached theme preview palettes (rebuilt only when accent color changes).
@@ -303,21 +1026,45 @@ pub struct Digger {
 }
 
 impl Digger {
-    pub fn new() -> Self {
-        let prefs = Preferences::load();
-        let live_max = prefs.live_buffer_size;
-        let mut collector = Collector::with_process_limit(prefs.process_limit);
+    pub fn new(prefs: Preferences, kiosk: bool, read_only_flag: bool) -> Self {
+        locale::set_language(prefs.language);
+        locale::set_rate_units(prefs.rate_units());
+        locale::set_utc(prefs.utc_times);
+        let temp_unit = prefs.temp_unit;
         let mut history = History::open();
+        history.set_retention_hours(prefs.retention_hours);
+        let mut history_writer = HistoryWriter::open(prefs.retention_hours);
+        history_writer.set_recorded(prefs.recorded_metrics);
+        let mut event_log = VecDeque::with_capacity(EVENT_LOG_MAX);
+        let history_backup = history.recovered_backup.clone();
+        if let Some(backup) = &history_backup {
+            push_event(&mut event_log, LogEvent {
+                timestamp: Arc::from(locale::clock_now()),
+                icon: ICON_WARNING,
+                message: format!(
+                    "History database was corrupt and has been reset; the old file was saved as {}",
+                    backup.display()
+                ),
+                severity: EventSeverity::Warning,
+                source: EventSource::Digger,
+                key: EventKey::new("history_corrupt", ""),
+            });
+        }
 
         // Collect immediately so the UI never shows "Collecting data..."
-        let snap = Arc::new(collector.collect());
-        history.record(&snap);
+        let (mut sampler, snap) = Sampler::start(prefs.process_limit, prefs.recorded_metrics, prefs.sensor_offsets.clone());
+        if prefs.search_all_processes {
+            sampler.set_process_filter(&prefs.process_filter);
+        }
+        sampler.set_pinned(prefs.pinned_processes.iter().cloned().collect());
+        let snap = Arc::new(snap);
+        history_writer.submit(vec![Arc::clone(&snap)]);
         let mem_pct = if snap.memory_total > 0 {
             snap.memory_used as f32 / snap.memory_total as f32 * 100.0
         } else {
             0.0
         };
-        let mut live_buffer = RingBuffer::new(live_max);
+        let mut live_buffer = RingBuffer::new(prefs.live_buffer_size);
         live_buffer.push(LivePoint {
             cpu: snap.cpu_usage_global,
             mem_pct,
@@ -325,38 +1072,92 @@ impl Digger {
             net_tx: snap.net_tx_bytes,
             disk_read: snap.disk_io.read_bytes,
             disk_write: snap.disk_io.write_bytes,
+            power_watts: snap.power.map_or(0.0, |p| p.watts),
+            max_temp_c: snap.temperatures.iter().map(|t| t.temp_c).fold(0.0_f32, f32::max),
+            cpu_times: snap.cpu_times.unwrap_or_default(),
+            minor_faults: snap.memory_activity.faults.map_or(0.0, |f| f.minor),
+            major_faults: snap.memory_activity.faults.map_or(0.0, |f| f.major),
         });
 
         Self {
-            collector,
+            sampler,
             history,
+            history_writer,
             current: Some(Arc::clone(&snap)),
             live_buffer,
-            live_max,
+            recent_snapshots: VecDeque::new(),
+            diff_window_idx: 1,
+            snapshot_diff: None,
             tab: Tab::Overview,
             overview_panel: OverviewPanel::Cpu,
-            process_filter: String::new(),
-            process_sort: match prefs.process_sort.as_str() {
-                "pid" => ProcessSort::Pid,
-                "name" => ProcessSort::Name,
-                "memory" => ProcessSort::Memory,
-                _ => ProcessSort::Cpu,
-            },
-            process_sort_asc: prefs.process_sort_asc,
-            process_grouped: prefs.process_grouped,
-            history_range_idx: 0,
-            history_points: Vec::new(),
+            process_view: ProcessesTab::from_prefs(&prefs),
+            selected_pid: None,
+            selected_details: None,
+            gpu_proc_sort: ProcessSort::Memory,
+            gpu_proc_sort_asc: false,
+            disk_scan: None,
+            lan_scan: None,
+            log_tail: (!prefs.log_tail_path.is_empty()).then(|| LogTail::start(&prefs.log_tail_path)),
+            log_tail_path: prefs.log_tail_path,
+            log_tail_filter: prefs.log_tail_filter,
+            ping: (!prefs.ping_host.is_empty()).then(|| PingMonitor::start(&prefs.ping_host)),
+            ping_host: prefs.ping_host,
+            watched_processes: prefs.watched_processes,
+            port_watch: prefs.port_watch.then(PortWatch::start),
+            port_watch_notify: prefs.port_watch_notify,
+            port_allowlist: prefs.port_allowlist,
+            port_allow_input: String::new(),
+            watch_input: String::new(),
+            kiosk,
+            kiosk_mode: prefs.kiosk_mode,
+            overview_cycle: prefs.overview_cycle,
+            overview_cycle_secs: prefs.overview_cycle_secs,
+            overview_hovered: false,
+            window_size: iced::Size::new(prefs.window_width, prefs.window_height),
+            window_position: prefs.window_position.map(|(x, y)| iced::Point::new(x, y)),
+            window_maximized: prefs.window_maximized,
+            check_updates: prefs.check_updates,
+            last_update_check: prefs.last_update_check,
+            update_check: None,
+            update_status: None,
+            history_view: HistoryTab::new(),
             show_settings: false,
             settings_panel: SettingsPanel::General,
+            keyboard_focus: KeyboardFocus::Tabs,
             refresh_interval_secs: prefs.refresh_interval_secs,
-            temp_celsius: prefs.temp_celsius,
+            temp_unit,
+            sensor_offsets: prefs.sensor_offsets,
+            process_mem_unit: prefs.process_mem_unit,
+            process_cpu_per_core: prefs.process_cpu_per_core,
+            search_all_processes: prefs.search_all_processes,
+            chart_smoothing: prefs.chart_smoothing,
+            chart_smoothing_alpha: prefs.chart_smoothing_alpha,
+            offer_elevation: prefs.offer_elevation,
+            elevation_request: None,
+            elevated_kill: None,
+            read_only: prefs.read_only,
+            touch_mode: prefs.touch_mode,
+            pull: None,
+            refresh_pending: false,
+            read_only_flag,
+            export_schema_sidecar: prefs.export_schema_sidecar,
+            recorded: prefs.recorded_metrics,
+            adaptive_recording: prefs.adaptive_recording,
+            adaptive_recording_threshold: prefs.adaptive_recording_threshold,
+            recording_policy: RecordingPolicy::new(prefs.adaptive_recording.then_some(prefs.adaptive_recording_threshold)),
+            row_density: prefs.row_density,
             collapsed_sections: HashSet::new(),
             theme_variant: if prefs.auto_theme {
                 if system_prefers_dark() { ThemeVariant::CatppuccinMocha } else { ThemeVariant::CatppuccinLatte }
             } else {
                 prefs.theme
             },
+            auto_theme: prefs.auto_theme,
             accent_color: prefs.accent,
+            custom_accent: prefs.custom_accent,
+            custom_accent_hsv: theme_colors::rgb_to_hsv(prefs.custom_accent),
+            custom_accent_input: String::new(),
+            color_vision: prefs.color_vision,
             language: prefs.language,
             ui_mono: font_for_lang(prefs.language),
             pal: build_palette(
@@ -366,38 +1167,73 @@ impl Digger {
                     prefs.theme
                 },
                 prefs.accent,
+                prefs.custom_accent,
+                prefs.color_vision,
             ),
             process_limit: prefs.process_limit,
             use_dyslexic_font: prefs.use_dyslexic_font,
             retention_hours: prefs.retention_hours,
             cpu_alert_threshold: prefs.cpu_alert_threshold,
             mem_alert_threshold: prefs.mem_alert_threshold,
+            vram_alert_threshold: prefs.vram_alert_threshold,
+            gpu_temp_alert_threshold: prefs.gpu_temp_alert_threshold,
+            temp_alert_threshold: prefs.temp_alert_threshold,
+            gpu_temp_alert_input: String::new(),
+            temp_alert_input: String::new(),
+            notify_net_changes: prefs.notify_net_changes,
+            notify_level: prefs.notify_level,
+            system_events: prefs.system_log_events.then(SystemEventFeed::start),
             status_message: None,
+            crash_report: crash::take_pending(),
+            history_backup,
             // Health & events
             health_score: 100.0,
-            event_log: VecDeque::with_capacity(EVENT_LOG_MAX),
+            event_log,
             prev_cpu: snap.cpu_usage_global,
             prev_mem_pct: mem_pct,
+            prev_gpu: Vec::new(),
+            cpu_baseline: Baseline::default(),
+            mem_baseline: Baseline::default(),
+            anomaly_sensitivity: prefs.anomaly_sensitivity,
+            idle_tracker: IdleTracker::new(),
             // Animation state
             anim_cpu: snap.cpu_usage_global,
             anim_mem_pct: mem_pct,
+            session_cpu: (snap.cpu_usage_global, snap.cpu_usage_global),
+            session_mem: (mem_pct, mem_pct),
             anim_cores: snap.cpu_usage_per_core.clone(),
             page_opacity: 1.0,
             pulse_phase: 0.0,
             heart_phase: 0.0,
+            anim_settled: false,
             prev_tab: Tab::Overview,
             prev_show_settings: false,
-            history_last_reload: 0.0,
+            disk_full_at: HashMap::new(),
+            swap_full_at: None,
+            forecast_last_update: 0.0,
+            forecast_horizon: prefs.forecast_horizon,
+            hardware: None,
+            hardware_read: None,
+            sessions: crate::sessions::list_cached().unwrap_or_default(),
+            sessions_loaded: false,
+            stale_subsystems: HashSet::new(),
+            resumed_at: 0.0,
+            forecast_warned: HashSet::new(),
+            collect_timings: RingBuffer::new(TIMINGS_WINDOW),
+            slow_stages: HashSet::new(),
             pending_snapshots: Vec::new(),
+            events_stored_until: 0.0,
             last_db_flush: 0.0,
             // Cached UI strings
             cached_tab_overview: format!("{ICON_OVERVIEW}  {}", prefs.language.strings().tab_overview),
             cached_tab_processes: format!("{ICON_PROCESSES}  {}", prefs.language.strings().tab_processes),
             cached_tab_history: format!("{ICON_HISTORY}  {}", prefs.language.strings().tab_history),
             cached_tab_events: format!("{ICON_LOG}  {}", prefs.language.strings().tab_events),
+            cached_tab_hardware: format!("{ICON_SERVER}  {}", prefs.language.strings().tab_hardware),
+            cached_tab_logs: format!("{ICON_FILE}  {}", prefs.language.strings().tab_logs),
             cached_digger_label: format!("{ICON_DIGGER} Digger"),
             cached_digger_label_settings: format!("{ICON_DIGGER} Digger  {ICON_CLOSE}"),
-            cached_theme_previews: Self::build_theme_previews(prefs.accent),
+            cached_theme_previews: Self::build_theme_previews(prefs.accent, prefs.custom_accent, prefs.color_vision),
             cached_theme_accent: prefs.accent,
         }
     }
@@ -407,6 +1243,10 @@ impl Digger {
         self.language.strings()
     }
 
+    fn refresh_selected_details(&mut self) {
+        self.selected_details = self.selected_pid.map(crate::procdetail::read);
+    }
+
     /// Rebuild cached tab strings when language changes.
     fn rebuild_cached_strings(&mut self) {
         let t = self.language.strings();
@@ -414,9 +1254,11 @@ impl Digger {
         self.cached_tab_processes = format!("{ICON_PROCESSES}  {}", t.tab_processes);
         self.cached_tab_history = format!("{ICON_HISTORY}  {}", t.tab_history);
         self.cached_tab_events = format!("{ICON_LOG}  {}", t.tab_events);
+        self.cached_tab_hardware = format!("{ICON_SERVER}  {}", t.tab_hardware);
+        self.cached_tab_logs = format!("{ICON_FILE}  {}", t.tab_logs);
     }
 
-    fn build_theme_previews(accent: AccentColor) -> Vec<(ThemeVariant, Palette)> {
+    fn build_theme_previews(accent: AccentColor, custom: [u8; 3], vision: ColorVision) -> Vec<(ThemeVariant, Palette)> {
         use ThemeVariant::*;
         let variants = [
             CatppuccinLatte, CatppuccinFrappe, CatppuccinMacchiato, CatppuccinMocha,
@@ -424,7 +1266,15 @@ impl Digger {
             EverblushLight, EverblushDark,
             KanagawaLight, KanagawaDark, KanagawaDragon,
         ];
-        variants.iter().map(|&v| (v, build_palette(v, accent))).collect()
+        variants.iter().map(|&v| (v, build_palette(v, accent, custom, vision))).collect()
+    }
+
+    /// Rebuild the palette and theme previews after an accent or color
+    /// vision change.
+    fn rebuild_palette(&mut self) {
+        self.pal = build_palette(self.theme_variant, self.accent_color, self.custom_accent, self.color_vision);
+        self.cached_theme_previews = Self::build_theme_previews(self.accent_color, self.custom_accent, self.color_vision);
+        self.cached_theme_accent = self.accent_color;
     }
 
     pub fn title(&self) -> String {
@@ -435,29 +1285,176 @@ impl Digger {
         if self.theme_variant.is_light() { Theme::Light } else { Theme::Dark }
     }
 
+    pub fn scale_factor(&self) -> f64 {
+        if self.kiosk {
+            KIOSK_SCALE
+        } else if self.touch_mode {
+            TOUCH_SCALE
+        } else {
+            1.0
+        }
+    }
+
+    /// Row density of the process and event tables; touch mode overrides
+    /// the setting.
+    fn table_density(&self) -> RowDensity {
+        if self.touch_mode { RowDensity::Touch } else { self.row_density }
+    }
+
+    /// Animation tick interval: full rate while anything is moving.
+    fn anim_tick_ms(&self) -> u64 {
+        if self.anim_settled && self.page_opacity >= 1.0 { ANIM_IDLE_TICK_MS } else { ANIM_TICK_MS }
+    }
+
     pub fn subscription(&self) -> Subscription<Message> {
         let data_tick = iced::time::every(Duration::from_secs(self.refresh_interval_secs))
             .map(|_| Message::Tick);
-        let anim_tick = iced::time::every(Duration::from_millis(ANIM_TICK_MS))
+        let anim_tick = iced::time::every(Duration::from_millis(self.anim_tick_ms()))
             .map(|_| Message::AnimTick);
         let keys = keyboard::on_key_press(|key, modifiers| {
             Some(Message::KeyPressed(key, modifiers))
         });
-        Subscription::batch([data_tick, anim_tick, keys])
+        let close = iced::window::close_requests().map(|_| Message::CloseRequested);
+        // Dividers only report the press; follow the cursor until release
+        let column_drag = if self.process_view.column_resize.is_some() {
+            iced::event::listen_with(|event, _status, _window| match event {
+                iced::Event::Mouse(iced::mouse::Event::CursorMoved { position }) => {
+                    Some(Message::ColumnResizeMove(position.x))
+                }
+                iced::Event::Mouse(iced::mouse::Event::ButtonReleased(iced::mouse::Button::Left)) => {
+                    Some(Message::ColumnResizeEnd)
+                }
+                _ => None,
+            })
+        } else {
+            Subscription::none()
+        };
+        let window_geometry = iced::event::listen_with(|event, _status, id| match event {
+            iced::Event::Window(iced::window::Event::Moved(position)) => Some(Message::WindowMoved(position)),
+            iced::Event::Window(iced::window::Event::Resized(size)) => Some(Message::WindowResized(id, size)),
+            _ => None,
+        });
+        // Hovering pauses the cycle a user turned on, but never a wall display
+        let rotating = self.kiosk || (self.overview_cycle && !self.overview_hovered);
+        let overview_cycle = if rotating && self.tab == Tab::Overview && !self.show_settings {
+            iced::time::every(Duration::from_secs(self.overview_cycle_secs)).map(|_| Message::CycleOverview)
+        } else {
+            Subscription::none()
+        };
+        let touch = if self.touch_mode && self.tab == Tab::Processes && !self.show_settings {
+            iced::event::listen_with(|event, _status, _window| match event {
+                iced::Event::Touch(touch) => Some(Message::Touch(touch)),
+                _ => None,
+            })
+        } else {
+            Subscription::none()
+        };
+        Subscription::batch([data_tick, anim_tick, keys, close, column_drag, window_geometry, overview_cycle, touch])
+    }
+
+    /// Hand warnings and critical events logged since the last call to the
+    /// history writer, for the History chart markers.
+    fn queue_logged_events(&mut self) {
+        let since = self.events_stored_until;
+        let fresh: Vec<StoredEvent> = self
+            .event_log
+            .iter()
+            .filter(|e| e.at > since && e.event.severity != EventSeverity::Info)
+            .map(|e| StoredEvent {
+                timestamp: e.at,
+                critical: e.event.severity == EventSeverity::Critical,
+                text: format!("{} {}", e.event.icon, e.event.message),
+            })
+            .collect();
+        if let Some(newest) = fresh.iter().map(|e| e.timestamp).reduce(f64::max) {
+            self.events_stored_until = newest;
+        }
+        self.history_writer.log_events(fresh);
+    }
+
+    /// Flush batched snapshots, checkpoint the history DB and save
+    /// preferences. Called before the window closes.
+    fn shutdown(&mut self) {
+        self.queue_logged_events();
+        self.history_writer.submit(std::mem::take(&mut self.pending_snapshots));
+        // Blocks until the writer has drained its queue and checkpointed
+        self.history_writer.finish();
+        self.save_prefs();
+    }
+
+    /// Logged as Critical: the data shown froze until the restart.
+    fn collector_restarted(&mut self, msg: String) {
+        self.notify(EventSeverity::Critical, "Digger: Data", &msg);
+        push_event(&mut self.event_log, LogEvent {
+            timestamp: Arc::from(locale::clock_now()),
+            icon: ICON_REFRESH,
+            message: msg,
+            severity: EventSeverity::Critical,
+            source: EventSource::Digger,
+            key: EventKey::new("collector_restarted", ""),
+        });
     }
 
-    pub fn update(&mut self, message: Message) {
+    pub fn update(&mut self, message: Message) -> Task<Message> {
         match message {
+            // Maximizing resizes the window too; only keep the size once
+            // it is known not to be the maximized one
+            Message::WindowResized(id, size) => {
+                return iced::window::get_maximized(id).map(move |maximized| Message::WindowGeometry { size, maximized });
+            }
+            // Fullscreen geometry isn't worth restoring
+            Message::WindowGeometry { .. } | Message::WindowMoved(_) if self.kiosk => {}
+            Message::WindowGeometry { size, maximized } => {
+                self.window_maximized = maximized;
+                if !maximized {
+                    self.window_size = size;
+                }
+            }
+            Message::WindowMoved(position) => {
+                if !self.window_maximized {
+                    self.window_position = Some(position);
+                }
+            }
+            Message::CloseRequested => {
+                self.shutdown();
+                return iced::exit();
+            }
             Message::Tick => {
-                let snap = Arc::new(self.collector.collect());
+                // New targets for the tweens: back to full frame rate
+                self.anim_settled = false;
+                // The Memory panel shows the page faults of the selected process
+                self.sampler.set_fault_pid(self.selected_pid);
+                let snap = match self.sampler.poll() {
+                    Poll::Ready(snap) => {
+                        self.refresh_pending = false;
+                        snap
+                    }
+                    Poll::Pending => return Task::none(),
+                    Poll::Hung(stalled) => {
+                        self.refresh_pending = false;
+                        self.collector_restarted(format!(
+                            "Data collection hung for {}s (a stuck disk or driver?); restarted the collector",
+                            stalled.as_secs()
+                        ));
+                        return Task::none();
+                    }
+                    Poll::Died => {
+                        self.refresh_pending = false;
+                        self.collector_restarted("Data collection thread crashed; restarted the collector".into());
+                        return Task::none();
+                    }
+                };
                 let now_ts = snap.timestamp;
 
                 // Opt #10 + #11: Batch SQLite inserts in a single transaction — flush every 5 seconds.
-                self.pending_snapshots.push(Arc::clone(&snap));
+                // The writer thread runs the inserts so a slow disk never blocks the UI.
+                // Adaptive recording keeps quiet stretches at one row per 10 s
+                if let Some(row) = self.recording_policy.admit(&snap) {
+                    self.pending_snapshots.push(row);
+                }
                 if now_ts - self.last_db_flush >= 5.0 || self.last_db_flush == 0.0 {
-                    let batch: Vec<Arc<Snapshot>> = self.pending_snapshots.drain(..).collect();
-                    let refs: Vec<&Snapshot> = batch.iter().map(|a| a.as_ref()).collect();
-                    self.history.record_batch(&refs);
+                    self.queue_logged_events();
+                    self.history_writer.submit(std::mem::take(&mut self.pending_snapshots));
                     self.last_db_flush = now_ts;
                 }
 
@@ -473,7 +1470,14 @@ impl Digger {
                     net_tx: snap.net_tx_bytes,
                     disk_read: snap.disk_io.read_bytes,
                     disk_write: snap.disk_io.write_bytes,
+                    power_watts: snap.power.map_or(0.0, |p| p.watts),
+                    max_temp_c: snap.temperatures.iter().map(|t| t.temp_c).fold(0.0_f32, f32::max),
+                    cpu_times: snap.cpu_times.unwrap_or_default(),
+                    minor_faults: snap.memory_activity.faults.map_or(0.0, |f| f.minor),
+                    major_faults: snap.memory_activity.faults.map_or(0.0, |f| f.major),
                 });
+                self.session_cpu = (self.session_cpu.0.min(snap.cpu_usage_global), self.session_cpu.1.max(snap.cpu_usage_global));
+                self.session_mem = (self.session_mem.0.min(mem_pct), self.session_mem.1.max(mem_pct));
 
                 // Check alert thresholds
                 if snap.cpu_usage_global >= self.cpu_alert_threshold {
@@ -487,7 +1491,7 @@ impl Digger {
                         mem_pct, self.mem_alert_threshold
                     ));
                 } else {
-                    if let Some(err) = &self.history.last_error {
+                    if let Some(err) = self.history_error() {
                         self.status_message = Some(format!("{ICON_WARNING} {err}"));
                     } else {
                         self.status_message = None;
@@ -495,58 +1499,77 @@ impl Digger {
                 }
 
                 // ─── Anomaly detection & event logging (opt #5: bounded VecDeque) ───
-                let now_str: Arc<str> = Arc::from(chrono::Local::now().format("%H:%M:%S").to_string());
-
-                // Helper closure: push to bounded event log
-                let push_event = |log: &mut VecDeque<LogEvent>, event: LogEvent| {
-                    if log.len() >= EVENT_LOG_MAX {
-                        log.pop_front();
+                let now_str: Arc<str> = Arc::from(locale::clock_now());
+
+                // Collection stages over their time budget
+                self.collect_timings.push(snap.timings);
+                let slow: HashSet<CollectStage> = snap.timings.over_budget().map(|(stage, _)| stage).collect();
+                for (stage, took) in snap.timings.over_budget() {
+                    if !self.slow_stages.contains(&stage) {
+                        push_event(&mut self.event_log, LogEvent {
+                            timestamp: Arc::clone(&now_str),
+                            icon: ICON_CLOCK,
+                            message: format!(
+                                "Slow collection: {} took {} ms (budget {} ms)",
+                                stage.name(),
+                                took.as_millis(),
+                                stage.budget().as_millis(),
+                            ),
+                            severity: EventSeverity::Warning,
+                            source: EventSource::Digger,
+                            key: EventKey::new("slow_collection", stage.name()),
+                        });
                     }
-                    log.push_back(event);
-                };
+                }
+                self.slow_stages = slow;
 
-                // CPU spike: jumped more than 40% in one tick
-                let cpu_delta = snap.cpu_usage_global - self.prev_cpu;
-                if cpu_delta > 40.0 {
-                    let msg = format!("CPU spike: {:.0}% → {:.0}% (+{:.0}%)", self.prev_cpu, snap.cpu_usage_global, cpu_delta);
-                    send_notification("Digger: CPU Spike", &msg);
+                // Spikes: well above the metric's recent baseline
+                if let Some(a) = self.cpu_baseline.observe(snap.cpu_usage_global, self.anomaly_sensitivity) {
+                    let msg = format!("CPU spike: {:.0}% (usually {:.0}% ± {:.0})", a.value, a.mean, a.std_dev);
+                    self.notify(EventSeverity::Warning, "Digger: CPU Spike", &msg);
                     push_event(&mut self.event_log, LogEvent {
                         timestamp: Arc::clone(&now_str),
                         icon: ICON_BOLT,
                         message: msg,
                         severity: EventSeverity::Warning,
+                        source: EventSource::Digger,
+                        key: EventKey::new("cpu_spike", ""),
                     });
                 }
-
-                // Memory monotonic rise detection
-                if mem_pct > self.prev_mem_pct + 2.0 && mem_pct > 80.0 {
+                if let Some(a) = self.mem_baseline.observe(mem_pct, self.anomaly_sensitivity) {
                     push_event(&mut self.event_log, LogEvent {
                         timestamp: Arc::clone(&now_str),
                         icon: ICON_WARNING,
-                        message: format!("Memory rising: {:.1}% → {:.1}%", self.prev_mem_pct, mem_pct),
+                        message: format!("Memory rising: {:.1}% (usually {:.1}% ± {:.1})", a.value, a.mean, a.std_dev),
                         severity: EventSeverity::Warning,
+                        source: EventSource::Digger,
+                        key: EventKey::new("memory_rising", ""),
                     });
                 }
 
                 // Critical thresholds
                 if snap.cpu_usage_global >= self.cpu_alert_threshold && self.prev_cpu < self.cpu_alert_threshold {
                     let msg = format!("CPU exceeded threshold: {:.0}% >= {:.0}%", snap.cpu_usage_global, self.cpu_alert_threshold);
-                    send_notification("Digger: CPU Alert", &msg);
+                    self.notify(EventSeverity::Critical, "Digger: CPU Alert", &msg);
                     push_event(&mut self.event_log, LogEvent {
                         timestamp: Arc::clone(&now_str),
                         icon: ICON_WARNING,
                         message: msg,
                         severity: EventSeverity::Critical,
+                        source: EventSource::Digger,
+                        key: EventKey::new("cpu_threshold", ""),
                     });
                 }
                 if mem_pct >= self.mem_alert_threshold && self.prev_mem_pct < self.mem_alert_threshold {
                     let msg = format!("Memory exceeded threshold: {:.0}% >= {:.0}%", mem_pct, self.mem_alert_threshold);
-                    send_notification("Digger: Memory Alert", &msg);
+                    self.notify(EventSeverity::Critical, "Digger: Memory Alert", &msg);
                     push_event(&mut self.event_log, LogEvent {
                         timestamp: Arc::clone(&now_str),
                         icon: ICON_WARNING,
                         message: msg,
                         severity: EventSeverity::Critical,
+                        source: EventSource::Digger,
+                        key: EventKey::new("memory_threshold", ""),
                     });
                 }
 
@@ -557,6 +1580,8 @@ impl Digger {
                         icon: ICON_CHECK,
                         message: format!("CPU recovered: {:.0}% < {:.0}% threshold", snap.cpu_usage_global, self.cpu_alert_threshold),
                         severity: EventSeverity::Info,
+                        source: EventSource::Digger,
+                        key: EventKey::new("cpu_recovered", ""),
                     });
                 }
                 if mem_pct < self.mem_alert_threshold && self.prev_mem_pct >= self.mem_alert_threshold {
@@ -565,41 +1590,329 @@ impl Digger {
                         icon: ICON_CHECK,
                         message: format!("Memory recovered: {:.0}% < {:.0}% threshold", mem_pct, self.mem_alert_threshold),
                         severity: EventSeverity::Info,
+                        source: EventSource::Digger,
+                        key: EventKey::new("memory_recovered", ""),
+                    });
+                }
+
+                // GPU thresholds, per device
+                for (i, gpu) in snap.gpu.gpus.iter().enumerate() {
+                    let vram_pct = gpu.memory_percent();
+                    let (prev_vram, prev_temp) = self.prev_gpu.get(i).copied().unwrap_or_default();
+                    let vram_limit = self.vram_alert_threshold;
+                    let temp_limit = self.gpu_temp_alert_threshold;
+                    if vram_pct >= vram_limit && prev_vram < vram_limit {
+                        let msg = format!("{} VRAM exceeded threshold: {:.0}% >= {:.0}%", gpu.name, vram_pct, vram_limit);
+                        self.notify(EventSeverity::Critical, "Digger: VRAM Alert", &msg);
+                        push_event(&mut self.event_log, LogEvent {
+                            timestamp: Arc::clone(&now_str),
+                            icon: ICON_GPU,
+                            message: msg,
+                            severity: EventSeverity::Critical,
+                            source: EventSource::Digger,
+                            key: EventKey::new("vram_threshold", gpu.name.as_str()),
+                        });
+                    } else if vram_pct < vram_limit && prev_vram >= vram_limit {
+                        push_event(&mut self.event_log, LogEvent {
+                            timestamp: Arc::clone(&now_str),
+                            icon: ICON_CHECK,
+                            message: format!("{} VRAM recovered: {:.0}% < {:.0}% threshold", gpu.name, vram_pct, vram_limit),
+                            severity: EventSeverity::Info,
+                            source: EventSource::Digger,
+                            key: EventKey::new("vram_recovered", gpu.name.as_str()),
+                        });
+                    }
+                    if gpu.temperature >= temp_limit && prev_temp < temp_limit {
+                        let msg = format!(
+                            "{} temperature exceeded threshold: {} >= {}",
+                            gpu.name,
+                            locale::temperature(gpu.temperature, self.temp_unit),
+                            locale::temperature(temp_limit, self.temp_unit),
+                        );
+                        self.notify(EventSeverity::Critical, "Digger: GPU Temperature Alert", &msg);
+                        push_event(&mut self.event_log, LogEvent {
+                            timestamp: Arc::clone(&now_str),
+                            icon: ICON_TEMP,
+                            message: msg,
+                            severity: EventSeverity::Critical,
+                            source: EventSource::Digger,
+                            key: EventKey::new("gpu_temp_threshold", gpu.name.as_str()),
+                        });
+                    } else if gpu.temperature < temp_limit && prev_temp >= temp_limit {
+                        push_event(&mut self.event_log, LogEvent {
+                            timestamp: Arc::clone(&now_str),
+                            icon: ICON_CHECK,
+                            message: format!(
+                                "{} temperature recovered: {} < {} threshold",
+                                gpu.name,
+                                locale::temperature(gpu.temperature, self.temp_unit),
+                                locale::temperature(temp_limit, self.temp_unit),
+                            ),
+                            severity: EventSeverity::Info,
+                            source: EventSource::Digger,
+                            key: EventKey::new("gpu_temp_recovered", gpu.name.as_str()),
+                        });
+                    }
+                }
+                self.prev_gpu = snap.gpu.gpus.iter().map(|g| (g.memory_percent(), g.temperature)).collect();
+
+                // Network interfaces appearing, disappearing or losing link;
+                // not while collection is off or has just been turned back on
+                if let Some(prev) = self.current.as_ref().filter(|prev| self.recorded.network && !prev.net_interfaces.is_empty()) {
+                    for change in crate::metrics::diff_interfaces(&prev.net_interfaces, &snap.net_interfaces) {
+                        let (msg, severity, key) = match change {
+                            IfaceChange::Added(name) => {
+                                (format!("Interface {name} appeared"), EventSeverity::Info, EventKey::new("iface_added", name))
+                            }
+                            IfaceChange::Removed(name) => {
+                                (format!("Interface {name} disappeared"), EventSeverity::Warning, EventKey::new("iface_removed", name))
+                            }
+                            IfaceChange::LinkUp(name) => {
+                                (format!("Interface {name} is up"), EventSeverity::Info, EventKey::new("iface_up", name))
+                            }
+                            IfaceChange::LinkDown(name) => {
+                                (format!("Interface {name} went down"), EventSeverity::Warning, EventKey::new("iface_down", name))
+                            }
+                        };
+                        if self.notify_net_changes {
+                            self.notify(severity, "Digger: Network", &msg);
+                        }
+                        push_event(&mut self.event_log, LogEvent {
+                            timestamp: Arc::clone(&now_str),
+                            icon: ICON_NETWORK,
+                            message: msg,
+                            severity,
+                            source: EventSource::Digger,
+                            key,
+                        });
+                    }
+                }
+
+                // Watched processes starting or exiting
+                if let Some(prev) = &self.current {
+                    for ev in crate::snapdiff::watch_events(&self.watched_processes, &prev.all_processes, &snap.all_processes) {
+                        let (msg, key) = match ev {
+                            WatchEvent::Started(name, 1) => {
+                                (format!("Watched process {name} started"), EventKey::new("watch_started", name))
+                            }
+                            WatchEvent::Started(name, n) => {
+                                (format!("Watched process {name} started ({n} instances)"), EventKey::new("watch_started", name))
+                            }
+                            WatchEvent::Exited(name) => (format!("Watched process {name} exited"), EventKey::new("watch_exited", name)),
+                        };
+                        self.notify(EventSeverity::Warning, "Digger: Process Watch", &msg);
+                        push_event(&mut self.event_log, LogEvent {
+                            timestamp: Arc::clone(&now_str),
+                            icon: ICON_EYE,
+                            message: msg,
+                            severity: EventSeverity::Warning,
+                            source: EventSource::Digger,
+                            key,
+                        });
+                    }
+                }
+
+                // Ports that started listening since the previous tick
+                let new_listeners = self.port_watch.as_ref().map(PortWatch::drain).unwrap_or_default();
+                for socket in new_listeners {
+                    let process = socket.pid.and_then(|pid| snap.processes.iter().find(|p| p.pid == pid));
+                    if crate::sockets::allowlisted(&self.port_allowlist, &socket, process.map(|p| &*p.name)) {
+                        continue;
+                    }
+                    let owner = match (process, socket.pid) {
+                        (Some(p), _) => format!("{} (PID {})", p.name, p.pid),
+                        (None, Some(pid)) => format!("PID {pid}"),
+                        (None, None) => "an unknown process".to_string(),
+                    };
+                    let msg = format!("New listening port {} {} opened by {owner}", socket.protocol.name(), socket.local);
+                    if self.port_watch_notify {
+                        self.notify(EventSeverity::Warning, "Digger: Security Watch", &msg);
+                    }
+                    push_event(&mut self.event_log, LogEvent {
+                        timestamp: Arc::clone(&now_str),
+                        icon: ICON_SHIELD,
+                        message: msg,
+                        severity: EventSeverity::Warning,
+                        source: EventSource::Digger,
+                        key: EventKey::new("new_listener", format!("{} {}", socket.protocol.name(), socket.local)),
+                    });
+                }
+
+                if let Some(inventory) = self.hardware_read.as_ref().and_then(InventoryRead::result) {
+                    self.hardware = Some(inventory);
+                    self.hardware_read = None;
+                }
+
+                if let Some(sessions) = crate::sessions::list_cached() {
+                    let known = if self.sessions_loaded { &self.sessions[..] } else { &sessions[..] };
+                    for session in crate::sessions::new_logins(known, &sessions) {
+                        let msg = match &session.host {
+                            Some(host) => format!("User {} logged in on {} from {host}", session.user, session.line),
+                            None => format!("User {} logged in on {}", session.user, session.line),
+                        };
+                        self.notify(EventSeverity::Info, "Digger: Sessions", &msg);
+                        push_event(&mut self.event_log, LogEvent {
+                            timestamp: Arc::clone(&now_str),
+                            icon: ICON_USERS,
+                            message: msg,
+                            severity: EventSeverity::Info,
+                            source: EventSource::Digger,
+                            key: EventKey::new("login", format!("{}@{}", session.user, session.line)),
+                        });
+                    }
+                    self.sessions = sessions;
+                    self.sessions_loaded = true;
+                }
+
+                if self.current.as_ref().is_some_and(|prev| now_ts - prev.timestamp > SUSPEND_GAP_SECS) {
+                    self.resumed_at = now_ts;
+                }
+                let since_resume = (now_ts - self.resumed_at).max(0.0) as u64;
+                for subsystem in Subsystem::ALL {
+                    let age = snap.data_age.get(subsystem).map_or(0, |a| a.as_secs()).min(since_resume);
+                    let (msg, severity, icon, template) = if age >= STALE_EVENT_SECS && self.stale_subsystems.insert(subsystem) {
+                        (
+                            format!("{} data has not updated for {age}s; its backend looks hung", subsystem.name()),
+                            EventSeverity::Warning,
+                            ICON_WARNING,
+                            "stale",
+                        )
+                    } else if age < STALE_AFTER_SECS && self.stale_subsystems.remove(&subsystem) {
+                        (format!("{} data is updating again", subsystem.name()), EventSeverity::Info, ICON_REFRESH, "stale_recovered")
+                    } else {
+                        continue;
+                    };
+                    self.notify(severity, "Digger: Data", &msg);
+                    push_event(&mut self.event_log, LogEvent {
+                        timestamp: Arc::clone(&now_str),
+                        icon,
+                        message: msg,
+                        severity,
+                        source: EventSource::Digger,
+                        key: EventKey::new(template, subsystem.name()),
                     });
                 }
 
+                if let Some(ping) = &mut self.ping {
+                    ping.poll(now_ts);
+                }
+
+                // OS log errors, rate limited to a few entries per tick
+                if let Some(feed) = &self.system_events {
+                    let (events, skipped) = feed.drain(SYSTEM_EVENTS_PER_TICK);
+                    for ev in events {
+                        push_event(&mut self.event_log, LogEvent {
+                            timestamp: Arc::clone(&now_str),
+                            icon: ICON_SYSTEM,
+                            message: format!("{}: {}", ev.source, ev.message),
+                            severity: if ev.critical { EventSeverity::Critical } else { EventSeverity::Warning },
+                            source: EventSource::System,
+                            key: EventKey::new("system", format!("{}: {}", ev.source, ev.message)),
+                        });
+                    }
+                    if skipped > 0 {
+                        push_event(&mut self.event_log, LogEvent {
+                            timestamp: Arc::clone(&now_str),
+                            icon: ICON_SYSTEM,
+                            message: format!("{skipped} more system errors not shown"),
+                            severity: EventSeverity::Warning,
+                            source: EventSource::System,
+                            key: EventKey::new("system_skipped", ""),
+                        });
+                    }
+                }
+
+                // Idle background activity: report the top consumer on wake
+                let was_idle = self.idle_tracker.is_idle();
+                let idle = crate::idle::is_session_idle();
+                self.idle_tracker.update(now_ts, snap.cpu_core_count, &snap.processes, idle);
+                if was_idle && !idle {
+                    if let Some(top) = self.idle_tracker.top(1).first() {
+                        push_event(&mut self.event_log, LogEvent {
+                            timestamp: Arc::clone(&now_str),
+                            icon: ICON_CLOCK,
+                            message: format!(
+                                "Back from idle: {} used {:.1} CPU-seconds in the background",
+                                top.name, top.cpu_secs
+                            ),
+                            severity: EventSeverity::Info,
+                            source: EventSource::Digger,
+                            key: EventKey::new("back_from_idle", top.name.as_str()),
+                        });
+                    }
+                }
+
                 // Temperature alerts
                 let max_temp = snap.temperatures.iter().map(|t| t.temp_c).fold(0.0_f32, f32::max);
-                if max_temp > 85.0 {
-                    let temp_msg = format!("High temperature: {:.0}°C", max_temp);
-                    send_notification("Digger: Temperature Alert", &temp_msg);
+                if max_temp > self.temp_alert_threshold {
+                    let temp_msg = format!(
+                        "High temperature: {} (limit {})",
+                        locale::temperature(max_temp, self.temp_unit),
+                        locale::temperature(self.temp_alert_threshold, self.temp_unit),
+                    );
+                    self.notify(EventSeverity::Critical, "Digger: Temperature Alert", &temp_msg);
                     push_event(&mut self.event_log, LogEvent {
                         timestamp: now_str,
                         icon: ICON_TEMP,
-                        message: format!("High temperature: {:.0}°C", max_temp),
+                        message: temp_msg,
                         severity: EventSeverity::Critical,
+                        source: EventSource::Digger,
+                        key: EventKey::new("high_temp", ""),
                     });
                 }
 
                 self.prev_cpu = snap.cpu_usage_global;
                 self.prev_mem_pct = mem_pct;
 
+                // Drop the selection once the process has exited
+                if let Some(pid) = self.selected_pid {
+                    if !snap.processes.iter().any(|p| p.pid == pid) {
+                        self.selected_pid = None;
+                    }
+                }
+                // The working directory and output targets can change
+                if self.tab == Tab::Processes {
+                    self.refresh_selected_details();
+                }
+                // Stop sampling threads of exited processes
+                let expanded = self.process_view.expanded_threads.len();
+                self.process_view.expanded_threads
+                    .retain(|pid| snap.all_processes.binary_search_by_key(pid, |p| p.pid).is_ok());
+                if self.process_view.expanded_threads.len() != expanded {
+                    self.sampler.set_thread_pids(self.process_view.expanded_threads.iter().copied().collect());
+                }
+
                 // ─── Heartbeat BPM ───
                 self.health_score = compute_heartbeat_bpm(
                     snap.cpu_usage_global, mem_pct
                 );
 
+                // Keep enough snapshots for the longest diff window
+                let keep_secs = DIFF_WINDOWS.iter().map(|(secs, _)| *secs).fold(0.0, f64::max);
+                while self.recent_snapshots.front().is_some_and(|s| s.timestamp < snap.timestamp - keep_secs - 1.0) {
+                    self.recent_snapshots.pop_front();
+                }
+                self.recent_snapshots.push_back(Arc::clone(&snap));
+
+                if now_ts - self.forecast_last_update >= FORECAST_INTERVAL_SECS {
+                    self.forecast_last_update = now_ts;
+                    self.update_forecasts(&snap);
+                }
+
                 self.current = Some(snap);
+                self.refresh_snapshot_diff();
+                self.poll_update_check(now_ts);
+                self.poll_elevated_kill();
 
                 // Opt #7: Throttle History tab SQL reload to every 10s.
-                if self.tab == Tab::History && (now_ts - self.history_last_reload >= HISTORY_RELOAD_INTERVAL_SECS) {
-                    self.history_last_reload = now_ts;
-                    let range = HISTORY_RANGES[self.history_range_idx].0;
-                    self.history_points = self.history.load_last_n_seconds_downsampled(range, 600);
+                if self.tab == Tab::History && !self.show_settings {
+                    self.history_view.refresh(&self.history, now_ts);
+                    self.history_view.catch_up(&self.history, now_ts, self.history_writer.written_until());
                 }
             }
             Message::AnimTick => {
                 // Opt #4: Skip animation work when values have converged.
+                let dt = self.anim_tick_ms() as f32 / 1000.0;
                 let mut needs_anim = self.page_opacity < 1.0;
 
                 if let Some(snap) = &self.current {
@@ -650,28 +1963,37 @@ impl Digger {
                     self.pulse_phase -= std::f32::consts::TAU;
                 }
 
-                let dt = ANIM_TICK_MS as f32 / 1000.0;
+                // Critical-threshold pulses need the full frame rate
+                if self.anim_cpu >= self.cpu_alert_threshold || self.anim_mem_pct >= self.mem_alert_threshold {
+                    needs_anim = true;
+                }
+
                 let freq = self.health_score / 60.0;
                 self.heart_phase += std::f32::consts::TAU * freq * dt;
                 if self.heart_phase > std::f32::consts::TAU {
                     self.heart_phase -= std::f32::consts::TAU;
                 }
 
-                let _ = needs_anim; // reserved for future: could skip redraw when false
+                if self.refresh_pending {
+                    if self.sampler.has_result() {
+                        return self.update(Message::Tick);
+                    }
+                    needs_anim = true;
+                }
+
+                self.anim_settled = !needs_anim;
             }
             Message::TabSelected(tab) => {
                 self.prev_tab = self.tab;
                 self.tab = tab;
+                self.keyboard_focus = KeyboardFocus::Tabs;
+                // The Overview's exit event is lost when it's replaced
+                self.overview_hovered = false;
                 // Trigger fade-in on page change
                 if tab != self.prev_tab {
                     self.page_opacity = 0.0;
                 }
-                if tab == Tab::History {
-                    // Force immediate reload on tab switch
-                    self.history_last_reload = 0.0;
-                    let range = HISTORY_RANGES[self.history_range_idx].0;
-                    self.history_points = self.history.load_last_n_seconds_downsampled(range, 600);
-                }
+                return self.show_tab();
             }
             Message::OverviewSection(s) => {
                 if s != self.overview_panel {
@@ -679,29 +2001,176 @@ impl Digger {
                 }
                 self.overview_panel = s;
             }
-            Message::ProcessFilterChanged(f) => self.process_filter = f,
-            Message::ToggleGrouped => {
-                self.process_grouped = !self.process_grouped;
+            Message::ProcessFilterChanged(f) => {
+                self.process_view.filter = f;
+                self.sync_process_filter();
+            }
+            Message::ProcessesScrolled(viewport) => {
+                let offset = viewport.absolute_offset();
+                self.process_view.viewport_h = viewport.bounds().height;
+                self.process_view.scroll_y = offset.y;
+                if offset.x != self.process_view.scroll_x {
+                    self.process_view.scroll_x = offset.x;
+                    return scrollable::scroll_to(
+                        process_header_scroll_id(),
+                        scrollable::AbsoluteOffset { x: offset.x, y: 0.0 },
+                    );
+                }
+            }
+            Message::ColumnResizeStart(column) => {
+                self.process_view.column_resize = Some(ColumnResize {
+                    column,
+                    start_width: self.process_view.columns.get(column),
+                    anchor_x: None,
+                });
+            }
+            Message::ColumnResizeMove(x) => {
+                if let Some(drag) = &mut self.process_view.column_resize {
+                    let anchor = *drag.anchor_x.get_or_insert(x);
+                    self.process_view.columns.set(drag.column, drag.start_width + x - anchor);
+                }
+            }
+            Message::ToggleCommandColumn => {
+                self.process_view.show_command = !self.process_view.show_command;
+                self.save_prefs();
+            }
+            Message::ColumnResizeEnd => {
+                if self.process_view.column_resize.take().is_some() {
+                    self.save_prefs();
+                }
+            }
+            Message::ToggleGrouped => {
+                self.process_view.grouped = !self.process_view.grouped;
+                self.save_prefs();
+            }
+            Message::ToggleProcessGroup(group) => {
+                if !self.process_view.collapsed_groups.remove(&group) {
+                    self.process_view.collapsed_groups.insert(group);
+                }
+                self.save_prefs();
+            }
+            Message::SelectProcess(pid) => {
+                self.selected_pid = if self.selected_pid == Some(pid) { None } else { Some(pid) };
+                self.keyboard_focus = KeyboardFocus::Table;
+                self.refresh_selected_details();
+            }
+            Message::FocusProcessTable => {
+                self.keyboard_focus = KeyboardFocus::Table;
+                let scroll = if self.selected_pid.is_none() { self.move_process_selection(0) } else { Task::none() };
+                return Task::batch([unfocus(), scroll]);
+            }
+            Message::ToggleThreads(pid) => {
+                if !self.process_view.expanded_threads.remove(&pid) {
+                    self.process_view.expanded_threads.insert(pid);
+                }
+                self.sampler.set_thread_pids(self.process_view.expanded_threads.iter().copied().collect());
+            }
+            Message::TogglePinned(name) => {
+                if !self.process_view.pinned.remove(&*name) {
+                    self.process_view.pinned.insert(name.to_string());
+                }
+                self.sampler.set_pinned(self.process_view.pinned.clone());
                 self.save_prefs();
             }
+            Message::ShowProcess(pid, col) => {
+                self.prev_tab = self.tab;
+                self.tab = Tab::Processes;
+                if self.prev_tab != Tab::Processes {
+                    self.page_opacity = 0.0;
+                }
+                // Sort by the clicked metric so the selected row lands near the top
+                self.process_view.sort = col;
+                self.process_view.sort_asc = false;
+                self.process_view.filter.clear();
+                self.sync_process_filter();
+                self.process_view.scroll_y = 0.0;
+                self.selected_pid = Some(pid);
+                self.refresh_selected_details();
+                return self.show_tab();
+            }
+            Message::SortGpuProcesses(col) => {
+                if self.gpu_proc_sort == col {
+                    self.gpu_proc_sort_asc = !self.gpu_proc_sort_asc;
+                } else {
+                    self.gpu_proc_sort = col;
+                    self.gpu_proc_sort_asc = false;
+                }
+            }
             Message::SortBy(col) => {
-                if self.process_sort == col {
-                    self.process_sort_asc = !self.process_sort_asc;
+                if self.process_view.sort == col {
+                    self.process_view.sort_asc = !self.process_view.sort_asc;
                 } else {
-                    self.process_sort = col;
-                    self.process_sort_asc = false;
+                    self.process_view.sort = col;
+                    self.process_view.sort_asc = false;
                 }
                 self.save_prefs();
             }
+            Message::DiffWindowSelected(idx) => {
+                self.diff_window_idx = idx.min(DIFF_WINDOWS.len() - 1);
+                self.refresh_snapshot_diff();
+            }
             Message::HistoryRangeSelected(idx) => {
-                self.history_range_idx = idx;
-                let range = HISTORY_RANGES[idx].0;
-                self.history_points = self.history.load_last_n_seconds_downsampled(range, 600);
+                self.history_view.range_idx = idx;
+                self.history_view.custom = None;
+                self.history_view.range_error = None;
+                self.history_view.reload(&self.history, chrono::Utc::now().timestamp_millis() as f64 / 1000.0);
+            }
+            Message::ToggleHistoryFollow => {
+                self.history_view.follow = !self.history_view.follow;
+                if self.history_view.follow && self.history_view.custom.is_some() {
+                    self.history_view.custom = None;
+                    self.history_view.reload(&self.history, chrono::Utc::now().timestamp_millis() as f64 / 1000.0);
+                }
+            }
+            Message::ToggleChartBand(chart) => {
+                if !self.history_view.band_charts.remove(&chart) {
+                    self.history_view.band_charts.insert(chart);
+                }
+            }
+            Message::HistoryFromInput(value) => {
+                self.history_view.from_input = value;
+                self.history_view.range_error = None;
+            }
+            Message::HistoryToInput(value) => {
+                self.history_view.to_input = value;
+                self.history_view.range_error = None;
+            }
+            Message::ApplyHistoryRange => {
+                let t = self.t();
+                let now = chrono::Utc::now().timestamp_millis() as f64 / 1000.0;
+                let from = locale::parse_date_time(&self.history_view.from_input);
+                let to = locale::parse_date_time(&self.history_view.to_input);
+                let (Some(from), Some(to)) = (from, to) else {
+                    self.history_view.range_error = Some(t.range_invalid_date);
+                    return Task::none();
+                };
+                match history::clamp_range(from, to, now, self.history.retention_secs()) {
+                    Ok(window) => self.history_view.set_custom(&self.history, window, now),
+                    Err(e) => self.history_view.range_error = Some(range_error_text(e, t)),
+                }
+            }
+            Message::JumpToHistory(at) => {
+                let now = chrono::Utc::now().timestamp_millis() as f64 / 1000.0;
+                let retention = self.history.retention_secs();
+                match history::clamp_range(at - HISTORY_JUMP_SECS, at + HISTORY_JUMP_SECS, now, retention) {
+                    Ok(window) => {
+                        self.history_view.set_custom(&self.history, window, now);
+                        return self.update(Message::TabSelected(Tab::History));
+                    }
+                    Err(e) => self.status_message = Some(range_error_text(e, self.t()).to_string()),
+                }
+            }
+            Message::HistogramMetricSelected(metric) => {
+                self.history_view.histogram_metric = metric;
+                self.history_view.reload(&self.history, chrono::Utc::now().timestamp_millis() as f64 / 1000.0);
             }
             Message::ToggleSettings => {
                 self.prev_show_settings = self.show_settings;
                 self.show_settings = !self.show_settings;
                 self.page_opacity = 0.0;
+                if !self.show_settings {
+                    return self.show_tab();
+                }
             }
             Message::SettingsPanelSelected(p) => {
                 if p != self.settings_panel {
@@ -713,8 +2182,171 @@ impl Digger {
                 self.refresh_interval_secs = secs;
                 self.save_prefs();
             }
-            Message::ToggleTempUnit => {
-                self.temp_celsius = !self.temp_celsius;
+            Message::SetTempUnit(unit) => {
+                self.temp_unit = unit;
+                self.save_prefs();
+            }
+            Message::AdjustSensorOffset(id, step) => {
+                let offset = self.sensor_offsets.entry(id).or_insert(0.0);
+                *offset = ((*offset + step) * 10.0).round().clamp(-SENSOR_OFFSET_MAX_C * 10.0, SENSOR_OFFSET_MAX_C * 10.0) / 10.0;
+                self.sensor_offsets.retain(|_, o| *o != 0.0);
+                self.sampler.set_sensor_offsets(self.sensor_offsets.clone());
+                self.save_prefs();
+            }
+            Message::ResetSensorOffset(id) => {
+                self.sensor_offsets.remove(&id);
+                self.sampler.set_sensor_offsets(self.sensor_offsets.clone());
+                self.save_prefs();
+            }
+            Message::ToggleRateBits => {
+                let units = locale::rate_units();
+                locale::set_rate_units(RateUnits { bits: !units.bits, ..units });
+                self.save_prefs();
+            }
+            Message::SetProcessMemUnit(unit) => {
+                self.process_mem_unit = unit;
+                self.save_prefs();
+            }
+            Message::ToggleProcessCpuPerCore => {
+                self.process_cpu_per_core = !self.process_cpu_per_core;
+                self.save_prefs();
+            }
+            Message::ToggleSearchAllProcesses => {
+                self.search_all_processes = !self.search_all_processes;
+                self.sync_process_filter();
+                self.save_prefs();
+            }
+            Message::ToggleChartSmoothing => {
+                self.chart_smoothing = !self.chart_smoothing;
+                self.save_prefs();
+            }
+            Message::SetChartSmoothingAlpha(alpha) => {
+                self.chart_smoothing_alpha = alpha;
+                self.save_prefs();
+            }
+            Message::ToggleSchemaSidecar => {
+                self.export_schema_sidecar = !self.export_schema_sidecar;
+                self.save_prefs();
+            }
+            Message::ToggleRecorded(group) => {
+                self.recorded.toggle(group);
+                self.sampler.set_recorded(self.recorded);
+                self.history_writer.set_recorded(self.recorded);
+                self.save_prefs();
+            }
+            Message::ToggleAdaptiveRecording => {
+                self.adaptive_recording = !self.adaptive_recording;
+                self.recording_policy.threshold = self.adaptive_recording.then_some(self.adaptive_recording_threshold);
+                self.save_prefs();
+            }
+            Message::SetAdaptiveRecordingThreshold(v) => {
+                self.adaptive_recording_threshold = v;
+                self.recording_policy.threshold = self.adaptive_recording.then_some(v);
+                self.save_prefs();
+            }
+            Message::ToggleReadOnly => {
+                self.read_only = !self.read_only;
+                if self.read_only {
+                    self.elevation_request = None;
+                }
+                self.save_prefs();
+            }
+            Message::ToggleTouchMode => {
+                self.touch_mode = !self.touch_mode;
+                self.pull = None;
+                self.save_prefs();
+            }
+            Message::Touch(event) => {
+                use iced::touch::Event as TouchEvent;
+                match event {
+                    // Only a pull that starts with the list at its top, and on
+                    // the rows rather than the sidebar or the filter bar
+                    TouchEvent::FingerPressed { id, position } if self.process_view.scroll_y <= 0.0 => {
+                        self.pull = None;
+                        return container::visible_bounds(process_rows_id())
+                            .map(move |bounds| Message::PullPressed(id, position, bounds));
+                    }
+                    TouchEvent::FingerMoved { id, position } => {
+                        if let Some(pull) = self.pull.as_mut().filter(|p| p.finger == id) {
+                            pull.distance = (position.y - pull.start_y).max(0.0);
+                        }
+                        if self.process_view.scroll_y > 0.0 {
+                            self.pull = None;
+                        }
+                    }
+                    TouchEvent::FingerLifted { id, .. } => {
+                        if self.pull.take().is_some_and(|p| p.finger == id && p.distance >= PULL_REFRESH_PX) {
+                            // Hand over what is already collected, which asks
+                            // for a fresh snapshot, then show that one as soon
+                            // as it is in
+                            let task = self.update(Message::Tick);
+                            self.refresh_pending = true;
+                            self.anim_settled = false;
+                            return task;
+                        }
+                    }
+                    TouchEvent::FingerLost { .. } | TouchEvent::FingerPressed { .. } => self.pull = None,
+                }
+            }
+            Message::PullPressed(finger, position, bounds) => {
+                if bounds.is_some_and(|b| b.contains(position)) {
+                    self.pull = Some(PullGesture { finger, start_y: position.y, distance: 0.0 });
+                }
+            }
+            Message::ToggleOfferElevation => {
+                self.offer_elevation = !self.offer_elevation;
+                if !self.offer_elevation {
+                    self.elevation_request = None;
+                }
+                self.save_prefs();
+            }
+            Message::ToggleKioskMode => {
+                self.kiosk_mode = !self.kiosk_mode;
+                self.save_prefs();
+                return self.set_kiosk(self.kiosk_mode);
+            }
+            Message::ToggleOverviewCycle => {
+                self.overview_cycle = !self.overview_cycle;
+                self.save_prefs();
+            }
+            Message::OverviewHovered(hovered) => self.overview_hovered = hovered,
+            Message::SetOverviewCycle(secs) => {
+                self.overview_cycle_secs = secs;
+                self.save_prefs();
+            }
+            Message::SetLiveBufferSize(size) => {
+                self.live_buffer.set_capacity(size);
+                self.save_prefs();
+            }
+            Message::CycleOverview => {
+                let next = self.next_overview_panel();
+                return self.update(Message::OverviewSection(next));
+            }
+            Message::SetRowDensity(density) => {
+                self.row_density = density;
+                self.save_prefs();
+            }
+            Message::SetNotifyLevel(level) => {
+                self.notify_level = level;
+                self.save_prefs();
+            }
+            Message::SetAnomalySensitivity(sensitivity) => {
+                self.anomaly_sensitivity = sensitivity;
+                self.save_prefs();
+            }
+            Message::SetForecastHorizon(horizon) => {
+                self.forecast_horizon = horizon;
+                self.forecast_warned.clear();
+                self.forecast_last_update = 0.0;
+                self.save_prefs();
+            }
+            Message::ToggleRateSi => {
+                let units = locale::rate_units();
+                locale::set_rate_units(RateUnits { si: !units.si, ..units });
+                self.save_prefs();
+            }
+            Message::ToggleUtcTimes => {
+                locale::set_utc(!locale::utc());
                 self.save_prefs();
             }
             Message::ToggleSection(section) => {
@@ -724,44 +2356,140 @@ impl Digger {
             }
             Message::SetTheme(variant) => {
                 self.theme_variant = variant;
-                self.pal = build_palette(variant, self.accent_color);
+                self.auto_theme = false;
+                self.pal = build_palette(variant, self.accent_color, self.custom_accent, self.color_vision);
                 self.save_prefs();
             }
             Message::SetAccent(accent) => {
                 self.accent_color = accent;
-                self.pal = build_palette(self.theme_variant, accent);
-                self.cached_theme_previews = Self::build_theme_previews(accent);
-                self.cached_theme_accent = accent;
+                self.rebuild_palette();
+                self.save_prefs();
+            }
+            Message::SetCustomAccentHsv(hsv) => {
+                self.custom_accent_hsv = hsv;
+                self.custom_accent = theme_colors::hsv_to_rgb(hsv);
+                self.custom_accent_input.clear();
+                self.accent_color = AccentColor::Custom;
+                self.rebuild_palette();
+            }
+            Message::SaveCustomAccent => self.save_prefs(),
+            Message::CustomAccentInput(value) => self.custom_accent_input = value,
+            Message::ApplyCustomAccentHex => {
+                let Some(rgb) = theme_colors::parse_hex(&self.custom_accent_input) else {
+                    self.status_message = Some(fill(self.t().invalid_color, &[("value", &self.custom_accent_input)]));
+                    return Task::none();
+                };
+                self.custom_accent = rgb;
+                self.custom_accent_hsv = theme_colors::rgb_to_hsv(rgb);
+                self.custom_accent_input.clear();
+                self.accent_color = AccentColor::Custom;
+                self.rebuild_palette();
                 self.save_prefs();
             }
             Message::ToggleDyslexicFont => {
                 self.use_dyslexic_font = !self.use_dyslexic_font;
                 self.save_prefs();
             }
+            Message::SetColorVision(vision) => {
+                self.color_vision = vision;
+                self.rebuild_palette();
+                self.save_prefs();
+            }
             Message::ExportCsv => {
-                let range = HISTORY_RANGES[self.history_range_idx].0;
-                let now = chrono::Utc::now().timestamp_millis() as f64 / 1000.0;
-                let csv = self.history.export_csv(now - range, now);
+                let (from, to) = self.history_view.window(chrono::Utc::now().timestamp_millis() as f64 / 1000.0);
+                let csv = self.history.export_csv(from, to);
                 if let Some(dir) = dirs::download_dir().or_else(dirs::home_dir) {
-                    let path = dir.join("digger_export.csv");
-                    match std::fs::write(&path, &csv) {
-                        Ok(_) => self.status_message = Some(format!("Exported to {}", path.display())),
-                        Err(e) => self.status_message = Some(format!("Export failed: {e}")),
-                    }
+                    let columns: Vec<&exportmeta::Column> = exportmeta::HISTORY_COLUMNS.iter().collect();
+                    let meta = self.export_meta("history", Some((from, to)), &columns, false);
+                    self.write_export(&dir.join("digger_export.csv"), &csv, Some(meta));
                 }
             }
             Message::ExportJson => {
-                let range = HISTORY_RANGES[self.history_range_idx].0;
-                let now = chrono::Utc::now().timestamp_millis() as f64 / 1000.0;
-                let json = self.history.export_json(now - range, now);
+                let (from, to) = self.history_view.window(chrono::Utc::now().timestamp_millis() as f64 / 1000.0);
+                let columns: Vec<&exportmeta::Column> = exportmeta::HISTORY_COLUMNS.iter().collect();
+                let meta = self.export_meta("history", Some((from, to)), &columns, true);
+                let json = exportmeta::wrap_json(&meta, &self.history.export_json(from, to));
+                if let Some(dir) = dirs::download_dir().or_else(dirs::home_dir) {
+                    self.write_export(&dir.join("digger_export.json"), &json, None);
+                }
+            }
+            Message::ExportProcesses { json } => self.export_processes(json),
+            Message::CreateDiagnosticsBundle => self.create_diagnostics_bundle(),
+            Message::OpenCrashReport => {
+                if let Some(path) = self.crash_report.take() {
+                    if let Err(e) = launch::open(&path) {
+                        self.status_message = Some(format!("Could not open {}: {e}", path.display()));
+                    }
+                }
+            }
+            Message::DismissCrashReport => self.crash_report = None,
+            Message::RetryHistory => self.reopen_history(false),
+            Message::ResetHistory => self.reopen_history(true),
+            Message::OpenHistoryBackup => {
+                if let Some(dir) = self.history_backup.take().as_deref().and_then(std::path::Path::parent) {
+                    if let Err(e) = launch::open(dir) {
+                        self.status_message = Some(format!("Could not open {}: {e}", dir.display()));
+                    }
+                }
+            }
+            Message::DismissHistoryBackup => self.history_backup = None,
+            Message::CheckForUpdates => {
+                if self.update_check.is_none() {
+                    self.update_check = Some(UpdateCheck::start(false));
+                }
+            }
+            Message::ToggleUpdateChecks => {
+                self.check_updates = !self.check_updates;
+                self.save_prefs();
+            }
+            Message::OpenReleasePage => {
+                if let Some(UpdateStatus::Available(release)) = &self.update_status {
+                    if let Err(e) = launch::open(&release.url) {
+                        self.status_message = Some(format!("Could not open {}: {e}", release.url));
+                    }
+                }
+            }
+            Message::SaveChartPng(id) => {
+                let Some(chart) = self.chart_cfg(id).map(ChartCfg::into_chart) else { return Task::none() };
                 if let Some(dir) = dirs::download_dir().or_else(dirs::home_dir) {
-                    let path = dir.join("digger_export.json");
-                    match std::fs::write(&path, &json) {
+                    let stamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+                    let path = dir.join(format!("digger_chart_{stamp}.png"));
+                    let (w, h) = CHART_PNG_SIZE;
+                    match chart.save_png(w, h, &path) {
                         Ok(_) => self.status_message = Some(format!("Exported to {}", path.display())),
                         Err(e) => self.status_message = Some(format!("Export failed: {e}")),
                     }
                 }
             }
+            Message::GenerateReport(period) => {
+                // Busiest first, whatever order the collector kept
+                let processes = self.current.as_ref().map(|snap| {
+                    let mut busiest: Vec<_> = snap.processes.iter().collect();
+                    busiest.sort_by(|a, b| b.cpu_usage.total_cmp(&a.cpu_usage));
+                    busiest.into_iter().take(10).map(|p| ReportProcess {
+                        name: p.name.to_string(),
+                        cpu_usage: p.cpu_usage,
+                        memory_bytes: p.memory_bytes,
+                    }).collect()
+                }).unwrap_or_default();
+                let alerts = self.event_log.iter()
+                    .map(|entry| &entry.event)
+                    .filter(|e| e.severity != EventSeverity::Info)
+                    .map(|e| ReportAlert {
+                        timestamp: e.timestamp.to_string(),
+                        message: e.message.clone(),
+                        critical: e.severity == EventSeverity::Critical,
+                    })
+                    .collect();
+                let hostname = self.current.as_ref().map(|s| s.sys_info.hostname.clone()).unwrap_or_default();
+                let input = ReportInput { period, hostname, palette: self.pal, processes, alerts };
+                return Task::perform(crate::report::generate_in_background(input), Message::ReportGenerated);
+            }
+            Message::ReportGenerated(result) => match result {
+                Ok(path) => self.status_message = Some(format!("Report saved to {}", path.display())),
+                Err(e) => self.status_message = Some(format!("Report failed: {e}")),
+            },
+            Message::KillProcess(_) | Message::ConfirmElevatedKill if self.is_read_only() => {}
             Message::KillProcess(pid) => {
                 // SAFETY: Sending SIGTERM to a process is safe when the PID
                 // is a valid process ID obtained from sysinfo. The libc::kill
@@ -774,7 +2502,11 @@ impl Digger {
                     if result == 0 {
                         self.status_message = Some(format!("Sent SIGTERM to PID {pid}"));
                     } else {
-                        self.status_message = Some(format!("Failed to kill PID {pid} (permission denied?)"));
+                        let err = std::io::Error::last_os_error();
+                        self.status_message = Some(format!("Failed to kill PID {pid} ({err})"));
+                        if err.raw_os_error() == Some(libc::EPERM) {
+                            self.offer_elevated_kill(pid);
+                        }
                     }
                 }
                 #[cfg(windows)]
@@ -829,12 +2561,14 @@ impl Digger {
                                 self.status_message = Some(format!(
                                     "Failed to kill PID {pid} (access denied — try running as administrator)"
                                 ));
+                                self.offer_elevated_kill(pid);
                             }
                             CloseHandle(handle);
                         } else {
                             self.status_message = Some(format!(
                                 "Failed to open PID {pid} (access denied — try running as administrator)"
                             ));
+                            self.offer_elevated_kill(pid);
                         }
                     }
                 }
@@ -843,6 +2577,18 @@ impl Digger {
                     self.status_message = Some("Process kill not supported on this platform".into());
                 }
             }
+            Message::ConfirmElevatedKill => {
+                if let Some((pid, identity)) = self.elevation_request.take() {
+                    // The PID may have been reused while the banner was up
+                    if ProcessIdentity::of(pid).as_ref() != Some(&identity) {
+                        self.status_message = Some(format!("PID {pid} is no longer {}; nothing was stopped", identity.name));
+                    } else if self.elevated_kill.is_none() {
+                        self.elevated_kill = Some(ElevatedKill::start(pid));
+                        self.status_message = Some(format!("Waiting for administrator approval to stop PID {pid}…"));
+                    }
+                }
+            }
+            Message::CancelElevatedKill => self.elevation_request = None,
             Message::SetCpuAlertThreshold(v) => {
                 self.cpu_alert_threshold = v;
                 self.save_prefs();
@@ -851,136 +2597,624 @@ impl Digger {
                 self.mem_alert_threshold = v;
                 self.save_prefs();
             }
+            Message::SetVramAlertThreshold(v) => {
+                self.vram_alert_threshold = v;
+                self.save_prefs();
+            }
+            Message::SetGpuTempAlertThreshold(v) => {
+                self.gpu_temp_alert_threshold = v;
+                self.gpu_temp_alert_input.clear();
+                self.save_prefs();
+            }
+            Message::SetTempAlertThreshold(v) => {
+                self.temp_alert_threshold = v;
+                self.temp_alert_input.clear();
+                self.save_prefs();
+            }
+            Message::TempThresholdInput(alert, value) => match alert {
+                TempAlert::Sensor => self.temp_alert_input = value,
+                TempAlert::Gpu => self.gpu_temp_alert_input = value,
+            },
+            Message::ApplyTempThreshold(alert) => {
+                let input = match alert {
+                    TempAlert::Sensor => &self.temp_alert_input,
+                    TempAlert::Gpu => &self.gpu_temp_alert_input,
+                };
+                let Some(celsius) = locale::parse_temperature(input, self.temp_unit) else {
+                    self.status_message = Some(fill(self.t().invalid_temperature, &[("value", input)]));
+                    return Task::none();
+                };
+                let (min, max) = alert.range();
+                let celsius = celsius.clamp(min, max);
+                return self.update(match alert {
+                    TempAlert::Sensor => Message::SetTempAlertThreshold(celsius),
+                    TempAlert::Gpu => Message::SetGpuTempAlertThreshold(celsius),
+                });
+            }
+            Message::ScanDisk(mount) => {
+                // Replacing the handle cancels any scan still running
+                self.disk_scan = Some(DiskScan::start(mount));
+            }
+            Message::CloseDiskScan => {
+                self.disk_scan = None;
+            }
+            Message::ScanLan => {
+                self.lan_scan = Some(LanScan::start());
+            }
+            Message::CloseLanScan => {
+                self.lan_scan = None;
+            }
+            Message::ToggleSystemLogEvents => {
+                self.system_events = match self.system_events {
+                    Some(_) => None,
+                    None => Some(SystemEventFeed::start()),
+                };
+                self.save_prefs();
+            }
+            Message::TogglePortWatch => {
+                self.port_watch = match self.port_watch {
+                    Some(_) => None,
+                    None => Some(PortWatch::start()),
+                };
+                self.save_prefs();
+            }
+            Message::TogglePortWatchNotify => {
+                self.port_watch_notify = !self.port_watch_notify;
+                self.save_prefs();
+            }
+            Message::PortAllowInputChanged(entry) => self.port_allow_input = entry,
+            Message::AddPortAllow => {
+                let entry = self.port_allow_input.trim().to_string();
+                if !entry.is_empty() && !self.port_allowlist.iter().any(|e| e.eq_ignore_ascii_case(&entry)) {
+                    self.port_allowlist.push(entry);
+                    self.save_prefs();
+                }
+                self.port_allow_input.clear();
+            }
+            Message::RemovePortAllow(entry) => {
+                self.port_allowlist.retain(|e| *e != entry);
+                self.save_prefs();
+            }
+            Message::ToggleNetNotifications => {
+                self.notify_net_changes = !self.notify_net_changes;
+                self.save_prefs();
+            }
+            Message::ResetIdleReport => {
+                self.idle_tracker.reset();
+            }
+            Message::LogTailPathChanged(path) => self.log_tail_path = path,
+            Message::PingHostChanged(host) => self.ping_host = host,
+            Message::ApplyPingHost => {
+                let host = self.ping_host.trim();
+                self.ping = (!host.is_empty()).then(|| PingMonitor::start(host));
+                self.save_prefs();
+            }
+            Message::WatchInputChanged(name) => self.watch_input = name,
+            Message::AddWatch => {
+                let name = self.watch_input.trim().to_string();
+                if !name.is_empty() && !self.watched_processes.iter().any(|w| w.eq_ignore_ascii_case(&name)) {
+                    self.watched_processes.push(name);
+                    self.save_prefs();
+                }
+                self.watch_input.clear();
+            }
+            Message::RemoveWatch(name) => {
+                self.watched_processes.retain(|w| *w != name);
+                self.save_prefs();
+            }
+            Message::ApplyLogTail => {
+                let path = self.log_tail_path.trim();
+                self.log_tail = (!path.is_empty()).then(|| LogTail::start(path));
+                if self.log_tail.is_none() && self.tab == Tab::Logs {
+                    self.tab = Tab::Overview;
+                }
+                self.save_prefs();
+            }
+            // Saved with the other preferences on exit, not per keystroke
+            Message::LogTailFilterChanged(filter) => self.log_tail_filter = filter,
             Message::SetLanguage(lang) => {
                 self.language = lang;
+                locale::set_language(lang);
                 self.ui_mono = font_for_lang(lang);
                 self.rebuild_cached_strings();
                 self.save_prefs();
             }
             Message::KeyPressed(key, modifiers) => {
                 use keyboard::key::Named;
+                if self.kiosk {
+                    if key == keyboard::Key::Named(Named::Escape) {
+                        return self.set_kiosk(false);
+                    }
+                    return Task::none();
+                }
                 match key {
-                    // Tab navigation: 1-4 for tabs
+                    // Tab navigation: 1-6 for tabs
                     keyboard::Key::Character(ref c) if !self.show_settings => {
                         match c.as_str() {
-                            "1" => { self.prev_tab = self.tab; self.tab = Tab::Overview; self.page_opacity = 0.0; }
-                            "2" => { self.prev_tab = self.tab; self.tab = Tab::Processes; self.page_opacity = 0.0; }
-                            "3" => { self.prev_tab = self.tab; self.tab = Tab::History; self.page_opacity = 0.0; }
-                            "4" => { self.prev_tab = self.tab; self.tab = Tab::EventLog; self.page_opacity = 0.0; }
-                            "s" | "," => {
-                                self.prev_show_settings = self.show_settings;
-                                self.show_settings = !self.show_settings;
-                                self.page_opacity = 0.0;
-                            }
+                            "1" => return self.update(Message::TabSelected(Tab::Overview)),
+                            "2" => return self.update(Message::TabSelected(Tab::Processes)),
+                            "3" => return self.update(Message::TabSelected(Tab::History)),
+                            "4" => return self.update(Message::TabSelected(Tab::EventLog)),
+                            "5" => return self.update(Message::TabSelected(Tab::Hardware)),
+                            "6" if self.log_tail.is_some() => return self.update(Message::TabSelected(Tab::Logs)),
+                            "s" | "," => return self.update(Message::ToggleSettings),
                             "g" if self.tab == Tab::Processes => {
-                                self.process_grouped = !self.process_grouped;
+                                self.process_view.grouped = !self.process_view.grouped;
                                 self.save_prefs();
                             }
+                            "w" if self.tab == Tab::Processes => {
+                                // Watch / unwatch the selected process by name
+                                let name = self.selected_pid.and_then(|pid| {
+                                    self.current.as_ref()?.processes.iter().find(|p| p.pid == pid).map(|p| p.name.to_string())
+                                });
+                                if let Some(name) = name {
+                                    // Same matching as AddWatch's duplicate check
+                                    if let Some(watched) = self.watched_processes.iter().find(|w| w.eq_ignore_ascii_case(&name)) {
+                                        return self.update(Message::RemoveWatch(watched.clone()));
+                                    }
+                                    self.watch_input = name;
+                                    return self.update(Message::AddWatch);
+                                }
+                            }
                             "/" if self.tab == Tab::Processes => {
-                                // Focus on search (will be handled by the text input focus)
+                                self.keyboard_focus = KeyboardFocus::Filter;
+                                return text_input::focus(process_filter_id());
                             }
                             _ => {}
                         }
                     }
-                    keyboard::Key::Named(Named::Escape) => {
-                        if self.show_settings {
-                            self.show_settings = false;
-                            self.page_opacity = 0.0;
+                    keyboard::Key::Named(Named::Escape) if self.show_settings => {
+                        return self.update(Message::ToggleSettings);
+                    }
+                    keyboard::Key::Named(Named::Escape) if self.tab == Tab::Processes => {
+                        self.selected_pid = None;
+                        self.keyboard_focus = KeyboardFocus::Tabs;
+                    }
+                    // Settings: Tab walks the text fields, Ctrl+Tab the panels
+                    keyboard::Key::Named(Named::Tab) if self.show_settings => {
+                        if modifiers.control() {
+                            return self.update(Message::SettingsPanelSelected(self.settings_panel.cycle(!modifiers.shift())));
                         }
+                        return if modifiers.shift() { iced::widget::focus_previous() } else { iced::widget::focus_next() };
                     }
-                    keyboard::Key::Named(Named::Tab) if !modifiers.shift() && !self.show_settings => {
-                        // Cycle tabs forward
-                        self.prev_tab = self.tab;
-                        self.tab = match self.tab {
-                            Tab::Overview => Tab::Processes,
-                            Tab::Processes => Tab::History,
-                            Tab::History => Tab::EventLog,
-                            Tab::EventLog => Tab::Overview,
-                        };
-                        self.page_opacity = 0.0;
+                    // Processes: tab bar → filter → rows → next tab
+                    keyboard::Key::Named(Named::Tab) if self.tab == Tab::Processes => {
+                        match (self.keyboard_focus, modifiers.shift()) {
+                            (KeyboardFocus::Tabs, false) | (KeyboardFocus::Table, true) => {
+                                self.keyboard_focus = KeyboardFocus::Filter;
+                                return text_input::focus(process_filter_id());
+                            }
+                            (KeyboardFocus::Filter, false) => return self.update(Message::FocusProcessTable),
+                            (KeyboardFocus::Filter, true) => {
+                                self.keyboard_focus = KeyboardFocus::Tabs;
+                                return unfocus();
+                            }
+                            (KeyboardFocus::Table, false) | (KeyboardFocus::Tabs, true) => {
+                                return self.update(Message::TabSelected(self.cycled_tab(!modifiers.shift())));
+                            }
+                        }
+                    }
+                    keyboard::Key::Named(Named::Tab) => {
+                        return self.update(Message::TabSelected(self.cycled_tab(!modifiers.shift())));
                     }
-                    keyboard::Key::Named(Named::Tab) if modifiers.shift() && !self.show_settings => {
-                        // Cycle tabs backward
-                        self.prev_tab = self.tab;
-                        self.tab = match self.tab {
-                            Tab::Overview => Tab::EventLog,
-                            Tab::Processes => Tab::Overview,
-                            Tab::History => Tab::Processes,
-                            Tab::EventLog => Tab::History,
+                    keyboard::Key::Named(
+                        key @ (Named::ArrowUp | Named::ArrowDown | Named::PageUp | Named::PageDown | Named::Home | Named::End),
+                    ) if self.tab == Tab::Processes && !self.show_settings => {
+                        let page = (self.process_view.viewport_h / self.process_row_height()).max(1.0) as isize;
+                        let step = match key {
+                            Named::ArrowUp => -1,
+                            Named::ArrowDown => 1,
+                            Named::PageUp => -page,
+                            Named::PageDown => page,
+                            Named::Home => isize::MIN,
+                            _ => isize::MAX,
                         };
-                        self.page_opacity = 0.0;
+                        return self.move_process_selection(step);
                     }
                     _ => {}
                 }
             }
+            Message::CopyChartData(id) => {
+                if let Some(chart) = self.chart_cfg(id).map(ChartCfg::into_chart) {
+                    self.status_message = Some(self.t().chart_data_copied.to_string());
+                    return iced::clipboard::write(chart.to_csv());
+                }
+            }
+            Message::CopySystemSummary => {
+                if let Some(snap) = &self.current {
+                    self.status_message = Some(self.t().system_summary_copied.to_string());
+                    return iced::clipboard::write(crate::summary::markdown(snap));
+                }
+            }
         }
+        Task::none()
     }
 
-    fn save_prefs(&self) {
-        let prefs = Preferences {
-            theme: self.theme_variant,
-            accent: self.accent_color,
-            refresh_interval_secs: self.refresh_interval_secs,
-            temp_celsius: self.temp_celsius,
-            process_limit: self.process_limit,
-            live_buffer_size: self.live_max,
-            retention_hours: self.retention_hours,
-            cpu_alert_threshold: self.cpu_alert_threshold,
-            mem_alert_threshold: self.mem_alert_threshold,
-            use_dyslexic_font: self.use_dyslexic_font,
-            process_grouped: self.process_grouped,
-            process_sort: match self.process_sort {
-                ProcessSort::Pid => "pid",
-                ProcessSort::Name => "name",
-                ProcessSort::Cpu => "cpu",
-                ProcessSort::Memory => "memory",
-            }.into(),
-            process_sort_asc: self.process_sort_asc,
-            auto_theme: false, // When saving manually, auto is off
-            language: self.language,
-        };
-        prefs.save();
+    /// The tab after the current one, or before it; Logs only while a log
+    /// file is being followed.
+    fn cycled_tab(&self, forward: bool) -> Tab {
+        let logs = self.log_tail.is_some();
+        if forward {
+            match self.tab {
+                Tab::Overview => Tab::Processes,
+                Tab::Processes => Tab::History,
+                Tab::History => Tab::EventLog,
+                Tab::EventLog => Tab::Hardware,
+                Tab::Hardware if logs => Tab::Logs,
+                Tab::Hardware | Tab::Logs => Tab::Overview,
+            }
+        } else {
+            match self.tab {
+                Tab::Overview if logs => Tab::Logs,
+                Tab::Overview | Tab::Logs => Tab::Hardware,
+                Tab::Hardware => Tab::EventLog,
+                Tab::Processes => Tab::Overview,
+                Tab::History => Tab::Processes,
+                Tab::EventLog => Tab::History,
+            }
+        }
     }
 
-    fn chart_colors(&self) -> ChartColors {
-        ChartColors {
-            bg: self.pal.panel_bg,
-            border: self.pal.border,
-            grid: self.pal.grid,
-            label: self.pal.label,
-            text: self.pal.text,
+    /// Processes in table order, `None` standing for a group header; what
+    /// the arrow keys step through.
+    fn process_row_order(&self, snap: &Snapshot) -> Vec<Option<u32>> {
+        let mut procs = self.filter_processes(snap);
+        if !self.process_view.grouped {
+            self.sort_processes(&mut procs);
+            return procs.iter().take(self.process_limit).map(|p| Some(p.pid)).collect();
+        }
+        let current_uid = ProcessGroup::current_uid();
+        let mut order = Vec::new();
+        for group in ProcessGroup::ALL {
+            let mut list: Vec<_> = procs.iter().copied().filter(|p| ProcessGroup::of(p, current_uid) == group).collect();
+            if list.is_empty() {
+                continue;
+            }
+            order.push(None);
+            if !self.process_view.collapsed_groups.contains(&group) {
+                self.sort_processes(&mut list);
+                order.extend(list.iter().map(|p| Some(p.pid)));
+            }
         }
+        order
     }
 
-    // ─── MAIN VIEW ──────────────────────────────────────────────
-
-    pub fn view(&self) -> Element<'_, Message> {
-        let p = &self.pal;
-        let tabs = row![
-            menu_tab(&self.cached_tab_overview, Tab::Overview, self.tab, p, self.ui_mono),
-            menu_tab(&self.cached_tab_processes, Tab::Processes, self.tab, p, self.ui_mono),
-            menu_tab(&self.cached_tab_history, Tab::History, self.tab, p, self.ui_mono),
-            menu_tab(&self.cached_tab_events, Tab::EventLog, self.tab, p, self.ui_mono),
-        ]
-        .spacing(4);
+    /// Approximate height of a process row at the current density; rows
+    /// with threads or details open below them are taller.
+    fn process_row_height(&self) -> f32 {
+        let d = self.table_density();
+        d.text_size(11) as f32 * 1.3 + 2.0 * (d.pad_y(2) + d.pad_y(1)) as f32
+    }
 
-        let digger_label = if self.show_settings {
-            &self.cached_digger_label_settings
+    /// Select the process `step` rows from the selected one (the first row
+    /// when nothing is selected) and scroll it into view.
+    fn move_process_selection(&mut self, step: isize) -> Task<Message> {
+        let Some(snap) = self.current.as_ref() else {
+            return Task::none();
+        };
+        let order = self.process_row_order(snap);
+        let rows: Vec<(usize, u32)> = order.iter().enumerate().filter_map(|(i, pid)| Some((i, (*pid)?))).collect();
+        if rows.is_empty() {
+            return Task::none();
+        }
+        let target = match rows.iter().position(|&(_, pid)| Some(pid) == self.selected_pid) {
+            Some(i) => (i as isize).saturating_add(step).clamp(0, rows.len() as isize - 1) as usize,
+            None if step < 0 => rows.len() - 1,
+            None => 0,
+        };
+        let (index, pid) = rows[target];
+        self.selected_pid = Some(pid);
+        self.keyboard_focus = KeyboardFocus::Table;
+        self.refresh_selected_details();
+
+        let row_h = self.process_row_height();
+        let top = index as f32 * row_h;
+        let view = &self.process_view;
+        let y = if top < view.scroll_y {
+            top
+        } else if view.viewport_h > 0.0 && top + row_h > view.scroll_y + view.viewport_h {
+            top + row_h - view.viewport_h
         } else {
-            &self.cached_digger_label
+            return Task::none();
         };
-        let accent = p.accent;
-        let digger_btn = button(
-            text(digger_label).size(15).color(accent)
-        )
-        .on_press(Message::ToggleSettings)
-        .style(button::text)
-        .padding([2, 4]);
+        scrollable::scroll_to(process_scroll_id(), scrollable::AbsoluteOffset { x: view.scroll_x, y })
+    }
 
-        let border_c = p.border;
-        let text_c = p.text;
+    /// One value of the newest `n` live points, oldest first, smoothed when
+    /// chart smoothing is on. Every live chart and sparkline draws from here.
+    fn live_series(&self, n: usize, value: impl Fn(&LivePoint) -> f32) -> Vec<f32> {
+        let mut data: Vec<f32> = self.live_buffer.last(n).map(value).collect();
+        if self.chart_smoothing {
+            smooth_ema(&mut data, self.chart_smoothing_alpha);
+        }
+        data
+    }
 
-        // Heartbeat BPM indicator with pulsing icon
+    /// Gauge arrow: the last few seconds against the last half minute.
+    /// None until the buffer holds enough points to tell.
+    fn gauge_trend(&self, value: impl Fn(&LivePoint) -> f32 + Copy) -> Option<Trend> {
+        if self.live_buffer.len() < TREND_RECENT_POINTS * 2 {
+            return None;
+        }
+        let recent = self.live_buffer.stats(TREND_RECENT_POINTS, value)?;
+        let baseline = self.live_buffer.stats(TREND_BASELINE_POINTS, value)?;
+        Some(Trend::between(baseline.avg, recent.avg, TREND_DEAD_BAND))
+    }
+
+    /// Write-side errors take precedence over the read connection's.
+    fn history_error(&self) -> Option<HistoryError> {
+        self.history_writer.last_error().or_else(|| self.history.last_error.clone())
+    }
+
+    /// Open the history database again, after it failed to open, moving
+    /// the old file aside first when `reset`. The writer thread gets a
+    /// fresh connection too.
+    fn reopen_history(&mut self, reset: bool) {
+        self.history_writer.finish();
+        self.history = if reset { History::reset() } else { History::open() };
+        self.history.set_retention_hours(self.retention_hours);
+        self.history_writer = HistoryWriter::open(self.retention_hours);
+        self.history_writer.set_recorded(self.recorded);
+        if let Some(backup) = self.history.recovered_backup.clone() {
+            push_event(&mut self.event_log, LogEvent {
+                timestamp: Arc::from(locale::clock_now()),
+                icon: ICON_WARNING,
+                message: format!("History database has been reset; the old file was saved as {}", backup.display()),
+                severity: EventSeverity::Warning,
+                source: EventSource::Digger,
+                key: EventKey::new("history_reset", ""),
+            });
+            self.history_backup = Some(backup);
+        }
+        self.status_message = match self.history_error() {
+            Some(err) => Some(format!("{ICON_WARNING} {err}")),
+            None => Some(self.t().history_restored.to_string()),
+        };
+        self.history_view.reload(&self.history, chrono::Utc::now().timestamp_millis() as f64 / 1000.0);
+    }
+
+    /// Run the lifecycle hook of the tab that just became visible, after a
+    /// tab switch or when Settings closes over it.
+    fn show_tab(&mut self) -> Task<Message> {
+        match self.tab {
+            Tab::Processes => self.process_view.on_show(),
+            Tab::History => {
+                self.history_view.on_show(&self.history, chrono::Utc::now().timestamp_millis() as f64 / 1000.0);
+                Task::none()
+            }
+            Tab::Hardware => {
+                if self.hardware.is_none() && self.hardware_read.is_none() {
+                    self.hardware_read = Some(InventoryRead::start());
+                }
+                Task::none()
+            }
+            Tab::Overview | Tab::EventLog | Tab::Logs => Task::none(),
+        }
+    }
+
+    /// Refit the drive and swap trends from history and raise a warning for
+    /// each one that is newly projected to fill within the horizon.
+    fn update_forecasts(&mut self, snap: &Snapshot) {
+        let now = snap.timestamp;
+        let from = now - forecast::LOOKBACK_SECS;
+        self.disk_full_at = snap
+            .disks
+            .iter()
+            .filter_map(|d| {
+                let eta = forecast::time_to_full(&self.history.disk_usage(&d.mount, from), now)?;
+                Some((d.mount.clone(), now + eta))
+            })
+            .collect();
+        self.swap_full_at = forecast::time_to_full(&self.history.swap_usage(from), now).map(|eta| now + eta);
+
+        let horizon = self.forecast_horizon.secs();
+        let mut due: Vec<(String, String)> = self
+            .disk_full_at
+            .iter()
+            .map(|(mount, at)| (format!("disk:{mount}"), *at, format!("Drive {mount}")))
+            .chain(self.swap_full_at.map(|at| ("swap".to_string(), at, "Swap".to_string())))
+            .filter(|(_, at, _)| at - now < horizon)
+            .map(|(key, at, what)| (key, format!("{what} projected to be full in ~{}", forecast::format_eta(at - now))))
+            .collect();
+        due.sort();
+        let due_keys: HashSet<String> = due.iter().map(|(key, _)| key.clone()).collect();
+        self.forecast_warned.retain(|key| due_keys.contains(key));
+
+        let timestamp: Arc<str> = Arc::from(locale::clock_now());
+        for (key, msg) in due {
+            if !self.forecast_warned.insert(key.clone()) {
+                continue;
+            }
+            self.notify(EventSeverity::Warning, "Digger: Storage Forecast", &msg);
+            push_event(&mut self.event_log, LogEvent {
+                timestamp: Arc::clone(&timestamp),
+                icon: ICON_DISK,
+                message: msg,
+                severity: EventSeverity::Warning,
+                source: EventSource::Digger,
+                key: EventKey::new("storage_forecast", key),
+            });
+        }
+    }
+
+    /// Whether kill actions are disabled, by the setting or `--read-only`.
+    fn is_read_only(&self) -> bool {
+        self.read_only || self.read_only_flag
+    }
+
+    /// Factor applied to collected process CPU (a share of the whole
+    /// machine) before it is shown or compared with the alert threshold.
+    fn process_cpu_scale(&self) -> f32 {
+        match &self.current {
+            Some(snap) if self.process_cpu_per_core => snap.cpu_core_count.max(1) as f32,
+            _ => 1.0,
+        }
+    }
+
+    /// Enter or leave kiosk mode. Entering shows the Overview fullscreen.
+    fn set_kiosk(&mut self, on: bool) -> Task<Message> {
+        self.kiosk = on;
+        let mut task = Task::none();
+        if on {
+            task = self.update(Message::TabSelected(Tab::Overview));
+            self.show_settings = false;
+        }
+        let mode = if on { iced::window::Mode::Fullscreen } else { iced::window::Mode::Windowed };
+        task.chain(iced::window::get_oldest().and_then(move |id| iced::window::change_mode(id, mode)))
+    }
+
+    /// The panel after the current one, skipping GPU and power when the
+    /// machine has nothing to show there.
+    fn next_overview_panel(&self) -> OverviewPanel {
+        let has_data = |panel: OverviewPanel| match (panel, &self.current) {
+            (OverviewPanel::Gpu, Some(snap)) => !snap.gpu.gpus.is_empty(),
+            (OverviewPanel::Power, Some(snap)) => snap.power.is_some(),
+            _ => true,
+        };
+        let start = OverviewPanel::ALL.iter().position(|&p| p == self.overview_panel).unwrap_or(0);
+        (1..=OverviewPanel::ALL.len())
+            .map(|i| OverviewPanel::ALL[(start + i) % OverviewPanel::ALL.len()])
+            .find(|&panel| has_data(panel))
+            .unwrap_or(self.overview_panel)
+    }
+
+    /// Send a desktop notification if the event's severity reaches the
+    /// configured level.
+    fn notify(&self, severity: EventSeverity, title: &str, body: &str) {
+        let urgency = severity.urgency();
+        if self.notify_level.allows(urgency) {
+            crate::notify::send(title, body, urgency);
+        }
+    }
+
+    fn save_prefs(&self) {
+        self.current_prefs().save();
+    }
+
+    /// Preferences as they would be saved now.
+    fn current_prefs(&self) -> Preferences {
+        Preferences {
+            theme: self.theme_variant,
+            accent: self.accent_color,
+            custom_accent: self.custom_accent,
+            refresh_interval_secs: self.refresh_interval_secs,
+            temp_unit: self.temp_unit,
+            temp_celsius: None,
+            temp_kelvin: None,
+            sensor_offsets: self.sensor_offsets.clone(),
+            rate_bits: locale::rate_units().bits,
+            rate_si: locale::rate_units().si,
+            utc_times: locale::utc(),
+            process_mem_unit: self.process_mem_unit,
+            process_cpu_per_core: self.process_cpu_per_core,
+            search_all_processes: self.search_all_processes,
+            chart_smoothing: self.chart_smoothing,
+            chart_smoothing_alpha: self.chart_smoothing_alpha,
+            offer_elevation: self.offer_elevation,
+            read_only: self.read_only,
+            touch_mode: self.touch_mode,
+            export_schema_sidecar: self.export_schema_sidecar,
+            recorded_metrics: self.recorded,
+            adaptive_recording: self.adaptive_recording,
+            adaptive_recording_threshold: self.adaptive_recording_threshold,
+            row_density: self.row_density,
+            process_limit: self.process_limit,
+            live_buffer_size: self.live_buffer.capacity(),
+            retention_hours: self.retention_hours,
+            cpu_alert_threshold: self.cpu_alert_threshold,
+            mem_alert_threshold: self.mem_alert_threshold,
+            vram_alert_threshold: self.vram_alert_threshold,
+            gpu_temp_alert_threshold: self.gpu_temp_alert_threshold,
+            temp_alert_threshold: self.temp_alert_threshold,
+            use_dyslexic_font: self.use_dyslexic_font,
+            color_vision: self.color_vision,
+            process_grouped: self.process_view.grouped,
+            process_sort: self.process_view.sort_key().into(),
+            process_sort_asc: self.process_view.sort_asc,
+            process_filter: self.process_view.filter.clone(),
+            process_scroll_y: self.process_view.scroll_y,
+            process_column_widths: self.process_view.columns.0.to_vec(),
+            show_command_column: self.process_view.show_command,
+            collapsed_process_groups: ProcessGroup::ALL
+                .into_iter()
+                .filter(|g| self.process_view.collapsed_groups.contains(g))
+                .map(|g| g.key().to_string())
+                .collect(),
+            pinned_processes: {
+                let mut names: Vec<String> = self.process_view.pinned.iter().cloned().collect();
+                names.sort();
+                names
+            },
+            auto_theme: self.auto_theme,
+            language: self.language,
+            notify_net_changes: self.notify_net_changes,
+            notify_level: self.notify_level,
+            anomaly_sensitivity: self.anomaly_sensitivity,
+            forecast_horizon: self.forecast_horizon,
+            system_log_events: self.system_events.is_some(),
+            log_tail_path: self.log_tail.as_ref().map(|t| t.path().to_string_lossy().to_string()).unwrap_or_default(),
+            log_tail_filter: self.log_tail_filter.clone(),
+            ping_host: self.ping.as_ref().map(|m| m.host().to_string()).unwrap_or_default(),
+            watched_processes: self.watched_processes.clone(),
+            port_watch: self.port_watch.is_some(),
+            port_watch_notify: self.port_watch_notify,
+            port_allowlist: self.port_allowlist.clone(),
+            check_updates: self.check_updates,
+            last_update_check: self.last_update_check,
+            window_width: self.window_size.width,
+            window_height: self.window_size.height,
+            window_position: self.window_position.map(|p| (p.x, p.y)),
+            window_maximized: self.window_maximized,
+            kiosk_mode: self.kiosk_mode,
+            overview_cycle: self.overview_cycle,
+            overview_cycle_secs: self.overview_cycle_secs,
+        }
+    }
+
+    fn chart_colors(&self) -> ChartColors {
+        ChartColors {
+            bg: self.pal.panel_bg,
+            border: self.pal.border,
+            grid: self.pal.grid,
+            label: self.pal.label,
+            text: self.pal.text,
+        }
+    }
+
+    // ─── MAIN VIEW ──────────────────────────────────────────────
+
+    pub fn view(&self) -> Element<'_, Message> {
+        let p = &self.pal;
+        let rtl = locale::is_rtl();
+        let mut tab_items = vec![
+            menu_tab(&self.cached_tab_overview, Tab::Overview, self.tab, p, self.ui_mono),
+            menu_tab(&self.cached_tab_processes, Tab::Processes, self.tab, p, self.ui_mono),
+            menu_tab(&self.cached_tab_history, Tab::History, self.tab, p, self.ui_mono),
+            menu_tab(&self.cached_tab_events, Tab::EventLog, self.tab, p, self.ui_mono),
+            menu_tab(&self.cached_tab_hardware, Tab::Hardware, self.tab, p, self.ui_mono),
+        ];
+        if self.log_tail.is_some() {
+            tab_items.push(menu_tab(&self.cached_tab_logs, Tab::Logs, self.tab, p, self.ui_mono));
+        }
+        let tabs = directional_row(rtl, tab_items).spacing(4);
+
+        let digger_label = if self.show_settings {
+            &self.cached_digger_label_settings
+        } else {
+            &self.cached_digger_label
+        };
+        let accent = p.accent;
+        let digger_btn = button(
+            text(digger_label).size(15).color(accent)
+        )
+        .on_press(Message::ToggleSettings)
+        .style(button::text)
+        .padding([2, 4]);
+
+        let border_c = p.border;
+        let text_c = p.text;
+
+        // Heartbeat BPM indicator with pulsing icon
         let bpm = self.health_score;
         let heart_color = if bpm < 100.0 { p.green }
             else if bpm <= 130.0 { p.yellow }
@@ -1008,7 +3242,7 @@ impl Digger {
         // Event log badge
         let event_count = self.event_log.len();
         let event_badge: Element<Message> = if event_count > 0 {
-            let badge_color = if self.event_log.back().map(|e| e.severity) == Some(EventSeverity::Critical) {
+            let badge_color = if self.event_log.back().map(|e| e.event.severity) == Some(EventSeverity::Critical) {
                 p.red
             } else {
                 p.yellow
@@ -1021,24 +3255,38 @@ impl Digger {
             Space::new(0, 0).into()
         };
 
-        let menu_bar = row![
+        let read_only_badge: Element<Message> = if self.is_read_only() {
+            text(format!("  {ICON_EYE} {}", self.t().read_only)).size(10).font(self.ui_mono).color(p.label).into()
+        } else {
+            Space::new(0, 0).into()
+        };
+
+        // Kiosk mode has no way into Settings
+        let digger_btn: Element<Message> = if self.kiosk {
+            container(text(&self.cached_digger_label).size(15).color(accent)).padding([2, 4]).into()
+        } else {
+            digger_btn.into()
+        };
+        let menu_bar = directional_row(rtl, vec![
             digger_btn,
-            Space::with_width(8),
+            Space::with_width(8).into(),
             health_el,
-            Space::with_width(6),
+            Space::with_width(6).into(),
             event_badge,
-            Space::with_width(8),
-            text(ICON_SEPARATOR).size(14).color(border_c),
-            Space::with_width(8),
+            read_only_badge,
+            Space::with_width(8).into(),
+            text(ICON_SEPARATOR).size(14).color(border_c).into(),
+            Space::with_width(8).into(),
             status_el,
-            Space::with_width(Length::Fill),
-            tabs,
-            Space::with_width(Length::Fill),
-            text(chrono::Local::now().format("%H:%M:%S").to_string())
+            Space::with_width(Length::Fill).into(),
+            tabs.into(),
+            Space::with_width(Length::Fill).into(),
+            text(if locale::utc() { format!("{} UTC", locale::clock_now()) } else { locale::clock_now() })
                 .size(13)
                 .font(self.ui_mono)
-                .color(text_c),
-        ]
+                .color(text_c)
+                .into(),
+        ])
         .align_y(Alignment::Center)
         .padding([6, 12]);
 
@@ -1046,20 +3294,26 @@ impl Digger {
             self.view_settings()
         } else {
             match self.tab {
-                Tab::Overview => self.view_overview(),
+                Tab::Overview => mouse_area(self.view_overview())
+                    .on_enter(Message::OverviewHovered(true))
+                    .on_exit(Message::OverviewHovered(false))
+                    .into(),
                 Tab::Processes => self.view_processes(),
                 Tab::History => self.view_history(),
                 Tab::EventLog => self.view_event_log(),
+                Tab::Hardware => self.view_hardware(),
+                Tab::Logs => self.view_logs(),
             }
         };
 
         let bg = p.bg;
         let sidebar_bg = p.sidebar_bg;
-        let main = column![
-            panel_bg(menu_bar.into(), sidebar_bg, border_c),
-            content,
-        ]
-        .spacing(0);
+        let main = column![panel_bg(menu_bar.into(), sidebar_bg, border_c)]
+            .push_maybe(self.crash_report.as_ref().map(|path| self.view_crash_banner(path)))
+            .push_maybe(self.view_history_banner())
+            .push_maybe(self.elevation_request.as_ref().map(|(pid, identity)| self.view_elevation_banner(*pid, &identity.name)))
+            .push(content)
+            .spacing(0);
 
         container(main)
             .width(Length::Fill)
@@ -1071,6 +3325,160 @@ impl Digger {
             .into()
     }
 
+    /// Offer the panic report left by the previous run.
+    fn view_crash_banner(&self, path: &std::path::Path) -> Element<'_, Message> {
+        let p = &self.pal;
+        let t = self.t();
+        let body = row![
+            text(ICON_WARNING).size(16).color(p.red),
+            column![
+                text(t.crash_title).size(13).font(self.ui_mono).color(p.text),
+                text(t.crash_desc).size(11).font(self.ui_mono).color(p.label),
+                text(path.display().to_string()).size(10).font(self.ui_mono).color(p.label),
+            ]
+            .spacing(2)
+            .width(Length::Fill),
+            button(text(t.open_report).size(11).font(self.ui_mono))
+                .on_press(Message::OpenCrashReport)
+                .style(button::primary)
+                .padding([4, 12]),
+            button(text(t.dismiss).size(11).font(self.ui_mono).color(p.text))
+                .on_press(Message::DismissCrashReport)
+                .style(button::secondary)
+                .padding([4, 12]),
+        ]
+        .spacing(12)
+        .align_y(Alignment::Center);
+        container(panel(body.into(), p)).padding([4, 8]).into()
+    }
+
+    /// Remediation when the history database can't be opened, or notice
+    /// that a damaged one was replaced.
+    fn view_history_banner(&self) -> Option<Element<'_, Message>> {
+        let p = &self.pal;
+        let t = self.t();
+        let body = if !self.history.is_available() {
+            let err = self.history_error().map(|e| e.to_string()).unwrap_or_default();
+            row![
+                text(ICON_WARNING).size(16).color(p.red),
+                column![
+                    text(t.history_unavailable_title).size(13).font(self.ui_mono).color(p.text),
+                    text(t.history_unavailable_desc).size(11).font(self.ui_mono).color(p.label),
+                    text(err).size(10).font(self.ui_mono).color(p.label),
+                ]
+                .spacing(2)
+                .width(Length::Fill),
+                button(text(t.retry).size(11).font(self.ui_mono).color(p.text))
+                    .on_press(Message::RetryHistory)
+                    .style(button::secondary)
+                    .padding([4, 12]),
+                button(text(t.reset_history_db).size(11).font(self.ui_mono))
+                    .on_press(Message::ResetHistory)
+                    .style(button::primary)
+                    .padding([4, 12]),
+            ]
+        } else {
+            let backup = self.history_backup.as_ref()?;
+            row![
+                text(ICON_WARNING).size(16).color(p.yellow),
+                column![
+                    text(t.history_reset_title).size(13).font(self.ui_mono).color(p.text),
+                    text(t.history_reset_desc).size(11).font(self.ui_mono).color(p.label),
+                    text(backup.display().to_string()).size(10).font(self.ui_mono).color(p.label),
+                ]
+                .spacing(2)
+                .width(Length::Fill),
+                button(text(t.open_folder).size(11).font(self.ui_mono))
+                    .on_press(Message::OpenHistoryBackup)
+                    .style(button::primary)
+                    .padding([4, 12]),
+                button(text(t.dismiss).size(11).font(self.ui_mono).color(p.text))
+                    .on_press(Message::DismissHistoryBackup)
+                    .style(button::secondary)
+                    .padding([4, 12]),
+            ]
+        };
+        Some(container(panel(body.spacing(12).align_y(Alignment::Center).into(), p)).padding([4, 8]).into())
+    }
+
+    fn view_elevation_banner(&self, pid: u32, name: &str) -> Element<'_, Message> {
+        let p = &self.pal;
+        let t = self.t();
+        let desc = fill(t.elevation_desc, &[
+            ("name", &name),
+            ("pid", &pid),
+            ("prompt", &elevate::prompt_name().unwrap_or_default()),
+        ]);
+        let body = row![
+            text(ICON_WARNING).size(16).color(p.yellow),
+            column![
+                text(t.elevation_title).size(13).font(self.ui_mono).color(p.text),
+                text(desc).size(11).font(self.ui_mono).color(p.label),
+                text(elevate::kill_command_line(pid)).size(11).font(self.ui_mono).color(p.accent),
+            ]
+            .spacing(2)
+            .width(Length::Fill),
+            button(text(t.run_as_admin).size(11).font(self.ui_mono))
+                .on_press(Message::ConfirmElevatedKill)
+                .style(button::primary)
+                .padding([4, 12]),
+            button(text(t.cancel).size(11).font(self.ui_mono).color(p.text))
+                .on_press(Message::CancelElevatedKill)
+                .style(button::secondary)
+                .padding([4, 12]),
+        ]
+        .spacing(12)
+        .align_y(Alignment::Center);
+        container(panel(body.into(), p)).padding([4, 8]).into()
+    }
+
+    // ─── LOGS TAB ──────────────────────────────────────────────
+
+    fn view_logs(&self) -> Element<'_, Message> {
+        let p = &self.pal;
+        let t = self.t();
+        let label_c = p.label;
+        let Some(tail) = &self.log_tail else {
+            return Space::new(0, 0).into();
+        };
+
+        let lines = tail.lines(&self.log_tail_filter);
+        let header = row![
+            text(format!("{ICON_FILE} {}", tail.path().display())).size(13).font(self.ui_mono).color(p.accent),
+            Space::with_width(Length::Fill),
+            text(format!("{ICON_SEARCH} {}", t.filter)).size(11).font(self.ui_mono).color(label_c),
+            text_input(t.search, &self.log_tail_filter)
+                .on_input(Message::LogTailFilterChanged)
+                .width(200),
+            text(t.lines.format(self.language, lines.len() as u64)).size(11).font(self.ui_mono).color(label_c),
+        ]
+        .spacing(8)
+        .padding([6, 10])
+        .align_y(Alignment::Center);
+
+        let mut rows: Vec<Element<Message>> = Vec::new();
+        if let Some(err) = tail.error() {
+            rows.push(text(format!("{ICON_WARNING} {err}")).size(11).color(p.red).into());
+        }
+        for line in lines {
+            let color = match crate::logtail::classify(&line) {
+                LineLevel::Error => p.red,
+                LineLevel::Warning => p.yellow,
+                LineLevel::Normal => p.text,
+            };
+            let font = font_for_text(&line, self.ui_mono);
+            rows.push(text(line).size(11).font(font).color(color).into());
+        }
+
+        let body = scrollable(Column::with_children(rows).spacing(1).padding([4, 10]))
+            .anchor_bottom()
+            .height(Length::Fill);
+        container(panel(column![header, body].spacing(0).into(), p))
+            .padding(4)
+            .height(Length::Fill)
+            .into()
+    }
+
     // ─── EVENT LOG TAB ─────────────────────────────────────────
 
     fn view_event_log(&self) -> Element<'_, Message> {
@@ -1083,7 +3491,7 @@ impl Digger {
         let title_row = row![
             text(format!("{ICON_LOG} {}", t.event_log)).size(13).font(self.ui_mono).color(p.accent),
             Space::with_width(Length::Fill),
-            text(format!("{} {}", self.event_log.len(), t.events)).size(11).font(self.ui_mono).color(label_c),
+            text(t.events.format(self.language, self.event_log.len() as u64)).size(11).font(self.ui_mono).color(label_c),
         ]
         .padding([6, 10])
         .align_y(Alignment::Center);
@@ -1100,23 +3508,44 @@ impl Digger {
                 .into()
             );
         } else {
-            for (i, ev) in self.event_log.iter().rev().enumerate() {
+            for (i, entry) in self.event_log.iter().rev().enumerate() {
+                let ev = &entry.event;
                 let sev_color = match ev.severity {
                     EventSeverity::Info => p.green,
                     EventSeverity::Warning => p.yellow,
                     EventSeverity::Critical => p.red,
                 };
                 let row_bg = if i % 2 == 0 { panel_bg } else { bg };
+                let d = self.table_density();
                 let r = container(
                     row![
-                        text(&*ev.timestamp).size(10).font(self.ui_mono).color(label_c).width(80),
-                        text(ev.icon).size(11).color(sev_color).width(20),
-                        text(&ev.message).size(11).color(p.text),
+                        text(&*ev.timestamp).size(d.text_size(10)).font(self.ui_mono).color(label_c).width(80),
+                        text(ev.icon).size(d.text_size(11)).color(sev_color).width(20),
+                        text(if ev.source == EventSource::System { t.system_tag } else { "" })
+                            .size(d.text_size(9)).font(self.ui_mono).color(label_c).width(50),
+                        text(&ev.message).size(d.text_size(11)).font(font_for_text(&ev.message, NERD_FONT)).color(p.text),
+                        Space::with_width(Length::Fill),
+                        text(if entry.count > 1 {
+                            fill(t.event_repeated, &[("n", &entry.count), ("time", &entry.first_seen)])
+                        } else {
+                            String::new()
+                        })
+                        .size(d.text_size(10)).font(self.ui_mono).color(label_c),
+                        tooltip(
+                            button(text(ICON_HISTORY).size(d.text_size(11)).color(label_c))
+                                .on_press(Message::JumpToHistory(entry.at))
+                                .style(button::text)
+                                .padding([0, 4]),
+                            text(t.show_in_history).size(10).font(self.ui_mono),
+                            tooltip::Position::Left,
+                        )
+                        .style(container::rounded_box)
+                        .padding(4),
                     ]
                     .spacing(6)
                     .align_y(Alignment::Center)
                 )
-                .padding([3, 10])
+                .padding([d.pad_y(3), 10])
                 .style(move |_: &Theme| container::Style {
                     background: Some(Background::Color(row_bg)),
                     ..Default::default()
@@ -1131,94 +3560,335 @@ impl Digger {
             p,
         );
 
-        scrollable(column![content].padding(4)).into()
+        scrollable(column![self.view_background_activity(), content].spacing(6).padding(4)).into()
     }
 
-    // ─── SETTINGS VIEW ─────────────────────────────────────────
-
-    fn view_settings(&self) -> Element<'_, Message> {
+    /// Processes that consumed CPU while the session was idle or locked.
+    fn view_background_activity(&self) -> Element<'_, Message> {
         let p = &self.pal;
-        let sidebar_bg = p.sidebar_bg;
-        let border_c = p.border;
-
-        let sidebar = container(
-            column![
-                settings_sidebar_item(
-                    format!("{ICON_SETTINGS}  {}", self.t().general_settings),
-                    SettingsPanel::General,
-                    self.settings_panel,
-                    p, self.ui_mono,
-                ),
-                settings_sidebar_item(
-                    format!("{ICON_PAINT}  {}", self.t().appearance),
-                    SettingsPanel::Appearance,
-                    self.settings_panel,
-                    p, self.ui_mono,
-                ),
-                settings_sidebar_item(
-                    format!("{ICON_ACCESS}  {}", self.t().accessibility),
-                    SettingsPanel::Accessibility,
-                    self.settings_panel,
-                    p, self.ui_mono,
-                ),
-                settings_sidebar_item(
-                    format!("{ICON_NETWORK}  {}", self.t().language),
-                    SettingsPanel::Language,
-                    self.settings_panel,
-                    p, self.ui_mono,
-                ),
-                settings_sidebar_item(
-                    format!("{ICON_INFO}  {}", self.t().about_digger),
-                    SettingsPanel::About,
-                    self.settings_panel,
-                    p, self.ui_mono,
-                ),
-            ]
-            .spacing(2)
-            .padding(8)
-        )
-        .width(170)
-        .height(Length::Fill)
-        .style(move |_: &Theme| container::Style {
-            background: Some(Background::Color(sidebar_bg)),
-            border: Border { color: border_c, width: 1.0, radius: 0.0.into() },
-            shadow: Shadow {
-                color: Color::from_rgba(0.0, 0.0, 0.0, 0.12),
-                offset: Vector::new(2.0, 0.0),
-                blur_radius: 8.0,
-            },
-            ..Default::default()
-        });
+        let t = self.t();
+        let label_c = p.label;
 
-        let detail = match self.settings_panel {
-            SettingsPanel::General => self.view_settings_general(),
-            SettingsPanel::Appearance => self.view_settings_appearance(),
-            SettingsPanel::Accessibility => self.view_settings_accessibility(),
-            SettingsPanel::Language => self.view_settings_language(),
-            SettingsPanel::About => self.view_settings_about(),
+        let (state_label, state_color) = if self.idle_tracker.is_idle() {
+            (t.session_idle, p.yellow)
+        } else {
+            (t.session_active, p.green)
         };
-
-        row![
-            sidebar,
-            scrollable(
-                container(detail).width(Length::Fill).padding(16)
-            ),
+        let title_row = row![
+            text(format!("{ICON_CLOCK} {}", t.background_activity)).size(13).font(self.ui_mono).color(p.accent),
+            Space::with_width(Length::Fill),
+            text(state_label).size(11).font(self.ui_mono).color(state_color),
+            text(format!("{}: {}", t.idle_time, format_duration(self.idle_tracker.idle_secs() as u64)))
+                .size(11).font(self.ui_mono).color(label_c),
+            button(text(t.reset).size(11).color(label_c))
+                .on_press(Message::ResetIdleReport)
+                .style(button::secondary)
+                .padding([3, 10]),
         ]
-        .spacing(0)
-        .height(Length::Fill)
-        .into()
-    }
-
-    fn view_settings_general(&self) -> Element<'_, Message> {
-        let p = &self.pal;
-        let text_c = p.text;
-        let label_c = p.label;
-        let accent = p.accent;
-        let green = p.green;
-        let t = self.t();
+        .spacing(10)
+        .padding([6, 10])
+        .align_y(Alignment::Center);
 
-        let title = column![
-            text(t.general_settings).size(16).font(self.ui_mono).color(text_c),
+        let top = self.idle_tracker.top(10);
+        let mut rows: Vec<Element<Message>> = Vec::new();
+        if top.is_empty() {
+            rows.push(
+                container(
+                    text(t.no_background_activity).size(12).font(self.ui_mono).color(label_c)
+                )
+                .padding([12, 10])
+                .center_x(Length::Fill)
+                .into()
+            );
+        } else {
+            rows.push(
+                row![
+                    text(t.command).size(10).font(self.ui_mono).color(label_c).width(Length::Fill),
+                    text(t.cpu_time).size(10).font(self.ui_mono).color(label_c).width(100),
+                    text(t.wakeups).size(10).font(self.ui_mono).color(label_c).width(80),
+                ]
+                .padding([3, 10])
+                .into()
+            );
+            for (i, entry) in top.iter().enumerate() {
+                let row_bg = if i % 2 == 0 { p.panel_bg } else { p.bg };
+                let r = container(
+                    row![
+                        text(&entry.name).size(11).font(font_for_text(&entry.name, NERD_FONT)).color(p.text).width(Length::Fill),
+                        text(locale::with_unit(entry.cpu_secs, 1, " s")).size(11).font(self.ui_mono).color(p.text).width(100),
+                        text(entry.wakeups.to_string()).size(11).font(self.ui_mono).color(p.text).width(80),
+                    ]
+                    .align_y(Alignment::Center)
+                )
+                .padding([3, 10])
+                .style(move |_: &Theme| container::Style {
+                    background: Some(Background::Color(row_bg)),
+                    ..Default::default()
+                });
+                rows.push(r.into());
+            }
+        }
+
+        panel(
+            column![title_row, Column::with_children(rows).spacing(0)].spacing(0).into(),
+            p,
+        )
+    }
+
+    // ─── HARDWARE TAB ──────────────────────────────────────────
+
+    fn view_hardware(&self) -> Element<'_, Message> {
+        let p = &self.pal;
+        let t = self.t();
+        let label_c = p.label;
+        let text_c = p.text;
+        let Some(hw) = &self.hardware else {
+            return container(text(format!("{ICON_LOADING} {}", t.hw_reading)).size(12).font(self.ui_mono).color(label_c))
+                .padding([20, 10])
+                .center_x(Length::Fill)
+                .into();
+        };
+        let cell = |s: String, color: Color| text(s).size(11).font(self.ui_mono).color(color);
+        let dash = || String::from("—");
+
+        // System, motherboard and BIOS; groups the firmware left blank are skipped
+        let mut system: Vec<Element<Message>> = vec![section_title(format!("{ICON_SERVER} {}", t.hw_system), p, self.ui_mono)];
+        let groups = [
+            (None, [(t.vendor, &hw.system.vendor), (t.model, &hw.system.model), (t.version, &hw.system.version)]),
+            (Some(t.hw_motherboard), [(t.vendor, &hw.board.vendor), (t.model, &hw.board.model), (t.version, &hw.board.version)]),
+            (Some(t.hw_bios), [(t.vendor, &hw.bios.vendor), (t.version, &hw.bios.version), (t.hw_release_date, &hw.bios.date)]),
+        ];
+        for (title, fields) in groups {
+            if fields.iter().all(|(_, v)| v.is_none()) {
+                continue;
+            }
+            if let Some(title) = title {
+                system.push(Space::with_height(4).into());
+                system.push(text(title).size(12).font(self.ui_mono).color(text_c).into());
+            }
+            for (label, value) in fields {
+                if let Some(value) = value {
+                    system.push(info_row(label, value, p, self.ui_mono));
+                }
+            }
+        }
+
+        let cpu = &hw.cpu;
+        let mut cpu_items: Vec<Element<Message>> = vec![section_title(format!("{ICON_CPU} {}", t.cpu), p, self.ui_mono)];
+        if let Some(snap) = &self.current {
+            cpu_items.push(info_row(t.model, &snap.cpu_name, p, self.ui_mono));
+        }
+        if cpu.threads > 0 {
+            cpu_items.push(info_row(t.hw_sockets, cpu.sockets, p, self.ui_mono));
+            cpu_items.push(info_row(t.hw_physical_cores, cpu.cores, p, self.ui_mono));
+            cpu_items.push(info_row(t.logical_cores, cpu.threads, p, self.ui_mono));
+        }
+        if let Some(mhz) = cpu.max_mhz {
+            cpu_items.push(info_row(t.hw_max_clock, locale::with_unit(mhz as f64 / 1000.0, 2, " GHz"), p, self.ui_mono));
+        }
+        if !cpu.caches.is_empty() {
+            cpu_items.push(Space::with_height(4).into());
+            cpu_items.push(text(t.hw_caches).size(12).font(self.ui_mono).color(text_c).into());
+            for cache in &cpu.caches {
+                let size = if cache.instances > 1 {
+                    format!("{} × {}", format_bytes(cache.size), cache.instances)
+                } else {
+                    format_bytes(cache.size)
+                };
+                cpu_items.push(info_row(&cache.name, size, p, self.ui_mono));
+            }
+        }
+
+        let memory = &hw.memory;
+        let mut mem_items: Vec<Element<Message>> = vec![section_title(format!("{ICON_MEMORY} {}", t.memory), p, self.ui_mono)];
+        if let Some(snap) = &self.current {
+            mem_items.push(info_row(t.total_ram, format_bytes(snap.memory_total), p, self.ui_mono));
+        }
+        if let Some(slots) = memory.slots.filter(|&s| s > 0) {
+            let used = fill(t.hw_slots_used, &[("used", &memory.dimms.len()), ("total", &slots)]);
+            mem_items.push(text(used).size(11).font(self.ui_mono).color(label_c).into());
+        }
+        if memory.dimms.is_empty() {
+            let reason = match &memory.error {
+                Some(e) => format!("{ICON_INFO} {}: {e}", t.hw_no_dimms),
+                None => format!("{ICON_INFO} {}", t.hw_no_dimms),
+            };
+            mem_items.push(text(reason).size(11).font(self.ui_mono).color(label_c).into());
+        } else {
+            mem_items.push(
+                row![
+                    cell(t.hw_slot.into(), label_c).width(Length::FillPortion(2)),
+                    cell(t.hw_size.into(), label_c).width(Length::FillPortion(1)),
+                    cell(t.type_label.into(), label_c).width(Length::FillPortion(1)),
+                    cell(t.hw_speed.into(), label_c).width(Length::FillPortion(1)),
+                    cell(t.vendor.into(), label_c).width(Length::FillPortion(2)),
+                    cell(t.hw_part_number.into(), label_c).width(Length::FillPortion(2)),
+                ]
+                .spacing(8)
+                .into()
+            );
+            for dimm in &memory.dimms {
+                mem_items.push(
+                    row![
+                        cell(dimm.slot.clone(), text_c).width(Length::FillPortion(2)),
+                        cell(format_bytes(dimm.size), text_c).width(Length::FillPortion(1)),
+                        cell(dimm.kind.clone().unwrap_or_else(dash), text_c).width(Length::FillPortion(1)),
+                        cell(dimm.speed_mts.map_or_else(dash, |s| format!("{s} MT/s")), text_c).width(Length::FillPortion(1)),
+                        cell(dimm.manufacturer.clone().unwrap_or_else(dash), label_c).width(Length::FillPortion(2)),
+                        cell(dimm.part.clone().unwrap_or_else(dash), label_c).width(Length::FillPortion(2)),
+                    ]
+                    .spacing(8)
+                    .into()
+                );
+            }
+        }
+
+        let mut gpu_items: Vec<Element<Message>> = vec![section_title(format!("{ICON_GPU} {}", t.hw_graphics), p, self.ui_mono)];
+        if hw.gpus.is_empty() {
+            gpu_items.push(text(t.no_gpu).size(11).font(self.ui_mono).color(label_c).into());
+        }
+        for gpu in &hw.gpus {
+            gpu_items.push(
+                row![
+                    cell(gpu.name.clone(), text_c).width(Length::FillPortion(3)),
+                    cell(gpu.driver.as_ref().map_or_else(String::new, |d| format!("{}: {d}", t.hw_driver)), label_c)
+                        .width(Length::FillPortion(1)),
+                ]
+                .spacing(8)
+                .into()
+            );
+        }
+
+        let mut drive_items: Vec<Element<Message>> = vec![section_title(format!("{ICON_DISK} {}", t.hw_storage), p, self.ui_mono)];
+        if hw.drives.is_empty() {
+            drive_items.push(text(t.hw_no_drives).size(11).font(self.ui_mono).color(label_c).into());
+        } else {
+            drive_items.push(
+                row![
+                    cell(t.device.into(), label_c).width(Length::FillPortion(2)),
+                    cell(t.model.into(), label_c).width(Length::FillPortion(3)),
+                    cell(t.type_label.into(), label_c).width(Length::FillPortion(1)),
+                    cell(t.hw_size.into(), label_c).width(Length::FillPortion(1)),
+                ]
+                .spacing(8)
+                .into()
+            );
+            for drive in &hw.drives {
+                let kind = drive.kind();
+                drive_items.push(
+                    row![
+                        cell(drive.name.clone(), text_c).width(Length::FillPortion(2)),
+                        cell(drive.model.clone().unwrap_or_else(dash), text_c).width(Length::FillPortion(3)),
+                        cell(if kind.is_empty() { dash() } else { kind }, label_c).width(Length::FillPortion(1)),
+                        cell(format_bytes(drive.size), text_c).width(Length::FillPortion(1)),
+                    ]
+                    .spacing(8)
+                    .into()
+                );
+            }
+        }
+
+        scrollable(
+            column![
+                panel(Column::with_children(system).spacing(4).into(), p),
+                panel(Column::with_children(cpu_items).spacing(4).into(), p),
+                panel(Column::with_children(mem_items).spacing(4).into(), p),
+                panel(Column::with_children(gpu_items).spacing(4).into(), p),
+                panel(Column::with_children(drive_items).spacing(4).into(), p),
+            ]
+            .spacing(4)
+            .padding(4)
+        )
+        .into()
+    }
+
+    // ─── SETTINGS VIEW ─────────────────────────────────────────
+
+    fn view_settings(&self) -> Element<'_, Message> {
+        let p = &self.pal;
+        let rtl = locale::is_rtl();
+        let sidebar_bg = p.sidebar_bg;
+        let border_c = p.border;
+
+        let sidebar = container(
+            column![
+                settings_sidebar_item(
+                    format!("{ICON_SETTINGS}  {}", self.t().general_settings),
+                    SettingsPanel::General,
+                    self.settings_panel,
+                    p, self.ui_mono,
+                ),
+                settings_sidebar_item(
+                    format!("{ICON_PAINT}  {}", self.t().appearance),
+                    SettingsPanel::Appearance,
+                    self.settings_panel,
+                    p, self.ui_mono,
+                ),
+                settings_sidebar_item(
+                    format!("{ICON_ACCESS}  {}", self.t().accessibility),
+                    SettingsPanel::Accessibility,
+                    self.settings_panel,
+                    p, self.ui_mono,
+                ),
+                settings_sidebar_item(
+                    format!("{ICON_NETWORK}  {}", self.t().language),
+                    SettingsPanel::Language,
+                    self.settings_panel,
+                    p, self.ui_mono,
+                ),
+                settings_sidebar_item(
+                    format!("{ICON_INFO}  {}", self.t().about_digger),
+                    SettingsPanel::About,
+                    self.settings_panel,
+                    p, self.ui_mono,
+                ),
+            ]
+            .spacing(2)
+            .padding(8)
+        )
+        .width(170)
+        .height(Length::Fill)
+        .style(move |_: &Theme| container::Style {
+            background: Some(Background::Color(sidebar_bg)),
+            border: Border { color: border_c, width: 1.0, radius: 0.0.into() },
+            shadow: Shadow {
+                color: Color::from_rgba(0.0, 0.0, 0.0, 0.12),
+                offset: Vector::new(if rtl { -2.0 } else { 2.0 }, 0.0),
+                blur_radius: 8.0,
+            },
+            ..Default::default()
+        });
+
+        let detail = match self.settings_panel {
+            SettingsPanel::General => self.view_settings_general(),
+            SettingsPanel::Appearance => self.view_settings_appearance(),
+            SettingsPanel::Accessibility => self.view_settings_accessibility(),
+            SettingsPanel::Language => self.view_settings_language(),
+            SettingsPanel::About => self.view_settings_about(),
+        };
+
+        directional_row(rtl, vec![
+            sidebar.into(),
+            scrollable(
+                container(detail).width(Length::Fill).padding(16)
+            )
+            .into(),
+        ])
+        .spacing(0)
+        .height(Length::Fill)
+        .into()
+    }
+
+    fn view_settings_general(&self) -> Element<'_, Message> {
+        let p = &self.pal;
+        let text_c = p.text;
+        let label_c = p.label;
+        let accent = p.accent;
+        let green = p.green;
+        let t = self.t();
+
+        let title = column![
+            text(t.general_settings).size(16).font(self.ui_mono).color(text_c),
             text(t.settings_saved_auto).size(11).font(self.ui_mono).color(label_c),
         ]
         .spacing(4);
@@ -1236,36 +3906,64 @@ impl Digger {
             rate_btns.push(btn.into());
         }
 
-        let refresh_row = row![
-            column![
-                text(t.refresh_rate).size(12).font(self.ui_mono).color(text_c),
-                text(t.refresh_rate_desc).size(10).font(self.ui_mono).color(label_c),
-            ].spacing(2).width(Length::FillPortion(2)),
+        let refresh_row = setting_row(
+            t.refresh_rate,
+            t.refresh_rate_desc,
             Row::with_children(rate_btns).spacing(4),
-        ]
-        .align_y(Alignment::Center)
-        .spacing(12);
+            p,
+            self.ui_mono,
+        );
 
-        let temp_toggle = button(
-            text(if self.temp_celsius { ICON_TOGGLE_ON } else { ICON_TOGGLE_OFF })
-                .size(22)
-                .color(if self.temp_celsius { accent } else { label_c })
-        )
-        .on_press(Message::ToggleTempUnit)
-        .style(button::text)
-        .padding(0);
+        let unit_name = |unit: TempUnit| match unit {
+            TempUnit::Celsius => t.celsius,
+            TempUnit::Fahrenheit => t.fahrenheit,
+            TempUnit::Kelvin => t.kelvin,
+        };
+        let mut unit_btns: Vec<Element<Message>> = Vec::new();
+        for &unit in TempUnit::ALL {
+            let is_active = self.temp_unit == unit;
+            let color = if is_active { accent } else { label_c };
+            let btn = button(
+                text(unit.symbol().trim()).size(11).font(self.ui_mono).color(color)
+            )
+            .on_press(Message::SetTempUnit(unit))
+            .style(if is_active { button::primary } else { button::secondary })
+            .padding([4, 12]);
+            unit_btns.push(btn.into());
+        }
 
-        let temp_label = if self.temp_celsius { format!("{} (\u{00b0}C)", t.celsius) } else { format!("{} (\u{00b0}F)", t.fahrenheit) };
+        let temp_label = format!("{} ({})", unit_name(self.temp_unit), self.temp_unit.symbol().trim());
 
-        let temp_row = row![
-            column![
-                text(t.temperature_unit).size(12).font(self.ui_mono).color(text_c),
-                text(format!("{} {temp_label}", t.currently)).size(10).font(self.ui_mono).color(label_c),
-            ].spacing(2).width(Length::FillPortion(2)),
-            temp_toggle,
-        ]
-        .align_y(Alignment::Center)
-        .spacing(12);
+        let temp_row = setting_row(
+            t.temperature_unit,
+            format!("{} {temp_label}", t.currently),
+            Row::with_children(unit_btns).spacing(4),
+            p,
+            self.ui_mono,
+        );
+
+        let rate_units = locale::rate_units();
+        let rate_bits_row = setting_row(
+            t.rate_bits,
+            t.rate_bits_desc,
+            toggle_button(rate_units.bits, Message::ToggleRateBits, p),
+            p,
+            self.ui_mono,
+        );
+        let rate_si_row = setting_row(
+            t.rate_si,
+            t.rate_si_desc,
+            toggle_button(rate_units.si, Message::ToggleRateSi, p),
+            p,
+            self.ui_mono,
+        );
+        let utc_row = setting_row(
+            t.utc_times,
+            t.utc_times_desc,
+            toggle_button(locale::utc(), Message::ToggleUtcTimes, p),
+            p,
+            self.ui_mono,
+        );
 
         let monitoring_section = collapsible_section(
             SettingsSection::Monitoring,
@@ -1276,40 +3974,222 @@ impl Digger {
                 refresh_row,
                 Space::with_height(12),
                 temp_row,
+                Space::with_height(12),
+                self.view_sensor_calibration(),
+                Space::with_height(12),
+                rate_bits_row,
+                Space::with_height(12),
+                rate_si_row,
+                Space::with_height(12),
+                utc_row,
             ].into(),
             p,
             self.ui_mono,
         );
 
-        let process_limit_row = row![
-            column![
-                text(t.process_limit).size(12).font(self.ui_mono).color(text_c),
-                text(t.process_limit_desc).size(10).font(self.ui_mono).color(label_c),
-            ].spacing(2).width(Length::FillPortion(2)),
-            text(format!("{}", self.process_limit)).size(12).font(self.ui_mono).color(accent),
-        ]
-        .align_y(Alignment::Center)
-        .spacing(12);
+        let process_limit_row = setting_row(
+            t.process_limit,
+            t.process_limit_desc,
+            text(format!("{}", self.process_limit)).size(12).font(self.ui_mono).color(accent),
+            p,
+            self.ui_mono,
+        );
+
+        let mut mem_unit_btns: Vec<Element<Message>> = Vec::new();
+        for &unit in MemUnit::ALL {
+            let is_active = self.process_mem_unit == unit;
+            let color = if is_active { accent } else { label_c };
+            let label = match unit {
+                MemUnit::Auto => t.auto_unit,
+                MemUnit::MiB => "MiB",
+                MemUnit::GiB => "GiB",
+            };
+            let btn = button(
+                text(label).size(11).font(self.ui_mono).color(color)
+            )
+            .on_press(Message::SetProcessMemUnit(unit))
+            .style(if is_active { button::primary } else { button::secondary })
+            .padding([4, 12]);
+            mem_unit_btns.push(btn.into());
+        }
+
+        let mem_unit_row = setting_row(
+            t.memory_unit,
+            t.memory_unit_desc,
+            Row::with_children(mem_unit_btns).spacing(4),
+            p,
+            self.ui_mono,
+        );
+
+        let cpu_per_core_row = setting_row(
+            t.cpu_per_core,
+            t.cpu_per_core_desc,
+            toggle_button(self.process_cpu_per_core, Message::ToggleProcessCpuPerCore, p),
+            p,
+            self.ui_mono,
+        );
+
+        let search_all_row = setting_row(
+            t.search_all_processes,
+            t.search_all_processes_desc,
+            toggle_button(self.search_all_processes, Message::ToggleSearchAllProcesses, p),
+            p,
+            self.ui_mono,
+        );
+
+        let smoothing_row = setting_row(
+            t.chart_smoothing,
+            t.chart_smoothing_desc,
+            toggle_button(self.chart_smoothing, Message::ToggleChartSmoothing, p),
+            p,
+            self.ui_mono,
+        );
+
+        let alpha_btns: Vec<Element<Message>> = SMOOTHING_ALPHAS
+            .iter()
+            .map(|&alpha| {
+                let is_active = (self.chart_smoothing_alpha - alpha).abs() < 0.001;
+                button(text(format!("α {}", locale::number(alpha as f64, 2))).size(11).font(self.ui_mono).color(if is_active { accent } else { label_c }))
+                    .on_press(Message::SetChartSmoothingAlpha(alpha))
+                    .style(if is_active { button::primary } else { button::secondary })
+                    .padding([4, 10])
+                    .into()
+            })
+            .collect();
+        let alpha_row = setting_row(
+            t.smoothing_alpha,
+            t.smoothing_alpha_desc,
+            Row::with_children(alpha_btns).spacing(4),
+            p,
+            self.ui_mono,
+        );
+        let smoothing_rows = column![smoothing_row]
+            .push_maybe(self.chart_smoothing.then_some(alpha_row))
+            .spacing(12);
+
+        let elevation_row = setting_row(
+            t.offer_elevation,
+            t.offer_elevation_desc,
+            toggle_button(self.offer_elevation, Message::ToggleOfferElevation, p),
+            p,
+            self.ui_mono,
+        );
+
+        let read_only_control: Element<Message> = if self.read_only_flag {
+            text(t.read_only_flag).size(11).font(self.ui_mono).color(label_c).into()
+        } else {
+            toggle_button(self.read_only, Message::ToggleReadOnly, p)
+        };
+        let read_only_row = setting_row(t.read_only_mode, t.read_only_mode_desc, read_only_control, p, self.ui_mono);
+
+        let mut density_btns: Vec<Element<Message>> = Vec::new();
+        for &density in RowDensity::ALL {
+            let is_active = self.row_density == density;
+            let color = if is_active { accent } else { label_c };
+            let label = match density {
+                RowDensity::Comfortable => t.density_comfortable,
+                RowDensity::Compact => t.density_compact,
+                RowDensity::Touch => t.touch_mode,
+            };
+            let btn = button(
+                text(label).size(11).font(self.ui_mono).color(color)
+            )
+            .on_press(Message::SetRowDensity(density))
+            .style(if is_active { button::primary } else { button::secondary })
+            .padding([4, 12]);
+            density_btns.push(btn.into());
+        }
+
+        let density_row = setting_row(
+            t.row_density,
+            t.row_density_desc,
+            Row::with_children(density_btns).spacing(4),
+            p,
+            self.ui_mono,
+        );
+
+        let touch_row = setting_row(
+            t.touch_mode,
+            t.touch_mode_desc,
+            toggle_button(self.touch_mode, Message::ToggleTouchMode, p),
+            p,
+            self.ui_mono,
+        );
 
+        let live_span_secs = self.live_buffer.capacity() as u64 * self.refresh_interval_secs;
+        let live_span = if live_span_secs < 60 { format!("{live_span_secs}s") } else { format_duration(live_span_secs) };
+        let buffer_btns: Vec<Element<Message>> = LIVE_BUFFER_OPTIONS
+            .iter()
+            .map(|&size| {
+                let is_active = self.live_buffer.capacity() == size;
+                button(text(size.to_string()).size(11).font(self.ui_mono).color(if is_active { accent } else { label_c }))
+                    .on_press(Message::SetLiveBufferSize(size))
+                    .style(if is_active { button::primary } else { button::secondary })
+                    .padding([4, 10])
+                    .into()
+            })
+            .collect();
         let history_points_row = row![
             column![
                 text(t.history_buffer).size(12).font(self.ui_mono).color(text_c),
                 text(t.history_buffer_desc).size(10).font(self.ui_mono).color(label_c),
+                text(fill(t.history_buffer_span, &[("span", &live_span)])).size(10).font(self.ui_mono).color(label_c),
             ].spacing(2).width(Length::FillPortion(2)),
-            text(format!("{}", self.live_max)).size(12).font(self.ui_mono).color(accent),
+            Row::with_children(buffer_btns).spacing(4),
         ]
         .align_y(Alignment::Center)
         .spacing(12);
 
-        let retention_row = row![
-            column![
-                text(t.history_retention).size(12).font(self.ui_mono).color(text_c),
-                text(t.history_retention_desc).size(10).font(self.ui_mono).color(label_c),
-            ].spacing(2).width(Length::FillPortion(2)),
+        let retention_row = setting_row(
+            t.history_retention,
+            t.history_retention_desc,
             text(format!("{}h", self.retention_hours)).size(12).font(self.ui_mono).color(accent),
-        ]
-        .align_y(Alignment::Center)
-        .spacing(12);
+            p,
+            self.ui_mono,
+        );
+
+        let sidecar_row = setting_row(
+            t.schema_sidecar,
+            t.schema_sidecar_desc,
+            toggle_button(self.export_schema_sidecar, Message::ToggleSchemaSidecar, p),
+            p,
+            self.ui_mono,
+        );
+
+        let kiosk_row = setting_row(
+            t.kiosk_mode,
+            t.kiosk_mode_desc,
+            toggle_button(self.kiosk_mode, Message::ToggleKioskMode, p),
+            p,
+            self.ui_mono,
+        );
+
+        let rotate_row = setting_row(
+            t.rotate_panels,
+            t.rotate_panels_desc,
+            toggle_button(self.overview_cycle, Message::ToggleOverviewCycle, p),
+            p,
+            self.ui_mono,
+        );
+
+        let cycle_btns: Vec<Element<Message>> = OVERVIEW_CYCLE_OPTIONS
+            .iter()
+            .map(|&secs| {
+                let is_active = self.overview_cycle_secs == secs;
+                button(text(format!("{secs}s")).size(11).font(self.ui_mono).color(if is_active { accent } else { label_c }))
+                    .on_press(Message::SetOverviewCycle(secs))
+                    .style(if is_active { button::primary } else { button::secondary })
+                    .padding([4, 12])
+                    .into()
+            })
+            .collect();
+        let cycle_row = setting_row(
+            t.panel_rotation,
+            t.panel_rotation_desc,
+            Row::with_children(cycle_btns).spacing(4),
+            p,
+            self.ui_mono,
+        );
 
         let display_section = collapsible_section(
             SettingsSection::Display,
@@ -1319,9 +4199,33 @@ impl Digger {
             column![
                 process_limit_row,
                 Space::with_height(12),
+                search_all_row,
+                Space::with_height(12),
+                mem_unit_row,
+                Space::with_height(12),
+                cpu_per_core_row,
+                Space::with_height(12),
+                smoothing_rows,
+                Space::with_height(12),
+                elevation_row,
+                Space::with_height(12),
+                read_only_row,
+                Space::with_height(12),
+                density_row,
+                Space::with_height(12),
+                touch_row,
+                Space::with_height(12),
                 history_points_row,
                 Space::with_height(12),
                 retention_row,
+                Space::with_height(12),
+                sidecar_row,
+                Space::with_height(12),
+                kiosk_row,
+                Space::with_height(12),
+                rotate_row,
+                Space::with_height(12),
+                cycle_row,
             ].into(),
             p,
             self.ui_mono,
@@ -1335,20 +4239,129 @@ impl Digger {
         let db_color = if self.history.is_available() { green } else { p.red };
 
         let mut data_items: Vec<Element<Message>> = vec![
-            row![
-                column![
-                    text(t.history_database).size(12).font(self.ui_mono).color(text_c),
-                    text(t.history_database_desc).size(10).font(self.ui_mono).color(label_c),
-                ].spacing(2).width(Length::FillPortion(2)),
+            setting_row(
+                t.history_database,
+                t.history_database_desc,
                 text(db_status).size(11).font(self.ui_mono).color(db_color),
-            ]
-            .align_y(Alignment::Center)
-            .spacing(12)
+                p,
+                self.ui_mono,
+            )
             .into(),
         ];
 
+        data_items.push(Space::with_height(12).into());
+        data_items.push(
+            column![
+                text(t.recorded_metrics).size(12).font(self.ui_mono).color(text_c),
+                text(t.recorded_metrics_desc).size(10).font(self.ui_mono).color(label_c),
+            ]
+            .spacing(2)
+            .into()
+        );
+        for group in MetricGroup::ALL {
+            let (name, desc) = match group {
+                MetricGroup::Network => (t.record_network, t.record_network_desc),
+                MetricGroup::Gpu => (t.record_gpu, t.record_gpu_desc),
+                MetricGroup::Power => (t.record_power, t.record_power_desc),
+                MetricGroup::DiskUsage => (t.record_disk_usage, t.record_disk_usage_desc),
+            };
+            data_items.push(Space::with_height(6).into());
+            data_items.push(
+                setting_row(
+                    name,
+                    desc,
+                    toggle_button(self.recorded.contains(group), Message::ToggleRecorded(group), p),
+                    p,
+                    self.ui_mono,
+                )
+                .into()
+            );
+        }
+
+        data_items.push(Space::with_height(12).into());
+        data_items.push(
+            setting_row(
+                t.adaptive_recording,
+                t.adaptive_recording_desc,
+                toggle_button(self.adaptive_recording, Message::ToggleAdaptiveRecording, p),
+                p,
+                self.ui_mono,
+            )
+            .into()
+        );
+        if self.adaptive_recording {
+            data_items.push(Space::with_height(6).into());
+            data_items.push(
+                setting_row(
+                    t.full_rate_above,
+                    t.full_rate_above_desc,
+                    make_threshold_buttons(
+                        self.adaptive_recording_threshold,
+                        &[50.0, 70.0, 80.0, 90.0],
+                        |v| locale::percent(v, 0),
+                        Message::SetAdaptiveRecordingThreshold,
+                        accent,
+                        label_c,
+                        self.ui_mono,
+                    ),
+                    p,
+                    self.ui_mono,
+                )
+                .into()
+            );
+        }
+
+        data_items.push(Space::with_height(12).into());
+        data_items.push(
+            setting_row(
+                t.log_file,
+                t.log_file_desc,
+                row![
+                    text_input("/var/log/syslog", &self.log_tail_path)
+                        .on_input(Message::LogTailPathChanged)
+                        .on_submit(Message::ApplyLogTail)
+                        .size(11)
+                        .width(Length::Fill),
+                    button(text(t.apply).size(11).font(self.ui_mono).color(label_c))
+                        .on_press(Message::ApplyLogTail)
+                        .style(button::secondary)
+                        .padding([3, 10]),
+                ]
+                .spacing(12)
+                .align_y(Alignment::Center)
+                .width(Length::FillPortion(2)),
+                p,
+                self.ui_mono,
+            )
+            .into()
+        );
+        data_items.push(Space::with_height(6).into());
+        data_items.push(
+            setting_row(
+                t.ping_host,
+                t.ping_host_desc,
+                row![
+                    text_input("1.1.1.1", &self.ping_host)
+                        .on_input(Message::PingHostChanged)
+                        .on_submit(Message::ApplyPingHost)
+                        .size(11)
+                        .width(Length::Fill),
+                    button(text(t.apply).size(11).font(self.ui_mono).color(label_c))
+                        .on_press(Message::ApplyPingHost)
+                        .style(button::secondary)
+                        .padding([3, 10]),
+                ]
+                .spacing(12)
+                .align_y(Alignment::Center)
+                .width(Length::FillPortion(2)),
+                p,
+                self.ui_mono,
+            )
+            .into()
+        );
+
         // Show DB error if any
-        if let Some(err) = &self.history.last_error {
+        if let Some(err) = self.history_error() {
             data_items.push(Space::with_height(6).into());
             data_items.push(
                 text(format!("{ICON_WARNING} {err}")).size(10).color(p.red).into()
@@ -1369,15 +4382,182 @@ impl Digger {
         let cpu_alert_btns = make_threshold_buttons(
             self.cpu_alert_threshold,
             &[70.0, 80.0, 90.0, 95.0],
+            |v| locale::percent(v, 0),
             Message::SetCpuAlertThreshold,
             accent, label_c, self.ui_mono,
         );
         let mem_alert_btns = make_threshold_buttons(
             self.mem_alert_threshold,
             &[70.0, 80.0, 90.0, 95.0],
+            |v| locale::percent(v, 0),
             Message::SetMemAlertThreshold,
             accent, label_c, self.ui_mono,
         );
+        let vram_alert_btns = make_threshold_buttons(
+            self.vram_alert_threshold,
+            &[70.0, 80.0, 90.0, 95.0],
+            |v| locale::percent(v, 0),
+            Message::SetVramAlertThreshold,
+            accent, label_c, self.ui_mono,
+        );
+        let gpu_temp_alert_btns = self.temp_threshold_input(TempAlert::Gpu, &[75.0, 80.0, 85.0, 90.0]);
+        let temp_alert_btns = self.temp_threshold_input(TempAlert::Sensor, &[70.0, 80.0, 85.0, 95.0]);
+
+        let mut sensitivity_btns: Vec<Element<Message>> = Vec::new();
+        for &sensitivity in Sensitivity::ALL {
+            let is_active = self.anomaly_sensitivity == sensitivity;
+            let color = if is_active { accent } else { label_c };
+            let label = match sensitivity {
+                Sensitivity::Low => t.sensitivity_low,
+                Sensitivity::Medium => t.sensitivity_medium,
+                Sensitivity::High => t.sensitivity_high,
+            };
+            sensitivity_btns.push(
+                button(text(label).size(11).font(self.ui_mono).color(color))
+                    .on_press(Message::SetAnomalySensitivity(sensitivity))
+                    .style(if is_active { button::primary } else { button::secondary })
+                    .padding([4, 12])
+                    .into(),
+            );
+        }
+
+        let mut horizon_btns: Vec<Element<Message>> = Vec::new();
+        for &horizon in Horizon::ALL {
+            let is_active = self.forecast_horizon == horizon;
+            let color = if is_active { accent } else { label_c };
+            horizon_btns.push(
+                button(text(horizon.label()).size(11).font(self.ui_mono).color(color))
+                    .on_press(Message::SetForecastHorizon(horizon))
+                    .style(if is_active { button::primary } else { button::secondary })
+                    .padding([4, 12])
+                    .into(),
+            );
+        }
+
+        let mut notify_btns: Vec<Element<Message>> = Vec::new();
+        for &level in NotifyLevel::ALL {
+            let is_active = self.notify_level == level;
+            let color = if is_active { accent } else { label_c };
+            let label = match level {
+                NotifyLevel::All => t.notify_all,
+                NotifyLevel::Warning => t.notify_warnings,
+                NotifyLevel::Critical => t.notify_critical,
+                NotifyLevel::Off => t.notify_off,
+            };
+            notify_btns.push(
+                button(text(label).size(11).font(self.ui_mono).color(color))
+                    .on_press(Message::SetNotifyLevel(level))
+                    .style(if is_active { button::primary } else { button::secondary })
+                    .padding([4, 12])
+                    .into(),
+            );
+        }
+
+        let mut watch_items: Vec<Element<Message>> = vec![
+            setting_row(
+                t.watched_processes,
+                t.watched_processes_desc,
+                row![
+                    text_input(t.process_name, &self.watch_input)
+                        .on_input(Message::WatchInputChanged)
+                        .on_submit(Message::AddWatch)
+                        .size(11)
+                        .width(Length::Fill),
+                    button(text(t.add).size(11).font(self.ui_mono).color(label_c))
+                        .on_press(Message::AddWatch)
+                        .style(button::secondary)
+                        .padding([3, 10]),
+                ]
+                .spacing(12)
+                .align_y(Alignment::Center)
+                .width(Length::FillPortion(2)),
+                p,
+                self.ui_mono,
+            )
+            .into(),
+        ];
+        for name in &self.watched_processes {
+            watch_items.push(
+                row![
+                    text(format!("{ICON_EYE} {name}")).size(11).font(self.ui_mono).color(text_c).width(Length::Fill),
+                    labeled(
+                        button(text(ICON_CLOSE).size(10).color(label_c))
+                            .on_press(Message::RemoveWatch(name.clone()))
+                            .style(button::text)
+                            .padding([1, 4]),
+                        t.remove,
+                        self.ui_mono,
+                    ),
+                ]
+                .align_y(Alignment::Center)
+                .into()
+            );
+        }
+
+        let mut port_watch_items: Vec<Element<Message>> = vec![
+            setting_row(
+                t.port_watch,
+                t.port_watch_desc,
+                toggle_button(self.port_watch.is_some(), Message::TogglePortWatch, p),
+                p,
+                self.ui_mono,
+            )
+            .into(),
+        ];
+        if self.port_watch.is_some() {
+            port_watch_items.push(Space::with_height(8).into());
+            port_watch_items.push(
+                setting_row(
+                    t.port_watch_notify,
+                    t.port_watch_notify_desc,
+                    toggle_button(self.port_watch_notify, Message::TogglePortWatchNotify, p),
+                    p,
+                    self.ui_mono,
+                )
+                .into()
+            );
+            port_watch_items.push(Space::with_height(8).into());
+            port_watch_items.push(
+                setting_row(
+                    t.port_allowlist,
+                    t.port_allowlist_desc,
+                    row![
+                        text_input(t.port_or_process, &self.port_allow_input)
+                            .on_input(Message::PortAllowInputChanged)
+                            .on_submit(Message::AddPortAllow)
+                            .size(11)
+                            .width(Length::Fill),
+                        button(text(t.add).size(11).font(self.ui_mono).color(label_c))
+                            .on_press(Message::AddPortAllow)
+                            .style(button::secondary)
+                            .padding([3, 10]),
+                    ]
+                    .spacing(12)
+                    .align_y(Alignment::Center)
+                    .width(Length::FillPortion(2)),
+                    p,
+                    self.ui_mono,
+                )
+                .into()
+            );
+            for entry in &self.port_allowlist {
+                port_watch_items.push(
+                    row![
+                        text(format!("{ICON_SHIELD} {entry}")).size(11).font(self.ui_mono).color(text_c).width(Length::Fill),
+                        labeled(
+                            button(text(ICON_CLOSE).size(10).color(label_c))
+                                .on_press(Message::RemovePortAllow(entry.clone()))
+                                .style(button::text)
+                                .padding([1, 4]),
+                            t.remove,
+                            self.ui_mono,
+                        ),
+                    ]
+                    .align_y(Alignment::Center)
+                    .into()
+                );
+            }
+        }
 
         let alerts_section = collapsible_section(
             SettingsSection::Alerts,
@@ -1385,21 +4565,59 @@ impl Digger {
             t.alerts_desc,
             self.collapsed_sections.contains(&SettingsSection::Alerts),
             column![
-                row![
-                    column![
-                        text(t.cpu_threshold).size(12).font(self.ui_mono).color(text_c),
-                        text(t.cpu_threshold_desc).size(10).font(self.ui_mono).color(label_c),
-                    ].spacing(2).width(Length::FillPortion(2)),
-                    cpu_alert_btns,
-                ].align_y(Alignment::Center).spacing(12),
+                setting_row(t.cpu_threshold, t.cpu_threshold_desc, cpu_alert_btns, p, self.ui_mono),
                 Space::with_height(12),
-                row![
-                    column![
-                        text(t.memory_threshold).size(12).font(self.ui_mono).color(text_c),
-                        text(t.memory_threshold_desc).size(10).font(self.ui_mono).color(label_c),
-                    ].spacing(2).width(Length::FillPortion(2)),
-                    mem_alert_btns,
-                ].align_y(Alignment::Center).spacing(12),
+                setting_row(t.memory_threshold, t.memory_threshold_desc, mem_alert_btns, p, self.ui_mono),
+                Space::with_height(12),
+                setting_row(t.vram_threshold, t.vram_threshold_desc, vram_alert_btns, p, self.ui_mono),
+                Space::with_height(12),
+                setting_row(t.gpu_temp_threshold, t.gpu_temp_threshold_desc, gpu_temp_alert_btns, p, self.ui_mono),
+                Space::with_height(12),
+                setting_row(t.temp_threshold, t.temp_threshold_desc, temp_alert_btns, p, self.ui_mono),
+                Space::with_height(12),
+                setting_row(
+                    t.anomaly_sensitivity,
+                    t.anomaly_sensitivity_desc,
+                    Row::with_children(sensitivity_btns).spacing(4),
+                    p,
+                    self.ui_mono,
+                ),
+                Space::with_height(12),
+                setting_row(
+                    t.forecast_horizon,
+                    t.forecast_horizon_desc,
+                    Row::with_children(horizon_btns).spacing(4),
+                    p,
+                    self.ui_mono,
+                ),
+                Space::with_height(12),
+                setting_row(
+                    t.notify_level,
+                    t.notify_level_desc,
+                    Row::with_children(notify_btns).spacing(4),
+                    p,
+                    self.ui_mono,
+                ),
+                Space::with_height(12),
+                setting_row(
+                    t.net_notifications,
+                    t.net_notifications_desc,
+                    toggle_button(self.notify_net_changes, Message::ToggleNetNotifications, p),
+                    p,
+                    self.ui_mono,
+                ),
+                Space::with_height(12),
+                setting_row(
+                    t.system_log_events,
+                    t.system_log_events_desc,
+                    toggle_button(self.system_events.is_some(), Message::ToggleSystemLogEvents, p),
+                    p,
+                    self.ui_mono,
+                ),
+                Space::with_height(12),
+                Column::with_children(watch_items).spacing(4),
+                Space::with_height(12),
+                Column::with_children(port_watch_items).spacing(4),
             ].into(),
             p,
             self.ui_mono,
@@ -1467,11 +4685,15 @@ impl Digger {
             let mut variant_btns: Vec<Element<Message>> = Vec::new();
             for &variant in *variants {
                 let is_active = self.theme_variant == variant;
-                // Use cached palette instead of rebuilding every frame
-                let pv = self.cached_theme_previews.iter()
-                    .find(|(v, _)| *v == variant)
-                    .map(|(_, p)| p.clone())
-                    .unwrap_or_else(|| build_palette(variant, self.accent_color));
+                // Borrow the cached palette instead of rebuilding or copying it every frame
+                let fallback;
+                let pv = match self.cached_theme_previews.iter().find(|(v, _)| *v == variant) {
+                    Some((_, pv)) => pv,
+                    None => {
+                        fallback = build_palette(variant, self.accent_color, self.custom_accent, self.color_vision);
+                        &fallback
+                    }
+                };
                 let pv_bg = pv.bg;
                 let pv_panel = pv.panel_bg;
                 let pv_text = pv.text;
@@ -1555,9 +4777,9 @@ impl Digger {
 
         // Accent color selector
         let mut accent_btns: Vec<Element<Message>> = Vec::new();
-        for &ac in AccentColor::ALL {
+        for &ac in AccentColor::ALL.iter().chain([&AccentColor::Custom]) {
             let is_active = self.accent_color == ac;
-            let ac_color = ac.color();
+            let ac_color = ac.color(self.custom_accent);
             let check_color = text_c;
             let btn_border = if is_active { text_c } else { Color::TRANSPARENT };
 
@@ -1607,7 +4829,12 @@ impl Digger {
             t.accent_color,
             t.accent_color_desc,
             self.collapsed_sections.contains(&SettingsSection::Accent),
-            Row::with_children(accent_btns).spacing(8).into(),
+            column![
+                Row::with_children(accent_btns).spacing(8),
+                self.custom_accent_picker(),
+            ]
+            .spacing(12)
+            .into(),
             p,
             self.ui_mono,
         );
@@ -1619,7 +4846,7 @@ impl Digger {
                     .size(11).font(self.ui_mono).color(accent),
                 Space::with_width(12),
                 text(format!("{ICON_BULLET} Accent: {}", self.accent_color.name()))
-                    .size(11).font(self.ui_mono).color(self.accent_color.color()),
+                    .size(11).font(self.ui_mono).color(accent),
             ]
         )
         .padding([8, 12])
@@ -1652,7 +4879,6 @@ impl Digger {
         let p = &self.pal;
         let text_c = p.text;
         let label_c = p.label;
-        let accent = p.accent;
         let t = self.t();
 
         let title = column![
@@ -1661,14 +4887,7 @@ impl Digger {
         ]
         .spacing(4);
 
-        let dyslexic_toggle = button(
-            text(if self.use_dyslexic_font { ICON_TOGGLE_ON } else { ICON_TOGGLE_OFF })
-                .size(22)
-                .color(if self.use_dyslexic_font { accent } else { label_c })
-        )
-        .on_press(Message::ToggleDyslexicFont)
-        .style(button::text)
-        .padding(0);
+        let dyslexic_toggle = toggle_button(self.use_dyslexic_font, Message::ToggleDyslexicFont, p);
 
         let font_status = if self.use_dyslexic_font { t.enabled } else { t.disabled };
 
@@ -1678,24 +4897,71 @@ impl Digger {
             t.fonts_desc,
             self.collapsed_sections.contains(&SettingsSection::Fonts),
             column![
-                row![
-                    column![
-                        text(t.dyslexic_font).size(12).font(self.ui_mono).color(text_c),
-                        text(format!("{} {} {font_status}", t.dyslexic_font_desc, t.currently)).size(10).font(self.ui_mono).color(label_c),
-                    ].spacing(2).width(Length::FillPortion(2)),
+                setting_row(
+                    t.dyslexic_font,
+                    format!("{} {} {font_status}", t.dyslexic_font_desc, t.currently),
                     dyslexic_toggle,
-                ]
-                .align_y(Alignment::Center)
-                .spacing(12),
+                    p,
+                    self.ui_mono,
+                ),
             ].into(),
             p,
             self.ui_mono,
         );
 
+        let mut vision_btns: Vec<Element<Message>> = Vec::new();
+        for &vision in ColorVision::ALL {
+            let is_active = self.color_vision == vision;
+            let label = match vision {
+                ColorVision::Normal => t.color_vision_normal,
+                ColorVision::Deuteranopia => t.color_vision_deuteranopia,
+                ColorVision::Protanopia => t.color_vision_protanopia,
+                ColorVision::Tritanopia => t.color_vision_tritanopia,
+            };
+            vision_btns.push(
+                button(text(label).size(11).font(self.ui_mono).color(if is_active { p.accent } else { label_c }))
+                    .on_press(Message::SetColorVision(vision))
+                    .style(if is_active { button::primary } else { button::secondary })
+                    .padding([4, 12])
+                    .into(),
+            );
+        }
+        // good → warning → critical, as gauges and charts will show them
+        let swatches = Row::with_children([0.0, 0.5, 1.0].map(|level| {
+            let color = gradient_color(level, p);
+            container(Space::new(0, 0))
+                .width(28)
+                .height(12)
+                .style(move |_: &Theme| container::Style {
+                    background: Some(Background::Color(color)),
+                    border: Border { radius: 3.0.into(), ..Default::default() },
+                    ..Default::default()
+                })
+                .into()
+        }))
+        .spacing(4);
+
+        let vision_section = collapsible_section(
+            SettingsSection::ColorVision,
+            t.color_vision,
+            t.color_vision_desc,
+            self.collapsed_sections.contains(&SettingsSection::ColorVision),
+            column![
+                Row::with_children(vision_btns).spacing(4),
+                swatches,
+            ]
+            .spacing(10)
+            .into(),
+            p,
+            self.ui_mono,
+        );
+
         column![
             title,
             Space::with_height(16),
             font_section,
+            Space::with_height(6),
+            vision_section,
         ]
         .spacing(4)
         .into()
@@ -1876,39 +5142,309 @@ impl Digger {
                 info_row(t.os, &snap.sys_info.os_name, p, self.ui_mono),
                 info_row(t.os_version, &snap.sys_info.os_version, p, self.ui_mono),
                 info_row(t.kernel, &snap.sys_info.kernel_version, p, self.ui_mono),
+            ]
+            .push_maybe(snap.sys_info.hypervisor.as_ref().map(|h| info_row(t.virtualized, h.name(), p, self.ui_mono)))
+            .extend([
                 info_row(t.cpu, &snap.cpu_name, p, self.ui_mono),
                 info_row(t.cores, snap.cpu_core_count.to_string(), p, self.ui_mono),
                 info_row(t.total_ram, format_bytes(snap.memory_total), p, self.ui_mono),
-            ].spacing(6)
+                Space::with_height(2).into(),
+                button(text(format!("{ICON_COPY} {}", t.copy_system_summary)).size(11).font(self.ui_mono).color(p.text))
+                    .on_press(Message::CopySystemSummary)
+                    .style(button::secondary)
+                    .padding([4, 12])
+                    .into(),
+            ])
+            .spacing(6)
         } else {
             column![
                 text(t.waiting_for_data).size(11).font(self.ui_mono).color(label_c),
             ]
         };
 
-        let system_section = collapsible_section(
-            SettingsSection::SystemInfo,
-            t.system_information,
-            "",
-            self.collapsed_sections.contains(&SettingsSection::SystemInfo),
-            sys_items.into(),
-            p,
-            self.ui_mono,
-        );
+        let system_section = collapsible_section(
+            SettingsSection::SystemInfo,
+            t.system_information,
+            "",
+            self.collapsed_sections.contains(&SettingsSection::SystemInfo),
+            sys_items.into(),
+            p,
+            self.ui_mono,
+        );
+
+        let timings_section = collapsible_section(
+            SettingsSection::Timings,
+            t.collection_timings,
+            t.collection_timings_desc,
+            self.collapsed_sections.contains(&SettingsSection::Timings),
+            self.view_collect_timings(),
+            p,
+            self.ui_mono,
+        );
+
+        let updates_section = collapsible_section(
+            SettingsSection::Updates,
+            t.updates,
+            t.updates_desc,
+            self.collapsed_sections.contains(&SettingsSection::Updates),
+            self.view_update_check(),
+            p,
+            self.ui_mono,
+        );
+
+        let diagnostics_section = collapsible_section(
+            SettingsSection::Diagnostics,
+            t.diagnostics,
+            t.diagnostics_desc,
+            self.collapsed_sections.contains(&SettingsSection::Diagnostics),
+            button(text(format!("{ICON_EXPORT} {}", t.create_diagnostics_bundle)).size(11).font(self.ui_mono).color(p.text))
+                .on_press(Message::CreateDiagnosticsBundle)
+                .style(button::secondary)
+                .padding([4, 12])
+                .into(),
+            p,
+            self.ui_mono,
+        );
+
+        column![
+            title,
+            Space::with_height(16),
+            version_section,
+            Space::with_height(8),
+            font_section,
+            Space::with_height(8),
+            system_section,
+            Space::with_height(8),
+            timings_section,
+            Space::with_height(8),
+            updates_section,
+            Space::with_height(8),
+            diagnostics_section,
+        ]
+        .spacing(4)
+        .into()
+    }
+
+    /// Hue, saturation and value sliders plus a hex field for the custom
+    /// accent. Moving any of them selects it.
+    fn custom_accent_picker(&self) -> Element<'_, Message> {
+        let p = &self.pal;
+        let t = self.t();
+        let label_c = p.label;
+        let [h, s, v] = self.custom_accent_hsv;
+        let swatch = AccentColor::Custom.color(self.custom_accent);
+        let channel = |name: &'static str, value: String, control: Element<'static, Message>| -> Element<'static, Message> {
+            row![
+                text(name).size(11).font(self.ui_mono).color(label_c).width(80),
+                control,
+                text(value).size(11).font(self.ui_mono).color(label_c).width(40),
+            ]
+            .spacing(8)
+            .align_y(Alignment::Center)
+            .into()
+        };
+        let hex = theme_colors::to_hex(self.custom_accent);
+        column![
+            row![
+                container(Space::new(0, 0)).width(24).height(24).style(move |_: &Theme| container::Style {
+                    background: Some(Background::Color(swatch)),
+                    border: Border { color: Color::TRANSPARENT, width: 0.0, radius: 12.0.into() },
+                    ..Default::default()
+                }),
+                text_input(&hex, &self.custom_accent_input)
+                    .on_input(Message::CustomAccentInput)
+                    .on_submit(Message::ApplyCustomAccentHex)
+                    .size(11)
+                    .width(90),
+                text(t.custom_accent_desc).size(10).font(self.ui_mono).color(label_c),
+            ]
+            .spacing(8)
+            .align_y(Alignment::Center),
+            channel(
+                t.accent_hue,
+                format!("{h:.0}°"),
+                slider(0.0..=360.0, h, move |h| Message::SetCustomAccentHsv([h, s, v]))
+                    .on_release(Message::SaveCustomAccent)
+                    .width(Length::Fill)
+                    .into(),
+            ),
+            channel(
+                t.accent_saturation,
+                locale::percent(s * 100.0, 0),
+                slider(0.0..=1.0, s, move |s| Message::SetCustomAccentHsv([h, s, v]))
+                    .step(0.01)
+                    .on_release(Message::SaveCustomAccent)
+                    .width(Length::Fill)
+                    .into(),
+            ),
+            channel(
+                t.accent_brightness,
+                locale::percent(v * 100.0, 0),
+                slider(0.0..=1.0, v, move |v| Message::SetCustomAccentHsv([h, s, v]))
+                    .step(0.01)
+                    .on_release(Message::SaveCustomAccent)
+                    .width(Length::Fill)
+                    .into(),
+            ),
+        ]
+        .spacing(6)
+        .into()
+    }
+
+    /// Preset buttons plus a field for any value, both in the display unit;
+    /// the threshold itself stays in °C.
+    fn temp_threshold_input(&self, alert: TempAlert, presets: &[f32]) -> Element<'_, Message> {
+        let p = &self.pal;
+        let unit = self.temp_unit;
+        let (current, input, on_press): (f32, &str, fn(f32) -> Message) = match alert {
+            TempAlert::Sensor => (self.temp_alert_threshold, &self.temp_alert_input, Message::SetTempAlertThreshold),
+            TempAlert::Gpu => (self.gpu_temp_alert_threshold, &self.gpu_temp_alert_input, Message::SetGpuTempAlertThreshold),
+        };
+        let placeholder = locale::number(unit.convert(current as f64), 0);
+        row![
+            make_threshold_buttons(current, presets, move |v| locale::temperature(v, unit), on_press, p.accent, p.label, self.ui_mono),
+            text_input(&placeholder, input)
+                .on_input(move |s| Message::TempThresholdInput(alert, s))
+                .on_submit(Message::ApplyTempThreshold(alert))
+                .size(11)
+                .width(56),
+            text(unit.symbol().trim()).size(11).font(self.ui_mono).color(p.label),
+        ]
+        .spacing(4)
+        .align_y(Alignment::Center)
+        .into()
+    }
+
+    /// One row per sensor of the current snapshot with −/+ buttons for its
+    /// offset, in steps of `SENSOR_OFFSET_STEP_C`.
+    fn view_sensor_calibration(&self) -> Element<'_, Message> {
+        let p = &self.pal;
+        let t = self.t();
+        let label_c = p.label;
+        let text_c = p.text;
+        let mut items: Vec<Element<Message>> = vec![
+            text(t.sensor_calibration).size(12).font(self.ui_mono).color(text_c).into(),
+            text(t.sensor_calibration_desc).size(10).font(self.ui_mono).color(label_c).into(),
+        ];
+        let temps = self.current.as_ref().map(|s| s.temperatures.as_slice()).unwrap_or_default();
+        if temps.is_empty() {
+            items.push(text(t.no_sensors).size(11).font(self.ui_mono).color(label_c).into());
+        }
+        for sensor in temps {
+            let offset = self.sensor_offsets.get(&sensor.id).copied().unwrap_or(0.0);
+            let step = |icon: &'static str, delta: f32| {
+                button(text(icon).size(11).color(label_c))
+                    .on_press(Message::AdjustSensorOffset(sensor.id.clone(), delta))
+                    .style(button::secondary)
+                    .padding([2, 8])
+            };
+            let reset: Element<Message> = if offset != 0.0 {
+                labeled(
+                    button(text(ICON_CLOSE).size(11).color(label_c))
+                        .on_press(Message::ResetSensorOffset(sensor.id.clone()))
+                        .style(button::text)
+                        .padding([2, 6]),
+                    t.reset,
+                    self.ui_mono,
+                )
+            } else {
+                Space::with_width(26).into()
+            };
+            items.push(
+                row![
+                    text(&sensor.id).size(11).font(font_for_text(&sensor.id, self.ui_mono)).color(text_c).width(Length::FillPortion(2)),
+                    text(format_temp(sensor.temp_c, self.temp_unit)).size(11).font(self.ui_mono).color(label_c).width(70),
+                    step("\u{2212}", -SENSOR_OFFSET_STEP_C),
+                    text(locale::temperature_delta(offset, self.temp_unit))
+                        .size(11).font(self.ui_mono).color(if offset != 0.0 { p.accent } else { label_c })
+                        .width(70).align_x(iced::alignment::Horizontal::Center),
+                    step("+", SENSOR_OFFSET_STEP_C),
+                    reset,
+                ]
+                .spacing(6)
+                .align_y(Alignment::Center)
+                .into(),
+            );
+        }
+        Column::with_children(items).spacing(4).into()
+    }
 
+    fn view_update_check(&self) -> Element<'_, Message> {
+        let p = &self.pal;
+        let t = self.t();
+        let (status, color) = match (&self.update_check, &self.update_status) {
+            (Some(_), _) => (t.checking_updates.to_string(), p.label),
+            (None, Some(UpdateStatus::UpToDate)) => (fill(t.up_to_date, &[("version", &env!("CARGO_PKG_VERSION"))]), p.green),
+            (None, Some(UpdateStatus::Available(release))) => (fill(t.update_available, &[("version", &release.version)]), p.accent),
+            (None, Some(UpdateStatus::Failed(e))) => (fill(t.update_failed, &[("error", e)]), p.red),
+            (None, None) => (String::new(), p.label),
+        };
+        let release_notes = matches!(self.update_status, Some(UpdateStatus::Available(_))).then(|| {
+            button(text(t.release_notes).size(11).font(self.ui_mono))
+                .on_press(Message::OpenReleasePage)
+                .style(button::primary)
+                .padding([4, 12])
+        });
         column![
-            title,
-            Space::with_height(16),
-            version_section,
-            Space::with_height(8),
-            font_section,
-            Space::with_height(8),
-            system_section,
+            row![
+                button(text(t.check_for_updates).size(11).font(self.ui_mono).color(p.text))
+                    .on_press_maybe(self.update_check.is_none().then_some(Message::CheckForUpdates))
+                    .style(button::secondary)
+                    .padding([4, 12]),
+                text(status).size(11).font(self.ui_mono).color(color),
+            ]
+            .push_maybe(release_notes)
+            .spacing(12)
+            .align_y(Alignment::Center),
+            setting_row(
+                t.check_weekly,
+                t.check_weekly_desc,
+                toggle_button(self.check_updates, Message::ToggleUpdateChecks, p),
+                p,
+                self.ui_mono,
+            ),
         ]
-        .spacing(4)
+        .spacing(12)
         .into()
     }
 
+    /// Average and worst duration of each collection stage over the
+    /// timings window; worst times over budget are shown in red.
+    fn view_collect_timings(&self) -> Element<'_, Message> {
+        let p = &self.pal;
+        let t = self.t();
+        let ms = |d: std::time::Duration| locale::with_unit(d.as_secs_f64() * 1000.0, 1, " ms");
+        let cell = |s: String, color: Color, width: Length| text(s).size(11).font(self.ui_mono).color(color).width(width);
+
+        let mut rows: Vec<Element<Message>> = vec![
+            row![
+                cell(String::new(), p.label, Length::Fill),
+                cell(t.average.to_string(), p.label, Length::Fixed(80.0)),
+                cell(t.maximum.to_string(), p.label, Length::Fixed(80.0)),
+            ]
+            .into(),
+        ];
+        let timings = &self.collect_timings;
+        let mut stage_row = |name: &str, took: &dyn Fn(&CollectTimings) -> std::time::Duration, budget: Option<std::time::Duration>| {
+            let (avg, max) = timings.stats(timings.len(), |c| took(c).as_secs_f32()).map_or((0.0, 0.0), |s| (s.avg, s.max));
+            let (avg, max) = (std::time::Duration::from_secs_f32(avg), std::time::Duration::from_secs_f32(max));
+            let max_color = if budget.is_some_and(|b| max > b) { p.red } else { p.text };
+            rows.push(
+                row![
+                    cell(name.to_string(), p.label, Length::Fill),
+                    cell(ms(avg), p.text, Length::Fixed(80.0)),
+                    cell(ms(max), max_color, Length::Fixed(80.0)),
+                ]
+                .into(),
+            );
+        };
+        for stage in CollectStage::ALL {
+            stage_row(stage.name(), &|c| c.get(stage), Some(stage.budget()));
+        }
+        stage_row(t.total, &|c| c.total, None);
+        Column::with_children(rows).spacing(4).into()
+    }
+
     // ─── OVERVIEW TAB ───────────────────────────────────────────
 
     fn view_overview(&self) -> Element<'_, Message> {
@@ -1929,13 +5465,14 @@ impl Digger {
 
         let sidebar_bg = p.sidebar_bg;
         let border_c = p.border;
+        let rtl = locale::is_rtl();
 
         // Mini sparkline data
-        let cpu_spark_data: Vec<f32> = self.live_buffer.iter().map(|lp| lp.cpu).collect();
-        let mem_spark_data: Vec<f32> = self.live_buffer.iter().map(|lp| lp.mem_pct).collect();
-        let disk_io_spark: Vec<f32> = self.live_buffer.iter()
-            .map(|lp| (lp.disk_read + lp.disk_write) as f32 / 1024.0)
-            .collect();
+        let cpu_spark_data = self.live_series(SPARKLINE_POINTS, |lp| lp.cpu);
+        let mem_spark_data = self.live_series(SPARKLINE_POINTS, |lp| lp.mem_pct);
+        let disk_io_spark = self.live_series(SPARKLINE_POINTS, |lp| (lp.disk_read + lp.disk_write) as f32 / 1024.0);
+        let net_spark = self.live_series(SPARKLINE_POINTS, |lp| (lp.net_rx + lp.net_tx) as f32 / 1024.0);
+        let temp_spark = self.live_series(SPARKLINE_POINTS, |lp| lp.max_temp_c);
 
         let make_spark = |data: Vec<f32>, color: Color| -> Element<'_, Message> {
             Canvas::new(Sparkline {
@@ -1951,14 +5488,14 @@ impl Digger {
             column![
                 sidebar_item(
                     format!("{ICON_CPU} {}", t.cpu),
-                    format!("{:.0}%", display_cpu),
+                    locale::percent(display_cpu, 0),
                     dynamic_color(p.accent, display_cpu / 100.0),
                     OverviewPanel::Cpu, self.overview_panel, p, self.ui_mono,
                 ),
                 make_spark(cpu_spark_data, p.accent),
                 sidebar_item(
                     format!("{ICON_MEMORY} {}", t.memory),
-                    format!("{:.0}%", display_mem),
+                    locale::percent(display_mem, 0),
                     dynamic_color(p.green, display_mem / 100.0),
                     OverviewPanel::Memory, self.overview_panel, p, self.ui_mono,
                 ),
@@ -1971,23 +5508,47 @@ impl Digger {
                 make_spark(disk_io_spark, p.cyan),
                 sidebar_item(
                     format!("{ICON_NETWORK} {}", t.network),
-                    format!("{}/s", format_bytes(snap.net_rx_bytes + snap.net_tx_bytes)),
+                    locale::rate(snap.net_rx_bytes + snap.net_tx_bytes),
                     p.yellow, OverviewPanel::Network, self.overview_panel, p, self.ui_mono,
                 ),
+                make_spark(net_spark, p.yellow),
                 sidebar_item(
                     format!("{ICON_TEMP} {}", t.temp),
-                    format!("{} {}", snap.temperatures.len(), t.sensors),
+                    t.sensors.format(self.language, snap.temperatures.len() as u64),
                     p.red, OverviewPanel::Temperature, self.overview_panel, p, self.ui_mono,
                 ),
+                make_spark(temp_spark, p.red),
                 sidebar_item(
                     format!("{ICON_GPU} {}", t.gpu),
-                    if snap.gpu.gpus.is_empty() { t.n_a.into() } else { format!("{} GPU(s)", snap.gpu.gpus.len()) },
+                    if snap.gpu.gpus.is_empty() { t.n_a.into() } else { t.gpu_count.format(self.language, snap.gpu.gpus.len() as u64) },
                     p.magenta, OverviewPanel::Gpu, self.overview_panel, p, self.ui_mono,
                 ),
+                sidebar_item(
+                    format!("{ICON_BOLT} {}", t.power),
+                    snap.power.map_or_else(|| t.n_a.into(), |pw| locale::with_unit(pw.watts as f64, 1, "W")),
+                    p.yellow, OverviewPanel::Power, self.overview_panel, p, self.ui_mono,
+                ),
+                sidebar_item(
+                    format!("{ICON_HISTORY} {}", t.changes),
+                    self.snapshot_diff.as_ref().map(|diff| {
+                        format!("+{} / \u{2212}{}", diff.started.len(), diff.exited.len())
+                    }).unwrap_or_else(|| t.n_a.into()),
+                    p.cyan, OverviewPanel::Changes, self.overview_panel, p, self.ui_mono,
+                ),
+                sidebar_item(
+                    format!("{ICON_USERS} {}", t.sessions),
+                    self.sessions.len().to_string(),
+                    p.green, OverviewPanel::Sessions, self.overview_panel, p, self.ui_mono,
+                ),
                 // Load Average (small display at bottom of sidebar)
                 Space::with_height(Length::Fill),
-                text(format!("{ICON_LOAD} {}", t.load)).size(10).font(self.ui_mono).color(p.label),
-                text(format!("{:.2}  {:.2}  {:.2}", snap.load_avg[0], snap.load_avg[1], snap.load_avg[2]))
+                text(format!("{ICON_LOAD} {}", if matches!(snap.load, SystemLoad::QueueLength(_)) { t.cpu_queue } else { t.load }))
+                    .size(10).font(self.ui_mono).color(p.label),
+                text(match snap.load {
+                    SystemLoad::Average(avg) => format!("{}  {}  {}", locale::number(avg[0], 2), locale::number(avg[1], 2), locale::number(avg[2], 2)),
+                    SystemLoad::QueueLength(n) => n.to_string(),
+                    SystemLoad::Unavailable => t.n_a.to_string(),
+                })
                     .size(10).font(self.ui_mono).color(p.text),
             ]
             .spacing(2)
@@ -2000,7 +5561,7 @@ impl Digger {
             border: Border { color: border_c, width: 1.0, radius: 0.0.into() },
             shadow: Shadow {
                 color: Color::from_rgba(0.0, 0.0, 0.0, 0.12),
-                offset: Vector::new(2.0, 0.0),
+                offset: Vector::new(if rtl { -2.0 } else { 2.0 }, 0.0),
                 blur_radius: 8.0,
             },
             ..Default::default()
@@ -2013,37 +5574,98 @@ impl Digger {
             OverviewPanel::Disk => self.view_detail_disk(snap),
             OverviewPanel::Temperature => self.view_detail_temp(snap),
             OverviewPanel::Gpu => self.view_detail_gpu(snap),
+            OverviewPanel::Power => self.view_detail_power(snap),
+            OverviewPanel::Changes => self.view_detail_changes(),
+            OverviewPanel::Sessions => self.view_detail_sessions(snap),
         };
 
-        row![
-            sidebar,
+        directional_row(rtl, vec![
+            sidebar.into(),
             scrollable(
-                container(detail).width(Length::Fill).padding(6)
-            ),
-        ]
+                column![
+                    container(detail).width(Length::Fill),
+                    self.view_top_processes(snap),
+                ]
+                .spacing(6)
+                .padding(6)
+            )
+            .into(),
+        ])
         .spacing(0)
         .height(Length::Fill)
         .into()
     }
 
+    /// Two compact cards listing the top 5 processes by CPU and by memory.
+    /// Clicking an entry opens it in the Processes tab.
+    fn view_top_processes<'a>(&'a self, snap: &'a Snapshot) -> Element<'a, Message> {
+        let p = &self.pal;
+        let t = self.t();
+
+        let mut by_cpu: Vec<&crate::metrics::ProcessInfo> = snap.processes.iter().collect();
+        by_cpu.sort_by(|a, b| b.cpu_usage.total_cmp(&a.cpu_usage));
+        by_cpu.truncate(5);
+        let mut by_mem: Vec<&crate::metrics::ProcessInfo> = snap.processes.iter().collect();
+        by_mem.sort_by_key(|p| std::cmp::Reverse(p.memory_bytes));
+        by_mem.truncate(5);
+
+        let mem_total = snap.memory_total.max(1) as f32;
+        let cpu_scale = self.process_cpu_scale();
+        let card = |title: String, color: Color, sort: ProcessSort, list: Vec<&crate::metrics::ProcessInfo>| -> Element<'a, Message> {
+            let mut col = Column::new()
+                .spacing(2)
+                .push(text(title).size(12).font(self.ui_mono).color(color));
+            for proc in list {
+                let (pct, value) = match sort {
+                    ProcessSort::Memory => (
+                        proc.memory_bytes as f32 / mem_total * 100.0,
+                        format_memory(proc.memory_bytes, self.process_mem_unit),
+                    ),
+                    _ => {
+                        let cpu = proc.cpu_usage * cpu_scale;
+                        (cpu, locale::percent(cpu, 1))
+                    }
+                };
+                let bar_bg = p.bar_bg;
+                let entry = row![
+                    text(proc.name.to_string()).size(11).font(font_for_text(&proc.name, NERD_FONT)).color(p.text).width(Length::FillPortion(3)),
+                    progress_bar(0.0..=100.0, pct.clamp(0.0, 100.0))
+                        .width(Length::FillPortion(2))
+                        .height(6)
+                        .style(move |_: &Theme| progress_bar::Style {
+                            background: Background::Color(bar_bg),
+                            bar: Background::Color(color),
+                            border: Border { color: Color::TRANSPARENT, width: 0.0, radius: 3.0.into() },
+                        }),
+                    text(value).size(11).font(self.ui_mono).color(color).width(70),
+                ]
+                .spacing(6)
+                .align_y(Alignment::Center);
+                col = col.push(
+                    button(entry)
+                        .on_press(Message::ShowProcess(proc.pid, sort))
+                        .style(button::text)
+                        .padding([2, 4])
+                        .width(Length::Fill),
+                );
+            }
+            panel(col.into(), p)
+        };
+
+        row![
+            card(format!("{ICON_CPU} {}", t.top_cpu), p.accent, ProcessSort::Cpu, by_cpu),
+            card(format!("{ICON_MEMORY} {}", t.top_memory), p.green, ProcessSort::Memory, by_mem),
+        ]
+        .spacing(6)
+        .into()
+    }
+
     // ─── CPU Detail ──
     fn view_detail_cpu<'a>(&'a self, snap: &'a Snapshot) -> Element<'a, Message> {
         let p = &self.pal;
         let t = self.t();
-        let cc = self.chart_colors();
-        let cpu_data: Vec<f32> = self.live_buffer.iter().map(|p| p.cpu).collect();
         // Pulse effect: if CPU exceeds threshold, pulse the chart title
-        let is_critical = self.anim_cpu >= self.cpu_alert_threshold;
-        let pulse_alpha = if is_critical {
-            0.7 + 0.3 * self.pulse_phase.sin().abs()
-        } else {
-            1.0
-        };
-        let title_color = if is_critical {
-            Color::from_rgba(p.red.r, p.red.g, p.red.b, pulse_alpha)
-        } else {
-            p.accent
-        };
+        let title_color = self.alert_color(self.anim_cpu, self.cpu_alert_threshold, p.accent);
         // Radial gauge for CPU
         let gc = GaugeColors {
             bg: p.panel_bg,
@@ -2056,24 +5678,46 @@ impl Digger {
             label: "CPU".into(),
             color: title_color,
             colors: gc,
+            target: Some(self.cpu_alert_threshold),
+            range: Some(self.session_cpu),
+            trend: self.gauge_trend(|p| p.cpu),
         })
         .width(Length::Fixed(120.0))
         .height(Length::Fixed(100.0))
         .into();
 
-        let cpu_chart = make_chart(ChartCfg {
-            title: format!("CPU {ICON_DASH} {:.1}%", self.anim_cpu),
-            series: vec![("CPU".into(), title_color, cpu_data)],
-            y_min: 0.0, y_max: 100.0, filled: true, height: 180.0, unit: "%".into(), colors: cc,
-        });
 
-        // Load average info
-        let load_info: Row<Message> = row![
-            text(format!("{ICON_LOAD} {}", t.load_avg)).size(10).font(self.ui_mono).color(p.label),
-            text(format!(" 1m {:.2}", snap.load_avg[0])).size(10).font(self.ui_mono).color(p.text),
-            text(format!("  5m {:.2}", snap.load_avg[1])).size(10).font(self.ui_mono).color(p.text),
-            text(format!("  15m {:.2}", snap.load_avg[2])).size(10).font(self.ui_mono).color(p.text),
-        ].spacing(2).align_y(Alignment::Center);
+        // Load average info (processor queue length on Windows)
+        let load_info: Row<Message> = match snap.load {
+            SystemLoad::Average(avg) => row![
+                text(format!("{ICON_LOAD} {}", t.load_avg)).size(10).font(self.ui_mono).color(p.label),
+                text(format!(" 1m {}", locale::number(avg[0], 2))).size(10).font(self.ui_mono).color(p.text),
+                text(format!("  5m {}", locale::number(avg[1], 2))).size(10).font(self.ui_mono).color(p.text),
+                text(format!("  15m {}", locale::number(avg[2], 2))).size(10).font(self.ui_mono).color(p.text),
+            ],
+            SystemLoad::QueueLength(n) => row![
+                text(format!("{ICON_LOAD} {}", t.cpu_queue_label)).size(10).font(self.ui_mono).color(p.label),
+                text(format!(" {n}")).size(10).font(self.ui_mono).color(p.text),
+            ],
+            SystemLoad::Unavailable => row![
+                text(format!("{ICON_LOAD} {}", t.load_avg)).size(10).font(self.ui_mono).color(p.label),
+                text(format!(" {}", t.n_a)).size(10).font(self.ui_mono).color(p.text),
+            ],
+        }.spacing(2).align_y(Alignment::Center);
+        let load_info = match &snap.sys_info.hypervisor {
+            Some(hypervisor) => {
+                let badge_c = p.yellow;
+                load_info.push(Space::with_width(Length::Fill)).push(
+                    container(text(format!("{}: {}", t.virtualized, hypervisor.name())).size(9).font(self.ui_mono).color(badge_c))
+                        .padding([0, 4])
+                        .style(move |_: &Theme| container::Style {
+                            border: Border { color: badge_c, width: 1.0, radius: 3.0.into() },
+                            ..Default::default()
+                        }),
+                )
+            }
+            None => load_info,
+        };
 
         // Use animated per-core values
         let cores = &self.anim_cores;
@@ -2108,27 +5752,40 @@ impl Digger {
             info_row(t.model, &snap.cpu_name, p, self.ui_mono),
             info_row(t.logical_cores, snap.cpu_core_count.to_string(), p, self.ui_mono),
             info_row(t.base_speed, format!("{} MHz", snap.cpu_frequency_mhz), p, self.ui_mono),
-            info_row(t.utilization, format!("{:.1}%", self.anim_cpu), p, self.ui_mono),
+            info_row(t.utilization, locale::percent(self.anim_cpu, 1), p, self.ui_mono),
+        ]
+        .push_maybe(snap.cpu_times.map(|times| info_row(t.io_wait, locale::percent(times.iowait, 1), p, self.ui_mono)))
+        // Steal only means something inside a VM, but is worth showing if
+        // detection missed one
+        .push_maybe(
+            snap.cpu_times
+                .filter(|times| snap.sys_info.hypervisor.is_some() || times.steal > 0.0)
+                .map(|times| info_row(t.steal_time, locale::percent(times.steal, 1), p, self.ui_mono)),
+        )
+        .extend([
             info_row(t.processes, snap.process_count.to_string(), p, self.ui_mono),
             info_row(t.uptime, &uptime, p, self.ui_mono),
-        ]
+        ])
         .spacing(4);
 
         panel(
             column![
                 row![
                     cpu_gauge,
-                    column![cpu_chart].width(Length::Fill),
+                    column![].push_maybe(self.chart(ChartId::Cpu)).width(Length::Fill),
                 ].spacing(6).align_y(Alignment::Center),
-                Space::with_height(4),
+            ]
+            .push_maybe(self.chart(ChartId::CpuSplit))
+            .extend([
+                Space::with_height(4).into(),
                 Element::from(load_info),
-                Space::with_height(6),
+                Space::with_height(6).into(),
                 section_title(t.per_core_usage, p, self.ui_mono),
-                cores_grid,
-                Space::with_height(6),
+                cores_grid.into(),
+                Space::with_height(6).into(),
                 section_title(t.system_info, p, self.ui_mono),
-                info,
-            ]
+                info.into(),
+            ])
             .spacing(4)
             .into(),
             p,
@@ -2139,27 +5796,9 @@ impl Digger {
     fn view_detail_memory<'a>(&'a self, snap: &'a Snapshot) -> Element<'a, Message> {
         let p = &self.pal;
         let t = self.t();
-        let cc = self.chart_colors();
-        let mem_data: Vec<f32> = self.live_buffer.iter().map(|p| p.mem_pct).collect();
         let display_mem = self.anim_mem_pct;
         // Pulse effect for memory threshold
-        let is_critical = display_mem >= self.mem_alert_threshold;
-        let pulse_alpha = if is_critical {
-            0.7 + 0.3 * self.pulse_phase.sin().abs()
-        } else {
-            1.0
-        };
-        let chart_color = if is_critical {
-            Color::from_rgba(p.red.r, p.red.g, p.red.b, pulse_alpha)
-        } else {
-            p.green
-        };
-        let mem_chart = make_chart(ChartCfg {
-            title: format!("Memory {ICON_DASH} {:.1}%", display_mem),
-            series: vec![("RAM".into(), chart_color, mem_data)],
-            y_min: 0.0, y_max: 100.0, filled: true, height: 200.0, unit: "%".into(), colors: cc,
-        });
-
+        let chart_color = self.alert_color(display_mem, self.mem_alert_threshold, p.green);
         let swap_pct = if snap.swap_total > 0 {
             snap.swap_used as f32 / snap.swap_total as f32 * 100.0
         } else { 0.0 };
@@ -2169,7 +5808,7 @@ impl Digger {
         let info = column![
             info_row(t.in_use, format!("{} / {}", format_bytes(snap.memory_used), format_bytes(snap.memory_total)), p, self.ui_mono),
             info_row(t.available, format_bytes(available), p, self.ui_mono),
-            info_row(t.usage, format!("{:.1}%", display_mem), p, self.ui_mono),
+            info_row(t.usage, locale::percent(display_mem, 1), p, self.ui_mono),
         ]
         .spacing(4);
 
@@ -2186,10 +5825,52 @@ impl Digger {
 
         let swap_info = column![
             info_row(t.swap_used, format!("{} / {}", format_bytes(snap.swap_used), format_bytes(snap.swap_total)), p, self.ui_mono),
-            info_row(t.swap_usage, format!("{:.1}%", swap_pct), p, self.ui_mono),
+            info_row(t.swap_usage, locale::percent(swap_pct, 1), p, self.ui_mono),
             info_row(t.virtual_memory_total, format_bytes(total_virt), p, self.ui_mono),
         ]
         .spacing(4);
+        let swap_info = match self.swap_full_at {
+            Some(at) => swap_info.push(info_row(
+                t.projected_full,
+                fill(t.full_in, &[("time", &forecast::format_eta(at - snap.timestamp))]),
+                p,
+                self.ui_mono,
+            )),
+            None => swap_info,
+        };
+
+        // Page faults and hugepages (Linux); absent elsewhere
+        let activity = &snap.memory_activity;
+        let per_sec = |v: f32| format!("{}/s", locale::number(v as f64, 0));
+        let spark = |data: Vec<f32>, color: Color| -> Element<'a, Message> {
+            Canvas::new(Sparkline { data, color }).width(Length::Fill).height(Length::Fixed(20.0)).into()
+        };
+        let faults_info = activity.faults.map(|faults| {
+            let selected = activity.process_faults.filter(|&(pid, _)| self.selected_pid == Some(pid)).map(|(pid, rates)| {
+                let name = snap.processes.iter().find(|p| p.pid == pid).map_or_else(|| pid.to_string(), |p| format!("{} ({pid})", p.name));
+                info_row(name, format!("{} / {}", per_sec(rates.minor), per_sec(rates.major)), p, self.ui_mono)
+            });
+            column![
+                info_row(t.minor_faults, per_sec(faults.minor), p, self.ui_mono),
+                spark(self.live_series(SPARKLINE_POINTS, |lp| lp.minor_faults), p.accent),
+                info_row(t.major_faults, per_sec(faults.major), p, self.ui_mono),
+                spark(self.live_series(SPARKLINE_POINTS, |lp| lp.major_faults), p.red),
+            ]
+            .push_maybe(selected)
+            .spacing(4)
+        });
+        let hugepages_info = activity.hugepages.filter(|h| h.total > 0 || h.transparent > 0).map(|huge| {
+            column![
+                info_row(
+                    t.hugepages,
+                    format!("{} / {} ({} × {})", format_bytes(huge.used_bytes()), format_bytes(huge.total_bytes()), huge.total, format_bytes(huge.page_size)),
+                    p,
+                    self.ui_mono,
+                ),
+                info_row(t.transparent_hugepages, format_bytes(huge.transparent), p, self.ui_mono),
+            ]
+            .spacing(4)
+        });
 
         let gc = GaugeColors {
             bg: p.panel_bg, label: p.label, text: p.text, bar_bg: p.bar_bg,
@@ -2199,6 +5880,9 @@ impl Digger {
             label: "RAM".into(),
             color: chart_color,
             colors: gc,
+            target: Some(self.mem_alert_threshold),
+            range: Some(self.session_mem),
+            trend: self.gauge_trend(|p| p.mem_pct),
         })
         .width(Length::Fixed(120.0))
         .height(Length::Fixed(100.0))
@@ -2208,7 +5892,7 @@ impl Digger {
             column![
                 row![
                     mem_gauge,
-                    column![mem_chart].width(Length::Fill),
+                    column![].push_maybe(self.chart(ChartId::Memory)).width(Length::Fill),
                 ].spacing(6).align_y(Alignment::Center),
                 Space::with_height(8),
                 bars,
@@ -2219,6 +5903,8 @@ impl Digger {
                 section_title(t.swap, p, self.ui_mono),
                 swap_info,
             ]
+            .push_maybe(faults_info.map(|info| column![Space::with_height(8), section_title(t.page_faults, p, self.ui_mono), info].spacing(4)))
+            .push_maybe(hugepages_info.map(|info| column![Space::with_height(8), section_title(t.hugepages, p, self.ui_mono), info].spacing(4)))
             .spacing(4)
             .into(),
             p,
@@ -2229,30 +5915,9 @@ impl Digger {
     fn view_detail_network<'a>(&'a self, snap: &'a Snapshot) -> Element<'a, Message> {
         let p = &self.pal;
         let t = self.t();
-        let cc = self.chart_colors();
-        let rx_kb: Vec<f32> = self.live_buffer.iter().map(|p| p.net_rx as f32 / 1024.0).collect();
-        let tx_kb: Vec<f32> = self.live_buffer.iter().map(|p| p.net_tx as f32 / 1024.0).collect();
-        let max_kb = rx_kb.iter().chain(tx_kb.iter()).cloned().fold(0.001f32, f32::max);
-        let (rx_data, tx_data, unit, y_max) = if max_kb >= 1024.0 {
-            let rx_mb: Vec<f32> = rx_kb.iter().map(|v| v / 1024.0).collect();
-            let tx_mb: Vec<f32> = tx_kb.iter().map(|v| v / 1024.0).collect();
-            let max_mb = max_kb / 1024.0;
-            (rx_mb, tx_mb, " MB/s", max_mb)
-        } else {
-            (rx_kb, tx_kb, " KB/s", max_kb)
-        };
-        let net_chart = make_chart(ChartCfg {
-            title: t.network.into(),
-            series: vec![
-                (format!("{ICON_ARROW_DOWN} rx"), p.green, rx_data),
-                (format!("{ICON_ARROW_UP} tx"), p.red, tx_data),
-            ],
-            y_min: 0.0, y_max, filled: true, height: 200.0, unit: unit.into(), colors: cc,
-        });
-
         let totals = column![
-            info_row(format!("{ICON_ARROW_DOWN} {}", t.receive), format!("{}/s", format_bytes(snap.net_rx_bytes)), p, self.ui_mono),
-            info_row(format!("{ICON_ARROW_UP} {}", t.send), format!("{}/s", format_bytes(snap.net_tx_bytes)), p, self.ui_mono),
+            info_row(format!("{ICON_ARROW_DOWN} {}", t.receive), locale::rate(snap.net_rx_bytes), p, self.ui_mono),
+            info_row(format!("{ICON_ARROW_UP} {}", t.send), locale::rate(snap.net_tx_bytes), p, self.ui_mono),
         ]
         .spacing(4);
 
@@ -2262,6 +5927,7 @@ impl Digger {
         let mut iface_items: Vec<Element<Message>> = Vec::new();
         for iface in &snap.net_interfaces {
             let item = row![
+                text(ICON_BULLET).size(11).color(if iface.is_up { green } else { red }),
                 text(&iface.name).size(11).color(text_c).width(140),
                 text(format!("{ICON_ARROW_DOWN} {}", format_bytes(iface.rx_bytes))).size(11).font(self.ui_mono).color(green).width(110),
                 text(format!("{ICON_ARROW_UP} {}", format_bytes(iface.tx_bytes))).size(11).font(self.ui_mono).color(red).width(110),
@@ -2271,22 +5937,160 @@ impl Digger {
             iface_items.push(item.into());
         }
 
+        if !self.recorded.network {
+            iface_items.push(text(t.collection_off).size(11).font(self.ui_mono).color(p.label).into());
+        }
+
         panel(
-            column![
-                net_chart,
-                Space::with_height(8),
+            column![]
+            .push_maybe(self.chart(ChartId::Network))
+            .extend([
+                Space::with_height(8).into(),
                 section_title(t.throughput, p, self.ui_mono),
-                totals,
-                Space::with_height(8),
+                totals.into(),
+                Space::with_height(8).into(),
                 section_title(t.interfaces, p, self.ui_mono),
-                Column::with_children(iface_items).spacing(3),
-            ]
+                Column::with_children(iface_items).spacing(3).into(),
+            ])
+            .push_maybe(self.ping.as_ref().map(|ping| self.view_connection_quality(ping)))
+            .push(Space::with_height(8))
+            .push(section_title(t.lan_devices, p, self.ui_mono))
+            .push(self.view_lan_scan())
             .spacing(4)
             .into(),
             p,
         )
     }
 
+    /// Quality gauge, figures and chart for the pinged host.
+    fn view_connection_quality<'a>(&'a self, ping: &'a PingMonitor) -> Element<'a, Message> {
+        let p = &self.pal;
+        let t = self.t();
+        let title = section_title(format!("{} {ICON_DASH} {}", t.connection_quality, ping.host()), p, self.ui_mono);
+        let Some(q) = ping.quality() else {
+            let status = match ping.error() {
+                Some(err) => text(format!("{ICON_WARNING} {err}")).size(11).color(p.red),
+                None => text(fill(t.waiting_for_ping, &[("host", &ping.host())])).size(11).font(self.ui_mono).color(p.label),
+            };
+            return column![Space::with_height(8), title, status].spacing(4).into();
+        };
+
+        let score = q.score_pct();
+        let gauge: Element<Message> = Canvas::new(RadialGauge {
+            value: score,
+            label: t.quality.into(),
+            color: gradient_color(1.0 - score / 100.0, p),
+            colors: GaugeColors { bg: p.panel_bg, label: p.label, text: p.text, bar_bg: p.bar_bg },
+            target: None,
+            range: None,
+            trend: None,
+        })
+        .width(Length::Fixed(120.0))
+        .height(Length::Fixed(100.0))
+        .into();
+
+        let ms = |v: f32| format!("{} ms", locale::number(v as f64, 1));
+        let figures = column![
+            info_row(t.latency, ms(q.latency_ms), p, self.ui_mono),
+            info_row(t.jitter, ms(q.jitter_ms), p, self.ui_mono),
+            info_row(t.packet_loss, locale::percent(q.loss_pct, 0), p, self.ui_mono),
+            info_row(t.quality_score, locale::number(q.mos as f64, 1), p, self.ui_mono),
+        ]
+        .spacing(4)
+        .width(Length::Fill);
+
+        column![
+            Space::with_height(8),
+            title,
+            row![gauge, figures].spacing(12).align_y(Alignment::Center),
+        ]
+        .push_maybe(self.chart(ChartId::Latency))
+        .spacing(4)
+        .into()
+    }
+
+    /// The scan button, or the devices found by the LAN scan, updated
+    /// while it runs.
+    fn view_lan_scan(&self) -> Element<'_, Message> {
+        let p = &self.pal;
+        let t = self.t();
+        let label_c = p.label;
+        let Some(scan) = &self.lan_scan else {
+            return row![
+                text(t.lan_scan_desc).size(10).font(self.ui_mono).color(label_c).width(Length::Fill),
+                button(text(format!("{ICON_SEARCH} {}", t.scan_network)).size(11).color(label_c))
+                    .on_press(Message::ScanLan)
+                    .style(button::secondary)
+                    .padding([2, 8]),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center)
+            .into();
+        };
+        let progress = scan.progress();
+
+        let status = if progress.done {
+            format!("{ICON_CHECK} {}", t.scan_complete)
+        } else {
+            format!("{ICON_LOADING} {}…", t.scanning)
+        };
+        let probed = fill(t.addresses_probed, &[("done", &progress.probed), ("total", &progress.total)]);
+        let header = row![
+            text(progress.subnets.join(", ")).size(12).font(self.ui_mono).color(p.accent),
+            Space::with_width(Length::Fill),
+            text(status).size(11).font(self.ui_mono).color(if progress.done { p.green } else { p.yellow }),
+            text(probed).size(11).font(self.ui_mono).color(label_c),
+            button(text(if progress.done { t.close } else { t.cancel }).size(11).color(label_c))
+                .on_press(Message::CloseLanScan)
+                .style(button::secondary)
+                .padding([2, 8]),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center);
+
+        let mut rows: Vec<Element<Message>> = vec![header.into()];
+        if progress.done && progress.devices.is_empty() {
+            rows.push(text(t.no_lan_devices).size(11).font(self.ui_mono).color(label_c).into());
+        } else if !progress.devices.is_empty() {
+            let cell = |s: String, color: Color| text(s).size(11).font(self.ui_mono).color(color);
+            // Without an OUI registry or reverse DNS these columns would
+            // stay empty
+            let show_vendor = progress.vendors_available;
+            let show_hostname = crate::lanscan::HOSTNAMES_SUPPORTED;
+            rows.push(
+                row![
+                    cell(t.ip_address.into(), label_c).width(120),
+                    cell(t.mac_address.into(), label_c).width(140),
+                ]
+                .push_maybe(show_vendor.then(|| cell(t.vendor.into(), label_c).width(Length::FillPortion(1))))
+                .push_maybe(show_hostname.then(|| cell(t.hostname.into(), label_c).width(Length::FillPortion(1))))
+                .spacing(8)
+                .into()
+            );
+            for device in &progress.devices {
+                let vendor = match (&device.vendor, device.randomized_mac()) {
+                    (Some(vendor), _) => vendor.clone(),
+                    (None, true) => t.private_mac.into(),
+                    (None, false) => "—".into(),
+                };
+                rows.push(
+                    row![
+                        cell(device.ip.to_string(), p.text).width(120),
+                        cell(device.mac.clone().unwrap_or_else(|| "—".into()), p.text).width(140),
+                    ]
+                    .push_maybe(show_vendor.then(|| cell(vendor, label_c).width(Length::FillPortion(1))))
+                    .push_maybe(
+                        show_hostname.then(|| cell(device.hostname.clone().unwrap_or_default(), p.text).width(Length::FillPortion(1))),
+                    )
+                    .spacing(8)
+                    .into()
+                );
+            }
+        }
+
+        Column::with_children(rows).spacing(4).into()
+    }
+
     // ─── Disk Detail ──
     fn view_detail_disk<'a>(&'a self, snap: &'a Snapshot) -> Element<'a, Message> {
         let p = &self.pal;
@@ -2306,8 +6110,8 @@ impl Digger {
         let summary = container(
             row![
                 column![
-                    text(format!("{} {}", snap.disks.len(), t.drives)).size(20).font(self.ui_mono).color(text_c),
-                    text(format!("{:.1}% {}", total_pct, t.overall_usage)).size(11).font(self.ui_mono).color(label_c),
+                    text(t.drives.format(self.language, snap.disks.len() as u64)).size(20).font(self.ui_mono).color(text_c),
+                    text(format!("{} {}", locale::percent(total_pct as f32, 1), t.overall_usage)).size(11).font(self.ui_mono).color(label_c),
                 ].spacing(4).width(Length::FillPortion(1)),
                 column![
                     info_row(t.total_capacity, format_bytes(total_space), p, self.ui_mono),
@@ -2330,12 +6134,13 @@ impl Digger {
         });
 
         let mut disk_items: Vec<Element<Message>> = Vec::new();
-        for d in &snap.disks {
+        for d in snap.disks.iter() {
             let used = d.total.saturating_sub(d.available);
             let pct = if d.total > 0 { used as f32 / d.total as f32 * 100.0 } else { 0.0 };
             let color = gradient_color(pct / 100.0, p);
             let bar_bg = p.bar_bg;
 
+            let eta = self.disk_full_at.get(&d.mount).map(|at| at - snap.timestamp);
             let icon = if d.is_removable { ICON_USB } else { ICON_DISK };
             let disk_type = if d.name.contains("nvme") { "NVMe SSD" }
                 else if d.name.contains("sd") { "SATA" }
@@ -2347,12 +6152,18 @@ impl Digger {
                         text(format!("{icon} {}", &d.mount)).size(14).color(text_c),
                         Space::with_width(Length::Fill),
                         text(format!("{} {ICON_BULLET} {}", &d.name, disk_type)).size(10).color(label_c),
-                    ],
+                        button(text(format!("{ICON_SEARCH} {}", t.scan_disk)).size(10).color(label_c))
+                            .on_press(Message::ScanDisk(d.mount.clone()))
+                            .style(button::secondary)
+                            .padding([2, 8]),
+                    ]
+                    .spacing(8)
+                    .align_y(Alignment::Center),
                     Space::with_height(6),
                     themed_bar(pct, color, bar_bg),
                     Space::with_height(6),
                     row![
-                        text(format!("{:.1}%", pct)).size(14).font(self.ui_mono).color(color),
+                        text(locale::percent(pct, 1)).size(14).font(self.ui_mono).color(color),
                         Space::with_width(Length::Fill),
                         text(format!("{} {}", format_bytes(used), t.used)).size(11).font(self.ui_mono).color(text_c),
                         Space::with_width(12),
@@ -2360,6 +6171,9 @@ impl Digger {
                         Space::with_width(12),
                         text(format!("{} {}", format_bytes(d.total), t.total)).size(11).font(self.ui_mono).color(label_c),
                     ],
+                    text(eta.map(|secs| fill(t.full_in, &[("time", &forecast::format_eta(secs))])).unwrap_or_default())
+                        .size(10).font(self.ui_mono)
+                        .color(if eta.is_some_and(|secs| secs < self.forecast_horizon.secs()) { p.red } else { label_c }),
                     Space::with_height(8),
                     row![
                         column![
@@ -2404,6 +6218,7 @@ impl Digger {
                 section_title(t.io_throughput, p, self.ui_mono),
                 disk_io_info,
                 Space::with_height(8),
+                self.view_disk_scan(),
                 Column::with_children(disk_items).spacing(8),
             ]
             .spacing(4)
@@ -2412,6 +6227,57 @@ impl Digger {
         )
     }
 
+    /// Results of the largest-directories scan, updated live while it runs.
+    fn view_disk_scan(&self) -> Element<'_, Message> {
+        let Some(scan) = &self.disk_scan else {
+            return column![].into();
+        };
+        let p = &self.pal;
+        let t = self.t();
+        let label_c = p.label;
+        let progress = scan.progress();
+
+        let status = if progress.done {
+            format!("{ICON_CHECK} {}", t.scan_complete)
+        } else {
+            format!("{ICON_LOADING} {}…", t.scanning)
+        };
+        let header = row![
+            text(format!("{ICON_DISK} {} {ICON_BULLET} {}", t.largest_directories, progress.root.display()))
+                .size(12).font(self.ui_mono).color(p.accent),
+            Space::with_width(Length::Fill),
+            text(status).size(11).font(self.ui_mono).color(if progress.done { p.green } else { p.yellow }),
+            text(format!("{} {ICON_BULLET} {}", t.files.format(self.language, progress.files_scanned), format_bytes(progress.bytes_scanned)))
+                .size(11).font(self.ui_mono).color(label_c),
+            button(text(if progress.done { t.close } else { t.cancel }).size(11).color(label_c))
+                .on_press(Message::CloseDiskScan)
+                .style(button::secondary)
+                .padding([2, 8]),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center);
+
+        let largest = progress.entries.first().map(|e| e.bytes).unwrap_or(0).max(1);
+        let bar_bg = p.bar_bg;
+        let mut rows: Vec<Element<Message>> = vec![header.into()];
+        for entry in progress.entries.iter().take(15) {
+            let pct = entry.bytes as f32 / largest as f32 * 100.0;
+            let color = gradient_color(entry.bytes as f32 / largest as f32, p);
+            rows.push(
+                row![
+                    text(entry.path.display().to_string()).size(11).color(p.text).width(Length::FillPortion(3)),
+                    container(themed_bar(pct, color, bar_bg)).width(Length::FillPortion(2)),
+                    text(format_bytes(entry.bytes)).size(11).font(self.ui_mono).color(color).width(80),
+                ]
+                .spacing(8)
+                .align_y(Alignment::Center)
+                .into()
+            );
+        }
+
+        Column::with_children(rows).spacing(4).into()
+    }
+
     // ─── Temperature Detail ──
     fn view_detail_temp<'a>(&'a self, snap: &'a Snapshot) -> Element<'a, Message> {
         let p = &self.pal;
@@ -2421,27 +6287,24 @@ impl Digger {
         let green = p.green;
         let red = p.red;
         let yellow = p.yellow;
-        let panel_bg = p.panel_bg;
-        let bg = p.bg;
-
-        let temp_title = format!("{ICON_TEMP} {}", t.temperatures);
-        if snap.temperatures.is_empty() {
-            return panel(
-                column![
-                    section_title(&temp_title, p, self.ui_mono),
-                    text(t.no_sensors).size(12).font(self.ui_mono).color(label_c),
-                ]
-                .spacing(6)
-                .into(),
-                p,
-            );
+        let panel_bg = p.panel_bg;
+        let bg = p.bg;
+
+        let temp_title = format!("{ICON_TEMP} {}", t.temperatures);
+        if snap.temperatures.is_empty() {
+            let mut items: Vec<Element<Message>> = vec![
+                section_title(&temp_title, p, self.ui_mono),
+                text(t.no_sensors).size(12).font(self.ui_mono).color(label_c).into(),
+            ];
+            items.extend(self.sensor_hints(&snap.temp_status));
+            return panel(Column::with_children(items).spacing(6).into(), p);
         }
 
         let mut temp_items: Vec<Element<Message>> = Vec::new();
         for (i, t) in snap.temperatures.iter().enumerate() {
             let color = if t.temp_c > 80.0 { red } else if t.temp_c > 60.0 { yellow } else { green };
             let row_bg = if i % 2 == 0 { panel_bg } else { bg };
-            let temp_str = format_temp(t.temp_c, self.temp_celsius);
+            let temp_str = format_temp(t.temp_c, self.temp_unit);
             let item = container(
                 row![
                     text(&t.label).size(11).color(text_c).width(Length::Fill),
@@ -2472,10 +6335,10 @@ impl Digger {
         };
 
         let summary = column![
-            info_row(t.sensors, snap.temperatures.len().to_string(), p, self.ui_mono),
-            info_row(t.minimum, format_temp(min_t, self.temp_celsius), p, self.ui_mono),
-            info_row(t.maximum, format_temp(max_t, self.temp_celsius), p, self.ui_mono),
-            info_row(t.average, format_temp(avg_t, self.temp_celsius), p, self.ui_mono),
+            info_row(t.all_sensors, snap.temperatures.len().to_string(), p, self.ui_mono),
+            info_row(t.minimum, format_temp(min_t, self.temp_unit), p, self.ui_mono),
+            info_row(t.maximum, format_temp(max_t, self.temp_unit), p, self.ui_mono),
+            info_row(t.average, format_temp(avg_t, self.temp_unit), p, self.ui_mono),
         ]
         .spacing(4);
 
@@ -2483,17 +6346,54 @@ impl Digger {
         panel(
             column![
                 section_title(&temp_overview_title, p, self.ui_mono),
-                summary,
-                Space::with_height(8),
-                section_title(t.all_sensors, p, self.ui_mono),
-                Column::with_children(temp_items).spacing(0),
             ]
+            .push_maybe(self.stale_notice(snap, Subsystem::Sensors))
+            .extend([
+                summary.into(),
+                Space::with_height(8).into(),
+                section_title(t.all_sensors, p, self.ui_mono),
+                Column::with_children(temp_items).spacing(0).into(),
+            ])
             .spacing(4)
             .into(),
             p,
         )
     }
 
+    /// "Stale since …" under a panel title when `subsystem`'s poller has
+    /// fallen behind, so frozen values don't pass for live ones.
+    fn stale_notice(&self, snap: &Snapshot, subsystem: Subsystem) -> Option<Element<'_, Message>> {
+        let age = snap.data_age.get(subsystem)?.as_secs();
+        if age < STALE_AFTER_SECS {
+            return None;
+        }
+        let age = if age < 60 { format!("{age}s") } else { format_duration(age) };
+        Some(
+            text(format!("{ICON_CLOCK} {}", fill(self.t().stale_since, &[("age", &age)])))
+                .size(10)
+                .font(self.ui_mono)
+                .color(self.pal.yellow)
+                .into(),
+        )
+    }
+
+    /// One line per failed sensor backend, with a hint on how to fix it.
+    fn sensor_hints(&self, status: &[SensorStatus]) -> Vec<Element<'_, Message>> {
+        let t = self.t();
+        status.iter().map(|s| {
+            let backend: &dyn std::fmt::Display = &s.backend;
+            let hint = match &s.issue {
+                SensorIssue::PermissionDenied => fill(t.sensor_permission_denied, &[("backend", backend)]),
+                SensorIssue::DriverMissing => fill(t.sensor_driver_missing, &[("backend", backend)]),
+                SensorIssue::ServiceMissing(service) => {
+                    fill(t.sensor_service_missing, &[("backend", backend), ("service", service)])
+                }
+                SensorIssue::Failed(err) => format!("{}: {err}", s.backend),
+            };
+            text(format!("{ICON_WARNING} {hint}")).size(11).font(self.ui_mono).color(self.pal.yellow).into()
+        }).collect()
+    }
+
     // ─── GPU Detail ──
     fn view_detail_gpu<'a>(&'a self, snap: &'a Snapshot) -> Element<'a, Message> {
         let p = &self.pal;
@@ -2502,23 +6402,18 @@ impl Digger {
         let label_c = p.label;
 
         if snap.gpu.gpus.is_empty() {
-            return panel(
-                column![
-                    section_title(format!("{ICON_GPU} {}", t.gpu), p, self.ui_mono),
-                    text(t.no_gpu)
-                        .size(12).font(self.ui_mono).color(label_c),
-                ]
-                .spacing(6)
-                .into(),
-                p,
-            );
+            let mut items: Vec<Element<Message>> = vec![
+                section_title(format!("{ICON_GPU} {}", t.gpu), p, self.ui_mono),
+                text(if self.recorded.gpu { t.no_gpu } else { t.collection_off })
+                    .size(12).font(self.ui_mono).color(label_c).into(),
+            ];
+            items.extend(self.sensor_hints(&snap.gpu.status));
+            return panel(Column::with_children(items).spacing(6).into(), p);
         }
 
         let mut gpu_items: Vec<Element<Message>> = Vec::new();
         for gpu in &snap.gpu.gpus {
-            let mem_pct = if gpu.memory_total > 0 {
-                gpu.memory_used as f32 / gpu.memory_total as f32 * 100.0
-            } else { 0.0 };
+            let mem_pct = gpu.memory_percent();
             let util_color = gradient_color(gpu.utilization as f32 / 100.0, p);
             let _temp_color = if gpu.temperature > 80.0 { p.red }
                 else if gpu.temperature > 60.0 { p.yellow }
@@ -2529,31 +6424,831 @@ impl Digger {
                     text(&gpu.name).size(14).color(text_c),
                     Space::with_height(4),
                     info_row(t.utilization, format!("{}%", gpu.utilization), p, self.ui_mono),
-                    info_row(t.temperature, format!("{:.0}°C", gpu.temperature), p, self.ui_mono),
+                    info_row(t.temperature, format_temp(gpu.temperature, self.temp_unit), p, self.ui_mono),
                     info_row(t.vram, format!("{} / {}", format_bytes(gpu.memory_used), format_bytes(gpu.memory_total)), p, self.ui_mono),
-                    info_row(t.vram_usage, format!("{:.1}%", mem_pct), p, self.ui_mono),
-                    info_row(t.power, format!("{:.1}W", gpu.power_watts), p, self.ui_mono),
-                    Space::with_height(4),
+                    info_row(t.vram_usage, locale::percent(mem_pct, 1), p, self.ui_mono),
+                    info_row(t.power, locale::with_unit(gpu.power_watts as f64, 1, "W"), p, self.ui_mono),
+                ]
+                .push_maybe(gpu.fan_percent.map(|f| info_row(t.fan_speed, format!("{f}%"), p, self.ui_mono)))
+                .push_maybe(gpu.core_clock_mhz.map(|c| info_row(t.core_clock, locale::with_unit(c as f64, 0, " MHz"), p, self.ui_mono)))
+                .push_maybe(gpu.memory_clock_mhz.map(|c| info_row(t.memory_clock, locale::with_unit(c as f64, 0, " MHz"), p, self.ui_mono)))
+                .extend([
+                    Space::with_height(4).into(),
                     labeled_bar("Util", gpu.utilization as u64, 100, util_color, p, self.ui_mono),
                     labeled_bar("VRAM", gpu.memory_used, gpu.memory_total, p.magenta, p, self.ui_mono),
-                ]
+                ])
                 .spacing(4)
                 .into()
             );
         }
 
-        panel(
-            column![
-                section_title(format!("{ICON_GPU} {}", t.gpu), p, self.ui_mono),
-                Column::with_children(gpu_items).spacing(12),
-            ]
-            .spacing(4)
-            .into(),
-            p,
-        )
+        panel(
+            column![
+                section_title(format!("{ICON_GPU} {}", t.gpu), p, self.ui_mono),
+            ]
+            .push_maybe(self.stale_notice(snap, Subsystem::Gpu))
+            .extend([
+                Column::with_children(gpu_items).spacing(12).into(),
+                Space::with_height(8).into(),
+                self.view_gpu_processes(snap),
+            ])
+            .spacing(4)
+            .into(),
+            p,
+        )
+    }
+
+    /// Processes holding GPU memory, sortable, with a kill shortcut.
+    fn view_gpu_processes<'a>(&'a self, snap: &'a Snapshot) -> Element<'a, Message> {
+        let p = &self.pal;
+        let t = self.t();
+        let label_c = p.label;
+        let accent = p.accent;
+
+        let mut procs: Vec<&crate::gpu::GpuProcess> = snap.gpu.processes.iter().collect();
+        match self.gpu_proc_sort {
+            ProcessSort::Pid => procs.sort_by_key(|g| g.pid),
+            ProcessSort::Name => procs.sort_by_key(|g| g.name.to_lowercase()),
+            ProcessSort::Cpu => procs.sort_by(|a, b| a.utilization.unwrap_or(0.0).total_cmp(&b.utilization.unwrap_or(0.0))),
+            ProcessSort::Memory => procs.sort_by_key(|g| g.vram_bytes),
+            ProcessSort::DiskIo | ProcessSort::Threads => {}
+        }
+        if !self.gpu_proc_sort_asc {
+            procs.reverse();
+        }
+
+        let mut rows: Vec<Element<Message>> = vec![section_title(t.gpu_processes, p, self.ui_mono)];
+        if procs.is_empty() {
+            rows.push(text(t.no_gpu_processes).size(11).font(self.ui_mono).color(label_c).into());
+            return Column::with_children(rows).spacing(4).into();
+        }
+
+        let si = |col: ProcessSort| -> &str {
+            if self.gpu_proc_sort == col {
+                if self.gpu_proc_sort_asc { ICON_SORT_UP } else { ICON_SORT_DOWN }
+            } else { "" }
+        };
+        // Per-process engine utilization is only reported on Windows
+        let show_util = procs.iter().any(|g| g.utilization.is_some());
+        let mut header = row![
+            sort_btn(format!("PID {}", si(ProcessSort::Pid)), Message::SortGpuProcesses(ProcessSort::Pid), 60, accent),
+            sort_btn(format!("{} {}", t.command, si(ProcessSort::Name)), Message::SortGpuProcesses(ProcessSort::Name), 200, accent),
+        ]
+        .spacing(2);
+        if show_util {
+            header = header.push(sort_btn(format!("{} % {}", t.gpu, si(ProcessSort::Cpu)), Message::SortGpuProcesses(ProcessSort::Cpu), 70, accent));
+        }
+        header = header.push(sort_btn(format!("{} {}", t.vram, si(ProcessSort::Memory)), Message::SortGpuProcesses(ProcessSort::Memory), 100, accent));
+        rows.push(header.into());
+
+        let vram_total: u64 = snap.gpu.gpus.iter().map(|g| g.memory_total).sum();
+        for proc in procs {
+            let color = if vram_total > 0 {
+                gradient_color(proc.vram_bytes as f32 / vram_total as f32, p)
+            } else {
+                p.magenta
+            };
+            let name = if proc.name.is_empty() { t.n_a } else { proc.name.as_str() };
+            let mut line = row![
+                text(proc.pid.to_string()).size(11).font(self.ui_mono).color(label_c).width(60),
+                text(name).size(11).font(font_for_text(name, NERD_FONT)).color(p.text).width(200),
+            ]
+            .spacing(2)
+            .padding([0, 4])
+            .align_y(Alignment::Center);
+            if show_util {
+                let util = proc.utilization.unwrap_or(0.0);
+                line = line.push(
+                    text(locale::percent(util, 1)).size(11).font(self.ui_mono).color(gradient_color(util / 100.0, p)).width(70),
+                );
+            }
+            rows.push(
+                line.push(text(format_bytes(proc.vram_bytes)).size(11).font(self.ui_mono).color(color).width(100))
+                    .push_maybe((!self.is_read_only()).then(|| {
+                        labeled(
+                            button(text(ICON_KILL).size(10).color(label_c))
+                                .on_press(Message::KillProcess(proc.pid))
+                                .style(button::text)
+                                .padding([1, 4]),
+                            t.end_process,
+                            self.ui_mono,
+                        )
+                    }))
+                    .into(),
+            );
+        }
+
+        Column::with_children(rows).spacing(3).into()
+    }
+
+    // ─── Power Detail ──
+    fn view_detail_power<'a>(&'a self, snap: &'a Snapshot) -> Element<'a, Message> {
+        let p = &self.pal;
+        let t = self.t();
+        let title = format!("{ICON_BOLT} {}", t.system_power);
+
+        let Some(power) = snap.power else {
+            return panel(
+                column![
+                    section_title(&title, p, self.ui_mono),
+                    text(t.no_power).size(12).font(self.ui_mono).color(p.label),
+                    text(t.no_power_hint).size(11).color(p.label),
+                ]
+                .spacing(6)
+                .into(),
+                p,
+            );
+        };
+
+        // Ticks without a reading are stored as 0 W and left out of the stats
+        let valid: Vec<f32> = self.live_buffer.iter().map(|lp| lp.power_watts).filter(|&w| w > 0.0).collect();
+        let peak = valid.iter().copied().fold(0.0f32, f32::max);
+        let avg = if valid.is_empty() { 0.0 } else { valid.iter().sum::<f32>() / valid.len() as f32 };
+
+        let source = match power.source {
+            crate::power::PowerSource::Rapl => t.power_rapl,
+            crate::power::PowerSource::Battery => t.power_battery,
+        };
+        let info = column![
+            info_row(t.power_source, source, p, self.ui_mono),
+            info_row(t.average, locale::with_unit(avg as f64, 1, "W"), p, self.ui_mono),
+            info_row(t.maximum, locale::with_unit(peak as f64, 1, "W"), p, self.ui_mono),
+        ]
+        .spacing(4);
+
+        panel(
+            column![
+                section_title(&title, p, self.ui_mono),
+            ]
+            .push_maybe(self.chart(ChartId::Power))
+            .extend([Space::with_height(8).into(), info.into()])
+            .spacing(4)
+            .into(),
+            p,
+        )
+    }
+
+    // ─── Charts ──
+    /// Title color of a live chart: `base`, pulsing red once `value`
+    /// reaches its alert threshold.
+    fn alert_color(&self, value: f32, threshold: f32, base: Color) -> Color {
+        if value >= threshold {
+            let red = self.pal.red;
+            Color::from_rgba(red.r, red.g, red.b, 0.7 + 0.3 * self.pulse_phase.sin().abs())
+        } else {
+            base
+        }
+    }
+
+    /// An exportable chart with its buttons; `None` while it has no data.
+    fn chart(&self, id: ChartId) -> Option<Element<'static, Message>> {
+        self.chart_cfg(id).map(|cfg| make_chart(id, cfg, self.t()))
+    }
+
+    /// Settings and data of an exportable chart, from the live buffer, the
+    /// ping history or the loaded History range.
+    fn chart_cfg(&self, id: ChartId) -> Option<ChartCfg> {
+        let p = &self.pal;
+        let t = self.t();
+        let cc = self.chart_colors();
+        let live = |value: fn(&LivePoint) -> f32| self.live_series(self.live_buffer.len(), value);
+        let cfg = match id {
+            ChartId::Cpu => ChartCfg {
+                title: format!("CPU {ICON_DASH} {}", locale::percent(self.anim_cpu, 1)),
+                series: vec![("CPU".into(), self.alert_color(self.anim_cpu, self.cpu_alert_threshold, p.accent), live(|lp| lp.cpu))],
+                y_min: 0.0, y_max: 100.0, filled: true, height: 180.0, unit: "%".into(), colors: cc,
+                x_labels: Vec::new(),
+                bands: Vec::new(),
+                band_toggle: None,
+                markers: Vec::new(),
+                right_axis: None,
+                stacked: false,
+            },
+            // Stacked user/system/iowait/irq/steal; absent until two samples exist
+            ChartId::CpuSplit => {
+                let times = self.current.as_ref()?.cpu_times?;
+                ChartCfg {
+                    title: format!("{} {ICON_DASH} {} {}", t.cpu_time_split, t.io_wait, locale::percent(times.iowait, 1)),
+                    series: vec![
+                        ("user".into(), p.accent, live(|lp| lp.cpu_times.user)),
+                        ("sys".into(), p.red, live(|lp| lp.cpu_times.system)),
+                        ("iowait".into(), p.yellow, live(|lp| lp.cpu_times.iowait)),
+                        ("irq".into(), p.magenta, live(|lp| lp.cpu_times.irq)),
+                        ("steal".into(), p.cyan, live(|lp| lp.cpu_times.steal)),
+                    ],
+                    y_min: 0.0, y_max: 100.0, filled: true, height: 140.0, unit: "%".into(), colors: cc,
+                    x_labels: Vec::new(),
+                    bands: Vec::new(),
+                    band_toggle: None,
+                    markers: Vec::new(),
+                    right_axis: None,
+                    stacked: true,
+                }
+            }
+            ChartId::Memory => ChartCfg {
+                title: format!("Memory {ICON_DASH} {}", locale::percent(self.anim_mem_pct, 1)),
+                series: vec![("RAM".into(), self.alert_color(self.anim_mem_pct, self.mem_alert_threshold, p.green), live(|lp| lp.mem_pct))],
+                y_min: 0.0, y_max: 100.0, filled: true, height: 200.0, unit: "%".into(), colors: cc,
+                x_labels: Vec::new(),
+                bands: Vec::new(),
+                band_toggle: None,
+                markers: Vec::new(),
+                right_axis: None,
+                stacked: false,
+            },
+            ChartId::Network => {
+                let (rx_data, tx_data, unit, y_max) = scale_rates(live(|lp| lp.net_rx as f32), live(|lp| lp.net_tx as f32));
+                ChartCfg {
+                    title: t.network.into(),
+                    series: vec![
+                        (format!("{ICON_ARROW_DOWN} rx"), p.green, rx_data),
+                        (format!("{ICON_ARROW_UP} tx"), p.red, tx_data),
+                    ],
+                    y_min: 0.0, y_max, filled: true, height: 200.0, unit: unit.into(), colors: cc,
+                    x_labels: Vec::new(),
+                    bands: Vec::new(),
+                    band_toggle: None,
+                    markers: Vec::new(),
+                    right_axis: None,
+                    stacked: false,
+                }
+            }
+            ChartId::Latency => {
+                let ping = self.ping.as_ref()?;
+                ping.quality()?;
+                let history = ping.history();
+                let timestamps: Vec<f64> = history.iter().map(|pt| pt.timestamp).collect();
+                let latency: Vec<f32> = history.iter().map(|pt| pt.rtt_ms.unwrap_or(0.0)).collect();
+                let jitter: Vec<f32> = history.iter().map(|pt| pt.quality.jitter_ms).collect();
+                let y_max = latency.iter().copied().fold(10.0_f32, f32::max) * 1.1;
+                let losses = history
+                    .iter()
+                    .filter(|pt| pt.rtt_ms.is_none())
+                    .map(|pt| (pt.timestamp, p.red, format!("{ICON_WARNING} {}", t.packet_loss)));
+                ChartCfg {
+                    title: t.latency.into(),
+                    series: vec![
+                        (t.latency.into(), p.accent, latency),
+                        (t.jitter.into(), p.yellow, jitter),
+                    ],
+                    y_min: 0.0, y_max, filled: false, height: 140.0, unit: " ms".into(), colors: cc,
+                    x_labels: time_labels(&timestamps),
+                    bands: Vec::new(),
+                    band_toggle: None,
+                    markers: time_markers(&timestamps, losses),
+                    right_axis: Some(RightAxis {
+                        series: vec![("MOS".into(), p.green, history.iter().map(|pt| pt.quality.mos).collect())],
+                        y_min: 1.0,
+                        y_max: 4.5,
+                        unit: String::new(),
+                    }),
+                    stacked: false,
+                }
+            }
+            ChartId::Power => {
+                let power = self.current.as_ref()?.power?;
+                // Ticks without a reading are stored as 0 W
+                let peak = self.live_buffer.iter().map(|lp| lp.power_watts).fold(0.0f32, f32::max);
+                ChartCfg {
+                    title: format!("{} {ICON_DASH} {}", t.power, locale::with_unit(power.watts as f64, 1, "W")),
+                    series: vec![(t.power.into(), p.yellow, live(|lp| lp.power_watts))],
+                    y_min: 0.0, y_max: (peak * 1.2).max(1.0), filled: true, height: 200.0, unit: "W".into(), colors: cc,
+                    x_labels: Vec::new(),
+                    bands: Vec::new(),
+                    band_toggle: None,
+                    markers: Vec::new(),
+                    // CPU load on the right, as the usual cause of power swings
+                    right_axis: Some(RightAxis {
+                        series: vec![("CPU".into(), p.accent, live(|lp| lp.cpu))],
+                        y_min: 0.0,
+                        y_max: 100.0,
+                        unit: "%".into(),
+                    }),
+                    stacked: false,
+                }
+            }
+            ChartId::History(which) => return self.history_chart_cfg(which),
+        };
+        Some(cfg)
+    }
+
+    fn history_chart_cfg(&self, which: HistoryChart) -> Option<ChartCfg> {
+        const MAX_PTS: usize = 600;
+
+        let p = &self.pal;
+        let t = self.t();
+        let cc = self.chart_colors();
+        let points = &self.history_view.points;
+        if points.is_empty() {
+            return None;
+        }
+        let timestamps: Vec<f64> = points.iter().map(|h| h.timestamp).collect();
+        let x_labels = time_labels(&timestamps);
+        // Warnings and critical events stored with the range, on every chart
+        let markers = time_markers(
+            &timestamps,
+            self.history_view.events.iter().map(|e| {
+                let color = if e.critical { p.red } else { p.yellow };
+                (e.timestamp, color, format!("{} {}", locale::date_time(e.timestamp), e.text))
+            }),
+        );
+        let band = self.history_view.band_charts.contains(&which);
+
+        let cfg = match which {
+            HistoryChart::Cpu => ChartCfg {
+                title: format!("{ICON_CPU} {}", t.cpu_history),
+                series: vec![("CPU".into(), p.accent, downsample(&points.iter().map(|h| h.cpu).collect::<Vec<_>>(), MAX_PTS))],
+                y_min: 0.0, y_max: 100.0, filled: !band, height: 140.0, unit: "%".into(), colors: cc,
+                x_labels,
+                bands: if band {
+                    vec![history_band(points, p.accent, MAX_PTS, |h| h.range.map_or((h.cpu, h.cpu), |r| r.cpu))]
+                } else {
+                    Vec::new()
+                },
+                band_toggle: Some((HistoryChart::Cpu, band)),
+                markers,
+                right_axis: None,
+                stacked: false,
+            },
+            HistoryChart::Memory => {
+                let mem_pct = |h: &HistoryPoint| if h.mem_total > 0 { h.mem_used as f32 / h.mem_total as f32 * 100.0 } else { 0.0 };
+                ChartCfg {
+                    title: format!("{ICON_MEMORY} {}", t.memory_history),
+                    series: vec![("RAM".into(), p.green, downsample(&points.iter().map(mem_pct).collect::<Vec<_>>(), MAX_PTS))],
+                    y_min: 0.0, y_max: 100.0, filled: !band, height: 140.0, unit: "%".into(), colors: cc,
+                    x_labels,
+                    bands: if band {
+                        vec![history_band(points, p.green, MAX_PTS, |h| h.range.map_or((mem_pct(h), mem_pct(h)), |r| r.mem_percent))]
+                    } else {
+                        Vec::new()
+                    },
+                    band_toggle: Some((HistoryChart::Memory, band)),
+                    markers,
+                    right_axis: None,
+                    stacked: false,
+                }
+            }
+            HistoryChart::Network => {
+                let rx = downsample(&points.iter().map(|h| h.net_rx as f32).collect::<Vec<_>>(), MAX_PTS);
+                let tx = downsample(&points.iter().map(|h| h.net_tx as f32).collect::<Vec<_>>(), MAX_PTS);
+                let (rx, tx, unit, y_max, bands) = if band {
+                    let (_, rx_lo, rx_hi) = history_band(points, p.green, MAX_PTS, |h| {
+                        let (lo, hi) = h.range.map_or((h.net_rx, h.net_rx), |r| r.net_rx);
+                        (lo as f32, hi as f32)
+                    });
+                    let (_, tx_lo, tx_hi) = history_band(points, p.red, MAX_PTS, |h| {
+                        let (lo, hi) = h.range.map_or((h.net_tx, h.net_tx), |r| r.net_tx);
+                        (lo as f32, hi as f32)
+                    });
+                    // Scale to the peaks so the top of the band stays on the chart
+                    let peak = rx_hi.iter().chain(&tx_hi).copied().fold(0.0f32, f32::max);
+                    let (div, unit) = locale::rate_units().scale(f64::from(peak));
+                    let scale = |v: Vec<f32>| v.into_iter().map(|x| x / div as f32).collect::<Vec<_>>();
+                    let bands = vec![(p.green, scale(rx_lo), scale(rx_hi)), (p.red, scale(tx_lo), scale(tx_hi))];
+                    (scale(rx), scale(tx), unit, (peak / div as f32).max(0.001), bands)
+                } else {
+                    let (rx, tx, unit, y_max) = scale_rates(rx, tx);
+                    (rx, tx, unit, y_max, Vec::new())
+                };
+                ChartCfg {
+                    title: format!("{ICON_NETWORK} {}", t.network_history),
+                    series: vec![
+                        (format!("{ICON_ARROW_DOWN} rx"), p.green, rx),
+                        (format!("{ICON_ARROW_UP} tx"), p.red, tx),
+                    ],
+                    y_min: 0.0, y_max, filled: !band, height: 140.0, unit: unit.into(), colors: cc,
+                    x_labels,
+                    bands,
+                    band_toggle: Some((HistoryChart::Network, band)),
+                    markers,
+                    right_axis: None,
+                    stacked: false,
+                }
+            }
+            // Only machines with a power source ever record one
+            HistoryChart::Power => {
+                if !points.iter().any(|h| h.power.is_some()) {
+                    return None;
+                }
+                let power = downsample(&points.iter().map(|h| h.power.unwrap_or(0.0)).collect::<Vec<_>>(), MAX_PTS);
+                let bands = if band {
+                    let watts = |h: &HistoryPoint| h.power.unwrap_or(0.0);
+                    vec![history_band(points, p.yellow, MAX_PTS, |h| h.range.and_then(|r| r.power).unwrap_or((watts(h), watts(h))))]
+                } else {
+                    Vec::new()
+                };
+                let peak = bands.iter().flat_map(|(_, _, high)| high).chain(&power).copied().fold(0.0f32, f32::max);
+                ChartCfg {
+                    title: format!("{ICON_BOLT} {}", t.power_history),
+                    series: vec![(t.power.into(), p.yellow, power)],
+                    y_min: 0.0, y_max: (peak * 1.2).max(1.0), filled: !band, height: 140.0, unit: "W".into(), colors: cc,
+                    x_labels,
+                    bands,
+                    band_toggle: Some((HistoryChart::Power, band)),
+                    markers,
+                    right_axis: Some(RightAxis {
+                        series: vec![("CPU".into(), p.accent, downsample(&points.iter().map(|h| h.cpu).collect::<Vec<_>>(), MAX_PTS))],
+                        y_min: 0.0,
+                        y_max: 100.0,
+                        unit: "%".into(),
+                    }),
+                    stacked: false,
+                }
+            }
+        };
+        Some(cfg)
+    }
+
+    // ─── Changes Detail ──
+    /// Retained snapshot closest to the selected look-back window.
+    fn diff_baseline(&self, snap: &Snapshot) -> Option<&Snapshot> {
+        let target = snap.timestamp - DIFF_WINDOWS[self.diff_window_idx].0;
+        self.recent_snapshots
+            .iter()
+            .rev()
+            .find(|s| s.timestamp <= target)
+            .or_else(|| self.recent_snapshots.front())
+            .map(|s| s.as_ref())
+            .filter(|s| s.timestamp < snap.timestamp)
+    }
+
+    fn refresh_snapshot_diff(&mut self) {
+        self.snapshot_diff = self
+            .current
+            .as_deref()
+            .and_then(|snap| Some(crate::snapdiff::diff(self.diff_baseline(snap)?, snap)));
+    }
+
+    fn view_detail_changes(&self) -> Element<'_, Message> {
+        let p = &self.pal;
+        let t = self.t();
+        let label_c = p.label;
+        let text_c = p.text;
+        let accent = p.accent;
+
+        let mut window_btns: Vec<Element<Message>> = Vec::new();
+        window_btns.push(text(format!("{ICON_CLOCK} {}", t.range)).size(11).font(self.ui_mono).color(label_c).into());
+        for (i, (_, label)) in DIFF_WINDOWS.iter().enumerate() {
+            let is_active = self.diff_window_idx == i;
+            let color = if is_active { accent } else { label_c };
+            window_btns.push(
+                button(text(*label).size(11).color(color))
+                    .on_press(Message::DiffWindowSelected(i))
+                    .style(if is_active { button::primary } else { button::secondary })
+                    .padding([3, 10])
+                    .into()
+            );
+        }
+        let window_row = Row::with_children(window_btns).spacing(4).align_y(Alignment::Center);
+
+        let Some(diff) = &self.snapshot_diff else {
+            return panel(
+                column![
+                    section_title(format!("{ICON_HISTORY} {}", t.changes), p, self.ui_mono),
+                    window_row,
+                    text(t.collecting_data).size(12).font(self.ui_mono).color(label_c),
+                ]
+                .spacing(6)
+                .into(),
+                p,
+            );
+        };
+
+        let mut items: Vec<Element<Message>> = vec![
+            section_title(
+                format!("{ICON_HISTORY} {} ({})", t.changes, locale::with_unit(diff.elapsed_secs, 0, "s")),
+                p,
+                self.ui_mono,
+            ),
+            window_row.into(),
+        ];
+        if diff.is_empty() {
+            items.push(text(t.no_changes).size(12).font(self.ui_mono).color(label_c).into());
+        }
+
+        let mut group = |title: String, lines: Vec<(String, String, Color)>| {
+            if lines.is_empty() {
+                return;
+            }
+            items.push(Space::with_height(4).into());
+            items.push(text(title).size(12).font(self.ui_mono).color(text_c).into());
+            for (name, value, color) in lines {
+                let font = font_for_text(&name, NERD_FONT);
+                items.push(
+                    row![
+                        text(name).size(11).font(font).color(text_c).width(Length::FillPortion(3)),
+                        text(value).size(11).font(self.ui_mono).color(color).width(Length::FillPortion(1)),
+                    ]
+                    .spacing(8)
+                    .into(),
+                );
+            }
+        };
+
+        let by_pid = |list: &[crate::metrics::ProcessId], color: Color| {
+            list.iter().map(|pr| (pr.name.to_string(), format!("PID {}", pr.pid), color)).collect::<Vec<_>>()
+        };
+        group(format!("{ICON_ARROW_UP} {}", t.started_processes), by_pid(&diff.started, p.green));
+        group(format!("{ICON_ARROW_DOWN} {}", t.exited_processes), by_pid(&diff.exited, p.red));
+        group(
+            format!("{ICON_CPU} {}", t.cpu_movers),
+            diff.cpu_movers.iter().map(|m| {
+                let sign = if m.delta >= 0.0 { "+" } else { "\u{2212}" };
+                let delta = m.delta.abs() * self.process_cpu_scale();
+                (m.name.to_string(), format!("{sign}{}", locale::percent(delta, 1)), if m.delta >= 0.0 { p.red } else { p.green })
+            }).collect(),
+        );
+        group(
+            format!("{ICON_MEMORY} {}", t.memory_movers),
+            diff.mem_movers.iter().map(|m| {
+                let sign = if m.delta >= 0 { "+" } else { "\u{2212}" };
+                (m.name.to_string(), format!("{sign}{}", format_bytes(m.delta.unsigned_abs())), if m.delta >= 0 { p.red } else { p.green })
+            }).collect(),
+        );
+        group(
+            format!("{ICON_NETWORK} {}", t.interfaces),
+            diff.interfaces.iter().map(|c| match c {
+                IfaceChange::Added(name) => (name.clone(), "+".into(), p.green),
+                IfaceChange::Removed(name) => (name.clone(), "\u{2212}".into(), p.red),
+                IfaceChange::LinkUp(name) => (name.clone(), t.link_up.into(), p.green),
+                IfaceChange::LinkDown(name) => (name.clone(), t.link_down.into(), p.red),
+            }).collect(),
+        );
+
+        panel(Column::with_children(items).spacing(3).into(), p)
+    }
+
+    fn view_detail_sessions<'a>(&'a self, snap: &'a Snapshot) -> Element<'a, Message> {
+        let p = &self.pal;
+        let t = self.t();
+        let label_c = p.label;
+
+        let mut rows: Vec<Element<Message>> = vec![
+            section_title(format!("{ICON_USERS} {} ({})", t.sessions, self.sessions.len()), p, self.ui_mono),
+        ];
+        if self.sessions.is_empty() {
+            rows.push(text(t.no_sessions).size(12).font(self.ui_mono).color(label_c).into());
+            return panel(Column::with_children(rows).spacing(6).into(), p);
+        }
+
+        let cell = |s: String, color: Color| text(s).size(11).font(self.ui_mono).color(color);
+        rows.push(
+            row![
+                cell(t.session_user.into(), label_c).width(Length::FillPortion(2)),
+                cell(t.session_line.into(), label_c).width(Length::FillPortion(2)),
+                cell(t.session_from.into(), label_c).width(Length::FillPortion(3)),
+                cell(t.session_since.into(), label_c).width(Length::FillPortion(3)),
+                cell(t.session_duration.into(), label_c).width(Length::FillPortion(2)),
+            ]
+            .spacing(8)
+            .into()
+        );
+        for session in &self.sessions {
+            let since = session.login_time.map_or_else(|| "—".into(), |ts| locale::date_time(ts as f64));
+            let duration = session.login_time
+                .map_or_else(|| "—".into(), |ts| format_duration((snap.timestamp - ts as f64).max(0.0) as u64));
+            let (from, from_c) = match &session.host {
+                Some(host) => (host.clone(), p.yellow),
+                None => (t.session_local.into(), label_c),
+            };
+            rows.push(
+                row![
+                    cell(session.user.clone(), p.text).width(Length::FillPortion(2)),
+                    cell(session.line.clone(), p.text).width(Length::FillPortion(2)),
+                    cell(from, from_c).width(Length::FillPortion(3)),
+                    cell(since, label_c).width(Length::FillPortion(3)),
+                    cell(duration, p.text).width(Length::FillPortion(2)),
+                ]
+                .spacing(8)
+                .into()
+            );
+        }
+
+        panel(Column::with_children(rows).spacing(4).into(), p)
+    }
+
+    // ─── PROCESSES TAB ──────────────────────────────────────────
+
+    /// Hand the search text to the collector when it should be applied
+    /// before the process limit, or clear it there.
+    fn sync_process_filter(&mut self) {
+        let filter = if self.search_all_processes { self.process_view.filter.as_str() } else { "" };
+        self.sampler.set_process_filter(filter);
+    }
+
+    /// Processes matching the filter text by name or command line.
+    fn filter_processes<'a>(&self, snap: &'a Snapshot) -> Vec<&'a crate::metrics::ProcessInfo> {
+        let filter_lower = self.process_view.filter.to_lowercase();
+        // The collector's matches lag a keystroke behind; until they come
+        // in, search what the limit kept
+        let source = if self.search_all_processes && !snap.search_matches.is_empty() {
+            &snap.search_matches
+        } else {
+            &snap.processes
+        };
+        source.iter().filter(|p| p.matches(&filter_lower)).collect()
+    }
+
+    fn sort_processes(&self, list: &mut [&crate::metrics::ProcessInfo]) {
+        match self.process_view.sort {
+            ProcessSort::Pid => list.sort_by_key(|p| p.pid),
+            ProcessSort::Name => list.sort_by_key(|p| p.name.to_lowercase()),
+            ProcessSort::Cpu => list.sort_by(|a, b| a.cpu_usage.partial_cmp(&b.cpu_usage).unwrap_or(std::cmp::Ordering::Equal)),
+            ProcessSort::Memory => list.sort_by_key(|p| p.memory_bytes),
+            ProcessSort::DiskIo => list.sort_by_key(|p| p.disk_bytes),
+            ProcessSort::Threads => list.sort_by_key(|p| p.thread_count),
+        }
+        if !self.process_view.sort_asc {
+            list.reverse();
+        }
+        // Stable, so pinned processes keep the chosen order among themselves
+        list.sort_by_key(|p| !self.process_view.pinned.contains(&*p.name));
+    }
+
+    /// After a denied kill, ask whether to retry it as administrator.
+    fn offer_elevated_kill(&mut self, pid: u32) {
+        if !self.offer_elevation || elevate::prompt_name().is_none() {
+            return;
+        }
+        self.elevation_request = ProcessIdentity::of(pid).map(|identity| (pid, identity));
+    }
+
+    fn poll_elevated_kill(&mut self) {
+        let Some(result) = self.elevated_kill.as_ref().and_then(ElevatedKill::result) else { return };
+        let Some(kill) = self.elevated_kill.take() else { return };
+        let pid = kill.pid;
+        self.status_message = Some(match result {
+            Ok(()) => format!("Stopped PID {pid} as administrator"),
+            Err(e) => format!("Failed to stop PID {pid} as administrator: {e}"),
+        });
+    }
+
+    /// Start the weekly check when due and pick up the result of a running
+    /// one. Background checks only speak up when a release is available.
+    fn poll_update_check(&mut self, now_ts: f64) {
+        if self.check_updates && self.update_check.is_none() && now_ts - self.last_update_check >= update::CHECK_INTERVAL_SECS {
+            self.update_check = Some(UpdateCheck::start(true));
+        }
+        let Some(status) = self.update_check.as_ref().and_then(UpdateCheck::result) else { return };
+        let background = self.update_check.take().is_some_and(|c| c.background);
+        if background {
+            match &status {
+                UpdateStatus::Available(release) => {
+                    let msg = format!("Digger {} is available: {}", release.version, release.url);
+                    push_event(&mut self.event_log, LogEvent {
+                        timestamp: Arc::from(locale::clock_now()),
+                        icon: ICON_INFO,
+                        message: msg.clone(),
+                        severity: EventSeverity::Info,
+                        source: EventSource::Digger,
+                        key: EventKey::new("update_available", release.version.as_str()),
+                    });
+                    self.notify(EventSeverity::Info, "Update available", &msg);
+                }
+                UpdateStatus::Failed(e) => eprintln!("[digger] Update check failed: {e}"),
+                UpdateStatus::UpToDate => {}
+            }
+        }
+        // A failed check is retried a week later too, rather than on every tick
+        self.last_update_check = now_ts;
+        self.update_status = Some(status);
+        self.save_prefs();
+    }
+
+    /// Zip preferences, the event log, collection timings, platform info and
+    /// the latest history rows into the downloads folder for bug reports.
+    fn create_diagnostics_bundle(&mut self) {
+        use std::fmt::Write;
+
+        let hostname = self.current.as_ref().map(|s| s.sys_info.hostname.clone()).unwrap_or_default();
+        let home = dirs::home_dir().map(|h| h.to_string_lossy().to_string());
+        let prefs = diagnostics::redacted_prefs(&self.current_prefs(), home.as_deref(), &hostname);
+
+        let mut events = String::new();
+        for entry in &self.event_log {
+            let e = &entry.event;
+            let _ = write!(events, "{} {:?} {:?} {}", e.timestamp, e.severity, e.source, e.message);
+            if entry.count > 1 {
+                let _ = write!(events, " (x{}, first {})", entry.count, entry.first_seen);
+            }
+            events.push('\n');
+        }
+
+        let mut timings = String::from("stage,avg_ms,max_ms,budget_ms\n");
+        let mut timing_line = |name: &str, durations: Vec<Duration>, budget: Option<Duration>| {
+            let avg = durations.iter().sum::<Duration>() / durations.len().max(1) as u32;
+            let max = durations.iter().copied().max().unwrap_or_default();
+            let budget = budget.map(|b| format!("{:.1}", b.as_secs_f64() * 1000.0)).unwrap_or_default();
+            let _ = writeln!(timings, "{name},{:.1},{:.1},{budget}", avg.as_secs_f64() * 1000.0, max.as_secs_f64() * 1000.0);
+        };
+        for stage in CollectStage::ALL {
+            timing_line(stage.name(), self.collect_timings.iter().map(|c| c.get(stage)).collect(), Some(stage.budget()));
+        }
+        timing_line("total", self.collect_timings.iter().map(|c| c.total).collect(), None);
+
+        let mut platform = format!(
+            "digger {}\ntarget: {} {}\nfeatures: {}\n",
+            env!("CARGO_PKG_VERSION"),
+            std::env::consts::OS,
+            std::env::consts::ARCH,
+            if cfg!(feature = "gpu") { "gpu" } else { "none" },
+        );
+        if let Some(snap) = &self.current {
+            let _ = writeln!(platform, "os: {} {}", snap.sys_info.os_name, snap.sys_info.os_version);
+            let _ = writeln!(platform, "kernel: {}", snap.sys_info.kernel_version);
+            if let Some(hypervisor) = &snap.sys_info.hypervisor {
+                let _ = writeln!(platform, "hypervisor: {}", hypervisor.name());
+            }
+            let _ = writeln!(platform, "cpu: {} ({} cores)", snap.cpu_name, snap.cpu_core_count);
+            let _ = writeln!(platform, "memory: {} bytes, swap: {} bytes", snap.memory_total, snap.swap_total);
+            let _ = writeln!(platform, "temperature sensors: {}", snap.temperatures.len());
+            for status in &snap.temp_status {
+                let _ = writeln!(platform, "  {}: {:?}", status.backend, status.issue);
+            }
+            for gpu in &snap.gpu.gpus {
+                let _ = writeln!(platform, "gpu: {} ({} bytes VRAM)", gpu.name, gpu.memory_total);
+            }
+            for status in &snap.gpu.status {
+                let _ = writeln!(platform, "  gpu {}: {:?}", status.backend, status.issue);
+            }
+        }
+        if let Some(err) = self.history_error() {
+            let _ = writeln!(platform, "history: {err}");
+        }
+
+        let files = [
+            ("preferences.json", prefs),
+            ("events.txt", events),
+            ("timings.csv", timings),
+            ("platform.txt", platform),
+            ("history.csv", self.history.export_recent_csv(DIAGNOSTICS_HISTORY_ROWS)),
+        ];
+        if let Some(dir) = dirs::download_dir().or_else(dirs::home_dir) {
+            let now = chrono::Local::now();
+            let path = dir.join(format!("digger_diagnostics_{}.zip", now.format("%Y%m%d_%H%M%S")));
+            match diagnostics::zip(&files, now.naive_local()).and_then(|bytes| std::fs::write(&path, bytes)) {
+                Ok(_) => self.status_message = Some(format!("Exported to {}", path.display())),
+                Err(e) => self.status_message = Some(format!("Export failed: {e}")),
+            }
+        }
     }
 
-    // ─── PROCESSES TAB ──────────────────────────────────────────
+    /// Write the filtered process table, in display order, to the downloads
+    /// folder. Collapsed groups and rows past the display limit are included.
+    fn export_processes(&mut self, json: bool) {
+        let Some(snap) = &self.current else { return };
+        let mut procs = self.filter_processes(snap);
+        self.sort_processes(&mut procs);
+        let rows: Vec<procexport::ExportRow> = if self.process_view.grouped {
+            let current_uid = ProcessGroup::current_uid();
+            ProcessGroup::ALL
+                .iter()
+                .flat_map(|&group| {
+                    procs
+                        .iter()
+                        .filter(move |p| ProcessGroup::of(p, current_uid) == group)
+                        .map(move |p| procexport::ExportRow { group: Some(group.key()), process: p })
+                })
+                .collect()
+        } else {
+            procs.iter().map(|p| procexport::ExportRow { group: None, process: p }).collect()
+        };
+        let columns = procexport::ExportColumns { group: self.process_view.grouped, command: self.process_view.show_command };
+        let meta_columns: Vec<&exportmeta::Column> = exportmeta::PROCESS_COLUMNS
+            .iter()
+            .filter(|c| (c.csv != "group" || columns.group) && (c.csv != "command" || columns.command))
+            .collect();
+        let meta = self.export_meta("processes", None, &meta_columns, json);
+        let (contents, ext, sidecar) = if json {
+            (exportmeta::wrap_json(&meta, &procexport::to_json(&rows, columns, snap.memory_total)), "json", None)
+        } else {
+            (procexport::to_csv(&rows, columns, snap.memory_total), "csv", Some(meta))
+        };
+        if let Some(dir) = dirs::download_dir().or_else(dirs::home_dir) {
+            let stamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+            self.write_export(&dir.join(format!("digger_processes_{stamp}.{ext}")), &contents, sidecar);
+        }
+    }
+
+    /// Metadata block for an export of `columns`, by JSON or CSV name.
+    fn export_meta(&self, kind: &'static str, range: Option<(f64, f64)>, columns: &[&exportmeta::Column], json_names: bool) -> serde_json::Value {
+        let sys_info = self.current.as_ref().map(|s| Arc::clone(&s.sys_info)).unwrap_or_default();
+        let meta = exportmeta::ExportMeta { kind, sys_info: &sys_info, generated_at: locale::now(), range };
+        meta.to_value(columns, json_names)
+    }
+
+    /// Write an export, plus its schema sidecar when it is a CSV with the
+    /// sidecar setting on, and report the outcome in the status bar.
+    fn write_export(&mut self, path: &std::path::Path, contents: &str, csv_meta: Option<serde_json::Value>) {
+        let sidecar = csv_meta.filter(|_| self.export_schema_sidecar).map(|meta| {
+            let json = serde_json::to_string_pretty(&meta).unwrap_or_default();
+            std::fs::write(exportmeta::sidecar_path(path), json)
+        });
+        match std::fs::write(path, contents).and(sidecar.unwrap_or(Ok(()))) {
+            Ok(_) => self.status_message = Some(format!("Exported to {}", path.display())),
+            Err(e) => self.status_message = Some(format!("Export failed: {e}")),
+        }
+    }
 
     fn view_processes(&self) -> Element<'_, Message> {
         let p = &self.pal;
@@ -2577,160 +7272,233 @@ impl Digger {
             .into();
         };
 
-        let group_label = if self.process_grouped { t.grouped } else { t.all };
-        let group_color = if self.process_grouped { accent } else { label_c };
+        let group_label = if self.process_view.grouped { t.grouped } else { t.all };
+        let group_color = if self.process_view.grouped { accent } else { label_c };
 
         let filter_row = row![
             text(format!("{ICON_SEARCH} {}", t.filter)).size(11).font(self.ui_mono).color(label_c),
             Space::with_width(4),
-            text_input(t.search, &self.process_filter)
+            text_input(t.search, &self.process_view.filter)
+                .id(process_filter_id())
                 .on_input(Message::ProcessFilterChanged)
+                .on_submit(Message::FocusProcessTable)
                 .width(220),
             Space::with_width(12),
             button(text(format!("{ICON_BARS} {group_label}")).size(11).font(self.ui_mono).color(group_color))
                 .on_press(Message::ToggleGrouped)
                 .style(button::secondary)
                 .padding([3, 10]),
+            button(text(format!("{ICON_FILE} {}", t.command_line)).size(11).font(self.ui_mono)
+                .color(if self.process_view.show_command { accent } else { label_c }))
+                .on_press(Message::ToggleCommandColumn)
+                .style(button::secondary)
+                .padding([3, 10]),
             Space::with_width(Length::Fill),
-            text(format!("{ICON_LIST} {} {}", snap.processes.len(), t.processes)).size(11).font(self.ui_mono).color(label_c),
+            text(format!("{ICON_LIST} {}", t.process_count.format(self.language, snap.processes.len() as u64))).size(11).font(self.ui_mono).color(label_c),
+            Space::with_width(6),
+            text(format!("{ICON_EXPORT} {}", t.export_processes)).size(11).font(self.ui_mono).color(label_c),
+            button(text("CSV").size(11).color(label_c))
+                .on_press(Message::ExportProcesses { json: false })
+                .style(button::secondary)
+                .padding([3, 10]),
+            button(text("JSON").size(11).color(label_c))
+                .on_press(Message::ExportProcesses { json: true })
+                .style(button::secondary)
+                .padding([3, 10]),
         ]
         .spacing(6)
         .align_y(Alignment::Center)
         .padding([6, 10]);
 
-        let filter_lower = self.process_filter.to_lowercase();
-        let filtered: Vec<_> = snap
-            .processes
-            .iter()
-            .filter(|p| {
-                filter_lower.is_empty()
-                    || p.name.to_lowercase().contains(&filter_lower)
-                    || p.cmd.iter().any(|c| c.to_lowercase().contains(&filter_lower))
-            })
-            .collect();
+        let filtered = self.filter_processes(snap);
 
         let si = |col: ProcessSort| -> &str {
-            if self.process_sort == col {
-                if self.process_sort_asc { ICON_SORT_UP } else { ICON_SORT_DOWN }
+            if self.process_view.sort == col {
+                if self.process_view.sort_asc { ICON_SORT_UP } else { ICON_SORT_DOWN }
             } else { "" }
         };
 
+        let layout = TableLayout {
+            density: self.table_density(),
+            columns: self.process_view.columns,
+            show_command: self.process_view.show_command,
+            cpu_scale: self.process_cpu_scale(),
+            refresh_secs: self.refresh_interval_secs,
+            gpu: snap.gpu.processes.iter().filter_map(|g| Some((g.pid, g.utilization?))).collect(),
+            read_only: self.is_read_only(),
+        };
+        let cols = &layout.columns;
+        let cell = |column: ProcessColumn, content: Element<'static, Message>| {
+            header_cell(column, cols.get(column), content, border_c)
+        };
+        let label = |s: &str| -> Element<'static, Message> {
+            text(s.to_string()).size(11).color(accent).width(Length::Fill).into()
+        };
+        let mut header_cells = vec![
+            cell(ProcessColumn::Pid, sort_btn(format!("PID {}", si(ProcessSort::Pid)), Message::SortBy(ProcessSort::Pid), Length::Fill, accent)),
+            cell(ProcessColumn::Ppid, label("PPID")),
+            cell(ProcessColumn::Name, sort_btn(format!("{} {}", t.command, si(ProcessSort::Name)), Message::SortBy(ProcessSort::Name), Length::Fill, accent)),
+        ];
+        if layout.show_command {
+            header_cells.push(cell(ProcessColumn::Command, label(t.command_line)));
+        }
+        header_cells.extend([
+            cell(ProcessColumn::Cpu, sort_btn(format!("CPU% {}", si(ProcessSort::Cpu)), Message::SortBy(ProcessSort::Cpu), Length::Fill, accent)),
+            cell(ProcessColumn::Memory, sort_btn(format!("{} {}", t.memory, si(ProcessSort::Memory)), Message::SortBy(ProcessSort::Memory), Length::Fill, accent)),
+            cell(ProcessColumn::MemPct, label("Mem%")),
+            cell(ProcessColumn::DiskIo, sort_btn(format!("{} {}", t.disk, si(ProcessSort::DiskIo)), Message::SortBy(ProcessSort::DiskIo), Length::Fill, accent)),
+        ]);
+        if layout.show_gpu() {
+            header_cells.push(cell(ProcessColumn::Gpu, label(&format!("{}%", t.gpu))));
+        }
+        header_cells.extend([
+            cell(ProcessColumn::Status, label("St")),
+            cell(ProcessColumn::Threads, sort_btn(format!("{ICON_THREAD} Thr {}", si(ProcessSort::Threads)), Message::SortBy(ProcessSort::Threads), Length::Fill, accent)),
+            text(t.action).size(11).font(self.ui_mono).color(accent).width(ColumnWidths::ACTION).into(),
+        ]);
         let header = container(
-            row![
-                sort_btn(format!("PID {}", si(ProcessSort::Pid)), ProcessSort::Pid, 60, accent),
-                text("PPID").size(11).color(accent).width(50),
-                sort_btn(format!("{} {}", t.command, si(ProcessSort::Name)), ProcessSort::Name, 180, accent),
-                sort_btn(format!("CPU% {}", si(ProcessSort::Cpu)), ProcessSort::Cpu, 70, accent),
-                sort_btn(format!("{} {}", t.memory, si(ProcessSort::Memory)), ProcessSort::Memory, 90, accent),
-                text("St").size(11).color(accent).width(25),
-                text(format!("{ICON_THREAD} Thr")).size(11).color(accent).width(40),
-                text(t.action).size(11).font(self.ui_mono).color(accent).width(60),
-            ]
+            Row::with_children(header_cells)
             .spacing(2)
+            .align_y(Alignment::Center)
         )
         .padding([4, 10])
+        .width(layout.width())
         .style(move |_: &Theme| container::Style {
             background: Some(Background::Color(sidebar_bg)),
             border: Border { color: border_c, width: 0.0, radius: 0.0.into() },
             ..Default::default()
         });
+        let header = scrollable(header)
+            .id(process_header_scroll_id())
+            .direction(scrollable::Direction::Horizontal(scrollable::Scrollbar::new().width(0).scroller_width(0)))
+            .width(Length::Fill);
 
         let mut rows: Vec<Element<Message>> = Vec::new();
 
-        if self.process_grouped {
-            // SAFETY: libc::getuid() is a simple POSIX syscall that returns the real
-            // user ID of the calling process. It is always safe to call, has no side
-            // effects, cannot fail, and requires no special resources or permissions.
-            // It is used here to separate user-owned processes from system processes.
-            #[cfg(unix)]
-            let current_uid = unsafe { libc::getuid() };
-            // On Windows, metrics.rs sets uid=0 for user processes and uid=1
-            // for system processes (SYSTEM/LOCAL SERVICE/NETWORK SERVICE).
-            // current_uid=0 makes the grouping logic work correctly:
-            // uid != 0 → System, is_desktop_app → Apps, else → Background.
-            #[cfg(not(unix))]
-            let current_uid = 0u32;
-
+        if self.process_view.grouped {
+            let current_uid = ProcessGroup::current_uid();
             let mut apps: Vec<_> = Vec::new();
             let mut background: Vec<_> = Vec::new();
             let mut system: Vec<_> = Vec::new();
 
             for proc in &filtered {
-                if proc.uid != current_uid {
-                    system.push(*proc);
-                } else if proc.is_desktop_app {
-                    apps.push(*proc);
-                } else {
-                    background.push(*proc);
+                match ProcessGroup::of(proc, current_uid) {
+                    ProcessGroup::System => system.push(*proc),
+                    ProcessGroup::Apps => apps.push(*proc),
+                    ProcessGroup::Background => background.push(*proc),
                 }
             }
 
-            let sort_fn = |list: &mut Vec<&crate::metrics::ProcessInfo>| {
-                match self.process_sort {
-                    ProcessSort::Pid => list.sort_by_key(|p| p.pid),
-                    ProcessSort::Name => list.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase())),
-                    ProcessSort::Cpu => list.sort_by(|a, b| a.cpu_usage.partial_cmp(&b.cpu_usage).unwrap_or(std::cmp::Ordering::Equal)),
-                    ProcessSort::Memory => list.sort_by_key(|p| p.memory_bytes),
-                }
-                if !self.process_sort_asc {
-                    list.reverse();
-                }
-            };
-            sort_fn(&mut apps);
-            sort_fn(&mut background);
-            sort_fn(&mut system);
+            self.sort_processes(&mut apps);
+            self.sort_processes(&mut background);
+            self.sort_processes(&mut system);
 
             let mut row_idx = 0usize;
-            let sections: Vec<(&str, &str, Color, &Vec<&crate::metrics::ProcessInfo>)> = vec![
-                (ICON_APPS, t.applications, green, &apps),
-                (ICON_BACKGROUND, t.background_processes, yellow, &background),
-                (ICON_SYSTEM, t.system, red, &system),
+            let rtl = locale::is_rtl();
+            let sections: Vec<(ProcessGroup, &str, &str, Color, &Vec<&crate::metrics::ProcessInfo>)> = vec![
+                (ProcessGroup::Apps, ICON_APPS, t.applications, green, &apps),
+                (ProcessGroup::Background, ICON_BACKGROUND, t.background_processes, yellow, &background),
+                (ProcessGroup::System, ICON_SYSTEM, t.system, red, &system),
             ];
 
-            for (icon, label, color, list) in sections {
+            for (group, icon, label, color, list) in sections {
                 if list.is_empty() { continue; }
+                let collapsed = self.process_view.collapsed_groups.contains(&group);
+                let chevron = match (collapsed, rtl) {
+                    (false, _) => ICON_CHEVRON_DOWN,
+                    (true, false) => ICON_CHEVRON_RIGHT,
+                    (true, true) => ICON_CHEVRON_LEFT,
+                };
+                let mut hdr_items: Vec<Element<Message>> = vec![
+                    text(chevron).size(10).color(label_c).into(),
+                    text(format!("{icon} {label} ({})", list.len())).size(self.table_density().text_size(11)).font(self.ui_mono).color(color).into(),
+                ];
+                // Rows are hidden, so summarize them in the header instead
+                if collapsed {
+                    let cpu: f32 = list.iter().map(|p| p.cpu_usage).sum::<f32>() * self.process_cpu_scale();
+                    let mem: u64 = list.iter().map(|p| p.memory_bytes).sum();
+                    hdr_items.push(Space::with_width(Length::Fill).into());
+                    hdr_items.push(
+                        text(format!("CPU {}  {} {}", locale::percent(cpu, 1), t.memory, format_memory(mem, self.process_mem_unit)))
+                            .size(11).font(self.ui_mono).color(label_c).into(),
+                    );
+                }
                 let hdr_bg = sidebar_bg;
-                let section_hdr = container(
-                    text(format!("{icon} {label} ({})", list.len())).size(11).font(self.ui_mono).color(color),
-                )
-                .padding([4, 10])
-                .width(Length::Fill)
-                .style(move |_: &Theme| container::Style {
-                    background: Some(Background::Color(hdr_bg)),
-                    ..Default::default()
-                });
+                let section_hdr = button(directional_row(rtl, hdr_items).spacing(6).align_y(Alignment::Center))
+                    .on_press(Message::ToggleProcessGroup(group))
+                    .padding([self.table_density().pad_y(4), 10])
+                    .width(layout.width())
+                    .style(move |_: &Theme, _| button::Style {
+                        background: Some(Background::Color(hdr_bg)),
+                        text_color: color,
+                        ..Default::default()
+                    });
                 rows.push(section_hdr.into());
+                if collapsed { continue; }
 
                 for proc in list.iter() {
                     let row_bg = if row_idx.is_multiple_of(2) { panel_bg } else { bg };
-                    rows.push(process_row(proc, row_bg, p, self.cpu_alert_threshold, self.ui_mono));
+                    let expanded = self.process_view.expanded_threads.contains(&proc.pid);
+                    rows.push(process_row(proc, row_bg, p, self.cpu_alert_threshold, snap.memory_total, self.process_mem_unit, &layout, self.selected_pid == Some(proc.pid), expanded, self.process_view.pinned.contains(&*proc.name), t, self.ui_mono));
+                    if let Some(details) = self.selected_details.as_ref().filter(|_| self.selected_pid == Some(proc.pid)) {
+                        rows.push(process_details_row(details, p, &layout, t));
+                    }
+                    if expanded {
+                        rows.extend(thread_rows(snap.threads.get(&proc.pid), row_bg, p, &layout, t, self.ui_mono));
+                    }
                     row_idx += 1;
                 }
             }
         } else {
             let mut procs = filtered;
-            match self.process_sort {
-                ProcessSort::Pid => procs.sort_by_key(|p| p.pid),
-                ProcessSort::Name => procs.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase())),
-                ProcessSort::Cpu => procs.sort_by(|a, b| a.cpu_usage.partial_cmp(&b.cpu_usage).unwrap_or(std::cmp::Ordering::Equal)),
-                ProcessSort::Memory => procs.sort_by_key(|p| p.memory_bytes),
-            }
-            if !self.process_sort_asc {
-                procs.reverse();
-            }
+            self.sort_processes(&mut procs);
             for (i, proc) in procs.iter().take(self.process_limit).enumerate() {
                 let row_bg = if i % 2 == 0 { panel_bg } else { bg };
-                rows.push(process_row(proc, row_bg, p, self.cpu_alert_threshold, self.ui_mono));
+                let expanded = self.process_view.expanded_threads.contains(&proc.pid);
+                rows.push(process_row(proc, row_bg, p, self.cpu_alert_threshold, snap.memory_total, self.process_mem_unit, &layout, self.selected_pid == Some(proc.pid), expanded, self.process_view.pinned.contains(&*proc.name), t, self.ui_mono));
+                if let Some(details) = self.selected_details.as_ref().filter(|_| self.selected_pid == Some(proc.pid)) {
+                    rows.push(process_details_row(details, p, &layout, t));
+                }
+                if expanded {
+                    rows.extend(thread_rows(snap.threads.get(&proc.pid), row_bg, p, &layout, t, self.ui_mono));
+                }
             }
         }
 
-        let table = Column::with_children(rows).spacing(0);
+        // Only the rows scroll; the filter bar and column header stay pinned.
+        // Shift+wheel or the bottom scrollbar pans wide tables sideways.
+        let table = scrollable(Column::with_children(rows).spacing(0))
+            .id(process_scroll_id())
+            .direction(scrollable::Direction::Both {
+                vertical: scrollable::Scrollbar::default(),
+                horizontal: scrollable::Scrollbar::default(),
+            })
+            .on_scroll(Message::ProcessesScrolled)
+            .width(Length::Fill)
+            .height(Length::Fill);
+        // Pull-to-refresh progress, between the header and the rows
+        let pull_hint: Option<Element<Message>> = self.pull.filter(|p| p.distance > 0.0).map(|pull| {
+            let (icon, hint, color) = if pull.distance >= PULL_REFRESH_PX {
+                (ICON_REFRESH, t.release_to_refresh, accent)
+            } else {
+                (ICON_ARROW_DOWN, t.pull_to_refresh, label_c)
+            };
+            container(text(format!("{icon} {hint}")).size(11).font(self.ui_mono).color(color))
+                .center_x(Length::Fill)
+                .padding([(pull.distance.min(PULL_REFRESH_PX) / 4.0) as u16, 0])
+                .into()
+        });
         let content = panel(
-            column![filter_row, header, table].spacing(0).into(),
+            column![filter_row, header]
+                .push_maybe(pull_hint)
+                .push(container(table).id(process_rows_id()))
+                .spacing(0)
+                .height(Length::Fill)
+                .into(),
             p,
         );
 
-        scrollable(column![content].padding(4)).into()
+        container(content).padding(4).height(Length::Fill).into()
     }
 
     // ─── HISTORY TAB ────────────────────────────────────────────
@@ -2746,7 +7514,7 @@ impl Digger {
         range_btns.push(text(format!("{ICON_CLOCK} {}", t.range)).size(11).font(self.ui_mono).color(label_c).into());
         range_btns.push(Space::with_width(4).into());
         for (i, (_, label)) in HISTORY_RANGES.iter().enumerate() {
-            let is_active = self.history_range_idx == i;
+            let is_active = self.history_view.custom.is_none() && self.history_view.range_idx == i;
             let color = if is_active { accent } else { label_c };
             let btn = button(text(*label).size(11).color(color))
                 .on_press(Message::HistoryRangeSelected(i))
@@ -2754,6 +7522,17 @@ impl Digger {
                 .padding([3, 10]);
             range_btns.push(btn.into());
         }
+        if HISTORY_RANGES[self.history_view.range_idx].0 <= HISTORY_FOLLOW_MAX_SECS {
+            let following = self.history_view.follows();
+            range_btns.push(Space::with_width(4).into());
+            range_btns.push(
+                button(text(format!("{ICON_BULLET} {}", t.follow_live)).size(11).color(if following { p.green } else { label_c }))
+                    .on_press(Message::ToggleHistoryFollow)
+                    .style(if following { button::primary } else { button::secondary })
+                    .padding([3, 10])
+                    .into()
+            );
+        }
 
         // Export buttons
         range_btns.push(Space::with_width(Length::Fill).into());
@@ -2771,10 +7550,49 @@ impl Digger {
                 .padding([3, 10])
                 .into()
         );
+        for (period, label) in [(ReportPeriod::Week, t.report_weekly), (ReportPeriod::Month, t.report_monthly)] {
+            range_btns.push(
+                button(text(format!("{ICON_FILE} {label}")).size(11).color(label_c))
+                    .on_press(Message::GenerateReport(period))
+                    .style(button::secondary)
+                    .padding([3, 10])
+                    .into()
+            );
+        }
+
+        // Custom window, typed in the zone chosen in settings
+        let custom_active = self.history_view.custom.is_some();
+        let placeholder = if locale::utc() { "YYYY-MM-DD HH:MM UTC" } else { "YYYY-MM-DD HH:MM" };
+        let picker_row = row![
+            text(t.range_custom).size(11).font(self.ui_mono).color(if custom_active { accent } else { label_c }),
+            text_input(placeholder, &self.history_view.from_input)
+                .on_input(Message::HistoryFromInput)
+                .on_submit(Message::ApplyHistoryRange)
+                .size(11)
+                .width(170),
+            text("–").size(11).color(label_c),
+            text_input(placeholder, &self.history_view.to_input)
+                .on_input(Message::HistoryToInput)
+                .on_submit(Message::ApplyHistoryRange)
+                .size(11)
+                .width(170),
+            button(text(t.apply).size(11).color(if custom_active { accent } else { label_c }))
+                .on_press(Message::ApplyHistoryRange)
+                .style(if custom_active { button::primary } else { button::secondary })
+                .padding([3, 10]),
+        ]
+        .push_maybe(self.history_view.range_error.map(|e| text(e).size(11).font(self.ui_mono).color(p.red)))
+        .spacing(6)
+        .align_y(Alignment::Center);
 
-        let range_row = Row::with_children(range_btns).spacing(4).padding([6, 10]);
+        let range_row = column![
+            Row::with_children(range_btns).spacing(4),
+            picker_row,
+        ]
+        .spacing(6)
+        .padding([6, 10]);
 
-        if self.history_points.is_empty() {
+        if self.history_view.points.is_empty() {
             return panel(
                 column![
                     range_row,
@@ -2789,59 +7607,92 @@ impl Digger {
             );
         }
 
-        const MAX_PTS: usize = 600;
-
-        let cpu_data = downsample(
-            &self.history_points.iter().map(|h| h.cpu).collect::<Vec<_>>(), MAX_PTS,
-        );
-        let cpu_chart = make_chart(ChartCfg {
-            title: format!("{ICON_CPU} {}", t.cpu_history),
-            series: vec![("CPU".into(), p.accent, cpu_data)],
-            y_min: 0.0, y_max: 100.0, filled: true, height: 140.0, unit: "%".into(), colors: cc,
-        });
-
-        let mem_data = downsample(
-            &self.history_points.iter().map(|h| {
-                if h.mem_total > 0 { h.mem_used as f32 / h.mem_total as f32 * 100.0 } else { 0.0 }
-            }).collect::<Vec<_>>(), MAX_PTS,
-        );
-        let mem_chart = make_chart(ChartCfg {
-            title: format!("{ICON_MEMORY} {}", t.memory_history),
-            series: vec![("RAM".into(), p.green, mem_data)],
-            y_min: 0.0, y_max: 100.0, filled: true, height: 140.0, unit: "%".into(), colors: cc,
-        });
-
-        let rx_kb = downsample(
-            &self.history_points.iter().map(|h| h.net_rx as f32 / 1024.0).collect::<Vec<_>>(), MAX_PTS,
-        );
-        let tx_kb = downsample(
-            &self.history_points.iter().map(|h| h.net_tx as f32 / 1024.0).collect::<Vec<_>>(), MAX_PTS,
-        );
-        let hist_max_kb = rx_kb.iter().chain(tx_kb.iter()).cloned().fold(0.001f32, f32::max);
-        let (h_rx, h_tx, h_unit, h_ymax) = if hist_max_kb >= 1024.0 {
-            let rx_mb: Vec<f32> = rx_kb.iter().map(|v| v / 1024.0).collect();
-            let tx_mb: Vec<f32> = tx_kb.iter().map(|v| v / 1024.0).collect();
-            (rx_mb, tx_mb, " MB/s", hist_max_kb / 1024.0)
-        } else {
-            (rx_kb, tx_kb, " KB/s", hist_max_kb)
-        };
-        let net_chart = make_chart(ChartCfg {
-            title: format!("{ICON_NETWORK} {}", t.network_history),
-            series: vec![
-                (format!("{ICON_ARROW_DOWN} rx"), p.green, h_rx),
-                (format!("{ICON_ARROW_UP} tx"), p.red, h_tx),
-            ],
-            y_min: 0.0, y_max: h_ymax, filled: true, height: 140.0, unit: h_unit.into(), colors: cc,
-        });
-
-        let content = column![
-            panel(column![range_row, cpu_chart].spacing(6).into(), p),
-            panel(mem_chart, p),
-            panel(net_chart, p),
+        let history_panel = |which| self.chart(ChartId::History(which)).map(|chart| panel(chart, p));
+        let mut content = column![
+            panel(column![range_row].push_maybe(self.chart(ChartId::History(HistoryChart::Cpu))).spacing(6).into(), p),
         ]
+        .push_maybe(history_panel(HistoryChart::Memory))
+        .push_maybe(history_panel(HistoryChart::Network))
+        .push_maybe(history_panel(HistoryChart::Power))
         .spacing(4)
         .padding(4);
 
+        // Distribution of the selected metric over the range
+        let has_power = self.history_view.points.iter().any(|h| h.power.is_some());
+        let mut metric_btns: Vec<Element<Message>> = vec![
+            text(format!("{ICON_LOAD} {}", t.distribution)).size(12).font(self.ui_mono).color(p.accent).into(),
+            Space::with_width(Length::Fill).into(),
+        ];
+        for metric in HistoryMetric::ALL {
+            let name = match metric {
+                HistoryMetric::Cpu => "CPU",
+                HistoryMetric::Memory => t.memory,
+                HistoryMetric::Power if has_power => t.power,
+                HistoryMetric::Power => continue,
+            };
+            let is_active = self.history_view.histogram_metric == metric;
+            metric_btns.push(
+                button(text(name).size(11).color(if is_active { accent } else { label_c }))
+                    .on_press(Message::HistogramMetricSelected(metric))
+                    .style(if is_active { button::primary } else { button::secondary })
+                    .padding([3, 10])
+                    .into(),
+            );
+        }
+        let (hist_color, hist_unit) = match self.history_view.histogram_metric {
+            HistoryMetric::Cpu => (p.accent, "%"),
+            HistoryMetric::Memory => (p.green, "%"),
+            HistoryMetric::Power => (p.yellow, "W"),
+        };
+        let histogram = Canvas::new(HistogramChart {
+            dist: self.history_view.histogram.clone(),
+            color: hist_color,
+            marker: p.red,
+            unit: hist_unit.into(),
+            colors: cc,
+        })
+        .width(Length::Fill)
+        .height(Length::Fixed(140.0));
+        content = content.push(panel(
+            column![
+                Row::with_children(metric_btns).spacing(4).align_y(Alignment::Center),
+                histogram,
+            ]
+            .spacing(6)
+            .into(),
+            p,
+        ));
+
+        // Weekly pattern: always the last 7 days, regardless of the range
+        let mut days = t.weekdays_short.split(',').map(str::to_string);
+        let heatmap = Canvas::new(Heatmap {
+            grid: self.history_view.heatmap,
+            days: std::array::from_fn(|_| days.next().unwrap_or_default()),
+            colors: HeatmapColors {
+                bg: p.panel_bg,
+                empty: p.bar_bg,
+                low: Color::from_rgb(
+                    p.panel_bg.r + (p.accent.r - p.panel_bg.r) * 0.12,
+                    p.panel_bg.g + (p.accent.g - p.panel_bg.g) * 0.12,
+                    p.panel_bg.b + (p.accent.b - p.panel_bg.b) * 0.12,
+                ),
+                high: p.accent,
+                label: p.label,
+            },
+        })
+        .width(Length::Fill)
+        .height(Length::Fixed(170.0));
+        content = content.push(panel(
+            column![
+                text(format!("{ICON_CALENDAR} {}", t.cpu_heatmap)).size(12).font(self.ui_mono).color(p.accent),
+                text(t.cpu_heatmap_desc).size(10).font(self.ui_mono).color(label_c),
+                heatmap,
+            ]
+            .spacing(6)
+            .into(),
+            p,
+        ));
+
         scrollable(content).into()
     }
 }
@@ -2867,13 +7718,19 @@ fn gradient_color(t: f32, p: &Palette) -> Color {
     }
 }
 
-fn format_temp(temp_c: f32, celsius: bool) -> String {
+fn format_temp(temp_c: f32, unit: TempUnit) -> String {
     if temp_c < -30.0 {
         "N/A".to_string()
-    } else if celsius {
-        format!("{:.0}\u{00b0}C", temp_c)
     } else {
-        format!("{:.0}\u{00b0}F", temp_c * 9.0 / 5.0 + 32.0)
+        locale::temperature(temp_c, unit)
+    }
+}
+
+fn range_error_text(e: RangeError, t: &Strings) -> &'static str {
+    match e {
+        RangeError::Empty => t.range_empty,
+        RangeError::Future => t.range_future,
+        RangeError::Expired => t.range_expired,
     }
 }
 
@@ -2904,23 +7761,68 @@ struct ChartCfg {
     height: f32,
     unit: String,
     colors: ChartColors,
+    x_labels: Vec<(f32, String)>,
+    bands: Vec<(Color, Vec<f32>, Vec<f32>)>,
+    /// Adds an Average / Min-max switch for this History chart, with
+    /// whether the band is shown.
+    band_toggle: Option<(HistoryChart, bool)>,
+    markers: Vec<ChartMarker>,
+    /// Series in a second unit, labelled on the right.
+    right_axis: Option<RightAxis>,
+    /// Draw the series on top of each other; see `LineChart::stacked`.
+    stacked: bool,
 }
 
-fn make_chart(cfg: ChartCfg) -> Element<'static, Message> {
-    let chart = LineChart {
-        series: cfg.series,
-        y_min: cfg.y_min,
-        y_max: cfg.y_max,
-        title: cfg.title,
-        filled: cfg.filled,
-        unit: cfg.unit,
-        colors: cfg.colors,
-        show_avg: true,
-    };
-    Canvas::new(chart)
-        .width(Length::Fill)
-        .height(Length::Fixed(cfg.height))
-        .into()
+/// Pixel size of charts exported with "Save as PNG".
+const CHART_PNG_SIZE: (u32, u32) = (960, 320);
+
+impl ChartCfg {
+    fn into_chart(self) -> LineChart {
+        LineChart {
+            series: self.series,
+            y_min: self.y_min,
+            y_max: self.y_max,
+            title: self.title,
+            filled: self.filled,
+            stacked: self.stacked,
+            unit: self.unit,
+            colors: self.colors,
+            show_avg: !self.stacked,
+            x_labels: self.x_labels,
+            bands: self.bands,
+            markers: self.markers,
+            right_axis: self.right_axis,
+        }
+    }
+}
+
+fn make_chart(id: ChartId, cfg: ChartCfg, t: &Strings) -> Element<'static, Message> {
+    let (height, band_toggle) = (cfg.height, cfg.band_toggle);
+    let chart = cfg.into_chart();
+    let label_c = chart.colors.label;
+    let copy_btn = button(text(format!("{ICON_COPY} {}", t.copy_data)).size(10).color(label_c))
+        .on_press(Message::CopyChartData(id))
+        .style(button::text)
+        .padding([1, 6]);
+    let save_btn = button(text(format!("{ICON_IMAGE} {}", t.save_png)).size(10).color(label_c))
+        .on_press(Message::SaveChartPng(id))
+        .style(button::text)
+        .padding([1, 6]);
+    let band_btn = band_toggle.map(|(which, on)| {
+        let (label, color) = if on { (t.chart_min_max, chart.colors.text) } else { (t.chart_average, label_c) };
+        button(text(format!("{ICON_BARS} {label}")).size(10).color(color))
+            .on_press(Message::ToggleChartBand(which))
+            .style(button::text)
+            .padding([1, 6])
+    });
+    column![
+        Canvas::new(chart)
+            .width(Length::Fill)
+            .height(Length::Fixed(height)),
+        row![Space::with_width(Length::Fill)].push_maybe(band_btn).push(copy_btn).push(save_btn),
+    ]
+    .spacing(2)
+    .into()
 }
 
 fn sidebar_item<'a>(
@@ -2956,7 +7858,9 @@ fn sidebar_item<'a>(
         text(label).size(12).color(if is_active { color } else { label_c }),
         text(value).size(13).font(mono_font).color(if is_active { text_c } else { label_c }),
     ]
-    .spacing(2);
+    .spacing(2)
+    .width(Length::Fill)
+    .align_x(if locale::is_rtl() { Alignment::End } else { Alignment::Start });
 
     button(content)
         .on_press(Message::OverviewSection(target))
@@ -3004,7 +7908,8 @@ fn settings_sidebar_item(
     let text_color = if is_active { p.text } else { p.label };
     let text_c = p.text;
 
-    button(text(label.to_string()).size(12).font(mono_font).color(text_color))
+    let align = if locale::is_rtl() { Alignment::End } else { Alignment::Start };
+    button(text(label.to_string()).size(12).font(mono_font).color(text_color).width(Length::Fill).align_x(align))
         .on_press(Message::SettingsPanelSelected(target))
         .width(Length::Fill)
         .padding([8, 12])
@@ -3036,6 +7941,11 @@ fn settings_sidebar_item(
         .into()
 }
 
+/// An icon-only control with its name in a tooltip.
+fn labeled<'a>(control: impl Into<Element<'a, Message>>, label: &str, font: iced::Font) -> Element<'a, Message> {
+    tooltip(control, text(label.to_string()).size(10).font(font), tooltip::Position::Left).into()
+}
+
 fn collapsible_section<'a>(
     section: SettingsSection,
     title: impl ToString,
@@ -3047,7 +7957,12 @@ fn collapsible_section<'a>(
 ) -> Element<'a, Message> {
     let title_str = title.to_string();
     let desc_str = description.to_string();
-    let chevron = if collapsed { ICON_CHEVRON_RIGHT } else { ICON_CHEVRON_DOWN };
+    let rtl = locale::is_rtl();
+    let chevron = match (collapsed, rtl) {
+        (false, _) => ICON_CHEVRON_DOWN,
+        (true, false) => ICON_CHEVRON_RIGHT,
+        (true, true) => ICON_CHEVRON_LEFT,
+    };
     let text_c = p.text;
     let label_c = p.label;
     let panel_bg = p.panel_bg;
@@ -3059,11 +7974,11 @@ fn collapsible_section<'a>(
         (panel_bg.b + 0.02).min(1.0),
     );
     let header = button(
-        row![
-            text(title_str).size(13).font(mono_font).color(text_c),
-            Space::with_width(Length::Fill),
-            text(chevron).size(12).color(label_c),
-        ]
+        directional_row(rtl, vec![
+            text(title_str).size(13).font(mono_font).color(text_c).into(),
+            Space::with_width(Length::Fill).into(),
+            text(chevron).size(12).color(label_c).into(),
+        ])
         .align_y(Alignment::Center)
     )
     .on_press(Message::ToggleSection(section))
@@ -3096,8 +8011,9 @@ fn collapsible_section<'a>(
 
     let mut body_items: Vec<Element<Message>> = Vec::new();
     if !desc_str.is_empty() {
+        let align = if rtl { Alignment::End } else { Alignment::Start };
         body_items.push(
-            text(desc_str).size(10).color(label_c).into()
+            text(desc_str).size(10).color(label_c).width(Length::Fill).align_x(align).into()
         );
         body_items.push(Space::with_height(10).into());
     }
@@ -3126,21 +8042,52 @@ fn info_row<'a>(label: impl ToString, value: impl ToString, p: &Palette, mono_fo
     let v = value.to_string();
     let label_c = p.label;
     let text_c = p.text;
-    row![
-        text(l).size(11).color(label_c).width(120),
-        text(v).size(11).font(mono_font).color(text_c),
-    ]
+    directional_row(locale::is_rtl(), vec![
+        text(l).size(11).color(label_c).width(120).into(),
+        text(v).size(11).font(mono_font).color(text_c).into(),
+    ])
     .spacing(8)
     .into()
 }
 
-fn process_row<'a>(proc: &crate::metrics::ProcessInfo, bg: Color, p: &'a Palette, cpu_threshold: f32, mono_font: iced::Font) -> Element<'a, Message> {
-    let cpu_color = gradient_color(proc.cpu_usage / 100.0, p);
+/// A row whose children are laid out right-to-left when `rtl` is set.
+fn directional_row<'a>(rtl: bool, mut children: Vec<Element<'a, Message>>) -> Row<'a, Message> {
+    if rtl {
+        children.reverse();
+    }
+    Row::with_children(children)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn process_row<'a>(
+    proc: &crate::metrics::ProcessInfo,
+    bg: Color,
+    p: &'a Palette,
+    cpu_threshold: f32,
+    mem_total: u64,
+    mem_unit: MemUnit,
+    layout: &TableLayout,
+    selected: bool,
+    threads_expanded: bool,
+    pinned: bool,
+    t: &Strings,
+    mono_font: iced::Font,
+) -> Element<'a, Message> {
+    let density = layout.density;
+    let cols = &layout.columns;
+    let cpu_pct = proc.cpu_usage * layout.cpu_scale;
+    let cpu_color = gradient_color(cpu_pct / 100.0, p);
     let pid = proc.pid;
     let pid_str = pid.to_string();
-    let name = proc.name.clone();
-    let cpu = format!("{:.1}%", proc.cpu_usage);
-    let mem = format_bytes(proc.memory_bytes);
+    let name = proc.name.to_string();
+    let cpu = locale::percent(cpu_pct, 1);
+    let mem = format_memory(proc.memory_bytes, mem_unit);
+    let mem_pct = locale::percent(mem_percent(proc.memory_bytes, mem_total), 1);
+    let disk = if proc.disk_bytes == 0 {
+        String::from("-")
+    } else {
+        format!("{}/s", format_bytes(proc.disk_bytes / layout.refresh_secs.max(1)))
+    };
     let label_c = p.label;
     let text_c = p.text;
     let accent = p.accent;
@@ -3165,26 +8112,75 @@ fn process_row<'a>(proc: &crate::metrics::ProcessInfo, bg: Color, p: &'a Palette
     // Parent PID display
     let ppid_str = proc.parent_pid.map(|p| p.to_string()).unwrap_or_default();
 
-    // Highlight row if CPU exceeds threshold
-    let row_bg = if proc.cpu_usage >= cpu_threshold {
+    // Highlight the selected row, then rows whose CPU exceeds the threshold
+    let row_bg = if selected {
+        Color::from_rgba(accent.r, accent.g, accent.b, 0.2)
+    } else if cpu_pct >= cpu_threshold {
         Color::from_rgba(p.red.r, p.red.g, p.red.b, 0.1)
     } else {
         bg
     };
 
     let kill_btn = button(
-        text(ICON_KILL).size(10).color(label_c)
+        text(ICON_KILL).size(density.text_size(10)).color(label_c)
     )
     .on_press(Message::KillProcess(pid))
     .style(button::text)
-    .padding([1, 4]);
+    .padding([density.pad_y(1), 4]);
+
+    let pin_btn = labeled(
+        button(
+            text(if pinned { ICON_STAR } else { ICON_STAR_EMPTY })
+                .size(density.text_size(10))
+                .color(if pinned { p.yellow } else { label_c })
+        )
+        .on_press(Message::TogglePinned(Arc::clone(&proc.name)))
+        .style(button::text)
+        .padding([density.pad_y(1), 2]),
+        if pinned { t.unpin_process } else { t.pin_process },
+        mono_font,
+    );
+
+    // Multi-threaded processes can list their threads under the row
+    let threads_label = text(proc.thread_count.to_string()).size(density.text_size(11)).font(mono_font).color(label_c);
+    let threads_cell: Element<Message> = if proc.thread_count > 1 {
+        let chevron = if threads_expanded { ICON_CHEVRON_DOWN } else { ICON_CHEVRON_RIGHT };
+        button(row![text(chevron).size(density.text_size(8)).color(label_c), threads_label].spacing(3).align_y(Alignment::Center))
+            .on_press(Message::ToggleThreads(pid))
+            .style(button::text)
+            .padding(0)
+            .width(cols.get(ProcessColumn::Threads))
+            .into()
+    } else {
+        threads_label.width(cols.get(ProcessColumn::Threads)).into()
+    };
 
+    let name_font = font_for_text(&name, NERD_FONT);
+    let name_text = text(name).size(density.text_size(11)).font(name_font).color(text_c);
+    // Flatpak, Snap and AppImage apps get a badge after the name
+    let name_label: Element<Message> = match proc.packaging.badge() {
+        None => name_text.width(cols.get(ProcessColumn::Name)).into(),
+        Some(badge) => row![
+            name_text,
+            container(text(badge).size(density.text_size(8)).font(mono_font).color(accent))
+                .padding([0, 4])
+                .style(move |_: &Theme| container::Style {
+                    border: Border { color: accent, width: 1.0, radius: 3.0.into() },
+                    ..Default::default()
+                }),
+        ]
+        .spacing(4)
+        .align_y(Alignment::Center)
+        .width(cols.get(ProcessColumn::Name))
+        .into(),
+    };
     let name_col: Element<Message> = if cmd_str.is_empty() {
-        text(name.clone()).size(11).color(text_c).width(180).into()
+        name_label
     } else {
+        let cmd_font = font_for_text(&cmd_str, NERD_FONT);
         tooltip(
-            text(name.clone()).size(11).color(text_c).width(180),
-            text(cmd_str).size(9).color(text_c),
+            name_label,
+            text(cmd_str).size(9).font(cmd_font).color(text_c),
             tooltip::Position::Top,
         )
         .style(move |_theme: &Theme| container::Style {
@@ -3201,31 +8197,185 @@ fn process_row<'a>(proc: &crate::metrics::ProcessInfo, bg: Color, p: &'a Palette
         .into()
     };
 
-    container(
-        row![
-            text(pid_str).size(11).font(mono_font).color(label_c).width(60),
-            text(ppid_str).size(10).font(mono_font).color(label_c).width(50),
-            name_col,
-            text(cpu).size(11).font(mono_font).color(cpu_color).width(70),
-            text(mem).size(11).font(mono_font).color(accent).width(90),
-            text(String::from(proc.status)).size(11).font(mono_font).color(match proc.status {
+    let mut cells: Vec<Element<Message>> = vec![
+        text(pid_str).size(density.text_size(11)).font(mono_font).color(label_c).width(cols.get(ProcessColumn::Pid)).into(),
+        text(ppid_str).size(density.text_size(10)).font(mono_font).color(label_c).width(cols.get(ProcessColumn::Ppid)).into(),
+        name_col,
+    ];
+    if layout.show_command {
+        // The selected row wraps the full command line instead of eliding it
+        let width = cols.get(ProcessColumn::Command);
+        let line = if selected {
+            proc.cmd.join(" ")
+        } else {
+            crate::metrics::elide_command_line(&proc.cmd, (width / COMMAND_CHAR_WIDTH) as usize)
+        };
+        let font = font_for_text(&line, mono_font);
+        cells.push(text(line).size(density.text_size(10)).font(font).color(label_c).width(width).into());
+    }
+
+    let row = container(
+        Row::with_children(cells).extend([
+            text(cpu).size(density.text_size(11)).font(mono_font).color(cpu_color).width(cols.get(ProcessColumn::Cpu)).into(),
+            text(mem).size(density.text_size(11)).font(mono_font).color(accent).width(cols.get(ProcessColumn::Memory)).into(),
+            text(mem_pct).size(density.text_size(11)).font(mono_font).color(label_c).width(cols.get(ProcessColumn::MemPct)).into(),
+            text(disk).size(density.text_size(11)).font(mono_font).color(label_c).width(cols.get(ProcessColumn::DiskIo)).into(),
+        ])
+        .push_maybe(layout.show_gpu().then(|| {
+            let gpu = layout.gpu.get(&pid).map_or_else(|| String::from("-"), |&util| locale::percent(util, 0));
+            text(gpu).size(density.text_size(11)).font(mono_font).color(label_c).width(cols.get(ProcessColumn::Gpu))
+        }))
+        .extend([
+            text(String::from(proc.status)).size(density.text_size(11)).font(mono_font).color(match proc.status {
                 'R' => p.green,
                 'Z' => p.red,
                 'D' => p.yellow,
                 _ => label_c,
-            }).width(25),
-            text(proc.thread_count.to_string()).size(11).font(mono_font).color(label_c).width(40),
-            kill_btn,
-        ]
+            }).width(cols.get(ProcessColumn::Status)).into(),
+            threads_cell,
+            pin_btn,
+        ])
+        .push_maybe((!layout.read_only).then_some(kill_btn))
         .spacing(2)
         .align_y(Alignment::Center),
     )
-    .padding([2, 10])
+    .padding([density.pad_y(2), 10])
     .style(move |_: &Theme| container::Style {
         background: Some(Background::Color(row_bg)),
         ..Default::default()
-    })
-    .into()
+    });
+
+    mouse_area(row).on_press(Message::SelectProcess(pid)).into()
+}
+
+/// Executable, working directory and output targets of the selected
+/// process, under its row and aligned with the name column.
+fn process_details_row<'a>(
+    details: &crate::procdetail::ProcessDetails,
+    p: &'a Palette,
+    layout: &TableLayout,
+    t: &Strings,
+) -> Element<'a, Message> {
+    use crate::procdetail::OutputTarget;
+
+    let density = layout.density;
+    let cols = &layout.columns;
+    let accent = p.accent;
+    let path = |path: &Option<std::path::PathBuf>| {
+        path.as_ref().map_or_else(|| t.unavailable.to_string(), |p| p.display().to_string())
+    };
+    let output = |target: &Option<OutputTarget>| match target {
+        None => t.unavailable.to_string(),
+        Some(OutputTarget::Terminal(device)) => fill(t.output_terminal, &[("device", &device.display().to_string())]),
+        Some(OutputTarget::Journal) => t.output_journal.to_string(),
+        Some(OutputTarget::File(path)) => path.display().to_string(),
+        Some(OutputTarget::Pipe) => t.output_pipe.to_string(),
+        Some(OutputTarget::Socket) => t.output_socket.to_string(),
+        Some(OutputTarget::Discarded) => t.output_discarded.to_string(),
+        Some(OutputTarget::Other(name)) => name.clone(),
+    };
+    let mut lines = vec![
+        (t.proc_executable, path(&details.exe)),
+        (t.proc_cwd, path(&details.cwd)),
+        (t.proc_stdout, output(&details.stdout)),
+        (t.proc_stderr, output(&details.stderr)),
+    ];
+    let (listening, connected): (Vec<_>, Vec<_>) = details.sockets.iter().partition(|s| s.listening());
+    let mut ports: Vec<String> = listening.iter().map(|s| format!("{} {}", s.protocol.name(), s.local)).collect();
+    ports.dedup();
+    lines.push((t.proc_listening, if ports.is_empty() { t.proc_no_sockets.to_string() } else { ports.join(", ") }));
+    if connected.is_empty() {
+        lines.push((t.proc_connections, t.proc_no_sockets.to_string()));
+    }
+    for (i, s) in connected.iter().take(MAX_DETAIL_CONNECTIONS).enumerate() {
+        let remote = s.remote.map(|r| r.to_string()).unwrap_or_default();
+        let label = if i == 0 { t.proc_connections } else { "" };
+        lines.push((label, format!("{} {} {ICON_ARROW_RIGHT} {remote} {}", s.protocol.name(), s.local, s.state).trim_end().to_string()));
+    }
+    if connected.len() > MAX_DETAIL_CONNECTIONS {
+        let more = (connected.len() - MAX_DETAIL_CONNECTIONS).to_string();
+        lines.push(("", fill(t.proc_more_sockets, &[("count", &more)])));
+    }
+    let content = Column::with_children(lines.into_iter().map(|(label, value)| {
+        let font = font_for_text(&value, NERD_FONT);
+        row![
+            text(label).size(density.text_size(10)).color(p.label).width(140),
+            text(value).size(density.text_size(10)).font(font).color(p.text),
+        ]
+        .spacing(8)
+        .into()
+    }))
+    .spacing(2);
+    container(row![Space::with_width(cols.get(ProcessColumn::Pid) + cols.get(ProcessColumn::Ppid) + 4.0), content])
+        .padding([density.pad_y(4), 10])
+        .width(layout.width())
+        .style(move |_: &Theme| container::Style {
+            background: Some(Background::Color(Color::from_rgba(accent.r, accent.g, accent.b, 0.08))),
+            ..Default::default()
+        })
+        .into()
+}
+
+/// Indented rows for the threads of an expanded process: TID in the PID
+/// column, then name, CPU and state.
+fn thread_rows<'a>(
+    threads: Option<&Vec<crate::threads::ThreadInfo>>,
+    bg: Color,
+    p: &'a Palette,
+    layout: &TableLayout,
+    t: &Strings,
+    mono_font: iced::Font,
+) -> Vec<Element<'a, Message>> {
+    let density = layout.density;
+    let cols = &layout.columns;
+    let label_c = p.label;
+    let row_bg = Color { a: bg.a * 0.6, ..bg };
+    let wrap = move |content: Element<'a, Message>| -> Element<'a, Message> {
+        container(content)
+            .padding([density.pad_y(1), 10])
+            .style(move |_: &Theme| container::Style {
+                background: Some(Background::Color(row_bg)),
+                ..Default::default()
+            })
+            .into()
+    };
+    let Some(threads) = threads else {
+        let note = if cfg!(target_os = "linux") { t.threads_loading } else { t.threads_unavailable };
+        return vec![wrap(
+            row![
+                Space::with_width(cols.get(ProcessColumn::Pid) + cols.get(ProcessColumn::Ppid) + 2.0),
+                text(note).size(density.text_size(10)).color(label_c),
+            ]
+            .spacing(2)
+            .into(),
+        )];
+    };
+    threads
+        .iter()
+        .map(|thread| {
+            let cpu_pct = thread.cpu_usage * layout.cpu_scale;
+            let name_font = font_for_text(&thread.name, NERD_FONT);
+            let mut cells: Vec<Element<Message>> = vec![
+                text(thread.tid.to_string()).size(density.text_size(10)).font(mono_font).color(label_c).width(cols.get(ProcessColumn::Pid)).into(),
+                Space::with_width(cols.get(ProcessColumn::Ppid)).into(),
+                text(format!("\u{2514} {}", thread.name)).size(density.text_size(10)).font(name_font).color(label_c).width(cols.get(ProcessColumn::Name)).into(),
+            ];
+            if layout.show_command {
+                cells.push(Space::with_width(cols.get(ProcessColumn::Command)).into());
+            }
+            cells.extend([
+                text(locale::percent(cpu_pct, 1)).size(density.text_size(10)).font(mono_font).color(gradient_color(cpu_pct / 100.0, p)).width(cols.get(ProcessColumn::Cpu)).into(),
+                Space::with_width(cols.get(ProcessColumn::Memory) + cols.get(ProcessColumn::MemPct) + cols.get(ProcessColumn::DiskIo) + 4.0).into(),
+            ]);
+            if layout.show_gpu() {
+                cells.push(Space::with_width(cols.get(ProcessColumn::Gpu)).into());
+            }
+            cells.extend([
+                text(String::from(thread.status)).size(density.text_size(10)).font(mono_font).color(label_c).width(cols.get(ProcessColumn::Status)).into(),
+            ]);
+            wrap(Row::with_children(cells).spacing(2).align_y(Alignment::Center).into())
+        })
+        .collect()
 }
 
 fn panel<'a>(content: Element<'a, Message>, p: &Palette) -> Element<'a, Message> {
@@ -3329,35 +8479,91 @@ fn labeled_bar(
     .into()
 }
 
-fn sort_btn(label: String, col: ProcessSort, width: u16, accent: Color) -> Element<'static, Message> {
+/// Approximate advance of a size-10 monospace glyph, for eliding command lines.
+const COMMAND_CHAR_WIDTH: f32 = 6.0;
+
+/// Process table header cell of `width` with a drag handle on its right edge.
+fn header_cell(column: ProcessColumn, width: f32, content: Element<'static, Message>, border_c: Color) -> Element<'static, Message> {
+    let handle = mouse_area(
+        container(
+            container(Space::new(1, 14)).style(move |_: &Theme| container::Style {
+                background: Some(Background::Color(border_c)),
+                ..Default::default()
+            }),
+        )
+        .center_x(ColumnWidths::DIVIDER),
+    )
+    .on_press(Message::ColumnResizeStart(column))
+    .interaction(iced::mouse::Interaction::ResizingHorizontally);
+    row![
+        container(content).width(width - ColumnWidths::DIVIDER),
+        handle,
+    ]
+    .align_y(Alignment::Center)
+    .width(width)
+    .into()
+}
+
+fn sort_btn(label: String, msg: Message, width: impl Into<Length>, accent: Color) -> Element<'static, Message> {
     button(text(label).size(11).color(accent))
-        .on_press(Message::SortBy(col))
+        .on_press(msg)
         .style(button::text)
         .padding([2, 4])
         .width(width)
         .into()
 }
 
-fn format_bytes(bytes: u64) -> String {
+pub(crate) fn format_bytes(bytes: u64) -> String {
+    let (value, decimals, unit) = byte_scale(bytes);
+    locale::with_unit(value, decimals, unit)
+}
+
+/// `bytes` in the largest binary unit it reaches, with the decimals to
+/// show and the unit suffix.
+pub(crate) fn byte_scale(bytes: u64) -> (f64, usize, &'static str) {
     const KB: u64 = 1024;
     const MB: u64 = KB * 1024;
     const GB: u64 = MB * 1024;
     const TB: u64 = GB * 1024;
 
     if bytes >= TB {
-        format!("{:.1} TiB", bytes as f64 / TB as f64)
+        (bytes as f64 / TB as f64, 1, " TiB")
     } else if bytes >= GB {
-        format!("{:.1} GiB", bytes as f64 / GB as f64)
+        (bytes as f64 / GB as f64, 1, " GiB")
     } else if bytes >= MB {
-        format!("{:.1} MiB", bytes as f64 / MB as f64)
+        (bytes as f64 / MB as f64, 1, " MiB")
     } else if bytes >= KB {
-        format!("{:.1} KiB", bytes as f64 / KB as f64)
+        (bytes as f64 / KB as f64, 1, " KiB")
     } else {
-        format!("{bytes} B")
+        (bytes as f64, 0, " B")
+    }
+}
+
+/// Process memory in a fixed unit, or `format_bytes` for `MemUnit::Auto`.
+fn format_memory(bytes: u64, unit: MemUnit) -> String {
+    match unit {
+        MemUnit::Auto => format_bytes(bytes),
+        MemUnit::MiB => locale::with_unit(bytes as f64 / (1024.0 * 1024.0), 1, " MiB"),
+        MemUnit::GiB => locale::with_unit(bytes as f64 / (1024.0 * 1024.0 * 1024.0), 2, " GiB"),
     }
 }
 
-fn format_duration(secs: u64) -> String {
+/// Share of total memory used by `bytes`, in percent.
+fn mem_percent(bytes: u64, total: u64) -> f32 {
+    if total == 0 { 0.0 } else { (bytes as f64 / total as f64 * 100.0) as f32 }
+}
+
+/// Scale rx/tx series given in bytes/s to the preferred rate unit.
+/// Returns the scaled series, the unit suffix and the y-axis maximum.
+pub(crate) fn scale_rates(rx: Vec<f32>, tx: Vec<f32>) -> (Vec<f32>, Vec<f32>, &'static str, f32) {
+    let max = rx.iter().chain(tx.iter()).cloned().fold(0.0f32, f32::max);
+    let (div, unit) = locale::rate_units().scale(max as f64);
+    let div = div as f32;
+    let scale = |v: Vec<f32>| v.into_iter().map(|x| x / div).collect::<Vec<_>>();
+    (scale(rx), scale(tx), unit, (max / div).max(0.001))
+}
+
+pub(crate) fn format_duration(secs: u64) -> String {
     let days = secs / 86400;
     let hours = (secs % 86400) / 3600;
     let mins = (secs % 3600) / 60;
@@ -3371,6 +8577,12 @@ fn format_duration(secs: u64) -> String {
 }
 
 fn downsample(data: &[f32], max_points: usize) -> Vec<f32> {
+    downsample_with(data, max_points, f32::max)
+}
+
+/// Shrink `data` to at most `max_points` buckets, folding each bucket
+/// with `pick` (`f32::max` keeps peaks, `f32::min` troughs).
+fn downsample_with(data: &[f32], max_points: usize, pick: fn(f32, f32) -> f32) -> Vec<f32> {
     let n = data.len();
     if n <= max_points {
         return data.to_vec();
@@ -3380,18 +8592,59 @@ fn downsample(data: &[f32], max_points: usize) -> Vec<f32> {
     for i in 0..max_points {
         let start = (i as f64 * bucket_size) as usize;
         let end = (((i + 1) as f64 * bucket_size) as usize).min(n);
-        let peak = data[start..end]
-            .iter()
-            .cloned()
-            .fold(f32::NEG_INFINITY, f32::max);
-        out.push(peak);
+        out.push(data[start..end].iter().copied().reduce(pick).unwrap_or_default());
     }
     out
 }
 
+/// Min/max band of History points for a chart, downsampled like its series.
+fn history_band(
+    points: &[HistoryPoint],
+    color: Color,
+    max_points: usize,
+    extremes: impl Fn(&HistoryPoint) -> (f32, f32),
+) -> (Color, Vec<f32>, Vec<f32>) {
+    let (low, high): (Vec<f32>, Vec<f32>) = points.iter().map(extremes).unzip();
+    (color, downsample_with(&low, max_points, f32::min), downsample_with(&high, max_points, f32::max))
+}
+
+/// A settings row: title and description on the left, the control on
+/// the right.
+fn setting_row<'a>(
+    title: impl text::IntoFragment<'a>,
+    desc: impl text::IntoFragment<'a>,
+    control: impl Into<Element<'a, Message>>,
+    p: &Palette,
+    mono_font: iced::Font,
+) -> Row<'a, Message> {
+    row![
+        column![
+            text(title).size(12).font(mono_font).color(p.text),
+            text(desc).size(10).font(mono_font).color(p.label),
+        ].spacing(2).width(Length::FillPortion(2)),
+        control.into(),
+    ]
+    .align_y(Alignment::Center)
+    .spacing(12)
+}
+
+/// On/off switch used by boolean settings.
+fn toggle_button(on: bool, msg: Message, p: &Palette) -> Element<'static, Message> {
+    button(
+        text(if on { ICON_TOGGLE_ON } else { ICON_TOGGLE_OFF })
+            .size(22)
+            .color(if on { p.accent } else { p.label })
+    )
+    .on_press(msg)
+    .style(button::text)
+    .padding(0)
+    .into()
+}
+
 fn make_threshold_buttons<'a>(
     current: f32,
     options: &[f32],
+    label: impl Fn(f32) -> String,
     on_press: impl Fn(f32) -> Message + 'a,
     accent: Color,
     label_c: Color,
@@ -3402,7 +8655,7 @@ fn make_threshold_buttons<'a>(
         let is_active = (current - val).abs() < 0.5;
         let color = if is_active { accent } else { label_c };
         let btn = button(
-            text(format!("{:.0}%", val)).size(11).font(mono_font).color(color)
+            text(label(val)).size(11).font(mono_font).color(color)
         )
         .on_press(on_press(val))
         .style(if is_active { button::primary } else { button::secondary })
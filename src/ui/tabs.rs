@@ -0,0 +1,205 @@
+//! View state of the heavier tabs.
+//!
+//! Each tab owns its state for the whole session, so switching away and back
+//! (or opening Settings on top) keeps filters, scroll positions and loaded
+//! data. `on_show` runs whenever a tab becomes visible and only does work
+//! that is due: restoring scroll offsets the rebuilt widgets have lost, or
+//! reloading data older than its refresh interval.
+
+use std::collections::HashSet;
+
+use iced::widget::scrollable;
+use iced::Task;
+
+use super::{
+    process_header_scroll_id, process_scroll_id, ColumnResize, ColumnWidths, HistoryChart, Message, ProcessGroup,
+    ProcessSort, HEATMAP_RANGE_SECS, HEATMAP_RELOAD_INTERVAL_SECS, HISTORY_FOLLOW_MAX_SECS, HISTORY_RANGES,
+    HISTORY_RELOAD_INTERVAL_SECS,
+};
+use crate::histogram::Distribution;
+use crate::history::{History, HistoryMetric, HistoryPoint, StoredEvent, WeeklyGrid};
+use crate::locale;
+use crate::preferences::Preferences;
+
+/// Processes tab: filter, sort, grouping, layout and scroll position.
+pub(super) struct ProcessesTab {
+    pub filter: String,
+    pub sort: ProcessSort,
+    pub sort_asc: bool,
+    pub grouped: bool,
+    pub collapsed_groups: HashSet<ProcessGroup>,
+    /// Process names shown above the others whatever the sort.
+    pub pinned: HashSet<String>,
+    /// Last scroll offset of the rows, restored when the tab reopens.
+    pub scroll_x: f32,
+    pub scroll_y: f32,
+    /// Height of the visible rows, to keep the keyboard selection in view.
+    pub viewport_h: f32,
+    pub columns: ColumnWidths,
+    pub show_command: bool,
+    pub column_resize: Option<ColumnResize>,
+    /// Processes whose threads are listed under their row.
+    pub expanded_threads: HashSet<u32>,
+}
+
+impl ProcessesTab {
+    pub fn from_prefs(prefs: &Preferences) -> Self {
+        Self {
+            filter: prefs.process_filter.clone(),
+            sort: match prefs.process_sort.as_str() {
+                "pid" => ProcessSort::Pid,
+                "name" => ProcessSort::Name,
+                "memory" => ProcessSort::Memory,
+                "disk_io" => ProcessSort::DiskIo,
+                "threads" => ProcessSort::Threads,
+                _ => ProcessSort::Cpu,
+            },
+            sort_asc: prefs.process_sort_asc,
+            grouped: prefs.process_grouped,
+            collapsed_groups: ProcessGroup::ALL
+                .into_iter()
+                .filter(|g| prefs.collapsed_process_groups.iter().any(|k| k == g.key()))
+                .collect(),
+            pinned: prefs.pinned_processes.iter().cloned().collect(),
+            scroll_x: 0.0,
+            scroll_y: prefs.process_scroll_y,
+            viewport_h: 0.0,
+            columns: ColumnWidths::from_prefs(&prefs.process_column_widths),
+            show_command: prefs.show_command_column,
+            column_resize: None,
+            expanded_threads: HashSet::new(),
+        }
+    }
+
+    pub fn sort_key(&self) -> &'static str {
+        match self.sort {
+            ProcessSort::Pid => "pid",
+            ProcessSort::Name => "name",
+            ProcessSort::Cpu => "cpu",
+            ProcessSort::Memory => "memory",
+            ProcessSort::DiskIo => "disk_io",
+            ProcessSort::Threads => "threads",
+        }
+    }
+
+    /// The scrollables are rebuilt whenever the tab is hidden; put the rows
+    /// and the header back where they were.
+    pub fn on_show(&self) -> Task<Message> {
+        let x = self.scroll_x;
+        Task::batch([
+            scrollable::scroll_to(process_scroll_id(), scrollable::AbsoluteOffset { x, y: self.scroll_y }),
+            scrollable::scroll_to(process_header_scroll_id(), scrollable::AbsoluteOffset { x, y: 0.0 }),
+        ])
+    }
+}
+
+/// History tab: selected range and the data loaded for it. Nothing is
+/// queried until the tab is first shown.
+pub(super) struct HistoryTab {
+    pub range_idx: usize,
+    /// A fixed window picked by the user, shown instead of the preset range.
+    pub custom: Option<(f64, f64)>,
+    /// Contents of the custom range inputs.
+    pub from_input: String,
+    pub to_input: String,
+    /// Why the typed range was rejected.
+    pub range_error: Option<&'static str>,
+    /// Reload whenever new samples are written rather than on the reload
+    /// interval; only for short preset ranges.
+    pub follow: bool,
+    /// Newest written sample when following last reloaded.
+    followed_until: f64,
+    /// Charts showing the min/max band of each downsampled point.
+    pub band_charts: HashSet<HistoryChart>,
+    pub points: Vec<HistoryPoint>,
+    /// Warnings and critical events logged within the range.
+    pub events: Vec<StoredEvent>,
+    pub histogram_metric: HistoryMetric,
+    pub histogram: Distribution,
+    pub heatmap: WeeklyGrid,
+    /// When the charts and heatmap were last loaded; `None` until first shown.
+    last_reload: Option<f64>,
+    heatmap_last_reload: Option<f64>,
+}
+
+impl HistoryTab {
+    pub fn new() -> Self {
+        Self {
+            range_idx: 0,
+            custom: None,
+            from_input: String::new(),
+            to_input: String::new(),
+            range_error: None,
+            follow: false,
+            followed_until: 0.0,
+            band_charts: HashSet::new(),
+            points: Vec::new(),
+            events: Vec::new(),
+            histogram_metric: HistoryMetric::Cpu,
+            histogram: Distribution::default(),
+            heatmap: [[None; 24]; 7],
+            last_reload: None,
+            heatmap_last_reload: None,
+        }
+    }
+
+    pub fn on_show(&mut self, history: &History, now: f64) {
+        self.refresh(history, now);
+    }
+
+    /// The window shown: the custom one, or the preset range ending now.
+    pub fn window(&self, now: f64) -> (f64, f64) {
+        self.custom.unwrap_or_else(|| (now - HISTORY_RANGES[self.range_idx].0, now))
+    }
+
+    /// Whether the charts follow new samples: follow mode is on and a short
+    /// preset range is shown.
+    pub fn follows(&self) -> bool {
+        self.follow && self.custom.is_none() && HISTORY_RANGES[self.range_idx].0 <= HISTORY_FOLLOW_MAX_SECS
+    }
+
+    /// When following, reload as soon as the writer has stored samples
+    /// newer than the charts show, keeping the right edge at now.
+    pub fn catch_up(&mut self, history: &History, now: f64, written_until: f64) {
+        if self.follows() && written_until > self.followed_until {
+            self.followed_until = written_until;
+            self.reload(history, now);
+        }
+    }
+
+    /// Show a fixed window instead of the preset range.
+    pub fn set_custom(&mut self, history: &History, window: (f64, f64), now: f64) {
+        self.custom = Some(window);
+        self.from_input = locale::date_time(window.0);
+        self.to_input = locale::date_time(window.1);
+        self.range_error = None;
+        self.reload(history, now);
+    }
+
+    /// Reload whatever is older than its interval. Called on show and on
+    /// every tick while visible. A custom window is loaded once.
+    pub fn refresh(&mut self, history: &History, now: f64) {
+        if self.last_reload.is_none_or(|t| self.custom.is_none() && now - t >= HISTORY_RELOAD_INTERVAL_SECS) {
+            self.reload(history, now);
+        }
+        // The heatmap aggregates a week of rows, so it reloads slowly
+        if self.heatmap_last_reload.is_none_or(|t| now - t >= HEATMAP_RELOAD_INTERVAL_SECS) {
+            self.heatmap_last_reload = Some(now);
+            self.heatmap = history.cpu_by_weekday_hour(now - HEATMAP_RANGE_SECS);
+        }
+    }
+
+    /// Load the charts and the distribution for the selected range.
+    pub fn reload(&mut self, history: &History, now: f64) {
+        self.last_reload = Some(now);
+        let (from, to) = self.window(now);
+        self.points = history.load_range_downsampled(from, to, 600);
+        self.events = history.events(from, to);
+        let values = history.values(self.histogram_metric, from, to);
+        let max = match self.histogram_metric {
+            HistoryMetric::Cpu | HistoryMetric::Memory => Some(100.0),
+            HistoryMetric::Power => None,
+        };
+        self.histogram = Distribution::new(values, max);
+    }
+}
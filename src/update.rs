@@ -0,0 +1,183 @@
+//! Release check against GitHub.
+//!
+//! Asks the GitHub releases API for the latest release through `curl`
+//! (shipped with Linux distributions, macOS and Windows 10+) on a
+//! background thread and compares its tag with the running version.
+//! Nothing is downloaded; the UI only links to the release page.
+
+use std::process::Command;
+use std::sync::{Arc, Mutex};
+
+use serde_json::Value;
+
+/// Manual checks aside, the background check runs at most this often.
+pub const CHECK_INTERVAL_SECS: f64 = 7.0 * 86400.0;
+
+/// Public repository the releases are published to. The crate's
+/// `repository` is the private source tree, which the API hides from
+/// unauthenticated requests.
+const RELEASES_REPO: &str = "Project-Colony/Digger";
+
+/// The latest published release.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Release {
+    /// Version without the leading `v` of the tag.
+    pub version: String,
+    /// Release page with the changelog.
+    pub url: String,
+}
+
+/// Outcome of a finished check.
+#[derive(Clone, Debug, PartialEq)]
+pub enum UpdateStatus {
+    UpToDate,
+    Available(Release),
+    Failed(String),
+}
+
+/// Handle to a running check; the UI polls `result()` on each tick.
+pub struct UpdateCheck {
+    shared: Arc<Mutex<Option<UpdateStatus>>>,
+    /// Started by the weekly schedule rather than the button.
+    pub background: bool,
+}
+
+impl UpdateCheck {
+    pub fn start(background: bool) -> Self {
+        let shared = Arc::new(Mutex::new(None));
+        let thread_shared = Arc::clone(&shared);
+        std::thread::spawn(move || {
+            let status = match fetch_latest() {
+                Ok(Some(release)) if is_newer(&release.version, env!("CARGO_PKG_VERSION")) => UpdateStatus::Available(release),
+                Ok(_) => UpdateStatus::UpToDate,
+                Err(e) => UpdateStatus::Failed(e),
+            };
+            if let Ok(mut slot) = thread_shared.lock() {
+                *slot = Some(status);
+            }
+        });
+        Self { shared, background }
+    }
+
+    /// The outcome once the check has finished.
+    pub fn result(&self) -> Option<UpdateStatus> {
+        self.shared.lock().ok().and_then(|slot| slot.clone())
+    }
+}
+
+fn latest_release_api() -> String {
+    format!("https://api.github.com/repos/{RELEASES_REPO}/releases/latest")
+}
+
+/// The latest release, or `None` when nothing has been published yet.
+fn fetch_latest() -> Result<Option<Release>, String> {
+    let mut cmd = Command::new("curl");
+    cmd.args(["--silent", "--show-error", "--location", "--max-time", "15"])
+        .args(["--write-out", "\n%{http_code}"])
+        .args(["--header", "Accept: application/vnd.github+json"])
+        .args(["--user-agent", concat!("digger/", env!("CARGO_PKG_VERSION"))])
+        .arg(latest_release_api());
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        // CREATE_NO_WINDOW: don't flash a console
+        cmd.creation_flags(0x0800_0000);
+    }
+    let output = cmd.output().map_err(|e| format!("curl: {e}"))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(stderr.trim().trim_start_matches("curl: ").to_string());
+    }
+    parse_response(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Body of a `releases/latest` response followed by its HTTP status on
+/// the last line. GitHub answers 404 when no release has been published.
+fn parse_response(output: &str) -> Result<Option<Release>, String> {
+    let (body, code) = output.rsplit_once('\n').unwrap_or(("", output));
+    match code.trim() {
+        "404" => Ok(None),
+        code if code.starts_with('2') => parse_release(body).map(Some).ok_or_else(|| "unexpected response".to_string()),
+        code => {
+            let message = serde_json::from_str::<Value>(body)
+                .ok()
+                .and_then(|v| v.get("message")?.as_str().map(str::to_string));
+            Err(match message {
+                Some(message) => format!("HTTP {code}: {message}"),
+                None => format!("HTTP {code}"),
+            })
+        }
+    }
+}
+
+fn parse_release(json: &str) -> Option<Release> {
+    let value: Value = serde_json::from_str(json).ok()?;
+    let tag = value.get("tag_name")?.as_str()?;
+    Some(Release {
+        version: tag.trim_start_matches(['v', 'V']).to_string(),
+        url: value.get("html_url")?.as_str()?.to_string(),
+    })
+}
+
+/// Whether `latest` is a higher `major.minor.patch` than `current`.
+/// Pre-release suffixes (`-beta.1`) are ignored.
+fn is_newer(latest: &str, current: &str) -> bool {
+    fn parts(v: &str) -> Vec<u64> {
+        v.split(['-', '+'])
+            .next()
+            .unwrap_or_default()
+            .split('.')
+            .map(|p| p.parse().unwrap_or(0))
+            .collect()
+    }
+    let (mut latest, mut current) = (parts(latest), parts(current));
+    let len = latest.len().max(current.len());
+    latest.resize(len, 0);
+    current.resize(len, 0);
+    latest > current
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_newer() {
+        assert!(is_newer("0.2.0", "0.1.0"));
+        assert!(is_newer("0.1.10", "0.1.9"));
+        assert!(is_newer("1.0", "0.9.9"));
+        assert!(!is_newer("0.1.0", "0.1.0"));
+        assert!(!is_newer("0.1", "0.1.0"));
+        assert!(!is_newer("0.0.9", "0.1.0"));
+        assert!(!is_newer("0.1.0-beta.2", "0.1.0"));
+    }
+
+    #[test]
+    fn test_parse_release() {
+        let json = r#"{"tag_name": "v0.3.1", "html_url": "https://github.com/o/r/releases/tag/v0.3.1", "assets": []}"#;
+        assert_eq!(
+            parse_release(json),
+            Some(Release { version: "0.3.1".into(), url: "https://github.com/o/r/releases/tag/v0.3.1".into() })
+        );
+        assert_eq!(parse_release(r#"{"message": "Not Found"}"#), None);
+        assert_eq!(parse_release("<html>"), None);
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let body = r#"{"tag_name": "v0.3.1", "html_url": "https://github.com/o/r/releases/tag/v0.3.1"}"#;
+        assert_eq!(parse_response(&format!("{body}\n200")).unwrap().unwrap().version, "0.3.1");
+        assert_eq!(parse_response("{\"message\": \"Not Found\"}\n404"), Ok(None));
+        assert_eq!(
+            parse_response("{\"message\": \"API rate limit exceeded\"}\n403"),
+            Err("HTTP 403: API rate limit exceeded".to_string())
+        );
+        assert_eq!(parse_response("<html>\n200"), Err("unexpected response".to_string()));
+    }
+
+    #[test]
+    fn test_latest_release_api() {
+        assert!(latest_release_api().starts_with("https://api.github.com/repos/"));
+        assert!(latest_release_api().ends_with("/releases/latest"));
+    }
+}
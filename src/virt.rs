@@ -0,0 +1,164 @@
+//! Whether Digger runs inside a virtual machine, and under which hypervisor.
+//!
+//! On a cloud VM the host can hand the vCPUs to other guests, which shows up
+//! as steal time and otherwise unexplained slowness; naming the hypervisor
+//! next to the steal figure makes that easy to spot. x86 asks CPUID for the
+//! hypervisor signature, skipping the Hyper-V root partition (a Windows host
+//! with Hyper-V or VBS enabled). Linux falls back on the DMI vendor and
+//! `/sys/hypervisor` (ARM guests, Xen PV), macOS on `kern.hv_vmm_present`.
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Hypervisor {
+    Kvm,
+    /// QEMU without acceleration (TCG).
+    Qemu,
+    Vmware,
+    HyperV,
+    Xen,
+    VirtualBox,
+    Parallels,
+    Bhyve,
+    /// A guest whose hypervisor doesn't say who it is, or an unknown
+    /// CPUID signature.
+    Other(String),
+}
+
+impl Hypervisor {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Kvm => "KVM",
+            Self::Qemu => "QEMU",
+            Self::Vmware => "VMware",
+            Self::HyperV => "Hyper-V",
+            Self::Xen => "Xen",
+            Self::VirtualBox => "VirtualBox",
+            Self::Parallels => "Parallels",
+            Self::Bhyve => "bhyve",
+            Self::Other(name) => name,
+        }
+    }
+}
+
+/// The hypervisor Digger runs under, `None` on bare metal or when nothing
+/// gives it away. Cheap enough to call once per collector.
+pub fn detect() -> Option<Hypervisor> {
+    from_cpuid().or_else(from_platform)
+}
+
+/// The 12-byte vendor signature of CPUID leaf 0x40000000.
+fn from_signature(signature: &str) -> Option<Hypervisor> {
+    let signature = signature.trim_matches(|c: char| c == '\0' || c.is_whitespace());
+    Some(match signature {
+        "" => return None,
+        "KVMKVMKVM" | "Linux KVM Hv" => Hypervisor::Kvm,
+        "TCGTCGTCGTCG" => Hypervisor::Qemu,
+        "VMwareVMware" => Hypervisor::Vmware,
+        "Microsoft Hv" => Hypervisor::HyperV,
+        "XenVMMXenVMM" => Hypervisor::Xen,
+        "VBoxVBoxVBox" => Hypervisor::VirtualBox,
+        "prl hyperv" | "lrpepyh  vr" => Hypervisor::Parallels,
+        "bhyve bhyve" => Hypervisor::Bhyve,
+        other => Hypervisor::Other(other.to_string()),
+    })
+}
+
+/// DMI system vendor and product name, as found in `/sys/class/dmi/id`.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn from_dmi(vendor: &str, product: &str) -> Option<Hypervisor> {
+    let vendor = vendor.trim();
+    let product = product.trim();
+    Some(match vendor {
+        "QEMU" | "Red Hat" | "oVirt" | "Nutanix" => Hypervisor::Kvm,
+        "VMware, Inc." => Hypervisor::Vmware,
+        "innotek GmbH" | "Oracle Corporation" if product == "VirtualBox" => Hypervisor::VirtualBox,
+        "Xen" => Hypervisor::Xen,
+        "Parallels Software International Inc." | "Parallels International GmbH." => Hypervisor::Parallels,
+        "BHYVE" => Hypervisor::Bhyve,
+        "Microsoft Corporation" if product == "Virtual Machine" => Hypervisor::HyperV,
+        "Amazon EC2" if !product.is_empty() && !product.ends_with(".metal") => Hypervisor::Kvm,
+        "Google" if product == "Google Compute Engine" => Hypervisor::Kvm,
+        _ if product == "KVM" => Hypervisor::Kvm,
+        _ => return None,
+    })
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn from_cpuid() -> Option<Hypervisor> {
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::__cpuid;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::__cpuid;
+
+    // ECX bit 31 is reserved for guests and always clear on bare metal
+    if __cpuid(1).ecx & (1 << 31) == 0 {
+        return None;
+    }
+    let vendor = __cpuid(0x4000_0000);
+    let bytes: Vec<u8> = [vendor.ebx, vendor.ecx, vendor.edx].iter().flat_map(|r| r.to_le_bytes()).collect();
+    let hypervisor = from_signature(&String::from_utf8_lossy(&bytes))?;
+    // The root partition sees Hyper-V too, but may create partitions
+    // (EBX bit 0 of the feature leaf) where a guest may not
+    if hypervisor == Hypervisor::HyperV && vendor.eax >= 0x4000_0003 && __cpuid(0x4000_0003).ebx & 1 != 0 {
+        return None;
+    }
+    Some(hypervisor)
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+fn from_cpuid() -> Option<Hypervisor> {
+    None
+}
+
+#[cfg(target_os = "linux")]
+fn from_platform() -> Option<Hypervisor> {
+    let dmi = |field: &str| std::fs::read_to_string(format!("/sys/class/dmi/id/{field}")).unwrap_or_default();
+    from_dmi(&dmi("sys_vendor"), &dmi("product_name")).or_else(|| {
+        // Paravirtualized Xen guests have no DMI tables at all
+        let kind = std::fs::read_to_string("/sys/hypervisor/type").ok()?;
+        (kind.trim() == "xen").then_some(Hypervisor::Xen)
+    })
+}
+
+#[cfg(target_os = "macos")]
+fn from_platform() -> Option<Hypervisor> {
+    let mut present: libc::c_int = 0;
+    let mut len = std::mem::size_of::<libc::c_int>();
+    // SAFETY: the name is NUL-terminated and `len` matches the buffer
+    let rc = unsafe {
+        let out = &mut present as *mut libc::c_int as *mut libc::c_void;
+        libc::sysctlbyname(c"kern.hv_vmm_present".as_ptr(), out, &mut len, std::ptr::null_mut(), 0)
+    };
+    // Apple Silicon guests all run on Hypervisor.framework, whatever the app
+    (rc == 0 && present == 1).then(|| Hypervisor::Other("Apple Hypervisor".into()))
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn from_platform() -> Option<Hypervisor> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_signature() {
+        assert_eq!(from_signature("KVMKVMKVM\0\0\0"), Some(Hypervisor::Kvm));
+        assert_eq!(from_signature("Microsoft Hv"), Some(Hypervisor::HyperV));
+        assert_eq!(from_signature("VMwareVMware"), Some(Hypervisor::Vmware));
+        assert_eq!(from_signature("ACRNACRNACRN"), Some(Hypervisor::Other("ACRNACRNACRN".into())));
+        assert_eq!(from_signature("\0\0\0\0\0\0\0\0\0\0\0\0"), None);
+    }
+
+    #[test]
+    fn test_from_dmi() {
+        assert_eq!(from_dmi("QEMU\n", "Standard PC (Q35 + ICH9, 2009)\n"), Some(Hypervisor::Kvm));
+        assert_eq!(from_dmi("innotek GmbH\n", "VirtualBox\n"), Some(Hypervisor::VirtualBox));
+        assert_eq!(from_dmi("Microsoft Corporation", "Virtual Machine"), Some(Hypervisor::HyperV));
+        assert_eq!(from_dmi("Amazon EC2", "m5.large"), Some(Hypervisor::Kvm));
+        // Bare metal, including EC2's
+        assert_eq!(from_dmi("Amazon EC2", "m5.metal"), None);
+        assert_eq!(from_dmi("Microsoft Corporation", "Surface Laptop 5"), None);
+        assert_eq!(from_dmi("LENOVO", "21CB"), None);
+    }
+}
@@ -0,0 +1,203 @@
+//! Page fault rates and hugepage usage for the Memory panel (Linux).
+//!
+//! A workload that keeps faulting, especially major faults that wait on the
+//! disk, is memory-bound whatever the utilization says. System-wide counts
+//! come from `/proc/vmstat`, those of one watched process (the selected one)
+//! from `/proc/<pid>/stat`, hugepages from `/proc/meminfo`. Other platforms
+//! report nothing.
+
+use std::time::Instant;
+
+/// Faults per second since the previous sample.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FaultRates {
+    /// Served from memory: first touch, copy-on-write, page cache hits.
+    pub minor: f32,
+    /// Had to wait for the page to be read from disk or swap.
+    pub major: f32,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct HugePages {
+    /// Preallocated pages of the default size.
+    pub total: u64,
+    pub free: u64,
+    /// Promised to a mapping but not yet faulted in; counted as free.
+    pub reserved: u64,
+    pub page_size: u64,
+    /// Transparent hugepages backing anonymous memory, in bytes.
+    pub transparent: u64,
+}
+
+impl HugePages {
+    pub fn used_bytes(&self) -> u64 {
+        self.total.saturating_sub(self.free).saturating_add(self.reserved) * self.page_size
+    }
+
+    pub fn total_bytes(&self) -> u64 {
+        self.total * self.page_size
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MemoryActivity {
+    /// `None` before the second sample.
+    pub faults: Option<FaultRates>,
+    /// The watched process and its rates, once sampled twice.
+    pub process_faults: Option<(u32, FaultRates)>,
+    pub hugepages: Option<HugePages>,
+}
+
+/// Cumulative minor and major fault counts.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Counts {
+    minor: u64,
+    major: u64,
+}
+
+#[derive(Default)]
+pub struct VmSampler {
+    last: Option<(Instant, Counts)>,
+    pid: Option<u32>,
+    last_pid: Option<(Instant, Counts)>,
+}
+
+impl VmSampler {
+    /// Also sample the faults of `pid` from now on.
+    pub fn set_pid(&mut self, pid: Option<u32>) {
+        if self.pid != pid {
+            self.pid = pid;
+            self.last_pid = None;
+        }
+    }
+
+    pub fn sample(&mut self) -> MemoryActivity {
+        let now = Instant::now();
+        let faults = read_system().and_then(|counts| rates(self.last.replace((now, counts))?, (now, counts)));
+        let process_faults = self.pid.and_then(|pid| {
+            let Some(counts) = read_process(pid) else {
+                self.last_pid = None;
+                return None;
+            };
+            Some((pid, rates(self.last_pid.replace((now, counts))?, (now, counts))?))
+        });
+        MemoryActivity { faults, process_faults, hugepages: read_hugepages() }
+    }
+}
+
+fn rates((then, prev): (Instant, Counts), (now, counts): (Instant, Counts)) -> Option<FaultRates> {
+    let secs = now.duration_since(then).as_secs_f32();
+    if secs <= 0.0 {
+        return None;
+    }
+    Some(FaultRates {
+        minor: counts.minor.saturating_sub(prev.minor) as f32 / secs,
+        major: counts.major.saturating_sub(prev.major) as f32 / secs,
+    })
+}
+
+#[cfg(target_os = "linux")]
+fn read_system() -> Option<Counts> {
+    parse_vmstat(&std::fs::read_to_string("/proc/vmstat").ok()?)
+}
+
+#[cfg(target_os = "linux")]
+fn read_process(pid: u32) -> Option<Counts> {
+    parse_pid_stat(&std::fs::read_to_string(format!("/proc/{pid}/stat")).ok()?)
+}
+
+#[cfg(target_os = "linux")]
+fn read_hugepages() -> Option<HugePages> {
+    parse_meminfo(&std::fs::read_to_string("/proc/meminfo").ok()?)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_system() -> Option<Counts> {
+    None
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_process(_pid: u32) -> Option<Counts> {
+    None
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_hugepages() -> Option<HugePages> {
+    None
+}
+
+/// `pgfault` counts every fault, major ones included.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_vmstat(vmstat: &str) -> Option<Counts> {
+    let field = |name: &str| {
+        vmstat.lines().find_map(|l| l.strip_prefix(name)?.strip_prefix(' ')?.trim().parse::<u64>().ok())
+    };
+    let (all, major) = (field("pgfault")?, field("pgmajfault")?);
+    Some(Counts { minor: all.saturating_sub(major), major })
+}
+
+/// `minflt` and `majflt` are the 10th and 12th fields; the name before
+/// them is parenthesized and may itself contain spaces and parentheses.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_pid_stat(stat: &str) -> Option<Counts> {
+    let fields: Vec<&str> = stat.rsplit_once(')')?.1.split_whitespace().collect();
+    Some(Counts { minor: fields.get(7)?.parse().ok()?, major: fields.get(9)?.parse().ok()? })
+}
+
+/// `None` when the kernel has no hugepage support at all.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_meminfo(meminfo: &str) -> Option<HugePages> {
+    let field = |name: &str| {
+        let rest = meminfo.lines().find_map(|l| l.strip_prefix(name)?.strip_prefix(':'))?;
+        let mut parts = rest.split_whitespace();
+        let value: u64 = parts.next()?.parse().ok()?;
+        // Sizes are in kB, page counts have no unit
+        Some(if parts.next() == Some("kB") { value * 1024 } else { value })
+    };
+    Some(HugePages {
+        total: field("HugePages_Total")?,
+        free: field("HugePages_Free").unwrap_or(0),
+        reserved: field("HugePages_Rsvd").unwrap_or(0),
+        page_size: field("Hugepagesize").unwrap_or(0),
+        transparent: field("AnonHugePages").unwrap_or(0),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_parse_vmstat() {
+        let vmstat = "pgfree 10\npgfault 1500\npgmajfault 40\npgmajfault_s 1\n";
+        assert_eq!(parse_vmstat(vmstat), Some(Counts { minor: 1460, major: 40 }));
+        assert_eq!(parse_vmstat("pgfree 10\n"), None);
+    }
+
+    #[test]
+    fn test_parse_pid_stat() {
+        let stat = "1234 (my (odd) app) S 1 1234 1234 0 -1 4194304 5230 120 7 0 12 3 0 0 20 0 4 0 100 1000 50\n";
+        assert_eq!(parse_pid_stat(stat), Some(Counts { minor: 5230, major: 7 }));
+        assert_eq!(parse_pid_stat("1234 (gone"), None);
+    }
+
+    #[test]
+    fn test_parse_meminfo() {
+        let meminfo = "MemTotal: 16000000 kB\nAnonHugePages: 2048 kB\nHugePages_Total: 10\nHugePages_Free: 6\n\
+                       HugePages_Rsvd: 1\nHugepagesize: 2048 kB\n";
+        let huge = parse_meminfo(meminfo).unwrap();
+        assert_eq!(huge, HugePages { total: 10, free: 6, reserved: 1, page_size: 2 << 20, transparent: 2 << 20 });
+        assert_eq!((huge.used_bytes(), huge.total_bytes()), (10 << 20, 20 << 20));
+        assert_eq!(parse_meminfo("MemTotal: 16000000 kB\n"), None);
+    }
+
+    #[test]
+    fn test_rates() {
+        let then = Instant::now();
+        let now = then + Duration::from_secs(2);
+        let r = rates((then, Counts { minor: 100, major: 10 }), (now, Counts { minor: 300, major: 14 })).unwrap();
+        assert_eq!(r, FaultRates { minor: 100.0, major: 2.0 });
+        assert_eq!(rates((now, Counts::default()), (now, Counts::default())), None);
+    }
+}